@@ -23,6 +23,25 @@ mod pki_sign;
 #[path = "exi-encoder.rs"]
 mod exi_encoder;
 
+#[path = "exi-error.rs"]
+mod exi_error;
+
+#[path = "exi-format.rs"]
+mod exi_format;
+
+#[path = "exi-scaled.rs"]
+mod exi_scaled;
+
+#[path = "exi-quantity.rs"]
+mod exi_quantity;
+
+#[path = "exi-quantity-messages.rs"]
+mod exi_quantity_messages;
+
+#[cfg(feature = "serde")]
+#[path = "exi-serde.rs"]
+mod exi_serde;
+
 #[path = "v2g-messages/@v2g-lib.rs"]
 mod v2g_encoder;
 
@@ -32,11 +51,21 @@ mod iso2_encoder;
 #[path = "din-messages/@din-lib.rs"]
 mod din_encoder;
 
+#[path = "iso-20-messages/@iso20-lib.rs"]
+mod iso20_encoder;
+
 pub mod prelude {
     pub use crate::capi::din_encoder::*;
     pub use crate::capi::exi_encoder::*;
+    pub use crate::capi::exi_error::*;
+    pub use crate::capi::exi_format::*;
+    pub use crate::capi::exi_quantity::*;
+    pub use crate::capi::exi_scaled::*;
+    #[cfg(feature = "serde")]
+    pub use crate::capi::exi_serde::*;
     pub use crate::capi::iso2_encoder::*;
+    pub use crate::capi::iso20_encoder::*;
     pub use crate::capi::pki_sign::*;
     pub use crate::capi::v2g_encoder::*;
-    pub use afbv4::prelude::*;
+    pub use crate::afb::*;
 }
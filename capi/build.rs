@@ -7,15 +7,113 @@
  *
 */
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// default location of tux-evse/iso15118-encoders sources for "vendored" feature
+const VENDORED_DIR: &str = "vendor/iso15118-encoders";
+
+// collect C sources & headers, generator/test directories are ignored
+fn scan_sources(dir: &Path, sources: &mut Vec<PathBuf>, headers: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) => panic!(
+            "fail to read vendored dir:{} error:{}",
+            dir.display(),
+            error
+        ),
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+            if name.starts_with('.')
+                || matches!(
+                    name.as_str(),
+                    "build" | "test" | "tests" | "examples" | "samples" | "cmake"
+                )
+            {
+                continue;
+            }
+            scan_sources(&path, sources, headers);
+        } else if name.ends_with(".c") {
+            sources.push(path);
+        } else if name.ends_with(".h") {
+            headers.push(path);
+        }
+    }
+}
+
+// compile libiso15118 from sources and return include dir for bindgen
+fn build_vendored(out_dir: &Path) -> PathBuf {
+    let src_dir = match env::var("ISO15118_ENCODERS_DIR") {
+        Ok(value) => PathBuf::from(value),
+        Err(_) => PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join(VENDORED_DIR),
+    };
+    println!("cargo:rerun-if-env-changed=ISO15118_ENCODERS_DIR");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+    if !src_dir.is_dir() {
+        panic!(
+            "vendored feature needs tux-evse/iso15118-encoders sources in {}, clone https://github.com/tux-evse/iso15118-encoders there or set ISO15118_ENCODERS_DIR",
+            src_dir.display()
+        );
+    }
+
+    let mut sources = Vec::new();
+    let mut headers = Vec::new();
+    scan_sources(&src_dir, &mut sources, &mut headers);
+    if sources.is_empty() {
+        panic!(
+            "no C sources found in {}, check tux-evse/iso15118-encoders checkout or ISO15118_ENCODERS_DIR",
+            src_dir.display()
+        );
+    }
+
+    // C API is included as <iso15118/xxx.h>, mirror installed header layout
+    let include_dir = out_dir.join("include");
+    let iso_dir = include_dir.join("iso15118");
+    fs::create_dir_all(&iso_dir).expect("Couldn't create vendored include dir");
+    for header in &headers {
+        fs::copy(header, iso_dir.join(header.file_name().unwrap()))
+            .expect("Couldn't copy vendored header");
+    }
+
+    let mut build = cc::Build::new();
+    build
+        .include(&include_dir)
+        .include(&iso_dir)
+        .warnings(false);
+    for source in &sources {
+        build.file(source);
+    }
+    build.compile("iso15118");
+    include_dir
+}
 
 fn main() {
-    // check pkgconfig dependencies
-    // system_deps::Config::new().probe().unwrap();
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut clang_args = Vec::new();
 
-    // invalidate the built crate whenever the wrapper changes
-    println!("cargo:rustc-link-search=/usr/local/lib64");
-    println!("cargo:rustc-link-arg=-liso15118");
-    println!("cargo:rustc-link-arg=-lgnutls");
+    if env::var("CARGO_FEATURE_VENDORED").is_ok() {
+        // cc emits rustc-link-lib=static=iso15118
+        let include_dir = build_vendored(&out_dir);
+        clang_args.push(format!("-I{}", include_dir.display()));
+    } else {
+        // check pkgconfig dependencies, fallback on legacy cmake install path
+        match system_deps::Config::new().probe() {
+            Ok(deps) => {
+                for dep in deps.all_include_paths() {
+                    clang_args.push(format!("-I{}", dep.display()));
+                }
+            }
+            Err(_) => {
+                println!("cargo:rustc-link-search=/usr/local/lib64");
+                println!("cargo:rustc-link-arg=-liso15118");
+            }
+        }
+    }
+    // pki-sign binding calls gnutls directly, required whatever libiso15118 origin
+    println!("cargo:rustc-link-lib=gnutls");
 
     if let Ok(value) = env::var("CARGO_TARGET_DIR") {
         if let Ok(profile) = env::var("PROFILE") {
@@ -26,7 +124,7 @@ fn main() {
     // -----------------------------------------------------------------------
     //         <- private 'lib-iso15118' Rust/C unsafe binding ->
     // -----------------------------------------------------------------------
-    //   Do not exit this file it will be regenerated automatically by cargo.
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
     //   Check:
     //     - build.rs for C/Rust glue options
     //     - src/capi/capi-exi.h for C prototype inputs
@@ -37,6 +135,7 @@ fn main() {
         .header("capi/capi-exi.h") // Chargebyte C prototype wrapper input
         .raw_line(header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
         .derive_debug(false)
         .layout_tests(false)
         .allowlist_item("exi_.*")
@@ -51,14 +150,14 @@ fn main() {
         .expect("Unable to generate _exi-capi.rs");
 
     libcapi
-        .write_to_file("capi/_exi-capi.rs")
+        .write_to_file(out_dir.join("_exi-capi.rs"))
         .expect("Couldn't write _exi-capi.rs!");
 
     let header = "
     // -----------------------------------------------------------------------
     //         <- private 'lib-iso15118' Rust/C unsafe binding ->
     // -----------------------------------------------------------------------
-    //   Do not exit this file it will be regenerated automatically by cargo.
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
     //   Check:
     //     - build.rs for C/Rust glue options
     //     - src/capi/capi-v2g.h for C prototype inputs
@@ -69,6 +168,7 @@ fn main() {
         .header("capi/v2g-messages/capi-v2g.h") // Chargebyte C prototype wrapper input
         .raw_line(header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
         .derive_debug(false)
         .layout_tests(false)
         .allowlist_item("sdp_.*")
@@ -85,14 +185,14 @@ fn main() {
         .expect("Unable to generate _v2g-capi.rs");
 
     libcapi
-        .write_to_file("capi/v2g-messages/_v2g-capi.rs")
-        .expect("Couldn't write capi/v2g-messages/_v2g-capi.rs!");
+        .write_to_file(out_dir.join("_v2g-capi.rs"))
+        .expect("Couldn't write _v2g-capi.rs!");
 
     let header = "
     // -----------------------------------------------------------------------
     //         <- private 'lib-iso15118' Rust/C unsafe binding ->
     // -----------------------------------------------------------------------
-    //   Do not exit this file it will be regenerated automatically by cargo.
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
     //   Check:
     //     - build.rs for C/Rust glue options
     //     - src/capi/capi-iso2.h for C prototype inputs
@@ -103,6 +203,7 @@ fn main() {
         .header("capi/iso2-messages/capi-iso2.h") // Chargebyte C prototype wrapper input
         .raw_line(header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
         .derive_debug(false)
         .layout_tests(false)
         .allowlist_item("iso2_.*")
@@ -115,14 +216,14 @@ fn main() {
         .expect("Unable to generate _iso2-capi.rs");
 
     libcapi
-        .write_to_file("capi/iso2-messages/_iso2-capi.rs")
-        .expect("Couldn't write capi/iso2-messages/_iso2-capi.rs!");
+        .write_to_file(out_dir.join("_iso2-capi.rs"))
+        .expect("Couldn't write _iso2-capi.rs!");
 
     let header = "
     // -----------------------------------------------------------------------
     //         <- private 'lib-iso15118' Rust/C unsafe binding ->
     // -----------------------------------------------------------------------
-    //   Do not exit this file it will be regenerated automatically by cargo.
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
     //   Check:
     //     - build.rs for C/Rust glue options
     //     - src/capi/capi-din.h for C prototype inputs
@@ -133,6 +234,7 @@ fn main() {
         .header("capi/din-messages/capi-din.h") // Chargebyte C prototype wrapper input
         .raw_line(header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
         .derive_debug(false)
         .layout_tests(false)
         .allowlist_item("din_.*")
@@ -143,14 +245,44 @@ fn main() {
         .expect("Unable to generate _din-capi.rs");
 
     libcapi
-        .write_to_file("capi/din-messages/_din-capi.rs")
-        .expect("Couldn't write capi/din-messages/_din-capi.rs!");
+        .write_to_file(out_dir.join("_din-capi.rs"))
+        .expect("Couldn't write _din-capi.rs!");
+
+    let header = "
+    // -----------------------------------------------------------------------
+    //         <- private 'lib-iso15118' Rust/C unsafe binding ->
+    // -----------------------------------------------------------------------
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
+    //   Check:
+    //     - build.rs for C/Rust glue options
+    //     - src/capi/iso20-encoder.h for C prototype inputs
+    // -----------------------------------------------------------------------
+    ";
+    println!("cargo:rerun-if-changed=capi/iso-20-messages/iso20-encoder.h");
+    let libcapi = bindgen::Builder::default()
+        .header("capi/iso-20-messages/iso20-encoder.h") // Chargebyte C prototype wrapper input
+        .raw_line(header)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
+        .derive_debug(false)
+        .layout_tests(false)
+        .allowlist_item("iso20_.*")
+        .allowlist_function("decode_iso20_.*")
+        .allowlist_function("encode_iso20_.*")
+        .blocklist_item("gnutls_.*")
+        .blocklist_item("exi.*")
+        .generate()
+        .expect("Unable to generate _iso20-capi.rs");
+
+    libcapi
+        .write_to_file(out_dir.join("_iso20-capi.rs"))
+        .expect("Couldn't write _iso20-capi.rs!");
 
     let header = "
     // -----------------------------------------------------------------------
     //         <- private 'lib-iso15118' Rust/C unsafe binding ->
     // -----------------------------------------------------------------------
-    //   Do not exit this file it will be regenerated automatically by cargo.
+    //   Do not edit this file it is regenerated into OUT_DIR by cargo.
     //   Check:
     //     - build.rs for C/Rust glue options
     //     - src/capi/capi-signatures.h for C prototype inputs
@@ -161,6 +293,7 @@ fn main() {
         .header("capi/pki-sign/capi-pki.h") // gnutls signature wrapper
         .raw_line(header)
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .clang_args(&clang_args)
         .derive_debug(false)
         .layout_tests(false)
         .allowlist_item("gnutls_privkey_.*")
@@ -181,6 +314,6 @@ fn main() {
         .expect("Unable to generate _signatures_capi.rs");
 
     libcapi
-        .write_to_file("capi/pki-sign/_pki-capi.rs")
-        .expect("Couldn't write capi/pki-sign/_pki-capi.rs!");
+        .write_to_file(out_dir.join("_pki-capi.rs"))
+        .expect("Couldn't write _pki-capi.rs!");
 }
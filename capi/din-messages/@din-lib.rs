@@ -26,7 +26,7 @@ pub(self) mod cglue {
     #![allow(non_snake_case)]
     // force reuse of C bitstream from exi-encoder
     use crate::prelude::exi_bitstream_t;
-    include!("_din-capi.rs");
+    include!(concat!(env!("OUT_DIR"), "/_din-capi.rs"));
 }
 
 #[path = "status-enums.rs"]
@@ -92,6 +92,9 @@ mod session_stop;
 #[path = "welding-detection.rs"]
 mod welding_detection;
 
+#[path = "header-signature.rs"]
+mod header_signature;
+
 #[path = "body-encoder.rs"]
 mod body_encoder;
 
@@ -105,6 +108,7 @@ pub mod din_exi {
     pub use super::charging_status::*;
     pub use super::contract_authentication::*;
     pub use super::current_demand::*;
+    pub use super::header_signature::*;
     pub use super::metering_receipt::*;
     pub use super::param_discovery::*;
     pub use super::param_value::*;
@@ -118,8 +122,12 @@ pub mod din_exi {
     pub use super::session_stop::*;
     pub use super::status_enums::*;
     pub use super::welding_detection::*;
-    use afbv4::prelude::*;
+    use crate::afb::*;
+    use std::fmt;
 
+    // serde uses XSD body element names as variant tags
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum MessageBody {
         SessionSetupReq(SessionSetupRequest),
         SessionSetupRes(SessionSetupResponse),
@@ -130,13 +138,17 @@ pub mod din_exi {
         BodyElement(BodyBaseElement),
         CableCheckReq(CableCheckRequest),
         CableCheckRes(CableCheckResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "CertificateInstallationReq"))]
         CertificateInstallReq(CertificateInstallRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "CertificateInstallationRes"))]
         CertificateInstallRes(CertificateInstallResponse),
         CertificateUpdateReq(CertificateUpdateRequest),
         CertificateUpdateRes(CertificateUpdateResponse),
         ContractAuthenticationReq(ContractAuthenticationRequest),
         ContractAuthenticationRes(ContractAuthenticationResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "ChargeParameterDiscoveryReq"))]
         ParamDiscoveryReq(ParamDiscoveryRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "ChargeParameterDiscoveryRes"))]
         ParamDiscoveryRes(ParamDiscoveryResponse),
         ChargingStatusReq(ChargingStatusRequest),
         ChargingStatusRes(ChargingStatusResponse),
@@ -146,7 +158,9 @@ pub mod din_exi {
         MeteringReceiptRes(MeteringReceiptResponse),
         PaymentDetailsReq(PaymentDetailsRequest),
         PaymentDetailsRes(PaymentDetailsResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "ServicePaymentSelectionReq"))]
         PaymentSelectionReq(PaymentSelectionRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "ServicePaymentSelectionRes"))]
         PaymentSelectionRes(PaymentSelectionResponse),
         PowerDeliveryReq(PowerDeliveryRequest),
         PowerDeliveryRes(PowerDeliveryResponse),
@@ -156,9 +170,94 @@ pub mod din_exi {
         SessionStopRes(SessionStopResponse),
         WeldingDetectionReq(WeldingDetectionRequest),
         WeldingDetectionRes(WeldingDetectionResponse),
+        #[cfg_attr(feature = "serde", serde(skip))]
         Unsupported,
     }
 
+    // one line session log, XSD body element name followed by its fields
+    impl fmt::Display for MessageBody {
+        fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MessageBody::SessionSetupReq(body) => write!(format, "SessionSetupReq {}", body),
+                MessageBody::SessionSetupRes(body) => write!(format, "SessionSetupRes {}", body),
+                MessageBody::ServiceDiscoveryReq(body) => {
+                    write!(format, "ServiceDiscoveryReq {}", body)
+                }
+                MessageBody::ServiceDiscoveryRes(body) => {
+                    write!(format, "ServiceDiscoveryRes {}", body)
+                }
+                MessageBody::ServiceDetailReq(body) => write!(format, "ServiceDetailReq {}", body),
+                MessageBody::ServiceDetailRes(body) => write!(format, "ServiceDetailRes {}", body),
+                MessageBody::BodyElement(body) => write!(format, "BodyElement {}", body),
+                MessageBody::CableCheckReq(body) => write!(format, "CableCheckReq {}", body),
+                MessageBody::CableCheckRes(body) => write!(format, "CableCheckRes {}", body),
+                MessageBody::CertificateInstallReq(body) => {
+                    write!(format, "CertificateInstallationReq {}", body)
+                }
+                MessageBody::CertificateInstallRes(body) => {
+                    write!(format, "CertificateInstallationRes {}", body)
+                }
+                MessageBody::CertificateUpdateReq(body) => {
+                    write!(format, "CertificateUpdateReq {}", body)
+                }
+                MessageBody::CertificateUpdateRes(body) => {
+                    write!(format, "CertificateUpdateRes {}", body)
+                }
+                MessageBody::ContractAuthenticationReq(body) => {
+                    write!(format, "ContractAuthenticationReq {}", body)
+                }
+                MessageBody::ContractAuthenticationRes(body) => {
+                    write!(format, "ContractAuthenticationRes {}", body)
+                }
+                MessageBody::ParamDiscoveryReq(body) => {
+                    write!(format, "ChargeParameterDiscoveryReq {}", body)
+                }
+                MessageBody::ParamDiscoveryRes(body) => {
+                    write!(format, "ChargeParameterDiscoveryRes {}", body)
+                }
+                MessageBody::ChargingStatusReq(body) => {
+                    write!(format, "ChargingStatusReq {}", body)
+                }
+                MessageBody::ChargingStatusRes(body) => {
+                    write!(format, "ChargingStatusRes {}", body)
+                }
+                MessageBody::CurrentDemandReq(body) => write!(format, "CurrentDemandReq {}", body),
+                MessageBody::CurrentDemandRes(body) => write!(format, "CurrentDemandRes {}", body),
+                MessageBody::MeteringReceiptReq(body) => {
+                    write!(format, "MeteringReceiptReq {}", body)
+                }
+                MessageBody::MeteringReceiptRes(body) => {
+                    write!(format, "MeteringReceiptRes {}", body)
+                }
+                MessageBody::PaymentDetailsReq(body) => {
+                    write!(format, "PaymentDetailsReq {}", body)
+                }
+                MessageBody::PaymentDetailsRes(body) => {
+                    write!(format, "PaymentDetailsRes {}", body)
+                }
+                MessageBody::PaymentSelectionReq(body) => {
+                    write!(format, "ServicePaymentSelectionReq {}", body)
+                }
+                MessageBody::PaymentSelectionRes(body) => {
+                    write!(format, "ServicePaymentSelectionRes {}", body)
+                }
+                MessageBody::PowerDeliveryReq(body) => write!(format, "PowerDeliveryReq {}", body),
+                MessageBody::PowerDeliveryRes(body) => write!(format, "PowerDeliveryRes {}", body),
+                MessageBody::PreChargeReq(body) => write!(format, "PreChargeReq {}", body),
+                MessageBody::PreChargeRes(body) => write!(format, "PreChargeRes {}", body),
+                MessageBody::SessionStopReq(body) => write!(format, "SessionStopReq {}", body),
+                MessageBody::SessionStopRes(body) => write!(format, "SessionStopRes {}", body),
+                MessageBody::WeldingDetectionReq(body) => {
+                    write!(format, "WeldingDetectionReq {}", body)
+                }
+                MessageBody::WeldingDetectionRes(body) => {
+                    write!(format, "WeldingDetectionRes {}", body)
+                }
+                MessageBody::Unsupported => format.write_str("Unsupported"),
+            }
+        }
+    }
+
     impl MessageBody {
         #[track_caller]
         pub fn get_tagid(&self) -> MessageTagId {
@@ -208,6 +307,51 @@ pub mod din_exi {
             }
         }
 
+        #[track_caller]
+        pub fn encode(&self) -> Result<DinBodyType, AfbError> {
+            let body = match self {
+                MessageBody::SessionSetupReq(body) => body.encode(),
+                MessageBody::SessionSetupRes(body) => body.encode(),
+                MessageBody::ServiceDiscoveryReq(body) => body.encode(),
+                MessageBody::ServiceDiscoveryRes(body) => body.encode(),
+                MessageBody::ServiceDetailReq(body) => body.encode(),
+                MessageBody::ServiceDetailRes(body) => body.encode(),
+                MessageBody::BodyElement(body) => body.encode(),
+                MessageBody::CableCheckReq(body) => body.encode(),
+                MessageBody::CableCheckRes(body) => body.encode(),
+                MessageBody::CertificateInstallReq(body) => body.encode(),
+                MessageBody::CertificateInstallRes(body) => body.encode(),
+                MessageBody::CertificateUpdateReq(body) => body.encode(),
+                MessageBody::CertificateUpdateRes(body) => body.encode(),
+                MessageBody::ContractAuthenticationReq(body) => body.encode(),
+                MessageBody::ContractAuthenticationRes(body) => body.encode(),
+                MessageBody::ParamDiscoveryReq(body) => body.encode(),
+                MessageBody::ParamDiscoveryRes(body) => body.encode(),
+                MessageBody::ChargingStatusReq(body) => body.encode(),
+                MessageBody::ChargingStatusRes(body) => body.encode(),
+                MessageBody::CurrentDemandReq(body) => body.encode(),
+                MessageBody::CurrentDemandRes(body) => body.encode(),
+                MessageBody::MeteringReceiptReq(body) => body.encode(),
+                MessageBody::MeteringReceiptRes(body) => body.encode(),
+                MessageBody::PaymentDetailsReq(body) => body.encode(),
+                MessageBody::PaymentDetailsRes(body) => body.encode(),
+                MessageBody::PaymentSelectionReq(body) => body.encode(),
+                MessageBody::PaymentSelectionRes(body) => body.encode(),
+                MessageBody::PowerDeliveryReq(body) => body.encode(),
+                MessageBody::PowerDeliveryRes(body) => body.encode(),
+                MessageBody::PreChargeReq(body) => body.encode(),
+                MessageBody::PreChargeRes(body) => body.encode(),
+                MessageBody::SessionStopReq(body) => body.encode(),
+                MessageBody::SessionStopRes(body) => body.encode(),
+                MessageBody::WeldingDetectionReq(body) => body.encode(),
+                MessageBody::WeldingDetectionRes(body) => body.encode(),
+                MessageBody::Unsupported => {
+                    return afb_error!("din-encode-exi", "unknown/unsupported message")
+                }
+            };
+            Ok(body)
+        }
+
         #[track_caller]
         pub fn decode(payload: &super::cglue::din_BodyType) -> Result<Self, AfbError> {
             // SessionSetup
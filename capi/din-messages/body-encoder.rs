@@ -52,18 +52,32 @@ impl ExiMessageHeader {
     }
 
     pub fn set_signature_used(&mut self) -> &mut Self {
-        self.payload.set_Notification_isUsed(1);
+        self.payload.set_Signature_isUsed(1);
         self
     }
 
     pub fn get_signature_used(&self) -> bool {
-        if self.payload.Notification_isUsed() == 0 {
+        if self.payload.Signature_isUsed() == 0 {
             false
         } else {
             true
         }
     }
 
+    pub fn set_signature(&mut self, signature: &SignatureType) -> &mut Self {
+        self.payload.Signature = signature.encode();
+        self.payload.set_Signature_isUsed(1);
+        self
+    }
+
+    pub fn get_signature(&self) -> Option<SignatureType> {
+        if self.payload.Signature_isUsed() == 0 {
+            None
+        } else {
+            Some(SignatureType::decode(self.payload.Signature))
+        }
+    }
+
     pub fn get_session_id(&self) -> &[u8] {
         let session = array_to_bytes(
             &self.payload.SessionID.bytes,
@@ -72,6 +86,15 @@ impl ExiMessageHeader {
         session
     }
 
+    pub fn set_session_id(&mut self, session_id: &[u8]) -> Result<&mut Self, AfbError> {
+        self.payload.SessionID.bytesLen = bytes_to_array(
+            session_id,
+            &mut self.payload.SessionID.bytes,
+            cglue::din_sessionIDType_BYTES_SIZE,
+        )?;
+        Ok(self)
+    }
+
     pub fn decode(payload: cglue::din_MessageHeaderType) -> Self {
         Self {
             payload: payload.clone(),
@@ -90,16 +113,24 @@ pub struct ExiMessageDoc {
 impl ExiMessageDoc {
     #[track_caller]
     pub fn decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, AfbError> {
+        Ok(Self::try_decode_from_stream(locked)?)
+    }
+
+    /// same as decode_from_stream with typed error (exi code & stream position)
+    pub fn try_decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, Iso15118Error> {
         let payload = unsafe {
             let mut buffer = mem::MaybeUninit::<cglue::din_exiDocument>::uninit();
-            let status = cglue::decode_din_exiDocument(locked.stream, buffer.as_mut_ptr());
-            let exi_raw = buffer.assume_init();
+            let status = cglue::decode_din_exiDocument(locked.as_mut_ptr(), buffer.as_mut_ptr());
             if status < 0 {
-                return afb_error!(
-                    "din-exi-decode",
-                    "fail to decode din (ExiDocument) from stream"
-                );
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::Din,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
             }
+            let exi_raw = buffer.assume_init();
             locked.reset();
             exi_raw
         };
@@ -108,32 +139,31 @@ impl ExiMessageDoc {
 
     #[track_caller]
     pub fn encode_to_stream(&self, locked: &mut RawStream) -> Result<(), AfbError> {
+        Ok(self.try_encode_to_stream(locked)?)
+    }
+
+    /// same as encode_to_stream with typed error (exi code, stream position & message tag)
+    pub fn try_encode_to_stream(&self, locked: &mut RawStream) -> Result<(), Iso15118Error> {
         locked.reset(); // cleanup stream before encoding
 
         // reserve space for v2g header
-        match unsafe { locked.stream.as_mut() } {
-            Some(data) => {
-                data.byte_pos = SDP_V2G_HEADER_LEN as usize;
-            }
-            None => {
-                return afb_error!(
-                    "encode_stream-header",
-                    "fail to get locked.stream (invalid stream)"
-                )
-            }
-        };
+        locked.set_window(EXI_MAX_DOCUMENT_SIZE, SDP_V2G_HEADER_LEN as usize);
 
         let status = unsafe {
             cglue::encode_din_exiDocument(
-                locked.stream,
+                locked.as_mut_ptr(),
                 &self.payload as *const _ as *mut cglue::din_exiDocument,
             )
         };
         if status < 0 {
-            return afb_error!(
-                "exi-iso-encode",
-                "fail to encode encode_din_exiDocument to exi"
-            );
+            let tagid = self.get_body().ok().map(|body| body.get_tagid());
+            return Err(Iso15118Error::exi(
+                ExiProtocol::Din,
+                ExiOperation::Encode,
+                status,
+                locked,
+                tagid.as_ref().map(|tagid| tagid.to_label()),
+            ));
         }
 
         // retrieve document encoded size from stream and insert header
@@ -170,3 +200,73 @@ impl ExiMessageDoc {
         self.payload
     }
 }
+
+impl ExiFields for ExiMessageHeader {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("SessionID", ExiHexa(self.get_session_id()))
+            .option("Signature", self.get_signature());
+    }
+}
+
+impl ExiFields for ExiMessageDoc {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("Header", self.get_header())
+            .result("Body", self.get_body());
+    }
+}
+crate::exi_format!(ExiMessageHeader, ExiMessageDoc);
+
+// note: header Notification content is not modeled, only SessionID & Signature are serialized
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct MessageHeaderXsd {
+        SessionID: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Signature: Option<SignatureType>,
+    }
+
+    impl MessageHeaderXsd {
+        fn from_exi(exi: &ExiMessageHeader) -> Result<Self, AfbError> {
+            Ok(Self {
+                SessionID: ExiBytes::from(exi.get_session_id()),
+                Signature: exi.get_signature(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageHeader, AfbError> {
+            let mut exi = ExiMessageHeader::new(self.SessionID.as_slice())?;
+            if let Some(signature) = &self.Signature {
+                exi.set_signature(signature);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageHeader, MessageHeaderXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct V2GMessageXsd {
+        Header: ExiMessageHeader,
+        Body: MessageBody,
+    }
+
+    impl V2GMessageXsd {
+        fn from_exi(exi: &ExiMessageDoc) -> Result<Self, AfbError> {
+            Ok(Self {
+                Header: exi.get_header(),
+                Body: exi.get_body()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageDoc, AfbError> {
+            Ok(ExiMessageDoc::new(&self.Header, &self.Body.encode()?))
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageDoc, V2GMessageXsd);
+}
@@ -62,7 +62,7 @@ impl CableCheckResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -70,7 +70,7 @@ impl CableCheckResponse {
         DcEvseStatusType::decode(self.payload.DC_EVSEStatus)
     }
 
-    pub fn get_processing(&self) -> EvseProcessing {
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
         EvseProcessing::from_u32(self.payload.EVSEProcessing)
     }
 
@@ -88,3 +88,70 @@ impl CableCheckResponse {
         body
     }
 }
+
+impl ExiFields for CableCheckRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("DC_EVStatus", self.get_status());
+    }
+}
+
+impl ExiFields for CableCheckResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("DC_EVSEStatus", self.get_status())
+            .result("EVSEProcessing", self.get_processing());
+    }
+}
+crate::exi_format!(CableCheckRequest, CableCheckResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CableCheckReqXsd {
+        DC_EVStatus: DcEvStatusType,
+    }
+
+    impl CableCheckReqXsd {
+        fn from_exi(exi: &CableCheckRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CableCheckRequest, AfbError> {
+            Ok(CableCheckRequest::new(&self.DC_EVStatus))
+        }
+    }
+    crate::exi_serde_mirror!(CableCheckRequest, CableCheckReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CableCheckResXsd {
+        ResponseCode: ResponseCode,
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEProcessing: EvseProcessing,
+    }
+
+    impl CableCheckResXsd {
+        fn from_exi(exi: &CableCheckResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_EVSEStatus: exi.get_status(),
+                EVSEProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<CableCheckResponse, AfbError> {
+            Ok(CableCheckResponse::new(
+                self.ResponseCode,
+                &self.DC_EVSEStatus,
+                self.EVSEProcessing,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(CableCheckResponse, CableCheckResXsd);
+}
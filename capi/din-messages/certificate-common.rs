@@ -163,3 +163,113 @@ impl CertificateChainType {
         self.payload
     }
 }
+
+impl ExiFields for IssuerSerialType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("X509IssuerName", self.get_issuer())
+            .field("X509SerialNumber", self.get_serial());
+    }
+}
+
+impl ExiFields for CertificateRootList {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("RootCertificateID", self.get_certs().map(ExiList));
+    }
+}
+
+impl ExiFields for CertificateChainType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("Certificate", ExiHexa(self.get_cert()))
+            .option("SubCertificates", self.get_subcert().map(ExiHexa));
+    }
+}
+crate::exi_format!(IssuerSerialType, CertificateRootList, CertificateChainType);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct X509IssuerSerialXsd {
+        X509IssuerName: String,
+        X509SerialNumber: i32,
+    }
+
+    impl X509IssuerSerialXsd {
+        fn from_exi(exi: &IssuerSerialType) -> Result<Self, AfbError> {
+            Ok(Self {
+                X509IssuerName: exi.get_issuer()?.to_string(),
+                X509SerialNumber: exi.get_serial(),
+            })
+        }
+
+        fn into_exi(self) -> Result<IssuerSerialType, AfbError> {
+            IssuerSerialType::new(&self.X509IssuerName, self.X509SerialNumber)
+        }
+    }
+    crate::exi_serde_mirror!(IssuerSerialType, X509IssuerSerialXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ListOfRootCertificateIDsXsd {
+        RootCertificateID: Vec<String>,
+    }
+
+    impl ListOfRootCertificateIDsXsd {
+        fn from_exi(exi: &CertificateRootList) -> Result<Self, AfbError> {
+            Ok(Self {
+                RootCertificateID: exi.get_certs()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateRootList, AfbError> {
+            let mut certs = self.RootCertificateID.iter();
+            let mut exi = match certs.next() {
+                Some(cert) => CertificateRootList::new(cert)?,
+                None => CertificateRootList::decode(unsafe {
+                    mem::zeroed::<cglue::din_ListOfRootCertificateIDsType>()
+                }),
+            };
+            for cert in certs {
+                exi.add_cert(cert)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateRootList, ListOfRootCertificateIDsXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SubCertificatesXsd {
+        Certificate: ExiBytes,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateChainXsd {
+        Certificate: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SubCertificates: Option<SubCertificatesXsd>,
+    }
+
+    impl CertificateChainXsd {
+        fn from_exi(exi: &CertificateChainType) -> Result<Self, AfbError> {
+            Ok(Self {
+                Certificate: ExiBytes::from(exi.get_cert()),
+                SubCertificates: exi.get_subcert().map(|cert| SubCertificatesXsd {
+                    Certificate: ExiBytes::from(cert),
+                }),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateChainType, AfbError> {
+            let mut exi = CertificateChainType::new(self.Certificate.as_slice())?;
+            if let Some(subcerts) = &self.SubCertificates {
+                exi.set_subcert(subcerts.Certificate.as_slice())?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateChainType, CertificateChainXsd);
+}
@@ -133,7 +133,7 @@ impl CertificateInstallResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -171,3 +171,117 @@ impl CertificateInstallResponse {
         body
     }
 }
+
+impl ExiFields for CertificateInstallRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result_option("Id", self.get_id())
+            .field("OEMProvisioningCert", ExiHexa(self.get_provisioning()))
+            .field("ListOfRootCertificateIDs", self.get_certs_list());
+    }
+}
+
+impl ExiFields for CertificateInstallResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .result("ResponseCode", self.get_rcode())
+            .field("ContractSignatureCertChain", self.get_contract_chain())
+            .field(
+                "ContractSignatureEncryptedPrivateKey",
+                ExiHexa(self.get_contract_signature()),
+            )
+            .field("DHParams", ExiHexa(self.get_public_key()))
+            .result("ContractID", self.get_contract_id());
+    }
+}
+crate::exi_format!(CertificateInstallRequest, CertificateInstallResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateInstallationReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        OEMProvisioningCert: ExiBytes,
+        ListOfRootCertificateIDs: CertificateRootList,
+    }
+
+    impl CertificateInstallationReqXsd {
+        fn from_exi(exi: &CertificateInstallRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.map(|value| value.to_string()),
+                OEMProvisioningCert: ExiBytes::from(exi.get_provisioning()),
+                ListOfRootCertificateIDs: exi.get_certs_list(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateInstallRequest, AfbError> {
+            let mut exi = CertificateInstallRequest::new(
+                self.OEMProvisioningCert.as_slice(),
+                &self.ListOfRootCertificateIDs,
+            )?;
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateInstallRequest, CertificateInstallationReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateInstallationResXsd {
+        Id: String,
+        ResponseCode: ResponseCode,
+        ContractSignatureCertChain: CertificateChainType,
+        ContractSignatureEncryptedPrivateKey: ExiBytes,
+        DHParams: ExiBytes,
+        ContractID: String,
+    }
+
+    impl CertificateInstallationResXsd {
+        fn from_exi(exi: &CertificateInstallResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                ResponseCode: exi.get_rcode()?,
+                ContractSignatureCertChain: exi.get_contract_chain(),
+                ContractSignatureEncryptedPrivateKey: ExiBytes::from(exi.get_contract_signature()),
+                DHParams: ExiBytes::from(exi.get_public_key()),
+                ContractID: exi.get_contract_id()?.to_string(),
+            })
+        }
+
+        // new() mixes Id/ContractID, payload is filled directly to keep received values
+        fn into_exi(self) -> Result<CertificateInstallResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_CertificateInstallationResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.Id.charactersLen = str_to_array(
+                &self.Id,
+                &mut payload.Id.characters,
+                cglue::din_Id_CHARACTER_SIZE,
+            )?;
+            payload.ContractID.charactersLen = str_to_array(
+                &self.ContractID,
+                &mut payload.ContractID.characters,
+                cglue::din_ContractID_CHARACTER_SIZE,
+            )?;
+            payload.ContractSignatureCertChain = self.ContractSignatureCertChain.encode();
+            payload.DHParams.bytesLen = bytes_to_array(
+                self.DHParams.as_slice(),
+                &mut payload.DHParams.bytes,
+                cglue::din_dHParamsType_BYTES_SIZE,
+            )?;
+            payload.ContractSignatureEncryptedPrivateKey.bytesLen = bytes_to_array(
+                self.ContractSignatureEncryptedPrivateKey.as_slice(),
+                &mut payload.ContractSignatureEncryptedPrivateKey.bytes,
+                cglue::din_privateKeyType_BYTES_SIZE,
+            )?;
+            Ok(CertificateInstallResponse::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(CertificateInstallResponse, CertificateInstallationResXsd);
+}
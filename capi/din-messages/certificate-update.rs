@@ -140,7 +140,7 @@ impl CertificateUpdateResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -188,3 +188,108 @@ impl CertificateUpdateResponse {
         body
     }
 }
+
+impl ExiFields for CertificateUpdateRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .result("ContractID", self.get_contract_id())
+            .field("ListOfRootCertificateIDs", self.get_root_certs())
+            .field("DHParams", ExiHexa(self.get_public_key()));
+    }
+}
+
+impl ExiFields for CertificateUpdateResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .result("ResponseCode", self.get_rcode())
+            .field("ContractSignatureCertChain", self.get_contract_chain())
+            .field(
+                "ContractSignatureEncryptedPrivateKey",
+                ExiHexa(self.get_signature()),
+            )
+            .field("DHParams", ExiHexa(self.get_public_key()))
+            .result("ContractID", self.get_contract_id())
+            .field("RetryCounter", self.get_rcount());
+    }
+}
+crate::exi_format!(CertificateUpdateRequest, CertificateUpdateResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateUpdateReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        ContractID: String,
+        ListOfRootCertificateIDs: CertificateRootList,
+        DHParams: ExiBytes,
+    }
+
+    impl CertificateUpdateReqXsd {
+        fn from_exi(exi: &CertificateUpdateRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id().map(|value| value.to_string()),
+                ContractID: exi.get_contract_id()?.to_string(),
+                ListOfRootCertificateIDs: exi.get_root_certs(),
+                DHParams: ExiBytes::from(exi.get_public_key()),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateUpdateRequest, AfbError> {
+            let mut exi = CertificateUpdateRequest::new(
+                &self.ContractID,
+                &self.ListOfRootCertificateIDs,
+                self.DHParams.as_slice(),
+            )?;
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateUpdateRequest, CertificateUpdateReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateUpdateResXsd {
+        Id: String,
+        ResponseCode: ResponseCode,
+        ContractSignatureCertChain: CertificateChainType,
+        ContractSignatureEncryptedPrivateKey: ExiBytes,
+        DHParams: ExiBytes,
+        ContractID: String,
+        RetryCounter: i16,
+    }
+
+    impl CertificateUpdateResXsd {
+        fn from_exi(exi: &CertificateUpdateResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                ResponseCode: exi.get_rcode()?,
+                ContractSignatureCertChain: exi.get_contract_chain(),
+                ContractSignatureEncryptedPrivateKey: ExiBytes::from(exi.get_signature()),
+                DHParams: ExiBytes::from(exi.get_public_key()),
+                ContractID: exi.get_contract_id()?.to_string(),
+                RetryCounter: exi.get_rcount(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateUpdateResponse, AfbError> {
+            CertificateUpdateResponse::new(
+                self.ResponseCode,
+                &self.Id,
+                &self.ContractID,
+                &self.ContractSignatureCertChain,
+                self.ContractSignatureEncryptedPrivateKey.as_slice(),
+                self.DHParams.as_slice(),
+                self.RetryCounter,
+            )
+        }
+    }
+    crate::exi_serde_mirror!(CertificateUpdateResponse, CertificateUpdateResXsd);
+}
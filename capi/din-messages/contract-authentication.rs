@@ -99,11 +99,11 @@ impl ContractAuthenticationResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
-    pub fn get_processing(&self) -> EvseProcessing {
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
         EvseProcessing::from_u32(self.payload.EVSEProcessing)
     }
 
@@ -121,3 +121,82 @@ impl ContractAuthenticationResponse {
         body
     }
 }
+
+impl ExiFields for ContractAuthenticationRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result_option("Id", self.get_id())
+            .result_option("GenChallenge", self.get_challenge());
+    }
+}
+
+impl ExiFields for ContractAuthenticationResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing());
+    }
+}
+crate::exi_format!(
+    ContractAuthenticationRequest,
+    ContractAuthenticationResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ContractAuthenticationReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        GenChallenge: Option<String>,
+    }
+
+    impl ContractAuthenticationReqXsd {
+        fn from_exi(exi: &ContractAuthenticationRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.map(|value| value.to_string()),
+                GenChallenge: exi.get_challenge()?.map(|value| value.to_string()),
+            })
+        }
+
+        fn into_exi(self) -> Result<ContractAuthenticationRequest, AfbError> {
+            let mut exi = ContractAuthenticationRequest::new();
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            if let Some(value) = &self.GenChallenge {
+                exi.set_challenge(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ContractAuthenticationRequest, ContractAuthenticationReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ContractAuthenticationResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+    }
+
+    impl ContractAuthenticationResXsd {
+        fn from_exi(exi: &ContractAuthenticationResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ContractAuthenticationResponse, AfbError> {
+            Ok(ContractAuthenticationResponse::new(
+                self.ResponseCode,
+                self.EVSEProcessing,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(ContractAuthenticationResponse, ContractAuthenticationResXsd);
+}
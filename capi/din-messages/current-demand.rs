@@ -62,7 +62,7 @@ impl CurrentDemandRequest {
         &mut self,
         voltage_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = voltage_limit.get_unit() {
+        if let Some(unit) = voltage_limit.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "current-demand-req",
@@ -88,7 +88,7 @@ impl CurrentDemandRequest {
         &mut self,
         current_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = current_limit.get_unit() {
+        if let Some(unit) = current_limit.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "current-demand-req",
@@ -112,7 +112,7 @@ impl CurrentDemandRequest {
     }
 
     pub fn set_power_limit(&mut self, power_limit: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = power_limit.get_unit() {
+        if let Some(unit) = power_limit.get_unit()? {
             if unit != PhysicalUnit::Watt {
                 return afb_error!(
                     "current-demand-req",
@@ -138,7 +138,7 @@ impl CurrentDemandRequest {
         &mut self,
         remaining_time: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = remaining_time.get_unit() {
+        if let Some(unit) = remaining_time.get_unit()? {
             if unit != PhysicalUnit::Hour
                 && unit != PhysicalUnit::Minute
                 && unit != PhysicalUnit::Second
@@ -167,7 +167,7 @@ impl CurrentDemandRequest {
         &mut self,
         remaining_time: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = remaining_time.get_unit() {
+        if let Some(unit) = remaining_time.get_unit()? {
             if unit != PhysicalUnit::Hour
                 && unit != PhysicalUnit::Minute
                 && unit != PhysicalUnit::Second
@@ -245,7 +245,7 @@ impl CurrentDemandResponse {
 
         payload.ResponseCode = rcode as u32;
 
-        if let Some(unit) = current_present.get_unit() {
+        if let Some(unit) = current_present.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "current-demand-res",
@@ -255,7 +255,7 @@ impl CurrentDemandResponse {
             }
         }
 
-        if let Some(unit) = voltage_present.get_unit() {
+        if let Some(unit) = voltage_present.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "current-demand-res",
@@ -275,7 +275,7 @@ impl CurrentDemandResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -316,7 +316,7 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_voltage_limit(&mut self, voltage: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = voltage.get_unit() {
+        if let Some(unit) = voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "current-demand-res",
@@ -340,7 +340,7 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_current_limit(&mut self, current: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = current.get_unit() {
+        if let Some(unit) = current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "current-demand-res",
@@ -363,7 +363,7 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_power_limit(&mut self, power: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = power.get_unit() {
+        if let Some(unit) = power.get_unit()? {
             if unit != PhysicalUnit::Watt {
                 return afb_error!(
                     "current-demand-res",
@@ -398,3 +398,175 @@ impl CurrentDemandResponse {
         body
     }
 }
+
+impl ExiFields for CurrentDemandRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVStatus", self.get_status())
+            .field("EVTargetCurrent", self.get_current_target())
+            .option("EVMaximumVoltageLimit", self.get_voltage_limit())
+            .option("EVMaximumCurrentLimit", self.get_current_limit())
+            .option("EVMaximumPowerLimit", self.get_power_limit())
+            .option("BulkChargingComplete", self.get_bulk_complete())
+            .field("ChargingComplete", self.get_charging_complete())
+            .option("RemainingTimeToFullSoC", self.get_time_to_full_sock())
+            .option("RemainingTimeToBulkSoC", self.get_time_to_bulk_sock())
+            .field("EVTargetVoltage", self.get_voltage_target());
+    }
+}
+
+impl ExiFields for CurrentDemandResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEPresentVoltage", self.get_voltage_present())
+            .field("EVSEPresentCurrent", self.get_current_present())
+            .field("EVSECurrentLimitAchieved", self.get_current_limit_reach())
+            .field("EVSEVoltageLimitAchieved", self.get_voltage_limit_reach())
+            .field("EVSEPowerLimitAchieved", self.get_power_limit_reach())
+            .option("EVSEMaximumVoltageLimit", self.get_voltage_limit())
+            .option("EVSEMaximumCurrentLimit", self.get_current_limit())
+            .option("EVSEMaximumPowerLimit", self.get_power_limit());
+    }
+}
+crate::exi_format!(CurrentDemandRequest, CurrentDemandResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CurrentDemandReqXsd {
+        DC_EVStatus: DcEvStatusType,
+        EVTargetCurrent: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumVoltageLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumCurrentLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumPowerLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BulkChargingComplete: Option<bool>,
+        ChargingComplete: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RemainingTimeToFullSoC: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RemainingTimeToBulkSoC: Option<PhysicalValue>,
+        EVTargetVoltage: PhysicalValue,
+    }
+
+    impl CurrentDemandReqXsd {
+        fn from_exi(exi: &CurrentDemandRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+                EVTargetCurrent: exi.get_current_target(),
+                EVMaximumVoltageLimit: exi.get_voltage_limit(),
+                EVMaximumCurrentLimit: exi.get_current_limit(),
+                EVMaximumPowerLimit: exi.get_power_limit(),
+                BulkChargingComplete: exi.get_bulk_complete(),
+                ChargingComplete: exi.get_charging_complete(),
+                RemainingTimeToFullSoC: exi.get_time_to_full_sock(),
+                RemainingTimeToBulkSoC: exi.get_time_to_bulk_sock(),
+                EVTargetVoltage: exi.get_voltage_target(),
+            })
+        }
+
+        // fill payload directly, decoded messages are not always compliant with setter checks
+        fn into_exi(self) -> Result<CurrentDemandRequest, AfbError> {
+            let mut exi = CurrentDemandRequest::new(
+                &self.DC_EVStatus,
+                &self.EVTargetCurrent,
+                &self.EVTargetVoltage,
+                self.ChargingComplete,
+            );
+            let payload = &mut exi.payload;
+            if let Some(value) = &self.EVMaximumVoltageLimit {
+                payload.EVMaximumVoltageLimit = value.encode();
+                payload.set_EVMaximumVoltageLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVMaximumCurrentLimit {
+                payload.EVMaximumCurrentLimit = value.encode();
+                payload.set_EVMaximumCurrentLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVMaximumPowerLimit {
+                payload.EVMaximumPowerLimit = value.encode();
+                payload.set_EVMaximumPowerLimit_isUsed(1);
+            }
+            if let Some(value) = &self.RemainingTimeToFullSoC {
+                payload.RemainingTimeToFullSoC = value.encode();
+                payload.set_RemainingTimeToFullSoC_isUsed(1);
+            }
+            if let Some(value) = &self.RemainingTimeToBulkSoC {
+                payload.RemainingTimeToBulkSoC = value.encode();
+                payload.set_RemainingTimeToBulkSoC_isUsed(1);
+            }
+            if let Some(value) = self.BulkChargingComplete {
+                exi.set_bulk_complete(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CurrentDemandRequest, CurrentDemandReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CurrentDemandResXsd {
+        ResponseCode: ResponseCode,
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEPresentVoltage: PhysicalValue,
+        EVSEPresentCurrent: PhysicalValue,
+        EVSECurrentLimitAchieved: bool,
+        EVSEVoltageLimitAchieved: bool,
+        EVSEPowerLimitAchieved: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumVoltageLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumCurrentLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumPowerLimit: Option<PhysicalValue>,
+    }
+
+    impl CurrentDemandResXsd {
+        fn from_exi(exi: &CurrentDemandResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_EVSEStatus: exi.get_status(),
+                EVSEPresentVoltage: exi.get_voltage_present(),
+                EVSEPresentCurrent: exi.get_current_present(),
+                EVSECurrentLimitAchieved: exi.get_current_limit_reach(),
+                EVSEVoltageLimitAchieved: exi.get_voltage_limit_reach(),
+                EVSEPowerLimitAchieved: exi.get_power_limit_reach(),
+                EVSEMaximumVoltageLimit: exi.get_voltage_limit(),
+                EVSEMaximumCurrentLimit: exi.get_current_limit(),
+                EVSEMaximumPowerLimit: exi.get_power_limit(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CurrentDemandResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_CurrentDemandResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEPresentVoltage = self.EVSEPresentVoltage.encode();
+            payload.EVSEPresentCurrent = self.EVSEPresentCurrent.encode();
+            payload.EVSECurrentLimitAchieved = if self.EVSECurrentLimitAchieved { 1 } else { 0 };
+            payload.EVSEVoltageLimitAchieved = if self.EVSEVoltageLimitAchieved { 1 } else { 0 };
+            payload.EVSEPowerLimitAchieved = if self.EVSEPowerLimitAchieved { 1 } else { 0 };
+            if let Some(value) = &self.EVSEMaximumVoltageLimit {
+                payload.EVSEMaximumVoltageLimit = value.encode();
+                payload.set_EVSEMaximumVoltageLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEMaximumCurrentLimit {
+                payload.EVSEMaximumCurrentLimit = value.encode();
+                payload.set_EVSEMaximumCurrentLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEMaximumPowerLimit {
+                payload.EVSEMaximumPowerLimit = value.encode();
+                payload.set_EVSEMaximumPowerLimit_isUsed(1);
+            }
+            Ok(CurrentDemandResponse::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(CurrentDemandResponse, CurrentDemandResXsd);
+}
@@ -17,6 +17,7 @@
  */
 use super::*;
 use std::mem;
+use strum_macros::{Display, EnumIter};
 
 pub struct DcEvChargeParam {
     payload: cglue::din_DC_EVChargeParameterType,
@@ -31,7 +32,7 @@ impl DcEvChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::din_DC_EVChargeParameterType>() };
 
-        if let Some(unit) = max_current.get_unit() {
+        if let Some(unit) = max_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -41,7 +42,7 @@ impl DcEvChargeParam {
             }
         }
 
-        if let Some(unit) = max_voltage.get_unit() {
+        if let Some(unit) = max_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -71,7 +72,7 @@ impl DcEvChargeParam {
 
     #[track_caller]
     pub fn set_max_power(&mut self, power_limit: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = power_limit.get_unit() {
+        if let Some(unit) = power_limit.get_unit()? {
             if unit != PhysicalUnit::Watt {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -98,7 +99,7 @@ impl DcEvChargeParam {
         &mut self,
         power_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = power_limit.get_unit() {
+        if let Some(unit) = power_limit.get_unit()? {
             if unit != PhysicalUnit::Wh {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -124,7 +125,7 @@ impl DcEvChargeParam {
         &mut self,
         power_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = power_limit.get_unit() {
+        if let Some(unit) = power_limit.get_unit()? {
             if unit != PhysicalUnit::Wh {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -197,7 +198,7 @@ impl AcEvChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::din_AC_EVChargeParameterType>() };
 
-        if let Some(unit) = max_current.get_unit() {
+        if let Some(unit) = max_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "ac-ev-charge-param",
@@ -207,7 +208,7 @@ impl AcEvChargeParam {
             }
         }
 
-        if let Some(unit) = min_current.get_unit() {
+        if let Some(unit) = min_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "ac-ev-charge-param",
@@ -217,7 +218,7 @@ impl AcEvChargeParam {
             }
         }
 
-        if let Some(unit) = max_voltage.get_unit() {
+        if let Some(unit) = max_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "ac-ev-charge-param",
@@ -291,7 +292,7 @@ impl ParamDiscoveryRequest {
         Self { payload }
     }
 
-    pub fn get_transfert_energy_mode(&self) -> EvRequestTransfertMode {
+    pub fn get_transfert_energy_mode(&self) -> Result<EvRequestTransfertMode, AfbError> {
         EvRequestTransfertMode::from_u32(self.payload.EVRequestedEnergyTransferType)
     }
 
@@ -309,7 +310,7 @@ impl ParamDiscoveryRequest {
             );
         }
 
-        match EvRequestTransfertMode::from_u32(self.payload.EVRequestedEnergyTransferType) {
+        match EvRequestTransfertMode::from_u32(self.payload.EVRequestedEnergyTransferType)? {
             EvRequestTransfertMode::AcSinglePhase => {}
             EvRequestTransfertMode::AcThreePhase => {}
             _ => {
@@ -345,7 +346,7 @@ impl ParamDiscoveryRequest {
                 "fail set_dc_charge_param because ac already set"
             );
         }
-        match EvRequestTransfertMode::from_u32(self.payload.EVRequestedEnergyTransferType) {
+        match EvRequestTransfertMode::from_u32(self.payload.EVRequestedEnergyTransferType)? {
             EvRequestTransfertMode::DcBasic => {}
             EvRequestTransfertMode::DcExtended => {}
             EvRequestTransfertMode::DcCombo => {}
@@ -600,15 +601,26 @@ impl PMaxSchedule {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumIter)]
+#[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum CostKind {
     PricePercent = cglue::din_costKindType_din_costKindType_relativePricePercentage,
     RenewGenPercent = cglue::din_costKindType_din_costKindType_RenewableGenerationPercentage,
     CarbonEmission = cglue::din_costKindType_din_costKindType_CarbonDioxideEmission,
 }
+crate::exi_enum_try_from!(CostKind, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(CostKind, {
+    PricePercent => "relativePricePercentage",
+    RenewGenPercent => "RenewableGenerationPercentage",
+    CarbonEmission => "CarbonDioxideEmission",
+});
+
 impl CostKind {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
 }
 
@@ -861,7 +873,7 @@ impl AcEvseChargeParam {
 
         payload.AC_EVSEStatus = status.encode();
 
-        if let Some(unit) = max_voltage.get_unit() {
+        if let Some(unit) = max_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "av-evse-charge-param",
@@ -872,7 +884,7 @@ impl AcEvseChargeParam {
         }
         payload.EVSEMaxVoltage = max_voltage.encode();
 
-        if let Some(unit) = max_current.get_unit() {
+        if let Some(unit) = max_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "av-evse-charge-param",
@@ -883,7 +895,7 @@ impl AcEvseChargeParam {
         }
         payload.EVSEMaxCurrent = max_current.encode();
 
-        if let Some(unit) = min_current.get_unit() {
+        if let Some(unit) = min_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "av-evse-charge-param",
@@ -938,17 +950,17 @@ impl DcEvseChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::din_DC_EVSEChargeParameterType>() };
 
-        if let Some(unit) = max_voltage.get_unit() {
+        if let Some(unit) = max_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!("dc-charge-param", "expect: PhysicalUnit::Volt get:{}", unit);
             }
         }
-        if let Some(unit) = min_voltage.get_unit() {
+        if let Some(unit) = min_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!("dc-charge-param", "expect: PhysicalUnit::Volt get:{}", unit);
             }
         }
-        if let Some(unit) = max_current.get_unit() {
+        if let Some(unit) = max_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "pre-charge-req",
@@ -957,7 +969,7 @@ impl DcEvseChargeParam {
                 );
             }
         }
-        if let Some(unit) = min_current.get_unit() {
+        if let Some(unit) = min_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "pre-charge-req",
@@ -967,7 +979,7 @@ impl DcEvseChargeParam {
             }
         }
 
-        if let Some(unit) = current_ripple.get_unit() {
+        if let Some(unit) = current_ripple.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!("pre-charge-req", "expect: PhysicalUnit::Volt get:{}", unit);
             }
@@ -1013,7 +1025,7 @@ impl DcEvseChargeParam {
     }
 
     pub fn set_max_power(&mut self, max_power: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = max_power.get_unit() {
+        if let Some(unit) = max_power.get_unit()? {
             if unit != PhysicalUnit::Watt {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -1031,7 +1043,7 @@ impl DcEvseChargeParam {
         &mut self,
         tolerance: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = tolerance.get_unit() {
+        if let Some(unit) = tolerance.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -1057,7 +1069,7 @@ impl DcEvseChargeParam {
     }
 
     pub fn set_energy_to_deliver(&mut self, energy: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if let Some(unit) = energy.get_unit() {
+        if let Some(unit) = energy.get_unit()? {
             if unit != PhysicalUnit::Wh {
                 return afb_error!(
                     "dc-ev-charge-param",
@@ -1103,10 +1115,19 @@ impl ParamDiscoveryResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
+    pub fn set_processing(&mut self, processing: EvseProcessing) -> &mut Self {
+        self.payload.EVSEProcessing = processing as u32;
+        self
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVSEProcessing)
+    }
+
     pub fn set_schedules(&mut self, unused: i32) -> &mut Self {
         self.payload.set_SASchedules_isUsed(1);
         self.payload.SASchedules._unused = unused;
@@ -1206,3 +1227,744 @@ impl ParamDiscoveryResponse {
         body
     }
 }
+
+impl ExiFields for DcEvChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVStatus", self.get_status())
+            .field("EVMaximumCurrentLimit", self.get_max_current())
+            .option("EVMaximumPowerLimit", self.get_max_power())
+            .field("EVMaximumVoltageLimit", self.get_max_voltage())
+            .option("EVEnergyCapacity", self.get_energy_capacity())
+            .option("EVEnergyRequest", self.get_energy_request())
+            .option("FullSOC", self.get_full_soc())
+            .option("BulkSOC", self.get_bulk_soc());
+    }
+}
+
+impl ExiFields for AcEvChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EAmount", self.get_ea_mount())
+            .field("EVMaxVoltage", self.get_max_voltage())
+            .field("EVMaxCurrent", self.get_max_current())
+            .field("EVMinCurrent", self.get_min_current());
+    }
+}
+
+// abstract EVChargeParameterType has no content
+impl ExiFields for EvChargeParam {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for ParamDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result(
+                "EVRequestedEnergyTransferType",
+                self.get_transfert_energy_mode(),
+            )
+            .option("EVChargeParameter", self.get_ev_charge_param())
+            .option("AC_EVChargeParameter", self.get_ac_charge_param())
+            .option("DC_EVChargeParameter", self.get_dc_charge_param());
+    }
+}
+
+impl ExiFields for SalesTariff {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .field("SalesTariffID", self.get_tariff_id())
+            .option("SalesTariffDescription", self.get_description())
+            .field("NumEPriceLevels", self.get_price_level())
+            .field("SalesTariffEntry", ExiList(self.get_entries()));
+    }
+}
+
+impl ExiFields for PMaxScheduleEntry {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.option("RelativeTimeInterval", self.get_relative_time_interval());
+        if self.payload.TimeInterval_isUsed() != 0 {
+            fields.field(
+                "TimeInterval",
+                TimeInterval::decode(self.payload.TimeInterval),
+            );
+        }
+        fields.field("PMax", self.get_pmax());
+    }
+}
+
+impl ExiFields for PMaxSchedule {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("PMaxScheduleID", self.get_id())
+            .field("PMaxScheduleEntry", ExiList(self.get_entries()));
+    }
+}
+
+impl ExiFields for CostType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("costKind", CostKind::from_u32(self.payload.costKind))
+            .field("amount", self.payload.amount)
+            .option("amountMultiplier", self.get_multiplier());
+    }
+}
+
+impl ExiFields for ConsumptionCost {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("startValue", self.payload.startValue)
+            .field("Cost", self.get_costs());
+    }
+}
+
+impl ExiFields for RelativeTimeInterval {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("start", self.get_start())
+            .option("duration", self.get_duration());
+    }
+}
+
+// abstract IntervalType has no content
+impl ExiFields for TimeInterval {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for SaleTariffEntry {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("RelativeTimeInterval", self.get_relative_time())
+            .option("TimeInterval", self.get_time())
+            .field("EPriceLevel", self.get_price_level())
+            .field(
+                "ConsumptionCost",
+                ConsumptionCost::decode(self.payload.ConsumptionCost),
+            );
+    }
+}
+
+impl ExiFields for SasScheduleTuple {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("SAScheduleTupleID", self.get_id())
+            .field("PMaxSchedule", self.get_pmax_schedule())
+            .option("SalesTariff", self.get_tariff());
+    }
+}
+
+impl ExiFields for AcEvseChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("AC_EVSEStatus", self.get_status())
+            .field("EVSEMaxVoltage", self.get_maximum_voltage())
+            .field("EVSEMaxCurrent", self.get_max_current())
+            .field("EVSEMinCurrent", self.get_min_current());
+    }
+}
+
+impl ExiFields for DcEvseChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEMaximumCurrentLimit", self.get_max_current())
+            .option("EVSEMaximumPowerLimit", self.get_max_power())
+            .field("EVSEMaximumVoltageLimit", self.get_max_voltage())
+            .field("EVSEMinimumCurrentLimit", self.get_min_current())
+            .field("EVSEMinimumVoltageLimit", self.get_min_voltage())
+            .option("EVSECurrentRegulationTolerance", self.get_regul_tolerance())
+            .field("EVSEPeakCurrentRipple", self.get_peak_current_ripple())
+            .option("EVSEEnergyToBeDelivered", self.get_energy_to_deliver());
+    }
+}
+
+impl ExiFields for ParamDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing())
+            .option("SASchedules", self.get_schedules());
+        if self.payload.SAScheduleList_isUsed() != 0 {
+            fields.field("SAScheduleList", ExiList(self.get_schedule_tuples()));
+        }
+        fields
+            .option("EVSEChargeParameter", self.get_evse_charge_param())
+            .option("AC_EVSEChargeParameter", self.get_evse_ac_charge_param())
+            .option("DC_EVSEChargeParameter", self.get_evse_dc_charge_param());
+    }
+}
+crate::exi_format!(
+    DcEvChargeParam,
+    AcEvChargeParam,
+    EvChargeParam,
+    ParamDiscoveryRequest,
+    SalesTariff,
+    PMaxScheduleEntry,
+    PMaxSchedule,
+    CostType,
+    ConsumptionCost,
+    RelativeTimeInterval,
+    TimeInterval,
+    SaleTariffEntry,
+    SasScheduleTuple,
+    AcEvseChargeParam,
+    DcEvseChargeParam,
+    ParamDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    // abstract/empty XSD elements (IntervalType, SASchedulesType, ...) only carry their presence
+    #[derive(Serialize, Deserialize)]
+    struct AbstractXsd {}
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvChargeParamXsd {
+        DC_EVStatus: DcEvStatusType,
+        EVMaximumCurrentLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumPowerLimit: Option<PhysicalValue>,
+        EVMaximumVoltageLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVEnergyCapacity: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVEnergyRequest: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        FullSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BulkSOC: Option<i8>,
+    }
+
+    impl DcEvChargeParamXsd {
+        fn from_exi(exi: &DcEvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+                EVMaximumCurrentLimit: exi.get_max_current(),
+                EVMaximumPowerLimit: exi.get_max_power(),
+                EVMaximumVoltageLimit: exi.get_max_voltage(),
+                EVEnergyCapacity: exi.get_energy_capacity(),
+                EVEnergyRequest: exi.get_energy_request(),
+                FullSOC: exi.get_full_soc(),
+                BulkSOC: exi.get_bulk_soc(),
+            })
+        }
+
+        // fill payload directly, decoded messages are not always compliant with setter checks
+        fn into_exi(self) -> Result<DcEvChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_DC_EVChargeParameterType>() };
+            payload.DC_EVStatus = self.DC_EVStatus.encode();
+            payload.EVMaximumCurrentLimit = self.EVMaximumCurrentLimit.encode();
+            payload.EVMaximumVoltageLimit = self.EVMaximumVoltageLimit.encode();
+            if let Some(value) = &self.EVMaximumPowerLimit {
+                payload.EVMaximumPowerLimit = value.encode();
+                payload.set_EVMaximumPowerLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVEnergyCapacity {
+                payload.EVEnergyCapacity = value.encode();
+                payload.set_EVEnergyCapacity_isUsed(1);
+            }
+            if let Some(value) = &self.EVEnergyRequest {
+                payload.EVEnergyRequest = value.encode();
+                payload.set_EVEnergyRequest_isUsed(1);
+            }
+
+            let mut exi = DcEvChargeParam::decode(payload);
+            if let Some(value) = self.FullSOC {
+                exi.set_full_soc(value);
+            }
+            if let Some(value) = self.BulkSOC {
+                exi.set_bulk_soc(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvChargeParam, DcEvChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvChargeParamXsd {
+        EAmount: PhysicalValue,
+        EVMaxVoltage: PhysicalValue,
+        EVMaxCurrent: PhysicalValue,
+        EVMinCurrent: PhysicalValue,
+    }
+
+    impl AcEvChargeParamXsd {
+        fn from_exi(exi: &AcEvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                EAmount: exi.get_ea_mount(),
+                EVMaxVoltage: exi.get_max_voltage(),
+                EVMaxCurrent: exi.get_max_current(),
+                EVMinCurrent: exi.get_min_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_AC_EVChargeParameterType>() };
+            payload.EAmount = self.EAmount.encode();
+            payload.EVMaxVoltage = self.EVMaxVoltage.encode();
+            payload.EVMaxCurrent = self.EVMaxCurrent.encode();
+            payload.EVMinCurrent = self.EVMinCurrent.encode();
+            Ok(AcEvChargeParam::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(AcEvChargeParam, AcEvChargeParamXsd);
+
+    impl AbstractXsd {
+        fn from_exi(_exi: &EvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<EvChargeParam, AfbError> {
+            Ok(EvChargeParam::new(0))
+        }
+    }
+    crate::exi_serde_mirror!(EvChargeParam, AbstractXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargeParameterDiscoveryReqXsd {
+        EVRequestedEnergyTransferType: EvRequestTransfertMode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVChargeParameter: Option<EvChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVChargeParameter: Option<AcEvChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVChargeParameter: Option<DcEvChargeParam>,
+    }
+
+    impl ChargeParameterDiscoveryReqXsd {
+        fn from_exi(exi: &ParamDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVRequestedEnergyTransferType: exi.get_transfert_energy_mode()?,
+                EVChargeParameter: exi.get_ev_charge_param(),
+                AC_EVChargeParameter: exi.get_ac_charge_param(),
+                DC_EVChargeParameter: exi.get_dc_charge_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamDiscoveryRequest, AfbError> {
+            let mut exi = ParamDiscoveryRequest::new(self.EVRequestedEnergyTransferType);
+            if let Some(value) = &self.EVChargeParameter {
+                exi.payload.EVChargeParameter = value.encode();
+                exi.payload.set_EVChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.AC_EVChargeParameter {
+                exi.payload.AC_EVChargeParameter = value.encode();
+                exi.payload.set_AC_EVChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.DC_EVChargeParameter {
+                exi.payload.DC_EVChargeParameter = value.encode();
+                exi.payload.set_DC_EVChargeParameter_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ParamDiscoveryRequest, ChargeParameterDiscoveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct RelativeTimeIntervalXsd {
+        start: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration: Option<u32>,
+    }
+
+    impl RelativeTimeIntervalXsd {
+        fn from_exi(exi: &RelativeTimeInterval) -> Result<Self, AfbError> {
+            Ok(Self {
+                start: exi.get_start(),
+                duration: exi.get_duration(),
+            })
+        }
+
+        fn into_exi(self) -> Result<RelativeTimeInterval, AfbError> {
+            let mut exi = RelativeTimeInterval::new(self.start);
+            if let Some(value) = self.duration {
+                exi.set_duration(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(RelativeTimeInterval, RelativeTimeIntervalXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct TimeIntervalXsd {}
+
+    impl TimeIntervalXsd {
+        fn from_exi(_exi: &TimeInterval) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<TimeInterval, AfbError> {
+            Ok(TimeInterval::new(0))
+        }
+    }
+    crate::exi_serde_mirror!(TimeInterval, TimeIntervalXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PMaxScheduleEntryXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RelativeTimeInterval: Option<RelativeTimeInterval>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TimeInterval: Option<AbstractXsd>,
+        PMax: i16,
+    }
+
+    impl PMaxScheduleEntryXsd {
+        fn from_exi(exi: &PMaxScheduleEntry) -> Result<Self, AfbError> {
+            Ok(Self {
+                RelativeTimeInterval: exi.get_relative_time_interval(),
+                TimeInterval: exi.get_time_interval().map(|_| AbstractXsd {}),
+                PMax: exi.get_pmax(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PMaxScheduleEntry, AfbError> {
+            let mut exi = PMaxScheduleEntry::new(self.PMax);
+            if let Some(value) = &self.RelativeTimeInterval {
+                exi.set_relative_time_interval(value);
+            }
+            if self.TimeInterval.is_some() {
+                exi.set_time_interval(0);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PMaxScheduleEntry, PMaxScheduleEntryXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PMaxScheduleXsd {
+        PMaxScheduleID: i16,
+        PMaxScheduleEntry: Vec<PMaxScheduleEntry>,
+    }
+
+    impl PMaxScheduleXsd {
+        fn from_exi(exi: &PMaxSchedule) -> Result<Self, AfbError> {
+            Ok(Self {
+                PMaxScheduleID: exi.get_id(),
+                PMaxScheduleEntry: exi.get_entries(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PMaxSchedule, AfbError> {
+            let mut exi = PMaxSchedule::new(self.PMaxScheduleID);
+            for entry in &self.PMaxScheduleEntry {
+                exi.add_entry(entry)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PMaxSchedule, PMaxScheduleXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CostXsd {
+        costKind: CostKind,
+        amount: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        amountMultiplier: Option<i8>,
+    }
+
+    impl CostXsd {
+        fn from_exi(exi: &CostType) -> Result<Self, AfbError> {
+            Ok(Self {
+                costKind: CostKind::from_u32(exi.payload.costKind)?,
+                amount: exi.payload.amount,
+                amountMultiplier: exi.get_multiplier(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CostType, AfbError> {
+            let mut exi = CostType::new(self.costKind, self.amount);
+            if let Some(value) = self.amountMultiplier {
+                exi.set_multiplier(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CostType, CostXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ConsumptionCostXsd {
+        startValue: u32,
+        Cost: CostType,
+    }
+
+    impl ConsumptionCostXsd {
+        fn from_exi(exi: &ConsumptionCost) -> Result<Self, AfbError> {
+            Ok(Self {
+                startValue: exi.payload.startValue,
+                Cost: exi.get_costs(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ConsumptionCost, AfbError> {
+            let mut exi = ConsumptionCost::new(self.startValue);
+            exi.set_cost(&self.Cost);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ConsumptionCost, ConsumptionCostXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SalesTariffEntryXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RelativeTimeInterval: Option<RelativeTimeInterval>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TimeInterval: Option<TimeInterval>,
+        EPriceLevel: u8,
+        ConsumptionCost: ConsumptionCost,
+    }
+
+    impl SalesTariffEntryXsd {
+        fn from_exi(exi: &SaleTariffEntry) -> Result<Self, AfbError> {
+            Ok(Self {
+                RelativeTimeInterval: exi.get_relative_time(),
+                TimeInterval: exi.get_time(),
+                EPriceLevel: exi.get_price_level(),
+                ConsumptionCost: ConsumptionCost::decode(exi.payload.ConsumptionCost),
+            })
+        }
+
+        fn into_exi(self) -> Result<SaleTariffEntry, AfbError> {
+            let mut exi = SaleTariffEntry::new(self.EPriceLevel);
+            if let Some(value) = &self.RelativeTimeInterval {
+                exi.set_relative_time(value);
+            }
+            if let Some(value) = &self.TimeInterval {
+                exi.set_time(value);
+            }
+            exi.set_comsumption_cost(self.ConsumptionCost)?;
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SaleTariffEntry, SalesTariffEntryXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SalesTariffXsd {
+        Id: String,
+        SalesTariffID: i16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SalesTariffDescription: Option<String>,
+        NumEPriceLevels: u8,
+        SalesTariffEntry: Vec<SaleTariffEntry>,
+    }
+
+    impl SalesTariffXsd {
+        fn from_exi(exi: &SalesTariff) -> Result<Self, AfbError> {
+            let payload = &exi.payload;
+            let description = if payload.SalesTariffDescription_isUsed() == 0 {
+                None
+            } else {
+                Some(
+                    array_to_str(
+                        &payload.SalesTariffDescription.characters,
+                        payload.SalesTariffDescription.charactersLen,
+                    )?
+                    .to_string(),
+                )
+            };
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                SalesTariffID: exi.get_tariff_id(),
+                SalesTariffDescription: description,
+                NumEPriceLevels: exi.get_price_level(),
+                SalesTariffEntry: exi.get_entries(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SalesTariff, AfbError> {
+            let mut exi = SalesTariff::new(&self.Id, self.SalesTariffID, self.NumEPriceLevels)?;
+            if let Some(value) = &self.SalesTariffDescription {
+                exi.set_description(value)?;
+                exi.payload.set_SalesTariffDescription_isUsed(1);
+            }
+            for entry in &self.SalesTariffEntry {
+                exi.add_entry(entry)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SalesTariff, SalesTariffXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SAScheduleTupleXsd {
+        SAScheduleTupleID: i16,
+        PMaxSchedule: PMaxSchedule,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SalesTariff: Option<SalesTariff>,
+    }
+
+    impl SAScheduleTupleXsd {
+        fn from_exi(exi: &SasScheduleTuple) -> Result<Self, AfbError> {
+            Ok(Self {
+                SAScheduleTupleID: exi.get_id(),
+                PMaxSchedule: exi.get_pmax_schedule(),
+                SalesTariff: exi.get_tariff(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SasScheduleTuple, AfbError> {
+            let mut exi = SasScheduleTuple::new(self.SAScheduleTupleID, &self.PMaxSchedule);
+            if let Some(value) = &self.SalesTariff {
+                exi.set_tariff(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SasScheduleTuple, SAScheduleTupleXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvseChargeParamXsd {
+        AC_EVSEStatus: AcEvseStatusType,
+        EVSEMaxVoltage: PhysicalValue,
+        EVSEMaxCurrent: PhysicalValue,
+        EVSEMinCurrent: PhysicalValue,
+    }
+
+    impl AcEvseChargeParamXsd {
+        fn from_exi(exi: &AcEvseChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                AC_EVSEStatus: exi.get_status(),
+                EVSEMaxVoltage: exi.get_maximum_voltage(),
+                EVSEMaxCurrent: exi.get_max_current(),
+                EVSEMinCurrent: exi.get_min_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvseChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_AC_EVSEChargeParameterType>() };
+            payload.AC_EVSEStatus = self.AC_EVSEStatus.encode();
+            payload.EVSEMaxVoltage = self.EVSEMaxVoltage.encode();
+            payload.EVSEMaxCurrent = self.EVSEMaxCurrent.encode();
+            payload.EVSEMinCurrent = self.EVSEMinCurrent.encode();
+            Ok(AcEvseChargeParam::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(AcEvseChargeParam, AcEvseChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvseChargeParamXsd {
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEMaximumCurrentLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumPowerLimit: Option<PhysicalValue>,
+        EVSEMaximumVoltageLimit: PhysicalValue,
+        EVSEMinimumCurrentLimit: PhysicalValue,
+        EVSEMinimumVoltageLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSECurrentRegulationTolerance: Option<PhysicalValue>,
+        EVSEPeakCurrentRipple: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEEnergyToBeDelivered: Option<PhysicalValue>,
+    }
+
+    impl DcEvseChargeParamXsd {
+        fn from_exi(exi: &DcEvseChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVSEStatus: exi.get_status(),
+                EVSEMaximumCurrentLimit: exi.get_max_current(),
+                EVSEMaximumPowerLimit: exi.get_max_power(),
+                EVSEMaximumVoltageLimit: exi.get_max_voltage(),
+                EVSEMinimumCurrentLimit: exi.get_min_current(),
+                EVSEMinimumVoltageLimit: exi.get_min_voltage(),
+                EVSECurrentRegulationTolerance: exi.get_regul_tolerance(),
+                EVSEPeakCurrentRipple: exi.get_peak_current_ripple(),
+                EVSEEnergyToBeDelivered: exi.get_energy_to_deliver(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvseChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_DC_EVSEChargeParameterType>() };
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEMaximumCurrentLimit = self.EVSEMaximumCurrentLimit.encode();
+            payload.EVSEMaximumVoltageLimit = self.EVSEMaximumVoltageLimit.encode();
+            payload.EVSEMinimumCurrentLimit = self.EVSEMinimumCurrentLimit.encode();
+            payload.EVSEMinimumVoltageLimit = self.EVSEMinimumVoltageLimit.encode();
+            payload.EVSEPeakCurrentRipple = self.EVSEPeakCurrentRipple.encode();
+            if let Some(value) = &self.EVSEMaximumPowerLimit {
+                payload.EVSEMaximumPowerLimit = value.encode();
+                payload.set_EVSEMaximumPowerLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVSECurrentRegulationTolerance {
+                payload.EVSECurrentRegulationTolerance = value.encode();
+                payload.set_EVSECurrentRegulationTolerance_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEEnergyToBeDelivered {
+                payload.EVSEEnergyToBeDelivered = value.encode();
+                payload.set_EVSEEnergyToBeDelivered_isUsed(1);
+            }
+            Ok(DcEvseChargeParam::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(DcEvseChargeParam, DcEvseChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SAScheduleListXsd {
+        SAScheduleTuple: Vec<SasScheduleTuple>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargeParameterDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SASchedules: Option<AbstractXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SAScheduleList: Option<SAScheduleListXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEChargeParameter: Option<AbstractXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVSEChargeParameter: Option<AcEvseChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVSEChargeParameter: Option<DcEvseChargeParam>,
+    }
+
+    impl ChargeParameterDiscoveryResXsd {
+        fn from_exi(exi: &ParamDiscoveryResponse) -> Result<Self, AfbError> {
+            let schedules = if exi.payload.SAScheduleList_isUsed() == 0 {
+                None
+            } else {
+                Some(SAScheduleListXsd {
+                    SAScheduleTuple: exi.get_schedule_tuples(),
+                })
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+                SASchedules: exi.get_schedules().map(|_| AbstractXsd {}),
+                SAScheduleList: schedules,
+                EVSEChargeParameter: exi.get_evse_charge_param().map(|_| AbstractXsd {}),
+                AC_EVSEChargeParameter: exi.get_evse_ac_charge_param(),
+                DC_EVSEChargeParameter: exi.get_evse_dc_charge_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamDiscoveryResponse, AfbError> {
+            let mut exi = ParamDiscoveryResponse::new(self.ResponseCode);
+            exi.set_processing(self.EVSEProcessing);
+            if self.SASchedules.is_some() {
+                exi.payload.set_SASchedules_isUsed(1);
+            }
+            if let Some(schedules) = &self.SAScheduleList {
+                for tuple in &schedules.SAScheduleTuple {
+                    exi.add_schedule_tuple(tuple)?;
+                }
+                exi.payload.set_SAScheduleList_isUsed(1);
+            }
+            if self.EVSEChargeParameter.is_some() {
+                exi.payload.set_EVSEChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.AC_EVSEChargeParameter {
+                exi.set_evse_ac_charge_param(value);
+            }
+            if let Some(value) = &self.DC_EVSEChargeParameter {
+                exi.set_evse_dc_charge_param(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ParamDiscoveryResponse, ChargeParameterDiscoveryResXsd);
+}
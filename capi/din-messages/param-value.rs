@@ -16,6 +16,7 @@
  *
  */
 use super::*;
+use std::cmp::Ordering;
 use std::fmt;
 use std::mem;
 
@@ -27,9 +28,10 @@ impl fmt::Debug for PhysicalValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value = self.get_value();
         let multiplier = self.get_multiplier();
-        let unit: PhysicalUnit = match self.get_unit() {
-            None => PhysicalUnit::Unset,
-            Some(value) => value
+        let unit = match self.get_unit() {
+            Ok(None) => PhysicalUnit::Unset.to_string(),
+            Ok(Some(value)) => value.to_string(),
+            Err(_) => format!("invalid({})", self.payload.Unit),
         };
         write!(
             f,
@@ -39,6 +41,23 @@ impl fmt::Debug for PhysicalValue {
     }
 }
 
+// scaled value with its unit symbol, ex: (value:4000, multiplier:-1 unit:volt) => 400 V
+impl fmt::Display for PhysicalValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = scaled_to_string(self.get_value(), self.get_multiplier());
+        let unit = match self.get_unit() {
+            Ok(None) => PhysicalUnit::Unset.to_symbol().to_string(),
+            Ok(Some(unit)) => unit.to_symbol().to_string(),
+            Err(_) => format!("invalid({})", self.payload.Unit),
+        };
+        if unit.is_empty() {
+            write!(f, "{}", value)
+        } else {
+            write!(f, "{} {}", value, unit)
+        }
+    }
+}
+
 impl PhysicalValue {
     pub fn new(value: i16, multiplier: i8, unit: PhysicalUnit) -> Self {
         let mut payload = unsafe { mem::zeroed::<cglue::din_PhysicalValueType>() };
@@ -53,11 +72,11 @@ impl PhysicalValue {
         Self { payload }
     }
 
-    pub fn get_unit(&self) -> Option<PhysicalUnit> {
+    pub fn get_unit(&self) -> Result<Option<PhysicalUnit>, AfbError> {
         if self.payload.Unit_isUsed() == 0 {
-            None
+            Ok(None)
         } else {
-            Some(PhysicalUnit::from_u32(self.payload.Unit))
+            Ok(Some(PhysicalUnit::from_u32(self.payload.Unit)?))
         }
     }
 
@@ -69,6 +88,70 @@ impl PhysicalValue {
         self.payload.Value
     }
 
+    /// Best precision value/multiplier (-3..3) for a float, ex: 400.5 => (4005,-1)
+    #[track_caller]
+    pub fn from_f64(value: f64, unit: PhysicalUnit) -> Result<Self, AfbError> {
+        let (value, multiplier) = scaled_from_f64(value)?;
+        Ok(Self::new(value, multiplier, unit))
+    }
+
+    /// Same as from_f64 without float rounding, ex: "400.5" => (4005,-1)
+    #[track_caller]
+    pub fn from_decimal(text: &str, unit: PhysicalUnit) -> Result<Self, AfbError> {
+        let (value, multiplier) = scaled_from_decimal(text)?;
+        Ok(Self::new(value, multiplier, unit))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        scaled_to_f64(self.get_value(), self.get_multiplier())
+    }
+
+    pub fn to_decimal(&self) -> String {
+        scaled_to_string(self.get_value(), self.get_multiplier())
+    }
+
+    fn get_scaled(&self) -> (i16, i8) {
+        (self.get_value(), self.get_multiplier())
+    }
+
+    // arithmetic & comparison only make sense between identical units (unset included)
+    #[track_caller]
+    fn check_unit(&self, other: &Self) -> Result<PhysicalUnit, AfbError> {
+        let unit = self.get_unit()?.unwrap_or(PhysicalUnit::Unset);
+        let other_unit = other.get_unit()?.unwrap_or(PhysicalUnit::Unset);
+        if unit != other_unit {
+            return afb_error!(
+                "physical-value-unit",
+                "unit mismatch:{} {}",
+                unit,
+                other_unit
+            );
+        }
+        Ok(unit)
+    }
+
+    /// Sum of values with identical units, multiplier is selected for best precision
+    #[track_caller]
+    pub fn checked_add(&self, other: &Self) -> Result<Self, AfbError> {
+        let unit = self.check_unit(other)?;
+        let (value, multiplier) = scaled_add(self.get_scaled(), other.get_scaled())?;
+        Ok(Self::new(value, multiplier, unit))
+    }
+
+    #[track_caller]
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, AfbError> {
+        let unit = self.check_unit(other)?;
+        let (value, multiplier) = scaled_sub(self.get_scaled(), other.get_scaled())?;
+        Ok(Self::new(value, multiplier, unit))
+    }
+
+    /// Compare values with different multipliers, fails when units differ
+    #[track_caller]
+    pub fn checked_cmp(&self, other: &Self) -> Result<Ordering, AfbError> {
+        self.check_unit(other)?;
+        Ok(scaled_cmp(self.get_scaled(), other.get_scaled()))
+    }
+
     pub fn decode(payload: cglue::din_PhysicalValueType) -> Self {
         Self {
             payload: payload.clone(),
@@ -80,6 +163,20 @@ impl PhysicalValue {
     }
 }
 
+// equal when scaled values match, ex: (4000,-1 V) == (400,0 V)
+impl PartialEq for PhysicalValue {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.checked_cmp(other), Ok(Ordering::Equal))
+    }
+}
+
+// values with different units are not comparable
+impl PartialOrd for PhysicalValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.checked_cmp(other).ok()
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum ParamValue {
     Bool(bool),
@@ -175,7 +272,7 @@ impl ParamTuple {
             let phys_value = PhysicalValue::new(
                 self.payload.physicalValue.Value,
                 self.payload.physicalValue.Multiplier,
-                PhysicalUnit::from_u32(self.payload.physicalValue.Unit),
+                PhysicalUnit::from_u32(self.payload.physicalValue.Unit)?,
             );
             ParamValue::PhyValue(phys_value)
         } else {
@@ -223,3 +320,202 @@ impl ParamSet {
         self.payload
     }
 }
+
+impl ExiFields for ParamTuple {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        let payload = &self.payload;
+        fields.result("Name", self.get_name());
+        if payload.boolValue_isUsed() != 0 {
+            fields.field("boolValue", payload.boolValue != 0);
+        }
+        if payload.byteValue_isUsed() != 0 {
+            fields.field("byteValue", payload.byteValue);
+        }
+        if payload.shortValue_isUsed() != 0 {
+            fields.field("shortValue", payload.shortValue);
+        }
+        if payload.intValue_isUsed() != 0 {
+            fields.field("intValue", payload.intValue);
+        }
+        if payload.physicalValue_isUsed() != 0 {
+            fields.field(
+                "physicalValue",
+                PhysicalValue::decode(payload.physicalValue),
+            );
+        }
+        if payload.stringValue_isUsed() != 0 {
+            fields.result(
+                "stringValue",
+                array_to_str(
+                    &payload.stringValue.characters,
+                    payload.stringValue.charactersLen,
+                ),
+            );
+        }
+    }
+}
+
+impl ExiFields for ParamSet {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ParameterSetID", self.get_id())
+            .field("Parameter", self.get_param());
+    }
+}
+crate::exi_format!(ParamTuple, ParamSet);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct PhysicalValueXsd {
+        Multiplier: i8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Unit: Option<PhysicalUnit>,
+        Value: i16,
+    }
+
+    impl PhysicalValueXsd {
+        fn from_exi(exi: &PhysicalValue) -> Result<Self, AfbError> {
+            Ok(Self {
+                Multiplier: exi.get_multiplier(),
+                Unit: exi.get_unit()?,
+                Value: exi.get_value(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PhysicalValue, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_PhysicalValueType>() };
+            payload.Multiplier = self.Multiplier;
+            payload.Value = self.Value;
+            match self.Unit {
+                None | Some(PhysicalUnit::Unset) => {}
+                Some(unit) => {
+                    payload.Unit = unit as u32;
+                    payload.set_Unit_isUsed(1);
+                }
+            }
+            Ok(PhysicalValue::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(PhysicalValue, PhysicalValueXsd);
+
+    // ParameterType value is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct ParameterXsd {
+        Name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        boolValue: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        byteValue: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        shortValue: Option<i16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        intValue: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        physicalValue: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        stringValue: Option<String>,
+    }
+
+    impl ParameterXsd {
+        fn from_exi(exi: &ParamTuple) -> Result<Self, AfbError> {
+            let payload = &exi.payload;
+            Ok(Self {
+                Name: exi.get_name()?.to_string(),
+                boolValue: match payload.boolValue_isUsed() {
+                    0 => None,
+                    _ => Some(payload.boolValue != 0),
+                },
+                byteValue: match payload.byteValue_isUsed() {
+                    0 => None,
+                    _ => Some(payload.byteValue),
+                },
+                shortValue: match payload.shortValue_isUsed() {
+                    0 => None,
+                    _ => Some(payload.shortValue),
+                },
+                intValue: match payload.intValue_isUsed() {
+                    0 => None,
+                    _ => Some(payload.intValue),
+                },
+                physicalValue: match payload.physicalValue_isUsed() {
+                    0 => None,
+                    _ => Some(PhysicalValue::decode(payload.physicalValue)),
+                },
+                stringValue: match payload.stringValue_isUsed() {
+                    0 => None,
+                    _ => Some(
+                        array_to_str(
+                            &payload.stringValue.characters,
+                            payload.stringValue.charactersLen,
+                        )?
+                        .to_string(),
+                    ),
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamTuple, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_ParameterType>() };
+            payload.Name.charactersLen = str_to_array(
+                &self.Name,
+                &mut payload.Name.characters,
+                cglue::din_Name_CHARACTER_SIZE,
+            )?;
+            if let Some(value) = self.boolValue {
+                payload.boolValue = if value { 1 } else { 0 };
+                payload.set_boolValue_isUsed(1);
+            }
+            if let Some(value) = self.byteValue {
+                payload.byteValue = value;
+                payload.set_byteValue_isUsed(1);
+            }
+            if let Some(value) = self.shortValue {
+                payload.shortValue = value;
+                payload.set_shortValue_isUsed(1);
+            }
+            if let Some(value) = self.intValue {
+                payload.intValue = value;
+                payload.set_intValue_isUsed(1);
+            }
+            if let Some(value) = &self.physicalValue {
+                payload.physicalValue = value.encode();
+                payload.set_physicalValue_isUsed(1);
+            }
+            if let Some(value) = &self.stringValue {
+                payload.stringValue.charactersLen = str_to_array(
+                    value,
+                    &mut payload.stringValue.characters,
+                    cglue::din_stringValue_CHARACTER_SIZE,
+                )?;
+                payload.set_stringValue_isUsed(1);
+            }
+            Ok(ParamTuple::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(ParamTuple, ParameterXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ParameterSetXsd {
+        ParameterSetID: i16,
+        Parameter: ParamTuple,
+    }
+
+    impl ParameterSetXsd {
+        fn from_exi(exi: &ParamSet) -> Result<Self, AfbError> {
+            Ok(Self {
+                ParameterSetID: exi.get_id(),
+                Parameter: exi.get_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamSet, AfbError> {
+            Ok(ParamSet::new(self.ParameterSetID, &self.Parameter))
+        }
+    }
+    crate::exi_serde_mirror!(ParamSet, ParameterSetXsd);
+}
@@ -88,7 +88,7 @@ impl PaymentDetailsResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -124,3 +124,72 @@ impl PaymentDetailsResponse {
     }
 
 }
+
+impl ExiFields for PaymentDetailsRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ContractID", self.get_contract_id())
+            .field("ContractSignatureCertChain", self.get_contract_chain());
+    }
+}
+
+impl ExiFields for PaymentDetailsResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("GenChallenge", self.get_challenge())
+            .field("DateTimeNow", self.get_time_stamp());
+    }
+}
+crate::exi_format!(PaymentDetailsRequest, PaymentDetailsResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentDetailsReqXsd {
+        ContractID: String,
+        ContractSignatureCertChain: CertificateChainType,
+    }
+
+    impl PaymentDetailsReqXsd {
+        fn from_exi(exi: &PaymentDetailsRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ContractID: exi.get_contract_id()?.to_string(),
+                ContractSignatureCertChain: exi.get_contract_chain(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentDetailsRequest, AfbError> {
+            PaymentDetailsRequest::new(&self.ContractID, &self.ContractSignatureCertChain)
+        }
+    }
+    crate::exi_serde_mirror!(PaymentDetailsRequest, PaymentDetailsReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentDetailsResXsd {
+        ResponseCode: ResponseCode,
+        GenChallenge: String,
+        DateTimeNow: i64,
+    }
+
+    impl PaymentDetailsResXsd {
+        fn from_exi(exi: &PaymentDetailsResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                GenChallenge: exi.get_challenge()?.to_string(),
+                DateTimeNow: exi.get_time_stamp(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentDetailsResponse, AfbError> {
+            let mut exi = PaymentDetailsResponse::new(self.ResponseCode, &self.GenChallenge)?;
+            exi.set_timestamp(self.DateTimeNow);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PaymentDetailsResponse, PaymentDetailsResXsd);
+}
@@ -231,7 +231,7 @@ impl PowerDeliveryResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -283,3 +283,194 @@ impl PowerDeliveryResponse {
         body
     }
 }
+
+impl ExiFields for ChargingProfileEntry {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ChargingProfileEntryStart", self.get_start())
+            .field("ChargingProfileEntryMaxPower", self.get_power_max());
+    }
+}
+
+impl ExiFields for DcEvPowerDeliveryParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVStatus", self.get_status())
+            .option("BulkChargingComplete", self.get_bulk_complete())
+            .field("ChargingComplete", self.get_charge_complete());
+    }
+}
+
+impl ExiFields for PowerDeliveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ReadyToChargeState", self.get_ready())
+            .option("SAScheduleTupleID", self.get_schedule_id());
+        if self.payload.ChargingProfile_isUsed() != 0 {
+            fields.field("ChargingProfile", ExiList(self.get_charging_profiles()));
+        }
+        fields
+            .option("EVPowerDeliveryParameter", self.get_ev_delivery_params())
+            .option("DC_EVPowerDeliveryParameter", self.get_dc_delivery_params());
+    }
+}
+
+impl ExiFields for PowerDeliveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("AC_EVSEStatus", self.get_ac_evse_status())
+            .option("DC_EVSEStatus", self.get_dc_evse_status());
+    }
+}
+crate::exi_format!(
+    ChargingProfileEntry,
+    DcEvPowerDeliveryParam,
+    PowerDeliveryRequest,
+    PowerDeliveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ProfileEntryXsd {
+        ChargingProfileEntryStart: u32,
+        ChargingProfileEntryMaxPower: i16,
+    }
+
+    impl ProfileEntryXsd {
+        fn from_exi(exi: &ChargingProfileEntry) -> Result<Self, AfbError> {
+            Ok(Self {
+                ChargingProfileEntryStart: exi.get_start(),
+                ChargingProfileEntryMaxPower: exi.get_power_max(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ChargingProfileEntry, AfbError> {
+            Ok(ChargingProfileEntry::new(
+                self.ChargingProfileEntryStart,
+                self.ChargingProfileEntryMaxPower,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(ChargingProfileEntry, ProfileEntryXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvPowerDeliveryParamXsd {
+        DC_EVStatus: DcEvStatusType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BulkChargingComplete: Option<bool>,
+        ChargingComplete: bool,
+    }
+
+    impl DcEvPowerDeliveryParamXsd {
+        fn from_exi(exi: &DcEvPowerDeliveryParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+                BulkChargingComplete: exi.get_bulk_complete(),
+                ChargingComplete: exi.get_charge_complete(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvPowerDeliveryParam, AfbError> {
+            let mut exi = DcEvPowerDeliveryParam::new(&self.DC_EVStatus, self.ChargingComplete);
+            if let Some(value) = self.BulkChargingComplete {
+                exi.set_bulk_complete(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvPowerDeliveryParam, DcEvPowerDeliveryParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargingProfileXsd {
+        SAScheduleTupleID: i16,
+        ProfileEntry: Vec<ChargingProfileEntry>,
+    }
+
+    // abstract EVPowerDeliveryParameterType only carries its presence
+    #[derive(Serialize, Deserialize)]
+    struct AbstractXsd {}
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerDeliveryReqXsd {
+        ReadyToChargeState: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ChargingProfile: Option<ChargingProfileXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPowerDeliveryParameter: Option<AbstractXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVPowerDeliveryParameter: Option<DcEvPowerDeliveryParam>,
+    }
+
+    impl PowerDeliveryReqXsd {
+        fn from_exi(exi: &PowerDeliveryRequest) -> Result<Self, AfbError> {
+            let profile = match exi.get_schedule_id() {
+                None => None,
+                Some(schedule_id) => Some(ChargingProfileXsd {
+                    SAScheduleTupleID: schedule_id,
+                    ProfileEntry: exi.get_charging_profiles(),
+                }),
+            };
+            Ok(Self {
+                ReadyToChargeState: exi.get_ready(),
+                ChargingProfile: profile,
+                EVPowerDeliveryParameter: exi.get_ev_delivery_params().map(|_| AbstractXsd {}),
+                DC_EVPowerDeliveryParameter: exi.get_dc_delivery_params(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PowerDeliveryRequest, AfbError> {
+            let mut exi = PowerDeliveryRequest::new(self.ReadyToChargeState);
+            if let Some(profile) = &self.ChargingProfile {
+                exi.set_schedule_id(profile.SAScheduleTupleID);
+                for entry in &profile.ProfileEntry {
+                    exi.add_charging_profile(entry)?;
+                }
+            }
+            if self.EVPowerDeliveryParameter.is_some() {
+                exi.set_ev_delivery_params(0);
+            }
+            if let Some(value) = &self.DC_EVPowerDeliveryParameter {
+                exi.set_dc_delivery_params(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PowerDeliveryRequest, PowerDeliveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerDeliveryResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVSEStatus: Option<AcEvseStatusType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVSEStatus: Option<DcEvseStatusType>,
+    }
+
+    impl PowerDeliveryResXsd {
+        fn from_exi(exi: &PowerDeliveryResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                AC_EVSEStatus: exi.get_ac_evse_status(),
+                DC_EVSEStatus: exi.get_dc_evse_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PowerDeliveryResponse, AfbError> {
+            let mut exi = PowerDeliveryResponse::new(self.ResponseCode);
+            if let Some(value) = &self.AC_EVSEStatus {
+                exi.set_ac_evse_status(value)?;
+            }
+            if let Some(value) = &self.DC_EVSEStatus {
+                exi.set_dc_evse_status(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PowerDeliveryResponse, PowerDeliveryResXsd);
+}
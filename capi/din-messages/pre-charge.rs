@@ -29,7 +29,7 @@ impl PreChargeRequest {
         target_voltage: &PhysicalValue,
         target_current: &PhysicalValue,
     ) -> Result<Self, AfbError> {
-        if let Some(unit) = target_voltage.get_unit() {
+        if let Some(unit) = target_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "pre-charge-req",
@@ -39,7 +39,7 @@ impl PreChargeRequest {
             }
         }
 
-        if let Some(unit) = target_current.get_unit() {
+        if let Some(unit) = target_current.get_unit()? {
             if unit != PhysicalUnit::Ampere {
                 return afb_error!(
                     "pre-charge-req",
@@ -93,7 +93,7 @@ impl PreChargeResponse {
         evse_status: &DcEvseStatusType,
         evse_voltage: &PhysicalValue,
     ) -> Result<Self, AfbError> {
-        if let Some(unit) = evse_voltage.get_unit() {
+        if let Some(unit) = evse_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!("pre-charge-res", "expect: PhysicalUnit::Volt get:{}", unit);
             }
@@ -106,7 +106,7 @@ impl PreChargeResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -132,3 +132,81 @@ impl PreChargeResponse {
         body
     }
 }
+
+impl ExiFields for PreChargeRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVStatus", self.get_status())
+            .field("EVTargetVoltage", self.get_target_voltage())
+            .field("EVTargetCurrent", self.get_target_current());
+    }
+}
+
+impl ExiFields for PreChargeResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEPresentVoltage", self.get_voltage());
+    }
+}
+crate::exi_format!(PreChargeRequest, PreChargeResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct PreChargeReqXsd {
+        DC_EVStatus: DcEvStatusType,
+        EVTargetVoltage: PhysicalValue,
+        EVTargetCurrent: PhysicalValue,
+    }
+
+    impl PreChargeReqXsd {
+        fn from_exi(exi: &PreChargeRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+                EVTargetVoltage: exi.get_target_voltage(),
+                EVTargetCurrent: exi.get_target_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PreChargeRequest, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_PreChargeReqType>() };
+            payload.DC_EVStatus = self.DC_EVStatus.encode();
+            payload.EVTargetVoltage = self.EVTargetVoltage.encode();
+            payload.EVTargetCurrent = self.EVTargetCurrent.encode();
+            Ok(PreChargeRequest::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(PreChargeRequest, PreChargeReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PreChargeResXsd {
+        ResponseCode: ResponseCode,
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEPresentVoltage: PhysicalValue,
+    }
+
+    impl PreChargeResXsd {
+        fn from_exi(exi: &PreChargeResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_EVSEStatus: exi.get_status(),
+                EVSEPresentVoltage: exi.get_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PreChargeResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_PreChargeResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEPresentVoltage = self.EVSEPresentVoltage.encode();
+            Ok(PreChargeResponse::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(PreChargeResponse, PreChargeResXsd);
+}
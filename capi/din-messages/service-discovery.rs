@@ -71,7 +71,7 @@ impl ServiceTag {
         self.payload.ServiceID
     }
 
-    pub fn get_category(&self) -> ServiceCategory {
+    pub fn get_category(&self) -> Result<ServiceCategory, AfbError> {
         ServiceCategory::from_u32(self.payload.ServiceCategory)
     }
 
@@ -193,7 +193,7 @@ impl ServiceCharging {
         ServiceTag::decode(self.payload.ServiceTag)
     }
 
-    pub fn get_transfer(&self) -> EvRequestTransfertMode {
+    pub fn get_transfer(&self) -> Result<EvRequestTransfertMode, AfbError> {
        EvRequestTransfertMode::from_u32(self.payload.EnergyTransferType)
 
     }
@@ -260,13 +260,13 @@ impl ServiceDiscoveryRequest {
         self
     }
 
-    pub fn get_category(&self) -> Option<ServiceCategory> {
+    pub fn get_category(&self) -> Result<Option<ServiceCategory>, AfbError> {
         let category = if self.payload.ServiceCategory_isUsed() == 0 {
             None
         } else {
-            Some(ServiceCategory::from_u32(self.payload.ServiceCategory))
+            Some(ServiceCategory::from_u32(self.payload.ServiceCategory)?)
         };
-        category
+        Ok(category)
     }
 
     pub fn decode(payload: cglue::din_ServiceDiscoveryReqType) -> Self {
@@ -296,7 +296,7 @@ impl ServiceDiscoveryResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -328,15 +328,15 @@ impl ServiceDiscoveryResponse {
         Ok(self)
     }
 
-    pub fn get_payments(&self) -> Vec<PaymentOption> {
+    pub fn get_payments(&self) -> Result<Vec<PaymentOption>, AfbError> {
         let mut payments = Vec::new();
         for idx in 0..self.payload.PaymentOptions.PaymentOption.arrayLen {
             let payment = PaymentOption::from_u32(
                 self.payload.PaymentOptions.PaymentOption.array[idx as usize],
-            );
+            )?;
             payments.push(payment);
         }
-        payments
+        Ok(payments)
     }
 
     pub fn decode(payload: cglue::din_ServiceDiscoveryResType) -> Self {
@@ -353,3 +353,216 @@ impl ServiceDiscoveryResponse {
         body
     }
 }
+
+impl ExiFields for ServiceTag {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceID", self.get_id())
+            .option("ServiceName", self.get_name())
+            .result("ServiceCategory", self.get_category())
+            .option("ServiceScope", self.get_scope());
+    }
+}
+
+impl ExiFields for ServiceOther {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceTag", self.get_tag())
+            .field("FreeService", self.get_isfree());
+    }
+}
+
+impl ExiFields for ServiceCharging {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceTag", self.get_tag())
+            .field("FreeService", self.get_isfree())
+            .result("EnergyTransferType", self.get_transfer());
+    }
+}
+
+impl ExiFields for ServiceDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("ServiceScope", self.get_scope())
+            .result_option("ServiceCategory", self.get_category());
+    }
+}
+
+impl ExiFields for ServiceDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("PaymentOptions", self.get_payments().map(ExiList))
+            .field("ChargeService", self.get_charging())
+            .option("ServiceList", self.get_service());
+    }
+}
+crate::exi_format!(
+    ServiceTag,
+    ServiceOther,
+    ServiceCharging,
+    ServiceDiscoveryRequest,
+    ServiceDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceTagXsd {
+        ServiceID: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceName: Option<String>,
+        ServiceCategory: ServiceCategory,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceScope: Option<String>,
+    }
+
+    impl ServiceTagXsd {
+        fn from_exi(exi: &ServiceTag) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_id(),
+                ServiceName: exi.get_name().map(|value| value.to_string()),
+                ServiceCategory: exi.get_category()?,
+                ServiceScope: exi.get_scope().map(|value| value.to_string()),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceTag, AfbError> {
+            let mut exi = ServiceTag::new(self.ServiceID, self.ServiceCategory);
+            if let Some(value) = &self.ServiceName {
+                exi.set_name(value)?;
+            }
+            if let Some(value) = &self.ServiceScope {
+                exi.set_scope(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceTag, ServiceTagXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceXsd {
+        ServiceTag: ServiceTag,
+        FreeService: bool,
+    }
+
+    impl ServiceXsd {
+        fn from_exi(exi: &ServiceOther) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceTag: exi.get_tag(),
+                FreeService: exi.get_isfree(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceOther, AfbError> {
+            Ok(ServiceOther::new(&self.ServiceTag, self.FreeService))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceOther, ServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceChargeXsd {
+        ServiceTag: ServiceTag,
+        FreeService: bool,
+        EnergyTransferType: EvRequestTransfertMode,
+    }
+
+    impl ServiceChargeXsd {
+        fn from_exi(exi: &ServiceCharging) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceTag: exi.get_tag(),
+                FreeService: exi.get_isfree(),
+                EnergyTransferType: exi.get_transfer()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceCharging, AfbError> {
+            Ok(ServiceCharging::new(
+                &self.ServiceTag,
+                self.EnergyTransferType,
+                self.FreeService,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceCharging, ServiceChargeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceScope: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceCategory: Option<ServiceCategory>,
+    }
+
+    impl ServiceDiscoveryReqXsd {
+        fn from_exi(exi: &ServiceDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceScope: exi.get_scope().map(|value| value.to_string()),
+                ServiceCategory: exi.get_category()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryRequest, AfbError> {
+            let mut exi = ServiceDiscoveryRequest::new();
+            if let Some(value) = &self.ServiceScope {
+                exi.set_scope(value)?;
+            }
+            if let Some(value) = self.ServiceCategory {
+                exi.set_category(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryRequest, ServiceDiscoveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentOptionsXsd {
+        PaymentOption: Vec<PaymentOption>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceListXsd {
+        Service: ServiceOther,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        PaymentOptions: PaymentOptionsXsd,
+        ChargeService: ServiceCharging,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceList: Option<ServiceListXsd>,
+    }
+
+    impl ServiceDiscoveryResXsd {
+        fn from_exi(exi: &ServiceDiscoveryResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                PaymentOptions: PaymentOptionsXsd {
+                    PaymentOption: exi.get_payments()?,
+                },
+                ChargeService: exi.get_charging(),
+                ServiceList: exi
+                    .get_service()
+                    .map(|service| ServiceListXsd { Service: service }),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryResponse, AfbError> {
+            let mut exi = ServiceDiscoveryResponse::new(self.ResponseCode, &self.ChargeService);
+            for payment in self.PaymentOptions.PaymentOption {
+                exi.add_payment(payment)?;
+            }
+            if let Some(list) = &self.ServiceList {
+                exi.set_service(&list.Service);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryResponse, ServiceDiscoveryResXsd);
+}
@@ -107,7 +107,7 @@ impl SessionSetupResponse {
         )
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -136,3 +136,84 @@ impl SessionSetupResponse {
     }
 
 }
+
+impl ExiFields for SessionSetupRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("EVCCID", ExiHexa(self.get_id()));
+    }
+}
+
+impl ExiFields for SessionSetupResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("EVSEID", ExiHexa(self.get_id()));
+        if self.payload.DateTimeNow_isUsed() != 0 {
+            fields.field("DateTimeNow", self.get_time_stamp());
+        }
+    }
+}
+crate::exi_format!(SessionSetupRequest, SessionSetupResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupReqXsd {
+        EVCCID: ExiBytes,
+    }
+
+    impl SessionSetupReqXsd {
+        fn from_exi(exi: &SessionSetupRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVCCID: ExiBytes::from(exi.get_id()),
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionSetupRequest, AfbError> {
+            SessionSetupRequest::new(self.EVCCID.as_slice())
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupRequest, SessionSetupReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupResXsd {
+        ResponseCode: ResponseCode,
+        EVSEID: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DateTimeNow: Option<i64>,
+    }
+
+    impl SessionSetupResXsd {
+        fn from_exi(exi: &SessionSetupResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEID: ExiBytes::from(exi.get_id()),
+                DateTimeNow: match exi.payload.DateTimeNow_isUsed() {
+                    0 => None,
+                    _ => Some(exi.get_time_stamp()),
+                },
+            })
+        }
+
+        // new() stamps current time, payload is filled directly to keep DateTimeNow as received
+        fn into_exi(self) -> Result<SessionSetupResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_SessionSetupResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.EVSEID.bytesLen = bytes_to_array(
+                self.EVSEID.as_slice(),
+                &mut payload.EVSEID.bytes,
+                cglue::din_evseIDType_BYTES_SIZE,
+            )?;
+            let mut exi = SessionSetupResponse::decode(payload);
+            if let Some(value) = self.DateTimeNow {
+                exi.set_timestamp(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupResponse, SessionSetupResXsd);
+}
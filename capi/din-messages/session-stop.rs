@@ -57,7 +57,7 @@ impl SessionStopResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -76,3 +76,54 @@ impl SessionStopResponse {
     }
 
 }
+
+impl ExiFields for SessionStopRequest {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for SessionStopResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ResponseCode", self.get_rcode());
+    }
+}
+crate::exi_format!(SessionStopRequest, SessionStopResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    // DIN SessionStopReq has no content
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopReqXsd {}
+
+    impl SessionStopReqXsd {
+        fn from_exi(_exi: &SessionStopRequest) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<SessionStopRequest, AfbError> {
+            Ok(SessionStopRequest::new(0))
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopRequest, SessionStopReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopResXsd {
+        ResponseCode: ResponseCode,
+    }
+
+    impl SessionStopResXsd {
+        fn from_exi(exi: &SessionStopResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionStopResponse, AfbError> {
+            Ok(SessionStopResponse::new(self.ResponseCode))
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopResponse, SessionStopResXsd);
+}
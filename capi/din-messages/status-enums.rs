@@ -18,11 +18,11 @@
 use std::convert::AsRef;
 use std::mem;
 use std::str::FromStr;
-use strum_macros::{AsRefStr, Display, EnumString};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
 use super::*;
 
-#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 #[allow(dead_code)]
@@ -67,9 +67,12 @@ pub enum MessageTagId {
     Unsupported,
 }
 
+crate::exi_enum_try_from!(MessageTagId, u32);
+
 impl MessageTagId {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
 
     #[track_caller]
@@ -117,7 +120,7 @@ impl MessageTagId {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 #[allow(dead_code)]
@@ -158,9 +161,43 @@ pub enum ResponseCode {
         cglue::din_responseCodeType_din_responseCodeType_FAILED_WrongEnergyTransferType,
 }
 
+crate::exi_enum_try_from!(ResponseCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ResponseCode, {
+    Ok => "OK",
+    Failed => "FAILED",
+    NewSession => "OK_NewSessionEstablished",
+    OldSessionJoin => "OK_OldSessionJoined",
+    CertificateExpiresSoon => "OK_CertificateExpiresSoon",
+    SequenceError => "FAILED_SequenceError",
+    ServiceIDInvalid => "FAILED_ServiceIDInvalid",
+    UnknownSession => "FAILED_UnknownSession",
+    ServiceSelectionInvalid => "FAILED_ServiceSelectionInvalid",
+    PaymentSelectionInvalid => "FAILED_PaymentSelectionInvalid",
+    CertificateExpired => "FAILED_CertificateExpired",
+    SignatureError => "FAILED_SignatureError",
+    NoCertificateAvailable => "FAILED_NoCertificateAvailable",
+    CertChainError => "FAILED_CertChainError",
+    ChallengeInvalid => "FAILED_ChallengeInvalid",
+    ContractCanceled => "FAILED_ContractCanceled",
+    WrongChargeParameter => "FAILED_WrongChargeParameter",
+    PowerDeliveryNotApplied => "FAILED_PowerDeliveryNotApplied",
+    TariffSelectionInvalid => "FAILED_TariffSelectionInvalid",
+    ChargingProfileInvalid => "FAILED_ChargingProfileInvalid",
+    MeteringSignatureNotValid => "FAILED_MeteringSignatureNotValid",
+    EVSEPresentVoltageToLow => "FAILED_EVSEPresentVoltageToLow",
+    WrongEnergyTransferType => "FAILED_WrongEnergyTransferType",
+});
+
 impl ResponseCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    // any FAILED_xxx response code terminates the communication session
+    pub fn is_failed(&self) -> bool {
+        *self as u32 >= ResponseCode::Failed as u32
     }
 
     #[track_caller]
@@ -178,7 +215,7 @@ impl ResponseCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum ServiceCategory {
@@ -188,9 +225,19 @@ pub enum ServiceCategory {
     Other = cglue::din_serviceCategoryType_din_serviceCategoryType_OtherCustom,
 }
 
+crate::exi_enum_try_from!(ServiceCategory, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ServiceCategory, {
+    EvCharger => "EVCharging",
+    Internet => "Internet",
+    Certificate => "ContractCertificate",
+    Other => "OtherCustom",
+});
+
 impl ServiceCategory {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -207,7 +254,7 @@ impl ServiceCategory {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -216,9 +263,17 @@ pub enum PaymentOption {
     External = cglue::din_paymentOptionType_din_paymentOptionType_ExternalPayment,
 }
 
+crate::exi_enum_try_from!(PaymentOption, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(PaymentOption, {
+    Contract => "Contract",
+    External => "ExternalPayment",
+});
+
 impl PaymentOption {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -236,16 +291,24 @@ impl PaymentOption {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum EvseProcessing {
     Finished = cglue::din_EVSEProcessingType_din_EVSEProcessingType_Finished,
     Ongoing = cglue::din_EVSEProcessingType_din_EVSEProcessingType_Ongoing,
 }
+crate::exi_enum_try_from!(EvseProcessing, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseProcessing, {
+    Finished => "Finished",
+    Ongoing => "Ongoing",
+});
+
 impl EvseProcessing {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -263,7 +326,7 @@ impl EvseProcessing {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum DcEvErrorCode {
@@ -285,9 +348,27 @@ pub enum DcEvErrorCode {
         cglue::din_DC_EVErrorCodeType_din_DC_EVErrorCodeType_FAILED_ChargingSystemIncompatibility,
     FailCodeNoData = cglue::din_DC_EVErrorCodeType_din_DC_EVErrorCodeType_NoData,
 }
+crate::exi_enum_try_from!(DcEvErrorCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(DcEvErrorCode, {
+    NoError => "NO_ERROR",
+    FailRessTempInhibit => "FAILED_RESSTemperatureInhibit",
+    FailEvShiftPos => "FAILED_EVShiftPosition",
+    FailChargeConnectLock => "FAILED_ChargerConnectorLockFault",
+    FailEvresFault => "FAILED_EVRESSMalfunction",
+    FailCurrentDifferential => "FAILED_ChargingCurrentdifferential",
+    FailVoltOutOfRange => "FAILED_ChargingVoltageOutOfRange",
+    FailReserveA => "Reserved_A",
+    FailReserveB => "Reserved_B",
+    FailReserveC => "Reserved_C",
+    FailIncompatible => "FAILED_ChargingSystemIncompatibility",
+    FailCodeNoData => "NoData",
+});
+
 impl DcEvErrorCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -304,7 +385,7 @@ impl DcEvErrorCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -318,9 +399,21 @@ pub enum EvRequestTransfertMode {
         cglue::din_EVRequestedEnergyTransferType_din_EVRequestedEnergyTransferType_DC_combo_core,
     DcUnique = cglue::din_EVRequestedEnergyTransferType_din_EVRequestedEnergyTransferType_DC_unique,
 }
+crate::exi_enum_try_from!(EvRequestTransfertMode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvRequestTransfertMode, {
+    AcSinglePhase => "AC_single_phase_core",
+    AcThreePhase => "AC_three_phase_core",
+    DcBasic => "DC_core",
+    DcExtended => "DC_extended",
+    DcCombo => "DC_combo_core",
+    DcUnique => "DC_unique",
+});
+
 impl EvRequestTransfertMode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -341,7 +434,7 @@ impl EvRequestTransfertMode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -351,9 +444,18 @@ pub enum EvseNotification {
     StopCharging = cglue::din_EVSENotificationType_din_EVSENotificationType_StopCharging,
     ReNegotiation = cglue::din_EVSENotificationType_din_EVSENotificationType_ReNegotiation,
 }
+crate::exi_enum_try_from!(EvseNotification, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseNotification, {
+    None => "None",
+    StopCharging => "StopCharging",
+    ReNegotiation => "ReNegotiation",
+});
+
 impl EvseNotification {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -370,7 +472,7 @@ impl EvseNotification {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -380,9 +482,19 @@ pub enum IsolationStatus {
     Warning = cglue::din_isolationLevelType_din_isolationLevelType_Warning,
     Fault = cglue::din_isolationLevelType_din_isolationLevelType_Fault,
 }
+crate::exi_enum_try_from!(IsolationStatus, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(IsolationStatus, {
+    Invalid => "Invalid",
+    Valid => "Valid",
+    Warning => "Warning",
+    Fault => "Fault",
+});
+
 impl IsolationStatus {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -399,7 +511,7 @@ impl IsolationStatus {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -421,9 +533,27 @@ pub enum DcEvseErrorCode {
     ReserveC = cglue::din_DC_EVSEStatusCodeType_din_DC_EVSEStatusCodeType_Reserved_C,
 }
 
+crate::exi_enum_try_from!(DcEvseErrorCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(DcEvseErrorCode, {
+    NotReady => "EVSE_NotReady",
+    Ready => "EVSE_Ready",
+    Shutdown => "EVSE_Shutdown",
+    UtilInteruptEvt => "EVSE_UtilityInterruptEvent",
+    MonitoringActive => "EVSE_IsolationMonitoringActive",
+    EmergencyShutdown => "EVSE_EmergencyShutdown",
+    EvseMalfunction => "EVSE_Malfunction",
+    Reserve8 => "Reserved_8",
+    Reserve9 => "Reserved_9",
+    ReserveA => "Reserved_A",
+    ReserveB => "Reserved_B",
+    ReserveC => "Reserved_C",
+});
+
 impl DcEvseErrorCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -444,7 +574,7 @@ impl DcEvseErrorCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -461,9 +591,26 @@ pub enum PhysicalUnit {
     WattSecond = cglue::din_unitSymbolType_din_unitSymbolType_W_s,
     Unset = 9999,
 }
+crate::exi_enum_try_from!(PhysicalUnit, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(PhysicalUnit, {
+    Hour => "h",
+    Minute => "m",
+    Second => "s",
+    Ampere => "A",
+    Volt => "V",
+    Watt => "W",
+    Wh => "Wh",
+    VolAmp => "VA",
+    AmpHour => "Ah",
+    WattSecond => "W.s",
+    Unset => "unset",
+});
+
 impl PhysicalUnit {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -478,6 +625,23 @@ impl PhysicalUnit {
     pub fn to_label(&self) -> &str {
         self.as_ref()
     }
+
+    /// SI symbol used by PhysicalValue Display, ex: "400 V"
+    pub fn to_symbol(&self) -> &'static str {
+        match self {
+            PhysicalUnit::Hour => "h",
+            PhysicalUnit::Minute => "min",
+            PhysicalUnit::Second => "s",
+            PhysicalUnit::Ampere => "A",
+            PhysicalUnit::Volt => "V",
+            PhysicalUnit::Watt => "W",
+            PhysicalUnit::Wh => "Wh",
+            PhysicalUnit::VolAmp => "VA",
+            PhysicalUnit::AmpHour => "Ah",
+            PhysicalUnit::WattSecond => "Ws",
+            PhysicalUnit::Unset => "",
+        }
+    }
 }
 
 pub struct DcEvseStatusType {
@@ -493,11 +657,11 @@ impl DcEvseStatusType {
         Self { payload }
     }
 
-    pub fn get_error(&self) -> DcEvseErrorCode {
+    pub fn get_error(&self) -> Result<DcEvseErrorCode, AfbError> {
         DcEvseErrorCode::from_u32(self.payload.EVSEStatusCode)
     }
 
-    pub fn get_notification(&self) -> EvseNotification {
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
         EvseNotification::from_u32(self.payload.EVSENotification)
     }
 
@@ -511,11 +675,13 @@ impl DcEvseStatusType {
         self
     }
 
-    pub fn get_isolation_status(&self) -> Option<IsolationStatus> {
+    pub fn get_isolation_status(&self) -> Result<Option<IsolationStatus>, AfbError> {
         if self.payload.EVSEIsolationStatus_isUsed() == 0 {
-            None
+            Ok(None)
         } else {
-            Some(IsolationStatus::from_u32(self.payload.EVSEIsolationStatus))
+            Ok(Some(IsolationStatus::from_u32(
+                self.payload.EVSEIsolationStatus,
+            )?))
         }
     }
 
@@ -552,7 +718,7 @@ impl DcEvStatusType {
         }
     }
 
-    pub fn get_error(&self) -> DcEvErrorCode {
+    pub fn get_error(&self) -> Result<DcEvErrorCode, AfbError> {
         DcEvErrorCode::from_u32(self.payload.EVErrorCode)
     }
 
@@ -616,7 +782,7 @@ impl AcEvseStatusType {
         Self { payload }
     }
 
-    pub fn get_notification(&self) -> EvseNotification {
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
         EvseNotification::from_u32(self.payload.EVSENotification)
     }
 
@@ -664,3 +830,175 @@ impl EvseStatusType {
         self.payload
     }
 }
+
+impl ExiFields for DcEvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result_option("EVSEIsolationStatus", self.get_isolation_status())
+            .result("EVSEStatusCode", self.get_error())
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification());
+    }
+}
+
+impl ExiFields for DcEvStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        let payload = &self.payload;
+        fields.field("EVReady", self.get_ready());
+        if payload.EVCabinConditioning_isUsed() != 0 {
+            fields.field("EVCabinConditioning", payload.EVCabinConditioning != 0);
+        }
+        if payload.EVRESSConditioning_isUsed() != 0 {
+            fields.field("EVRESSConditioning", payload.EVRESSConditioning != 0);
+        }
+        fields
+            .result("EVErrorCode", self.get_error())
+            .field("EVRESSSOC", self.get_evress_soc());
+    }
+}
+
+impl ExiFields for AcEvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("RCD", self.get_rcd())
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification());
+    }
+}
+
+// abstract EVSEStatusType has no content
+impl ExiFields for EvseStatusType {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+crate::exi_format!(
+    DcEvseStatusType,
+    DcEvStatusType,
+    AcEvseStatusType,
+    EvseStatusType
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvseStatusXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEIsolationStatus: Option<IsolationStatus>,
+        EVSEStatusCode: DcEvseErrorCode,
+        NotificationMaxDelay: u32,
+        EVSENotification: EvseNotification,
+    }
+
+    impl DcEvseStatusXsd {
+        fn from_exi(exi: &DcEvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEIsolationStatus: exi.get_isolation_status()?,
+                EVSEStatusCode: exi.get_error()?,
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvseStatusType, AfbError> {
+            let mut exi = DcEvseStatusType::new(
+                self.EVSEStatusCode,
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+            );
+            if let Some(value) = self.EVSEIsolationStatus {
+                exi.set_isolation_status(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvseStatusType, DcEvseStatusXsd);
+
+    // conditioning setters share EVCabinConditioning, payload is read/written directly
+    #[derive(Serialize, Deserialize)]
+    struct DcEvStatusXsd {
+        EVReady: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVCabinConditioning: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVRESSConditioning: Option<bool>,
+        EVErrorCode: DcEvErrorCode,
+        EVRESSSOC: i8,
+    }
+
+    impl DcEvStatusXsd {
+        fn from_exi(exi: &DcEvStatusType) -> Result<Self, AfbError> {
+            let payload = &exi.payload;
+            Ok(Self {
+                EVReady: exi.get_ready(),
+                EVCabinConditioning: match payload.EVCabinConditioning_isUsed() {
+                    0 => None,
+                    _ => Some(payload.EVCabinConditioning != 0),
+                },
+                EVRESSConditioning: match payload.EVRESSConditioning_isUsed() {
+                    0 => None,
+                    _ => Some(payload.EVRESSConditioning != 0),
+                },
+                EVErrorCode: exi.get_error()?,
+                EVRESSSOC: exi.get_evress_soc(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvStatusType, AfbError> {
+            let mut exi = DcEvStatusType::new(self.EVReady, self.EVErrorCode, self.EVRESSSOC);
+            if let Some(value) = self.EVCabinConditioning {
+                exi.payload.EVCabinConditioning = if value { 1 } else { 0 };
+                exi.payload.set_EVCabinConditioning_isUsed(1);
+            }
+            if let Some(value) = self.EVRESSConditioning {
+                exi.payload.EVRESSConditioning = if value { 1 } else { 0 };
+                exi.payload.set_EVRESSConditioning_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvStatusType, DcEvStatusXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvseStatusXsd {
+        RCD: bool,
+        NotificationMaxDelay: u32,
+        EVSENotification: EvseNotification,
+    }
+
+    impl AcEvseStatusXsd {
+        fn from_exi(exi: &AcEvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                RCD: exi.get_rcd(),
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvseStatusType, AfbError> {
+            Ok(AcEvseStatusType::new(
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+                self.RCD,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(AcEvseStatusType, AcEvseStatusXsd);
+
+    // abstract EVSEStatusType has no content
+    #[derive(Serialize, Deserialize)]
+    struct EvseStatusXsd {}
+
+    impl EvseStatusXsd {
+        fn from_exi(_exi: &EvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<EvseStatusType, AfbError> {
+            Ok(EvseStatusType::new(0))
+        }
+    }
+    crate::exi_serde_mirror!(EvseStatusType, EvseStatusXsd);
+}
@@ -59,7 +59,7 @@ impl WeldingDetectionResponse {
         evse_status: &DcEvseStatusType,
         evse_voltage: &PhysicalValue,
     ) -> Result<Self, AfbError> {
-        if let Some(unit) = evse_voltage.get_unit() {
+        if let Some(unit) = evse_voltage.get_unit()? {
             if unit != PhysicalUnit::Volt {
                 return afb_error!(
                     "welding-detection-response",
@@ -77,7 +77,7 @@ impl WeldingDetectionResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -103,3 +103,70 @@ impl WeldingDetectionResponse {
         body
     }
 }
+
+impl ExiFields for WeldingDetectionRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("DC_EVStatus", self.get_status());
+    }
+}
+
+impl ExiFields for WeldingDetectionResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEPresentVoltage", self.get_voltage());
+    }
+}
+crate::exi_format!(WeldingDetectionRequest, WeldingDetectionResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct WeldingDetectionReqXsd {
+        DC_EVStatus: DcEvStatusType,
+    }
+
+    impl WeldingDetectionReqXsd {
+        fn from_exi(exi: &WeldingDetectionRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<WeldingDetectionRequest, AfbError> {
+            Ok(WeldingDetectionRequest::new(&self.DC_EVStatus))
+        }
+    }
+    crate::exi_serde_mirror!(WeldingDetectionRequest, WeldingDetectionReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct WeldingDetectionResXsd {
+        ResponseCode: ResponseCode,
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEPresentVoltage: PhysicalValue,
+    }
+
+    impl WeldingDetectionResXsd {
+        fn from_exi(exi: &WeldingDetectionResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_EVSEStatus: exi.get_status(),
+                EVSEPresentVoltage: exi.get_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<WeldingDetectionResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::din_WeldingDetectionResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEPresentVoltage = self.EVSEPresentVoltage.encode();
+            Ok(WeldingDetectionResponse::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(WeldingDetectionResponse, WeldingDetectionResXsd);
+}
@@ -19,14 +19,13 @@
 //use afbv4::prelude::*;
 use crate::prelude::*;
 use core::slice;
-use std::alloc;
 use std::boxed::Box;
 use std::mem;
 use std::os::raw;
 use std::pin::Pin;
 use std::str;
 use std::str::FromStr;
-use strum_macros::{AsRefStr, Display, EnumString};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
 
 mod cglue {
@@ -34,11 +33,11 @@ mod cglue {
     #![allow(non_upper_case_globals)]
     #![allow(non_camel_case_types)]
     #![allow(non_snake_case)]
-    include!("_exi-capi.rs");
+    include!(concat!(env!("OUT_DIR"), "/_exi-capi.rs"));
 }
 
 #[repr(u32)]
-#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(non_camel_case_types)]
 pub enum PkiErrorStatus {
@@ -80,9 +79,12 @@ pub enum PkiErrorStatus {
     ERROR_INTERNAL9 = cglue::isox_sign_status_t_isox_sign_ERROR_INTERNAL9,
 }
 
+crate::exi_enum_try_from!(PkiErrorStatus, u32);
+
 impl PkiErrorStatus {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
 
     #[track_caller]
@@ -102,7 +104,7 @@ impl PkiErrorStatus {
 #[allow(non_camel_case_types)]
 pub(crate) type exi_bitstream_t = cglue::exi_bitstream_t;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, EnumIter)]
 #[allow(non_camel_case_types, dead_code)]
 #[repr(i32)]
 pub enum ExiErrorCode {
@@ -200,20 +202,33 @@ pub fn array_to_bytes<'a>(data: &'a [u8], len: u16) -> &'a [u8] {
     slice
 }
 
+crate::exi_enum_try_from!(ExiErrorCode, i32);
+
 impl ExiErrorCode {
-    pub fn from_i32(code: i32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_i32(code: i32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    // safe lookup for C status, unlisted values return None
+    pub fn from_status(code: i32) -> Option<Self> {
+        Self::try_from(code).ok()
     }
 }
 
+/// Exi stream owns both its data buffer and the C bitstream handle, both are released
+/// by Rust when the stream is dropped. C functions mutating the bitstream require &mut.
 pub struct RawStream {
     pub buffer: Pin<Box<[u8; cglue::EXI_MAX_DOCUMENT_SIZE]>>,
-    pub stream: *mut cglue::exi_bitstream_t,
-    layout: alloc::Layout,
+    bitstream: Box<cglue::exi_bitstream_t>,
     pub current_len: u32,
     pub expected_len: u32,
 }
 
+// bitstream.data only points to our own heap buffer, moving the stream to another
+// thread moves both. Sync is not implemented, shared access goes through ExiStream mutex.
+unsafe impl Send for RawStream {}
+
 #[no_mangle]
 pub extern "C" fn exi_stream_cb(message_id: i32, status_code: i32, value_1: i32, value_2: i32) {
     afb_log_msg!(
@@ -231,34 +246,54 @@ pub extern "C" fn exi_stream_cb(message_id: i32, status_code: i32, value_1: i32,
 impl RawStream {
     /// reserve stream memory space
     pub fn new() -> Self {
-        // allocate exi_bitstream_t in heap and freeze it
-        let layout = alloc::Layout::new::<cglue::exi_bitstream_t>();
-        let handle = unsafe { alloc::alloc(layout) as *mut cglue::exi_bitstream_t };
-
         let mut stream = RawStream {
-            layout,
             buffer: Box::pin([0; cglue::EXI_MAX_DOCUMENT_SIZE]),
-            stream: handle,
+            bitstream: Box::new(unsafe { mem::zeroed::<cglue::exi_bitstream_t>() }),
             current_len: 0,
             expected_len: 0,
         };
 
         // create an empty exi doc
+        #[cfg(not(miri))]
         unsafe {
             cglue::exi_bitstream_init(
-                handle,
+                &mut *stream.bitstream,
                 stream.buffer.as_mut_ptr(),
                 cglue::EXI_MAX_DOCUMENT_SIZE,
                 0,
                 Some(exi_stream_cb),
             )
         }
+
+        // Miri cannot call libiso15118, pure Rust stream tests only need the window
+        #[cfg(miri)]
+        {
+            stream.bitstream.data = stream.buffer.as_mut_ptr();
+            stream.set_window(cglue::EXI_MAX_DOCUMENT_SIZE, 0);
+        }
         stream
     }
 
-    pub fn drop(&self) {
-        // to drop object move back RawStream self into Rust allocate space
-        let _ = unsafe { alloc::dealloc(self.stream as *mut u8, self.layout) };
+    // C handle for encoders/decoders, data is refreshed from a fresh buffer borrow
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut cglue::exi_bitstream_t {
+        self.bitstream.data = self.buffer.as_mut_ptr();
+        &mut *self.bitstream
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const cglue::exi_bitstream_t {
+        &*self.bitstream
+    }
+
+    /// (decode only) restrict decoder to data_size and move cursor to byte_pos
+    pub fn set_window(&mut self, data_size: usize, byte_pos: usize) {
+        self.bitstream.data_size = data_size;
+        self.bitstream.byte_pos = byte_pos;
+        self.bitstream.bit_count = 0;
+    }
+
+    /// current (byte_pos, bit_count) of encoder/decoder cursor
+    pub fn get_position(&self) -> (usize, u8) {
+        (self.bitstream.byte_pos, self.bitstream.bit_count as u8)
     }
 
     pub fn get_buffer(&self) -> &[u8] {
@@ -266,81 +301,74 @@ impl RawStream {
     }
 
     pub fn get_cursor(&self) -> usize {
-        unsafe { cglue::exi_bitstream_get_length(self.stream) }
+        unsafe { cglue::exi_bitstream_get_length(self.as_ptr()) }
     }
 
     pub fn get_index(&self) -> (usize, usize) {
-        let index = unsafe { cglue::exi_bitstream_get_length(self.stream) };
+        let index = self.get_cursor();
         (index, cglue::EXI_MAX_DOCUMENT_SIZE - index)
     }
 
     pub fn get_size(&self) -> usize {
-        unsafe {
-            let stream = self.stream.as_ref().expect("stream.reset valid handle");
-            stream.data_size
-        }
+        self.bitstream.data_size
     }
 
-    pub fn set_size(&self, size:u32) {
-        unsafe {
-            let stream = self.stream.as_mut().expect("stream.reset valid handle");
-            stream.data_size= size as usize
-        }
+    pub fn set_size(&mut self, size: u32) {
+        self.bitstream.data_size = size as usize
     }
+
     pub fn reset(&mut self) {
         // reset everything including data_count
-        unsafe {
-            self.expected_len = 0;
-            self.current_len = 0;
-            let stream = self.stream.as_mut().expect("stream.reset valid handle");
-            stream.data_size = cglue::EXI_MAX_DOCUMENT_SIZE;
-            stream.byte_pos = 0;
-            stream.bit_count = 0;
-        }
+        self.expected_len = 0;
+        self.current_len = 0;
+        self.set_window(cglue::EXI_MAX_DOCUMENT_SIZE, 0);
     }
 
-    pub fn write_bits(&self, value: u32, bit_count: usize) -> Result<(), AfbError> {
-        let status = unsafe { cglue::exi_bitstream_write_bits(self.stream, bit_count, value) };
+    pub fn write_bits(&mut self, value: u32, bit_count: usize) -> Result<(), AfbError> {
+        let status =
+            unsafe { cglue::exi_bitstream_write_bits(self.as_mut_ptr(), bit_count, value) };
         if status != 0 {
             return afb_error!(
                 "exi-stream-wbits",
                 "fail to write bit error:{:?}",
-                ExiErrorCode::from_i32(status)
+                ExiErrorCode::from_status(status)
             );
         }
         Ok(())
     }
-    pub fn write_octet(&self, value: u8) -> Result<(), AfbError> {
-        let status = unsafe { cglue::exi_bitstream_write_octet(self.stream, value) };
+    pub fn write_octet(&mut self, value: u8) -> Result<(), AfbError> {
+        let status = unsafe { cglue::exi_bitstream_write_octet(self.as_mut_ptr(), value) };
         if status != 0 {
             return afb_error!(
                 "exi-stream-woctets",
                 "fail to write byte error:{:?}",
-                ExiErrorCode::from_i32(status)
+                ExiErrorCode::from_status(status)
             );
         }
         Ok(())
     }
-    pub fn read_bits(&self, bit_count: usize) -> Result<u32, AfbError> {
+    pub fn read_bits(&mut self, bit_count: usize) -> Result<u32, AfbError> {
         let mut value: u32 = 0;
-        let status = unsafe { cglue::exi_bitstream_read_bits(self.stream, bit_count, &mut value) };
+        let status = unsafe {
+            cglue::exi_bitstream_read_bits(self.as_mut_ptr(), bit_count, &mut value)
+        };
         if status != 0 {
             return afb_error!(
                 "exi-stream-rbits",
                 "fail to read bit error:{:?}",
-                ExiErrorCode::from_i32(status)
+                ExiErrorCode::from_status(status)
             );
         }
         Ok(value)
     }
-    pub fn read_octet(&self) -> Result<u8, AfbError> {
+    pub fn read_octet(&mut self) -> Result<u8, AfbError> {
         let mut value: u8 = 0;
-        let status = unsafe { cglue::exi_bitstream_read_octet(self.stream, &mut value) };
+        let status = unsafe { cglue::exi_bitstream_read_octet(self.as_mut_ptr(), &mut value) };
         if status != 0 {
             return afb_error!(
                 "exi-stream-roctets",
                 "fail to read byte error:{:?}",
-                ExiErrorCode::from_i32(status)
+                ExiErrorCode::from_status(status)
             );
         }
         Ok(value)
@@ -0,0 +1,251 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::prelude::*;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExiProtocol {
+    AppHand,
+    Din,
+    Iso2,
+    Iso20,
+    Iso20Dc,
+    Iso20Ac,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExiOperation {
+    Encode,
+    Decode,
+}
+
+/// Typed encoder/decoder error, converts into AfbError for existing API users
+#[derive(Clone, Debug)]
+pub enum Iso15118Error {
+    Exi {
+        protocol: ExiProtocol,
+        operation: ExiOperation,
+        // raw C status, code is None when libiso15118 returns an unlisted value
+        status: i32,
+        code: Option<ExiErrorCode>,
+        byte_pos: usize,
+        bit_pos: u8,
+        tag: Option<String>,
+    },
+    Pki {
+        protocol: ExiProtocol,
+        // raw isox_sign status, code is None when value is unlisted
+        status: u32,
+        code: Option<PkiErrorStatus>,
+        tag: String,
+    },
+    // message tag does not support requested operation (e.g. signature)
+    Unsupported {
+        protocol: ExiProtocol,
+        tag: String,
+    },
+}
+
+impl Iso15118Error {
+    /// capture C status and bitstream position at failure
+    pub fn exi(
+        protocol: ExiProtocol,
+        operation: ExiOperation,
+        status: i32,
+        stream: &RawStream,
+        tag: Option<&str>,
+    ) -> Self {
+        let (byte_pos, bit_pos) = stream.get_position();
+        Iso15118Error::Exi {
+            protocol,
+            operation,
+            status,
+            code: ExiErrorCode::from_status(status),
+            byte_pos,
+            bit_pos,
+            tag: tag.map(|value| value.to_string()),
+        }
+    }
+
+    pub fn pki(protocol: ExiProtocol, status: u32, tag: &str) -> Self {
+        Iso15118Error::Pki {
+            protocol,
+            status,
+            code: PkiErrorStatus::try_from(status).ok(),
+            tag: tag.to_string(),
+        }
+    }
+
+    pub fn unsupported(protocol: ExiProtocol, tag: &str) -> Self {
+        Iso15118Error::Unsupported {
+            protocol,
+            tag: tag.to_string(),
+        }
+    }
+
+    pub fn get_protocol(&self) -> ExiProtocol {
+        match self {
+            Iso15118Error::Exi { protocol, .. } => *protocol,
+            Iso15118Error::Pki { protocol, .. } => *protocol,
+            Iso15118Error::Unsupported { protocol, .. } => *protocol,
+        }
+    }
+
+    pub fn get_tag(&self) -> Option<&str> {
+        match self {
+            Iso15118Error::Exi { tag, .. } => tag.as_deref(),
+            Iso15118Error::Pki { tag, .. } => Some(tag),
+            Iso15118Error::Unsupported { tag, .. } => Some(tag),
+        }
+    }
+
+    pub fn get_exi_code(&self) -> Option<ExiErrorCode> {
+        match self {
+            Iso15118Error::Exi { code, .. } => *code,
+            _ => None,
+        }
+    }
+
+    pub fn get_pki_status(&self) -> Option<PkiErrorStatus> {
+        match self {
+            Iso15118Error::Pki { code, .. } => *code,
+            _ => None,
+        }
+    }
+
+    pub fn get_uid(&self) -> &'static str {
+        match self {
+            Iso15118Error::Exi {
+                operation: ExiOperation::Encode,
+                ..
+            } => "exi-encode",
+            Iso15118Error::Exi {
+                operation: ExiOperation::Decode,
+                ..
+            } => "exi-decode",
+            Iso15118Error::Pki { .. } => "pki-sign",
+            Iso15118Error::Unsupported { .. } => "exi-unsupported",
+        }
+    }
+}
+
+impl fmt::Display for Iso15118Error {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Iso15118Error::Exi {
+                protocol,
+                operation,
+                status,
+                code,
+                byte_pos,
+                bit_pos,
+                tag,
+            } => write!(
+                format,
+                "fail to {:?} {:?} tag:{} error:{:?}({}) at byte:{} bit:{}",
+                operation,
+                protocol,
+                tag.as_deref().unwrap_or("unknown"),
+                code,
+                status,
+                byte_pos,
+                bit_pos
+            ),
+            Iso15118Error::Pki {
+                protocol,
+                status,
+                code,
+                tag,
+            } => write!(
+                format,
+                "{:?} tag:{} signature error:{}({})",
+                protocol,
+                tag,
+                code.as_ref().map_or("unknown", |value| value.to_label()),
+                status
+            ),
+            Iso15118Error::Unsupported { protocol, tag } => {
+                write!(
+                    format,
+                    "{:?} tag:{} does not implement operation",
+                    protocol, tag
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Iso15118Error {}
+
+/// Raw value received from the wire or from libiso15118 matches no enum variant
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExiEnumError {
+    pub name: &'static str,
+    pub value: i64,
+}
+
+impl ExiEnumError {
+    pub fn new(name: &'static str, value: i64) -> Self {
+        ExiEnumError { name, value }
+    }
+}
+
+impl fmt::Display for ExiEnumError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(format, "invalid {} value:{}", self.name, self.value)
+    }
+}
+
+impl std::error::Error for ExiEnumError {}
+
+impl From<ExiEnumError> for AfbError {
+    #[track_caller]
+    fn from(error: ExiEnumError) -> Self {
+        AfbError::new("exi-enum", error.to_string())
+    }
+}
+
+// TryFrom<repr> for fieldless enums deriving strum EnumIter, unknown values are rejected
+#[doc(hidden)]
+#[macro_export]
+macro_rules! exi_enum_try_from {
+    ($enum:ident, $repr:ty) => {
+        impl TryFrom<$repr> for $enum {
+            type Error = $crate::prelude::ExiEnumError;
+            fn try_from(value: $repr) -> Result<Self, Self::Error> {
+                match <$enum as ::strum::IntoEnumIterator>::iter()
+                    .find(|item| *item as $repr == value)
+                {
+                    Some(item) => Ok(item),
+                    None => Err($crate::prelude::ExiEnumError::new(
+                        stringify!($enum),
+                        value as i64,
+                    )),
+                }
+            }
+        }
+    };
+}
+
+impl From<Iso15118Error> for AfbError {
+    #[track_caller]
+    fn from(error: Iso15118Error) -> Self {
+        AfbError::new(error.get_uid(), error.to_string())
+    }
+}
@@ -0,0 +1,215 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: Debug/Display helpers shared by DIN/ISO-2/ISO-20 messages
+ *  - each type lists its fields once (ExiFields) with XSD element names
+ *  - Debug prints every field as a struct ({:#?} pretty prints)
+ *  - Display prints a compact single line {Name:value ...} for session logs
+ *  - optional elements are only printed when used, invalid values as invalid(error)
+ */
+use std::fmt;
+
+/// Element fields printed by both Debug and Display
+pub trait ExiFields {
+    fn exi_fields(&self, fields: &mut ExiFormatter);
+}
+
+enum ExiFormatMode<'a, 'b: 'a> {
+    Debug(fmt::DebugStruct<'a, 'b>),
+    Display {
+        format: &'a mut fmt::Formatter<'b>,
+        count: usize,
+        status: fmt::Result,
+    },
+}
+
+pub struct ExiFormatter<'a, 'b: 'a> {
+    mode: ExiFormatMode<'a, 'b>,
+}
+
+impl<'a, 'b: 'a> ExiFormatter<'a, 'b> {
+    pub fn debug(format: &'a mut fmt::Formatter<'b>, name: &str) -> Self {
+        Self {
+            mode: ExiFormatMode::Debug(format.debug_struct(name)),
+        }
+    }
+
+    pub fn display(format: &'a mut fmt::Formatter<'b>) -> Self {
+        let status = format.write_str("{");
+        Self {
+            mode: ExiFormatMode::Display {
+                format,
+                count: 0,
+                status,
+            },
+        }
+    }
+
+    pub fn field<T>(&mut self, name: &str, value: T) -> &mut Self
+    where
+        T: fmt::Debug + fmt::Display,
+    {
+        match &mut self.mode {
+            ExiFormatMode::Debug(debug) => {
+                debug.field(name, &value);
+            }
+            ExiFormatMode::Display {
+                format,
+                count,
+                status,
+            } => {
+                if status.is_ok() {
+                    let separator = if *count == 0 { "" } else { " " };
+                    *status = write!(format, "{}{}:{}", separator, name, value);
+                }
+                *count += 1;
+            }
+        }
+        self
+    }
+
+    pub fn option<T>(&mut self, name: &str, value: Option<T>) -> &mut Self
+    where
+        T: fmt::Debug + fmt::Display,
+    {
+        match value {
+            Some(value) => self.field(name, value),
+            None => self,
+        }
+    }
+
+    pub fn result<T, E>(&mut self, name: &str, value: Result<T, E>) -> &mut Self
+    where
+        T: fmt::Debug + fmt::Display,
+        E: fmt::Display,
+    {
+        match value {
+            Ok(value) => self.field(name, value),
+            Err(error) => self.field(name, format!("invalid({})", error)),
+        }
+    }
+
+    pub fn result_option<T, E>(&mut self, name: &str, value: Result<Option<T>, E>) -> &mut Self
+    where
+        T: fmt::Debug + fmt::Display,
+        E: fmt::Display,
+    {
+        match value {
+            Ok(value) => self.option(name, value),
+            Err(error) => self.field(name, format!("invalid({})", error)),
+        }
+    }
+
+    pub fn finish(&mut self) -> fmt::Result {
+        match &mut self.mode {
+            ExiFormatMode::Debug(debug) => debug.finish(),
+            ExiFormatMode::Display { format, status, .. } => {
+                (*status)?;
+                format.write_str("}")
+            }
+        }
+    }
+}
+
+/// Binary content (hexBinary/base64Binary) printed as hexadecimal
+pub struct ExiHexa<'a>(pub &'a [u8]);
+
+impl fmt::Debug for ExiHexa<'_> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(format, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ExiHexa<'_> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, format)
+    }
+}
+
+/// Repeated elements, Display prints them as [value value ...]
+pub struct ExiList<T>(pub Vec<T>);
+
+impl<T: fmt::Debug> fmt::Debug for ExiList<T> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        format.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ExiList<T> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        format.write_str("[")?;
+        for (idx, value) in self.0.iter().enumerate() {
+            if idx > 0 {
+                format.write_str(" ")?;
+            }
+            write!(format, "{}", value)?;
+        }
+        format.write_str("]")
+    }
+}
+
+/// Decimal text of value*10^multiplier, ex: (4005,-1) => "400.5" (4,3) => "4000"
+pub fn scaled_to_string(value: i16, multiplier: i8) -> String {
+    let mut digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if value == 0 {
+        return digits;
+    }
+    if multiplier >= 0 {
+        digits.push_str(&"0".repeat(multiplier as usize));
+        return format!("{}{}", sign, digits);
+    }
+
+    let decimals = multiplier.unsigned_abs() as usize;
+    if digits.len() <= decimals {
+        digits = format!("{}{}", "0".repeat(decimals + 1 - digits.len()), digits);
+    }
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{}{}", sign, integer)
+    } else {
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
+// Debug & Display $exi through its ExiFields implementation
+#[doc(hidden)]
+#[macro_export]
+macro_rules! exi_format {
+    ($($exi:ident),+ $(,)?) => {
+        $(
+            impl ::std::fmt::Debug for $exi {
+                fn fmt(&self, format: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    let mut fields = $crate::prelude::ExiFormatter::debug(format, stringify!($exi));
+                    $crate::prelude::ExiFields::exi_fields(self, &mut fields);
+                    fields.finish()
+                }
+            }
+
+            impl ::std::fmt::Display for $exi {
+                fn fmt(&self, format: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    let mut fields = $crate::prelude::ExiFormatter::display(format);
+                    $crate::prelude::ExiFields::exi_fields(self, &mut fields);
+                    fields.finish()
+                }
+            }
+        )+
+    };
+}
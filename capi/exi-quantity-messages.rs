@@ -0,0 +1,1076 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: typed quantity builders/getters for DC charging messages, ISO-2 AC
+ *  ParamDiscovery and ISO-20 AC ParamDiscovery/ChargeLoop power & energy elements
+ *  - from_quantities(): constructor taking Voltage/Current/Power/Energy/Duration
+ *  - set_xxx_quantity(): setter taking the quantity the element expects
+ *  - get_xxx_quantity(): getter returning the quantity, fails on unit mismatch
+ *  - raw PhysicalValue/RationalNumber builders and getters stay unchanged
+ */
+
+use crate::prelude::*;
+
+// one typed accessor per element, the quantity type fixes the unit at compile time
+//  - get: mandatory element, option: optional element
+//  - set: raw setter returns &mut Self, try_set: raw setter returns Result<&mut Self>
+//  - soc_get, soc_option, soc_set: DIN/ISO-2 SoC carried as a raw i8 percent
+macro_rules! exi_quantity_accessors {
+    (@get $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&self) -> Result<$quantity, AfbError> {
+            self.$raw().to_quantity()
+        }
+    };
+    (@option $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&self) -> Result<Option<$quantity>, AfbError> {
+            self.$raw().map(|value| value.to_quantity()).transpose()
+        }
+    };
+    (@set $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&mut self, value: $quantity) -> &mut Self {
+            self.$raw(&<$wire>::from(value))
+        }
+    };
+    (@try_set $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&mut self, value: $quantity) -> Result<&mut Self, AfbError> {
+            self.$raw(&<$wire>::from(value))
+        }
+    };
+    (@soc_get $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&self) -> Result<$quantity, AfbError> {
+            <$quantity>::try_from(self.$raw())
+        }
+    };
+    (@soc_option $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&self) -> Result<Option<$quantity>, AfbError> {
+            self.$raw().map(<$quantity>::try_from).transpose()
+        }
+    };
+    (@soc_set $wire:ty, $raw:ident, $typed:ident, $quantity:ty) => {
+        pub fn $typed(&mut self, value: $quantity) -> &mut Self {
+            self.$raw(<$wire>::from(value))
+        }
+    };
+    ($exi:ty, $wire:ty, { $($kind:ident $raw:ident => $typed:ident: $quantity:ty),* $(,)? }) => {
+        impl $exi {
+            $(exi_quantity_accessors!(@$kind $wire, $raw, $typed, $quantity);)*
+        }
+    };
+}
+
+// ---------------------------------------------------------------- DIN
+impl din_exi::DcEvStatusType {
+    pub fn from_quantities(ready: bool, error: din_exi::DcEvErrorCode, soc: Soc) -> Self {
+        Self::new(ready, error, soc.into())
+    }
+}
+exi_quantity_accessors!(din_exi::DcEvStatusType, i8, {
+    soc_get get_evress_soc => get_evress_soc_quantity: Soc,
+});
+
+impl din_exi::PreChargeRequest {
+    pub fn from_quantities(
+        ev_status: &din_exi::DcEvStatusType,
+        target_voltage: Voltage,
+        target_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(ev_status, &target_voltage.into(), &target_current.into())
+    }
+}
+exi_quantity_accessors!(din_exi::PreChargeRequest, din_exi::PhysicalValue, {
+    get get_target_voltage => get_target_voltage_quantity: Voltage,
+    get get_target_current => get_target_current_quantity: Current,
+});
+
+impl din_exi::PreChargeResponse {
+    pub fn from_quantities(
+        rcode: din_exi::ResponseCode,
+        evse_status: &din_exi::DcEvseStatusType,
+        evse_voltage: Voltage,
+    ) -> Result<Self, AfbError> {
+        Self::new(rcode, evse_status, &evse_voltage.into())
+    }
+}
+exi_quantity_accessors!(din_exi::PreChargeResponse, din_exi::PhysicalValue, {
+    get get_voltage => get_voltage_quantity: Voltage,
+});
+
+impl din_exi::CurrentDemandRequest {
+    pub fn from_quantities(
+        dc_status: &din_exi::DcEvStatusType,
+        current_target: Current,
+        voltage_target: Voltage,
+        charging_complete: bool,
+    ) -> Self {
+        Self::new(
+            dc_status,
+            &current_target.into(),
+            &voltage_target.into(),
+            charging_complete,
+        )
+    }
+}
+exi_quantity_accessors!(din_exi::CurrentDemandRequest, din_exi::PhysicalValue, {
+    get get_current_target => get_current_target_quantity: Current,
+    get get_voltage_target => get_voltage_target_quantity: Voltage,
+    option get_voltage_limit => get_voltage_limit_quantity: Voltage,
+    try_set set_voltage_limit => set_voltage_limit_quantity: Voltage,
+    option get_current_limit => get_current_limit_quantity: Current,
+    try_set set_current_limit => set_current_limit_quantity: Current,
+    option get_power_limit => get_power_limit_quantity: Power,
+    try_set set_power_limit => set_power_limit_quantity: Power,
+    option get_time_to_full_sock => get_time_to_full_sock_quantity: Duration,
+    try_set set_time_to_full_sock => set_time_to_full_sock_quantity: Duration,
+    option get_time_to_bulk_sock => get_time_to_bulk_sock_quantity: Duration,
+    try_set set_time_to_bulk_sock => set_time_to_bulk_sock_quantity: Duration,
+});
+
+impl din_exi::CurrentDemandResponse {
+    pub fn from_quantities(
+        rcode: din_exi::ResponseCode,
+        dc_status: &din_exi::DcEvseStatusType,
+        voltage_present: Voltage,
+        current_present: Current,
+        voltage_limit_reach: bool,
+        current_limit_reach: bool,
+        power_limit_reach: bool,
+    ) -> Result<Self, AfbError> {
+        Self::new(
+            rcode,
+            dc_status,
+            &voltage_present.into(),
+            &current_present.into(),
+            voltage_limit_reach,
+            current_limit_reach,
+            power_limit_reach,
+        )
+    }
+}
+exi_quantity_accessors!(din_exi::CurrentDemandResponse, din_exi::PhysicalValue, {
+    get get_voltage_present => get_voltage_present_quantity: Voltage,
+    get get_current_present => get_current_present_quantity: Current,
+    option get_voltage_limit => get_voltage_limit_quantity: Voltage,
+    try_set set_voltage_limit => set_voltage_limit_quantity: Voltage,
+    option get_current_limit => get_current_limit_quantity: Current,
+    try_set set_current_limit => set_current_limit_quantity: Current,
+    option get_power_limit => get_power_limit_quantity: Power,
+    try_set set_power_limit => set_power_limit_quantity: Power,
+});
+
+impl din_exi::WeldingDetectionResponse {
+    pub fn from_quantities(
+        rcode: din_exi::ResponseCode,
+        evse_status: &din_exi::DcEvseStatusType,
+        evse_voltage: Voltage,
+    ) -> Result<Self, AfbError> {
+        Self::new(rcode, evse_status, &evse_voltage.into())
+    }
+}
+exi_quantity_accessors!(din_exi::WeldingDetectionResponse, din_exi::PhysicalValue, {
+    get get_voltage => get_voltage_quantity: Voltage,
+});
+
+impl din_exi::DcEvChargeParam {
+    pub fn from_quantities(
+        status: &din_exi::DcEvStatusType,
+        max_voltage: Voltage,
+        max_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(status, &max_voltage.into(), &max_current.into())
+    }
+}
+exi_quantity_accessors!(din_exi::DcEvChargeParam, din_exi::PhysicalValue, {
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+    option get_max_power => get_max_power_quantity: Power,
+    try_set set_max_power => set_max_power_quantity: Power,
+    option get_energy_capacity => get_energy_capacity_quantity: Energy,
+    try_set set_energy_capacity => set_energy_capacity_quantity: Energy,
+    option get_energy_request => get_energy_request_quantity: Energy,
+    try_set set_energy_request => set_energy_request_quantity: Energy,
+});
+exi_quantity_accessors!(din_exi::DcEvChargeParam, i8, {
+    soc_option get_bulk_soc => get_bulk_soc_quantity: Soc,
+    soc_set set_bulk_soc => set_bulk_soc_quantity: Soc,
+    soc_option get_full_soc => get_full_soc_quantity: Soc,
+    soc_set set_full_soc => set_full_soc_quantity: Soc,
+});
+
+impl din_exi::DcEvseChargeParam {
+    pub fn from_quantities(
+        status: &din_exi::DcEvseStatusType,
+        max_voltage: Voltage,
+        min_voltage: Voltage,
+        max_current: Current,
+        min_current: Current,
+        current_ripple: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(
+            status,
+            &max_voltage.into(),
+            &min_voltage.into(),
+            &max_current.into(),
+            &min_current.into(),
+            &current_ripple.into(),
+        )
+    }
+}
+exi_quantity_accessors!(din_exi::DcEvseChargeParam, din_exi::PhysicalValue, {
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_min_voltage => get_min_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+    get get_min_current => get_min_current_quantity: Current,
+    option get_max_power => get_max_power_quantity: Power,
+    try_set set_max_power => set_max_power_quantity: Power,
+    option get_regul_tolerance => get_regul_tolerance_quantity: Current,
+    try_set set_regul_tolerance => set_regul_tolerance_quantity: Current,
+    option get_energy_to_deliver => get_energy_to_deliver_quantity: Energy,
+    try_set set_energy_to_deliver => set_energy_to_deliver_quantity: Energy,
+    get get_peak_current_ripple => get_peak_current_ripple_quantity: Current,
+});
+
+// ---------------------------------------------------------------- ISO-2
+impl iso2_exi::DcEvStatusType {
+    pub fn from_quantities(ready: bool, error: iso2_exi::DcEvErrorCode, soc: Soc) -> Self {
+        Self::new(ready, error, soc.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::DcEvStatusType, i8, {
+    soc_get get_evresssoc => get_evresssoc_quantity: Soc,
+});
+
+impl iso2_exi::PreChargeRequest {
+    pub fn from_quantities(
+        ev_status: &iso2_exi::DcEvStatusType,
+        target_voltage: Voltage,
+        target_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(ev_status, &target_voltage.into(), &target_current.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::PreChargeRequest, iso2_exi::PhysicalValue, {
+    get get_target_voltage => get_target_voltage_quantity: Voltage,
+    get get_target_current => get_target_current_quantity: Current,
+});
+
+impl iso2_exi::PreChargeResponse {
+    pub fn from_quantities(
+        rcode: iso2_exi::ResponseCode,
+        evse_status: &iso2_exi::DcEvseStatusType,
+        evse_voltage: Voltage,
+    ) -> Result<Self, AfbError> {
+        Self::new(rcode, evse_status, &evse_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::PreChargeResponse, iso2_exi::PhysicalValue, {
+    get get_voltage => get_voltage_quantity: Voltage,
+});
+
+impl iso2_exi::CurrentDemandRequest {
+    pub fn from_quantities(
+        dc_status: &iso2_exi::DcEvStatusType,
+        current_target: Current,
+        voltage_target: Voltage,
+        charging_complete: bool,
+    ) -> Self {
+        Self::new(
+            dc_status,
+            &current_target.into(),
+            &voltage_target.into(),
+            charging_complete,
+        )
+    }
+}
+exi_quantity_accessors!(iso2_exi::CurrentDemandRequest, iso2_exi::PhysicalValue, {
+    get get_current_target => get_current_target_quantity: Current,
+    get get_voltage_target => get_voltage_target_quantity: Voltage,
+    option get_voltage_limit => get_voltage_limit_quantity: Voltage,
+    try_set set_voltage_limit => set_voltage_limit_quantity: Voltage,
+    option get_current_limit => get_current_limit_quantity: Current,
+    try_set set_current_limit => set_current_limit_quantity: Current,
+    option get_power_limit => get_power_limit_quantity: Power,
+    try_set set_power_limit => set_power_limit_quantity: Power,
+    option get_time_to_full_sock => get_time_to_full_sock_quantity: Duration,
+    try_set set_time_to_full_sock => set_time_to_full_sock_quantity: Duration,
+    option get_time_to_bulk_sock => get_time_to_bulk_sock_quantity: Duration,
+    try_set set_time_to_bulk_sock => set_time_to_bulk_sock_quantity: Duration,
+});
+
+impl iso2_exi::CurrentDemandResponse {
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_quantities(
+        rcode: iso2_exi::ResponseCode,
+        evse_id: &str,
+        dc_status: &iso2_exi::DcEvseStatusType,
+        current: Current,
+        current_limit: bool,
+        voltage: Voltage,
+        voltage_limit: bool,
+        power_limit: bool,
+        schd_tuple_id: u8,
+    ) -> Result<Self, AfbError> {
+        Self::new(
+            rcode,
+            evse_id,
+            dc_status,
+            &current.into(),
+            current_limit,
+            &voltage.into(),
+            voltage_limit,
+            power_limit,
+            schd_tuple_id,
+        )
+    }
+}
+exi_quantity_accessors!(iso2_exi::CurrentDemandResponse, iso2_exi::PhysicalValue, {
+    get get_voltage_present => get_voltage_present_quantity: Voltage,
+    get get_current_present => get_current_present_quantity: Current,
+    option get_voltage_limit => get_voltage_limit_quantity: Voltage,
+    try_set set_voltage_limit => set_voltage_limit_quantity: Voltage,
+    option get_current_limit => get_current_limit_quantity: Current,
+    try_set set_current_limit => set_current_limit_quantity: Current,
+    option get_power_limit => get_power_limit_quantity: Power,
+    try_set set_power_limit => set_power_limit_quantity: Power,
+});
+
+impl iso2_exi::WeldingDetectionResponse {
+    pub fn from_quantities(
+        rcode: iso2_exi::ResponseCode,
+        evse_status: &iso2_exi::DcEvseStatusType,
+        evse_voltage: Voltage,
+    ) -> Result<Self, AfbError> {
+        Self::new(rcode, evse_status, &evse_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::WeldingDetectionResponse, iso2_exi::PhysicalValue, {
+    get get_voltage => get_voltage_quantity: Voltage,
+});
+
+impl iso2_exi::DcEvChargeParam {
+    pub fn from_quantities(
+        status: &iso2_exi::DcEvStatusType,
+        max_voltage: Voltage,
+        max_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(status, &max_voltage.into(), &max_current.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::DcEvChargeParam, iso2_exi::PhysicalValue, {
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+    option get_max_power => get_max_power_quantity: Power,
+    try_set set_max_power => set_max_power_quantity: Power,
+    option get_energy_capacity => get_energy_capacity_quantity: Energy,
+    try_set set_energy_capacity => set_energy_capacity_quantity: Energy,
+    option get_energy_request => get_energy_request_quantity: Energy,
+    try_set set_energy_request => set_energy_request_quantity: Energy,
+});
+exi_quantity_accessors!(iso2_exi::DcEvChargeParam, i8, {
+    soc_option get_bulk_soc => get_bulk_soc_quantity: Soc,
+    soc_set set_bulk_soc => set_bulk_soc_quantity: Soc,
+    soc_option get_full_soc => get_full_soc_quantity: Soc,
+    soc_set set_full_soc => set_full_soc_quantity: Soc,
+});
+
+impl iso2_exi::DcEvseChargeParam {
+    pub fn from_quantities(
+        status: &iso2_exi::DcEvseStatusType,
+        max_voltage: Voltage,
+        min_voltage: Voltage,
+        max_current: Current,
+        min_current: Current,
+        max_power: Power,
+        current_ripple: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(
+            status,
+            &max_voltage.into(),
+            &min_voltage.into(),
+            &max_current.into(),
+            &min_current.into(),
+            &max_power.into(),
+            &current_ripple.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso2_exi::DcEvseChargeParam, iso2_exi::PhysicalValue, {
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_min_voltage => get_min_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+    get get_min_current => get_min_current_quantity: Current,
+    get get_max_power => get_max_power_quantity: Power,
+    option get_regul_tolerance => get_regul_tolerance_quantity: Current,
+    try_set set_regul_tolerance => set_regul_tolerance_quantity: Current,
+    option get_energy_to_deliver => get_energy_to_deliver_quantity: Energy,
+    try_set set_energy_to_deliver => set_energy_to_deliver_quantity: Energy,
+    get get_peak_current_ripple => get_peak_current_ripple_quantity: Current,
+});
+
+impl iso2_exi::AcEvChargeParam {
+    pub fn from_quantities(
+        ea_mount: Energy,
+        max_voltage: Voltage,
+        max_current: Current,
+        min_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(
+            &ea_mount.into(),
+            &max_voltage.into(),
+            &max_current.into(),
+            &min_current.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso2_exi::AcEvChargeParam, iso2_exi::PhysicalValue, {
+    get get_ea_mount => get_ea_mount_quantity: Energy,
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+    get get_min_current => get_min_current_quantity: Current,
+});
+
+impl iso2_exi::AcEvseChargeParam {
+    pub fn from_quantities(
+        status: &iso2_exi::AcEvseStatusType,
+        nominate_voltage: Voltage,
+        max_current: Current,
+    ) -> Result<Self, AfbError> {
+        Self::new(status, &nominate_voltage.into(), &max_current.into())
+    }
+}
+exi_quantity_accessors!(iso2_exi::AcEvseChargeParam, iso2_exi::PhysicalValue, {
+    get get_nominate_voltage => get_nominate_voltage_quantity: Voltage,
+    get get_max_current => get_max_current_quantity: Current,
+});
+
+// ---------------------------------------------------------------- ISO-20 DC
+impl iso20_dc_exi::DcPreChargeRequest {
+    pub fn from_quantities(
+        processing: iso20_exi::EvseProcessing,
+        present_voltage: Voltage,
+        target_voltage: Voltage,
+    ) -> Self {
+        Self::new(processing, &present_voltage.into(), &target_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DcPreChargeRequest, iso20_exi::RationalNumber, {
+    get get_present_voltage => get_present_voltage_quantity: Voltage,
+    get get_target_voltage => get_target_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::DcPreChargeResponse {
+    pub fn from_quantities(code: iso20_exi::ResponseCode, present_voltage: Voltage) -> Self {
+        Self::new(code, &present_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DcPreChargeResponse, iso20_exi::RationalNumber, {
+    get get_present_voltage => get_present_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::DcWeldingDetectionResponse {
+    pub fn from_quantities(code: iso20_exi::ResponseCode, present_voltage: Voltage) -> Self {
+        Self::new(code, &present_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DcWeldingDetectionResponse, iso20_exi::RationalNumber, {
+    get get_present_voltage => get_present_voltage_quantity: Voltage,
+});
+
+// ChargeParameterDiscovery energy transfer modes share the same mandatory limits
+macro_rules! iso20_dc_transfer_quantities {
+    ($transfer:ty) => {
+        impl $transfer {
+            pub fn from_quantities(
+                max_charge_power: Power,
+                min_charge_power: Power,
+                max_charge_current: Current,
+                min_charge_current: Current,
+                max_voltage: Voltage,
+                min_voltage: Voltage,
+            ) -> Self {
+                Self::new(
+                    &max_charge_power.into(),
+                    &min_charge_power.into(),
+                    &max_charge_current.into(),
+                    &min_charge_current.into(),
+                    &max_voltage.into(),
+                    &min_voltage.into(),
+                )
+            }
+        }
+        exi_quantity_accessors!($transfer, iso20_exi::RationalNumber, {
+            get get_max_charge_power => get_max_charge_power_quantity: Power,
+            get get_min_charge_power => get_min_charge_power_quantity: Power,
+            get get_max_charge_current => get_max_charge_current_quantity: Current,
+            get get_min_charge_current => get_min_charge_current_quantity: Current,
+            get get_max_voltage => get_max_voltage_quantity: Voltage,
+            get get_min_voltage => get_min_voltage_quantity: Voltage,
+        });
+    };
+}
+
+iso20_dc_transfer_quantities!(iso20_dc_exi::DcCpdReqEnergyTransfer);
+iso20_dc_transfer_quantities!(iso20_dc_exi::DcCpdResEnergyTransfer);
+iso20_dc_transfer_quantities!(iso20_dc_exi::BptDcCpdReqEnergyTransfer);
+iso20_dc_transfer_quantities!(iso20_dc_exi::BptDcCpdResEnergyTransfer);
+
+exi_quantity_accessors!(iso20_dc_exi::BptDcCpdReqEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    get get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+    get get_min_discharge_current => get_min_discharge_current_quantity: Current,
+    set set_min_discharge_current => set_min_discharge_current_quantity: Current,
+});
+
+exi_quantity_accessors!(iso20_dc_exi::BptDcCpdResEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    get get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+    get get_min_discharge_current => get_min_discharge_current_quantity: Current,
+    set set_min_discharge_current => set_min_discharge_current_quantity: Current,
+});
+
+impl iso20_dc_exi::DynamicDcClReqMode {
+    pub fn from_quantities(target_energy: Energy, max_energy: Energy, min_energy: Energy) -> Self {
+        Self::new(
+            &target_energy.into(),
+            &max_energy.into(),
+            &min_energy.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DynamicDcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_energy => get_target_energy_quantity: Energy,
+    get get_max_energy => get_max_energy_quantity: Energy,
+    get get_min_energy => get_min_energy_quantity: Energy,
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    get get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+    get get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::ScheduledDcClReqMode {
+    pub fn from_quantities(target_current: Current, target_voltage: Voltage) -> Self {
+        Self::new(&target_current.into(), &target_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::ScheduledDcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_current => get_target_current_quantity: Current,
+    get get_target_voltage => get_target_voltage_quantity: Voltage,
+    option get_target_energy => get_target_energy_quantity: Energy,
+    set set_target_energy => set_target_energy_quantity: Energy,
+    option get_max_energy => get_max_energy_quantity: Energy,
+    set set_max_energy => set_max_energy_quantity: Energy,
+    option get_min_energy => get_min_energy_quantity: Energy,
+    set set_min_energy => set_min_energy_quantity: Energy,
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    option get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+    option get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::BptDynamicDcClReqMode {
+    pub fn from_quantities(target_energy: Energy, max_energy: Energy, min_energy: Energy) -> Self {
+        Self::new(
+            &target_energy.into(),
+            &max_energy.into(),
+            &min_energy.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::BptDynamicDcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_energy => get_target_energy_quantity: Energy,
+    get get_max_energy => get_max_energy_quantity: Energy,
+    get get_min_energy => get_min_energy_quantity: Energy,
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    get get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+    get get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    get get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+    option get_max_v2x_energy => get_max_v2x_energy_quantity: Energy,
+    set set_max_v2x_energy => set_max_v2x_energy_quantity: Energy,
+    option get_min_v2x_energy => get_min_v2x_energy_quantity: Energy,
+    set set_min_v2x_energy => set_min_v2x_energy_quantity: Energy,
+});
+
+impl iso20_dc_exi::BptScheduledDcClReqMode {
+    pub fn from_quantities(target_current: Current, target_voltage: Voltage) -> Self {
+        Self::new(&target_current.into(), &target_voltage.into())
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::BptScheduledDcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_current => get_target_current_quantity: Current,
+    get get_target_voltage => get_target_voltage_quantity: Voltage,
+    option get_target_energy => get_target_energy_quantity: Energy,
+    set set_target_energy => set_target_energy_quantity: Energy,
+    option get_max_energy => get_max_energy_quantity: Energy,
+    set set_max_energy => set_max_energy_quantity: Energy,
+    option get_min_energy => get_min_energy_quantity: Energy,
+    set set_min_energy => set_min_energy_quantity: Energy,
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    option get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+    option get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+    option get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    option get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+});
+
+impl iso20_dc_exi::DcChargeLoopRequest {
+    pub fn from_quantities(
+        present_voltage: Voltage,
+        mode: &iso20_dc_exi::DcClReqControlMode,
+    ) -> Self {
+        Self::new(&present_voltage.into(), mode)
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DcChargeLoopRequest, iso20_exi::RationalNumber, {
+    get get_present_voltage => get_present_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::DynamicDcClResMode {
+    pub fn from_quantities(
+        max_charge_power: Power,
+        min_charge_power: Power,
+        max_charge_current: Current,
+        max_voltage: Voltage,
+    ) -> Self {
+        Self::new(
+            &max_charge_power.into(),
+            &min_charge_power.into(),
+            &max_charge_current.into(),
+            &max_voltage.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DynamicDcClResMode, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_max_charge_current => get_max_charge_current_quantity: Current,
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+});
+
+exi_quantity_accessors!(iso20_dc_exi::ScheduledDcClResMode, iso20_exi::RationalNumber, {
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    option get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::BptDynamicDcClResMode {
+    pub fn from_quantities(
+        max_charge_power: Power,
+        min_charge_power: Power,
+        max_charge_current: Current,
+        max_voltage: Voltage,
+    ) -> Self {
+        Self::new(
+            &max_charge_power.into(),
+            &min_charge_power.into(),
+            &max_charge_current.into(),
+            &max_voltage.into(),
+        )
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::BptDynamicDcClResMode, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_max_charge_current => get_max_charge_current_quantity: Current,
+    get get_max_voltage => get_max_voltage_quantity: Voltage,
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    get get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+    get get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+});
+
+exi_quantity_accessors!(iso20_dc_exi::BptScheduledDcClResMode, iso20_exi::RationalNumber, {
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_max_charge_current => get_max_charge_current_quantity: Current,
+    set set_max_charge_current => set_max_charge_current_quantity: Current,
+    option get_max_voltage => get_max_voltage_quantity: Voltage,
+    set set_max_voltage => set_max_voltage_quantity: Voltage,
+    option get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    option get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_max_discharge_current => get_max_discharge_current_quantity: Current,
+    set set_max_discharge_current => set_max_discharge_current_quantity: Current,
+    option get_min_voltage => get_min_voltage_quantity: Voltage,
+    set set_min_voltage => set_min_voltage_quantity: Voltage,
+});
+
+impl iso20_dc_exi::DcChargeLoopResponse {
+    pub fn from_quantities(
+        code: iso20_exi::ResponseCode,
+        present_current: Current,
+        present_voltage: Voltage,
+        mode: &iso20_dc_exi::DcClResControlMode,
+    ) -> Self {
+        Self::new(code, &present_current.into(), &present_voltage.into(), mode)
+    }
+}
+exi_quantity_accessors!(iso20_dc_exi::DcChargeLoopResponse, iso20_exi::RationalNumber, {
+    get get_present_current => get_present_current_quantity: Current,
+    get get_present_voltage => get_present_voltage_quantity: Voltage,
+});
+
+// ---------------------------------------------------------------- ISO-20 AC
+// reactive power (var), frequency, asymmetry & ramp limit elements have no quantity, they stay raw
+impl iso20_ac_exi::AcCpdReqEnergyTransfer {
+    pub fn from_quantities(max_charge_power: Power, min_charge_power: Power) -> Self {
+        Self::new(&max_charge_power.into(), &min_charge_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::AcCpdReqEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::AcCpdResEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+impl iso20_ac_exi::BptAcCpdReqEnergyTransfer {
+    pub fn from_quantities(max_charge_power: Power, min_charge_power: Power) -> Self {
+        Self::new(&max_charge_power.into(), &min_charge_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::BptAcCpdReqEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_max_discharge_power_l2 => get_max_discharge_power_l2_quantity: Power,
+    set set_max_discharge_power_l2 => set_max_discharge_power_l2_quantity: Power,
+    option get_max_discharge_power_l3 => get_max_discharge_power_l3_quantity: Power,
+    set set_max_discharge_power_l3 => set_max_discharge_power_l3_quantity: Power,
+    option get_min_discharge_power_l2 => get_min_discharge_power_l2_quantity: Power,
+    set set_min_discharge_power_l2 => set_min_discharge_power_l2_quantity: Power,
+    option get_min_discharge_power_l3 => get_min_discharge_power_l3_quantity: Power,
+    set set_min_discharge_power_l3 => set_min_discharge_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::BptAcCpdResEnergyTransfer, iso20_exi::RationalNumber, {
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_max_discharge_power_l2 => get_max_discharge_power_l2_quantity: Power,
+    set set_max_discharge_power_l2 => set_max_discharge_power_l2_quantity: Power,
+    option get_max_discharge_power_l3 => get_max_discharge_power_l3_quantity: Power,
+    set set_max_discharge_power_l3 => set_max_discharge_power_l3_quantity: Power,
+    option get_min_discharge_power_l2 => get_min_discharge_power_l2_quantity: Power,
+    set set_min_discharge_power_l2 => set_min_discharge_power_l2_quantity: Power,
+    option get_min_discharge_power_l3 => get_min_discharge_power_l3_quantity: Power,
+    set set_min_discharge_power_l3 => set_min_discharge_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::DynamicAcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_energy => get_target_energy_quantity: Energy,
+    get get_max_energy => get_max_energy_quantity: Energy,
+    get get_min_energy => get_min_energy_quantity: Energy,
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_present_active_power => get_present_active_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+impl iso20_ac_exi::ScheduledAcClReqMode {
+    pub fn from_quantities(present_active_power: Power) -> Self {
+        Self::new(&present_active_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::ScheduledAcClReqMode, iso20_exi::RationalNumber, {
+    get get_present_active_power => get_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+    option get_target_energy => get_target_energy_quantity: Energy,
+    set set_target_energy => set_target_energy_quantity: Energy,
+    option get_max_energy => get_max_energy_quantity: Energy,
+    set set_max_energy => set_max_energy_quantity: Energy,
+    option get_min_energy => get_min_energy_quantity: Energy,
+    set set_min_energy => set_min_energy_quantity: Energy,
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::BptDynamicAcClReqMode, iso20_exi::RationalNumber, {
+    get get_target_energy => get_target_energy_quantity: Energy,
+    get get_max_energy => get_max_energy_quantity: Energy,
+    get get_min_energy => get_min_energy_quantity: Energy,
+    get get_max_charge_power => get_max_charge_power_quantity: Power,
+    get get_min_charge_power => get_min_charge_power_quantity: Power,
+    get get_present_active_power => get_present_active_power_quantity: Power,
+    get get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    get get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+    option get_max_discharge_power_l2 => get_max_discharge_power_l2_quantity: Power,
+    set set_max_discharge_power_l2 => set_max_discharge_power_l2_quantity: Power,
+    option get_max_discharge_power_l3 => get_max_discharge_power_l3_quantity: Power,
+    set set_max_discharge_power_l3 => set_max_discharge_power_l3_quantity: Power,
+    option get_min_discharge_power_l2 => get_min_discharge_power_l2_quantity: Power,
+    set set_min_discharge_power_l2 => set_min_discharge_power_l2_quantity: Power,
+    option get_min_discharge_power_l3 => get_min_discharge_power_l3_quantity: Power,
+    set set_min_discharge_power_l3 => set_min_discharge_power_l3_quantity: Power,
+    option get_max_v2x_energy => get_max_v2x_energy_quantity: Energy,
+    set set_max_v2x_energy => set_max_v2x_energy_quantity: Energy,
+    option get_min_v2x_energy => get_min_v2x_energy_quantity: Energy,
+    set set_min_v2x_energy => set_min_v2x_energy_quantity: Energy,
+});
+
+impl iso20_ac_exi::BptScheduledAcClReqMode {
+    pub fn from_quantities(present_active_power: Power) -> Self {
+        Self::new(&present_active_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::BptScheduledAcClReqMode, iso20_exi::RationalNumber, {
+    get get_present_active_power => get_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+    option get_target_energy => get_target_energy_quantity: Energy,
+    set set_target_energy => set_target_energy_quantity: Energy,
+    option get_max_energy => get_max_energy_quantity: Energy,
+    set set_max_energy => set_max_energy_quantity: Energy,
+    option get_min_energy => get_min_energy_quantity: Energy,
+    set set_min_energy => set_min_energy_quantity: Energy,
+    option get_max_charge_power => get_max_charge_power_quantity: Power,
+    set set_max_charge_power => set_max_charge_power_quantity: Power,
+    option get_max_charge_power_l2 => get_max_charge_power_l2_quantity: Power,
+    set set_max_charge_power_l2 => set_max_charge_power_l2_quantity: Power,
+    option get_max_charge_power_l3 => get_max_charge_power_l3_quantity: Power,
+    set set_max_charge_power_l3 => set_max_charge_power_l3_quantity: Power,
+    option get_min_charge_power => get_min_charge_power_quantity: Power,
+    set set_min_charge_power => set_min_charge_power_quantity: Power,
+    option get_min_charge_power_l2 => get_min_charge_power_l2_quantity: Power,
+    set set_min_charge_power_l2 => set_min_charge_power_l2_quantity: Power,
+    option get_min_charge_power_l3 => get_min_charge_power_l3_quantity: Power,
+    set set_min_charge_power_l3 => set_min_charge_power_l3_quantity: Power,
+    option get_max_discharge_power => get_max_discharge_power_quantity: Power,
+    set set_max_discharge_power => set_max_discharge_power_quantity: Power,
+    option get_max_discharge_power_l2 => get_max_discharge_power_l2_quantity: Power,
+    set set_max_discharge_power_l2 => set_max_discharge_power_l2_quantity: Power,
+    option get_max_discharge_power_l3 => get_max_discharge_power_l3_quantity: Power,
+    set set_max_discharge_power_l3 => set_max_discharge_power_l3_quantity: Power,
+    option get_min_discharge_power => get_min_discharge_power_quantity: Power,
+    set set_min_discharge_power => set_min_discharge_power_quantity: Power,
+    option get_min_discharge_power_l2 => get_min_discharge_power_l2_quantity: Power,
+    set set_min_discharge_power_l2 => set_min_discharge_power_l2_quantity: Power,
+    option get_min_discharge_power_l3 => get_min_discharge_power_l3_quantity: Power,
+    set set_min_discharge_power_l3 => set_min_discharge_power_l3_quantity: Power,
+});
+
+impl iso20_ac_exi::DynamicAcClResMode {
+    pub fn from_quantities(target_active_power: Power) -> Self {
+        Self::new(&target_active_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::DynamicAcClResMode, iso20_exi::RationalNumber, {
+    get get_target_active_power => get_target_active_power_quantity: Power,
+    option get_target_active_power_l2 => get_target_active_power_l2_quantity: Power,
+    set set_target_active_power_l2 => set_target_active_power_l2_quantity: Power,
+    option get_target_active_power_l3 => get_target_active_power_l3_quantity: Power,
+    set set_target_active_power_l3 => set_target_active_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::ScheduledAcClResMode, iso20_exi::RationalNumber, {
+    option get_target_active_power => get_target_active_power_quantity: Power,
+    set set_target_active_power => set_target_active_power_quantity: Power,
+    option get_target_active_power_l2 => get_target_active_power_l2_quantity: Power,
+    set set_target_active_power_l2 => set_target_active_power_l2_quantity: Power,
+    option get_target_active_power_l3 => get_target_active_power_l3_quantity: Power,
+    set set_target_active_power_l3 => set_target_active_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+impl iso20_ac_exi::BptDynamicAcClResMode {
+    pub fn from_quantities(target_active_power: Power) -> Self {
+        Self::new(&target_active_power.into())
+    }
+}
+exi_quantity_accessors!(iso20_ac_exi::BptDynamicAcClResMode, iso20_exi::RationalNumber, {
+    get get_target_active_power => get_target_active_power_quantity: Power,
+    option get_target_active_power_l2 => get_target_active_power_l2_quantity: Power,
+    set set_target_active_power_l2 => set_target_active_power_l2_quantity: Power,
+    option get_target_active_power_l3 => get_target_active_power_l3_quantity: Power,
+    set set_target_active_power_l3 => set_target_active_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
+
+exi_quantity_accessors!(iso20_ac_exi::BptScheduledAcClResMode, iso20_exi::RationalNumber, {
+    option get_target_active_power => get_target_active_power_quantity: Power,
+    set set_target_active_power => set_target_active_power_quantity: Power,
+    option get_target_active_power_l2 => get_target_active_power_l2_quantity: Power,
+    set set_target_active_power_l2 => set_target_active_power_l2_quantity: Power,
+    option get_target_active_power_l3 => get_target_active_power_l3_quantity: Power,
+    set set_target_active_power_l3 => set_target_active_power_l3_quantity: Power,
+    option get_present_active_power => get_present_active_power_quantity: Power,
+    set set_present_active_power => set_present_active_power_quantity: Power,
+    option get_present_active_power_l2 => get_present_active_power_l2_quantity: Power,
+    set set_present_active_power_l2 => set_present_active_power_l2_quantity: Power,
+    option get_present_active_power_l3 => get_present_active_power_l3_quantity: Power,
+    set set_present_active_power_l3 => set_present_active_power_l3_quantity: Power,
+});
@@ -0,0 +1,333 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: protocol independent physical quantities (Voltage, Current, Power, Energy, Duration, Soc)
+ *  - DIN PhysicalValue: value*10^multiplier with an optional unit
+ *  - ISO-2 PhysicalValue: value*10^multiplier with a mandatory unit
+ *  - ISO-20 RationalNumber: value*10^exponent, unit is implied by the element
+ *  - quantities keep the wire (value, multiplier) and check units when read from DIN/ISO-2
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §8.5.2.7 PhysicalValueType
+ *   ISO 15118-20:2022 §8.3.5.3.10 RationalNumberType
+ */
+
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Quantity stored as value*10^multiplier within its reference unit
+pub trait ExiQuantity: Copy {
+    /// unit symbol used by Display, ex: "V"
+    const SYMBOL: &'static str;
+    const ISO2_UNIT: iso2_exi::PhysicalUnit;
+    const DIN_UNIT: din_exi::PhysicalUnit;
+
+    fn from_scaled(value: i16, multiplier: i8) -> Self;
+    fn get_scaled(&self) -> (i16, i8);
+}
+
+/// Wire representation of a quantity: DIN/ISO-2 PhysicalValue or ISO-20 RationalNumber.
+/// Reading checks the unit when the protocol carries one, writing never fails.
+pub trait ExiQuantityValue: Sized {
+    fn to_quantity<Q: ExiQuantity>(&self) -> Result<Q, AfbError>;
+    fn from_quantity<Q: ExiQuantity>(quantity: &Q) -> Self;
+}
+
+// unit scale to the quantity reference unit, hour/minute are only valid for durations
+#[track_caller]
+fn scaled_from_unit<Q: ExiQuantity>(
+    value: i16,
+    multiplier: i8,
+    scale: Option<i128>,
+    unit: &dyn fmt::Display,
+) -> Result<Q, AfbError> {
+    match scale {
+        Some(1) => Ok(Q::from_scaled(value, multiplier)),
+        Some(scale) => {
+            let (value, multiplier) = scaled_fit(value as i128 * scale, multiplier as i32)?;
+            Ok(Q::from_scaled(value, multiplier))
+        }
+        None => afb_error!(
+            "exi-quantity-unit",
+            "expect unit:{} get:{}",
+            Q::SYMBOL,
+            unit
+        ),
+    }
+}
+
+impl ExiQuantityValue for iso2_exi::PhysicalValue {
+    #[track_caller]
+    fn to_quantity<Q: ExiQuantity>(&self) -> Result<Q, AfbError> {
+        let unit = self.get_unit()?;
+        let duration = Q::ISO2_UNIT == iso2_exi::PhysicalUnit::Second;
+        let scale = match unit {
+            _ if unit == Q::ISO2_UNIT => Some(1),
+            iso2_exi::PhysicalUnit::Minute if duration => Some(60),
+            iso2_exi::PhysicalUnit::Hour if duration => Some(3600),
+            _ => None,
+        };
+        scaled_from_unit::<Q>(self.get_value(), self.get_multiplier(), scale, &unit)
+    }
+
+    fn from_quantity<Q: ExiQuantity>(quantity: &Q) -> Self {
+        let (value, multiplier) = quantity.get_scaled();
+        iso2_exi::PhysicalValue::new(value, multiplier, Q::ISO2_UNIT)
+    }
+}
+
+impl ExiQuantityValue for din_exi::PhysicalValue {
+    // DIN unit is optional, when missing the element defines it
+    #[track_caller]
+    fn to_quantity<Q: ExiQuantity>(&self) -> Result<Q, AfbError> {
+        let unit = match self.get_unit()? {
+            None | Some(din_exi::PhysicalUnit::Unset) => Q::DIN_UNIT,
+            Some(unit) => unit,
+        };
+        let duration = Q::DIN_UNIT == din_exi::PhysicalUnit::Second;
+        let scale = match unit {
+            _ if unit == Q::DIN_UNIT => Some(1),
+            din_exi::PhysicalUnit::Minute if duration => Some(60),
+            din_exi::PhysicalUnit::Hour if duration => Some(3600),
+            _ => None,
+        };
+        scaled_from_unit::<Q>(self.get_value(), self.get_multiplier(), scale, &unit)
+    }
+
+    fn from_quantity<Q: ExiQuantity>(quantity: &Q) -> Self {
+        let (value, multiplier) = quantity.get_scaled();
+        din_exi::PhysicalValue::new(value, multiplier, Q::DIN_UNIT)
+    }
+}
+
+impl ExiQuantityValue for iso20_exi::RationalNumber {
+    // ISO-20 exponent is a full i8, refit it within DIN/ISO-2 -3..3 multiplier range
+    #[track_caller]
+    fn to_quantity<Q: ExiQuantity>(&self) -> Result<Q, AfbError> {
+        let (value, exponent) = (self.get_value(), self.get_exponent());
+        if (SCALED_MULTIPLIER_MIN..=SCALED_MULTIPLIER_MAX).contains(&exponent) {
+            return Ok(Q::from_scaled(value, exponent));
+        }
+        let (value, multiplier) = scaled_fit(value as i128, exponent as i32)?;
+        Ok(Q::from_scaled(value, multiplier))
+    }
+
+    fn from_quantity<Q: ExiQuantity>(quantity: &Q) -> Self {
+        let (value, exponent) = quantity.get_scaled();
+        iso20_exi::RationalNumber::new(value, exponent)
+    }
+}
+
+macro_rules! exi_quantity {
+    ($quantity:ident, $symbol:literal, $unit:ident) => {
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $quantity {
+            value: i16,
+            multiplier: i8,
+        }
+
+        impl $quantity {
+            pub fn new(value: i16, multiplier: i8) -> Self {
+                Self { value, multiplier }
+            }
+
+            /// Best precision value/multiplier (-3..3) for a float
+            #[track_caller]
+            pub fn from_f64(value: f64) -> Result<Self, AfbError> {
+                let (value, multiplier) = scaled_from_f64(value)?;
+                Ok(Self::new(value, multiplier))
+            }
+
+            #[track_caller]
+            pub fn from_decimal(text: &str) -> Result<Self, AfbError> {
+                let (value, multiplier) = scaled_from_decimal(text)?;
+                Ok(Self::new(value, multiplier))
+            }
+
+            pub fn get_value(&self) -> i16 {
+                self.value
+            }
+
+            pub fn get_multiplier(&self) -> i8 {
+                self.multiplier
+            }
+
+            pub fn to_f64(self) -> f64 {
+                scaled_to_f64(self.value, self.multiplier)
+            }
+
+            pub fn to_decimal(self) -> String {
+                scaled_to_string(self.value, self.multiplier)
+            }
+
+            #[track_caller]
+            pub fn checked_add(&self, other: &Self) -> Result<Self, AfbError> {
+                let (value, multiplier) = scaled_add(self.get_scaled(), other.get_scaled())?;
+                Ok(Self::new(value, multiplier))
+            }
+
+            #[track_caller]
+            pub fn checked_sub(&self, other: &Self) -> Result<Self, AfbError> {
+                let (value, multiplier) = scaled_sub(self.get_scaled(), other.get_scaled())?;
+                Ok(Self::new(value, multiplier))
+            }
+        }
+
+        impl ExiQuantity for $quantity {
+            const SYMBOL: &'static str = $symbol;
+            const ISO2_UNIT: iso2_exi::PhysicalUnit = iso2_exi::PhysicalUnit::$unit;
+            const DIN_UNIT: din_exi::PhysicalUnit = din_exi::PhysicalUnit::$unit;
+
+            fn from_scaled(value: i16, multiplier: i8) -> Self {
+                Self::new(value, multiplier)
+            }
+
+            fn get_scaled(&self) -> (i16, i8) {
+                (self.value, self.multiplier)
+            }
+        }
+
+        // ex: (4005,-1) => 400.5 V
+        impl fmt::Display for $quantity {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} {}", self.to_decimal(), $symbol)
+            }
+        }
+
+        // (4000,-1) == (400,0)
+        impl PartialEq for $quantity {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+        impl Eq for $quantity {}
+
+        impl PartialOrd for $quantity {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $quantity {
+            fn cmp(&self, other: &Self) -> Ordering {
+                scaled_cmp(self.get_scaled(), other.get_scaled())
+            }
+        }
+
+        impl From<$quantity> for iso2_exi::PhysicalValue {
+            fn from(quantity: $quantity) -> Self {
+                Self::from_quantity(&quantity)
+            }
+        }
+
+        impl From<$quantity> for din_exi::PhysicalValue {
+            fn from(quantity: $quantity) -> Self {
+                Self::from_quantity(&quantity)
+            }
+        }
+
+        impl From<$quantity> for iso20_exi::RationalNumber {
+            fn from(quantity: $quantity) -> Self {
+                Self::from_quantity(&quantity)
+            }
+        }
+
+        impl TryFrom<iso2_exi::PhysicalValue> for $quantity {
+            type Error = AfbError;
+            #[track_caller]
+            fn try_from(value: iso2_exi::PhysicalValue) -> Result<Self, AfbError> {
+                value.to_quantity()
+            }
+        }
+
+        impl TryFrom<din_exi::PhysicalValue> for $quantity {
+            type Error = AfbError;
+            #[track_caller]
+            fn try_from(value: din_exi::PhysicalValue) -> Result<Self, AfbError> {
+                value.to_quantity()
+            }
+        }
+
+        impl TryFrom<iso20_exi::RationalNumber> for $quantity {
+            type Error = AfbError;
+            #[track_caller]
+            fn try_from(value: iso20_exi::RationalNumber) -> Result<Self, AfbError> {
+                value.to_quantity()
+            }
+        }
+    };
+}
+
+exi_quantity!(Voltage, "V", Volt);
+exi_quantity!(Current, "A", Ampere);
+exi_quantity!(Power, "W", Watt);
+exi_quantity!(Energy, "Wh", Wh);
+exi_quantity!(Duration, "s", Second);
+
+// ISO-20 and DIN/ISO-2 schedules use plain u32 seconds
+impl Duration {
+    #[track_caller]
+    pub fn from_secs(secs: u32) -> Result<Self, AfbError> {
+        let (value, multiplier) = scaled_fit(secs as i128, 0)?;
+        Ok(Self::new(value, multiplier))
+    }
+
+    /// Rounded to the nearest second, negative durations are 0
+    pub fn to_secs(self) -> u32 {
+        self.to_f64().round().clamp(0.0, u32::MAX as f64) as u32
+    }
+}
+
+/// State of charge in percent (0..100), an i8 for every protocol
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Soc {
+    percent: i8,
+}
+
+impl Soc {
+    #[track_caller]
+    pub fn new(percent: i8) -> Result<Self, AfbError> {
+        if !(0..=100).contains(&percent) {
+            return afb_error!("exi-quantity-soc", "invalid soc:{}% expect:0..100", percent);
+        }
+        Ok(Self { percent })
+    }
+
+    pub fn get_percent(&self) -> i8 {
+        self.percent
+    }
+}
+
+impl fmt::Display for Soc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} %", self.percent)
+    }
+}
+
+impl TryFrom<i8> for Soc {
+    type Error = AfbError;
+    #[track_caller]
+    fn try_from(percent: i8) -> Result<Self, AfbError> {
+        Self::new(percent)
+    }
+}
+
+impl From<Soc> for i8 {
+    fn from(soc: Soc) -> Self {
+        soc.percent
+    }
+}
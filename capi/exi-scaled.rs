@@ -0,0 +1,192 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: value*10^multiplier fixed point arithmetic shared by DIN/ISO-2 PhysicalValue
+ *  - computations are done on i128 aligned to the smallest multiplier, then fitted back to i16
+ *  - fitted values use the smallest (most precise) multiplier within -3..3 that avoids overflow
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §8.5.2.7 PhysicalValueType (Multiplier -3..3, Value short)
+ */
+
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+pub const SCALED_MULTIPLIER_MIN: i8 = -3;
+pub const SCALED_MULTIPLIER_MAX: i8 = 3;
+
+// above 10^38 i128 overflows, such multipliers never come from a sane peer
+const SCALED_MAX_SHIFT: u32 = 38;
+
+/// value*10^multiplier as float, ex: (4005,-1) => 400.5
+pub fn scaled_to_f64(value: i16, multiplier: i8) -> f64 {
+    value as f64 * 10f64.powi(multiplier as i32)
+}
+
+// integer division rounding half away from zero
+fn div_round(value: i128, divisor: i128) -> i128 {
+    let quotient = value / divisor;
+    let remainder = value % divisor;
+    if remainder.abs() * 2 >= divisor {
+        quotient + value.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Fit mantissa*10^exponent into (value, multiplier) with the smallest multiplier within
+/// -3..3 that does not overflow i16. Digits below 10^-3 are rounded half away from zero.
+#[track_caller]
+pub fn scaled_fit(mantissa: i128, exponent: i32) -> Result<(i16, i8), AfbError> {
+    if mantissa == 0 {
+        return Ok((0, 0));
+    }
+    for multiplier in SCALED_MULTIPLIER_MIN..=SCALED_MULTIPLIER_MAX {
+        let shift = exponent - multiplier as i32;
+        let candidate = if shift >= 0 {
+            match 10i128
+                .checked_pow(shift as u32)
+                .and_then(|scale| mantissa.checked_mul(scale))
+            {
+                Some(value) => value,
+                None => continue,
+            }
+        } else {
+            match 10i128.checked_pow(shift.unsigned_abs()) {
+                Some(scale) => div_round(mantissa, scale),
+                None => 0,
+            }
+        };
+        if let Ok(value) = i16::try_from(candidate) {
+            return Ok((value, multiplier));
+        }
+    }
+    afb_error!(
+        "scaled-value-fit",
+        "{}e{} out of range max:{}e{}",
+        mantissa,
+        exponent,
+        i16::MAX,
+        SCALED_MULTIPLIER_MAX
+    )
+}
+
+/// Best precision (value, multiplier) for a float, rounded to the nearest 10^-3
+#[track_caller]
+pub fn scaled_from_f64(value: f64) -> Result<(i16, i8), AfbError> {
+    if !value.is_finite() {
+        return afb_error!("scaled-value-float", "invalid float:{}", value);
+    }
+    for multiplier in SCALED_MULTIPLIER_MIN..=SCALED_MULTIPLIER_MAX {
+        let scaled = (value * 10f64.powi(-multiplier as i32)).round();
+        if scaled >= i16::MIN as f64 && scaled <= i16::MAX as f64 {
+            if scaled == 0.0 {
+                return Ok((0, 0));
+            }
+            return Ok((scaled as i16, multiplier));
+        }
+    }
+    afb_error!(
+        "scaled-value-float",
+        "{} out of range max:{}e{}",
+        value,
+        i16::MAX,
+        SCALED_MULTIPLIER_MAX
+    )
+}
+
+/// Exact conversion from decimal text, ex: "400.5" => (4005,-1), "-0.25" => (-250,-3)
+#[track_caller]
+pub fn scaled_from_decimal(text: &str) -> Result<(i16, i8), AfbError> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (digits, ""),
+    };
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return afb_error!("scaled-value-decimal", "invalid decimal:'{}'", text);
+    }
+
+    // leading zeros do not count, others digits should fit in i128
+    let significant = format!("{}{}", integer, fraction);
+    let significant = significant.trim_start_matches('0');
+    if significant.len() > SCALED_MAX_SHIFT as usize {
+        return afb_error!("scaled-value-decimal", "too many digits:'{}'", text);
+    }
+    let mut mantissa: i128 = 0;
+    for digit in significant.bytes() {
+        mantissa = mantissa * 10 + (digit - b'0') as i128;
+    }
+    if negative {
+        mantissa = -mantissa;
+    }
+    scaled_fit(mantissa, -(fraction.len() as i32))
+}
+
+// align both values on the smallest multiplier
+fn scaled_align(left: (i16, i8), right: (i16, i8)) -> Option<(i128, i128, i32)> {
+    let exponent = left.1.min(right.1) as i32;
+    let left_scale = 10i128.checked_pow((left.1 as i32 - exponent) as u32)?;
+    let right_scale = 10i128.checked_pow((right.1 as i32 - exponent) as u32)?;
+    Some((
+        (left.0 as i128).checked_mul(left_scale)?,
+        (right.0 as i128).checked_mul(right_scale)?,
+        exponent,
+    ))
+}
+
+/// Compare values with different multipliers, ex: (4000,-1) == (400,0)
+pub fn scaled_cmp(left: (i16, i8), right: (i16, i8)) -> Ordering {
+    match scaled_align(left, right) {
+        Some((left, right, _)) => left.cmp(&right),
+        // out of spec multipliers, float order is good enough
+        None => scaled_to_f64(left.0, left.1).total_cmp(&scaled_to_f64(right.0, right.1)),
+    }
+}
+
+/// Exact sum fitted back into (value, multiplier)
+#[track_caller]
+pub fn scaled_add(left: (i16, i8), right: (i16, i8)) -> Result<(i16, i8), AfbError> {
+    match scaled_align(left, right) {
+        Some((left, right, exponent)) => scaled_fit(left + right, exponent),
+        None => afb_error!(
+            "scaled-value-add",
+            "multipliers too far apart:{} {}",
+            left.1,
+            right.1
+        ),
+    }
+}
+
+/// Exact difference fitted back into (value, multiplier)
+#[track_caller]
+pub fn scaled_sub(left: (i16, i8), right: (i16, i8)) -> Result<(i16, i8), AfbError> {
+    match scaled_align(left, right) {
+        Some((left, right, exponent)) => scaled_fit(left - right, exponent),
+        None => afb_error!(
+            "scaled-value-sub",
+            "multipliers too far apart:{} {}",
+            left.1,
+            right.1
+        ),
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: serde helpers shared by DIN/ISO-2/appHand/ISO-20 messages ("serde" feature)
+ *  - messages are (de)serialized through private mirror structs using XSD element names
+ *  - enums are (de)serialized with their XSD enumeration value
+ *  - binary content (hexBinary/base64Binary) is serialized as an hexadecimal string
+ */
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Binary XSD content, serialized as an hexadecimal string
+#[derive(Clone, PartialEq)]
+pub struct ExiBytes(pub Vec<u8>);
+
+impl ExiBytes {
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<&[u8]> for ExiBytes {
+    fn from(data: &[u8]) -> Self {
+        ExiBytes(data.to_vec())
+    }
+}
+
+impl fmt::Debug for ExiBytes {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(format, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ExiBytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:?}", self))
+    }
+}
+
+impl<'de> Deserialize<'de> for ExiBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        if text.len() % 2 != 0 {
+            return Err(de::Error::custom(format!(
+                "hex string '{}' has an odd length",
+                text
+            )));
+        }
+        let mut data = Vec::with_capacity(text.len() / 2);
+        for idx in (0..text.len()).step_by(2) {
+            match text.get(idx..idx + 2).map(|digits| u8::from_str_radix(digits, 16)) {
+                Some(Ok(byte)) => data.push(byte),
+                _ => {
+                    return Err(de::Error::custom(format!(
+                        "invalid hex string '{}'",
+                        text
+                    )))
+                }
+            }
+        }
+        Ok(ExiBytes(data))
+    }
+}
+
+// Serialize/Deserialize $exi through $mirror, mirror provides from_exi(&$exi) and into_exi(self)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! exi_serde_mirror {
+    ($exi:ident, $mirror:ident) => {
+        impl ::serde::Serialize for $exi {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                let mirror = $mirror::from_exi(self)
+                    .map_err(|error| <S::Error as ::serde::ser::Error>::custom(error))?;
+                ::serde::Serialize::serialize(&mirror, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $exi {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let mirror = <$mirror as ::serde::Deserialize>::deserialize(deserializer)?;
+                mirror
+                    .into_exi()
+                    .map_err(|error| <D::Error as ::serde::de::Error>::custom(error))
+            }
+        }
+    };
+}
+
+// Serialize/Deserialize fieldless enums with their XSD enumeration value
+#[doc(hidden)]
+#[macro_export]
+macro_rules! exi_enum_serde {
+    ($enum:ident, { $($variant:ident => $label:literal),+ $(,)? }) => {
+        impl ::serde::Serialize for $enum {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                let label = match self {
+                    $($enum::$variant => $label,)+
+                };
+                serializer.serialize_str(label)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $enum {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let label = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                match label.as_str() {
+                    $($label => Ok($enum::$variant),)+
+                    _ => Err(<D::Error as ::serde::de::Error>::unknown_variant(
+                        label.as_str(),
+                        &[$($label),+],
+                    )),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,329 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   iso15118-20 CommonMessages (urn:iso:std:iso:15118:-20:CommonMessages)
+ *   DC & AC namespaces are exposed through iso20_dc_exi & iso20_ac_exi
+ */
+
+use crate::prelude::*;
+use iso20_exi::*;
+
+pub(self) mod cglue {
+    #![allow(dead_code)]
+    #![allow(non_upper_case_globals)]
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+    // force reuse of C bitstream from exi-encoder
+    use crate::prelude::exi_bitstream_t;
+    include!(concat!(env!("OUT_DIR"), "/_iso20-capi.rs"));
+}
+
+#[path = "status-enums.rs"]
+mod status_enums;
+
+#[path = "param-value.rs"]
+mod param_value;
+
+#[path = "session-setup.rs"]
+mod session_setup;
+
+#[path = "authorization-setup.rs"]
+mod authorization_setup;
+
+#[path = "authorization.rs"]
+mod authorization;
+
+#[path = "service-discovery.rs"]
+mod service_discovery;
+
+#[path = "service-detail.rs"]
+mod service_detail;
+
+#[path = "service-selection.rs"]
+mod service_selection;
+
+#[path = "schedule-exchange.rs"]
+mod schedule_exchange;
+
+#[path = "power-delivery.rs"]
+mod power_delivery;
+
+#[path = "session-stop.rs"]
+mod session_stop;
+
+#[path = "body-encoder.rs"]
+mod body_encoder;
+
+#[path = "dc-messages/@iso20-dc-lib.rs"]
+mod dc_encoder;
+pub use dc_encoder::iso20_dc_exi;
+
+#[path = "ac-messages/@iso20-ac-lib.rs"]
+mod ac_encoder;
+pub use ac_encoder::iso20_ac_exi;
+
+pub mod iso20_exi {
+    pub use super::authorization::*;
+    pub use super::authorization_setup::*;
+    pub use super::body_encoder::*;
+    pub use super::param_value::*;
+    pub use super::power_delivery::*;
+    pub use super::schedule_exchange::*;
+    pub use super::service_detail::*;
+    pub use super::service_discovery::*;
+    pub use super::service_selection::*;
+    pub use super::session_setup::*;
+    pub use super::session_stop::*;
+    pub use super::status_enums::*;
+    use crate::afb::*;
+    use std::fmt;
+
+    // serde uses XSD message element names as variant tags
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MessageBody {
+        SessionSetupReq(SessionSetupRequest),
+        SessionSetupRes(SessionSetupResponse),
+        AuthorizationSetupReq(AuthorizationSetupRequest),
+        AuthorizationSetupRes(AuthorizationSetupResponse),
+        AuthorizationReq(AuthorizationRequest),
+        AuthorizationRes(AuthorizationResponse),
+        ServiceDiscoveryReq(ServiceDiscoveryRequest),
+        ServiceDiscoveryRes(ServiceDiscoveryResponse),
+        ServiceDetailReq(ServiceDetailRequest),
+        ServiceDetailRes(ServiceDetailResponse),
+        ServiceSelectionReq(ServiceSelectionRequest),
+        ServiceSelectionRes(ServiceSelectionResponse),
+        ScheduleExchangeReq(ScheduleExchangeRequest),
+        ScheduleExchangeRes(ScheduleExchangeResponse),
+        PowerDeliveryReq(PowerDeliveryRequest),
+        PowerDeliveryRes(PowerDeliveryResponse),
+        SessionStopReq(SessionStopRequest),
+        SessionStopRes(SessionStopResponse),
+        #[cfg_attr(feature = "serde", serde(skip))]
+        Unsupported,
+    }
+
+    // one line session log, XSD message element name followed by its fields
+    impl fmt::Display for MessageBody {
+        fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MessageBody::SessionSetupReq(body) => write!(format, "SessionSetupReq {}", body),
+                MessageBody::SessionSetupRes(body) => write!(format, "SessionSetupRes {}", body),
+                MessageBody::AuthorizationSetupReq(body) => {
+                    write!(format, "AuthorizationSetupReq {}", body)
+                }
+                MessageBody::AuthorizationSetupRes(body) => {
+                    write!(format, "AuthorizationSetupRes {}", body)
+                }
+                MessageBody::AuthorizationReq(body) => write!(format, "AuthorizationReq {}", body),
+                MessageBody::AuthorizationRes(body) => write!(format, "AuthorizationRes {}", body),
+                MessageBody::ServiceDiscoveryReq(body) => {
+                    write!(format, "ServiceDiscoveryReq {}", body)
+                }
+                MessageBody::ServiceDiscoveryRes(body) => {
+                    write!(format, "ServiceDiscoveryRes {}", body)
+                }
+                MessageBody::ServiceDetailReq(body) => write!(format, "ServiceDetailReq {}", body),
+                MessageBody::ServiceDetailRes(body) => write!(format, "ServiceDetailRes {}", body),
+                MessageBody::ServiceSelectionReq(body) => {
+                    write!(format, "ServiceSelectionReq {}", body)
+                }
+                MessageBody::ServiceSelectionRes(body) => {
+                    write!(format, "ServiceSelectionRes {}", body)
+                }
+                MessageBody::ScheduleExchangeReq(body) => {
+                    write!(format, "ScheduleExchangeReq {}", body)
+                }
+                MessageBody::ScheduleExchangeRes(body) => {
+                    write!(format, "ScheduleExchangeRes {}", body)
+                }
+                MessageBody::PowerDeliveryReq(body) => write!(format, "PowerDeliveryReq {}", body),
+                MessageBody::PowerDeliveryRes(body) => write!(format, "PowerDeliveryRes {}", body),
+                MessageBody::SessionStopReq(body) => write!(format, "SessionStopReq {}", body),
+                MessageBody::SessionStopRes(body) => write!(format, "SessionStopRes {}", body),
+                MessageBody::Unsupported => format.write_str("Unsupported"),
+            }
+        }
+    }
+
+    impl MessageBody {
+        pub fn get_tagid(&self) -> MessageTagId {
+            match self {
+                MessageBody::SessionSetupReq(_) => MessageTagId::SessionSetupReq,
+                MessageBody::SessionSetupRes(_) => MessageTagId::SessionSetupRes,
+                MessageBody::AuthorizationSetupReq(_) => MessageTagId::AuthorizationSetupReq,
+                MessageBody::AuthorizationSetupRes(_) => MessageTagId::AuthorizationSetupRes,
+                MessageBody::AuthorizationReq(_) => MessageTagId::AuthorizationReq,
+                MessageBody::AuthorizationRes(_) => MessageTagId::AuthorizationRes,
+                MessageBody::ServiceDiscoveryReq(_) => MessageTagId::ServiceDiscoveryReq,
+                MessageBody::ServiceDiscoveryRes(_) => MessageTagId::ServiceDiscoveryRes,
+                MessageBody::ServiceDetailReq(_) => MessageTagId::ServiceDetailReq,
+                MessageBody::ServiceDetailRes(_) => MessageTagId::ServiceDetailRes,
+                MessageBody::ServiceSelectionReq(_) => MessageTagId::ServiceSelectionReq,
+                MessageBody::ServiceSelectionRes(_) => MessageTagId::ServiceSelectionRes,
+                MessageBody::ScheduleExchangeReq(_) => MessageTagId::ScheduleExchangeReq,
+                MessageBody::ScheduleExchangeRes(_) => MessageTagId::ScheduleExchangeRes,
+                MessageBody::PowerDeliveryReq(_) => MessageTagId::PowerDeliveryReq,
+                MessageBody::PowerDeliveryRes(_) => MessageTagId::PowerDeliveryRes,
+                MessageBody::SessionStopReq(_) => MessageTagId::SessionStopReq,
+                MessageBody::SessionStopRes(_) => MessageTagId::SessionStopRes,
+                MessageBody::Unsupported => MessageTagId::Unsupported,
+            }
+        }
+
+        pub fn encode(&self) -> Result<Iso20BodyType, AfbError> {
+            let body = match self {
+                MessageBody::SessionSetupReq(body) => body.encode(),
+                MessageBody::SessionSetupRes(body) => body.encode(),
+                MessageBody::AuthorizationSetupReq(body) => body.encode(),
+                MessageBody::AuthorizationSetupRes(body) => body.encode(),
+                MessageBody::AuthorizationReq(body) => body.encode(),
+                MessageBody::AuthorizationRes(body) => body.encode(),
+                MessageBody::ServiceDiscoveryReq(body) => body.encode(),
+                MessageBody::ServiceDiscoveryRes(body) => body.encode(),
+                MessageBody::ServiceDetailReq(body) => body.encode(),
+                MessageBody::ServiceDetailRes(body) => body.encode(),
+                MessageBody::ServiceSelectionReq(body) => body.encode(),
+                MessageBody::ServiceSelectionRes(body) => body.encode(),
+                MessageBody::ScheduleExchangeReq(body) => body.encode(),
+                MessageBody::ScheduleExchangeRes(body) => body.encode(),
+                MessageBody::PowerDeliveryReq(body) => body.encode(),
+                MessageBody::PowerDeliveryRes(body) => body.encode(),
+                MessageBody::SessionStopReq(body) => body.encode(),
+                MessageBody::SessionStopRes(body) => body.encode(),
+                MessageBody::Unsupported => {
+                    return afb_error!("iso20-encode-exi", "unknown/unsupported message")
+                }
+            };
+            Ok(body)
+        }
+
+        // iso20 has no body wrapper, each message embeds its own header
+        pub fn decode(payload: &super::cglue::iso20_exiDocument) -> Result<Self, AfbError> {
+            // SessionSetup
+            let body = if payload.SessionSetupReq_isUsed() == 1 {
+                let body = SessionSetupRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.SessionSetupReq
+                });
+                MessageBody::SessionSetupReq(body)
+            } else if payload.SessionSetupRes_isUsed() == 1 {
+                let body = SessionSetupResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.SessionSetupRes
+                });
+                MessageBody::SessionSetupRes(body)
+
+            // AuthorizationSetup
+            } else if payload.AuthorizationSetupReq_isUsed() == 1 {
+                let body = AuthorizationSetupRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.AuthorizationSetupReq
+                });
+                MessageBody::AuthorizationSetupReq(body)
+            } else if payload.AuthorizationSetupRes_isUsed() == 1 {
+                let body = AuthorizationSetupResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.AuthorizationSetupRes
+                });
+                MessageBody::AuthorizationSetupRes(body)
+
+            // Authorization
+            } else if payload.AuthorizationReq_isUsed() == 1 {
+                let body = AuthorizationRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.AuthorizationReq
+                });
+                MessageBody::AuthorizationReq(body)
+            } else if payload.AuthorizationRes_isUsed() == 1 {
+                let body = AuthorizationResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.AuthorizationRes
+                });
+                MessageBody::AuthorizationRes(body)
+
+            // ServiceDiscovery
+            } else if payload.ServiceDiscoveryReq_isUsed() == 1 {
+                let body = ServiceDiscoveryRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceDiscoveryReq
+                });
+                MessageBody::ServiceDiscoveryReq(body)
+            } else if payload.ServiceDiscoveryRes_isUsed() == 1 {
+                let body = ServiceDiscoveryResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceDiscoveryRes
+                });
+                MessageBody::ServiceDiscoveryRes(body)
+
+            // ServiceDetail
+            } else if payload.ServiceDetailReq_isUsed() == 1 {
+                let body = ServiceDetailRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceDetailReq
+                });
+                MessageBody::ServiceDetailReq(body)
+            } else if payload.ServiceDetailRes_isUsed() == 1 {
+                let body = ServiceDetailResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceDetailRes
+                });
+                MessageBody::ServiceDetailRes(body)
+
+            // ServiceSelection
+            } else if payload.ServiceSelectionReq_isUsed() == 1 {
+                let body = ServiceSelectionRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceSelectionReq
+                });
+                MessageBody::ServiceSelectionReq(body)
+            } else if payload.ServiceSelectionRes_isUsed() == 1 {
+                let body = ServiceSelectionResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.ServiceSelectionRes
+                });
+                MessageBody::ServiceSelectionRes(body)
+
+            // ScheduleExchange
+            } else if payload.ScheduleExchangeReq_isUsed() == 1 {
+                let body = ScheduleExchangeRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.ScheduleExchangeReq
+                });
+                MessageBody::ScheduleExchangeReq(body)
+            } else if payload.ScheduleExchangeRes_isUsed() == 1 {
+                let body = ScheduleExchangeResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.ScheduleExchangeRes
+                });
+                MessageBody::ScheduleExchangeRes(body)
+
+            // PowerDelivery
+            } else if payload.PowerDeliveryReq_isUsed() == 1 {
+                let body = PowerDeliveryRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.PowerDeliveryReq
+                });
+                MessageBody::PowerDeliveryReq(body)
+            } else if payload.PowerDeliveryRes_isUsed() == 1 {
+                let body = PowerDeliveryResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.PowerDeliveryRes
+                });
+                MessageBody::PowerDeliveryRes(body)
+
+            // SessionStop
+            } else if payload.SessionStopReq_isUsed() == 1 {
+                let body =
+                    SessionStopRequest::decode(unsafe { payload.__bindgen_anon_1.SessionStopReq });
+                MessageBody::SessionStopReq(body)
+            } else if payload.SessionStopRes_isUsed() == 1 {
+                let body =
+                    SessionStopResponse::decode(unsafe { payload.__bindgen_anon_1.SessionStopRes });
+                MessageBody::SessionStopRes(body)
+            } else {
+                return afb_error!("iso20-decode-exi", "unknown/unsupported message");
+            };
+            Ok(body)
+        }
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   iso15118-20 AC messages (urn:iso:std:iso:15118:-20:AC)
+ */
+
+use super::cglue;
+use crate::prelude::*;
+use iso20_ac_exi::*;
+use std::mem;
+
+// -20 AC schema redefines common types, convert them from/to iso20_exi wrappers
+fn to_ac_rational(value: &RationalNumber) -> cglue::iso20_ac_RationalNumberType {
+    let mut payload = unsafe { mem::zeroed::<cglue::iso20_ac_RationalNumberType>() };
+    payload.Value = value.get_value();
+    payload.Exponent = value.get_exponent();
+    payload
+}
+
+fn from_ac_rational(payload: cglue::iso20_ac_RationalNumberType) -> RationalNumber {
+    RationalNumber::new(payload.Value, payload.Exponent)
+}
+
+fn to_ac_status(status: &EvseStatusType) -> cglue::iso20_ac_EVSEStatusType {
+    let mut payload = unsafe { mem::zeroed::<cglue::iso20_ac_EVSEStatusType>() };
+    payload.NotificationMaxDelay = status.get_delay();
+    payload.EVSENotification = status.encode().EVSENotification;
+    payload
+}
+
+// notification is checked by EvseStatusType::get_notification, not when decoding
+fn from_ac_status(payload: cglue::iso20_ac_EVSEStatusType) -> EvseStatusType {
+    let mut status = unsafe { mem::zeroed::<cglue::iso20_EVSEStatusType>() };
+    status.NotificationMaxDelay = payload.NotificationMaxDelay;
+    status.EVSENotification = payload.EVSENotification;
+    EvseStatusType::decode(status)
+}
+
+#[path = "status-enums.rs"]
+mod status_enums;
+
+#[path = "param-discovery.rs"]
+mod param_discovery;
+
+#[path = "charge-loop.rs"]
+mod charge_loop;
+
+#[path = "body-encoder.rs"]
+mod body_encoder;
+
+pub mod iso20_ac_exi {
+    pub use super::body_encoder::*;
+    pub use super::charge_loop::*;
+    pub use super::param_discovery::*;
+    pub use super::status_enums::*;
+    // types shared with -20 CommonMessages
+    pub use crate::prelude::iso20_exi::{
+        EvseNotification, EvseProcessing, EvseStatusType, ExiMessageHeader, RationalNumber,
+        ResponseCode,
+    };
+    use crate::afb::*;
+    use std::fmt;
+
+    // serde uses XSD message element names as variant tags
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MessageBody {
+        #[cfg_attr(feature = "serde", serde(rename = "AC_ChargeParameterDiscoveryReq"))]
+        AcChargeParamDiscoveryReq(AcChargeParamDiscoveryRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "AC_ChargeParameterDiscoveryRes"))]
+        AcChargeParamDiscoveryRes(AcChargeParamDiscoveryResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "AC_ChargeLoopReq"))]
+        AcChargeLoopReq(AcChargeLoopRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "AC_ChargeLoopRes"))]
+        AcChargeLoopRes(AcChargeLoopResponse),
+        #[cfg_attr(feature = "serde", serde(skip))]
+        Unsupported,
+    }
+
+    // one line session log, XSD message element name followed by its fields
+    impl fmt::Display for MessageBody {
+        fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MessageBody::AcChargeParamDiscoveryReq(body) => {
+                    write!(format, "AC_ChargeParameterDiscoveryReq {}", body)
+                }
+                MessageBody::AcChargeParamDiscoveryRes(body) => {
+                    write!(format, "AC_ChargeParameterDiscoveryRes {}", body)
+                }
+                MessageBody::AcChargeLoopReq(body) => write!(format, "AC_ChargeLoopReq {}", body),
+                MessageBody::AcChargeLoopRes(body) => write!(format, "AC_ChargeLoopRes {}", body),
+                MessageBody::Unsupported => format.write_str("Unsupported"),
+            }
+        }
+    }
+
+    impl MessageBody {
+        pub fn get_tagid(&self) -> MessageTagId {
+            match self {
+                MessageBody::AcChargeParamDiscoveryReq(_) => {
+                    MessageTagId::AcChargeParamDiscoveryReq
+                }
+                MessageBody::AcChargeParamDiscoveryRes(_) => {
+                    MessageTagId::AcChargeParamDiscoveryRes
+                }
+                MessageBody::AcChargeLoopReq(_) => MessageTagId::AcChargeLoopReq,
+                MessageBody::AcChargeLoopRes(_) => MessageTagId::AcChargeLoopRes,
+                MessageBody::Unsupported => MessageTagId::Unsupported,
+            }
+        }
+
+        pub fn encode(&self) -> Result<Iso20AcBodyType, AfbError> {
+            let body = match self {
+                MessageBody::AcChargeParamDiscoveryReq(body) => body.encode(),
+                MessageBody::AcChargeParamDiscoveryRes(body) => body.encode(),
+                MessageBody::AcChargeLoopReq(body) => body.encode(),
+                MessageBody::AcChargeLoopRes(body) => body.encode(),
+                MessageBody::Unsupported => {
+                    return afb_error!("iso20-ac-encode-exi", "unknown/unsupported message")
+                }
+            };
+            Ok(body)
+        }
+
+        pub fn decode(payload: &super::cglue::iso20_ac_exiDocument) -> Result<Self, AfbError> {
+            // ChargeParameterDiscovery
+            let body = if payload.AC_ChargeParameterDiscoveryReq_isUsed() == 1 {
+                let body = AcChargeParamDiscoveryRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.AC_ChargeParameterDiscoveryReq
+                });
+                MessageBody::AcChargeParamDiscoveryReq(body)
+            } else if payload.AC_ChargeParameterDiscoveryRes_isUsed() == 1 {
+                let body = AcChargeParamDiscoveryResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.AC_ChargeParameterDiscoveryRes
+                });
+                MessageBody::AcChargeParamDiscoveryRes(body)
+
+            // ChargeLoop
+            } else if payload.AC_ChargeLoopReq_isUsed() == 1 {
+                let body = AcChargeLoopRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.AC_ChargeLoopReq
+                });
+                MessageBody::AcChargeLoopReq(body)
+            } else if payload.AC_ChargeLoopRes_isUsed() == 1 {
+                let body = AcChargeLoopResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.AC_ChargeLoopRes
+                });
+                MessageBody::AcChargeLoopRes(body)
+            } else {
+                return afb_error!("iso20-ac-decode-exi", "unknown/unsupported message");
+            };
+            Ok(body)
+        }
+    }
+}
@@ -0,0 +1,3218 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone, Copy)]
+pub struct DynamicAcClReqMode {
+    payload: cglue::iso20_ac_Dynamic_AC_CLReqControlModeType,
+}
+
+impl DynamicAcClReqMode {
+    pub fn new(
+        target_energy: &RationalNumber,
+        max_energy: &RationalNumber,
+        min_energy: &RationalNumber,
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        present_active_power: &RationalNumber,
+        present_reactive_power: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_Dynamic_AC_CLReqControlModeType>() };
+        payload.EVTargetEnergyRequest = to_ac_rational(target_energy);
+        payload.EVMaximumEnergyRequest = to_ac_rational(max_energy);
+        payload.EVMinimumEnergyRequest = to_ac_rational(min_energy);
+        payload.EVMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_ac_rational(min_charge_power);
+        payload.EVPresentActivePower = to_ac_rational(present_active_power);
+        payload.EVPresentReactivePower = to_ac_rational(present_reactive_power);
+        Self { payload }
+    }
+
+    pub fn get_target_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVTargetEnergyRequest)
+    }
+
+    pub fn get_max_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumEnergyRequest)
+    }
+
+    pub fn get_min_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumEnergyRequest)
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn get_present_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentActivePower)
+    }
+
+    pub fn get_present_reactive_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentReactivePower)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L3))
+        }
+    }
+
+    pub fn set_present_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L2))
+        }
+    }
+
+    pub fn set_present_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_Dynamic_AC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_Dynamic_AC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledAcClReqMode {
+    payload: cglue::iso20_ac_Scheduled_AC_CLReqControlModeType,
+}
+
+impl ScheduledAcClReqMode {
+    pub fn new(present_active_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_Scheduled_AC_CLReqControlModeType>() };
+        payload.EVPresentActivePower = to_ac_rational(present_active_power);
+        Self { payload }
+    }
+
+    pub fn get_present_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentActivePower)
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L3))
+        }
+    }
+
+    pub fn set_target_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVTargetEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVTargetEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_target_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVTargetEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVTargetEnergyRequest))
+        }
+    }
+
+    pub fn set_max_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMaximumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumEnergyRequest))
+        }
+    }
+
+    pub fn set_min_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMinimumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumEnergyRequest))
+        }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_present_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower))
+        }
+    }
+
+    pub fn set_present_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L2))
+        }
+    }
+
+    pub fn set_present_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_Scheduled_AC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_Scheduled_AC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory in dynamic mode, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptDynamicAcClReqMode {
+    payload: cglue::iso20_ac_BPT_Dynamic_AC_CLReqControlModeType,
+}
+
+impl BptDynamicAcClReqMode {
+    pub fn new(
+        target_energy: &RationalNumber,
+        max_energy: &RationalNumber,
+        min_energy: &RationalNumber,
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        present_active_power: &RationalNumber,
+        present_reactive_power: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_Dynamic_AC_CLReqControlModeType>() };
+        payload.EVTargetEnergyRequest = to_ac_rational(target_energy);
+        payload.EVMaximumEnergyRequest = to_ac_rational(max_energy);
+        payload.EVMinimumEnergyRequest = to_ac_rational(min_energy);
+        payload.EVMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_ac_rational(min_charge_power);
+        payload.EVPresentActivePower = to_ac_rational(present_active_power);
+        payload.EVPresentReactivePower = to_ac_rational(present_reactive_power);
+        Self { payload }
+    }
+
+    pub fn get_target_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVTargetEnergyRequest)
+    }
+
+    pub fn get_max_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumEnergyRequest)
+    }
+
+    pub fn get_min_energy(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumEnergyRequest)
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn get_present_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentActivePower)
+    }
+
+    pub fn get_present_reactive_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentReactivePower)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumDischargePower)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L3))
+        }
+    }
+
+    pub fn set_present_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L2))
+        }
+    }
+
+    pub fn set_present_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L3))
+        }
+    }
+
+    pub fn set_max_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L2))
+        }
+    }
+
+    pub fn set_max_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L3))
+        }
+    }
+
+    pub fn set_min_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L2))
+        }
+    }
+
+    pub fn set_min_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L3))
+        }
+    }
+
+    pub fn set_max_v2x_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumV2XEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMaximumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumV2XEnergyRequest))
+        }
+    }
+
+    pub fn set_min_v2x_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumV2XEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMinimumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumV2XEnergyRequest))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_Dynamic_AC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_Dynamic_AC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BptScheduledAcClReqMode {
+    payload: cglue::iso20_ac_BPT_Scheduled_AC_CLReqControlModeType,
+}
+
+impl BptScheduledAcClReqMode {
+    pub fn new(present_active_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_Scheduled_AC_CLReqControlModeType>() };
+        payload.EVPresentActivePower = to_ac_rational(present_active_power);
+        Self { payload }
+    }
+
+    pub fn get_present_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVPresentActivePower)
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentActivePower_L3))
+        }
+    }
+
+    pub fn set_target_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVTargetEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVTargetEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_target_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVTargetEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVTargetEnergyRequest))
+        }
+    }
+
+    pub fn set_max_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMaximumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumEnergyRequest))
+        }
+    }
+
+    pub fn set_min_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumEnergyRequest = to_ac_rational(value);
+        self.payload.set_EVMinimumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumEnergyRequest))
+        }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_present_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower))
+        }
+    }
+
+    pub fn set_present_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L2))
+        }
+    }
+
+    pub fn set_present_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVPresentReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVPresentReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVPresentReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVPresentReactivePower_L3))
+        }
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower))
+        }
+    }
+
+    pub fn set_max_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L2))
+        }
+    }
+
+    pub fn set_max_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L3))
+        }
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower))
+        }
+    }
+
+    pub fn set_min_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L2))
+        }
+    }
+
+    pub fn set_min_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_Scheduled_AC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_Scheduled_AC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+pub enum AcClReqControlMode {
+    Dynamic(DynamicAcClReqMode),
+    Scheduled(ScheduledAcClReqMode),
+    BptDynamic(BptDynamicAcClReqMode),
+    BptScheduled(BptScheduledAcClReqMode),
+}
+
+pub struct AcChargeLoopRequest {
+    payload: cglue::iso20_ac_AC_ChargeLoopReqType,
+}
+
+impl AcChargeLoopRequest {
+    pub fn new(mode: &AcClReqControlMode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeLoopReqType>() };
+        match mode {
+            AcClReqControlMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_AC_CLReqControlMode = value.encode();
+                payload.set_Dynamic_AC_CLReqControlMode_isUsed(1);
+            }
+            AcClReqControlMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_AC_CLReqControlMode = value.encode();
+                payload.set_Scheduled_AC_CLReqControlMode_isUsed(1);
+            }
+            AcClReqControlMode::BptDynamic(value) => {
+                payload.__bindgen_anon_1.BPT_Dynamic_AC_CLReqControlMode = value.encode();
+                payload.set_BPT_Dynamic_AC_CLReqControlMode_isUsed(1);
+            }
+            AcClReqControlMode::BptScheduled(value) => {
+                payload.__bindgen_anon_1.BPT_Scheduled_AC_CLReqControlMode = value.encode();
+                payload.set_BPT_Scheduled_AC_CLReqControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn set_meter_info_requested(&mut self, requested: bool) -> &mut Self {
+        self.payload.MeterInfoRequested = if requested { 1 } else { 0 };
+        self
+    }
+
+    pub fn get_meter_info_requested(&self) -> bool {
+        self.payload.MeterInfoRequested != 0
+    }
+
+    pub fn get_control_mode(&self) -> Result<AcClReqControlMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_AC_CLReqControlMode_isUsed() != 0 {
+                AcClReqControlMode::Dynamic(DynamicAcClReqMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_AC_CLReqControlMode,
+                ))
+            } else if self.payload.Scheduled_AC_CLReqControlMode_isUsed() != 0 {
+                AcClReqControlMode::Scheduled(ScheduledAcClReqMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_AC_CLReqControlMode,
+                ))
+            } else if self.payload.BPT_Dynamic_AC_CLReqControlMode_isUsed() != 0 {
+                AcClReqControlMode::BptDynamic(BptDynamicAcClReqMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Dynamic_AC_CLReqControlMode,
+                ))
+            } else if self.payload.BPT_Scheduled_AC_CLReqControlMode_isUsed() != 0 {
+                AcClReqControlMode::BptScheduled(BptScheduledAcClReqMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Scheduled_AC_CLReqControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-ac-charge-loop-req", "unsupported control mode");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_ChargeLoopReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20AcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20AcBodyType>();
+            exi_body.__bindgen_anon_1.AC_ChargeLoopReq = self.payload;
+            exi_body.set_AC_ChargeLoopReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DynamicAcClResMode {
+    payload: cglue::iso20_ac_Dynamic_AC_CLResControlModeType,
+}
+
+impl DynamicAcClResMode {
+    pub fn new(target_active_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_Dynamic_AC_CLResControlModeType>() };
+        payload.EVSETargetActivePower = to_ac_rational(target_active_power);
+        Self { payload }
+    }
+
+    pub fn get_target_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSETargetActivePower)
+    }
+
+    pub fn set_target_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L2))
+        }
+    }
+
+    pub fn set_target_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L3))
+        }
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_min_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.MinimumSOC = value;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn set_ack_max_delay(&mut self, value: u16) -> &mut Self {
+        self.payload.AckMaxDelay = value;
+        self.payload.set_AckMaxDelay_isUsed(1);
+        self
+    }
+
+    pub fn get_ack_max_delay(&self) -> Option<u16> {
+        if self.payload.AckMaxDelay_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.AckMaxDelay)
+        }
+    }
+
+    pub fn set_target_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower))
+        }
+    }
+
+    pub fn set_target_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L2))
+        }
+    }
+
+    pub fn set_target_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_Dynamic_AC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_Dynamic_AC_CLResControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledAcClResMode {
+    payload: cglue::iso20_ac_Scheduled_AC_CLResControlModeType,
+}
+
+impl ScheduledAcClResMode {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_ac_Scheduled_AC_CLResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_target_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower))
+        }
+    }
+
+    pub fn set_target_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L2))
+        }
+    }
+
+    pub fn set_target_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L3))
+        }
+    }
+
+    pub fn set_target_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower))
+        }
+    }
+
+    pub fn set_target_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L2))
+        }
+    }
+
+    pub fn set_target_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_Scheduled_AC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_Scheduled_AC_CLResControlModeType {
+        self.payload
+    }
+}
+
+// EVSETargetActivePower is negative when EVSE requests discharge
+#[derive(Clone, Copy)]
+pub struct BptDynamicAcClResMode {
+    payload: cglue::iso20_ac_BPT_Dynamic_AC_CLResControlModeType,
+}
+
+impl BptDynamicAcClResMode {
+    pub fn new(target_active_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_Dynamic_AC_CLResControlModeType>() };
+        payload.EVSETargetActivePower = to_ac_rational(target_active_power);
+        Self { payload }
+    }
+
+    pub fn get_target_active_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSETargetActivePower)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_min_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.MinimumSOC = value;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn set_ack_max_delay(&mut self, value: u16) -> &mut Self {
+        self.payload.AckMaxDelay = value;
+        self.payload.set_AckMaxDelay_isUsed(1);
+        self
+    }
+
+    pub fn get_ack_max_delay(&self) -> Option<u16> {
+        if self.payload.AckMaxDelay_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.AckMaxDelay)
+        }
+    }
+
+    pub fn set_target_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L2))
+        }
+    }
+
+    pub fn set_target_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L3))
+        }
+    }
+
+    pub fn set_target_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower))
+        }
+    }
+
+    pub fn set_target_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L2))
+        }
+    }
+
+    pub fn set_target_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_Dynamic_AC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_Dynamic_AC_CLResControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BptScheduledAcClResMode {
+    payload: cglue::iso20_ac_BPT_Scheduled_AC_CLResControlModeType,
+}
+
+impl BptScheduledAcClResMode {
+    pub fn new() -> Self {
+        let payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_Scheduled_AC_CLResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_target_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower))
+        }
+    }
+
+    pub fn set_target_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L2))
+        }
+    }
+
+    pub fn set_target_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetActivePower_L3))
+        }
+    }
+
+    pub fn set_target_reactive_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower))
+        }
+    }
+
+    pub fn set_target_reactive_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L2))
+        }
+    }
+
+    pub fn set_target_reactive_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetReactivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSETargetReactivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_target_reactive_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetReactivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetReactivePower_L3))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_Scheduled_AC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_Scheduled_AC_CLResControlModeType {
+        self.payload
+    }
+}
+
+pub enum AcClResControlMode {
+    Dynamic(DynamicAcClResMode),
+    Scheduled(ScheduledAcClResMode),
+    BptDynamic(BptDynamicAcClResMode),
+    BptScheduled(BptScheduledAcClResMode),
+}
+
+pub struct AcChargeLoopResponse {
+    payload: cglue::iso20_ac_AC_ChargeLoopResType,
+}
+
+impl AcChargeLoopResponse {
+    pub fn new(code: ResponseCode, mode: &AcClResControlMode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeLoopResType>() };
+        payload.ResponseCode = code as u32;
+        match mode {
+            AcClResControlMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_AC_CLResControlMode = value.encode();
+                payload.set_Dynamic_AC_CLResControlMode_isUsed(1);
+            }
+            AcClResControlMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_AC_CLResControlMode = value.encode();
+                payload.set_Scheduled_AC_CLResControlMode_isUsed(1);
+            }
+            AcClResControlMode::BptDynamic(value) => {
+                payload.__bindgen_anon_1.BPT_Dynamic_AC_CLResControlMode = value.encode();
+                payload.set_BPT_Dynamic_AC_CLResControlMode_isUsed(1);
+            }
+            AcClResControlMode::BptScheduled(value) => {
+                payload.__bindgen_anon_1.BPT_Scheduled_AC_CLResControlMode = value.encode();
+                payload.set_BPT_Scheduled_AC_CLResControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn set_target_frequency(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSETargetFrequency = to_ac_rational(value);
+        self.payload.set_EVSETargetFrequency_isUsed(1);
+        self
+    }
+
+    pub fn get_target_frequency(&self) -> Option<RationalNumber> {
+        if self.payload.EVSETargetFrequency_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSETargetFrequency))
+        }
+    }
+
+    pub fn set_evse_status(&mut self, status: &EvseStatusType) -> &mut Self {
+        self.payload.EVSEStatus = to_ac_status(status);
+        self.payload.set_EVSEStatus_isUsed(1);
+        self
+    }
+
+    pub fn get_evse_status(&self) -> Option<EvseStatusType> {
+        if self.payload.EVSEStatus_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_status(self.payload.EVSEStatus))
+        }
+    }
+
+    pub fn get_control_mode(&self) -> Result<AcClResControlMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_AC_CLResControlMode_isUsed() != 0 {
+                AcClResControlMode::Dynamic(DynamicAcClResMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_AC_CLResControlMode,
+                ))
+            } else if self.payload.Scheduled_AC_CLResControlMode_isUsed() != 0 {
+                AcClResControlMode::Scheduled(ScheduledAcClResMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_AC_CLResControlMode,
+                ))
+            } else if self.payload.BPT_Dynamic_AC_CLResControlMode_isUsed() != 0 {
+                AcClResControlMode::BptDynamic(BptDynamicAcClResMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Dynamic_AC_CLResControlMode,
+                ))
+            } else if self.payload.BPT_Scheduled_AC_CLResControlMode_isUsed() != 0 {
+                AcClResControlMode::BptScheduled(BptScheduledAcClResMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Scheduled_AC_CLResControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-ac-charge-loop-res", "unsupported control mode");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_ChargeLoopResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20AcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20AcBodyType>();
+            exi_body.__bindgen_anon_1.AC_ChargeLoopRes = self.payload;
+            exi_body.set_AC_ChargeLoopRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DynamicAcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetEnergyRequest", self.get_target_energy())
+            .field("EVMaximumEnergyRequest", self.get_max_energy())
+            .field("EVMinimumEnergyRequest", self.get_min_energy())
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVPresentActivePower", self.get_present_active_power())
+            .field("EVPresentReactivePower", self.get_present_reactive_power())
+            .option("DepartureTime", self.get_departure_time())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option(
+                "EVPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            )
+            .option(
+                "EVPresentReactivePower_L2",
+                self.get_present_reactive_power_l2(),
+            )
+            .option(
+                "EVPresentReactivePower_L3",
+                self.get_present_reactive_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for ScheduledAcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            )
+            .option("EVTargetEnergyRequest", self.get_target_energy())
+            .option("EVMaximumEnergyRequest", self.get_max_energy())
+            .option("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("EVMaximumChargePower", self.get_max_charge_power())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower", self.get_min_charge_power())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option("EVPresentReactivePower", self.get_present_reactive_power())
+            .option(
+                "EVPresentReactivePower_L2",
+                self.get_present_reactive_power_l2(),
+            )
+            .option(
+                "EVPresentReactivePower_L3",
+                self.get_present_reactive_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for BptDynamicAcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetEnergyRequest", self.get_target_energy())
+            .field("EVMaximumEnergyRequest", self.get_max_energy())
+            .field("EVMinimumEnergyRequest", self.get_min_energy())
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVPresentActivePower", self.get_present_active_power())
+            .field("EVPresentReactivePower", self.get_present_reactive_power())
+            .field("EVMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVMinimumDischargePower", self.get_min_discharge_power())
+            .option("DepartureTime", self.get_departure_time())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option(
+                "EVPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            )
+            .option(
+                "EVPresentReactivePower_L2",
+                self.get_present_reactive_power_l2(),
+            )
+            .option(
+                "EVPresentReactivePower_L3",
+                self.get_present_reactive_power_l3(),
+            )
+            .option(
+                "EVMaximumDischargePower_L2",
+                self.get_max_discharge_power_l2(),
+            )
+            .option(
+                "EVMaximumDischargePower_L3",
+                self.get_max_discharge_power_l3(),
+            )
+            .option(
+                "EVMinimumDischargePower_L2",
+                self.get_min_discharge_power_l2(),
+            )
+            .option(
+                "EVMinimumDischargePower_L3",
+                self.get_min_discharge_power_l3(),
+            )
+            .option("EVMaximumV2XEnergyRequest", self.get_max_v2x_energy())
+            .option("EVMinimumV2XEnergyRequest", self.get_min_v2x_energy());
+    }
+}
+
+impl ExiFields for BptScheduledAcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            )
+            .option("EVTargetEnergyRequest", self.get_target_energy())
+            .option("EVMaximumEnergyRequest", self.get_max_energy())
+            .option("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("EVMaximumChargePower", self.get_max_charge_power())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower", self.get_min_charge_power())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option("EVPresentReactivePower", self.get_present_reactive_power())
+            .option(
+                "EVPresentReactivePower_L2",
+                self.get_present_reactive_power_l2(),
+            )
+            .option(
+                "EVPresentReactivePower_L3",
+                self.get_present_reactive_power_l3(),
+            )
+            .option("EVMaximumDischargePower", self.get_max_discharge_power())
+            .option(
+                "EVMaximumDischargePower_L2",
+                self.get_max_discharge_power_l2(),
+            )
+            .option(
+                "EVMaximumDischargePower_L3",
+                self.get_max_discharge_power_l3(),
+            )
+            .option("EVMinimumDischargePower", self.get_min_discharge_power())
+            .option(
+                "EVMinimumDischargePower_L2",
+                self.get_min_discharge_power_l2(),
+            )
+            .option(
+                "EVMinimumDischargePower_L3",
+                self.get_min_discharge_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for AcChargeLoopRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("MeterInfoRequested", self.get_meter_info_requested());
+        match self.get_control_mode() {
+            Ok(AcClReqControlMode::Dynamic(mode)) => {
+                fields.field("Dynamic_AC_CLReqControlMode", mode)
+            }
+            Ok(AcClReqControlMode::Scheduled(mode)) => {
+                fields.field("Scheduled_AC_CLReqControlMode", mode)
+            }
+            Ok(AcClReqControlMode::BptDynamic(mode)) => {
+                fields.field("BPT_Dynamic_AC_CLReqControlMode", mode)
+            }
+            Ok(AcClReqControlMode::BptScheduled(mode)) => {
+                fields.field("BPT_Scheduled_AC_CLReqControlMode", mode)
+            }
+            Err(error) => fields.field("AC_CLReqControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+
+impl ExiFields for DynamicAcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSETargetActivePower", self.get_target_active_power())
+            .option(
+                "EVSETargetActivePower_L2",
+                self.get_target_active_power_l2(),
+            )
+            .option(
+                "EVSETargetActivePower_L3",
+                self.get_target_active_power_l3(),
+            )
+            .option("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc())
+            .option("AckMaxDelay", self.get_ack_max_delay())
+            .option("EVSETargetReactivePower", self.get_target_reactive_power())
+            .option(
+                "EVSETargetReactivePower_L2",
+                self.get_target_reactive_power_l2(),
+            )
+            .option(
+                "EVSETargetReactivePower_L3",
+                self.get_target_reactive_power_l3(),
+            )
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for ScheduledAcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("EVSETargetActivePower", self.get_target_active_power())
+            .option(
+                "EVSETargetActivePower_L2",
+                self.get_target_active_power_l2(),
+            )
+            .option(
+                "EVSETargetActivePower_L3",
+                self.get_target_active_power_l3(),
+            )
+            .option("EVSETargetReactivePower", self.get_target_reactive_power())
+            .option(
+                "EVSETargetReactivePower_L2",
+                self.get_target_reactive_power_l2(),
+            )
+            .option(
+                "EVSETargetReactivePower_L3",
+                self.get_target_reactive_power_l3(),
+            )
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for BptDynamicAcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSETargetActivePower", self.get_target_active_power())
+            .option("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc())
+            .option("AckMaxDelay", self.get_ack_max_delay())
+            .option(
+                "EVSETargetActivePower_L2",
+                self.get_target_active_power_l2(),
+            )
+            .option(
+                "EVSETargetActivePower_L3",
+                self.get_target_active_power_l3(),
+            )
+            .option("EVSETargetReactivePower", self.get_target_reactive_power())
+            .option(
+                "EVSETargetReactivePower_L2",
+                self.get_target_reactive_power_l2(),
+            )
+            .option(
+                "EVSETargetReactivePower_L3",
+                self.get_target_reactive_power_l3(),
+            )
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for BptScheduledAcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("EVSETargetActivePower", self.get_target_active_power())
+            .option(
+                "EVSETargetActivePower_L2",
+                self.get_target_active_power_l2(),
+            )
+            .option(
+                "EVSETargetActivePower_L3",
+                self.get_target_active_power_l3(),
+            )
+            .option("EVSETargetReactivePower", self.get_target_reactive_power())
+            .option(
+                "EVSETargetReactivePower_L2",
+                self.get_target_reactive_power_l2(),
+            )
+            .option(
+                "EVSETargetReactivePower_L3",
+                self.get_target_reactive_power_l3(),
+            )
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for AcChargeLoopResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("EVSETargetFrequency", self.get_target_frequency())
+            .option("EVSEStatus", self.get_evse_status());
+        match self.get_control_mode() {
+            Ok(AcClResControlMode::Dynamic(mode)) => {
+                fields.field("Dynamic_AC_CLResControlMode", mode)
+            }
+            Ok(AcClResControlMode::Scheduled(mode)) => {
+                fields.field("Scheduled_AC_CLResControlMode", mode)
+            }
+            Ok(AcClResControlMode::BptDynamic(mode)) => {
+                fields.field("BPT_Dynamic_AC_CLResControlMode", mode)
+            }
+            Ok(AcClResControlMode::BptScheduled(mode)) => {
+                fields.field("BPT_Scheduled_AC_CLResControlMode", mode)
+            }
+            Err(error) => fields.field("AC_CLResControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+crate::exi_format!(
+    DynamicAcClReqMode,
+    ScheduledAcClReqMode,
+    BptDynamicAcClReqMode,
+    BptScheduledAcClReqMode,
+    AcChargeLoopRequest,
+    DynamicAcClResMode,
+    ScheduledAcClResMode,
+    BptDynamicAcClResMode,
+    BptScheduledAcClResMode,
+    AcChargeLoopResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicAcClReqModeXsd {
+        EVTargetEnergyRequest: RationalNumber,
+        EVMaximumEnergyRequest: RationalNumber,
+        EVMinimumEnergyRequest: RationalNumber,
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVPresentActivePower: RationalNumber,
+        EVPresentReactivePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L3: Option<RationalNumber>,
+    }
+
+    impl DynamicAcClReqModeXsd {
+        fn from_exi(exi: &DynamicAcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVPresentActivePower: exi.get_present_active_power(),
+                EVPresentReactivePower: exi.get_present_reactive_power(),
+                DepartureTime: exi.get_departure_time(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVPresentActivePower_L3: exi.get_present_active_power_l3(),
+                EVPresentReactivePower_L2: exi.get_present_reactive_power_l2(),
+                EVPresentReactivePower_L3: exi.get_present_reactive_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicAcClReqMode, AfbError> {
+            let mut exi = DynamicAcClReqMode::new(
+                &self.EVTargetEnergyRequest,
+                &self.EVMaximumEnergyRequest,
+                &self.EVMinimumEnergyRequest,
+                &self.EVMaximumChargePower,
+                &self.EVMinimumChargePower,
+                &self.EVPresentActivePower,
+                &self.EVPresentReactivePower,
+            );
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L2 {
+                exi.set_present_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L3 {
+                exi.set_present_reactive_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicAcClReqMode, DynamicAcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledAcClReqModeXsd {
+        EVPresentActivePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTargetEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L3: Option<RationalNumber>,
+    }
+
+    impl ScheduledAcClReqModeXsd {
+        fn from_exi(exi: &ScheduledAcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVPresentActivePower: exi.get_present_active_power(),
+                EVPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVPresentActivePower_L3: exi.get_present_active_power_l3(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVPresentReactivePower: exi.get_present_reactive_power(),
+                EVPresentReactivePower_L2: exi.get_present_reactive_power_l2(),
+                EVPresentReactivePower_L3: exi.get_present_reactive_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledAcClReqMode, AfbError> {
+            let mut exi = ScheduledAcClReqMode::new(&self.EVPresentActivePower);
+            if let Some(value) = &self.EVPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVTargetEnergyRequest {
+                exi.set_target_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumEnergyRequest {
+                exi.set_max_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumEnergyRequest {
+                exi.set_min_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower {
+                exi.set_present_reactive_power(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L2 {
+                exi.set_present_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L3 {
+                exi.set_present_reactive_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledAcClReqMode, ScheduledAcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDynamicAcClReqModeXsd {
+        EVTargetEnergyRequest: RationalNumber,
+        EVMaximumEnergyRequest: RationalNumber,
+        EVMinimumEnergyRequest: RationalNumber,
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVPresentActivePower: RationalNumber,
+        EVPresentReactivePower: RationalNumber,
+        EVMaximumDischargePower: RationalNumber,
+        EVMinimumDischargePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumV2XEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumV2XEnergyRequest: Option<RationalNumber>,
+    }
+
+    impl BptDynamicAcClReqModeXsd {
+        fn from_exi(exi: &BptDynamicAcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVPresentActivePower: exi.get_present_active_power(),
+                EVPresentReactivePower: exi.get_present_reactive_power(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                DepartureTime: exi.get_departure_time(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVPresentActivePower_L3: exi.get_present_active_power_l3(),
+                EVPresentReactivePower_L2: exi.get_present_reactive_power_l2(),
+                EVPresentReactivePower_L3: exi.get_present_reactive_power_l3(),
+                EVMaximumDischargePower_L2: exi.get_max_discharge_power_l2(),
+                EVMaximumDischargePower_L3: exi.get_max_discharge_power_l3(),
+                EVMinimumDischargePower_L2: exi.get_min_discharge_power_l2(),
+                EVMinimumDischargePower_L3: exi.get_min_discharge_power_l3(),
+                EVMaximumV2XEnergyRequest: exi.get_max_v2x_energy(),
+                EVMinimumV2XEnergyRequest: exi.get_min_v2x_energy(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDynamicAcClReqMode, AfbError> {
+            let mut exi = BptDynamicAcClReqMode::new(
+                &self.EVTargetEnergyRequest,
+                &self.EVMaximumEnergyRequest,
+                &self.EVMinimumEnergyRequest,
+                &self.EVMaximumChargePower,
+                &self.EVMinimumChargePower,
+                &self.EVPresentActivePower,
+                &self.EVPresentReactivePower,
+            );
+            exi.set_max_discharge_power(&self.EVMaximumDischargePower)
+                .set_min_discharge_power(&self.EVMinimumDischargePower);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L2 {
+                exi.set_present_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L3 {
+                exi.set_present_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L2 {
+                exi.set_max_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L3 {
+                exi.set_max_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L2 {
+                exi.set_min_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L3 {
+                exi.set_min_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMaximumV2XEnergyRequest {
+                exi.set_max_v2x_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumV2XEnergyRequest {
+                exi.set_min_v2x_energy(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDynamicAcClReqMode, BptDynamicAcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptScheduledAcClReqModeXsd {
+        EVPresentActivePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTargetEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVPresentReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L3: Option<RationalNumber>,
+    }
+
+    impl BptScheduledAcClReqModeXsd {
+        fn from_exi(exi: &BptScheduledAcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVPresentActivePower: exi.get_present_active_power(),
+                EVPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVPresentActivePower_L3: exi.get_present_active_power_l3(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVPresentReactivePower: exi.get_present_reactive_power(),
+                EVPresentReactivePower_L2: exi.get_present_reactive_power_l2(),
+                EVPresentReactivePower_L3: exi.get_present_reactive_power_l3(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMaximumDischargePower_L2: exi.get_max_discharge_power_l2(),
+                EVMaximumDischargePower_L3: exi.get_max_discharge_power_l3(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                EVMinimumDischargePower_L2: exi.get_min_discharge_power_l2(),
+                EVMinimumDischargePower_L3: exi.get_min_discharge_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptScheduledAcClReqMode, AfbError> {
+            let mut exi = BptScheduledAcClReqMode::new(&self.EVPresentActivePower);
+            if let Some(value) = &self.EVPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVTargetEnergyRequest {
+                exi.set_target_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumEnergyRequest {
+                exi.set_max_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumEnergyRequest {
+                exi.set_min_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower {
+                exi.set_present_reactive_power(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L2 {
+                exi.set_present_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVPresentReactivePower_L3 {
+                exi.set_present_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower {
+                exi.set_max_discharge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L2 {
+                exi.set_max_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L3 {
+                exi.set_max_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower {
+                exi.set_min_discharge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L2 {
+                exi.set_min_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L3 {
+                exi.set_min_discharge_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptScheduledAcClReqMode, BptScheduledAcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcChargeLoopReqXsd {
+        MeterInfoRequested: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_AC_CLReqControlMode: Option<DynamicAcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_AC_CLReqControlMode: Option<ScheduledAcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Dynamic_AC_CLReqControlMode: Option<BptDynamicAcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Scheduled_AC_CLReqControlMode: Option<BptScheduledAcClReqMode>,
+    }
+
+    impl AcChargeLoopReqXsd {
+        fn from_exi(exi: &AcChargeLoopRequest) -> Result<Self, AfbError> {
+            let mode = exi.get_control_mode()?;
+            Ok(Self {
+                MeterInfoRequested: exi.get_meter_info_requested(),
+                Dynamic_AC_CLReqControlMode: match &mode {
+                    AcClReqControlMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_AC_CLReqControlMode: match &mode {
+                    AcClReqControlMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Dynamic_AC_CLReqControlMode: match &mode {
+                    AcClReqControlMode::BptDynamic(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Scheduled_AC_CLReqControlMode: match &mode {
+                    AcClReqControlMode::BptScheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<AcChargeLoopRequest, AfbError> {
+            let mode = match (
+                self.Dynamic_AC_CLReqControlMode,
+                self.Scheduled_AC_CLReqControlMode,
+                self.BPT_Dynamic_AC_CLReqControlMode,
+                self.BPT_Scheduled_AC_CLReqControlMode,
+            ) {
+                (Some(value), None, None, None) => AcClReqControlMode::Dynamic(value),
+                (None, Some(value), None, None) => AcClReqControlMode::Scheduled(value),
+                (None, None, Some(value), None) => AcClReqControlMode::BptDynamic(value),
+                (None, None, None, Some(value)) => AcClReqControlMode::BptScheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-ac-charge-loop-req",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            let mut exi = AcChargeLoopRequest::new(&mode);
+            exi.set_meter_info_requested(self.MeterInfoRequested);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AcChargeLoopRequest, AcChargeLoopReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicAcClResModeXsd {
+        EVSETargetActivePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AckMaxDelay: Option<u16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl DynamicAcClResModeXsd {
+        fn from_exi(exi: &DynamicAcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSETargetActivePower: exi.get_target_active_power(),
+                EVSETargetActivePower_L2: exi.get_target_active_power_l2(),
+                EVSETargetActivePower_L3: exi.get_target_active_power_l3(),
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+                AckMaxDelay: exi.get_ack_max_delay(),
+                EVSETargetReactivePower: exi.get_target_reactive_power(),
+                EVSETargetReactivePower_L2: exi.get_target_reactive_power_l2(),
+                EVSETargetReactivePower_L3: exi.get_target_reactive_power_l3(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicAcClResMode, AfbError> {
+            let mut exi = DynamicAcClResMode::new(&self.EVSETargetActivePower);
+            if let Some(value) = &self.EVSETargetActivePower_L2 {
+                exi.set_target_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L3 {
+                exi.set_target_active_power_l3(value);
+            }
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = self.MinimumSOC {
+                exi.set_min_soc(value);
+            }
+            if let Some(value) = self.TargetSOC {
+                exi.set_target_soc(value);
+            }
+            if let Some(value) = self.AckMaxDelay {
+                exi.set_ack_max_delay(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower {
+                exi.set_target_reactive_power(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L2 {
+                exi.set_target_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L3 {
+                exi.set_target_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicAcClResMode, DynamicAcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledAcClResModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl ScheduledAcClResModeXsd {
+        fn from_exi(exi: &ScheduledAcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSETargetActivePower: exi.get_target_active_power(),
+                EVSETargetActivePower_L2: exi.get_target_active_power_l2(),
+                EVSETargetActivePower_L3: exi.get_target_active_power_l3(),
+                EVSETargetReactivePower: exi.get_target_reactive_power(),
+                EVSETargetReactivePower_L2: exi.get_target_reactive_power_l2(),
+                EVSETargetReactivePower_L3: exi.get_target_reactive_power_l3(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledAcClResMode, AfbError> {
+            let mut exi = ScheduledAcClResMode::new();
+            if let Some(value) = &self.EVSETargetActivePower {
+                exi.set_target_active_power(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L2 {
+                exi.set_target_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L3 {
+                exi.set_target_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower {
+                exi.set_target_reactive_power(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L2 {
+                exi.set_target_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L3 {
+                exi.set_target_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledAcClResMode, ScheduledAcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDynamicAcClResModeXsd {
+        EVSETargetActivePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AckMaxDelay: Option<u16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl BptDynamicAcClResModeXsd {
+        fn from_exi(exi: &BptDynamicAcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSETargetActivePower: exi.get_target_active_power(),
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+                AckMaxDelay: exi.get_ack_max_delay(),
+                EVSETargetActivePower_L2: exi.get_target_active_power_l2(),
+                EVSETargetActivePower_L3: exi.get_target_active_power_l3(),
+                EVSETargetReactivePower: exi.get_target_reactive_power(),
+                EVSETargetReactivePower_L2: exi.get_target_reactive_power_l2(),
+                EVSETargetReactivePower_L3: exi.get_target_reactive_power_l3(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDynamicAcClResMode, AfbError> {
+            let mut exi = BptDynamicAcClResMode::new(&self.EVSETargetActivePower);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = self.MinimumSOC {
+                exi.set_min_soc(value);
+            }
+            if let Some(value) = self.TargetSOC {
+                exi.set_target_soc(value);
+            }
+            if let Some(value) = self.AckMaxDelay {
+                exi.set_ack_max_delay(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L2 {
+                exi.set_target_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L3 {
+                exi.set_target_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower {
+                exi.set_target_reactive_power(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L2 {
+                exi.set_target_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L3 {
+                exi.set_target_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDynamicAcClResMode, BptDynamicAcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptScheduledAcClResModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetActivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetReactivePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl BptScheduledAcClResModeXsd {
+        fn from_exi(exi: &BptScheduledAcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSETargetActivePower: exi.get_target_active_power(),
+                EVSETargetActivePower_L2: exi.get_target_active_power_l2(),
+                EVSETargetActivePower_L3: exi.get_target_active_power_l3(),
+                EVSETargetReactivePower: exi.get_target_reactive_power(),
+                EVSETargetReactivePower_L2: exi.get_target_reactive_power_l2(),
+                EVSETargetReactivePower_L3: exi.get_target_reactive_power_l3(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptScheduledAcClResMode, AfbError> {
+            let mut exi = BptScheduledAcClResMode::new();
+            if let Some(value) = &self.EVSETargetActivePower {
+                exi.set_target_active_power(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L2 {
+                exi.set_target_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetActivePower_L3 {
+                exi.set_target_active_power_l3(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower {
+                exi.set_target_reactive_power(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L2 {
+                exi.set_target_reactive_power_l2(value);
+            }
+            if let Some(value) = &self.EVSETargetReactivePower_L3 {
+                exi.set_target_reactive_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptScheduledAcClResMode, BptScheduledAcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcChargeLoopResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETargetFrequency: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEStatus: Option<EvseStatusType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_AC_CLResControlMode: Option<DynamicAcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_AC_CLResControlMode: Option<ScheduledAcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Dynamic_AC_CLResControlMode: Option<BptDynamicAcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Scheduled_AC_CLResControlMode: Option<BptScheduledAcClResMode>,
+    }
+
+    impl AcChargeLoopResXsd {
+        fn from_exi(exi: &AcChargeLoopResponse) -> Result<Self, AfbError> {
+            let mode = exi.get_control_mode()?;
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSETargetFrequency: exi.get_target_frequency(),
+                EVSEStatus: exi.get_evse_status(),
+                Dynamic_AC_CLResControlMode: match &mode {
+                    AcClResControlMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_AC_CLResControlMode: match &mode {
+                    AcClResControlMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Dynamic_AC_CLResControlMode: match &mode {
+                    AcClResControlMode::BptDynamic(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Scheduled_AC_CLResControlMode: match &mode {
+                    AcClResControlMode::BptScheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<AcChargeLoopResponse, AfbError> {
+            let mode = match (
+                self.Dynamic_AC_CLResControlMode,
+                self.Scheduled_AC_CLResControlMode,
+                self.BPT_Dynamic_AC_CLResControlMode,
+                self.BPT_Scheduled_AC_CLResControlMode,
+            ) {
+                (Some(value), None, None, None) => AcClResControlMode::Dynamic(value),
+                (None, Some(value), None, None) => AcClResControlMode::Scheduled(value),
+                (None, None, Some(value), None) => AcClResControlMode::BptDynamic(value),
+                (None, None, None, Some(value)) => AcClResControlMode::BptScheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-ac-charge-loop-res",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            let mut exi = AcChargeLoopResponse::new(self.ResponseCode, &mode);
+            if let Some(value) = &self.EVSETargetFrequency {
+                exi.set_target_frequency(value);
+            }
+            if let Some(status) = &self.EVSEStatus {
+                exi.set_evse_status(status);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AcChargeLoopResponse, AcChargeLoopResXsd);
+}
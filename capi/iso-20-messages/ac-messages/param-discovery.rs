@@ -0,0 +1,1338 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone, Copy)]
+pub struct AcCpdReqEnergyTransfer {
+    payload: cglue::iso20_ac_AC_CPDReqEnergyTransferModeType,
+}
+
+impl AcCpdReqEnergyTransfer {
+    pub fn new(max_charge_power: &RationalNumber, min_charge_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_CPDReqEnergyTransferModeType>() };
+        payload.EVMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_ac_rational(min_charge_power);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_CPDReqEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_AC_CPDReqEnergyTransferModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct AcCpdResEnergyTransfer {
+    payload: cglue::iso20_ac_AC_CPDResEnergyTransferModeType,
+}
+
+impl AcCpdResEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        nominal_frequency: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_CPDResEnergyTransferModeType>() };
+        payload.EVSEMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_ac_rational(min_charge_power);
+        payload.EVSENominalFrequency = to_ac_rational(nominal_frequency);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_nominal_frequency(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSENominalFrequency)
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_max_power_asymmetry(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.MaximumPowerAsymmetry = to_ac_rational(value);
+        self.payload.set_MaximumPowerAsymmetry_isUsed(1);
+        self
+    }
+
+    pub fn get_max_power_asymmetry(&self) -> Option<RationalNumber> {
+        if self.payload.MaximumPowerAsymmetry_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.MaximumPowerAsymmetry))
+        }
+    }
+
+    pub fn set_power_ramp_limit(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPowerRampLimitation = to_ac_rational(value);
+        self.payload.set_EVSEPowerRampLimitation_isUsed(1);
+        self
+    }
+
+    pub fn get_power_ramp_limit(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPowerRampLimitation_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPowerRampLimitation))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_CPDResEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_AC_CPDResEnergyTransferModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory for BPT, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptAcCpdReqEnergyTransfer {
+    payload: cglue::iso20_ac_BPT_AC_CPDReqEnergyTransferModeType,
+}
+
+impl BptAcCpdReqEnergyTransfer {
+    pub fn new(max_charge_power: &RationalNumber, min_charge_power: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_AC_CPDReqEnergyTransferModeType>() };
+        payload.EVMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_ac_rational(min_charge_power);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVMinimumDischargePower)
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_max_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L2))
+        }
+    }
+
+    pub fn set_max_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMaximumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMaximumDischargePower_L3))
+        }
+    }
+
+    pub fn set_min_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L2))
+        }
+    }
+
+    pub fn set_min_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVMinimumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVMinimumDischargePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_AC_CPDReqEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_AC_CPDReqEnergyTransferModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory for BPT, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptAcCpdResEnergyTransfer {
+    payload: cglue::iso20_ac_BPT_AC_CPDResEnergyTransferModeType,
+}
+
+impl BptAcCpdResEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        nominal_frequency: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_BPT_AC_CPDResEnergyTransferModeType>() };
+        payload.EVSEMaximumChargePower = to_ac_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_ac_rational(min_charge_power);
+        payload.EVSENominalFrequency = to_ac_rational(nominal_frequency);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_nominal_frequency(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSENominalFrequency)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower = to_ac_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_ac_rational(self.payload.EVSEMinimumDischargePower)
+    }
+
+    pub fn set_max_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumChargePower_L2))
+        }
+    }
+
+    pub fn set_max_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumChargePower_L3))
+        }
+    }
+
+    pub fn set_min_charge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumChargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumChargePower_L2))
+        }
+    }
+
+    pub fn set_min_charge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumChargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumChargePower_L3))
+        }
+    }
+
+    pub fn set_max_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumDischargePower_L2))
+        }
+    }
+
+    pub fn set_max_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMaximumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMaximumDischargePower_L3))
+        }
+    }
+
+    pub fn set_min_discharge_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumDischargePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumDischargePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumDischargePower_L2))
+        }
+    }
+
+    pub fn set_min_discharge_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEMinimumDischargePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumDischargePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEMinimumDischargePower_L3))
+        }
+    }
+
+    pub fn set_max_power_asymmetry(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.MaximumPowerAsymmetry = to_ac_rational(value);
+        self.payload.set_MaximumPowerAsymmetry_isUsed(1);
+        self
+    }
+
+    pub fn get_max_power_asymmetry(&self) -> Option<RationalNumber> {
+        if self.payload.MaximumPowerAsymmetry_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.MaximumPowerAsymmetry))
+        }
+    }
+
+    pub fn set_power_ramp_limit(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPowerRampLimitation = to_ac_rational(value);
+        self.payload.set_EVSEPowerRampLimitation_isUsed(1);
+        self
+    }
+
+    pub fn get_power_ramp_limit(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPowerRampLimitation_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPowerRampLimitation))
+        }
+    }
+
+    pub fn set_present_active_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower))
+        }
+    }
+
+    pub fn set_present_active_power_l2(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L2 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L2_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l2(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L2_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L2))
+        }
+    }
+
+    pub fn set_present_active_power_l3(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPresentActivePower_L3 = to_ac_rational(value);
+        self.payload.set_EVSEPresentActivePower_L3_isUsed(1);
+        self
+    }
+
+    pub fn get_present_active_power_l3(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPresentActivePower_L3_isUsed() == 0 {
+            None
+        } else {
+            Some(from_ac_rational(self.payload.EVSEPresentActivePower_L3))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_BPT_AC_CPDResEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ac_BPT_AC_CPDResEnergyTransferModeType {
+        self.payload
+    }
+}
+
+pub struct AcChargeParamDiscoveryRequest {
+    payload: cglue::iso20_ac_AC_ChargeParameterDiscoveryReqType,
+}
+
+impl AcChargeParamDiscoveryRequest {
+    pub fn new(transfer: &AcCpdReqEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeParameterDiscoveryReqType>() };
+        payload.__bindgen_anon_1.AC_CPDReqEnergyTransferMode = transfer.encode();
+        payload.set_AC_CPDReqEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn new_bpt(transfer: &BptAcCpdReqEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeParameterDiscoveryReqType>() };
+        payload.__bindgen_anon_1.BPT_AC_CPDReqEnergyTransferMode = transfer.encode();
+        payload.set_BPT_AC_CPDReqEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn get_energy_transfer(&self) -> Option<AcCpdReqEnergyTransfer> {
+        if self.payload.AC_CPDReqEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(AcCpdReqEnergyTransfer::decode(unsafe {
+                self.payload.__bindgen_anon_1.AC_CPDReqEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn get_bpt_energy_transfer(&self) -> Option<BptAcCpdReqEnergyTransfer> {
+        if self.payload.BPT_AC_CPDReqEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(BptAcCpdReqEnergyTransfer::decode(unsafe {
+                self.payload
+                    .__bindgen_anon_1
+                    .BPT_AC_CPDReqEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_ChargeParameterDiscoveryReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20AcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20AcBodyType>();
+            exi_body.__bindgen_anon_1.AC_ChargeParameterDiscoveryReq = self.payload;
+            exi_body.set_AC_ChargeParameterDiscoveryReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct AcChargeParamDiscoveryResponse {
+    payload: cglue::iso20_ac_AC_ChargeParameterDiscoveryResType,
+}
+
+impl AcChargeParamDiscoveryResponse {
+    pub fn new(code: ResponseCode, transfer: &AcCpdResEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeParameterDiscoveryResType>() };
+        payload.ResponseCode = code as u32;
+        payload.__bindgen_anon_1.AC_CPDResEnergyTransferMode = transfer.encode();
+        payload.set_AC_CPDResEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn new_bpt(code: ResponseCode, transfer: &BptAcCpdResEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_ac_AC_ChargeParameterDiscoveryResType>() };
+        payload.ResponseCode = code as u32;
+        payload.__bindgen_anon_1.BPT_AC_CPDResEnergyTransferMode = transfer.encode();
+        payload.set_BPT_AC_CPDResEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_energy_transfer(&self) -> Option<AcCpdResEnergyTransfer> {
+        if self.payload.AC_CPDResEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(AcCpdResEnergyTransfer::decode(unsafe {
+                self.payload.__bindgen_anon_1.AC_CPDResEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn get_bpt_energy_transfer(&self) -> Option<BptAcCpdResEnergyTransfer> {
+        if self.payload.BPT_AC_CPDResEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(BptAcCpdResEnergyTransfer::decode(unsafe {
+                self.payload
+                    .__bindgen_anon_1
+                    .BPT_AC_CPDResEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ac_AC_ChargeParameterDiscoveryResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20AcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20AcBodyType>();
+            exi_body.__bindgen_anon_1.AC_ChargeParameterDiscoveryRes = self.payload;
+            exi_body.set_AC_ChargeParameterDiscoveryRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for AcCpdReqEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3());
+    }
+}
+
+impl ExiFields for AcCpdResEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSENominalFrequency", self.get_nominal_frequency())
+            .option("EVSEMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVSEMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVSEMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVSEMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option("MaximumPowerAsymmetry", self.get_max_power_asymmetry())
+            .option("EVSEPowerRampLimitation", self.get_power_ramp_limit())
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for BptAcCpdReqEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVMinimumDischargePower", self.get_min_discharge_power())
+            .option("EVMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option(
+                "EVMaximumDischargePower_L2",
+                self.get_max_discharge_power_l2(),
+            )
+            .option(
+                "EVMaximumDischargePower_L3",
+                self.get_max_discharge_power_l3(),
+            )
+            .option(
+                "EVMinimumDischargePower_L2",
+                self.get_min_discharge_power_l2(),
+            )
+            .option(
+                "EVMinimumDischargePower_L3",
+                self.get_min_discharge_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for BptAcCpdResEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSENominalFrequency", self.get_nominal_frequency())
+            .field("EVSEMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVSEMinimumDischargePower", self.get_min_discharge_power())
+            .option("EVSEMaximumChargePower_L2", self.get_max_charge_power_l2())
+            .option("EVSEMaximumChargePower_L3", self.get_max_charge_power_l3())
+            .option("EVSEMinimumChargePower_L2", self.get_min_charge_power_l2())
+            .option("EVSEMinimumChargePower_L3", self.get_min_charge_power_l3())
+            .option(
+                "EVSEMaximumDischargePower_L2",
+                self.get_max_discharge_power_l2(),
+            )
+            .option(
+                "EVSEMaximumDischargePower_L3",
+                self.get_max_discharge_power_l3(),
+            )
+            .option(
+                "EVSEMinimumDischargePower_L2",
+                self.get_min_discharge_power_l2(),
+            )
+            .option(
+                "EVSEMinimumDischargePower_L3",
+                self.get_min_discharge_power_l3(),
+            )
+            .option("MaximumPowerAsymmetry", self.get_max_power_asymmetry())
+            .option("EVSEPowerRampLimitation", self.get_power_ramp_limit())
+            .option("EVSEPresentActivePower", self.get_present_active_power())
+            .option(
+                "EVSEPresentActivePower_L2",
+                self.get_present_active_power_l2(),
+            )
+            .option(
+                "EVSEPresentActivePower_L3",
+                self.get_present_active_power_l3(),
+            );
+    }
+}
+
+impl ExiFields for AcChargeParamDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("AC_CPDReqEnergyTransferMode", self.get_energy_transfer())
+            .option(
+                "BPT_AC_CPDReqEnergyTransferMode",
+                self.get_bpt_energy_transfer(),
+            );
+    }
+}
+
+impl ExiFields for AcChargeParamDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("AC_CPDResEnergyTransferMode", self.get_energy_transfer())
+            .option(
+                "BPT_AC_CPDResEnergyTransferMode",
+                self.get_bpt_energy_transfer(),
+            );
+    }
+}
+crate::exi_format!(
+    AcCpdReqEnergyTransfer,
+    AcCpdResEnergyTransfer,
+    BptAcCpdReqEnergyTransfer,
+    BptAcCpdResEnergyTransfer,
+    AcChargeParamDiscoveryRequest,
+    AcChargeParamDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct AcCpdReqEnergyTransferXsd {
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+    }
+
+    impl AcCpdReqEnergyTransferXsd {
+        fn from_exi(exi: &AcCpdReqEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcCpdReqEnergyTransfer, AfbError> {
+            let mut exi =
+                AcCpdReqEnergyTransfer::new(&self.EVMaximumChargePower, &self.EVMinimumChargePower);
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AcCpdReqEnergyTransfer, AcCpdReqEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcCpdResEnergyTransferXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSENominalFrequency: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MaximumPowerAsymmetry: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPowerRampLimitation: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl AcCpdResEnergyTransferXsd {
+        fn from_exi(exi: &AcCpdResEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSENominalFrequency: exi.get_nominal_frequency(),
+                EVSEMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVSEMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVSEMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVSEMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                MaximumPowerAsymmetry: exi.get_max_power_asymmetry(),
+                EVSEPowerRampLimitation: exi.get_power_ramp_limit(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcCpdResEnergyTransfer, AfbError> {
+            let mut exi = AcCpdResEnergyTransfer::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSENominalFrequency,
+            );
+            if let Some(value) = &self.EVSEMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.MaximumPowerAsymmetry {
+                exi.set_max_power_asymmetry(value);
+            }
+            if let Some(value) = &self.EVSEPowerRampLimitation {
+                exi.set_power_ramp_limit(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AcCpdResEnergyTransfer, AcCpdResEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptAcCpdReqEnergyTransferXsd {
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVMaximumDischargePower: RationalNumber,
+        EVMinimumDischargePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower_L3: Option<RationalNumber>,
+    }
+
+    impl BptAcCpdReqEnergyTransferXsd {
+        fn from_exi(exi: &BptAcCpdReqEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                EVMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVMaximumDischargePower_L2: exi.get_max_discharge_power_l2(),
+                EVMaximumDischargePower_L3: exi.get_max_discharge_power_l3(),
+                EVMinimumDischargePower_L2: exi.get_min_discharge_power_l2(),
+                EVMinimumDischargePower_L3: exi.get_min_discharge_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptAcCpdReqEnergyTransfer, AfbError> {
+            let mut exi = BptAcCpdReqEnergyTransfer::new(
+                &self.EVMaximumChargePower,
+                &self.EVMinimumChargePower,
+            );
+            exi.set_max_discharge_power(&self.EVMaximumDischargePower)
+                .set_min_discharge_power(&self.EVMinimumDischargePower);
+            if let Some(value) = &self.EVMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L2 {
+                exi.set_max_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower_L3 {
+                exi.set_max_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L2 {
+                exi.set_min_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower_L3 {
+                exi.set_min_discharge_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptAcCpdReqEnergyTransfer, BptAcCpdReqEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptAcCpdResEnergyTransferXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSENominalFrequency: RationalNumber,
+        EVSEMaximumDischargePower: RationalNumber,
+        EVSEMinimumDischargePower: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumDischargePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumDischargePower_L3: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MaximumPowerAsymmetry: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPowerRampLimitation: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L2: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPresentActivePower_L3: Option<RationalNumber>,
+    }
+
+    impl BptAcCpdResEnergyTransferXsd {
+        fn from_exi(exi: &BptAcCpdResEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSENominalFrequency: exi.get_nominal_frequency(),
+                EVSEMaximumDischargePower: exi.get_max_discharge_power(),
+                EVSEMinimumDischargePower: exi.get_min_discharge_power(),
+                EVSEMaximumChargePower_L2: exi.get_max_charge_power_l2(),
+                EVSEMaximumChargePower_L3: exi.get_max_charge_power_l3(),
+                EVSEMinimumChargePower_L2: exi.get_min_charge_power_l2(),
+                EVSEMinimumChargePower_L3: exi.get_min_charge_power_l3(),
+                EVSEMaximumDischargePower_L2: exi.get_max_discharge_power_l2(),
+                EVSEMaximumDischargePower_L3: exi.get_max_discharge_power_l3(),
+                EVSEMinimumDischargePower_L2: exi.get_min_discharge_power_l2(),
+                EVSEMinimumDischargePower_L3: exi.get_min_discharge_power_l3(),
+                MaximumPowerAsymmetry: exi.get_max_power_asymmetry(),
+                EVSEPowerRampLimitation: exi.get_power_ramp_limit(),
+                EVSEPresentActivePower: exi.get_present_active_power(),
+                EVSEPresentActivePower_L2: exi.get_present_active_power_l2(),
+                EVSEPresentActivePower_L3: exi.get_present_active_power_l3(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptAcCpdResEnergyTransfer, AfbError> {
+            let mut exi = BptAcCpdResEnergyTransfer::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSENominalFrequency,
+            );
+            exi.set_max_discharge_power(&self.EVSEMaximumDischargePower)
+                .set_min_discharge_power(&self.EVSEMinimumDischargePower);
+            if let Some(value) = &self.EVSEMaximumChargePower_L2 {
+                exi.set_max_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMaximumChargePower_L3 {
+                exi.set_max_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower_L2 {
+                exi.set_min_charge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower_L3 {
+                exi.set_min_charge_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEMaximumDischargePower_L2 {
+                exi.set_max_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMaximumDischargePower_L3 {
+                exi.set_max_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.EVSEMinimumDischargePower_L2 {
+                exi.set_min_discharge_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEMinimumDischargePower_L3 {
+                exi.set_min_discharge_power_l3(value);
+            }
+            if let Some(value) = &self.MaximumPowerAsymmetry {
+                exi.set_max_power_asymmetry(value);
+            }
+            if let Some(value) = &self.EVSEPowerRampLimitation {
+                exi.set_power_ramp_limit(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower {
+                exi.set_present_active_power(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L2 {
+                exi.set_present_active_power_l2(value);
+            }
+            if let Some(value) = &self.EVSEPresentActivePower_L3 {
+                exi.set_present_active_power_l3(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptAcCpdResEnergyTransfer, BptAcCpdResEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcChargeParameterDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_CPDReqEnergyTransferMode: Option<AcCpdReqEnergyTransfer>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_AC_CPDReqEnergyTransferMode: Option<BptAcCpdReqEnergyTransfer>,
+    }
+
+    impl AcChargeParameterDiscoveryReqXsd {
+        fn from_exi(exi: &AcChargeParamDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                AC_CPDReqEnergyTransferMode: exi.get_energy_transfer(),
+                BPT_AC_CPDReqEnergyTransferMode: exi.get_bpt_energy_transfer(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcChargeParamDiscoveryRequest, AfbError> {
+            match (
+                &self.AC_CPDReqEnergyTransferMode,
+                &self.BPT_AC_CPDReqEnergyTransferMode,
+            ) {
+                (Some(transfer), None) => Ok(AcChargeParamDiscoveryRequest::new(transfer)),
+                (None, Some(transfer)) => Ok(AcChargeParamDiscoveryRequest::new_bpt(transfer)),
+                _ => afb_error!(
+                    "iso20-ac-param-discovery-req",
+                    "expect exactly one energy transfer mode"
+                ),
+            }
+        }
+    }
+    crate::exi_serde_mirror!(
+        AcChargeParamDiscoveryRequest,
+        AcChargeParameterDiscoveryReqXsd
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct AcChargeParameterDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_CPDResEnergyTransferMode: Option<AcCpdResEnergyTransfer>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_AC_CPDResEnergyTransferMode: Option<BptAcCpdResEnergyTransfer>,
+    }
+
+    impl AcChargeParameterDiscoveryResXsd {
+        fn from_exi(exi: &AcChargeParamDiscoveryResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                AC_CPDResEnergyTransferMode: exi.get_energy_transfer(),
+                BPT_AC_CPDResEnergyTransferMode: exi.get_bpt_energy_transfer(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcChargeParamDiscoveryResponse, AfbError> {
+            match (
+                &self.AC_CPDResEnergyTransferMode,
+                &self.BPT_AC_CPDResEnergyTransferMode,
+            ) {
+                (Some(transfer), None) => Ok(AcChargeParamDiscoveryResponse::new(
+                    self.ResponseCode,
+                    transfer,
+                )),
+                (None, Some(transfer)) => Ok(AcChargeParamDiscoveryResponse::new_bpt(
+                    self.ResponseCode,
+                    transfer,
+                )),
+                _ => afb_error!(
+                    "iso20-ac-param-discovery-res",
+                    "expect exactly one energy transfer mode"
+                ),
+            }
+        }
+    }
+    crate::exi_serde_mirror!(
+        AcChargeParamDiscoveryResponse,
+        AcChargeParameterDiscoveryResXsd
+    );
+}
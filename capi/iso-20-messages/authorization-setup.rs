@@ -0,0 +1,231 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone)]
+pub struct AuthorizationSetupRequest {
+    payload: cglue::iso20_AuthorizationSetupReqType,
+}
+impl AuthorizationSetupRequest {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_AuthorizationSetupReqType>() };
+        Self { payload }
+    }
+
+    pub fn decode(payload: cglue::iso20_AuthorizationSetupReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.AuthorizationSetupReq = self.payload;
+            exi_body.set_AuthorizationSetupReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct AuthorizationSetupResponse {
+    payload: cglue::iso20_AuthorizationSetupResType,
+}
+
+impl AuthorizationSetupResponse {
+    pub fn new(code: ResponseCode, cert_install_service: bool) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_AuthorizationSetupResType>() };
+        payload.ResponseCode = code as u32;
+        payload.CertificateInstallationService = if cert_install_service { 1 } else { 0 };
+        // EIM is the default mode, PnC is selected when a challenge is provided
+        payload.set_EIM_ASResAuthorizationMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_cert_install_service(&self) -> bool {
+        if self.payload.CertificateInstallationService == 0 {
+            false
+        } else {
+            true
+        }
+    }
+
+    pub fn add_authorization(&mut self, auth: AuthorizationType) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.AuthorizationServices.arrayLen;
+        if idx == cglue::iso20_authorizationType_2_ARRAY_SIZE as u16 {
+            return afb_error!(
+                "iso20-auth-setup-res",
+                "fail to add authorization service (array full)"
+            );
+        }
+        self.payload.AuthorizationServices.array[idx as usize] = auth as u32;
+        self.payload.AuthorizationServices.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_authorizations(&self) -> Result<Vec<AuthorizationType>, AfbError> {
+        let mut response = Vec::new();
+        for idx in 0..self.payload.AuthorizationServices.arrayLen {
+            response.push(AuthorizationType::from_u32(
+                self.payload.AuthorizationServices.array[idx as usize],
+            )?);
+        }
+        Ok(response)
+    }
+
+    pub fn set_pnc_challenge(&mut self, challenge: &[u8]) -> Result<&mut Self, AfbError> {
+        let pnc_mode = unsafe { &mut self.payload.__bindgen_anon_1.PnC_ASResAuthorizationMode };
+        pnc_mode.GenChallenge.bytesLen = bytes_to_array(
+            challenge,
+            &mut pnc_mode.GenChallenge.bytes,
+            cglue::iso20_genChallengeType_BYTES_SIZE,
+        )?;
+        self.payload.set_EIM_ASResAuthorizationMode_isUsed(0);
+        self.payload.set_PnC_ASResAuthorizationMode_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_pnc_challenge(&self) -> Option<&[u8]> {
+        if self.payload.PnC_ASResAuthorizationMode_isUsed() == 0 {
+            None
+        } else {
+            let pnc_mode = unsafe { &self.payload.__bindgen_anon_1.PnC_ASResAuthorizationMode };
+            Some(array_to_bytes(
+                &pnc_mode.GenChallenge.bytes,
+                pnc_mode.GenChallenge.bytesLen,
+            ))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_AuthorizationSetupResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.AuthorizationSetupRes = self.payload;
+            exi_body.set_AuthorizationSetupRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for AuthorizationSetupRequest {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for AuthorizationSetupResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result(
+                "AuthorizationServices",
+                self.get_authorizations().map(ExiList),
+            )
+            .field(
+                "CertificateInstallationService",
+                self.get_cert_install_service(),
+            )
+            .option("GenChallenge", self.get_pnc_challenge().map(ExiHexa));
+    }
+}
+crate::exi_format!(AuthorizationSetupRequest, AuthorizationSetupResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationSetupReqXsd {}
+
+    impl AuthorizationSetupReqXsd {
+        fn from_exi(_exi: &AuthorizationSetupRequest) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<AuthorizationSetupRequest, AfbError> {
+            Ok(AuthorizationSetupRequest::new())
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationSetupRequest, AuthorizationSetupReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct EimAsResAuthorizationModeXsd {}
+
+    #[derive(Serialize, Deserialize)]
+    struct PncAsResAuthorizationModeXsd {
+        GenChallenge: ExiBytes,
+    }
+
+    // authorization mode is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationSetupResXsd {
+        ResponseCode: ResponseCode,
+        AuthorizationServices: Vec<AuthorizationType>,
+        CertificateInstallationService: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EIM_ASResAuthorizationMode: Option<EimAsResAuthorizationModeXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        PnC_ASResAuthorizationMode: Option<PncAsResAuthorizationModeXsd>,
+    }
+
+    impl AuthorizationSetupResXsd {
+        fn from_exi(exi: &AuthorizationSetupResponse) -> Result<Self, AfbError> {
+            let eim_mode = if exi.payload.EIM_ASResAuthorizationMode_isUsed() == 0 {
+                None
+            } else {
+                Some(EimAsResAuthorizationModeXsd {})
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                AuthorizationServices: exi.get_authorizations()?,
+                CertificateInstallationService: exi.get_cert_install_service(),
+                EIM_ASResAuthorizationMode: eim_mode,
+                PnC_ASResAuthorizationMode: exi.get_pnc_challenge().map(|challenge| {
+                    PncAsResAuthorizationModeXsd {
+                        GenChallenge: ExiBytes::from(challenge),
+                    }
+                }),
+            })
+        }
+
+        fn into_exi(self) -> Result<AuthorizationSetupResponse, AfbError> {
+            let mut exi = AuthorizationSetupResponse::new(
+                self.ResponseCode,
+                self.CertificateInstallationService,
+            );
+            for auth in self.AuthorizationServices {
+                exi.add_authorization(auth)?;
+            }
+            if let Some(pnc_mode) = self.PnC_ASResAuthorizationMode {
+                exi.set_pnc_challenge(pnc_mode.GenChallenge.as_slice())?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationSetupResponse, AuthorizationSetupResXsd);
+}
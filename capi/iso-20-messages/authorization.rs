@@ -0,0 +1,241 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone)]
+pub struct AuthorizationRequest {
+    payload: cglue::iso20_AuthorizationReqType,
+}
+
+impl AuthorizationRequest {
+    pub fn new(auth: AuthorizationType) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_AuthorizationReqType>() };
+        payload.SelectedAuthorizationService = auth as u32;
+        match auth {
+            AuthorizationType::Eim => payload.set_EIM_AReqAuthorizationMode_isUsed(1),
+            AuthorizationType::Pnc => payload.set_PnC_AReqAuthorizationMode_isUsed(1),
+        }
+        Self { payload }
+    }
+
+    pub fn get_authorization(&self) -> Result<AuthorizationType, AfbError> {
+        AuthorizationType::from_u32(self.payload.SelectedAuthorizationService)
+    }
+
+    pub fn set_pnc_id(&mut self, value: &str) -> Result<&mut Self, AfbError> {
+        if self.payload.PnC_AReqAuthorizationMode_isUsed() == 0 {
+            return afb_error!("iso20-auth-req", "fail to set id (EIM authorization mode)");
+        }
+        let pnc_mode = unsafe { &mut self.payload.__bindgen_anon_1.PnC_AReqAuthorizationMode };
+        pnc_mode.Id.charactersLen = str_to_array(
+            value,
+            &mut pnc_mode.Id.characters,
+            cglue::iso20_Id_CHARACTER_SIZE,
+        )?;
+        Ok(self)
+    }
+
+    pub fn get_pnc_id(&self) -> Option<&str> {
+        if self.payload.PnC_AReqAuthorizationMode_isUsed() == 0 {
+            None
+        } else {
+            let pnc_mode = unsafe { &self.payload.__bindgen_anon_1.PnC_AReqAuthorizationMode };
+            array_to_str(&pnc_mode.Id.characters, pnc_mode.Id.charactersLen).ok()
+        }
+    }
+
+    pub fn set_pnc_challenge(&mut self, value: &[u8]) -> Result<&mut Self, AfbError> {
+        if self.payload.PnC_AReqAuthorizationMode_isUsed() == 0 {
+            return afb_error!(
+                "iso20-auth-req",
+                "fail to set challenge (EIM authorization mode)"
+            );
+        }
+        let pnc_mode = unsafe { &mut self.payload.__bindgen_anon_1.PnC_AReqAuthorizationMode };
+        pnc_mode.GenChallenge.bytesLen = bytes_to_array(
+            value,
+            &mut pnc_mode.GenChallenge.bytes,
+            cglue::iso20_genChallengeType_BYTES_SIZE,
+        )?;
+        Ok(self)
+    }
+
+    pub fn get_pnc_challenge(&self) -> Option<&[u8]> {
+        if self.payload.PnC_AReqAuthorizationMode_isUsed() == 0 {
+            None
+        } else {
+            let pnc_mode = unsafe { &self.payload.__bindgen_anon_1.PnC_AReqAuthorizationMode };
+            Some(array_to_bytes(
+                &pnc_mode.GenChallenge.bytes,
+                pnc_mode.GenChallenge.bytesLen,
+            ))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_AuthorizationReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.AuthorizationReq = self.payload;
+            exi_body.set_AuthorizationReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct AuthorizationResponse {
+    payload: cglue::iso20_AuthorizationResType,
+}
+
+impl AuthorizationResponse {
+    pub fn new(code: ResponseCode, processing: EvseProcessing) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_AuthorizationResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEProcessing = processing as u32;
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVSEProcessing)
+    }
+
+    pub fn decode(payload: cglue::iso20_AuthorizationResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.AuthorizationRes = self.payload;
+            exi_body.set_AuthorizationRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for AuthorizationRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("SelectedAuthorizationService", self.get_authorization())
+            .option("Id", self.get_pnc_id())
+            .option("GenChallenge", self.get_pnc_challenge().map(ExiHexa));
+    }
+}
+
+impl ExiFields for AuthorizationResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing());
+    }
+}
+crate::exi_format!(AuthorizationRequest, AuthorizationResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct EimAReqAuthorizationModeXsd {}
+
+    #[derive(Serialize, Deserialize)]
+    struct PncAReqAuthorizationModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        GenChallenge: ExiBytes,
+    }
+
+    // authorization mode is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationReqXsd {
+        SelectedAuthorizationService: AuthorizationType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EIM_AReqAuthorizationMode: Option<EimAReqAuthorizationModeXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        PnC_AReqAuthorizationMode: Option<PncAReqAuthorizationModeXsd>,
+    }
+
+    impl AuthorizationReqXsd {
+        fn from_exi(exi: &AuthorizationRequest) -> Result<Self, AfbError> {
+            let eim_mode = if exi.payload.EIM_AReqAuthorizationMode_isUsed() == 0 {
+                None
+            } else {
+                Some(EimAReqAuthorizationModeXsd {})
+            };
+            let pnc_mode = exi
+                .get_pnc_challenge()
+                .map(|challenge| PncAReqAuthorizationModeXsd {
+                    Id: exi.get_pnc_id().map(|id| id.to_string()),
+                    GenChallenge: ExiBytes::from(challenge),
+                });
+            Ok(Self {
+                SelectedAuthorizationService: exi.get_authorization()?,
+                EIM_AReqAuthorizationMode: eim_mode,
+                PnC_AReqAuthorizationMode: pnc_mode,
+            })
+        }
+
+        fn into_exi(self) -> Result<AuthorizationRequest, AfbError> {
+            let mut exi = AuthorizationRequest::new(self.SelectedAuthorizationService);
+            if let Some(pnc_mode) = self.PnC_AReqAuthorizationMode {
+                exi.set_pnc_challenge(pnc_mode.GenChallenge.as_slice())?;
+                if let Some(id) = pnc_mode.Id {
+                    exi.set_pnc_id(&id)?;
+                }
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationRequest, AuthorizationReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+    }
+
+    impl AuthorizationResXsd {
+        fn from_exi(exi: &AuthorizationResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<AuthorizationResponse, AfbError> {
+            Ok(AuthorizationResponse::new(
+                self.ResponseCode,
+                self.EVSEProcessing,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationResponse, AuthorizationResXsd);
+}
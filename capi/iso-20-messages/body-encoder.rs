@@ -0,0 +1,293 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use crate::prelude::v2g::*;
+use std::mem;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// iso20 has no Body element, messages are directly exi document entries
+pub type Iso20BodyType = cglue::iso20_exiDocument;
+
+pub struct ExiMessageHeader {
+    payload: cglue::iso20_MessageHeaderType,
+}
+
+impl ExiMessageHeader {
+    pub fn new(session_id: &[u8]) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_MessageHeaderType>() };
+        payload.SessionID.bytesLen = bytes_to_array(
+            session_id,
+            &mut payload.SessionID.bytes,
+            cglue::iso20_sessionIDType_BYTES_SIZE,
+        )?;
+
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(time) => payload.TimeStamp = time.as_secs(),
+            Err(_) => {
+                return afb_error!("iso20-header-new", "Invalid system time (should be fixed)")
+            }
+        };
+        Ok(Self { payload })
+    }
+
+    pub fn set_timestamp(&mut self, timestamp: u64) -> &mut Self {
+        self.payload.TimeStamp = timestamp;
+        self
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.payload.TimeStamp
+    }
+
+    pub fn get_signature_used(&self) -> bool {
+        if self.payload.Signature_isUsed() == 0 {
+            false
+        } else {
+            true
+        }
+    }
+
+    pub fn get_session_id(&self) -> &[u8] {
+        array_to_bytes(
+            &self.payload.SessionID.bytes,
+            self.payload.SessionID.bytesLen,
+        )
+    }
+
+    pub fn decode(payload: cglue::iso20_MessageHeaderType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_MessageHeaderType {
+        self.payload
+    }
+}
+
+// return the header of whatever message is active within iso20 exi document
+fn get_header_mut(
+    doc: &mut cglue::iso20_exiDocument,
+) -> Result<&mut cglue::iso20_MessageHeaderType, AfbError> {
+    let header = unsafe {
+        let body = &mut doc.__bindgen_anon_1;
+        if doc.SessionSetupReq_isUsed() == 1 {
+            &mut body.SessionSetupReq.Header
+        } else if doc.SessionSetupRes_isUsed() == 1 {
+            &mut body.SessionSetupRes.Header
+        } else if doc.AuthorizationSetupReq_isUsed() == 1 {
+            &mut body.AuthorizationSetupReq.Header
+        } else if doc.AuthorizationSetupRes_isUsed() == 1 {
+            &mut body.AuthorizationSetupRes.Header
+        } else if doc.AuthorizationReq_isUsed() == 1 {
+            &mut body.AuthorizationReq.Header
+        } else if doc.AuthorizationRes_isUsed() == 1 {
+            &mut body.AuthorizationRes.Header
+        } else if doc.ServiceDiscoveryReq_isUsed() == 1 {
+            &mut body.ServiceDiscoveryReq.Header
+        } else if doc.ServiceDiscoveryRes_isUsed() == 1 {
+            &mut body.ServiceDiscoveryRes.Header
+        } else if doc.ServiceDetailReq_isUsed() == 1 {
+            &mut body.ServiceDetailReq.Header
+        } else if doc.ServiceDetailRes_isUsed() == 1 {
+            &mut body.ServiceDetailRes.Header
+        } else if doc.ServiceSelectionReq_isUsed() == 1 {
+            &mut body.ServiceSelectionReq.Header
+        } else if doc.ServiceSelectionRes_isUsed() == 1 {
+            &mut body.ServiceSelectionRes.Header
+        } else if doc.ScheduleExchangeReq_isUsed() == 1 {
+            &mut body.ScheduleExchangeReq.Header
+        } else if doc.ScheduleExchangeRes_isUsed() == 1 {
+            &mut body.ScheduleExchangeRes.Header
+        } else if doc.PowerDeliveryReq_isUsed() == 1 {
+            &mut body.PowerDeliveryReq.Header
+        } else if doc.PowerDeliveryRes_isUsed() == 1 {
+            &mut body.PowerDeliveryRes.Header
+        } else if doc.SessionStopReq_isUsed() == 1 {
+            &mut body.SessionStopReq.Header
+        } else if doc.SessionStopRes_isUsed() == 1 {
+            &mut body.SessionStopRes.Header
+        } else {
+            return afb_error!("iso20-exi-header", "unknown/unsupported message");
+        }
+    };
+    Ok(header)
+}
+
+pub struct ExiMessageDoc {
+    payload: cglue::iso20_exiDocument,
+}
+
+impl ExiMessageDoc {
+    pub fn new(header: &ExiMessageHeader, body: &Iso20BodyType) -> Result<Self, AfbError> {
+        let mut payload = *body;
+        *get_header_mut(&mut payload)? = header.encode();
+        Ok(Self { payload })
+    }
+
+    #[track_caller]
+    pub fn decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, AfbError> {
+        Ok(Self::try_decode_from_stream(locked)?)
+    }
+
+    /// same as decode_from_stream with typed error (exi code & stream position)
+    pub fn try_decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, Iso15118Error> {
+        let payload = unsafe {
+            let mut buffer = mem::MaybeUninit::<cglue::iso20_exiDocument>::uninit();
+            let status = cglue::decode_iso20_exiDocument(locked.as_mut_ptr(), buffer.as_mut_ptr());
+            if status < 0 {
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::Iso20,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
+            }
+            let exi_raw = buffer.assume_init();
+            locked.reset();
+            exi_raw
+        };
+        Ok(Self { payload })
+    }
+
+    #[track_caller]
+    pub fn encode_to_stream(&self, locked: &mut RawStream) -> Result<(), AfbError> {
+        Ok(self.try_encode_to_stream(locked)?)
+    }
+
+    /// same as encode_to_stream with typed error (exi code, stream position & message tag)
+    pub fn try_encode_to_stream(&self, locked: &mut RawStream) -> Result<(), Iso15118Error> {
+        locked.reset(); // cleanup stream before encoding
+
+        // reserve space for v2g header
+        locked.set_window(EXI_MAX_DOCUMENT_SIZE, SDP_V2G_HEADER_LEN as usize);
+
+        let status = unsafe {
+            cglue::encode_iso20_exiDocument(
+                locked.as_mut_ptr(),
+                &self.payload as *const _ as *mut cglue::iso20_exiDocument,
+            )
+        };
+        if status < 0 {
+            let tagid = self.get_body().ok().map(|body| body.get_tagid());
+            return Err(Iso15118Error::exi(
+                ExiProtocol::Iso20,
+                ExiOperation::Encode,
+                status,
+                locked,
+                tagid.as_ref().map(|tagid| tagid.to_label()),
+            ));
+        }
+
+        // retrieve document encoded size from stream and insert header
+        let index = locked.get_cursor() as u32;
+        v2gtp20_write_header(
+            locked.buffer.as_mut_ptr(),
+            index - SDP_V2G_HEADER_LEN as u32,
+            PayloadMsgId::MAIN as u16,
+        );
+
+        // force stream size for get_buffer function
+        locked.set_size(index);
+
+        Ok(())
+    }
+
+    pub fn get_header(&self) -> Result<ExiMessageHeader, AfbError> {
+        let mut payload = self.payload;
+        let header = get_header_mut(&mut payload)?;
+        Ok(ExiMessageHeader::decode(*header))
+    }
+
+    #[track_caller]
+    pub fn get_body(&self) -> Result<MessageBody, AfbError> {
+        MessageBody::decode(&self.payload)
+    }
+
+    pub fn get_payload(&self) -> cglue::iso20_exiDocument {
+        self.payload
+    }
+}
+
+impl ExiFields for ExiMessageHeader {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("SessionID", ExiHexa(self.get_session_id()))
+            .field("TimeStamp", self.get_timestamp());
+    }
+}
+
+impl ExiFields for ExiMessageDoc {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Header", self.get_header())
+            .result("Body", self.get_body());
+    }
+}
+crate::exi_format!(ExiMessageHeader, ExiMessageDoc);
+
+// iso20 messages embed their header, serde moves it next to the body as in DIN/ISO-2 V2G_Message
+// note: header Signature content is not modeled, only SessionID & TimeStamp are serialized
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct MessageHeaderXsd {
+        SessionID: ExiBytes,
+        TimeStamp: u64,
+    }
+
+    impl MessageHeaderXsd {
+        fn from_exi(exi: &ExiMessageHeader) -> Result<Self, AfbError> {
+            Ok(Self {
+                SessionID: ExiBytes::from(exi.get_session_id()),
+                TimeStamp: exi.get_timestamp(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageHeader, AfbError> {
+            let mut exi = ExiMessageHeader::new(self.SessionID.as_slice())?;
+            exi.set_timestamp(self.TimeStamp);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageHeader, MessageHeaderXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ExiDocumentXsd {
+        Header: ExiMessageHeader,
+        Body: MessageBody,
+    }
+
+    impl ExiDocumentXsd {
+        fn from_exi(exi: &ExiMessageDoc) -> Result<Self, AfbError> {
+            Ok(Self {
+                Header: exi.get_header()?,
+                Body: exi.get_body()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageDoc, AfbError> {
+            ExiMessageDoc::new(&self.Header, &self.Body.encode()?)
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageDoc, ExiDocumentXsd);
+}
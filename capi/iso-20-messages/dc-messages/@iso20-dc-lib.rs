@@ -0,0 +1,250 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   iso15118-20 DC messages (urn:iso:std:iso:15118:-20:DC)
+ */
+
+use super::cglue;
+use crate::prelude::*;
+use iso20_dc_exi::*;
+use std::mem;
+
+// -20 DC schema redefines common types, convert them from/to iso20_exi wrappers
+fn to_dc_rational(value: &RationalNumber) -> cglue::iso20_dc_RationalNumberType {
+    let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_RationalNumberType>() };
+    payload.Value = value.get_value();
+    payload.Exponent = value.get_exponent();
+    payload
+}
+
+fn from_dc_rational(payload: cglue::iso20_dc_RationalNumberType) -> RationalNumber {
+    RationalNumber::new(payload.Value, payload.Exponent)
+}
+
+fn to_dc_status(status: &EvseStatusType) -> cglue::iso20_dc_EVSEStatusType {
+    let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_EVSEStatusType>() };
+    payload.NotificationMaxDelay = status.get_delay();
+    payload.EVSENotification = status.encode().EVSENotification;
+    payload
+}
+
+// notification is checked by EvseStatusType::get_notification, not when decoding
+fn from_dc_status(payload: cglue::iso20_dc_EVSEStatusType) -> EvseStatusType {
+    let mut status = unsafe { mem::zeroed::<cglue::iso20_EVSEStatusType>() };
+    status.NotificationMaxDelay = payload.NotificationMaxDelay;
+    status.EVSENotification = payload.EVSENotification;
+    EvseStatusType::decode(status)
+}
+
+#[path = "status-enums.rs"]
+mod status_enums;
+
+#[path = "param-discovery.rs"]
+mod param_discovery;
+
+#[path = "cable-check.rs"]
+mod cable_check;
+
+#[path = "pre-charge.rs"]
+mod pre_charge;
+
+#[path = "charge-loop.rs"]
+mod charge_loop;
+
+#[path = "welding-detection.rs"]
+mod welding_detection;
+
+#[path = "body-encoder.rs"]
+mod body_encoder;
+
+pub mod iso20_dc_exi {
+    pub use super::body_encoder::*;
+    pub use super::cable_check::*;
+    pub use super::charge_loop::*;
+    pub use super::param_discovery::*;
+    pub use super::pre_charge::*;
+    pub use super::status_enums::*;
+    pub use super::welding_detection::*;
+    // types shared with -20 CommonMessages
+    pub use crate::prelude::iso20_exi::{
+        EvseNotification, EvseProcessing, EvseStatusType, ExiMessageHeader, RationalNumber,
+        ResponseCode,
+    };
+    use crate::afb::*;
+    use std::fmt;
+
+    // serde uses XSD message element names as variant tags
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MessageBody {
+        #[cfg_attr(feature = "serde", serde(rename = "DC_ChargeParameterDiscoveryReq"))]
+        DcChargeParamDiscoveryReq(DcChargeParamDiscoveryRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_ChargeParameterDiscoveryRes"))]
+        DcChargeParamDiscoveryRes(DcChargeParamDiscoveryResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_CableCheckReq"))]
+        DcCableCheckReq(DcCableCheckRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_CableCheckRes"))]
+        DcCableCheckRes(DcCableCheckResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_PreChargeReq"))]
+        DcPreChargeReq(DcPreChargeRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_PreChargeRes"))]
+        DcPreChargeRes(DcPreChargeResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_ChargeLoopReq"))]
+        DcChargeLoopReq(DcChargeLoopRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_ChargeLoopRes"))]
+        DcChargeLoopRes(DcChargeLoopResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_WeldingDetectionReq"))]
+        DcWeldingDetectionReq(DcWeldingDetectionRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "DC_WeldingDetectionRes"))]
+        DcWeldingDetectionRes(DcWeldingDetectionResponse),
+        #[cfg_attr(feature = "serde", serde(skip))]
+        Unsupported,
+    }
+
+    // one line session log, XSD message element name followed by its fields
+    impl fmt::Display for MessageBody {
+        fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MessageBody::DcChargeParamDiscoveryReq(body) => {
+                    write!(format, "DC_ChargeParameterDiscoveryReq {}", body)
+                }
+                MessageBody::DcChargeParamDiscoveryRes(body) => {
+                    write!(format, "DC_ChargeParameterDiscoveryRes {}", body)
+                }
+                MessageBody::DcCableCheckReq(body) => write!(format, "DC_CableCheckReq {}", body),
+                MessageBody::DcCableCheckRes(body) => write!(format, "DC_CableCheckRes {}", body),
+                MessageBody::DcPreChargeReq(body) => write!(format, "DC_PreChargeReq {}", body),
+                MessageBody::DcPreChargeRes(body) => write!(format, "DC_PreChargeRes {}", body),
+                MessageBody::DcChargeLoopReq(body) => write!(format, "DC_ChargeLoopReq {}", body),
+                MessageBody::DcChargeLoopRes(body) => write!(format, "DC_ChargeLoopRes {}", body),
+                MessageBody::DcWeldingDetectionReq(body) => {
+                    write!(format, "DC_WeldingDetectionReq {}", body)
+                }
+                MessageBody::DcWeldingDetectionRes(body) => {
+                    write!(format, "DC_WeldingDetectionRes {}", body)
+                }
+                MessageBody::Unsupported => format.write_str("Unsupported"),
+            }
+        }
+    }
+
+    impl MessageBody {
+        pub fn get_tagid(&self) -> MessageTagId {
+            match self {
+                MessageBody::DcChargeParamDiscoveryReq(_) => {
+                    MessageTagId::DcChargeParamDiscoveryReq
+                }
+                MessageBody::DcChargeParamDiscoveryRes(_) => {
+                    MessageTagId::DcChargeParamDiscoveryRes
+                }
+                MessageBody::DcCableCheckReq(_) => MessageTagId::DcCableCheckReq,
+                MessageBody::DcCableCheckRes(_) => MessageTagId::DcCableCheckRes,
+                MessageBody::DcPreChargeReq(_) => MessageTagId::DcPreChargeReq,
+                MessageBody::DcPreChargeRes(_) => MessageTagId::DcPreChargeRes,
+                MessageBody::DcChargeLoopReq(_) => MessageTagId::DcChargeLoopReq,
+                MessageBody::DcChargeLoopRes(_) => MessageTagId::DcChargeLoopRes,
+                MessageBody::DcWeldingDetectionReq(_) => MessageTagId::DcWeldingDetectionReq,
+                MessageBody::DcWeldingDetectionRes(_) => MessageTagId::DcWeldingDetectionRes,
+                MessageBody::Unsupported => MessageTagId::Unsupported,
+            }
+        }
+
+        pub fn encode(&self) -> Result<Iso20DcBodyType, AfbError> {
+            let body = match self {
+                MessageBody::DcChargeParamDiscoveryReq(body) => body.encode(),
+                MessageBody::DcChargeParamDiscoveryRes(body) => body.encode(),
+                MessageBody::DcCableCheckReq(body) => body.encode(),
+                MessageBody::DcCableCheckRes(body) => body.encode(),
+                MessageBody::DcPreChargeReq(body) => body.encode(),
+                MessageBody::DcPreChargeRes(body) => body.encode(),
+                MessageBody::DcChargeLoopReq(body) => body.encode(),
+                MessageBody::DcChargeLoopRes(body) => body.encode(),
+                MessageBody::DcWeldingDetectionReq(body) => body.encode(),
+                MessageBody::DcWeldingDetectionRes(body) => body.encode(),
+                MessageBody::Unsupported => {
+                    return afb_error!("iso20-dc-encode-exi", "unknown/unsupported message")
+                }
+            };
+            Ok(body)
+        }
+
+        pub fn decode(payload: &super::cglue::iso20_dc_exiDocument) -> Result<Self, AfbError> {
+            // ChargeParameterDiscovery
+            let body = if payload.DC_ChargeParameterDiscoveryReq_isUsed() == 1 {
+                let body = DcChargeParamDiscoveryRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_ChargeParameterDiscoveryReq
+                });
+                MessageBody::DcChargeParamDiscoveryReq(body)
+            } else if payload.DC_ChargeParameterDiscoveryRes_isUsed() == 1 {
+                let body = DcChargeParamDiscoveryResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_ChargeParameterDiscoveryRes
+                });
+                MessageBody::DcChargeParamDiscoveryRes(body)
+
+            // CableCheck
+            } else if payload.DC_CableCheckReq_isUsed() == 1 {
+                let body = DcCableCheckRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_CableCheckReq
+                });
+                MessageBody::DcCableCheckReq(body)
+            } else if payload.DC_CableCheckRes_isUsed() == 1 {
+                let body = DcCableCheckResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_CableCheckRes
+                });
+                MessageBody::DcCableCheckRes(body)
+
+            // PreCharge
+            } else if payload.DC_PreChargeReq_isUsed() == 1 {
+                let body =
+                    DcPreChargeRequest::decode(unsafe { payload.__bindgen_anon_1.DC_PreChargeReq });
+                MessageBody::DcPreChargeReq(body)
+            } else if payload.DC_PreChargeRes_isUsed() == 1 {
+                let body = DcPreChargeResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_PreChargeRes
+                });
+                MessageBody::DcPreChargeRes(body)
+
+            // ChargeLoop
+            } else if payload.DC_ChargeLoopReq_isUsed() == 1 {
+                let body = DcChargeLoopRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_ChargeLoopReq
+                });
+                MessageBody::DcChargeLoopReq(body)
+            } else if payload.DC_ChargeLoopRes_isUsed() == 1 {
+                let body = DcChargeLoopResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_ChargeLoopRes
+                });
+                MessageBody::DcChargeLoopRes(body)
+
+            // WeldingDetection
+            } else if payload.DC_WeldingDetectionReq_isUsed() == 1 {
+                let body = DcWeldingDetectionRequest::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_WeldingDetectionReq
+                });
+                MessageBody::DcWeldingDetectionReq(body)
+            } else if payload.DC_WeldingDetectionRes_isUsed() == 1 {
+                let body = DcWeldingDetectionResponse::decode(unsafe {
+                    payload.__bindgen_anon_1.DC_WeldingDetectionRes
+                });
+                MessageBody::DcWeldingDetectionRes(body)
+            } else {
+                return afb_error!("iso20-dc-decode-exi", "unknown/unsupported message");
+            };
+            Ok(body)
+        }
+    }
+}
@@ -0,0 +1,213 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use crate::prelude::v2g::*;
+use std::mem;
+
+pub type Iso20DcBodyType = cglue::iso20_dc_exiDocument;
+
+// -20 DC messages embed their own copy of CommonMessages header
+fn to_dc_header(header: &ExiMessageHeader) -> Result<cglue::iso20_dc_MessageHeaderType, AfbError> {
+    let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_MessageHeaderType>() };
+    payload.SessionID.bytesLen = bytes_to_array(
+        header.get_session_id(),
+        &mut payload.SessionID.bytes,
+        cglue::iso20_dc_sessionIDType_BYTES_SIZE,
+    )?;
+    payload.TimeStamp = header.get_timestamp();
+    Ok(payload)
+}
+
+fn from_dc_header(
+    payload: &cglue::iso20_dc_MessageHeaderType,
+) -> Result<ExiMessageHeader, AfbError> {
+    let mut header = ExiMessageHeader::new(array_to_bytes(
+        &payload.SessionID.bytes,
+        payload.SessionID.bytesLen,
+    ))?;
+    header.set_timestamp(payload.TimeStamp);
+    Ok(header)
+}
+
+// return the header of whatever message is active within iso20 dc exi document
+fn get_header_mut(
+    doc: &mut cglue::iso20_dc_exiDocument,
+) -> Result<&mut cglue::iso20_dc_MessageHeaderType, AfbError> {
+    let header = unsafe {
+        let body = &mut doc.__bindgen_anon_1;
+        if doc.DC_ChargeParameterDiscoveryReq_isUsed() == 1 {
+            &mut body.DC_ChargeParameterDiscoveryReq.Header
+        } else if doc.DC_ChargeParameterDiscoveryRes_isUsed() == 1 {
+            &mut body.DC_ChargeParameterDiscoveryRes.Header
+        } else if doc.DC_CableCheckReq_isUsed() == 1 {
+            &mut body.DC_CableCheckReq.Header
+        } else if doc.DC_CableCheckRes_isUsed() == 1 {
+            &mut body.DC_CableCheckRes.Header
+        } else if doc.DC_PreChargeReq_isUsed() == 1 {
+            &mut body.DC_PreChargeReq.Header
+        } else if doc.DC_PreChargeRes_isUsed() == 1 {
+            &mut body.DC_PreChargeRes.Header
+        } else if doc.DC_ChargeLoopReq_isUsed() == 1 {
+            &mut body.DC_ChargeLoopReq.Header
+        } else if doc.DC_ChargeLoopRes_isUsed() == 1 {
+            &mut body.DC_ChargeLoopRes.Header
+        } else if doc.DC_WeldingDetectionReq_isUsed() == 1 {
+            &mut body.DC_WeldingDetectionReq.Header
+        } else if doc.DC_WeldingDetectionRes_isUsed() == 1 {
+            &mut body.DC_WeldingDetectionRes.Header
+        } else {
+            return afb_error!("iso20-dc-exi-header", "unknown/unsupported message");
+        }
+    };
+    Ok(header)
+}
+
+pub struct ExiMessageDoc {
+    payload: cglue::iso20_dc_exiDocument,
+}
+
+impl ExiMessageDoc {
+    pub fn new(header: &ExiMessageHeader, body: &Iso20DcBodyType) -> Result<Self, AfbError> {
+        let mut payload = *body;
+        *get_header_mut(&mut payload)? = to_dc_header(header)?;
+        Ok(Self { payload })
+    }
+
+    #[track_caller]
+    pub fn decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, AfbError> {
+        Ok(Self::try_decode_from_stream(locked)?)
+    }
+
+    /// same as decode_from_stream with typed error (exi code & stream position)
+    pub fn try_decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, Iso15118Error> {
+        let payload = unsafe {
+            let mut buffer = mem::MaybeUninit::<cglue::iso20_dc_exiDocument>::uninit();
+            let status =
+                cglue::decode_iso20_dc_exiDocument(locked.as_mut_ptr(), buffer.as_mut_ptr());
+            if status < 0 {
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::Iso20Dc,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
+            }
+            let exi_raw = buffer.assume_init();
+            locked.reset();
+            exi_raw
+        };
+        Ok(Self { payload })
+    }
+
+    #[track_caller]
+    pub fn encode_to_stream(&self, locked: &mut RawStream) -> Result<(), AfbError> {
+        Ok(self.try_encode_to_stream(locked)?)
+    }
+
+    /// same as encode_to_stream with typed error (exi code, stream position & message tag)
+    pub fn try_encode_to_stream(&self, locked: &mut RawStream) -> Result<(), Iso15118Error> {
+        locked.reset(); // cleanup stream before encoding
+
+        // reserve space for v2g header
+        locked.set_window(EXI_MAX_DOCUMENT_SIZE, SDP_V2G_HEADER_LEN as usize);
+
+        let status = unsafe {
+            cglue::encode_iso20_dc_exiDocument(
+                locked.as_mut_ptr(),
+                &self.payload as *const _ as *mut cglue::iso20_dc_exiDocument,
+            )
+        };
+        if status < 0 {
+            let tagid = self.get_body().ok().map(|body| body.get_tagid());
+            return Err(Iso15118Error::exi(
+                ExiProtocol::Iso20Dc,
+                ExiOperation::Encode,
+                status,
+                locked,
+                tagid.as_ref().map(|tagid| tagid.to_label()),
+            ));
+        }
+
+        // retrieve document encoded size from stream and insert header
+        let index = locked.get_cursor() as u32;
+        v2gtp20_write_header(
+            locked.buffer.as_mut_ptr(),
+            index - SDP_V2G_HEADER_LEN as u32,
+            PayloadMsgId::DC_MAIN as u16,
+        );
+
+        // force stream size for get_buffer function
+        locked.set_size(index);
+
+        Ok(())
+    }
+
+    pub fn get_header(&self) -> Result<ExiMessageHeader, AfbError> {
+        let mut payload = self.payload;
+        let header = get_header_mut(&mut payload)?;
+        from_dc_header(header)
+    }
+
+    #[track_caller]
+    pub fn get_body(&self) -> Result<MessageBody, AfbError> {
+        MessageBody::decode(&self.payload)
+    }
+
+    pub fn get_payload(&self) -> cglue::iso20_dc_exiDocument {
+        self.payload
+    }
+}
+
+impl ExiFields for ExiMessageDoc {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Header", self.get_header())
+            .result("Body", self.get_body());
+    }
+}
+crate::exi_format!(ExiMessageDoc);
+
+// header is serialized next to the body, as for CommonMessages documents
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ExiDocumentXsd {
+        Header: ExiMessageHeader,
+        Body: MessageBody,
+    }
+
+    impl ExiDocumentXsd {
+        fn from_exi(exi: &ExiMessageDoc) -> Result<Self, AfbError> {
+            Ok(Self {
+                Header: exi.get_header()?,
+                Body: exi.get_body()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageDoc, AfbError> {
+            ExiMessageDoc::new(&self.Header, &self.Body.encode()?)
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageDoc, ExiDocumentXsd);
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct DcCableCheckRequest {
+    payload: cglue::iso20_dc_DC_CableCheckReqType,
+}
+
+impl DcCableCheckRequest {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_CableCheckReqType>() };
+        Self { payload }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_CableCheckReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_CableCheckReq = self.payload;
+            exi_body.set_DC_CableCheckReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct DcCableCheckResponse {
+    payload: cglue::iso20_dc_DC_CableCheckResType,
+}
+
+impl DcCableCheckResponse {
+    pub fn new(code: ResponseCode, processing: EvseProcessing) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_CableCheckResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEProcessing = processing as u32;
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVSEProcessing)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_CableCheckResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_CableCheckRes = self.payload;
+            exi_body.set_DC_CableCheckRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DcCableCheckRequest {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for DcCableCheckResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing());
+    }
+}
+crate::exi_format!(DcCableCheckRequest, DcCableCheckResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcCableCheckReqXsd {}
+
+    impl DcCableCheckReqXsd {
+        fn from_exi(_exi: &DcCableCheckRequest) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<DcCableCheckRequest, AfbError> {
+            Ok(DcCableCheckRequest::new())
+        }
+    }
+    crate::exi_serde_mirror!(DcCableCheckRequest, DcCableCheckReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcCableCheckResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+    }
+
+    impl DcCableCheckResXsd {
+        fn from_exi(exi: &DcCableCheckResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<DcCableCheckResponse, AfbError> {
+            Ok(DcCableCheckResponse::new(
+                self.ResponseCode,
+                self.EVSEProcessing,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(DcCableCheckResponse, DcCableCheckResXsd);
+}
@@ -0,0 +1,2145 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+// charging limits are mandatory in dynamic mode, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct DynamicDcClReqMode {
+    payload: cglue::iso20_dc_Dynamic_DC_CLReqControlModeType,
+}
+
+impl DynamicDcClReqMode {
+    pub fn new(
+        target_energy: &RationalNumber,
+        max_energy: &RationalNumber,
+        min_energy: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_Dynamic_DC_CLReqControlModeType>() };
+        payload.EVTargetEnergyRequest = to_dc_rational(target_energy);
+        payload.EVMaximumEnergyRequest = to_dc_rational(max_energy);
+        payload.EVMinimumEnergyRequest = to_dc_rational(min_energy);
+        Self { payload }
+    }
+
+    pub fn get_target_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetEnergyRequest)
+    }
+
+    pub fn get_max_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumEnergyRequest)
+    }
+
+    pub fn get_min_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumEnergyRequest)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargeCurrent)
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumVoltage = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumVoltage)
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumVoltage = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumVoltage)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_Dynamic_DC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_Dynamic_DC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledDcClReqMode {
+    payload: cglue::iso20_dc_Scheduled_DC_CLReqControlModeType,
+}
+
+impl ScheduledDcClReqMode {
+    pub fn new(target_current: &RationalNumber, target_voltage: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_Scheduled_DC_CLReqControlModeType>() };
+        payload.EVTargetCurrent = to_dc_rational(target_current);
+        payload.EVTargetVoltage = to_dc_rational(target_voltage);
+        Self { payload }
+    }
+
+    pub fn get_target_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetCurrent)
+    }
+
+    pub fn get_target_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetVoltage)
+    }
+
+    pub fn set_target_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVTargetEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVTargetEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_target_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVTargetEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVTargetEnergyRequest))
+        }
+    }
+
+    pub fn set_max_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMaximumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumEnergyRequest))
+        }
+    }
+
+    pub fn set_min_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMinimumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumEnergyRequest))
+        }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_dc_rational(value);
+        self.payload.set_EVMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_dc_rational(value);
+        self.payload.set_EVMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargeCurrent = to_dc_rational(value);
+        self.payload.set_EVMaximumChargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumChargeCurrent))
+        }
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumVoltage = to_dc_rational(value);
+        self.payload.set_EVMaximumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumVoltage))
+        }
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumVoltage = to_dc_rational(value);
+        self.payload.set_EVMinimumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumVoltage))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_Scheduled_DC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_Scheduled_DC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+// charge & discharge limits are mandatory in dynamic mode, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptDynamicDcClReqMode {
+    payload: cglue::iso20_dc_BPT_Dynamic_DC_CLReqControlModeType,
+}
+
+impl BptDynamicDcClReqMode {
+    pub fn new(
+        target_energy: &RationalNumber,
+        max_energy: &RationalNumber,
+        min_energy: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_Dynamic_DC_CLReqControlModeType>() };
+        payload.EVTargetEnergyRequest = to_dc_rational(target_energy);
+        payload.EVMaximumEnergyRequest = to_dc_rational(max_energy);
+        payload.EVMinimumEnergyRequest = to_dc_rational(min_energy);
+        Self { payload }
+    }
+
+    pub fn get_target_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetEnergyRequest)
+    }
+
+    pub fn get_max_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumEnergyRequest)
+    }
+
+    pub fn get_min_energy(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumEnergyRequest)
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargeCurrent)
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumVoltage = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumVoltage)
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumVoltage = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumVoltage)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumDischargePower)
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumDischargeCurrent)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_max_v2x_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumV2XEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMaximumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumV2XEnergyRequest))
+        }
+    }
+
+    pub fn set_min_v2x_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumV2XEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMinimumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumV2XEnergyRequest))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_Dynamic_DC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_Dynamic_DC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+// EVTargetCurrent is negative while discharging
+#[derive(Clone, Copy)]
+pub struct BptScheduledDcClReqMode {
+    payload: cglue::iso20_dc_BPT_Scheduled_DC_CLReqControlModeType,
+}
+
+impl BptScheduledDcClReqMode {
+    pub fn new(target_current: &RationalNumber, target_voltage: &RationalNumber) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_Scheduled_DC_CLReqControlModeType>() };
+        payload.EVTargetCurrent = to_dc_rational(target_current);
+        payload.EVTargetVoltage = to_dc_rational(target_voltage);
+        Self { payload }
+    }
+
+    pub fn get_target_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetCurrent)
+    }
+
+    pub fn get_target_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetVoltage)
+    }
+
+    pub fn set_target_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVTargetEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVTargetEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_target_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVTargetEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVTargetEnergyRequest))
+        }
+    }
+
+    pub fn set_max_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMaximumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumEnergyRequest))
+        }
+    }
+
+    pub fn set_min_energy(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumEnergyRequest = to_dc_rational(value);
+        self.payload.set_EVMinimumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumEnergyRequest))
+        }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargePower = to_dc_rational(value);
+        self.payload.set_EVMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumChargePower = to_dc_rational(value);
+        self.payload.set_EVMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumChargeCurrent = to_dc_rational(value);
+        self.payload.set_EVMaximumChargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumChargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumChargeCurrent))
+        }
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumVoltage = to_dc_rational(value);
+        self.payload.set_EVMaximumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumVoltage))
+        }
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumVoltage = to_dc_rational(value);
+        self.payload.set_EVMinimumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumVoltage))
+        }
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_dc_rational(value);
+        self.payload.set_EVMaximumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumDischargePower))
+        }
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_dc_rational(value);
+        self.payload.set_EVMinimumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMinimumDischargePower))
+        }
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargeCurrent = to_dc_rational(value);
+        self.payload.set_EVMaximumDischargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumDischargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVMaximumDischargeCurrent))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_Scheduled_DC_CLReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_Scheduled_DC_CLReqControlModeType {
+        self.payload
+    }
+}
+
+pub enum DcClReqControlMode {
+    Dynamic(DynamicDcClReqMode),
+    Scheduled(ScheduledDcClReqMode),
+    BptDynamic(BptDynamicDcClReqMode),
+    BptScheduled(BptScheduledDcClReqMode),
+}
+
+pub struct DcChargeLoopRequest {
+    payload: cglue::iso20_dc_DC_ChargeLoopReqType,
+}
+
+impl DcChargeLoopRequest {
+    pub fn new(present_voltage: &RationalNumber, mode: &DcClReqControlMode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeLoopReqType>() };
+        payload.EVPresentVoltage = to_dc_rational(present_voltage);
+        match mode {
+            DcClReqControlMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_DC_CLReqControlMode = value.encode();
+                payload.set_Dynamic_DC_CLReqControlMode_isUsed(1);
+            }
+            DcClReqControlMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_DC_CLReqControlMode = value.encode();
+                payload.set_Scheduled_DC_CLReqControlMode_isUsed(1);
+            }
+            DcClReqControlMode::BptDynamic(value) => {
+                payload.__bindgen_anon_1.BPT_Dynamic_DC_CLReqControlMode = value.encode();
+                payload.set_BPT_Dynamic_DC_CLReqControlMode_isUsed(1);
+            }
+            DcClReqControlMode::BptScheduled(value) => {
+                payload.__bindgen_anon_1.BPT_Scheduled_DC_CLReqControlMode = value.encode();
+                payload.set_BPT_Scheduled_DC_CLReqControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn get_present_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVPresentVoltage)
+    }
+
+    pub fn set_meter_info_requested(&mut self, requested: bool) -> &mut Self {
+        self.payload.MeterInfoRequested = if requested { 1 } else { 0 };
+        self
+    }
+
+    pub fn get_meter_info_requested(&self) -> bool {
+        self.payload.MeterInfoRequested != 0
+    }
+
+    pub fn get_control_mode(&self) -> Result<DcClReqControlMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_DC_CLReqControlMode_isUsed() != 0 {
+                DcClReqControlMode::Dynamic(DynamicDcClReqMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_DC_CLReqControlMode,
+                ))
+            } else if self.payload.Scheduled_DC_CLReqControlMode_isUsed() != 0 {
+                DcClReqControlMode::Scheduled(ScheduledDcClReqMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_DC_CLReqControlMode,
+                ))
+            } else if self.payload.BPT_Dynamic_DC_CLReqControlMode_isUsed() != 0 {
+                DcClReqControlMode::BptDynamic(BptDynamicDcClReqMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Dynamic_DC_CLReqControlMode,
+                ))
+            } else if self.payload.BPT_Scheduled_DC_CLReqControlMode_isUsed() != 0 {
+                DcClReqControlMode::BptScheduled(BptScheduledDcClReqMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Scheduled_DC_CLReqControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-dc-charge-loop-req", "unsupported control mode");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_ChargeLoopReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_ChargeLoopReq = self.payload;
+            exi_body.set_DC_ChargeLoopReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DynamicDcClResMode {
+    payload: cglue::iso20_dc_Dynamic_DC_CLResControlModeType,
+}
+
+impl DynamicDcClResMode {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_Dynamic_DC_CLResControlModeType>() };
+        payload.EVSEMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVSEMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVSEMaximumVoltage = to_dc_rational(max_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumVoltage)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_min_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.MinimumSOC = value;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn set_ack_max_delay(&mut self, value: u16) -> &mut Self {
+        self.payload.AckMaxDelay = value;
+        self.payload.set_AckMaxDelay_isUsed(1);
+        self
+    }
+
+    pub fn get_ack_max_delay(&self) -> Option<u16> {
+        if self.payload.AckMaxDelay_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.AckMaxDelay)
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_Dynamic_DC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_Dynamic_DC_CLResControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledDcClResMode {
+    payload: cglue::iso20_dc_Scheduled_DC_CLResControlModeType,
+}
+
+impl ScheduledDcClResMode {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_dc_Scheduled_DC_CLResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower = to_dc_rational(value);
+        self.payload.set_EVSEMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower = to_dc_rational(value);
+        self.payload.set_EVSEMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMinimumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargeCurrent = to_dc_rational(value);
+        self.payload.set_EVSEMaximumChargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumChargeCurrent))
+        }
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumVoltage = to_dc_rational(value);
+        self.payload.set_EVSEMaximumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumVoltage))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_Scheduled_DC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_Scheduled_DC_CLResControlModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory in dynamic mode, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptDynamicDcClResMode {
+    payload: cglue::iso20_dc_BPT_Dynamic_DC_CLResControlModeType,
+}
+
+impl BptDynamicDcClResMode {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_Dynamic_DC_CLResControlModeType>() };
+        payload.EVSEMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVSEMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVSEMaximumVoltage = to_dc_rational(max_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumVoltage)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumDischargePower)
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumDischargeCurrent)
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumVoltage = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumVoltage)
+    }
+
+    pub fn set_departure_time(&mut self, value: u32) -> &mut Self {
+        self.payload.DepartureTime = value;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_min_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.MinimumSOC = value;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn set_ack_max_delay(&mut self, value: u16) -> &mut Self {
+        self.payload.AckMaxDelay = value;
+        self.payload.set_AckMaxDelay_isUsed(1);
+        self
+    }
+
+    pub fn get_ack_max_delay(&self) -> Option<u16> {
+        if self.payload.AckMaxDelay_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.AckMaxDelay)
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_Dynamic_DC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_Dynamic_DC_CLResControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct BptScheduledDcClResMode {
+    payload: cglue::iso20_dc_BPT_Scheduled_DC_CLResControlModeType,
+}
+
+impl BptScheduledDcClResMode {
+    pub fn new() -> Self {
+        let payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_Scheduled_DC_CLResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_max_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargePower = to_dc_rational(value);
+        self.payload.set_EVSEMaximumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumChargePower))
+        }
+    }
+
+    pub fn set_min_charge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumChargePower = to_dc_rational(value);
+        self.payload.set_EVSEMinimumChargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_charge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumChargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMinimumChargePower))
+        }
+    }
+
+    pub fn set_max_charge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumChargeCurrent = to_dc_rational(value);
+        self.payload.set_EVSEMaximumChargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_charge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumChargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumChargeCurrent))
+        }
+    }
+
+    pub fn set_max_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumVoltage = to_dc_rational(value);
+        self.payload.set_EVSEMaximumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_max_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumVoltage))
+        }
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower = to_dc_rational(value);
+        self.payload.set_EVSEMaximumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumDischargePower))
+        }
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower = to_dc_rational(value);
+        self.payload.set_EVSEMinimumDischargePower_isUsed(1);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumDischargePower_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMinimumDischargePower))
+        }
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargeCurrent = to_dc_rational(value);
+        self.payload.set_EVSEMaximumDischargeCurrent_isUsed(1);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMaximumDischargeCurrent_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMaximumDischargeCurrent))
+        }
+    }
+
+    pub fn set_min_voltage(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumVoltage = to_dc_rational(value);
+        self.payload.set_EVSEMinimumVoltage_isUsed(1);
+        self
+    }
+
+    pub fn get_min_voltage(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEMinimumVoltage_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEMinimumVoltage))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_Scheduled_DC_CLResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_Scheduled_DC_CLResControlModeType {
+        self.payload
+    }
+}
+
+pub enum DcClResControlMode {
+    Dynamic(DynamicDcClResMode),
+    Scheduled(ScheduledDcClResMode),
+    BptDynamic(BptDynamicDcClResMode),
+    BptScheduled(BptScheduledDcClResMode),
+}
+
+pub struct DcChargeLoopResponse {
+    payload: cglue::iso20_dc_DC_ChargeLoopResType,
+}
+
+impl DcChargeLoopResponse {
+    pub fn new(
+        code: ResponseCode,
+        present_current: &RationalNumber,
+        present_voltage: &RationalNumber,
+        mode: &DcClResControlMode,
+    ) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeLoopResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEPresentCurrent = to_dc_rational(present_current);
+        payload.EVSEPresentVoltage = to_dc_rational(present_voltage);
+        match mode {
+            DcClResControlMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_DC_CLResControlMode = value.encode();
+                payload.set_Dynamic_DC_CLResControlMode_isUsed(1);
+            }
+            DcClResControlMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_DC_CLResControlMode = value.encode();
+                payload.set_Scheduled_DC_CLResControlMode_isUsed(1);
+            }
+            DcClResControlMode::BptDynamic(value) => {
+                payload.__bindgen_anon_1.BPT_Dynamic_DC_CLResControlMode = value.encode();
+                payload.set_BPT_Dynamic_DC_CLResControlMode_isUsed(1);
+            }
+            DcClResControlMode::BptScheduled(value) => {
+                payload.__bindgen_anon_1.BPT_Scheduled_DC_CLResControlMode = value.encode();
+                payload.set_BPT_Scheduled_DC_CLResControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    // negative while EVSE is discharging the EV (BPT)
+    pub fn get_present_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEPresentCurrent)
+    }
+
+    pub fn get_present_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEPresentVoltage)
+    }
+
+    pub fn set_limits_achieved(&mut self, power: bool, current: bool, voltage: bool) -> &mut Self {
+        self.payload.EVSEPowerLimitAchieved = if power { 1 } else { 0 };
+        self.payload.EVSECurrentLimitAchieved = if current { 1 } else { 0 };
+        self.payload.EVSEVoltageLimitAchieved = if voltage { 1 } else { 0 };
+        self
+    }
+
+    pub fn get_power_limit_achieved(&self) -> bool {
+        self.payload.EVSEPowerLimitAchieved != 0
+    }
+
+    pub fn get_current_limit_achieved(&self) -> bool {
+        self.payload.EVSECurrentLimitAchieved != 0
+    }
+
+    pub fn get_voltage_limit_achieved(&self) -> bool {
+        self.payload.EVSEVoltageLimitAchieved != 0
+    }
+
+    pub fn set_evse_status(&mut self, status: &EvseStatusType) -> &mut Self {
+        self.payload.EVSEStatus = to_dc_status(status);
+        self.payload.set_EVSEStatus_isUsed(1);
+        self
+    }
+
+    pub fn get_evse_status(&self) -> Option<EvseStatusType> {
+        if self.payload.EVSEStatus_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_status(self.payload.EVSEStatus))
+        }
+    }
+
+    pub fn get_control_mode(&self) -> Result<DcClResControlMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_DC_CLResControlMode_isUsed() != 0 {
+                DcClResControlMode::Dynamic(DynamicDcClResMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_DC_CLResControlMode,
+                ))
+            } else if self.payload.Scheduled_DC_CLResControlMode_isUsed() != 0 {
+                DcClResControlMode::Scheduled(ScheduledDcClResMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_DC_CLResControlMode,
+                ))
+            } else if self.payload.BPT_Dynamic_DC_CLResControlMode_isUsed() != 0 {
+                DcClResControlMode::BptDynamic(BptDynamicDcClResMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Dynamic_DC_CLResControlMode,
+                ))
+            } else if self.payload.BPT_Scheduled_DC_CLResControlMode_isUsed() != 0 {
+                DcClResControlMode::BptScheduled(BptScheduledDcClResMode::decode(
+                    self.payload
+                        .__bindgen_anon_1
+                        .BPT_Scheduled_DC_CLResControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-dc-charge-loop-res", "unsupported control mode");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_ChargeLoopResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_ChargeLoopRes = self.payload;
+            exi_body.set_DC_ChargeLoopRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DynamicDcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetEnergyRequest", self.get_target_energy())
+            .field("EVMaximumEnergyRequest", self.get_max_energy())
+            .field("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("DepartureTime", self.get_departure_time())
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVMaximumVoltage", self.get_max_voltage())
+            .field("EVMinimumVoltage", self.get_min_voltage());
+    }
+}
+
+impl ExiFields for ScheduledDcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetCurrent", self.get_target_current())
+            .field("EVTargetVoltage", self.get_target_voltage())
+            .option("EVTargetEnergyRequest", self.get_target_energy())
+            .option("EVMaximumEnergyRequest", self.get_max_energy())
+            .option("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("EVMaximumChargePower", self.get_max_charge_power())
+            .option("EVMinimumChargePower", self.get_min_charge_power())
+            .option("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .option("EVMaximumVoltage", self.get_max_voltage())
+            .option("EVMinimumVoltage", self.get_min_voltage());
+    }
+}
+
+impl ExiFields for BptDynamicDcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetEnergyRequest", self.get_target_energy())
+            .field("EVMaximumEnergyRequest", self.get_max_energy())
+            .field("EVMinimumEnergyRequest", self.get_min_energy())
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVMaximumVoltage", self.get_max_voltage())
+            .field("EVMinimumVoltage", self.get_min_voltage())
+            .field("EVMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVMinimumDischargePower", self.get_min_discharge_power())
+            .field(
+                "EVMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            )
+            .option("DepartureTime", self.get_departure_time())
+            .option("EVMaximumV2XEnergyRequest", self.get_max_v2x_energy())
+            .option("EVMinimumV2XEnergyRequest", self.get_min_v2x_energy());
+    }
+}
+
+impl ExiFields for BptScheduledDcClReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVTargetCurrent", self.get_target_current())
+            .field("EVTargetVoltage", self.get_target_voltage())
+            .option("EVTargetEnergyRequest", self.get_target_energy())
+            .option("EVMaximumEnergyRequest", self.get_max_energy())
+            .option("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("EVMaximumChargePower", self.get_max_charge_power())
+            .option("EVMinimumChargePower", self.get_min_charge_power())
+            .option("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .option("EVMaximumVoltage", self.get_max_voltage())
+            .option("EVMinimumVoltage", self.get_min_voltage())
+            .option("EVMaximumDischargePower", self.get_max_discharge_power())
+            .option("EVMinimumDischargePower", self.get_min_discharge_power())
+            .option(
+                "EVMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            );
+    }
+}
+
+impl ExiFields for DcChargeLoopRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVPresentVoltage", self.get_present_voltage())
+            .field("MeterInfoRequested", self.get_meter_info_requested());
+        match self.get_control_mode() {
+            Ok(DcClReqControlMode::Dynamic(mode)) => {
+                fields.field("Dynamic_DC_CLReqControlMode", mode)
+            }
+            Ok(DcClReqControlMode::Scheduled(mode)) => {
+                fields.field("Scheduled_DC_CLReqControlMode", mode)
+            }
+            Ok(DcClReqControlMode::BptDynamic(mode)) => {
+                fields.field("BPT_Dynamic_DC_CLReqControlMode", mode)
+            }
+            Ok(DcClReqControlMode::BptScheduled(mode)) => {
+                fields.field("BPT_Scheduled_DC_CLReqControlMode", mode)
+            }
+            Err(error) => fields.field("DC_CLReqControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+
+impl ExiFields for DynamicDcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVSEMaximumVoltage", self.get_max_voltage())
+            .option("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc())
+            .option("AckMaxDelay", self.get_ack_max_delay());
+    }
+}
+
+impl ExiFields for ScheduledDcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("EVSEMaximumChargePower", self.get_max_charge_power())
+            .option("EVSEMinimumChargePower", self.get_min_charge_power())
+            .option("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .option("EVSEMaximumVoltage", self.get_max_voltage());
+    }
+}
+
+impl ExiFields for BptDynamicDcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVSEMaximumVoltage", self.get_max_voltage())
+            .field("EVSEMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVSEMinimumDischargePower", self.get_min_discharge_power())
+            .field(
+                "EVSEMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            )
+            .field("EVSEMinimumVoltage", self.get_min_voltage())
+            .option("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc())
+            .option("AckMaxDelay", self.get_ack_max_delay());
+    }
+}
+
+impl ExiFields for BptScheduledDcClResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("EVSEMaximumChargePower", self.get_max_charge_power())
+            .option("EVSEMinimumChargePower", self.get_min_charge_power())
+            .option("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .option("EVSEMaximumVoltage", self.get_max_voltage())
+            .option("EVSEMaximumDischargePower", self.get_max_discharge_power())
+            .option("EVSEMinimumDischargePower", self.get_min_discharge_power())
+            .option(
+                "EVSEMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            )
+            .option("EVSEMinimumVoltage", self.get_min_voltage());
+    }
+}
+
+impl ExiFields for DcChargeLoopResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("EVSEPresentCurrent", self.get_present_current())
+            .field("EVSEPresentVoltage", self.get_present_voltage())
+            .field("EVSEPowerLimitAchieved", self.get_power_limit_achieved())
+            .field(
+                "EVSECurrentLimitAchieved",
+                self.get_current_limit_achieved(),
+            )
+            .field(
+                "EVSEVoltageLimitAchieved",
+                self.get_voltage_limit_achieved(),
+            )
+            .option("EVSEStatus", self.get_evse_status());
+        match self.get_control_mode() {
+            Ok(DcClResControlMode::Dynamic(mode)) => {
+                fields.field("Dynamic_DC_CLResControlMode", mode)
+            }
+            Ok(DcClResControlMode::Scheduled(mode)) => {
+                fields.field("Scheduled_DC_CLResControlMode", mode)
+            }
+            Ok(DcClResControlMode::BptDynamic(mode)) => {
+                fields.field("BPT_Dynamic_DC_CLResControlMode", mode)
+            }
+            Ok(DcClResControlMode::BptScheduled(mode)) => {
+                fields.field("BPT_Scheduled_DC_CLResControlMode", mode)
+            }
+            Err(error) => fields.field("DC_CLResControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+crate::exi_format!(
+    DynamicDcClReqMode,
+    ScheduledDcClReqMode,
+    BptDynamicDcClReqMode,
+    BptScheduledDcClReqMode,
+    DcChargeLoopRequest,
+    DynamicDcClResMode,
+    ScheduledDcClResMode,
+    BptDynamicDcClResMode,
+    BptScheduledDcClResMode,
+    DcChargeLoopResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicDcClReqModeXsd {
+        EVTargetEnergyRequest: RationalNumber,
+        EVMaximumEnergyRequest: RationalNumber,
+        EVMinimumEnergyRequest: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVMaximumChargeCurrent: RationalNumber,
+        EVMaximumVoltage: RationalNumber,
+        EVMinimumVoltage: RationalNumber,
+    }
+
+    impl DynamicDcClReqModeXsd {
+        fn from_exi(exi: &DynamicDcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                DepartureTime: exi.get_departure_time(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicDcClReqMode, AfbError> {
+            let mut exi = DynamicDcClReqMode::new(
+                &self.EVTargetEnergyRequest,
+                &self.EVMaximumEnergyRequest,
+                &self.EVMinimumEnergyRequest,
+            );
+            exi.set_max_charge_power(&self.EVMaximumChargePower)
+                .set_min_charge_power(&self.EVMinimumChargePower)
+                .set_max_charge_current(&self.EVMaximumChargeCurrent)
+                .set_max_voltage(&self.EVMaximumVoltage)
+                .set_min_voltage(&self.EVMinimumVoltage);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicDcClReqMode, DynamicDcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledDcClReqModeXsd {
+        EVTargetCurrent: RationalNumber,
+        EVTargetVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTargetEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargeCurrent: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumVoltage: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumVoltage: Option<RationalNumber>,
+    }
+
+    impl ScheduledDcClReqModeXsd {
+        fn from_exi(exi: &ScheduledDcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetCurrent: exi.get_target_current(),
+                EVTargetVoltage: exi.get_target_voltage(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledDcClReqMode, AfbError> {
+            let mut exi = ScheduledDcClReqMode::new(&self.EVTargetCurrent, &self.EVTargetVoltage);
+            if let Some(value) = &self.EVTargetEnergyRequest {
+                exi.set_target_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumEnergyRequest {
+                exi.set_max_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumEnergyRequest {
+                exi.set_min_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumChargeCurrent {
+                exi.set_max_charge_current(value);
+            }
+            if let Some(value) = &self.EVMaximumVoltage {
+                exi.set_max_voltage(value);
+            }
+            if let Some(value) = &self.EVMinimumVoltage {
+                exi.set_min_voltage(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledDcClReqMode, ScheduledDcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDynamicDcClReqModeXsd {
+        EVTargetEnergyRequest: RationalNumber,
+        EVMaximumEnergyRequest: RationalNumber,
+        EVMinimumEnergyRequest: RationalNumber,
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVMaximumChargeCurrent: RationalNumber,
+        EVMaximumVoltage: RationalNumber,
+        EVMinimumVoltage: RationalNumber,
+        EVMaximumDischargePower: RationalNumber,
+        EVMinimumDischargePower: RationalNumber,
+        EVMaximumDischargeCurrent: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumV2XEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumV2XEnergyRequest: Option<RationalNumber>,
+    }
+
+    impl BptDynamicDcClReqModeXsd {
+        fn from_exi(exi: &BptDynamicDcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                EVMaximumDischargeCurrent: exi.get_max_discharge_current(),
+                DepartureTime: exi.get_departure_time(),
+                EVMaximumV2XEnergyRequest: exi.get_max_v2x_energy(),
+                EVMinimumV2XEnergyRequest: exi.get_min_v2x_energy(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDynamicDcClReqMode, AfbError> {
+            let mut exi = BptDynamicDcClReqMode::new(
+                &self.EVTargetEnergyRequest,
+                &self.EVMaximumEnergyRequest,
+                &self.EVMinimumEnergyRequest,
+            );
+            exi.set_max_charge_power(&self.EVMaximumChargePower)
+                .set_min_charge_power(&self.EVMinimumChargePower)
+                .set_max_charge_current(&self.EVMaximumChargeCurrent)
+                .set_max_voltage(&self.EVMaximumVoltage)
+                .set_min_voltage(&self.EVMinimumVoltage)
+                .set_max_discharge_power(&self.EVMaximumDischargePower)
+                .set_min_discharge_power(&self.EVMinimumDischargePower)
+                .set_max_discharge_current(&self.EVMaximumDischargeCurrent);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = &self.EVMaximumV2XEnergyRequest {
+                exi.set_max_v2x_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumV2XEnergyRequest {
+                exi.set_min_v2x_energy(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDynamicDcClReqMode, BptDynamicDcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptScheduledDcClReqModeXsd {
+        EVTargetCurrent: RationalNumber,
+        EVTargetVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTargetEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumChargeCurrent: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumVoltage: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumVoltage: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumDischargeCurrent: Option<RationalNumber>,
+    }
+
+    impl BptScheduledDcClReqModeXsd {
+        fn from_exi(exi: &BptScheduledDcClReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVTargetCurrent: exi.get_target_current(),
+                EVTargetVoltage: exi.get_target_voltage(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                EVMaximumDischargeCurrent: exi.get_max_discharge_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptScheduledDcClReqMode, AfbError> {
+            let mut exi =
+                BptScheduledDcClReqMode::new(&self.EVTargetCurrent, &self.EVTargetVoltage);
+            if let Some(value) = &self.EVTargetEnergyRequest {
+                exi.set_target_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumEnergyRequest {
+                exi.set_max_energy(value);
+            }
+            if let Some(value) = &self.EVMinimumEnergyRequest {
+                exi.set_min_energy(value);
+            }
+            if let Some(value) = &self.EVMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumChargeCurrent {
+                exi.set_max_charge_current(value);
+            }
+            if let Some(value) = &self.EVMaximumVoltage {
+                exi.set_max_voltage(value);
+            }
+            if let Some(value) = &self.EVMinimumVoltage {
+                exi.set_min_voltage(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargePower {
+                exi.set_max_discharge_power(value);
+            }
+            if let Some(value) = &self.EVMinimumDischargePower {
+                exi.set_min_discharge_power(value);
+            }
+            if let Some(value) = &self.EVMaximumDischargeCurrent {
+                exi.set_max_discharge_current(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptScheduledDcClReqMode, BptScheduledDcClReqModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcChargeLoopReqXsd {
+        EVPresentVoltage: RationalNumber,
+        MeterInfoRequested: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_DC_CLReqControlMode: Option<DynamicDcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_DC_CLReqControlMode: Option<ScheduledDcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Dynamic_DC_CLReqControlMode: Option<BptDynamicDcClReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Scheduled_DC_CLReqControlMode: Option<BptScheduledDcClReqMode>,
+    }
+
+    impl DcChargeLoopReqXsd {
+        fn from_exi(exi: &DcChargeLoopRequest) -> Result<Self, AfbError> {
+            let mode = exi.get_control_mode()?;
+            Ok(Self {
+                EVPresentVoltage: exi.get_present_voltage(),
+                MeterInfoRequested: exi.get_meter_info_requested(),
+                Dynamic_DC_CLReqControlMode: match &mode {
+                    DcClReqControlMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_DC_CLReqControlMode: match &mode {
+                    DcClReqControlMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Dynamic_DC_CLReqControlMode: match &mode {
+                    DcClReqControlMode::BptDynamic(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Scheduled_DC_CLReqControlMode: match &mode {
+                    DcClReqControlMode::BptScheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<DcChargeLoopRequest, AfbError> {
+            let mode = match (
+                self.Dynamic_DC_CLReqControlMode,
+                self.Scheduled_DC_CLReqControlMode,
+                self.BPT_Dynamic_DC_CLReqControlMode,
+                self.BPT_Scheduled_DC_CLReqControlMode,
+            ) {
+                (Some(value), None, None, None) => DcClReqControlMode::Dynamic(value),
+                (None, Some(value), None, None) => DcClReqControlMode::Scheduled(value),
+                (None, None, Some(value), None) => DcClReqControlMode::BptDynamic(value),
+                (None, None, None, Some(value)) => DcClReqControlMode::BptScheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-dc-charge-loop-req",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            let mut exi = DcChargeLoopRequest::new(&self.EVPresentVoltage, &mode);
+            exi.set_meter_info_requested(self.MeterInfoRequested);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcChargeLoopRequest, DcChargeLoopReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicDcClResModeXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSEMaximumChargeCurrent: RationalNumber,
+        EVSEMaximumVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AckMaxDelay: Option<u16>,
+    }
+
+    impl DynamicDcClResModeXsd {
+        fn from_exi(exi: &DynamicDcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+                AckMaxDelay: exi.get_ack_max_delay(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicDcClResMode, AfbError> {
+            let mut exi = DynamicDcClResMode::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSEMaximumChargeCurrent,
+                &self.EVSEMaximumVoltage,
+            );
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = self.MinimumSOC {
+                exi.set_min_soc(value);
+            }
+            if let Some(value) = self.TargetSOC {
+                exi.set_target_soc(value);
+            }
+            if let Some(value) = self.AckMaxDelay {
+                exi.set_ack_max_delay(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicDcClResMode, DynamicDcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledDcClResModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargeCurrent: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumVoltage: Option<RationalNumber>,
+    }
+
+    impl ScheduledDcClResModeXsd {
+        fn from_exi(exi: &ScheduledDcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledDcClResMode, AfbError> {
+            let mut exi = ScheduledDcClResMode::new();
+            if let Some(value) = &self.EVSEMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVSEMaximumChargeCurrent {
+                exi.set_max_charge_current(value);
+            }
+            if let Some(value) = &self.EVSEMaximumVoltage {
+                exi.set_max_voltage(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledDcClResMode, ScheduledDcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDynamicDcClResModeXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSEMaximumChargeCurrent: RationalNumber,
+        EVSEMaximumVoltage: RationalNumber,
+        EVSEMaximumDischargePower: RationalNumber,
+        EVSEMinimumDischargePower: RationalNumber,
+        EVSEMaximumDischargeCurrent: RationalNumber,
+        EVSEMinimumVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AckMaxDelay: Option<u16>,
+    }
+
+    impl BptDynamicDcClResModeXsd {
+        fn from_exi(exi: &BptDynamicDcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+                EVSEMaximumDischargePower: exi.get_max_discharge_power(),
+                EVSEMinimumDischargePower: exi.get_min_discharge_power(),
+                EVSEMaximumDischargeCurrent: exi.get_max_discharge_current(),
+                EVSEMinimumVoltage: exi.get_min_voltage(),
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+                AckMaxDelay: exi.get_ack_max_delay(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDynamicDcClResMode, AfbError> {
+            let mut exi = BptDynamicDcClResMode::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSEMaximumChargeCurrent,
+                &self.EVSEMaximumVoltage,
+            );
+            exi.set_max_discharge_power(&self.EVSEMaximumDischargePower)
+                .set_min_discharge_power(&self.EVSEMinimumDischargePower)
+                .set_max_discharge_current(&self.EVSEMaximumDischargeCurrent)
+                .set_min_voltage(&self.EVSEMinimumVoltage);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = self.MinimumSOC {
+                exi.set_min_soc(value);
+            }
+            if let Some(value) = self.TargetSOC {
+                exi.set_target_soc(value);
+            }
+            if let Some(value) = self.AckMaxDelay {
+                exi.set_ack_max_delay(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDynamicDcClResMode, BptDynamicDcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptScheduledDcClResModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumChargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumChargeCurrent: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumVoltage: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumDischargePower: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumDischargeCurrent: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMinimumVoltage: Option<RationalNumber>,
+    }
+
+    impl BptScheduledDcClResModeXsd {
+        fn from_exi(exi: &BptScheduledDcClResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+                EVSEMaximumDischargePower: exi.get_max_discharge_power(),
+                EVSEMinimumDischargePower: exi.get_min_discharge_power(),
+                EVSEMaximumDischargeCurrent: exi.get_max_discharge_current(),
+                EVSEMinimumVoltage: exi.get_min_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptScheduledDcClResMode, AfbError> {
+            let mut exi = BptScheduledDcClResMode::new();
+            if let Some(value) = &self.EVSEMaximumChargePower {
+                exi.set_max_charge_power(value);
+            }
+            if let Some(value) = &self.EVSEMinimumChargePower {
+                exi.set_min_charge_power(value);
+            }
+            if let Some(value) = &self.EVSEMaximumChargeCurrent {
+                exi.set_max_charge_current(value);
+            }
+            if let Some(value) = &self.EVSEMaximumVoltage {
+                exi.set_max_voltage(value);
+            }
+            if let Some(value) = &self.EVSEMaximumDischargePower {
+                exi.set_max_discharge_power(value);
+            }
+            if let Some(value) = &self.EVSEMinimumDischargePower {
+                exi.set_min_discharge_power(value);
+            }
+            if let Some(value) = &self.EVSEMaximumDischargeCurrent {
+                exi.set_max_discharge_current(value);
+            }
+            if let Some(value) = &self.EVSEMinimumVoltage {
+                exi.set_min_voltage(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptScheduledDcClResMode, BptScheduledDcClResModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcChargeLoopResXsd {
+        ResponseCode: ResponseCode,
+        EVSEPresentCurrent: RationalNumber,
+        EVSEPresentVoltage: RationalNumber,
+        EVSEPowerLimitAchieved: bool,
+        EVSECurrentLimitAchieved: bool,
+        EVSEVoltageLimitAchieved: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEStatus: Option<EvseStatusType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_DC_CLResControlMode: Option<DynamicDcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_DC_CLResControlMode: Option<ScheduledDcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Dynamic_DC_CLResControlMode: Option<BptDynamicDcClResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_Scheduled_DC_CLResControlMode: Option<BptScheduledDcClResMode>,
+    }
+
+    impl DcChargeLoopResXsd {
+        fn from_exi(exi: &DcChargeLoopResponse) -> Result<Self, AfbError> {
+            let mode = exi.get_control_mode()?;
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEPresentCurrent: exi.get_present_current(),
+                EVSEPresentVoltage: exi.get_present_voltage(),
+                EVSEPowerLimitAchieved: exi.get_power_limit_achieved(),
+                EVSECurrentLimitAchieved: exi.get_current_limit_achieved(),
+                EVSEVoltageLimitAchieved: exi.get_voltage_limit_achieved(),
+                EVSEStatus: exi.get_evse_status(),
+                Dynamic_DC_CLResControlMode: match &mode {
+                    DcClResControlMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_DC_CLResControlMode: match &mode {
+                    DcClResControlMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Dynamic_DC_CLResControlMode: match &mode {
+                    DcClResControlMode::BptDynamic(value) => Some(*value),
+                    _ => None,
+                },
+                BPT_Scheduled_DC_CLResControlMode: match &mode {
+                    DcClResControlMode::BptScheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<DcChargeLoopResponse, AfbError> {
+            let mode = match (
+                self.Dynamic_DC_CLResControlMode,
+                self.Scheduled_DC_CLResControlMode,
+                self.BPT_Dynamic_DC_CLResControlMode,
+                self.BPT_Scheduled_DC_CLResControlMode,
+            ) {
+                (Some(value), None, None, None) => DcClResControlMode::Dynamic(value),
+                (None, Some(value), None, None) => DcClResControlMode::Scheduled(value),
+                (None, None, Some(value), None) => DcClResControlMode::BptDynamic(value),
+                (None, None, None, Some(value)) => DcClResControlMode::BptScheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-dc-charge-loop-res",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            let mut exi = DcChargeLoopResponse::new(
+                self.ResponseCode,
+                &self.EVSEPresentCurrent,
+                &self.EVSEPresentVoltage,
+                &mode,
+            );
+            exi.set_limits_achieved(
+                self.EVSEPowerLimitAchieved,
+                self.EVSECurrentLimitAchieved,
+                self.EVSEVoltageLimitAchieved,
+            );
+            if let Some(status) = &self.EVSEStatus {
+                exi.set_evse_status(status);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcChargeLoopResponse, DcChargeLoopResXsd);
+}
@@ -0,0 +1,884 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone, Copy)]
+pub struct DcCpdReqEnergyTransfer {
+    payload: cglue::iso20_dc_DC_CPDReqEnergyTransferModeType,
+}
+
+impl DcCpdReqEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        min_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+        min_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_CPDReqEnergyTransferModeType>() };
+        payload.EVMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVMinimumChargeCurrent = to_dc_rational(min_charge_current);
+        payload.EVMaximumVoltage = to_dc_rational(max_voltage);
+        payload.EVMinimumVoltage = to_dc_rational(min_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargeCurrent)
+    }
+
+    pub fn get_min_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumVoltage)
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumVoltage)
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_CPDReqEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_DC_CPDReqEnergyTransferModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DcCpdResEnergyTransfer {
+    payload: cglue::iso20_dc_DC_CPDResEnergyTransferModeType,
+}
+
+impl DcCpdResEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        min_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+        min_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_CPDResEnergyTransferModeType>() };
+        payload.EVSEMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVSEMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVSEMinimumChargeCurrent = to_dc_rational(min_charge_current);
+        payload.EVSEMaximumVoltage = to_dc_rational(max_voltage);
+        payload.EVSEMinimumVoltage = to_dc_rational(min_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargeCurrent)
+    }
+
+    pub fn get_min_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumVoltage)
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumVoltage)
+    }
+
+    pub fn set_power_ramp_limit(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPowerRampLimitation = to_dc_rational(value);
+        self.payload.set_EVSEPowerRampLimitation_isUsed(1);
+        self
+    }
+
+    pub fn get_power_ramp_limit(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPowerRampLimitation_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEPowerRampLimitation))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_CPDResEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_DC_CPDResEnergyTransferModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory for BPT, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptDcCpdReqEnergyTransfer {
+    payload: cglue::iso20_dc_BPT_DC_CPDReqEnergyTransferModeType,
+}
+
+impl BptDcCpdReqEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        min_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+        min_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_DC_CPDReqEnergyTransferModeType>() };
+        payload.EVMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVMinimumChargeCurrent = to_dc_rational(min_charge_current);
+        payload.EVMaximumVoltage = to_dc_rational(max_voltage);
+        payload.EVMinimumVoltage = to_dc_rational(min_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumChargeCurrent)
+    }
+
+    pub fn get_min_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumVoltage)
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumVoltage)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumDischargePower)
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMaximumDischargeCurrent)
+    }
+
+    pub fn set_min_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVMinimumDischargeCurrent)
+    }
+
+    pub fn set_target_soc(&mut self, value: i8) -> &mut Self {
+        self.payload.TargetSOC = value;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_DC_CPDReqEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_DC_CPDReqEnergyTransferModeType {
+        self.payload
+    }
+}
+
+// discharge limits are mandatory for BPT, they default to zero until set
+#[derive(Clone, Copy)]
+pub struct BptDcCpdResEnergyTransfer {
+    payload: cglue::iso20_dc_BPT_DC_CPDResEnergyTransferModeType,
+}
+
+impl BptDcCpdResEnergyTransfer {
+    pub fn new(
+        max_charge_power: &RationalNumber,
+        min_charge_power: &RationalNumber,
+        max_charge_current: &RationalNumber,
+        min_charge_current: &RationalNumber,
+        max_voltage: &RationalNumber,
+        min_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_BPT_DC_CPDResEnergyTransferModeType>() };
+        payload.EVSEMaximumChargePower = to_dc_rational(max_charge_power);
+        payload.EVSEMinimumChargePower = to_dc_rational(min_charge_power);
+        payload.EVSEMaximumChargeCurrent = to_dc_rational(max_charge_current);
+        payload.EVSEMinimumChargeCurrent = to_dc_rational(min_charge_current);
+        payload.EVSEMaximumVoltage = to_dc_rational(max_voltage);
+        payload.EVSEMinimumVoltage = to_dc_rational(min_voltage);
+        Self { payload }
+    }
+
+    pub fn get_max_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargePower)
+    }
+
+    pub fn get_min_charge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargePower)
+    }
+
+    pub fn get_max_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumChargeCurrent)
+    }
+
+    pub fn get_min_charge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumChargeCurrent)
+    }
+
+    pub fn get_max_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumVoltage)
+    }
+
+    pub fn get_min_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumVoltage)
+    }
+
+    pub fn set_max_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumDischargePower)
+    }
+
+    pub fn set_min_discharge_power(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargePower = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_power(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumDischargePower)
+    }
+
+    pub fn set_max_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMaximumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_max_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMaximumDischargeCurrent)
+    }
+
+    pub fn set_min_discharge_current(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEMinimumDischargeCurrent = to_dc_rational(value);
+        self
+    }
+
+    pub fn get_min_discharge_current(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEMinimumDischargeCurrent)
+    }
+
+    pub fn set_power_ramp_limit(&mut self, value: &RationalNumber) -> &mut Self {
+        self.payload.EVSEPowerRampLimitation = to_dc_rational(value);
+        self.payload.set_EVSEPowerRampLimitation_isUsed(1);
+        self
+    }
+
+    pub fn get_power_ramp_limit(&self) -> Option<RationalNumber> {
+        if self.payload.EVSEPowerRampLimitation_isUsed() == 0 {
+            None
+        } else {
+            Some(from_dc_rational(self.payload.EVSEPowerRampLimitation))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_BPT_DC_CPDResEnergyTransferModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_dc_BPT_DC_CPDResEnergyTransferModeType {
+        self.payload
+    }
+}
+
+pub struct DcChargeParamDiscoveryRequest {
+    payload: cglue::iso20_dc_DC_ChargeParameterDiscoveryReqType,
+}
+
+impl DcChargeParamDiscoveryRequest {
+    pub fn new(transfer: &DcCpdReqEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeParameterDiscoveryReqType>() };
+        payload.__bindgen_anon_1.DC_CPDReqEnergyTransferMode = transfer.encode();
+        payload.set_DC_CPDReqEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn new_bpt(transfer: &BptDcCpdReqEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeParameterDiscoveryReqType>() };
+        payload.__bindgen_anon_1.BPT_DC_CPDReqEnergyTransferMode = transfer.encode();
+        payload.set_BPT_DC_CPDReqEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn get_energy_transfer(&self) -> Option<DcCpdReqEnergyTransfer> {
+        if self.payload.DC_CPDReqEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(DcCpdReqEnergyTransfer::decode(unsafe {
+                self.payload.__bindgen_anon_1.DC_CPDReqEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn get_bpt_energy_transfer(&self) -> Option<BptDcCpdReqEnergyTransfer> {
+        if self.payload.BPT_DC_CPDReqEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(BptDcCpdReqEnergyTransfer::decode(unsafe {
+                self.payload
+                    .__bindgen_anon_1
+                    .BPT_DC_CPDReqEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_ChargeParameterDiscoveryReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_ChargeParameterDiscoveryReq = self.payload;
+            exi_body.set_DC_ChargeParameterDiscoveryReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct DcChargeParamDiscoveryResponse {
+    payload: cglue::iso20_dc_DC_ChargeParameterDiscoveryResType,
+}
+
+impl DcChargeParamDiscoveryResponse {
+    pub fn new(code: ResponseCode, transfer: &DcCpdResEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeParameterDiscoveryResType>() };
+        payload.ResponseCode = code as u32;
+        payload.__bindgen_anon_1.DC_CPDResEnergyTransferMode = transfer.encode();
+        payload.set_DC_CPDResEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn new_bpt(code: ResponseCode, transfer: &BptDcCpdResEnergyTransfer) -> Self {
+        let mut payload =
+            unsafe { mem::zeroed::<cglue::iso20_dc_DC_ChargeParameterDiscoveryResType>() };
+        payload.ResponseCode = code as u32;
+        payload.__bindgen_anon_1.BPT_DC_CPDResEnergyTransferMode = transfer.encode();
+        payload.set_BPT_DC_CPDResEnergyTransferMode_isUsed(1);
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_energy_transfer(&self) -> Option<DcCpdResEnergyTransfer> {
+        if self.payload.DC_CPDResEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(DcCpdResEnergyTransfer::decode(unsafe {
+                self.payload.__bindgen_anon_1.DC_CPDResEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn get_bpt_energy_transfer(&self) -> Option<BptDcCpdResEnergyTransfer> {
+        if self.payload.BPT_DC_CPDResEnergyTransferMode_isUsed() == 0 {
+            None
+        } else {
+            Some(BptDcCpdResEnergyTransfer::decode(unsafe {
+                self.payload
+                    .__bindgen_anon_1
+                    .BPT_DC_CPDResEnergyTransferMode
+            }))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_ChargeParameterDiscoveryResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_ChargeParameterDiscoveryRes = self.payload;
+            exi_body.set_DC_ChargeParameterDiscoveryRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DcCpdReqEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVMinimumChargeCurrent", self.get_min_charge_current())
+            .field("EVMaximumVoltage", self.get_max_voltage())
+            .field("EVMinimumVoltage", self.get_min_voltage())
+            .option("TargetSOC", self.get_target_soc());
+    }
+}
+
+impl ExiFields for DcCpdResEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVSEMinimumChargeCurrent", self.get_min_charge_current())
+            .field("EVSEMaximumVoltage", self.get_max_voltage())
+            .field("EVSEMinimumVoltage", self.get_min_voltage())
+            .option("EVSEPowerRampLimitation", self.get_power_ramp_limit());
+    }
+}
+
+impl ExiFields for BptDcCpdReqEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVMaximumChargePower", self.get_max_charge_power())
+            .field("EVMinimumChargePower", self.get_min_charge_power())
+            .field("EVMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVMinimumChargeCurrent", self.get_min_charge_current())
+            .field("EVMaximumVoltage", self.get_max_voltage())
+            .field("EVMinimumVoltage", self.get_min_voltage())
+            .field("EVMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVMinimumDischargePower", self.get_min_discharge_power())
+            .field(
+                "EVMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            )
+            .field(
+                "EVMinimumDischargeCurrent",
+                self.get_min_discharge_current(),
+            )
+            .option("TargetSOC", self.get_target_soc());
+    }
+}
+
+impl ExiFields for BptDcCpdResEnergyTransfer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVSEMaximumChargePower", self.get_max_charge_power())
+            .field("EVSEMinimumChargePower", self.get_min_charge_power())
+            .field("EVSEMaximumChargeCurrent", self.get_max_charge_current())
+            .field("EVSEMinimumChargeCurrent", self.get_min_charge_current())
+            .field("EVSEMaximumVoltage", self.get_max_voltage())
+            .field("EVSEMinimumVoltage", self.get_min_voltage())
+            .field("EVSEMaximumDischargePower", self.get_max_discharge_power())
+            .field("EVSEMinimumDischargePower", self.get_min_discharge_power())
+            .field(
+                "EVSEMaximumDischargeCurrent",
+                self.get_max_discharge_current(),
+            )
+            .field(
+                "EVSEMinimumDischargeCurrent",
+                self.get_min_discharge_current(),
+            )
+            .option("EVSEPowerRampLimitation", self.get_power_ramp_limit());
+    }
+}
+
+impl ExiFields for DcChargeParamDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DC_CPDReqEnergyTransferMode", self.get_energy_transfer())
+            .option(
+                "BPT_DC_CPDReqEnergyTransferMode",
+                self.get_bpt_energy_transfer(),
+            );
+    }
+}
+
+impl ExiFields for DcChargeParamDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("DC_CPDResEnergyTransferMode", self.get_energy_transfer())
+            .option(
+                "BPT_DC_CPDResEnergyTransferMode",
+                self.get_bpt_energy_transfer(),
+            );
+    }
+}
+crate::exi_format!(
+    DcCpdReqEnergyTransfer,
+    DcCpdResEnergyTransfer,
+    BptDcCpdReqEnergyTransfer,
+    BptDcCpdResEnergyTransfer,
+    DcChargeParamDiscoveryRequest,
+    DcChargeParamDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcCpdReqEnergyTransferXsd {
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVMaximumChargeCurrent: RationalNumber,
+        EVMinimumChargeCurrent: RationalNumber,
+        EVMaximumVoltage: RationalNumber,
+        EVMinimumVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+    }
+
+    impl DcCpdReqEnergyTransferXsd {
+        fn from_exi(exi: &DcCpdReqEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMinimumChargeCurrent: exi.get_min_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+                TargetSOC: exi.get_target_soc(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcCpdReqEnergyTransfer, AfbError> {
+            let mut exi = DcCpdReqEnergyTransfer::new(
+                &self.EVMaximumChargePower,
+                &self.EVMinimumChargePower,
+                &self.EVMaximumChargeCurrent,
+                &self.EVMinimumChargeCurrent,
+                &self.EVMaximumVoltage,
+                &self.EVMinimumVoltage,
+            );
+            if let Some(soc) = self.TargetSOC {
+                exi.set_target_soc(soc);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcCpdReqEnergyTransfer, DcCpdReqEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcCpdResEnergyTransferXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSEMaximumChargeCurrent: RationalNumber,
+        EVSEMinimumChargeCurrent: RationalNumber,
+        EVSEMaximumVoltage: RationalNumber,
+        EVSEMinimumVoltage: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPowerRampLimitation: Option<RationalNumber>,
+    }
+
+    impl DcCpdResEnergyTransferXsd {
+        fn from_exi(exi: &DcCpdResEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMinimumChargeCurrent: exi.get_min_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+                EVSEMinimumVoltage: exi.get_min_voltage(),
+                EVSEPowerRampLimitation: exi.get_power_ramp_limit(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcCpdResEnergyTransfer, AfbError> {
+            let mut exi = DcCpdResEnergyTransfer::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSEMaximumChargeCurrent,
+                &self.EVSEMinimumChargeCurrent,
+                &self.EVSEMaximumVoltage,
+                &self.EVSEMinimumVoltage,
+            );
+            if let Some(limit) = &self.EVSEPowerRampLimitation {
+                exi.set_power_ramp_limit(limit);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcCpdResEnergyTransfer, DcCpdResEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDcCpdReqEnergyTransferXsd {
+        EVMaximumChargePower: RationalNumber,
+        EVMinimumChargePower: RationalNumber,
+        EVMaximumChargeCurrent: RationalNumber,
+        EVMinimumChargeCurrent: RationalNumber,
+        EVMaximumVoltage: RationalNumber,
+        EVMinimumVoltage: RationalNumber,
+        EVMaximumDischargePower: RationalNumber,
+        EVMinimumDischargePower: RationalNumber,
+        EVMaximumDischargeCurrent: RationalNumber,
+        EVMinimumDischargeCurrent: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+    }
+
+    impl BptDcCpdReqEnergyTransferXsd {
+        fn from_exi(exi: &BptDcCpdReqEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVMaximumChargePower: exi.get_max_charge_power(),
+                EVMinimumChargePower: exi.get_min_charge_power(),
+                EVMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVMinimumChargeCurrent: exi.get_min_charge_current(),
+                EVMaximumVoltage: exi.get_max_voltage(),
+                EVMinimumVoltage: exi.get_min_voltage(),
+                EVMaximumDischargePower: exi.get_max_discharge_power(),
+                EVMinimumDischargePower: exi.get_min_discharge_power(),
+                EVMaximumDischargeCurrent: exi.get_max_discharge_current(),
+                EVMinimumDischargeCurrent: exi.get_min_discharge_current(),
+                TargetSOC: exi.get_target_soc(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDcCpdReqEnergyTransfer, AfbError> {
+            let mut exi = BptDcCpdReqEnergyTransfer::new(
+                &self.EVMaximumChargePower,
+                &self.EVMinimumChargePower,
+                &self.EVMaximumChargeCurrent,
+                &self.EVMinimumChargeCurrent,
+                &self.EVMaximumVoltage,
+                &self.EVMinimumVoltage,
+            );
+            exi.set_max_discharge_power(&self.EVMaximumDischargePower)
+                .set_min_discharge_power(&self.EVMinimumDischargePower)
+                .set_max_discharge_current(&self.EVMaximumDischargeCurrent)
+                .set_min_discharge_current(&self.EVMinimumDischargeCurrent);
+            if let Some(soc) = self.TargetSOC {
+                exi.set_target_soc(soc);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDcCpdReqEnergyTransfer, BptDcCpdReqEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct BptDcCpdResEnergyTransferXsd {
+        EVSEMaximumChargePower: RationalNumber,
+        EVSEMinimumChargePower: RationalNumber,
+        EVSEMaximumChargeCurrent: RationalNumber,
+        EVSEMinimumChargeCurrent: RationalNumber,
+        EVSEMaximumVoltage: RationalNumber,
+        EVSEMinimumVoltage: RationalNumber,
+        EVSEMaximumDischargePower: RationalNumber,
+        EVSEMinimumDischargePower: RationalNumber,
+        EVSEMaximumDischargeCurrent: RationalNumber,
+        EVSEMinimumDischargeCurrent: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEPowerRampLimitation: Option<RationalNumber>,
+    }
+
+    impl BptDcCpdResEnergyTransferXsd {
+        fn from_exi(exi: &BptDcCpdResEnergyTransfer) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVSEMaximumChargePower: exi.get_max_charge_power(),
+                EVSEMinimumChargePower: exi.get_min_charge_power(),
+                EVSEMaximumChargeCurrent: exi.get_max_charge_current(),
+                EVSEMinimumChargeCurrent: exi.get_min_charge_current(),
+                EVSEMaximumVoltage: exi.get_max_voltage(),
+                EVSEMinimumVoltage: exi.get_min_voltage(),
+                EVSEMaximumDischargePower: exi.get_max_discharge_power(),
+                EVSEMinimumDischargePower: exi.get_min_discharge_power(),
+                EVSEMaximumDischargeCurrent: exi.get_max_discharge_current(),
+                EVSEMinimumDischargeCurrent: exi.get_min_discharge_current(),
+                EVSEPowerRampLimitation: exi.get_power_ramp_limit(),
+            })
+        }
+
+        fn into_exi(self) -> Result<BptDcCpdResEnergyTransfer, AfbError> {
+            let mut exi = BptDcCpdResEnergyTransfer::new(
+                &self.EVSEMaximumChargePower,
+                &self.EVSEMinimumChargePower,
+                &self.EVSEMaximumChargeCurrent,
+                &self.EVSEMinimumChargeCurrent,
+                &self.EVSEMaximumVoltage,
+                &self.EVSEMinimumVoltage,
+            );
+            exi.set_max_discharge_power(&self.EVSEMaximumDischargePower)
+                .set_min_discharge_power(&self.EVSEMinimumDischargePower)
+                .set_max_discharge_current(&self.EVSEMaximumDischargeCurrent)
+                .set_min_discharge_current(&self.EVSEMinimumDischargeCurrent);
+            if let Some(limit) = &self.EVSEPowerRampLimitation {
+                exi.set_power_ramp_limit(limit);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(BptDcCpdResEnergyTransfer, BptDcCpdResEnergyTransferXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcChargeParameterDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_CPDReqEnergyTransferMode: Option<DcCpdReqEnergyTransfer>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_DC_CPDReqEnergyTransferMode: Option<BptDcCpdReqEnergyTransfer>,
+    }
+
+    impl DcChargeParameterDiscoveryReqXsd {
+        fn from_exi(exi: &DcChargeParamDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_CPDReqEnergyTransferMode: exi.get_energy_transfer(),
+                BPT_DC_CPDReqEnergyTransferMode: exi.get_bpt_energy_transfer(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcChargeParamDiscoveryRequest, AfbError> {
+            match (
+                &self.DC_CPDReqEnergyTransferMode,
+                &self.BPT_DC_CPDReqEnergyTransferMode,
+            ) {
+                (Some(transfer), None) => Ok(DcChargeParamDiscoveryRequest::new(transfer)),
+                (None, Some(transfer)) => Ok(DcChargeParamDiscoveryRequest::new_bpt(transfer)),
+                _ => afb_error!(
+                    "iso20-dc-param-discovery-req",
+                    "expect exactly one energy transfer mode"
+                ),
+            }
+        }
+    }
+    crate::exi_serde_mirror!(
+        DcChargeParamDiscoveryRequest,
+        DcChargeParameterDiscoveryReqXsd
+    );
+
+    #[derive(Serialize, Deserialize)]
+    struct DcChargeParameterDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_CPDResEnergyTransferMode: Option<DcCpdResEnergyTransfer>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_DC_CPDResEnergyTransferMode: Option<BptDcCpdResEnergyTransfer>,
+    }
+
+    impl DcChargeParameterDiscoveryResXsd {
+        fn from_exi(exi: &DcChargeParamDiscoveryResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_CPDResEnergyTransferMode: exi.get_energy_transfer(),
+                BPT_DC_CPDResEnergyTransferMode: exi.get_bpt_energy_transfer(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcChargeParamDiscoveryResponse, AfbError> {
+            match (
+                &self.DC_CPDResEnergyTransferMode,
+                &self.BPT_DC_CPDResEnergyTransferMode,
+            ) {
+                (Some(transfer), None) => Ok(DcChargeParamDiscoveryResponse::new(
+                    self.ResponseCode,
+                    transfer,
+                )),
+                (None, Some(transfer)) => Ok(DcChargeParamDiscoveryResponse::new_bpt(
+                    self.ResponseCode,
+                    transfer,
+                )),
+                _ => afb_error!(
+                    "iso20-dc-param-discovery-res",
+                    "expect exactly one energy transfer mode"
+                ),
+            }
+        }
+    }
+    crate::exi_serde_mirror!(
+        DcChargeParamDiscoveryResponse,
+        DcChargeParameterDiscoveryResXsd
+    );
+}
@@ -0,0 +1,173 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct DcPreChargeRequest {
+    payload: cglue::iso20_dc_DC_PreChargeReqType,
+}
+
+impl DcPreChargeRequest {
+    pub fn new(
+        processing: EvseProcessing,
+        present_voltage: &RationalNumber,
+        target_voltage: &RationalNumber,
+    ) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_PreChargeReqType>() };
+        payload.EVProcessing = processing as u32;
+        payload.EVPresentVoltage = to_dc_rational(present_voltage);
+        payload.EVTargetVoltage = to_dc_rational(target_voltage);
+        Self { payload }
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVProcessing)
+    }
+
+    pub fn get_present_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVPresentVoltage)
+    }
+
+    pub fn get_target_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVTargetVoltage)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_PreChargeReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_PreChargeReq = self.payload;
+            exi_body.set_DC_PreChargeReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct DcPreChargeResponse {
+    payload: cglue::iso20_dc_DC_PreChargeResType,
+}
+
+impl DcPreChargeResponse {
+    pub fn new(code: ResponseCode, present_voltage: &RationalNumber) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_PreChargeResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEPresentVoltage = to_dc_rational(present_voltage);
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_present_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEPresentVoltage)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_PreChargeResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_PreChargeRes = self.payload;
+            exi_body.set_DC_PreChargeRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DcPreChargeRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("EVProcessing", self.get_processing())
+            .field("EVPresentVoltage", self.get_present_voltage())
+            .field("EVTargetVoltage", self.get_target_voltage());
+    }
+}
+
+impl ExiFields for DcPreChargeResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("EVSEPresentVoltage", self.get_present_voltage());
+    }
+}
+crate::exi_format!(DcPreChargeRequest, DcPreChargeResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcPreChargeReqXsd {
+        EVProcessing: EvseProcessing,
+        EVPresentVoltage: RationalNumber,
+        EVTargetVoltage: RationalNumber,
+    }
+
+    impl DcPreChargeReqXsd {
+        fn from_exi(exi: &DcPreChargeRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVProcessing: exi.get_processing()?,
+                EVPresentVoltage: exi.get_present_voltage(),
+                EVTargetVoltage: exi.get_target_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcPreChargeRequest, AfbError> {
+            Ok(DcPreChargeRequest::new(
+                self.EVProcessing,
+                &self.EVPresentVoltage,
+                &self.EVTargetVoltage,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(DcPreChargeRequest, DcPreChargeReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcPreChargeResXsd {
+        ResponseCode: ResponseCode,
+        EVSEPresentVoltage: RationalNumber,
+    }
+
+    impl DcPreChargeResXsd {
+        fn from_exi(exi: &DcPreChargeResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEPresentVoltage: exi.get_present_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcPreChargeResponse, AfbError> {
+            Ok(DcPreChargeResponse::new(
+                self.ResponseCode,
+                &self.EVSEPresentVoltage,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(DcPreChargeResponse, DcPreChargeResXsd);
+}
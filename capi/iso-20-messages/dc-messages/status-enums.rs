@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::convert::AsRef;
+use std::str::FromStr;
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum MessageTagId {
+    DcChargeParamDiscoveryReq,
+    DcChargeParamDiscoveryRes,
+    DcCableCheckReq,
+    DcCableCheckRes,
+    DcPreChargeReq,
+    DcPreChargeRes,
+    DcChargeLoopReq,
+    DcChargeLoopRes,
+    DcWeldingDetectionReq,
+    DcWeldingDetectionRes,
+    Unsupported,
+}
+crate::exi_enum_try_from!(MessageTagId, u32);
+
+impl MessageTagId {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!(
+                    "message_tagid_from_label",
+                    "deserialize({}):{}",
+                    json,
+                    error
+                )
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+
+    pub fn match_resid(&self) -> Self {
+        let response = match self {
+            MessageTagId::DcChargeParamDiscoveryReq => MessageTagId::DcChargeParamDiscoveryRes,
+            MessageTagId::DcCableCheckReq => MessageTagId::DcCableCheckRes,
+            MessageTagId::DcPreChargeReq => MessageTagId::DcPreChargeRes,
+            MessageTagId::DcChargeLoopReq => MessageTagId::DcChargeLoopRes,
+            MessageTagId::DcWeldingDetectionReq => MessageTagId::DcWeldingDetectionRes,
+            _ => MessageTagId::Unsupported,
+        };
+        response
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct DcWeldingDetectionRequest {
+    payload: cglue::iso20_dc_DC_WeldingDetectionReqType,
+}
+
+impl DcWeldingDetectionRequest {
+    pub fn new(processing: EvseProcessing) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_WeldingDetectionReqType>() };
+        payload.EVProcessing = processing as u32;
+        Self { payload }
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVProcessing)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_WeldingDetectionReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_WeldingDetectionReq = self.payload;
+            exi_body.set_DC_WeldingDetectionReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct DcWeldingDetectionResponse {
+    payload: cglue::iso20_dc_DC_WeldingDetectionResType,
+}
+
+impl DcWeldingDetectionResponse {
+    pub fn new(code: ResponseCode, present_voltage: &RationalNumber) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_dc_DC_WeldingDetectionResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEPresentVoltage = to_dc_rational(present_voltage);
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_present_voltage(&self) -> RationalNumber {
+        from_dc_rational(self.payload.EVSEPresentVoltage)
+    }
+
+    pub fn decode(payload: cglue::iso20_dc_DC_WeldingDetectionResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20DcBodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20DcBodyType>();
+            exi_body.__bindgen_anon_1.DC_WeldingDetectionRes = self.payload;
+            exi_body.set_DC_WeldingDetectionRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DcWeldingDetectionRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("EVProcessing", self.get_processing());
+    }
+}
+
+impl ExiFields for DcWeldingDetectionResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("EVSEPresentVoltage", self.get_present_voltage());
+    }
+}
+crate::exi_format!(DcWeldingDetectionRequest, DcWeldingDetectionResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcWeldingDetectionReqXsd {
+        EVProcessing: EvseProcessing,
+    }
+
+    impl DcWeldingDetectionReqXsd {
+        fn from_exi(exi: &DcWeldingDetectionRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<DcWeldingDetectionRequest, AfbError> {
+            Ok(DcWeldingDetectionRequest::new(self.EVProcessing))
+        }
+    }
+    crate::exi_serde_mirror!(DcWeldingDetectionRequest, DcWeldingDetectionReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcWeldingDetectionResXsd {
+        ResponseCode: ResponseCode,
+        EVSEPresentVoltage: RationalNumber,
+    }
+
+    impl DcWeldingDetectionResXsd {
+        fn from_exi(exi: &DcWeldingDetectionResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEPresentVoltage: exi.get_present_voltage(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcWeldingDetectionResponse, AfbError> {
+            Ok(DcWeldingDetectionResponse::new(
+                self.ResponseCode,
+                &self.EVSEPresentVoltage,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(DcWeldingDetectionResponse, DcWeldingDetectionResXsd);
+}
@@ -17,6 +17,13 @@
  * Generate Rust structures for Din/ISO15118-2/20 messages.
  *
  */
+#include <iso15118/iso20_CommonMessages_Datatypes.h>
 #include <iso15118/iso20_CommonMessages_Decoder.h>
 #include <iso15118/iso20_CommonMessages_Encoder.h>
+#include <iso15118/iso20_DC_Datatypes.h>
+#include <iso15118/iso20_DC_Decoder.h>
+#include <iso15118/iso20_DC_Encoder.h>
+#include <iso15118/iso20_AC_Datatypes.h>
+#include <iso15118/iso20_AC_Decoder.h>
+#include <iso15118/iso20_AC_Encoder.h>
 
@@ -0,0 +1,353 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use super::*;
+use std::fmt;
+use std::mem;
+
+// iso20 replace iso2 PhysicalValue with a unit-less value*10^exponent
+#[derive(Clone, Copy)]
+pub struct RationalNumber {
+    payload: cglue::iso20_RationalNumberType,
+}
+impl fmt::Debug for RationalNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(value:{}, exponent:{})",
+            self.get_value(),
+            self.get_exponent()
+        )
+    }
+}
+
+// unit-less scaled value, the element defines the unit, ex: (value:4005, exponent:-1) => 400.5
+impl fmt::Display for RationalNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&scaled_to_string(self.get_value(), self.get_exponent()))
+    }
+}
+
+impl RationalNumber {
+    pub fn new(value: i16, exponent: i8) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_RationalNumberType>() };
+        payload.Value = value;
+        payload.Exponent = exponent;
+        Self { payload }
+    }
+
+    pub fn get_value(&self) -> i16 {
+        self.payload.Value
+    }
+
+    pub fn get_exponent(&self) -> i8 {
+        self.payload.Exponent
+    }
+
+    pub fn decode(payload: cglue::iso20_RationalNumberType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_RationalNumberType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ParamValue {
+    Bool(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Text(String),
+    Rational(RationalNumber),
+}
+
+pub struct ParamTuple {
+    payload: cglue::iso20_ParameterType,
+}
+
+impl ParamTuple {
+    pub fn new(prm_name: &str, prm_value: &ParamValue) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ParameterType>() };
+
+        payload.Name.charactersLen = str_to_array(
+            prm_name,
+            &mut payload.Name.characters,
+            cglue::iso20_Name_CHARACTER_SIZE,
+        )?;
+        match prm_value {
+            ParamValue::Bool(data) => {
+                payload.__bindgen_anon_1.boolValue = if *data { 1 } else { 0 };
+                payload.set_boolValue_isUsed(1);
+            }
+            ParamValue::Int8(data) => {
+                payload.__bindgen_anon_1.byteValue = *data;
+                payload.set_byteValue_isUsed(1);
+            }
+            ParamValue::Int16(data) => {
+                payload.__bindgen_anon_1.shortValue = *data;
+                payload.set_shortValue_isUsed(1);
+            }
+            ParamValue::Int32(data) => {
+                payload.__bindgen_anon_1.intValue = *data;
+                payload.set_intValue_isUsed(1);
+            }
+            ParamValue::Text(data) => {
+                let len = str_to_array(
+                    data.as_str(),
+                    unsafe { &mut payload.__bindgen_anon_1.finiteString.characters },
+                    cglue::iso20_finiteString_CHARACTER_SIZE,
+                )?;
+                unsafe { payload.__bindgen_anon_1.finiteString.charactersLen = len };
+                payload.set_finiteString_isUsed(1);
+            }
+            ParamValue::Rational(data) => {
+                payload.__bindgen_anon_1.rationalNumber = data.encode();
+                payload.set_rationalNumber_isUsed(1);
+            }
+        };
+        Ok(Self { payload })
+    }
+
+    pub fn get_name(&self) -> Result<&str, AfbError> {
+        array_to_str(
+            &self.payload.Name.characters,
+            self.payload.Name.charactersLen,
+        )
+    }
+
+    pub fn get_value(&self) -> Result<ParamValue, AfbError> {
+        let value = unsafe {
+            if self.payload.boolValue_isUsed() != 0 {
+                ParamValue::Bool(self.payload.__bindgen_anon_1.boolValue != 0)
+            } else if self.payload.byteValue_isUsed() != 0 {
+                ParamValue::Int8(self.payload.__bindgen_anon_1.byteValue)
+            } else if self.payload.shortValue_isUsed() != 0 {
+                ParamValue::Int16(self.payload.__bindgen_anon_1.shortValue)
+            } else if self.payload.intValue_isUsed() != 0 {
+                ParamValue::Int32(self.payload.__bindgen_anon_1.intValue)
+            } else if self.payload.finiteString_isUsed() != 0 {
+                ParamValue::Text(
+                    array_to_str(
+                        &self.payload.__bindgen_anon_1.finiteString.characters,
+                        self.payload.__bindgen_anon_1.finiteString.charactersLen,
+                    )?
+                    .to_string(),
+                )
+            } else if self.payload.rationalNumber_isUsed() != 0 {
+                ParamValue::Rational(RationalNumber::decode(
+                    self.payload.__bindgen_anon_1.rationalNumber,
+                ))
+            } else {
+                return afb_error!("iso20-param-value", "invalid param type");
+            }
+        };
+        Ok(value)
+    }
+
+    pub fn decode(payload: cglue::iso20_ParameterType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ParameterType {
+        self.payload
+    }
+}
+
+#[derive(Clone)]
+pub struct ParamSet {
+    payload: cglue::iso20_ParameterSetType,
+}
+
+impl ParamSet {
+    pub fn new(param_id: u16) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ParameterSetType>() };
+        payload.ParameterSetID = param_id;
+        Self { payload }
+    }
+
+    pub fn get_id(&self) -> u16 {
+        self.payload.ParameterSetID
+    }
+
+    pub fn add_param(&mut self, param: &ParamTuple) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.Parameter.arrayLen;
+        if idx >= cglue::iso20_ParameterType_32_ARRAY_SIZE as u16 {
+            return afb_error!(
+                "iso20-param-set",
+                "fail to add param (too many params max:{})",
+                cglue::iso20_ParameterType_32_ARRAY_SIZE
+            );
+        }
+        self.payload.Parameter.array[idx as usize] = param.encode();
+        self.payload.Parameter.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_params(&self) -> Vec<ParamTuple> {
+        let mut params = Vec::new();
+        for idx in 0..self.payload.Parameter.arrayLen {
+            params.push(ParamTuple::decode(self.payload.Parameter.array[idx as usize]));
+        }
+        params
+    }
+
+    pub fn decode(payload: cglue::iso20_ParameterSetType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ParameterSetType {
+        self.payload
+    }
+}
+
+impl ExiFields for ParamTuple {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("Name", self.get_name());
+        match self.get_value() {
+            Ok(ParamValue::Bool(value)) => fields.field("boolValue", value),
+            Ok(ParamValue::Int8(value)) => fields.field("byteValue", value),
+            Ok(ParamValue::Int16(value)) => fields.field("shortValue", value),
+            Ok(ParamValue::Int32(value)) => fields.field("intValue", value),
+            Ok(ParamValue::Text(value)) => fields.field("finiteString", value),
+            Ok(ParamValue::Rational(value)) => fields.field("rationalNumber", value),
+            Err(error) => fields.field("Value", format!("invalid({})", error)),
+        };
+    }
+}
+
+impl ExiFields for ParamSet {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ParameterSetID", self.get_id())
+            .field("Parameter", ExiList(self.get_params()));
+    }
+}
+crate::exi_format!(ParamTuple, ParamSet);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct RationalNumberXsd {
+        Exponent: i8,
+        Value: i16,
+    }
+
+    impl RationalNumberXsd {
+        fn from_exi(exi: &RationalNumber) -> Result<Self, AfbError> {
+            Ok(Self {
+                Exponent: exi.get_exponent(),
+                Value: exi.get_value(),
+            })
+        }
+
+        fn into_exi(self) -> Result<RationalNumber, AfbError> {
+            Ok(RationalNumber::new(self.Value, self.Exponent))
+        }
+    }
+    crate::exi_serde_mirror!(RationalNumber, RationalNumberXsd);
+
+    // ParameterType value is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct ParameterXsd {
+        Name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        boolValue: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        byteValue: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        shortValue: Option<i16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        intValue: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rationalNumber: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        finiteString: Option<String>,
+    }
+
+    impl ParameterXsd {
+        fn from_exi(exi: &ParamTuple) -> Result<Self, AfbError> {
+            let mut mirror = Self {
+                Name: exi.get_name()?.to_string(),
+                boolValue: None,
+                byteValue: None,
+                shortValue: None,
+                intValue: None,
+                rationalNumber: None,
+                finiteString: None,
+            };
+            match exi.get_value()? {
+                ParamValue::Bool(value) => mirror.boolValue = Some(value),
+                ParamValue::Int8(value) => mirror.byteValue = Some(value),
+                ParamValue::Int16(value) => mirror.shortValue = Some(value),
+                ParamValue::Int32(value) => mirror.intValue = Some(value),
+                ParamValue::Text(value) => mirror.finiteString = Some(value),
+                ParamValue::Rational(value) => mirror.rationalNumber = Some(value),
+            }
+            Ok(mirror)
+        }
+
+        fn into_exi(self) -> Result<ParamTuple, AfbError> {
+            let value = if let Some(value) = self.boolValue {
+                ParamValue::Bool(value)
+            } else if let Some(value) = self.byteValue {
+                ParamValue::Int8(value)
+            } else if let Some(value) = self.shortValue {
+                ParamValue::Int16(value)
+            } else if let Some(value) = self.intValue {
+                ParamValue::Int32(value)
+            } else if let Some(value) = self.rationalNumber {
+                ParamValue::Rational(value)
+            } else if let Some(value) = self.finiteString {
+                ParamValue::Text(value)
+            } else {
+                return afb_error!("iso20-param-value", "parameter:{} has no value", self.Name);
+            };
+            ParamTuple::new(&self.Name, &value)
+        }
+    }
+    crate::exi_serde_mirror!(ParamTuple, ParameterXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ParameterSetXsd {
+        ParameterSetID: u16,
+        Parameter: Vec<ParamTuple>,
+    }
+
+    impl ParameterSetXsd {
+        fn from_exi(exi: &ParamSet) -> Result<Self, AfbError> {
+            Ok(Self {
+                ParameterSetID: exi.get_id(),
+                Parameter: exi.get_params(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamSet, AfbError> {
+            let mut exi = ParamSet::new(self.ParameterSetID);
+            for param in &self.Parameter {
+                exi.add_param(param)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ParamSet, ParameterSetXsd);
+}
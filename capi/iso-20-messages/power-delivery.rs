@@ -0,0 +1,193 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct PowerDeliveryRequest {
+    payload: cglue::iso20_PowerDeliveryReqType,
+}
+
+impl PowerDeliveryRequest {
+    pub fn new(processing: EvseProcessing, progress: ChargeProgress) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_PowerDeliveryReqType>() };
+        payload.EVProcessing = processing as u32;
+        payload.ChargeProgress = progress as u32;
+        Self { payload }
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVProcessing)
+    }
+
+    pub fn get_progress(&self) -> Result<ChargeProgress, AfbError> {
+        ChargeProgress::from_u32(self.payload.ChargeProgress)
+    }
+
+    pub fn set_channel_selection(&mut self, channel: ChannelSelection) -> &mut Self {
+        self.payload.BPT_ChannelSelection = channel as u32;
+        self.payload.set_BPT_ChannelSelection_isUsed(1);
+        self
+    }
+
+    pub fn get_channel_selection(&self) -> Result<Option<ChannelSelection>, AfbError> {
+        let channel = if self.payload.BPT_ChannelSelection_isUsed() == 0 {
+            None
+        } else {
+            Some(ChannelSelection::from_u32(
+                self.payload.BPT_ChannelSelection,
+            )?)
+        };
+        Ok(channel)
+    }
+
+    pub fn decode(payload: cglue::iso20_PowerDeliveryReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.PowerDeliveryReq = self.payload;
+            exi_body.set_PowerDeliveryReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct PowerDeliveryResponse {
+    payload: cglue::iso20_PowerDeliveryResType,
+}
+
+impl PowerDeliveryResponse {
+    pub fn new(code: ResponseCode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_PowerDeliveryResType>() };
+        payload.ResponseCode = code as u32;
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn set_evse_status(&mut self, status: &EvseStatusType) -> &mut Self {
+        self.payload.EVSEStatus = status.encode();
+        self.payload.set_EVSEStatus_isUsed(1);
+        self
+    }
+
+    pub fn get_evse_status(&self) -> Option<EvseStatusType> {
+        if self.payload.EVSEStatus_isUsed() == 0 {
+            None
+        } else {
+            Some(EvseStatusType::decode(self.payload.EVSEStatus))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_PowerDeliveryResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.PowerDeliveryRes = self.payload;
+            exi_body.set_PowerDeliveryRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for PowerDeliveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("EVProcessing", self.get_processing())
+            .result("ChargeProgress", self.get_progress())
+            .result_option("BPT_ChannelSelection", self.get_channel_selection());
+    }
+}
+
+impl ExiFields for PowerDeliveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("EVSEStatus", self.get_evse_status());
+    }
+}
+crate::exi_format!(PowerDeliveryRequest, PowerDeliveryResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerDeliveryReqXsd {
+        EVProcessing: EvseProcessing,
+        ChargeProgress: ChargeProgress,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BPT_ChannelSelection: Option<ChannelSelection>,
+    }
+
+    impl PowerDeliveryReqXsd {
+        fn from_exi(exi: &PowerDeliveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVProcessing: exi.get_processing()?,
+                ChargeProgress: exi.get_progress()?,
+                BPT_ChannelSelection: exi.get_channel_selection()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<PowerDeliveryRequest, AfbError> {
+            let mut exi = PowerDeliveryRequest::new(self.EVProcessing, self.ChargeProgress);
+            if let Some(channel) = self.BPT_ChannelSelection {
+                exi.set_channel_selection(channel);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PowerDeliveryRequest, PowerDeliveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerDeliveryResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEStatus: Option<EvseStatusType>,
+    }
+
+    impl PowerDeliveryResXsd {
+        fn from_exi(exi: &PowerDeliveryResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEStatus: exi.get_evse_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PowerDeliveryResponse, AfbError> {
+            let mut exi = PowerDeliveryResponse::new(self.ResponseCode);
+            if let Some(status) = &self.EVSEStatus {
+                exi.set_evse_status(status);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PowerDeliveryResponse, PowerDeliveryResXsd);
+}
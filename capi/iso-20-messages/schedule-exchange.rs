@@ -0,0 +1,924 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone, Copy)]
+pub struct DynamicReqMode {
+    payload: cglue::iso20_Dynamic_SEReqControlModeType,
+}
+
+impl DynamicReqMode {
+    pub fn new(
+        departure_time: u32,
+        target_energy: &RationalNumber,
+        max_energy: &RationalNumber,
+        min_energy: &RationalNumber,
+    ) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_Dynamic_SEReqControlModeType>() };
+        payload.DepartureTime = departure_time;
+        payload.EVTargetEnergyRequest = target_energy.encode();
+        payload.EVMaximumEnergyRequest = max_energy.encode();
+        payload.EVMinimumEnergyRequest = min_energy.encode();
+        Self { payload }
+    }
+
+    pub fn get_departure_time(&self) -> u32 {
+        self.payload.DepartureTime
+    }
+
+    pub fn get_target_energy(&self) -> RationalNumber {
+        RationalNumber::decode(self.payload.EVTargetEnergyRequest)
+    }
+
+    pub fn get_max_energy(&self) -> RationalNumber {
+        RationalNumber::decode(self.payload.EVMaximumEnergyRequest)
+    }
+
+    pub fn get_min_energy(&self) -> RationalNumber {
+        RationalNumber::decode(self.payload.EVMinimumEnergyRequest)
+    }
+
+    pub fn set_min_soc(&mut self, soc: i8) -> &mut Self {
+        self.payload.MinimumSOC = soc;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, soc: i8) -> &mut Self {
+        self.payload.TargetSOC = soc;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    // BPT only, energy the EV accepts to discharge beyond its own needs
+    pub fn set_max_v2x_energy(&mut self, energy: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumV2XEnergyRequest = energy.encode();
+        self.payload.set_EVMaximumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(RationalNumber::decode(self.payload.EVMaximumV2XEnergyRequest))
+        }
+    }
+
+    pub fn set_min_v2x_energy(&mut self, energy: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumV2XEnergyRequest = energy.encode();
+        self.payload.set_EVMinimumV2XEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_v2x_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumV2XEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(RationalNumber::decode(self.payload.EVMinimumV2XEnergyRequest))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_Dynamic_SEReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_Dynamic_SEReqControlModeType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledReqMode {
+    payload: cglue::iso20_Scheduled_SEReqControlModeType,
+}
+
+impl ScheduledReqMode {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_Scheduled_SEReqControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_departure_time(&mut self, departure_time: u32) -> &mut Self {
+        self.payload.DepartureTime = departure_time;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_target_energy(&mut self, energy: &RationalNumber) -> &mut Self {
+        self.payload.EVTargetEnergyRequest = energy.encode();
+        self.payload.set_EVTargetEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_target_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVTargetEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(RationalNumber::decode(self.payload.EVTargetEnergyRequest))
+        }
+    }
+
+    pub fn set_max_energy(&mut self, energy: &RationalNumber) -> &mut Self {
+        self.payload.EVMaximumEnergyRequest = energy.encode();
+        self.payload.set_EVMaximumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_max_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMaximumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(RationalNumber::decode(self.payload.EVMaximumEnergyRequest))
+        }
+    }
+
+    pub fn set_min_energy(&mut self, energy: &RationalNumber) -> &mut Self {
+        self.payload.EVMinimumEnergyRequest = energy.encode();
+        self.payload.set_EVMinimumEnergyRequest_isUsed(1);
+        self
+    }
+
+    pub fn get_min_energy(&self) -> Option<RationalNumber> {
+        if self.payload.EVMinimumEnergyRequest_isUsed() == 0 {
+            None
+        } else {
+            Some(RationalNumber::decode(self.payload.EVMinimumEnergyRequest))
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_Scheduled_SEReqControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_Scheduled_SEReqControlModeType {
+        self.payload
+    }
+}
+
+pub enum ScheduleReqMode {
+    Dynamic(DynamicReqMode),
+    Scheduled(ScheduledReqMode),
+}
+
+pub struct ScheduleExchangeRequest {
+    payload: cglue::iso20_ScheduleExchangeReqType,
+}
+
+impl ScheduleExchangeRequest {
+    pub fn new(max_points: u16, mode: &ScheduleReqMode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ScheduleExchangeReqType>() };
+        payload.MaximumSupportingPoints = max_points;
+        match mode {
+            ScheduleReqMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_SEReqControlMode = value.encode();
+                payload.set_Dynamic_SEReqControlMode_isUsed(1);
+            }
+            ScheduleReqMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_SEReqControlMode = value.encode();
+                payload.set_Scheduled_SEReqControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn get_max_points(&self) -> u16 {
+        self.payload.MaximumSupportingPoints
+    }
+
+    pub fn get_mode(&self) -> Result<ScheduleReqMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_SEReqControlMode_isUsed() != 0 {
+                ScheduleReqMode::Dynamic(DynamicReqMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_SEReqControlMode,
+                ))
+            } else if self.payload.Scheduled_SEReqControlMode_isUsed() != 0 {
+                ScheduleReqMode::Scheduled(ScheduledReqMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_SEReqControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-schedule-exchange-req", "no control mode defined");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_ScheduleExchangeReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ScheduleExchangeReq = self.payload;
+            exi_body.set_ScheduleExchangeReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct DynamicResMode {
+    payload: cglue::iso20_Dynamic_SEResControlModeType,
+}
+
+impl DynamicResMode {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_Dynamic_SEResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn set_departure_time(&mut self, departure_time: u32) -> &mut Self {
+        self.payload.DepartureTime = departure_time;
+        self.payload.set_DepartureTime_isUsed(1);
+        self
+    }
+
+    pub fn get_departure_time(&self) -> Option<u32> {
+        if self.payload.DepartureTime_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.DepartureTime)
+        }
+    }
+
+    pub fn set_min_soc(&mut self, soc: i8) -> &mut Self {
+        self.payload.MinimumSOC = soc;
+        self.payload.set_MinimumSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_min_soc(&self) -> Option<i8> {
+        if self.payload.MinimumSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.MinimumSOC)
+        }
+    }
+
+    pub fn set_target_soc(&mut self, soc: i8) -> &mut Self {
+        self.payload.TargetSOC = soc;
+        self.payload.set_TargetSOC_isUsed(1);
+        self
+    }
+
+    pub fn get_target_soc(&self) -> Option<i8> {
+        if self.payload.TargetSOC_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.TargetSOC)
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_Dynamic_SEResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_Dynamic_SEResControlModeType {
+        self.payload
+    }
+}
+
+// charging schedule only, price and discharging schedules are not supported
+#[derive(Clone, Copy)]
+pub struct ScheduleTuple {
+    payload: cglue::iso20_ScheduleTupleType,
+}
+
+impl ScheduleTuple {
+    pub fn new(tuple_id: u32, time_anchor: u64) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ScheduleTupleType>() };
+        payload.ScheduleTupleID = tuple_id;
+        payload.ChargingSchedule.PowerSchedule.TimeAnchor = time_anchor;
+        Self { payload }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.payload.ScheduleTupleID
+    }
+
+    pub fn get_time_anchor(&self) -> u64 {
+        self.payload.ChargingSchedule.PowerSchedule.TimeAnchor
+    }
+
+    pub fn add_power_entry(
+        &mut self,
+        duration: u32,
+        power: &RationalNumber,
+    ) -> Result<&mut Self, AfbError> {
+        let entries = &mut self
+            .payload
+            .ChargingSchedule
+            .PowerSchedule
+            .PowerScheduleEntries
+            .PowerScheduleEntry;
+        let idx = entries.arrayLen;
+        if idx == cglue::iso20_PowerScheduleEntryType_1024_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-schedule-tuple", "power entry array full");
+        }
+        entries.array[idx as usize].Duration = duration;
+        entries.array[idx as usize].Power = power.encode();
+        entries.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_power_entries(&self) -> Vec<(u32, RationalNumber)> {
+        let entries = &self
+            .payload
+            .ChargingSchedule
+            .PowerSchedule
+            .PowerScheduleEntries
+            .PowerScheduleEntry;
+        let mut response = Vec::new();
+        for idx in 0..entries.arrayLen {
+            let entry = &entries.array[idx as usize];
+            response.push((entry.Duration, RationalNumber::decode(entry.Power)));
+        }
+        response
+    }
+
+    pub fn decode(payload: cglue::iso20_ScheduleTupleType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ScheduleTupleType {
+        self.payload
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct ScheduledResMode {
+    payload: cglue::iso20_Scheduled_SEResControlModeType,
+}
+
+impl ScheduledResMode {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_Scheduled_SEResControlModeType>() };
+        Self { payload }
+    }
+
+    pub fn add_schedule(&mut self, schedule: &ScheduleTuple) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.ScheduleTuple.arrayLen;
+        if idx == cglue::iso20_ScheduleTupleType_3_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-scheduled-res-mode", "schedule tuple array full");
+        }
+        self.payload.ScheduleTuple.array[idx as usize] = schedule.encode();
+        self.payload.ScheduleTuple.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_schedules(&self) -> Vec<ScheduleTuple> {
+        let mut schedules = Vec::new();
+        for idx in 0..self.payload.ScheduleTuple.arrayLen {
+            schedules.push(ScheduleTuple::decode(
+                self.payload.ScheduleTuple.array[idx as usize],
+            ));
+        }
+        schedules
+    }
+
+    pub fn decode(payload: cglue::iso20_Scheduled_SEResControlModeType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_Scheduled_SEResControlModeType {
+        self.payload
+    }
+}
+
+pub enum ScheduleResMode {
+    Dynamic(DynamicResMode),
+    Scheduled(ScheduledResMode),
+}
+
+pub struct ScheduleExchangeResponse {
+    payload: cglue::iso20_ScheduleExchangeResType,
+}
+
+impl ScheduleExchangeResponse {
+    pub fn new(code: ResponseCode, processing: EvseProcessing, mode: &ScheduleResMode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ScheduleExchangeResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEProcessing = processing as u32;
+        match mode {
+            ScheduleResMode::Dynamic(value) => {
+                payload.__bindgen_anon_1.Dynamic_SEResControlMode = value.encode();
+                payload.set_Dynamic_SEResControlMode_isUsed(1);
+            }
+            ScheduleResMode::Scheduled(value) => {
+                payload.__bindgen_anon_1.Scheduled_SEResControlMode = value.encode();
+                payload.set_Scheduled_SEResControlMode_isUsed(1);
+            }
+        }
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
+        EvseProcessing::from_u32(self.payload.EVSEProcessing)
+    }
+
+    pub fn set_go_to_pause(&mut self, pause: bool) -> &mut Self {
+        self.payload.GoToPause = if pause { 1 } else { 0 };
+        self.payload.set_GoToPause_isUsed(1);
+        self
+    }
+
+    pub fn get_go_to_pause(&self) -> Option<bool> {
+        if self.payload.GoToPause_isUsed() == 0 {
+            None
+        } else {
+            Some(self.payload.GoToPause != 0)
+        }
+    }
+
+    pub fn get_mode(&self) -> Result<ScheduleResMode, AfbError> {
+        let mode = unsafe {
+            if self.payload.Dynamic_SEResControlMode_isUsed() != 0 {
+                ScheduleResMode::Dynamic(DynamicResMode::decode(
+                    self.payload.__bindgen_anon_1.Dynamic_SEResControlMode,
+                ))
+            } else if self.payload.Scheduled_SEResControlMode_isUsed() != 0 {
+                ScheduleResMode::Scheduled(ScheduledResMode::decode(
+                    self.payload.__bindgen_anon_1.Scheduled_SEResControlMode,
+                ))
+            } else {
+                return afb_error!("iso20-schedule-exchange-res", "no control mode defined");
+            }
+        };
+        Ok(mode)
+    }
+
+    pub fn decode(payload: cglue::iso20_ScheduleExchangeResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ScheduleExchangeRes = self.payload;
+            exi_body.set_ScheduleExchangeRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for DynamicReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc())
+            .field("EVTargetEnergyRequest", self.get_target_energy())
+            .field("EVMaximumEnergyRequest", self.get_max_energy())
+            .field("EVMinimumEnergyRequest", self.get_min_energy())
+            .option("EVMaximumV2XEnergyRequest", self.get_max_v2x_energy())
+            .option("EVMinimumV2XEnergyRequest", self.get_min_v2x_energy());
+    }
+}
+
+impl ExiFields for ScheduledReqMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DepartureTime", self.get_departure_time())
+            .option("EVTargetEnergyRequest", self.get_target_energy())
+            .option("EVMaximumEnergyRequest", self.get_max_energy())
+            .option("EVMinimumEnergyRequest", self.get_min_energy());
+    }
+}
+
+impl ExiFields for ScheduleExchangeRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("MaximumSupportingPoints", self.get_max_points());
+        match self.get_mode() {
+            Ok(ScheduleReqMode::Dynamic(mode)) => fields.field("Dynamic_SEReqControlMode", mode),
+            Ok(ScheduleReqMode::Scheduled(mode)) => {
+                fields.field("Scheduled_SEReqControlMode", mode)
+            }
+            Err(error) => fields.field("SEReqControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+
+impl ExiFields for DynamicResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DepartureTime", self.get_departure_time())
+            .option("MinimumSOC", self.get_min_soc())
+            .option("TargetSOC", self.get_target_soc());
+    }
+}
+
+impl ExiFields for ScheduleTuple {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        let entries = self
+            .get_power_entries()
+            .iter()
+            .map(|(duration, power)| format!("{{Duration:{} Power:{}}}", duration, power))
+            .collect();
+        fields
+            .field("ScheduleTupleID", self.get_id())
+            .field("TimeAnchor", self.get_time_anchor())
+            .field("PowerScheduleEntries", ExiList::<String>(entries));
+    }
+}
+
+impl ExiFields for ScheduledResMode {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("ScheduleTuple", ExiList(self.get_schedules()));
+    }
+}
+
+impl ExiFields for ScheduleExchangeResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing())
+            .option("GoToPause", self.get_go_to_pause());
+        match self.get_mode() {
+            Ok(ScheduleResMode::Dynamic(mode)) => fields.field("Dynamic_SEResControlMode", mode),
+            Ok(ScheduleResMode::Scheduled(mode)) => {
+                fields.field("Scheduled_SEResControlMode", mode)
+            }
+            Err(error) => fields.field("SEResControlMode", format!("invalid({})", error)),
+        };
+    }
+}
+crate::exi_format!(
+    DynamicReqMode,
+    ScheduledReqMode,
+    ScheduleExchangeRequest,
+    DynamicResMode,
+    ScheduleTuple,
+    ScheduledResMode,
+    ScheduleExchangeResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicSeReqControlModeXsd {
+        DepartureTime: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+        EVTargetEnergyRequest: RationalNumber,
+        EVMaximumEnergyRequest: RationalNumber,
+        EVMinimumEnergyRequest: RationalNumber,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumV2XEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumV2XEnergyRequest: Option<RationalNumber>,
+    }
+
+    impl DynamicSeReqControlModeXsd {
+        fn from_exi(exi: &DynamicReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+                EVMaximumV2XEnergyRequest: exi.get_max_v2x_energy(),
+                EVMinimumV2XEnergyRequest: exi.get_min_v2x_energy(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicReqMode, AfbError> {
+            let mut exi = DynamicReqMode::new(
+                self.DepartureTime,
+                &self.EVTargetEnergyRequest,
+                &self.EVMaximumEnergyRequest,
+                &self.EVMinimumEnergyRequest,
+            );
+            if let Some(soc) = self.MinimumSOC {
+                exi.set_min_soc(soc);
+            }
+            if let Some(soc) = self.TargetSOC {
+                exi.set_target_soc(soc);
+            }
+            if let Some(energy) = &self.EVMaximumV2XEnergyRequest {
+                exi.set_max_v2x_energy(energy);
+            }
+            if let Some(energy) = &self.EVMinimumV2XEnergyRequest {
+                exi.set_min_v2x_energy(energy);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicReqMode, DynamicSeReqControlModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledSeReqControlModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTargetEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumEnergyRequest: Option<RationalNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMinimumEnergyRequest: Option<RationalNumber>,
+    }
+
+    impl ScheduledSeReqControlModeXsd {
+        fn from_exi(exi: &ScheduledReqMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                EVTargetEnergyRequest: exi.get_target_energy(),
+                EVMaximumEnergyRequest: exi.get_max_energy(),
+                EVMinimumEnergyRequest: exi.get_min_energy(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledReqMode, AfbError> {
+            let mut exi = ScheduledReqMode::new();
+            if let Some(departure_time) = self.DepartureTime {
+                exi.set_departure_time(departure_time);
+            }
+            if let Some(energy) = &self.EVTargetEnergyRequest {
+                exi.set_target_energy(energy);
+            }
+            if let Some(energy) = &self.EVMaximumEnergyRequest {
+                exi.set_max_energy(energy);
+            }
+            if let Some(energy) = &self.EVMinimumEnergyRequest {
+                exi.set_min_energy(energy);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledReqMode, ScheduledSeReqControlModeXsd);
+
+    // control mode is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct ScheduleExchangeReqXsd {
+        MaximumSupportingPoints: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_SEReqControlMode: Option<DynamicReqMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_SEReqControlMode: Option<ScheduledReqMode>,
+    }
+
+    impl ScheduleExchangeReqXsd {
+        fn from_exi(exi: &ScheduleExchangeRequest) -> Result<Self, AfbError> {
+            let mode = exi.get_mode()?;
+            Ok(Self {
+                MaximumSupportingPoints: exi.get_max_points(),
+                Dynamic_SEReqControlMode: match &mode {
+                    ScheduleReqMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_SEReqControlMode: match &mode {
+                    ScheduleReqMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduleExchangeRequest, AfbError> {
+            let mode = match (
+                self.Dynamic_SEReqControlMode,
+                self.Scheduled_SEReqControlMode,
+            ) {
+                (Some(value), None) => ScheduleReqMode::Dynamic(value),
+                (None, Some(value)) => ScheduleReqMode::Scheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-schedule-exchange-req",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            Ok(ScheduleExchangeRequest::new(
+                self.MaximumSupportingPoints,
+                &mode,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(ScheduleExchangeRequest, ScheduleExchangeReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DynamicSeResControlModeXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MinimumSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TargetSOC: Option<i8>,
+    }
+
+    impl DynamicSeResControlModeXsd {
+        fn from_exi(exi: &DynamicResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                MinimumSOC: exi.get_min_soc(),
+                TargetSOC: exi.get_target_soc(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DynamicResMode, AfbError> {
+            let mut exi = DynamicResMode::new();
+            if let Some(departure_time) = self.DepartureTime {
+                exi.set_departure_time(departure_time);
+            }
+            if let Some(soc) = self.MinimumSOC {
+                exi.set_min_soc(soc);
+            }
+            if let Some(soc) = self.TargetSOC {
+                exi.set_target_soc(soc);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DynamicResMode, DynamicSeResControlModeXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerScheduleEntryXsd {
+        Duration: u32,
+        Power: RationalNumber,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerScheduleEntryListXsd {
+        PowerScheduleEntry: Vec<PowerScheduleEntryXsd>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct PowerScheduleXsd {
+        TimeAnchor: u64,
+        PowerScheduleEntries: PowerScheduleEntryListXsd,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargingScheduleXsd {
+        PowerSchedule: PowerScheduleXsd,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduleTupleXsd {
+        ScheduleTupleID: u32,
+        ChargingSchedule: ChargingScheduleXsd,
+    }
+
+    impl ScheduleTupleXsd {
+        fn from_exi(exi: &ScheduleTuple) -> Result<Self, AfbError> {
+            let mut entries = Vec::new();
+            for (duration, power) in exi.get_power_entries() {
+                entries.push(PowerScheduleEntryXsd {
+                    Duration: duration,
+                    Power: power,
+                });
+            }
+            Ok(Self {
+                ScheduleTupleID: exi.get_id(),
+                ChargingSchedule: ChargingScheduleXsd {
+                    PowerSchedule: PowerScheduleXsd {
+                        TimeAnchor: exi.get_time_anchor(),
+                        PowerScheduleEntries: PowerScheduleEntryListXsd {
+                            PowerScheduleEntry: entries,
+                        },
+                    },
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduleTuple, AfbError> {
+            let power_schedule = self.ChargingSchedule.PowerSchedule;
+            let mut exi = ScheduleTuple::new(self.ScheduleTupleID, power_schedule.TimeAnchor);
+            for entry in &power_schedule.PowerScheduleEntries.PowerScheduleEntry {
+                exi.add_power_entry(entry.Duration, &entry.Power)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduleTuple, ScheduleTupleXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ScheduledSeResControlModeXsd {
+        ScheduleTuple: Vec<ScheduleTuple>,
+    }
+
+    impl ScheduledSeResControlModeXsd {
+        fn from_exi(exi: &ScheduledResMode) -> Result<Self, AfbError> {
+            Ok(Self {
+                ScheduleTuple: exi.get_schedules(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduledResMode, AfbError> {
+            let mut exi = ScheduledResMode::new();
+            for schedule in &self.ScheduleTuple {
+                exi.add_schedule(schedule)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduledResMode, ScheduledSeResControlModeXsd);
+
+    // control mode is an XSD choice, only one of the optional fields is present
+    #[derive(Serialize, Deserialize)]
+    struct ScheduleExchangeResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        GoToPause: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Dynamic_SEResControlMode: Option<DynamicResMode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Scheduled_SEResControlMode: Option<ScheduledResMode>,
+    }
+
+    impl ScheduleExchangeResXsd {
+        fn from_exi(exi: &ScheduleExchangeResponse) -> Result<Self, AfbError> {
+            let mode = exi.get_mode()?;
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+                GoToPause: exi.get_go_to_pause(),
+                Dynamic_SEResControlMode: match &mode {
+                    ScheduleResMode::Dynamic(value) => Some(*value),
+                    _ => None,
+                },
+                Scheduled_SEResControlMode: match &mode {
+                    ScheduleResMode::Scheduled(value) => Some(*value),
+                    _ => None,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ScheduleExchangeResponse, AfbError> {
+            let mode = match (
+                self.Dynamic_SEResControlMode,
+                self.Scheduled_SEResControlMode,
+            ) {
+                (Some(value), None) => ScheduleResMode::Dynamic(value),
+                (None, Some(value)) => ScheduleResMode::Scheduled(value),
+                _ => {
+                    return afb_error!(
+                        "iso20-schedule-exchange-res",
+                        "expect exactly one control mode"
+                    )
+                }
+            };
+            let mut exi =
+                ScheduleExchangeResponse::new(self.ResponseCode, self.EVSEProcessing, &mode);
+            if let Some(pause) = self.GoToPause {
+                exi.set_go_to_pause(pause);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ScheduleExchangeResponse, ScheduleExchangeResXsd);
+}
@@ -0,0 +1,179 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct ServiceDetailRequest {
+    payload: cglue::iso20_ServiceDetailReqType,
+}
+
+impl ServiceDetailRequest {
+    pub fn new(service_id: u16) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceDetailReqType>() };
+        payload.ServiceID = service_id;
+        Self { payload }
+    }
+
+    pub fn get_id(&self) -> u16 {
+        self.payload.ServiceID
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceDetailReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceDetailReq = self.payload;
+            exi_body.set_ServiceDetailReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct ServiceDetailResponse {
+    payload: cglue::iso20_ServiceDetailResType,
+}
+
+impl ServiceDetailResponse {
+    pub fn new(service_id: u16, code: ResponseCode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceDetailResType>() };
+        payload.ServiceID = service_id;
+        payload.ResponseCode = code as u32;
+        Self { payload }
+    }
+
+    pub fn get_id(&self) -> u16 {
+        self.payload.ServiceID
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn add_pset(&mut self, prm_set: &ParamSet) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.ServiceParameterList.ParameterSet.arrayLen;
+        if idx == cglue::iso20_ParameterSetType_32_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-service-detail-param", "param set array full");
+        }
+        self.payload.ServiceParameterList.ParameterSet.array[idx as usize] = prm_set.encode();
+        self.payload.ServiceParameterList.ParameterSet.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_psets(&self) -> Vec<ParamSet> {
+        let mut params = Vec::new();
+        for idx in 0..self.payload.ServiceParameterList.ParameterSet.arrayLen {
+            params.push(ParamSet::decode(
+                self.payload.ServiceParameterList.ParameterSet.array[idx as usize],
+            ))
+        }
+        params
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceDetailResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceDetailRes = self.payload;
+            exi_body.set_ServiceDetailRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for ServiceDetailRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("ServiceID", self.get_id());
+    }
+}
+
+impl ExiFields for ServiceDetailResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("ServiceID", self.get_id())
+            .field("ServiceParameterList", ExiList(self.get_psets()));
+    }
+}
+crate::exi_format!(ServiceDetailRequest, ServiceDetailResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDetailReqXsd {
+        ServiceID: u16,
+    }
+
+    impl ServiceDetailReqXsd {
+        fn from_exi(exi: &ServiceDetailRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_id(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDetailRequest, AfbError> {
+            Ok(ServiceDetailRequest::new(self.ServiceID))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDetailRequest, ServiceDetailReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceParameterListXsd {
+        ParameterSet: Vec<ParamSet>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDetailResXsd {
+        ResponseCode: ResponseCode,
+        ServiceID: u16,
+        ServiceParameterList: ServiceParameterListXsd,
+    }
+
+    impl ServiceDetailResXsd {
+        fn from_exi(exi: &ServiceDetailResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                ServiceID: exi.get_id(),
+                ServiceParameterList: ServiceParameterListXsd {
+                    ParameterSet: exi.get_psets(),
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDetailResponse, AfbError> {
+            let mut exi = ServiceDetailResponse::new(self.ServiceID, self.ResponseCode);
+            for prm_set in &self.ServiceParameterList.ParameterSet {
+                exi.add_pset(prm_set)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDetailResponse, ServiceDetailResXsd);
+}
@@ -0,0 +1,339 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone)]
+pub struct ServiceOffer {
+    payload: cglue::iso20_ServiceType,
+}
+
+impl ServiceOffer {
+    pub fn new(id: ServiceId, isfree: bool) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceType>() };
+        payload.ServiceID = id as u16;
+        if isfree {
+            payload.FreeService = 1;
+        }
+        Self { payload }
+    }
+
+    pub fn get_id(&self) -> Result<ServiceId, AfbError> {
+        ServiceId::from_u16(self.payload.ServiceID)
+    }
+
+    pub fn get_isfree(&self) -> bool {
+        if self.payload.FreeService != 0 {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_ServiceType {
+        self.payload
+    }
+}
+
+pub struct ServiceDiscoveryRequest {
+    payload: cglue::iso20_ServiceDiscoveryReqType,
+}
+
+impl ServiceDiscoveryRequest {
+    pub fn new() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::iso20_ServiceDiscoveryReqType>() };
+        Self { payload }
+    }
+
+    pub fn add_service_id(&mut self, service_id: u16) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.SupportedServiceIDs.ServiceID.arrayLen;
+        if idx == cglue::iso20_ServiceID_10_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-service-discovery-req", "service id array full");
+        }
+        self.payload.SupportedServiceIDs.ServiceID.array[idx as usize] = service_id;
+        self.payload.SupportedServiceIDs.ServiceID.arrayLen = idx + 1;
+        self.payload.set_SupportedServiceIDs_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_service_ids(&self) -> Vec<u16> {
+        let mut ids = Vec::new();
+        if self.payload.SupportedServiceIDs_isUsed() != 0 {
+            for idx in 0..self.payload.SupportedServiceIDs.ServiceID.arrayLen {
+                ids.push(self.payload.SupportedServiceIDs.ServiceID.array[idx as usize]);
+            }
+        }
+        ids
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceDiscoveryReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceDiscoveryReq = self.payload;
+            exi_body.set_ServiceDiscoveryReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct ServiceDiscoveryResponse {
+    payload: cglue::iso20_ServiceDiscoveryResType,
+}
+
+impl ServiceDiscoveryResponse {
+    pub fn new(code: ResponseCode, renegotiation: bool) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceDiscoveryResType>() };
+        payload.ResponseCode = code as u32;
+        payload.ServiceRenegotiationSupported = if renegotiation { 1 } else { 0 };
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn get_renegotiation(&self) -> bool {
+        if self.payload.ServiceRenegotiationSupported != 0 {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn add_transfer_service(&mut self, service: &ServiceOffer) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.EnergyTransferServiceList.Service.arrayLen;
+        if idx == cglue::iso20_ServiceType_8_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-service-discovery-res", "transfer service array full");
+        }
+        self.payload.EnergyTransferServiceList.Service.array[idx as usize] = service.encode();
+        self.payload.EnergyTransferServiceList.Service.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_transfer_services(&self) -> Vec<ServiceOffer> {
+        let mut services = Vec::new();
+        for idx in 0..self.payload.EnergyTransferServiceList.Service.arrayLen {
+            services.push(ServiceOffer::decode(
+                self.payload.EnergyTransferServiceList.Service.array[idx as usize],
+            ));
+        }
+        services
+    }
+
+    pub fn add_vas_service(&mut self, service: &ServiceOffer) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.VASList.Service.arrayLen;
+        if idx == cglue::iso20_ServiceType_8_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-service-discovery-res", "vas service array full");
+        }
+        self.payload.VASList.Service.array[idx as usize] = service.encode();
+        self.payload.VASList.Service.arrayLen = idx + 1;
+        self.payload.set_VASList_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_vas_services(&self) -> Vec<ServiceOffer> {
+        let mut services = Vec::new();
+        if self.payload.VASList_isUsed() != 0 {
+            for idx in 0..self.payload.VASList.Service.arrayLen {
+                services.push(ServiceOffer::decode(
+                    self.payload.VASList.Service.array[idx as usize],
+                ));
+            }
+        }
+        services
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceDiscoveryResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceDiscoveryRes = self.payload;
+            exi_body.set_ServiceDiscoveryRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for ServiceOffer {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ServiceID", self.get_id())
+            .field("FreeService", self.get_isfree());
+    }
+}
+
+impl ExiFields for ServiceDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        if self.payload.SupportedServiceIDs_isUsed() != 0 {
+            fields.field("SupportedServiceIDs", ExiList(self.get_service_ids()));
+        }
+    }
+}
+
+impl ExiFields for ServiceDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("ServiceRenegotiationSupported", self.get_renegotiation())
+            .field(
+                "EnergyTransferServiceList",
+                ExiList(self.get_transfer_services()),
+            );
+        if self.payload.VASList_isUsed() != 0 {
+            fields.field("VASList", ExiList(self.get_vas_services()));
+        }
+    }
+}
+crate::exi_format!(
+    ServiceOffer,
+    ServiceDiscoveryRequest,
+    ServiceDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    // raw ServiceID, value added services are not listed in ServiceId
+    #[derive(Serialize, Deserialize)]
+    struct ServiceXsd {
+        ServiceID: u16,
+        FreeService: bool,
+    }
+
+    impl ServiceXsd {
+        fn from_exi(exi: &ServiceOffer) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.payload.ServiceID,
+                FreeService: exi.get_isfree(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceOffer, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceType>() };
+            payload.ServiceID = self.ServiceID;
+            payload.FreeService = if self.FreeService { 1 } else { 0 };
+            Ok(ServiceOffer::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceOffer, ServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceIdListXsd {
+        ServiceID: Vec<u16>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SupportedServiceIDs: Option<ServiceIdListXsd>,
+    }
+
+    impl ServiceDiscoveryReqXsd {
+        fn from_exi(exi: &ServiceDiscoveryRequest) -> Result<Self, AfbError> {
+            let service_ids = if exi.payload.SupportedServiceIDs_isUsed() == 0 {
+                None
+            } else {
+                Some(ServiceIdListXsd {
+                    ServiceID: exi.get_service_ids(),
+                })
+            };
+            Ok(Self {
+                SupportedServiceIDs: service_ids,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryRequest, AfbError> {
+            let mut exi = ServiceDiscoveryRequest::new();
+            if let Some(service_ids) = self.SupportedServiceIDs {
+                for service_id in service_ids.ServiceID {
+                    exi.add_service_id(service_id)?;
+                }
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryRequest, ServiceDiscoveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceListXsd {
+        Service: Vec<ServiceOffer>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        ServiceRenegotiationSupported: bool,
+        EnergyTransferServiceList: ServiceListXsd,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        VASList: Option<ServiceListXsd>,
+    }
+
+    impl ServiceDiscoveryResXsd {
+        fn from_exi(exi: &ServiceDiscoveryResponse) -> Result<Self, AfbError> {
+            let vas_services = if exi.payload.VASList_isUsed() == 0 {
+                None
+            } else {
+                Some(ServiceListXsd {
+                    Service: exi.get_vas_services(),
+                })
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                ServiceRenegotiationSupported: exi.get_renegotiation(),
+                EnergyTransferServiceList: ServiceListXsd {
+                    Service: exi.get_transfer_services(),
+                },
+                VASList: vas_services,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryResponse, AfbError> {
+            let mut exi = ServiceDiscoveryResponse::new(
+                self.ResponseCode,
+                self.ServiceRenegotiationSupported,
+            );
+            for service in &self.EnergyTransferServiceList.Service {
+                exi.add_transfer_service(service)?;
+            }
+            if let Some(vas_list) = self.VASList {
+                for service in &vas_list.Service {
+                    exi.add_vas_service(service)?;
+                }
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryResponse, ServiceDiscoveryResXsd);
+}
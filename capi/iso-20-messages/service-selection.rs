@@ -0,0 +1,245 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone, Copy)]
+pub struct SelectedService {
+    payload: cglue::iso20_SelectedServiceType,
+}
+
+impl SelectedService {
+    pub fn new(service_id: u16, param_id: u16) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_SelectedServiceType>() };
+        payload.ServiceID = service_id;
+        payload.ParameterSetID = param_id;
+        Self { payload }
+    }
+
+    pub fn get_service_id(&self) -> u16 {
+        self.payload.ServiceID
+    }
+
+    pub fn get_param_id(&self) -> u16 {
+        self.payload.ParameterSetID
+    }
+
+    pub fn decode(payload: cglue::iso20_SelectedServiceType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_SelectedServiceType {
+        self.payload
+    }
+}
+
+pub struct ServiceSelectionRequest {
+    payload: cglue::iso20_ServiceSelectionReqType,
+}
+
+impl ServiceSelectionRequest {
+    pub fn new(energy_service: &SelectedService) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceSelectionReqType>() };
+        payload.SelectedEnergyTransferService = energy_service.encode();
+        Self { payload }
+    }
+
+    pub fn get_energy_service(&self) -> SelectedService {
+        SelectedService::decode(self.payload.SelectedEnergyTransferService)
+    }
+
+    pub fn add_vas_service(&mut self, service: &SelectedService) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.SelectedVASList.SelectedService.arrayLen;
+        if idx == cglue::iso20_SelectedServiceType_16_ARRAY_SIZE as u16 {
+            return afb_error!("iso20-service-selection-req", "vas service array full");
+        }
+        self.payload.SelectedVASList.SelectedService.array[idx as usize] = service.encode();
+        self.payload.SelectedVASList.SelectedService.arrayLen = idx + 1;
+        self.payload.set_SelectedVASList_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_vas_services(&self) -> Vec<SelectedService> {
+        let mut services = Vec::new();
+        if self.payload.SelectedVASList_isUsed() != 0 {
+            for idx in 0..self.payload.SelectedVASList.SelectedService.arrayLen {
+                services.push(SelectedService::decode(
+                    self.payload.SelectedVASList.SelectedService.array[idx as usize],
+                ));
+            }
+        }
+        services
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceSelectionReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceSelectionReq = self.payload;
+            exi_body.set_ServiceSelectionReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct ServiceSelectionResponse {
+    payload: cglue::iso20_ServiceSelectionResType,
+}
+
+impl ServiceSelectionResponse {
+    pub fn new(code: ResponseCode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_ServiceSelectionResType>() };
+        payload.ResponseCode = code as u32;
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn decode(payload: cglue::iso20_ServiceSelectionResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.ServiceSelectionRes = self.payload;
+            exi_body.set_ServiceSelectionRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for SelectedService {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceID", self.get_service_id())
+            .field("ParameterSetID", self.get_param_id());
+    }
+}
+
+impl ExiFields for ServiceSelectionRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("SelectedEnergyTransferService", self.get_energy_service());
+        if self.payload.SelectedVASList_isUsed() != 0 {
+            fields.field("SelectedVASList", ExiList(self.get_vas_services()));
+        }
+    }
+}
+
+impl ExiFields for ServiceSelectionResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ResponseCode", self.get_rcode());
+    }
+}
+crate::exi_format!(
+    SelectedService,
+    ServiceSelectionRequest,
+    ServiceSelectionResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SelectedServiceXsd {
+        ServiceID: u16,
+        ParameterSetID: u16,
+    }
+
+    impl SelectedServiceXsd {
+        fn from_exi(exi: &SelectedService) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_service_id(),
+                ParameterSetID: exi.get_param_id(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SelectedService, AfbError> {
+            Ok(SelectedService::new(self.ServiceID, self.ParameterSetID))
+        }
+    }
+    crate::exi_serde_mirror!(SelectedService, SelectedServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SelectedServiceListXsd {
+        SelectedService: Vec<SelectedService>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceSelectionReqXsd {
+        SelectedEnergyTransferService: SelectedService,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SelectedVASList: Option<SelectedServiceListXsd>,
+    }
+
+    impl ServiceSelectionReqXsd {
+        fn from_exi(exi: &ServiceSelectionRequest) -> Result<Self, AfbError> {
+            let vas_services = if exi.payload.SelectedVASList_isUsed() == 0 {
+                None
+            } else {
+                Some(SelectedServiceListXsd {
+                    SelectedService: exi.get_vas_services(),
+                })
+            };
+            Ok(Self {
+                SelectedEnergyTransferService: exi.get_energy_service(),
+                SelectedVASList: vas_services,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceSelectionRequest, AfbError> {
+            let mut exi = ServiceSelectionRequest::new(&self.SelectedEnergyTransferService);
+            if let Some(vas_list) = self.SelectedVASList {
+                for service in &vas_list.SelectedService {
+                    exi.add_vas_service(service)?;
+                }
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceSelectionRequest, ServiceSelectionReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceSelectionResXsd {
+        ResponseCode: ResponseCode,
+    }
+
+    impl ServiceSelectionResXsd {
+        fn from_exi(exi: &ServiceSelectionResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceSelectionResponse, AfbError> {
+            Ok(ServiceSelectionResponse::new(self.ResponseCode))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceSelectionResponse, ServiceSelectionResXsd);
+}
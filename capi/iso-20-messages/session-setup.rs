@@ -0,0 +1,162 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+#[derive(Clone)]
+pub struct SessionSetupRequest {
+    payload: cglue::iso20_SessionSetupReqType,
+}
+impl SessionSetupRequest {
+    pub fn new(evcc_id: &str) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_SessionSetupReqType>() };
+        payload.EVCCID.charactersLen = str_to_array(
+            evcc_id,
+            &mut payload.EVCCID.characters,
+            cglue::iso20_EVCCID_CHARACTER_SIZE,
+        )?;
+        if payload.EVCCID.charactersLen == 0 {
+            return afb_error!("iso20-session-setup-new", "evcc-id: should not be null");
+        }
+        Ok(Self { payload })
+    }
+
+    pub fn get_id(&self) -> Result<&str, AfbError> {
+        array_to_str(
+            &self.payload.EVCCID.characters,
+            self.payload.EVCCID.charactersLen,
+        )
+    }
+
+    pub fn decode(payload: cglue::iso20_SessionSetupReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.SessionSetupReq = self.payload;
+            exi_body.set_SessionSetupReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct SessionSetupResponse {
+    payload: cglue::iso20_SessionSetupResType,
+}
+
+impl SessionSetupResponse {
+    pub fn new(evse_id: &str, code: ResponseCode) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_SessionSetupResType>() };
+        payload.ResponseCode = code as u32;
+        payload.EVSEID.charactersLen = str_to_array(
+            evse_id,
+            &mut payload.EVSEID.characters,
+            cglue::iso20_EVSEID_CHARACTER_SIZE,
+        )?;
+        Ok(Self { payload })
+    }
+
+    pub fn get_id(&self) -> Result<&str, AfbError> {
+        array_to_str(
+            &self.payload.EVSEID.characters,
+            self.payload.EVSEID.charactersLen,
+        )
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn decode(payload: cglue::iso20_SessionSetupResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.SessionSetupRes = self.payload;
+            exi_body.set_SessionSetupRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for SessionSetupRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("EVCCID", self.get_id());
+    }
+}
+
+impl ExiFields for SessionSetupResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEID", self.get_id());
+    }
+}
+crate::exi_format!(SessionSetupRequest, SessionSetupResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupReqXsd {
+        EVCCID: String,
+    }
+
+    impl SessionSetupReqXsd {
+        fn from_exi(exi: &SessionSetupRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVCCID: exi.get_id()?.to_string(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionSetupRequest, AfbError> {
+            SessionSetupRequest::new(&self.EVCCID)
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupRequest, SessionSetupReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupResXsd {
+        ResponseCode: ResponseCode,
+        EVSEID: String,
+    }
+
+    impl SessionSetupResXsd {
+        fn from_exi(exi: &SessionSetupResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEID: exi.get_id()?.to_string(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionSetupResponse, AfbError> {
+            SessionSetupResponse::new(&self.EVSEID, self.ResponseCode)
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupResponse, SessionSetupResXsd);
+}
@@ -0,0 +1,201 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::*;
+use std::mem;
+
+pub struct SessionStopRequest {
+    payload: cglue::iso20_SessionStopReqType,
+}
+
+impl SessionStopRequest {
+    pub fn new(action: ChargingSessionType) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_SessionStopReqType>() };
+        payload.ChargingSession = action as u32;
+        Self { payload }
+    }
+
+    pub fn get_action(&self) -> Result<ChargingSessionType, AfbError> {
+        ChargingSessionType::from_u32(self.payload.ChargingSession)
+    }
+
+    pub fn set_termination_code(&mut self, code: &str) -> Result<&mut Self, AfbError> {
+        self.payload.EVTerminationCode.charactersLen = str_to_array(
+            code,
+            &mut self.payload.EVTerminationCode.characters,
+            cglue::iso20_EVTerminationCode_CHARACTER_SIZE,
+        )?;
+        self.payload.set_EVTerminationCode_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_termination_code(&self) -> Option<&str> {
+        if self.payload.EVTerminationCode_isUsed() == 0 {
+            None
+        } else {
+            array_to_str(
+                &self.payload.EVTerminationCode.characters,
+                self.payload.EVTerminationCode.charactersLen,
+            )
+            .ok()
+        }
+    }
+
+    pub fn set_termination_explanation(&mut self, text: &str) -> Result<&mut Self, AfbError> {
+        self.payload.EVTerminationExplanation.charactersLen = str_to_array(
+            text,
+            &mut self.payload.EVTerminationExplanation.characters,
+            cglue::iso20_EVTerminationExplanation_CHARACTER_SIZE,
+        )?;
+        self.payload.set_EVTerminationExplanation_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_termination_explanation(&self) -> Option<&str> {
+        if self.payload.EVTerminationExplanation_isUsed() == 0 {
+            None
+        } else {
+            array_to_str(
+                &self.payload.EVTerminationExplanation.characters,
+                self.payload.EVTerminationExplanation.charactersLen,
+            )
+            .ok()
+        }
+    }
+
+    pub fn decode(payload: cglue::iso20_SessionStopReqType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.SessionStopReq = self.payload;
+            exi_body.set_SessionStopReq_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+pub struct SessionStopResponse {
+    payload: cglue::iso20_SessionStopResType,
+}
+
+impl SessionStopResponse {
+    pub fn new(code: ResponseCode) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_SessionStopResType>() };
+        payload.ResponseCode = code as u32;
+        Self { payload }
+    }
+
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
+        ResponseCode::from_u32(self.payload.ResponseCode)
+    }
+
+    pub fn decode(payload: cglue::iso20_SessionStopResType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> Iso20BodyType {
+        let body = unsafe {
+            let mut exi_body = mem::zeroed::<Iso20BodyType>();
+            exi_body.__bindgen_anon_1.SessionStopRes = self.payload;
+            exi_body.set_SessionStopRes_isUsed(1);
+            exi_body
+        };
+        body
+    }
+}
+
+impl ExiFields for SessionStopRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ChargingSession", self.get_action())
+            .option("EVTerminationCode", self.get_termination_code())
+            .option(
+                "EVTerminationExplanation",
+                self.get_termination_explanation(),
+            );
+    }
+}
+
+impl ExiFields for SessionStopResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ResponseCode", self.get_rcode());
+    }
+}
+crate::exi_format!(SessionStopRequest, SessionStopResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopReqXsd {
+        ChargingSession: ChargingSessionType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTerminationCode: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVTerminationExplanation: Option<String>,
+    }
+
+    impl SessionStopReqXsd {
+        fn from_exi(exi: &SessionStopRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ChargingSession: exi.get_action()?,
+                EVTerminationCode: exi.get_termination_code().map(|code| code.to_string()),
+                EVTerminationExplanation: exi
+                    .get_termination_explanation()
+                    .map(|text| text.to_string()),
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionStopRequest, AfbError> {
+            let mut exi = SessionStopRequest::new(self.ChargingSession);
+            if let Some(code) = &self.EVTerminationCode {
+                exi.set_termination_code(code)?;
+            }
+            if let Some(text) = &self.EVTerminationExplanation {
+                exi.set_termination_explanation(text)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopRequest, SessionStopReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopResXsd {
+        ResponseCode: ResponseCode,
+    }
+
+    impl SessionStopResXsd {
+        fn from_exi(exi: &SessionStopResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionStopResponse, AfbError> {
+            Ok(SessionStopResponse::new(self.ResponseCode))
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopResponse, SessionStopResXsd);
+}
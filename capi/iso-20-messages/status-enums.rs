@@ -0,0 +1,569 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::convert::AsRef;
+use std::mem;
+use std::str::FromStr;
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum MessageTagId {
+    SessionSetupReq,
+    SessionSetupRes,
+    AuthorizationSetupReq,
+    AuthorizationSetupRes,
+    AuthorizationReq,
+    AuthorizationRes,
+    ServiceDiscoveryReq,
+    ServiceDiscoveryRes,
+    ServiceDetailReq,
+    ServiceDetailRes,
+    ServiceSelectionReq,
+    ServiceSelectionRes,
+    ScheduleExchangeReq,
+    ScheduleExchangeRes,
+    PowerDeliveryReq,
+    PowerDeliveryRes,
+    SessionStopReq,
+    SessionStopRes,
+    Unsupported,
+}
+crate::exi_enum_try_from!(MessageTagId, u32);
+
+impl MessageTagId {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!(
+                    "message_tagid_from_label",
+                    "deserialize({}):{}",
+                    json,
+                    error
+                )
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+
+    pub fn match_resid(&self) -> Self {
+        let response = match self {
+            MessageTagId::SessionSetupReq => MessageTagId::SessionSetupRes,
+            MessageTagId::AuthorizationSetupReq => MessageTagId::AuthorizationSetupRes,
+            MessageTagId::AuthorizationReq => MessageTagId::AuthorizationRes,
+            MessageTagId::ServiceDiscoveryReq => MessageTagId::ServiceDiscoveryRes,
+            MessageTagId::ServiceDetailReq => MessageTagId::ServiceDetailRes,
+            MessageTagId::ServiceSelectionReq => MessageTagId::ServiceSelectionRes,
+            MessageTagId::ScheduleExchangeReq => MessageTagId::ScheduleExchangeRes,
+            MessageTagId::PowerDeliveryReq => MessageTagId::PowerDeliveryRes,
+            MessageTagId::SessionStopReq => MessageTagId::SessionStopRes,
+            _ => MessageTagId::Unsupported,
+        };
+        response
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum ResponseCode {
+    Ok = cglue::iso20_responseCodeType_iso20_responseCodeType_OK,
+    CertificateExpiresSoon =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_OK_CertificateExpiresSoon,
+    NewSession = cglue::iso20_responseCodeType_iso20_responseCodeType_OK_NewSessionEstablished,
+    OldSessionJoin = cglue::iso20_responseCodeType_iso20_responseCodeType_OK_OldSessionJoined,
+    PowerToleranceConfirmed =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_OK_PowerToleranceConfirmed,
+    WarnAuthorizationSelectionInvalid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_AuthorizationSelectionInvalid,
+    WarnCertificateExpired =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_CertificateExpired,
+    WarnCertificateNotYetValid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_CertificateNotYetValid,
+    WarnCertificateRevoked =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_CertificateRevoked,
+    WarnCertificateValidationError =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_CertificateValidationError,
+    WarnChallengeInvalid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_ChallengeInvalid,
+    WarnEimAuthorizationFailure =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_EIMAuthorizationFailure,
+    WarnEmspUnknown = cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_eMSPUnknown,
+    WarnEvPowerProfileViolation =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_EVPowerProfileViolation,
+    WarnGeneralPncAuthorizationError =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_GeneralPnCAuthorizationError,
+    WarnNoCertificateAvailable =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_NoCertificateAvailable,
+    WarnNoContractMatchingPcidFound =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_NoContractMatchingPCIDFound,
+    WarnPowerToleranceNotConfirmed =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_PowerToleranceNotConfirmed,
+    WarnScheduleRenegotiationFailed =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_ScheduleRenegotiationFailed,
+    WarnStandbyNotAllowed =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_StandbyNotAllowed,
+    WarnWpt = cglue::iso20_responseCodeType_iso20_responseCodeType_WARNING_WPT,
+    Failed = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED,
+    AssociationError = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_AssociationError,
+    ContactorError = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_ContactorError,
+    EvPowerProfileInvalid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_EVPowerProfileInvalid,
+    EvPowerProfileViolation =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_EVPowerProfileViolation,
+    MeteringSignatureNotValid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_MeteringSignatureNotValid,
+    NoEnergyTransferServiceSelected =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_NoEnergyTransferServiceSelected,
+    NoServiceRenegotiationSupported =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_NoServiceRenegotiationSupported,
+    PauseNotAllowed = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_PauseNotAllowed,
+    PowerDeliveryNotApplied =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_PowerDeliveryNotApplied,
+    PowerToleranceNotConfirmed =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_PowerToleranceNotConfirmed,
+    ScheduleRenegotiation =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_ScheduleRenegotiation,
+    ScheduleSelectionInvalid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_ScheduleSelectionInvalid,
+    SequenceError = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_SequenceError,
+    ServiceIDInvalid = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_ServiceIDInvalid,
+    ServiceSelectionInvalid =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_ServiceSelectionInvalid,
+    SignatureError = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_SignatureError,
+    UnknownSession = cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_UnknownSession,
+    WrongChargeParameter =
+        cglue::iso20_responseCodeType_iso20_responseCodeType_FAILED_WrongChargeParameter,
+}
+crate::exi_enum_try_from!(ResponseCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ResponseCode, {
+    Ok => "OK",
+    CertificateExpiresSoon => "OK_CertificateExpiresSoon",
+    NewSession => "OK_NewSessionEstablished",
+    OldSessionJoin => "OK_OldSessionJoined",
+    PowerToleranceConfirmed => "OK_PowerToleranceConfirmed",
+    WarnAuthorizationSelectionInvalid => "WARNING_AuthorizationSelectionInvalid",
+    WarnCertificateExpired => "WARNING_CertificateExpired",
+    WarnCertificateNotYetValid => "WARNING_CertificateNotYetValid",
+    WarnCertificateRevoked => "WARNING_CertificateRevoked",
+    WarnCertificateValidationError => "WARNING_CertificateValidationError",
+    WarnChallengeInvalid => "WARNING_ChallengeInvalid",
+    WarnEimAuthorizationFailure => "WARNING_EIMAuthorizationFailure",
+    WarnEmspUnknown => "WARNING_eMSPUnknown",
+    WarnEvPowerProfileViolation => "WARNING_EVPowerProfileViolation",
+    WarnGeneralPncAuthorizationError => "WARNING_GeneralPnCAuthorizationError",
+    WarnNoCertificateAvailable => "WARNING_NoCertificateAvailable",
+    WarnNoContractMatchingPcidFound => "WARNING_NoContractMatchingPCIDFound",
+    WarnPowerToleranceNotConfirmed => "WARNING_PowerToleranceNotConfirmed",
+    WarnScheduleRenegotiationFailed => "WARNING_ScheduleRenegotiationFailed",
+    WarnStandbyNotAllowed => "WARNING_StandbyNotAllowed",
+    WarnWpt => "WARNING_WPT",
+    Failed => "FAILED",
+    AssociationError => "FAILED_AssociationError",
+    ContactorError => "FAILED_ContactorError",
+    EvPowerProfileInvalid => "FAILED_EVPowerProfileInvalid",
+    EvPowerProfileViolation => "FAILED_EVPowerProfileViolation",
+    MeteringSignatureNotValid => "FAILED_MeteringSignatureNotValid",
+    NoEnergyTransferServiceSelected => "FAILED_NoEnergyTransferServiceSelected",
+    NoServiceRenegotiationSupported => "FAILED_NoServiceRenegotiationSupported",
+    PauseNotAllowed => "FAILED_PauseNotAllowed",
+    PowerDeliveryNotApplied => "FAILED_PowerDeliveryNotApplied",
+    PowerToleranceNotConfirmed => "FAILED_PowerToleranceNotConfirmed",
+    ScheduleRenegotiation => "FAILED_ScheduleRenegotiation",
+    ScheduleSelectionInvalid => "FAILED_ScheduleSelectionInvalid",
+    SequenceError => "FAILED_SequenceError",
+    ServiceIDInvalid => "FAILED_ServiceIDInvalid",
+    ServiceSelectionInvalid => "FAILED_ServiceSelectionInvalid",
+    SignatureError => "FAILED_SignatureError",
+    UnknownSession => "FAILED_UnknownSession",
+    WrongChargeParameter => "FAILED_WrongChargeParameter",
+});
+
+impl ResponseCode {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("response-code-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum EvseProcessing {
+    Finished = cglue::iso20_processingType_iso20_processingType_Finished,
+    Ongoing = cglue::iso20_processingType_iso20_processingType_Ongoing,
+    CustomerInteraction =
+        cglue::iso20_processingType_iso20_processingType_Ongoing_WaitingForCustomerInteraction,
+}
+crate::exi_enum_try_from!(EvseProcessing, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseProcessing, {
+    Finished => "Finished",
+    Ongoing => "Ongoing",
+    CustomerInteraction => "Ongoing_WaitingForCustomerInteraction",
+});
+impl EvseProcessing {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("evse-processing-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum AuthorizationType {
+    Eim = cglue::iso20_authorizationType_iso20_authorizationType_EIM,
+    Pnc = cglue::iso20_authorizationType_iso20_authorizationType_PnC,
+}
+crate::exi_enum_try_from!(AuthorizationType, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(AuthorizationType, {
+    Eim => "EIM",
+    Pnc => "PnC",
+});
+impl AuthorizationType {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("authorization-type-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum ChargeProgress {
+    Start = cglue::iso20_chargeProgressType_iso20_chargeProgressType_Start,
+    Stop = cglue::iso20_chargeProgressType_iso20_chargeProgressType_Stop,
+    Standby = cglue::iso20_chargeProgressType_iso20_chargeProgressType_Standby,
+    ScheduleRenegotiation =
+        cglue::iso20_chargeProgressType_iso20_chargeProgressType_ScheduleRenegotiation,
+}
+crate::exi_enum_try_from!(ChargeProgress, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ChargeProgress, {
+    Start => "Start",
+    Stop => "Stop",
+    Standby => "Standby",
+    ScheduleRenegotiation => "ScheduleRenegotiation",
+});
+impl ChargeProgress {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("charge-progress-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum ChargingSessionType {
+    Pause = cglue::iso20_chargingSessionType_iso20_chargingSessionType_Pause,
+    Terminate = cglue::iso20_chargingSessionType_iso20_chargingSessionType_Terminate,
+    ServiceRenegotiation =
+        cglue::iso20_chargingSessionType_iso20_chargingSessionType_ServiceRenegotiation,
+}
+crate::exi_enum_try_from!(ChargingSessionType, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ChargingSessionType, {
+    Pause => "Pause",
+    Terminate => "Terminate",
+    ServiceRenegotiation => "ServiceRenegotiation",
+});
+impl ChargingSessionType {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("charging-session-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum EvseNotification {
+    Pause = cglue::iso20_evseNotificationType_iso20_evseNotificationType_Pause,
+    ExitStandby = cglue::iso20_evseNotificationType_iso20_evseNotificationType_ExitStandby,
+    Terminate = cglue::iso20_evseNotificationType_iso20_evseNotificationType_Terminate,
+    ScheduleRenegotiation =
+        cglue::iso20_evseNotificationType_iso20_evseNotificationType_ScheduleRenegotiation,
+    ServiceRenegotiation =
+        cglue::iso20_evseNotificationType_iso20_evseNotificationType_ServiceRenegotiation,
+    MeteringConfirmation =
+        cglue::iso20_evseNotificationType_iso20_evseNotificationType_MeteringConfirmation,
+}
+crate::exi_enum_try_from!(EvseNotification, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseNotification, {
+    Pause => "Pause",
+    ExitStandby => "ExitStandby",
+    Terminate => "Terminate",
+    ScheduleRenegotiation => "ScheduleRenegotiation",
+    ServiceRenegotiation => "ServiceRenegotiation",
+    MeteringConfirmation => "MeteringConfirmation",
+});
+impl EvseNotification {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("evse-notification-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+// bidirectional power transfer direction (BPT services only)
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u32)]
+#[allow(dead_code)]
+pub enum ChannelSelection {
+    Charge = cglue::iso20_channelSelectionType_iso20_channelSelectionType_Charge,
+    Discharge = cglue::iso20_channelSelectionType_iso20_channelSelectionType_Discharge,
+}
+crate::exi_enum_try_from!(ChannelSelection, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ChannelSelection, {
+    Charge => "Charge",
+    Discharge => "Discharge",
+});
+impl ChannelSelection {
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("channel-selection-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+// iso15118-20 [Table 204] energy transfer service identifiers
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+#[strum(serialize_all = "snake_case")]
+#[repr(u16)]
+#[allow(dead_code)]
+pub enum ServiceId {
+    Ac = 1,
+    Dc = 2,
+    Wpt = 3,
+    DcAcdp = 4,
+    AcBpt = 5,
+    DcBpt = 6,
+    DcAcdpBpt = 7,
+}
+crate::exi_enum_try_from!(ServiceId, u16);
+impl ServiceId {
+    #[track_caller]
+    pub fn from_u16(code: u16) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+    #[track_caller]
+    pub fn from_label(json: &str) -> Result<Self, AfbError> {
+        match Self::from_str(json) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                return afb_error!("service-id-from-label", "fail deserialize:{} {}", json, error)
+            }
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+
+    pub fn is_bpt(&self) -> bool {
+        match self {
+            ServiceId::AcBpt | ServiceId::DcBpt | ServiceId::DcAcdpBpt => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct EvseStatusType {
+    payload: cglue::iso20_EVSEStatusType,
+}
+
+impl EvseStatusType {
+    pub fn new(notification: EvseNotification, delay: u16) -> Self {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso20_EVSEStatusType>() };
+        payload.NotificationMaxDelay = delay;
+        payload.EVSENotification = notification as u32;
+        Self { payload }
+    }
+
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
+        EvseNotification::from_u32(self.payload.EVSENotification)
+    }
+
+    pub fn get_delay(&self) -> u16 {
+        self.payload.NotificationMaxDelay
+    }
+
+    pub fn decode(payload: cglue::iso20_EVSEStatusType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso20_EVSEStatusType {
+        self.payload
+    }
+}
+
+impl ExiFields for EvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification());
+    }
+}
+crate::exi_format!(EvseStatusType);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct EvseStatusXsd {
+        NotificationMaxDelay: u16,
+        EVSENotification: EvseNotification,
+    }
+
+    impl EvseStatusXsd {
+        fn from_exi(exi: &EvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<EvseStatusType, AfbError> {
+            Ok(EvseStatusType::new(
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(EvseStatusType, EvseStatusXsd);
+}
@@ -27,7 +27,7 @@ pub(self) mod cglue {
     // force reuse of C bitstream from exi-encoder
     use crate::prelude::exi_bitstream_t;
     use crate::prelude::cglue::{gnutls_pubkey_t, gnutls_privkey_t, gnutls_x509_trust_list_t, gnutls_x509_crt_t};
-    include!("_iso2-capi.rs");
+    include!(concat!(env!("OUT_DIR"), "/_iso2-capi.rs"));
 }
 
 
@@ -94,11 +94,14 @@ mod session_stop;
 #[path = "welding-detection.rs"]
 mod welding_detection;
 
+#[path = "header-signature.rs"]
+mod header_signature;
+
 #[path = "body-encoder.rs"]
 mod body_encoder;
 
 pub mod iso2_exi {
-    pub use afbv4::prelude::*;
+    pub use crate::afb::*;
     pub use super::authorization::*;
     pub use super::body_element::*;
     pub use super::body_encoder::*;
@@ -108,6 +111,7 @@ pub mod iso2_exi {
     pub use super::certificate_update::*;
     pub use super::charging_status::*;
     pub use super::current_demand::*;
+    pub use super::header_signature::*;
     pub use super::metering_receipt::*;
     pub use super::param_discovery::*;
     pub use super::param_value::*;
@@ -121,7 +125,11 @@ pub mod iso2_exi {
     pub use super::session_stop::*;
     pub use super::status_enums::*;
     pub use super::welding_detection::*;
+    use std::fmt;
 
+    // serde uses XSD body element names as variant tags
+    #[derive(Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum MessageBody {
         SessionSetupReq(SessionSetupRequest),
         SessionSetupRes(SessionSetupResponse),
@@ -134,11 +142,15 @@ pub mod iso2_exi {
         BodyElement(BodyBaseElement),
         CableCheckReq(CableCheckRequest),
         CableCheckRes(CableCheckResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "CertificateInstallationReq"))]
         CertificateInstallReq(CertificateInstallRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "CertificateInstallationRes"))]
         CertificateInstallRes(CertificateInstallResponse),
         CertificateUpdateReq(CertificateUpdateRequest),
         CertificateUpdateRes(CertificateUpdateResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "ChargeParameterDiscoveryReq"))]
         ParamDiscoveryReq(ParamDiscoveryRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "ChargeParameterDiscoveryRes"))]
         ParamDiscoveryRes(ParamDiscoveryResponse),
         ChargingStatusReq(ChargingStatusRequest),
         ChargingStatusRes(ChargingStatusResponse),
@@ -148,7 +160,9 @@ pub mod iso2_exi {
         MeteringReceiptRes(MeteringReceiptResponse),
         PaymentDetailsReq(PaymentDetailsRequest),
         PaymentDetailsRes(PaymentDetailsResponse),
+        #[cfg_attr(feature = "serde", serde(rename = "PaymentServiceSelectionReq"))]
         PaymentSelectionReq(PaymentSelectionRequest),
+        #[cfg_attr(feature = "serde", serde(rename = "PaymentServiceSelectionRes"))]
         PaymentSelectionRes(PaymentSelectionResponse),
         PowerDeliveryReq(PowerDeliveryRequest),
         PowerDeliveryRes(PowerDeliveryResponse),
@@ -158,9 +172,90 @@ pub mod iso2_exi {
         SessionStopRes(SessionStopResponse),
         WeldingDetectionReq(WeldingDetectionRequest),
         WeldingDetectionRes(WeldingDetectionResponse),
+        #[cfg_attr(feature = "serde", serde(skip))]
         Unsupported,
     }
 
+    // one line session log, XSD body element name followed by its fields
+    impl fmt::Display for MessageBody {
+        fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                MessageBody::SessionSetupReq(body) => write!(format, "SessionSetupReq {}", body),
+                MessageBody::SessionSetupRes(body) => write!(format, "SessionSetupRes {}", body),
+                MessageBody::ServiceDiscoveryReq(body) => {
+                    write!(format, "ServiceDiscoveryReq {}", body)
+                }
+                MessageBody::ServiceDiscoveryRes(body) => {
+                    write!(format, "ServiceDiscoveryRes {}", body)
+                }
+                MessageBody::ServiceDetailReq(body) => write!(format, "ServiceDetailReq {}", body),
+                MessageBody::ServiceDetailRes(body) => write!(format, "ServiceDetailRes {}", body),
+                MessageBody::AuthorizationReq(body) => write!(format, "AuthorizationReq {}", body),
+                MessageBody::AuthorizationRes(body) => write!(format, "AuthorizationRes {}", body),
+                MessageBody::BodyElement(body) => write!(format, "BodyElement {}", body),
+                MessageBody::CableCheckReq(body) => write!(format, "CableCheckReq {}", body),
+                MessageBody::CableCheckRes(body) => write!(format, "CableCheckRes {}", body),
+                MessageBody::CertificateInstallReq(body) => {
+                    write!(format, "CertificateInstallationReq {}", body)
+                }
+                MessageBody::CertificateInstallRes(body) => {
+                    write!(format, "CertificateInstallationRes {}", body)
+                }
+                MessageBody::CertificateUpdateReq(body) => {
+                    write!(format, "CertificateUpdateReq {}", body)
+                }
+                MessageBody::CertificateUpdateRes(body) => {
+                    write!(format, "CertificateUpdateRes {}", body)
+                }
+                MessageBody::ParamDiscoveryReq(body) => {
+                    write!(format, "ChargeParameterDiscoveryReq {}", body)
+                }
+                MessageBody::ParamDiscoveryRes(body) => {
+                    write!(format, "ChargeParameterDiscoveryRes {}", body)
+                }
+                MessageBody::ChargingStatusReq(body) => {
+                    write!(format, "ChargingStatusReq {}", body)
+                }
+                MessageBody::ChargingStatusRes(body) => {
+                    write!(format, "ChargingStatusRes {}", body)
+                }
+                MessageBody::CurrentDemandReq(body) => write!(format, "CurrentDemandReq {}", body),
+                MessageBody::CurrentDemandRes(body) => write!(format, "CurrentDemandRes {}", body),
+                MessageBody::MeteringReceiptReq(body) => {
+                    write!(format, "MeteringReceiptReq {}", body)
+                }
+                MessageBody::MeteringReceiptRes(body) => {
+                    write!(format, "MeteringReceiptRes {}", body)
+                }
+                MessageBody::PaymentDetailsReq(body) => {
+                    write!(format, "PaymentDetailsReq {}", body)
+                }
+                MessageBody::PaymentDetailsRes(body) => {
+                    write!(format, "PaymentDetailsRes {}", body)
+                }
+                MessageBody::PaymentSelectionReq(body) => {
+                    write!(format, "PaymentServiceSelectionReq {}", body)
+                }
+                MessageBody::PaymentSelectionRes(body) => {
+                    write!(format, "PaymentServiceSelectionRes {}", body)
+                }
+                MessageBody::PowerDeliveryReq(body) => write!(format, "PowerDeliveryReq {}", body),
+                MessageBody::PowerDeliveryRes(body) => write!(format, "PowerDeliveryRes {}", body),
+                MessageBody::PreChargeReq(body) => write!(format, "PreChargeReq {}", body),
+                MessageBody::PreChargeRes(body) => write!(format, "PreChargeRes {}", body),
+                MessageBody::SessionStopReq(body) => write!(format, "SessionStopReq {}", body),
+                MessageBody::SessionStopRes(body) => write!(format, "SessionStopRes {}", body),
+                MessageBody::WeldingDetectionReq(body) => {
+                    write!(format, "WeldingDetectionReq {}", body)
+                }
+                MessageBody::WeldingDetectionRes(body) => {
+                    write!(format, "WeldingDetectionRes {}", body)
+                }
+                MessageBody::Unsupported => format.write_str("Unsupported"),
+            }
+        }
+    }
+
     impl MessageBody {
         pub fn get_tagid(&self) -> MessageTagId {
             match self {
@@ -202,6 +297,50 @@ pub mod iso2_exi {
                 MessageBody::Unsupported => MessageTagId::Unsupported,
             }
         }
+        pub fn encode(&self) -> Result<Iso2BodyType, AfbError> {
+            let body = match self {
+                MessageBody::SessionSetupReq(body) => body.encode(),
+                MessageBody::SessionSetupRes(body) => body.encode(),
+                MessageBody::ServiceDiscoveryReq(body) => body.encode(),
+                MessageBody::ServiceDiscoveryRes(body) => body.encode(),
+                MessageBody::ServiceDetailReq(body) => body.encode(),
+                MessageBody::ServiceDetailRes(body) => body.encode(),
+                MessageBody::AuthorizationReq(body) => body.encode(),
+                MessageBody::AuthorizationRes(body) => body.encode(),
+                MessageBody::BodyElement(body) => body.encode(),
+                MessageBody::CableCheckReq(body) => body.encode(),
+                MessageBody::CableCheckRes(body) => body.encode(),
+                MessageBody::CertificateInstallReq(body) => body.encode(),
+                MessageBody::CertificateInstallRes(body) => body.encode(),
+                MessageBody::CertificateUpdateReq(body) => body.encode(),
+                MessageBody::CertificateUpdateRes(body) => body.encode(),
+                MessageBody::ParamDiscoveryReq(body) => body.encode(),
+                MessageBody::ParamDiscoveryRes(body) => body.encode(),
+                MessageBody::ChargingStatusReq(body) => body.encode(),
+                MessageBody::ChargingStatusRes(body) => body.encode(),
+                MessageBody::CurrentDemandReq(body) => body.encode(),
+                MessageBody::CurrentDemandRes(body) => body.encode(),
+                MessageBody::MeteringReceiptReq(body) => body.encode(),
+                MessageBody::MeteringReceiptRes(body) => body.encode(),
+                MessageBody::PaymentDetailsReq(body) => body.encode(),
+                MessageBody::PaymentDetailsRes(body) => body.encode(),
+                MessageBody::PaymentSelectionReq(body) => body.encode(),
+                MessageBody::PaymentSelectionRes(body) => body.encode(),
+                MessageBody::PowerDeliveryReq(body) => body.encode(),
+                MessageBody::PowerDeliveryRes(body) => body.encode(),
+                MessageBody::PreChargeReq(body) => body.encode(),
+                MessageBody::PreChargeRes(body) => body.encode(),
+                MessageBody::SessionStopReq(body) => body.encode(),
+                MessageBody::SessionStopRes(body) => body.encode(),
+                MessageBody::WeldingDetectionReq(body) => body.encode(),
+                MessageBody::WeldingDetectionRes(body) => body.encode(),
+                MessageBody::Unsupported => {
+                    return afb_error!("iso2-encode-exi", "unknown/unsupported message")
+                }
+            };
+            Ok(body)
+        }
+
         pub fn decode(payload: &super::cglue::iso2_BodyType) -> Result<Self, AfbError> {
             // SessionSetup
             let body = if payload.SessionSetupReq_isUsed() == 1 {
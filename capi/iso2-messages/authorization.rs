@@ -124,11 +124,105 @@ impl AuthorizationResponse {
         body
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
-    pub fn get_processing(&self) -> EvseProcessing {
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
         EvseProcessing::from_u32(self.payload.EVSEProcessing)
     }
 }
+
+impl ExiFields for AuthorizationRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .option("GenChallenge", self.get_challenge().map(ExiHexa));
+    }
+}
+
+impl ExiFields for AuthorizationResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing());
+    }
+}
+crate::exi_format!(AuthorizationRequest, AuthorizationResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        GenChallenge: Option<ExiBytes>,
+    }
+
+    impl AuthorizationReqXsd {
+        fn from_exi(exi: &AuthorizationRequest) -> Result<Self, AfbError> {
+            let id = if exi.payload.Id_isUsed() == 0 {
+                None
+            } else {
+                Some(
+                    array_to_str(&exi.payload.Id.characters, exi.payload.Id.charactersLen)?
+                        .to_string(),
+                )
+            };
+            Ok(Self {
+                Id: id,
+                GenChallenge: exi.get_challenge().map(ExiBytes::from),
+            })
+        }
+
+        fn into_exi(self) -> Result<AuthorizationRequest, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_AuthorizationReqType>() };
+            if let Some(id) = self.Id {
+                payload.Id.charactersLen = str_to_array(
+                    &id,
+                    &mut payload.Id.characters,
+                    cglue::iso2_Id_CHARACTER_SIZE,
+                )?;
+                payload.set_Id_isUsed(1);
+            }
+            if let Some(challenge) = self.GenChallenge {
+                payload.GenChallenge.bytesLen = bytes_to_array(
+                    challenge.as_slice(),
+                    &mut payload.GenChallenge.bytes,
+                    cglue::iso2_genChallengeType_BYTES_SIZE,
+                )?;
+                payload.set_GenChallenge_isUsed(1);
+            }
+            Ok(AuthorizationRequest::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationRequest, AuthorizationReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AuthorizationResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+    }
+
+    impl AuthorizationResXsd {
+        fn from_exi(exi: &AuthorizationResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<AuthorizationResponse, AfbError> {
+            Ok(AuthorizationResponse::new(
+                self.ResponseCode,
+                self.EVSEProcessing,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(AuthorizationResponse, AuthorizationResXsd);
+}
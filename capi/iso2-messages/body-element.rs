@@ -42,4 +42,30 @@ impl BodyBaseElement {
         };
         body
     }
-}
\ No newline at end of file
+}
+// abstract BodyBaseType has no content
+impl ExiFields for BodyBaseElement {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+crate::exi_format!(BodyBaseElement);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    // abstract BodyBaseType has no content
+    #[derive(Serialize, Deserialize)]
+    struct BodyElementXsd {}
+
+    impl BodyElementXsd {
+        fn from_exi(_exi: &BodyBaseElement) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<BodyBaseElement, AfbError> {
+            Ok(BodyBaseElement::new())
+        }
+    }
+    crate::exi_serde_mirror!(BodyBaseElement, BodyElementXsd);
+}
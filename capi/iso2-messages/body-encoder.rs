@@ -64,6 +64,20 @@ impl ExiMessageHeader {
         }
     }
 
+    pub fn set_signature(&mut self, signature: &SignatureType) -> &mut Self {
+        self.payload.Signature = signature.encode();
+        self.payload.set_Signature_isUsed(1);
+        self
+    }
+
+    pub fn get_signature(&self) -> Option<SignatureType> {
+        if self.payload.Signature_isUsed() == 0 {
+            None
+        } else {
+            Some(SignatureType::decode(self.payload.Signature))
+        }
+    }
+
     pub fn get_session_id(&self) -> &[u8] {
         let session = array_to_bytes(
             &self.payload.SessionID.bytes,
@@ -72,6 +86,15 @@ impl ExiMessageHeader {
         session
     }
 
+    pub fn set_session_id(&mut self, session_id: &[u8]) -> Result<&mut Self, AfbError> {
+        self.payload.SessionID.bytesLen = bytes_to_array(
+            session_id,
+            &mut self.payload.SessionID.bytes,
+            cglue::iso2_sessionIDType_BYTES_SIZE,
+        )?;
+        Ok(self)
+    }
+
     pub fn decode(payload: cglue::iso2_MessageHeaderType) -> Self {
         Self {
             payload: payload.clone(),
@@ -97,16 +120,24 @@ impl ExiMessageDoc {
 
     #[track_caller]
     pub fn decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, AfbError> {
+        Ok(Self::try_decode_from_stream(locked)?)
+    }
+
+    /// same as decode_from_stream with typed error (exi code & stream position)
+    pub fn try_decode_from_stream(locked: &mut RawStream) -> Result<ExiMessageDoc, Iso15118Error> {
         let payload = unsafe {
             let mut buffer = mem::MaybeUninit::<cglue::iso2_exiDocument>::uninit();
-            let status = cglue::decode_iso2_exiDocument(locked.stream, buffer.as_mut_ptr());
-            let exi_raw = buffer.assume_init();
+            let status = cglue::decode_iso2_exiDocument(locked.as_mut_ptr(), buffer.as_mut_ptr());
             if status < 0 {
-                return afb_error!(
-                    "iso2-exi-decode",
-                    "fail to decode iso-2 (ExiDocument) from stream"
-                );
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::Iso2,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
             }
+            let exi_raw = buffer.assume_init();
             locked.reset();
             exi_raw
         };
@@ -115,27 +146,26 @@ impl ExiMessageDoc {
 
     #[track_caller]
     pub fn encode_to_stream(&self, locked: &mut RawStream) -> Result<(), AfbError> {
+        Ok(self.try_encode_to_stream(locked)?)
+    }
+
+    /// same as encode_to_stream with typed error (exi code, stream position & message tag)
+    pub fn try_encode_to_stream(&self, locked: &mut RawStream) -> Result<(), Iso15118Error> {
         locked.reset(); // cleanup stream before encoding
 
         // reserve space for v2g header
-        match unsafe { locked.stream.as_mut() } {
-            Some(data) => {
-                data.byte_pos = SDP_V2G_HEADER_LEN as usize;
-            }
-            None => {
-                return afb_error!(
-                    "encode_stream-header",
-                    "fail to get locked.stream (invalid stream)"
-                )
-            }
-        };
+        locked.set_window(EXI_MAX_DOCUMENT_SIZE, SDP_V2G_HEADER_LEN as usize);
 
-        let status = unsafe { cglue::encode_iso2_exiDocument(locked.stream, &self.payload) };
+        let status = unsafe { cglue::encode_iso2_exiDocument(locked.as_mut_ptr(), &self.payload) };
         if status < 0 {
-            return afb_error!(
-                "exi-iso-encode",
-                "fail to encode encode_iso2_exiDocument to exi"
-            );
+            let tagid = self.get_body().ok().map(|body| body.get_tagid());
+            return Err(Iso15118Error::exi(
+                ExiProtocol::Iso2,
+                ExiOperation::Encode,
+                status,
+                locked,
+                tagid.as_ref().map(|tagid| tagid.to_label()),
+            ));
         }
 
         // retrieve document encoded size from stream and insert header
@@ -165,21 +195,22 @@ impl ExiMessageDoc {
     }
 }
 
-impl PkiSignature for ExiMessageDoc {
-    fn pki_sign_check(
+impl ExiMessageDoc {
+    /// same as PkiSignature::pki_sign_check with typed error (PkiErrorStatus)
+    pub fn try_pki_sign_check(
         &self,
         tagid: iso2_exi::MessageTagId,
         challenge: &[u8],
         pub_key: &PkiPubKey,
-    ) -> Result<(), AfbError> {
+    ) -> Result<(), Iso15118Error> {
         use iso2_exi::*;
 
         if self.payload.V2G_Message.Header.Signature_isUsed() == 0 {
-            return afb_error!(
-                "iso2-pki-sign-check",
-                "error: tagid:{} no signature set in exi header",
-                tagid.to_label()
-            );
+            return Err(Iso15118Error::pki(
+                ExiProtocol::Iso2,
+                PkiErrorStatus::NO_SIGNATURE as u32,
+                tagid.to_label(),
+            ));
         }
 
         let status = match tagid {
@@ -194,30 +225,30 @@ impl PkiSignature for ExiMessageDoc {
                 cglue::iso2_sign_check_metering_receipt_req(&self.payload, pub_key.get_payload())
             },
             others => {
-                return afb_error!(
-                    "exi-message-check-signature",
-                    "fail iso2-exi document tagid:{} does not implement signature",
-                    others.to_label()
-                )
+                return Err(Iso15118Error::unsupported(
+                    ExiProtocol::Iso2,
+                    others.to_label(),
+                ))
             }
         };
 
         if status != 0 {
-            return afb_error!(
-                "iso2-pki-sign-check",
-                "error:{}",
-                PkiErrorStatus::from_u32(status).to_label()
-            );
+            return Err(Iso15118Error::pki(
+                ExiProtocol::Iso2,
+                status,
+                tagid.to_label(),
+            ));
         }
 
         Ok(())
     }
 
-    fn pki_sign_sign(
+    /// same as PkiSignature::pki_sign_sign with typed error (PkiErrorStatus)
+    pub fn try_pki_sign_sign(
         &mut self,
         tagid: iso2_exi::MessageTagId,
         priv_key: &PkiPrivKey,
-    ) -> Result<(), AfbError> {
+    ) -> Result<(), Iso15118Error> {
         use iso2_exi::*;
 
         let status = match tagid {
@@ -231,22 +262,110 @@ impl PkiSignature for ExiMessageDoc {
                 )
             },
             others => {
-                return afb_error!(
-                    "exi-message-check-signature",
-                    "fail iso2-exi document tagid:{} does not implement signature",
-                    others.to_label()
-                )
+                return Err(Iso15118Error::unsupported(
+                    ExiProtocol::Iso2,
+                    others.to_label(),
+                ))
             }
         };
 
         if status != 0 {
-            return afb_error!(
-                "iso2-pki-sign-sign",
-                "error:{}",
-                PkiErrorStatus::from_u32(status).to_label()
-            );
+            return Err(Iso15118Error::pki(
+                ExiProtocol::Iso2,
+                status,
+                tagid.to_label(),
+            ));
         }
 
         Ok(())
     }
 }
+
+impl PkiSignature for ExiMessageDoc {
+    fn pki_sign_check(
+        &self,
+        tagid: iso2_exi::MessageTagId,
+        challenge: &[u8],
+        pub_key: &PkiPubKey,
+    ) -> Result<(), AfbError> {
+        Ok(self.try_pki_sign_check(tagid, challenge, pub_key)?)
+    }
+
+    fn pki_sign_sign(
+        &mut self,
+        tagid: iso2_exi::MessageTagId,
+        priv_key: &PkiPrivKey,
+    ) -> Result<(), AfbError> {
+        Ok(self.try_pki_sign_sign(tagid, priv_key)?)
+    }
+}
+
+impl ExiFields for ExiMessageHeader {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("SessionID", ExiHexa(self.get_session_id()))
+            .option("Signature", self.get_signature());
+    }
+}
+
+impl ExiFields for ExiMessageDoc {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("Header", self.get_header())
+            .result("Body", self.get_body());
+    }
+}
+crate::exi_format!(ExiMessageHeader, ExiMessageDoc);
+
+// note: header Notification content is not modeled, only SessionID & Signature are serialized
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct MessageHeaderXsd {
+        SessionID: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Signature: Option<SignatureType>,
+    }
+
+    impl MessageHeaderXsd {
+        fn from_exi(exi: &ExiMessageHeader) -> Result<Self, AfbError> {
+            Ok(Self {
+                SessionID: ExiBytes::from(exi.get_session_id()),
+                Signature: exi.get_signature(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageHeader, AfbError> {
+            let mut exi = ExiMessageHeader::new(self.SessionID.as_slice())?;
+            if let Some(signature) = &self.Signature {
+                exi.set_signature(signature);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageHeader, MessageHeaderXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct V2GMessageXsd {
+        Header: ExiMessageHeader,
+        Body: MessageBody,
+    }
+
+    impl V2GMessageXsd {
+        fn from_exi(exi: &ExiMessageDoc) -> Result<Self, AfbError> {
+            Ok(Self {
+                Header: exi.get_header(),
+                Body: exi.get_body()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ExiMessageDoc, AfbError> {
+            Ok(ExiMessageDoc::new(&self.Header, &self.Body.encode()?))
+        }
+    }
+    crate::exi_serde_mirror!(ExiMessageDoc, V2GMessageXsd);
+}
@@ -285,3 +285,225 @@ impl EmaidType {
         self.payload
     }
 }
+
+impl ExiFields for IssuerSerialType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("X509IssuerName", self.get_issuer())
+            .field("X509SerialNumber", self.get_serial());
+    }
+}
+
+impl ExiFields for CertificateRootList {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("RootCertificateID", self.get_certs().map(ExiList));
+    }
+}
+
+impl ExiFields for CertificateChainType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .field("Certificate", ExiHexa(self.get_cert()));
+        if self.payload.SubCertificates_isUsed() != 0 {
+            let subcerts = self.get_subcerts().into_iter().map(ExiHexa).collect();
+            fields.field("SubCertificates", ExiList(subcerts));
+        }
+    }
+}
+
+impl ExiFields for PrivateKeyType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .field("CONTENT", ExiHexa(self.get_data()));
+    }
+}
+
+impl ExiFields for DhPublicKeyType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .field("CONTENT", ExiHexa(self.get_data()));
+    }
+}
+
+impl ExiFields for EmaidType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .result("CONTENT", self.get_data());
+    }
+}
+crate::exi_format!(
+    IssuerSerialType,
+    CertificateRootList,
+    CertificateChainType,
+    PrivateKeyType,
+    DhPublicKeyType,
+    EmaidType
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct X509IssuerSerialXsd {
+        X509IssuerName: String,
+        X509SerialNumber: i32,
+    }
+
+    impl X509IssuerSerialXsd {
+        fn from_exi(exi: &IssuerSerialType) -> Result<Self, AfbError> {
+            Ok(Self {
+                X509IssuerName: exi.get_issuer()?.to_string(),
+                X509SerialNumber: exi.get_serial(),
+            })
+        }
+
+        fn into_exi(self) -> Result<IssuerSerialType, AfbError> {
+            IssuerSerialType::new(&self.X509IssuerName, self.X509SerialNumber)
+        }
+    }
+    crate::exi_serde_mirror!(IssuerSerialType, X509IssuerSerialXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ListOfRootCertificateIDsXsd {
+        RootCertificateID: Vec<IssuerSerialType>,
+    }
+
+    impl ListOfRootCertificateIDsXsd {
+        fn from_exi(exi: &CertificateRootList) -> Result<Self, AfbError> {
+            Ok(Self {
+                RootCertificateID: exi.get_certs()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateRootList, AfbError> {
+            let payload = unsafe { mem::zeroed::<cglue::iso2_ListOfRootCertificateIDsType>() };
+            let mut exi = CertificateRootList::decode(payload);
+            for cert in &self.RootCertificateID {
+                exi.add_cert(cert)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateRootList, ListOfRootCertificateIDsXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SubCertificatesXsd {
+        Certificate: Vec<ExiBytes>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateChainXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        Certificate: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SubCertificates: Option<SubCertificatesXsd>,
+    }
+
+    impl CertificateChainXsd {
+        fn from_exi(exi: &CertificateChainType) -> Result<Self, AfbError> {
+            let id = if exi.payload.Id_isUsed() == 0 {
+                None
+            } else {
+                Some(
+                    array_to_str(&exi.payload.Id.characters, exi.payload.Id.charactersLen)?
+                        .to_string(),
+                )
+            };
+            let subcerts = if exi.payload.SubCertificates_isUsed() == 0 {
+                None
+            } else {
+                Some(SubCertificatesXsd {
+                    Certificate: exi.get_subcerts().into_iter().map(ExiBytes::from).collect(),
+                })
+            };
+            Ok(Self {
+                Id: id,
+                Certificate: ExiBytes::from(exi.get_cert()),
+                SubCertificates: subcerts,
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateChainType, AfbError> {
+            let mut exi = CertificateChainType::new(self.Certificate.as_slice())?;
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            if let Some(subcerts) = &self.SubCertificates {
+                for cert in &subcerts.Certificate {
+                    exi.add_subcert(cert.as_slice())?;
+                }
+                exi.payload.set_SubCertificates_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateChainType, CertificateChainXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PrivateKeyXsd {
+        Id: String,
+        CONTENT: ExiBytes,
+    }
+
+    impl PrivateKeyXsd {
+        fn from_exi(exi: &PrivateKeyType) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                CONTENT: ExiBytes::from(exi.get_data()),
+            })
+        }
+
+        fn into_exi(self) -> Result<PrivateKeyType, AfbError> {
+            PrivateKeyType::new(&self.Id, self.CONTENT.as_slice())
+        }
+    }
+    crate::exi_serde_mirror!(PrivateKeyType, PrivateKeyXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DhPublicKeyXsd {
+        Id: String,
+        CONTENT: ExiBytes,
+    }
+
+    impl DhPublicKeyXsd {
+        fn from_exi(exi: &DhPublicKeyType) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                CONTENT: ExiBytes::from(exi.get_data()),
+            })
+        }
+
+        fn into_exi(self) -> Result<DhPublicKeyType, AfbError> {
+            DhPublicKeyType::new(&self.Id, self.CONTENT.as_slice())
+        }
+    }
+    crate::exi_serde_mirror!(DhPublicKeyType, DhPublicKeyXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct EmaidXsd {
+        Id: String,
+        CONTENT: String,
+    }
+
+    impl EmaidXsd {
+        fn from_exi(exi: &EmaidType) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                CONTENT: exi.get_data()?.to_string(),
+            })
+        }
+
+        fn into_exi(self) -> Result<EmaidType, AfbError> {
+            EmaidType::new(&self.Id, &self.CONTENT)
+        }
+    }
+    crate::exi_serde_mirror!(EmaidType, EmaidXsd);
+}
@@ -101,7 +101,7 @@ impl CertificateInstallResponse {
     }
 
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -139,3 +139,99 @@ impl CertificateInstallResponse {
         body
     }
 }
+
+impl ExiFields for CertificateInstallRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .field("OEMProvisioningCert", ExiHexa(self.get_provisioning()))
+            .field("ListOfRootCertificateIDs", self.get_certs_list());
+    }
+}
+
+impl ExiFields for CertificateInstallResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field(
+                "SAProvisioningCertificateChain",
+                self.get_provisioning_chain(),
+            )
+            .field("ContractSignatureCertChain", self.get_contract_chain())
+            .field(
+                "ContractSignatureEncryptedPrivateKey",
+                self.get_private_key(),
+            )
+            .field("DHpublickey", self.get_public_key())
+            .field("eMAID", self.get_emaid());
+    }
+}
+crate::exi_format!(CertificateInstallRequest, CertificateInstallResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateInstallationReqXsd {
+        Id: String,
+        OEMProvisioningCert: ExiBytes,
+        ListOfRootCertificateIDs: CertificateRootList,
+    }
+
+    impl CertificateInstallationReqXsd {
+        fn from_exi(exi: &CertificateInstallRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                OEMProvisioningCert: ExiBytes::from(exi.get_provisioning()),
+                ListOfRootCertificateIDs: exi.get_certs_list(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateInstallRequest, AfbError> {
+            CertificateInstallRequest::new(
+                &self.Id,
+                self.OEMProvisioningCert.as_slice(),
+                &self.ListOfRootCertificateIDs,
+            )
+        }
+    }
+    crate::exi_serde_mirror!(CertificateInstallRequest, CertificateInstallationReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateInstallationResXsd {
+        ResponseCode: ResponseCode,
+        SAProvisioningCertificateChain: CertificateChainType,
+        ContractSignatureCertChain: CertificateChainType,
+        ContractSignatureEncryptedPrivateKey: PrivateKeyType,
+        DHpublickey: DhPublicKeyType,
+        eMAID: EmaidType,
+    }
+
+    impl CertificateInstallationResXsd {
+        fn from_exi(exi: &CertificateInstallResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                SAProvisioningCertificateChain: exi.get_provisioning_chain(),
+                ContractSignatureCertChain: exi.get_contract_chain(),
+                ContractSignatureEncryptedPrivateKey: exi.get_private_key(),
+                DHpublickey: exi.get_public_key(),
+                eMAID: exi.get_emaid(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateInstallResponse, AfbError> {
+            Ok(CertificateInstallResponse::new(
+                self.ResponseCode,
+                &self.ContractSignatureCertChain,
+                &self.SAProvisioningCertificateChain,
+                &self.ContractSignatureEncryptedPrivateKey,
+                &self.DHpublickey,
+                &self.eMAID,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(CertificateInstallResponse, CertificateInstallationResXsd);
+}
@@ -108,7 +108,7 @@ impl CertificateUpdateResponse {
     }
 
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -160,3 +160,111 @@ impl CertificateUpdateResponse {
         body
     }
 }
+
+impl ExiFields for CertificateUpdateRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("Id", self.get_id())
+            .field("ContractSignatureCertChain", self.get_contract_chain())
+            .result("eMAID", self.get_emaid())
+            .field("ListOfRootCertificateIDs", self.get_root_certs());
+    }
+}
+
+impl ExiFields for CertificateUpdateResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field(
+                "SAProvisioningCertificateChain",
+                self.get_provisioning_chain(),
+            )
+            .field("ContractSignatureCertChain", self.get_contract_chain())
+            .field(
+                "ContractSignatureEncryptedPrivateKey",
+                self.get_private_key(),
+            )
+            .field("DHpublickey", self.get_public_key())
+            .field("eMAID", self.get_emaid())
+            .option("RetryCounter", self.get_rcount());
+    }
+}
+crate::exi_format!(CertificateUpdateRequest, CertificateUpdateResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateUpdateReqXsd {
+        Id: String,
+        ContractSignatureCertChain: CertificateChainType,
+        eMAID: String,
+        ListOfRootCertificateIDs: CertificateRootList,
+    }
+
+    impl CertificateUpdateReqXsd {
+        fn from_exi(exi: &CertificateUpdateRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id()?.to_string(),
+                ContractSignatureCertChain: exi.get_contract_chain(),
+                eMAID: exi.get_emaid()?.to_string(),
+                ListOfRootCertificateIDs: exi.get_root_certs(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateUpdateRequest, AfbError> {
+            CertificateUpdateRequest::new(
+                &self.Id,
+                &self.ContractSignatureCertChain,
+                &self.eMAID,
+                &self.ListOfRootCertificateIDs,
+            )
+        }
+    }
+    crate::exi_serde_mirror!(CertificateUpdateRequest, CertificateUpdateReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CertificateUpdateResXsd {
+        ResponseCode: ResponseCode,
+        SAProvisioningCertificateChain: CertificateChainType,
+        ContractSignatureCertChain: CertificateChainType,
+        ContractSignatureEncryptedPrivateKey: PrivateKeyType,
+        DHpublickey: DhPublicKeyType,
+        eMAID: EmaidType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RetryCounter: Option<i16>,
+    }
+
+    impl CertificateUpdateResXsd {
+        fn from_exi(exi: &CertificateUpdateResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                SAProvisioningCertificateChain: exi.get_provisioning_chain(),
+                ContractSignatureCertChain: exi.get_contract_chain(),
+                ContractSignatureEncryptedPrivateKey: exi.get_private_key(),
+                DHpublickey: exi.get_public_key(),
+                eMAID: exi.get_emaid(),
+                RetryCounter: exi.get_rcount(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CertificateUpdateResponse, AfbError> {
+            let mut exi = CertificateUpdateResponse::new(
+                self.ResponseCode,
+                &self.ContractSignatureCertChain,
+                &self.SAProvisioningCertificateChain,
+                &self.ContractSignatureEncryptedPrivateKey,
+                &self.DHpublickey,
+                &self.eMAID,
+            );
+            if let Some(value) = self.RetryCounter {
+                exi.set_rcount(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CertificateUpdateResponse, CertificateUpdateResXsd);
+}
@@ -79,7 +79,7 @@ impl ChargingStatusResponse {
             self.payload.SAScheduleTupleID
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -143,3 +143,90 @@ impl ChargingStatusResponse {
         body
     }
 }
+
+impl ExiFields for ChargingStatusRequest {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for ChargingStatusResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEID", self.get_evse_id())
+            .field("SAScheduleTupleID", self.get_tuple_id())
+            .option("EVSEMaxCurrent", self.get_max_current())
+            .option("MeterInfo", self.get_meter_info())
+            .option("ReceiptRequired", self.get_receipt_require())
+            .field("AC_EVSEStatus", self.get_ac_evse_status());
+    }
+}
+crate::exi_format!(ChargingStatusRequest, ChargingStatusResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargingStatusReqXsd {}
+
+    impl ChargingStatusReqXsd {
+        fn from_exi(_exi: &ChargingStatusRequest) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<ChargingStatusRequest, AfbError> {
+            Ok(ChargingStatusRequest::new())
+        }
+    }
+    crate::exi_serde_mirror!(ChargingStatusRequest, ChargingStatusReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargingStatusResXsd {
+        ResponseCode: ResponseCode,
+        EVSEID: String,
+        SAScheduleTupleID: u8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaxCurrent: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MeterInfo: Option<MeterInfo>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ReceiptRequired: Option<bool>,
+        AC_EVSEStatus: AcEvseStatusType,
+    }
+
+    impl ChargingStatusResXsd {
+        fn from_exi(exi: &ChargingStatusResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEID: exi.get_evse_id()?.to_string(),
+                SAScheduleTupleID: exi.get_tuple_id(),
+                EVSEMaxCurrent: exi.get_max_current(),
+                MeterInfo: exi.get_meter_info(),
+                ReceiptRequired: exi.get_receipt_require(),
+                AC_EVSEStatus: exi.get_ac_evse_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ChargingStatusResponse, AfbError> {
+            let mut exi = ChargingStatusResponse::new(
+                self.ResponseCode,
+                &self.EVSEID,
+                self.SAScheduleTupleID,
+                &self.AC_EVSEStatus,
+            )?;
+            if let Some(value) = &self.EVSEMaxCurrent {
+                exi.set_max_current(value);
+            }
+            if let Some(value) = &self.MeterInfo {
+                exi.set_meter_info(value);
+            }
+            if let Some(value) = self.ReceiptRequired {
+                exi.set_receipt_require(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ChargingStatusResponse, ChargingStatusResXsd);
+}
@@ -62,11 +62,11 @@ impl CurrentDemandRequest {
         &mut self,
         voltage_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if voltage_limit.get_unit() != PhysicalUnit::Volt {
+        if voltage_limit.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "current-demand-req",
                 "expect: PhysicalUnit::Volt get:{}",
-                voltage_limit.get_unit()
+                voltage_limit.get_unit()?
             );
         }
         self.payload.EVMaximumVoltageLimit = voltage_limit.encode();
@@ -86,11 +86,11 @@ impl CurrentDemandRequest {
         &mut self,
         current_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if current_limit.get_unit() != PhysicalUnit::Ampere {
+        if current_limit.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "current-demand-req",
                 "expect: PhysicalUnit::Ampere get:{}",
-                current_limit.get_unit()
+                current_limit.get_unit()?
             );
         }
         self.payload.EVMaximumCurrentLimit = current_limit.encode();
@@ -107,11 +107,11 @@ impl CurrentDemandRequest {
     }
 
     pub fn set_power_limit(&mut self, power_limit: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if power_limit.get_unit() != PhysicalUnit::Watt {
+        if power_limit.get_unit()? != PhysicalUnit::Watt {
             return afb_error!(
                 "current-demand-req",
                 "expect: PhysicalUnit::Watt get:{}",
-                power_limit.get_unit()
+                power_limit.get_unit()?
             );
         }
         self.payload.EVMaximumPowerLimit = power_limit.encode();
@@ -131,7 +131,7 @@ impl CurrentDemandRequest {
         &mut self,
         remaining_time: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        let unit = remaining_time.get_unit();
+        let unit = remaining_time.get_unit()?;
         if unit != PhysicalUnit::Hour
             && unit != PhysicalUnit::Minute
             && unit != PhysicalUnit::Second
@@ -139,7 +139,7 @@ impl CurrentDemandRequest {
             return afb_error!(
                 "current-demand-req",
                 "expect: PhysicalUnit::(Hour|Minute|Second) got:{}",
-                remaining_time.get_unit()
+                remaining_time.get_unit()?
             );
         }
         self.payload.RemainingTimeToFullSoC = remaining_time.encode();
@@ -159,7 +159,7 @@ impl CurrentDemandRequest {
         &mut self,
         remaining_time: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        let unit = remaining_time.get_unit();
+        let unit = remaining_time.get_unit()?;
         if unit != PhysicalUnit::Hour
             && unit != PhysicalUnit::Minute
             && unit != PhysicalUnit::Second
@@ -167,7 +167,7 @@ impl CurrentDemandRequest {
             return afb_error!(
                 "current-demand-req",
                 "expect: PhysicalUnit::Percent get:{}",
-                remaining_time.get_unit()
+                remaining_time.get_unit()?
             );
         }
 
@@ -243,19 +243,19 @@ impl CurrentDemandResponse {
             cglue::iso2_EVSEID_CHARACTER_SIZE,
         )?;
 
-        if current.get_unit() != PhysicalUnit::Ampere {
+        if current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "current-demand-res",
                 "expect: PhysicalUnit::Ampere get:{}",
-                current.get_unit()
+                current.get_unit()?
             );
         }
 
-        if voltage.get_unit() != PhysicalUnit::Volt {
+        if voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "current-demand-res",
                 "expect: PhysicalUnit::Volt get:{}",
-                voltage.get_unit()
+                voltage.get_unit()?
             );
         }
 
@@ -270,7 +270,7 @@ impl CurrentDemandResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -322,11 +322,11 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_voltage_limit(&mut self, voltage: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if voltage.get_unit() != PhysicalUnit::Volt {
+        if voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "current-demand-res",
                 "expect: PhysicalUnit::Volt get:{}",
-                voltage.get_unit()
+                voltage.get_unit()?
             );
         }
 
@@ -344,11 +344,11 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_current_limit(&mut self, current: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if current.get_unit() != PhysicalUnit::Ampere {
+        if current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "current-demand-res",
                 "expect: PhysicalUnit::Volt get:{}",
-                current.get_unit()
+                current.get_unit()?
             );
         }
         self.payload.EVSEMaximumCurrentLimit = current.encode();
@@ -365,11 +365,11 @@ impl CurrentDemandResponse {
     }
 
     pub fn set_power_limit(&mut self, power: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if power.get_unit() != PhysicalUnit::Watt {
+        if power.get_unit()? != PhysicalUnit::Watt {
             return afb_error!(
                 "current-demand-res",
                 "expect: PhysicalUnit::Volt get:{}",
-                power.get_unit()
+                power.get_unit()?
             );
         }
         self.payload.EVSEMaximumPowerLimit = power.encode();
@@ -430,3 +430,203 @@ impl CurrentDemandResponse {
         body
     }
 }
+
+impl ExiFields for CurrentDemandRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVStatus", self.get_status())
+            .field("EVTargetCurrent", self.get_current_target())
+            .option("EVMaximumVoltageLimit", self.get_voltage_limit())
+            .option("EVMaximumCurrentLimit", self.get_current_limit())
+            .option("EVMaximumPowerLimit", self.get_power_limit())
+            .option("BulkChargingComplete", self.get_bulk_complete())
+            .field("ChargingComplete", self.get_charging_complete())
+            .option("RemainingTimeToFullSoC", self.get_time_to_full_sock())
+            .option("RemainingTimeToBulkSoC", self.get_time_to_bulk_sock())
+            .field("EVTargetVoltage", self.get_voltage_target());
+    }
+}
+
+impl ExiFields for CurrentDemandResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEPresentVoltage", self.get_voltage_present())
+            .field("EVSEPresentCurrent", self.get_current_present())
+            .field("EVSECurrentLimitAchieved", self.get_current_limit_reach())
+            .field("EVSEVoltageLimitAchieved", self.get_voltage_limit_reach())
+            .field("EVSEPowerLimitAchieved", self.get_power_limit_reach())
+            .option("EVSEMaximumVoltageLimit", self.get_voltage_limit())
+            .option("EVSEMaximumCurrentLimit", self.get_current_limit())
+            .option("EVSEMaximumPowerLimit", self.get_power_limit())
+            .result("EVSEID", self.get_evse_id())
+            .field("SAScheduleTupleID", self.get_tuple_id())
+            .option("MeterInfo", self.get_meter_info())
+            .option("ReceiptRequired", self.get_receipt_require());
+    }
+}
+crate::exi_format!(CurrentDemandRequest, CurrentDemandResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct CurrentDemandReqXsd {
+        DC_EVStatus: DcEvStatusType,
+        EVTargetCurrent: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumVoltageLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumCurrentLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumPowerLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BulkChargingComplete: Option<bool>,
+        ChargingComplete: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RemainingTimeToFullSoC: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RemainingTimeToBulkSoC: Option<PhysicalValue>,
+        EVTargetVoltage: PhysicalValue,
+    }
+
+    impl CurrentDemandReqXsd {
+        fn from_exi(exi: &CurrentDemandRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVStatus: exi.get_status(),
+                EVTargetCurrent: exi.get_current_target(),
+                EVMaximumVoltageLimit: exi.get_voltage_limit(),
+                EVMaximumCurrentLimit: exi.get_current_limit(),
+                EVMaximumPowerLimit: exi.get_power_limit(),
+                BulkChargingComplete: exi.get_bulk_complete(),
+                ChargingComplete: exi.get_charging_complete(),
+                RemainingTimeToFullSoC: exi.get_time_to_full_sock(),
+                RemainingTimeToBulkSoC: exi.get_time_to_bulk_sock(),
+                EVTargetVoltage: exi.get_voltage_target(),
+            })
+        }
+
+        // fill payload directly, decoded messages are not always compliant with setter checks
+        fn into_exi(self) -> Result<CurrentDemandRequest, AfbError> {
+            let mut exi = CurrentDemandRequest::new(
+                &self.DC_EVStatus,
+                &self.EVTargetCurrent,
+                &self.EVTargetVoltage,
+                self.ChargingComplete,
+            );
+            let payload = &mut exi.payload;
+            if let Some(value) = &self.EVMaximumVoltageLimit {
+                payload.EVMaximumVoltageLimit = value.encode();
+                payload.set_EVMaximumVoltageLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVMaximumCurrentLimit {
+                payload.EVMaximumCurrentLimit = value.encode();
+                payload.set_EVMaximumCurrentLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVMaximumPowerLimit {
+                payload.EVMaximumPowerLimit = value.encode();
+                payload.set_EVMaximumPowerLimit_isUsed(1);
+            }
+            if let Some(value) = &self.RemainingTimeToFullSoC {
+                payload.RemainingTimeToFullSoC = value.encode();
+                payload.set_RemainingTimeToFullSoC_isUsed(1);
+            }
+            if let Some(value) = &self.RemainingTimeToBulkSoC {
+                payload.RemainingTimeToBulkSoC = value.encode();
+                payload.set_RemainingTimeToBulkSoC_isUsed(1);
+            }
+            if let Some(value) = self.BulkChargingComplete {
+                exi.set_bulk_complete(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CurrentDemandRequest, CurrentDemandReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CurrentDemandResXsd {
+        ResponseCode: ResponseCode,
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEPresentVoltage: PhysicalValue,
+        EVSEPresentCurrent: PhysicalValue,
+        EVSECurrentLimitAchieved: bool,
+        EVSEVoltageLimitAchieved: bool,
+        EVSEPowerLimitAchieved: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumVoltageLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumCurrentLimit: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEMaximumPowerLimit: Option<PhysicalValue>,
+        EVSEID: String,
+        SAScheduleTupleID: u8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MeterInfo: Option<MeterInfo>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ReceiptRequired: Option<bool>,
+    }
+
+    impl CurrentDemandResXsd {
+        fn from_exi(exi: &CurrentDemandResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                DC_EVSEStatus: exi.get_status(),
+                EVSEPresentVoltage: exi.get_voltage_present(),
+                EVSEPresentCurrent: exi.get_current_present(),
+                EVSECurrentLimitAchieved: exi.get_current_limit_reach(),
+                EVSEVoltageLimitAchieved: exi.get_voltage_limit_reach(),
+                EVSEPowerLimitAchieved: exi.get_power_limit_reach(),
+                EVSEMaximumVoltageLimit: exi.get_voltage_limit(),
+                EVSEMaximumCurrentLimit: exi.get_current_limit(),
+                EVSEMaximumPowerLimit: exi.get_power_limit(),
+                EVSEID: exi.get_evse_id()?.to_string(),
+                SAScheduleTupleID: exi.get_tuple_id(),
+                MeterInfo: exi.get_meter_info(),
+                ReceiptRequired: exi.get_receipt_require(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CurrentDemandResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_CurrentDemandResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEPresentVoltage = self.EVSEPresentVoltage.encode();
+            payload.EVSEPresentCurrent = self.EVSEPresentCurrent.encode();
+            payload.EVSECurrentLimitAchieved = if self.EVSECurrentLimitAchieved { 1 } else { 0 };
+            payload.EVSEVoltageLimitAchieved = if self.EVSEVoltageLimitAchieved { 1 } else { 0 };
+            payload.EVSEPowerLimitAchieved = if self.EVSEPowerLimitAchieved { 1 } else { 0 };
+            if let Some(value) = &self.EVSEMaximumVoltageLimit {
+                payload.EVSEMaximumVoltageLimit = value.encode();
+                payload.set_EVSEMaximumVoltageLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEMaximumCurrentLimit {
+                payload.EVSEMaximumCurrentLimit = value.encode();
+                payload.set_EVSEMaximumCurrentLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEMaximumPowerLimit {
+                payload.EVSEMaximumPowerLimit = value.encode();
+                payload.set_EVSEMaximumPowerLimit_isUsed(1);
+            }
+            payload.EVSEID.charactersLen = str_to_array(
+                &self.EVSEID,
+                &mut payload.EVSEID.characters,
+                cglue::iso2_EVSEID_CHARACTER_SIZE,
+            )?;
+            payload.SAScheduleTupleID = self.SAScheduleTupleID;
+
+            let mut exi = CurrentDemandResponse::decode(payload);
+            if let Some(value) = &self.MeterInfo {
+                exi.set_meter_info(value);
+            }
+            if let Some(value) = self.ReceiptRequired {
+                exi.set_receipt_require(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CurrentDemandResponse, CurrentDemandResXsd);
+}
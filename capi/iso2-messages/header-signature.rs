@@ -0,0 +1,360 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: XMLDSig header signature (SignedInfo, Reference & SignatureValue)
+ *  - KeyInfo & Object elements are not modeled, ISO-15118-2 signatures do not use them
+ */
+
+use super::*;
+use std::mem;
+
+pub struct SignatureReference {
+    payload: cglue::iso2_ReferenceType,
+}
+
+impl SignatureReference {
+    pub fn new(uri: &str, digest_method: &str, digest: &[u8]) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso2_ReferenceType>() };
+        payload.URI.charactersLen = str_to_array(
+            uri,
+            &mut payload.URI.characters,
+            cglue::iso2_URI_CHARACTER_SIZE,
+        )?;
+        payload.set_URI_isUsed(1);
+        payload.DigestMethod.Algorithm.charactersLen = str_to_array(
+            digest_method,
+            &mut payload.DigestMethod.Algorithm.characters,
+            cglue::iso2_Algorithm_CHARACTER_SIZE,
+        )?;
+        payload.DigestValue.bytesLen = bytes_to_array(
+            digest,
+            &mut payload.DigestValue.bytes,
+            cglue::iso2_DigestValueType_BYTES_SIZE,
+        )?;
+        Ok(Self { payload })
+    }
+
+    pub fn set_id(&mut self, id: &str) -> Result<&mut Self, AfbError> {
+        self.payload.Id.charactersLen = str_to_array(
+            id,
+            &mut self.payload.Id.characters,
+            cglue::iso2_Id_CHARACTER_SIZE,
+        )?;
+        self.payload.set_Id_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_id(&self) -> Option<&str> {
+        if self.payload.Id_isUsed() == 0 {
+            None
+        } else {
+            array_to_str(&self.payload.Id.characters, self.payload.Id.charactersLen).ok()
+        }
+    }
+
+    pub fn get_uri(&self) -> Option<&str> {
+        if self.payload.URI_isUsed() == 0 {
+            None
+        } else {
+            array_to_str(&self.payload.URI.characters, self.payload.URI.charactersLen).ok()
+        }
+    }
+
+    pub fn set_transform(&mut self, algorithm: &str) -> Result<&mut Self, AfbError> {
+        let transform = &mut self.payload.Transforms.Transform;
+        transform.Algorithm.charactersLen = str_to_array(
+            algorithm,
+            &mut transform.Algorithm.characters,
+            cglue::iso2_Algorithm_CHARACTER_SIZE,
+        )?;
+        self.payload.set_Transforms_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_transform(&self) -> Option<&str> {
+        if self.payload.Transforms_isUsed() == 0 {
+            None
+        } else {
+            let transform = &self.payload.Transforms.Transform;
+            array_to_str(
+                &transform.Algorithm.characters,
+                transform.Algorithm.charactersLen,
+            )
+            .ok()
+        }
+    }
+
+    pub fn get_digest_method(&self) -> Result<&str, AfbError> {
+        array_to_str(
+            &self.payload.DigestMethod.Algorithm.characters,
+            self.payload.DigestMethod.Algorithm.charactersLen,
+        )
+    }
+
+    pub fn get_digest(&self) -> &[u8] {
+        array_to_bytes(
+            &self.payload.DigestValue.bytes,
+            self.payload.DigestValue.bytesLen,
+        )
+    }
+
+    pub fn decode(payload: cglue::iso2_ReferenceType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso2_ReferenceType {
+        self.payload
+    }
+}
+
+pub struct SignatureType {
+    payload: cglue::iso2_SignatureType,
+}
+
+impl SignatureType {
+    pub fn new(
+        canonicalization: &str,
+        signature_method: &str,
+        signature: &[u8],
+    ) -> Result<Self, AfbError> {
+        let mut payload = unsafe { mem::zeroed::<cglue::iso2_SignatureType>() };
+        let info = &mut payload.SignedInfo;
+        info.CanonicalizationMethod.Algorithm.charactersLen = str_to_array(
+            canonicalization,
+            &mut info.CanonicalizationMethod.Algorithm.characters,
+            cglue::iso2_Algorithm_CHARACTER_SIZE,
+        )?;
+        info.SignatureMethod.Algorithm.charactersLen = str_to_array(
+            signature_method,
+            &mut info.SignatureMethod.Algorithm.characters,
+            cglue::iso2_Algorithm_CHARACTER_SIZE,
+        )?;
+        payload.SignatureValue.CONTENT.bytesLen = bytes_to_array(
+            signature,
+            &mut payload.SignatureValue.CONTENT.bytes,
+            cglue::iso2_SignatureValueType_BYTES_SIZE,
+        )?;
+        Ok(Self { payload })
+    }
+
+    pub fn set_id(&mut self, id: &str) -> Result<&mut Self, AfbError> {
+        self.payload.Id.charactersLen = str_to_array(
+            id,
+            &mut self.payload.Id.characters,
+            cglue::iso2_Id_CHARACTER_SIZE,
+        )?;
+        self.payload.set_Id_isUsed(1);
+        Ok(self)
+    }
+
+    pub fn get_id(&self) -> Option<&str> {
+        if self.payload.Id_isUsed() == 0 {
+            None
+        } else {
+            array_to_str(&self.payload.Id.characters, self.payload.Id.charactersLen).ok()
+        }
+    }
+
+    pub fn get_canonicalization(&self) -> Result<&str, AfbError> {
+        let method = &self.payload.SignedInfo.CanonicalizationMethod;
+        array_to_str(&method.Algorithm.characters, method.Algorithm.charactersLen)
+    }
+
+    pub fn get_signature_method(&self) -> Result<&str, AfbError> {
+        let method = &self.payload.SignedInfo.SignatureMethod;
+        array_to_str(&method.Algorithm.characters, method.Algorithm.charactersLen)
+    }
+
+    pub fn add_reference(&mut self, reference: &SignatureReference) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.SignedInfo.Reference.arrayLen;
+        if idx == cglue::iso2_ReferenceType_4_ARRAY_SIZE as u16 {
+            return afb_error!("signature-add-reference", "reach max:{} references", idx);
+        }
+        self.payload.SignedInfo.Reference.array[idx as usize] = reference.encode();
+        self.payload.SignedInfo.Reference.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    pub fn get_references(&self) -> Vec<SignatureReference> {
+        let mut references = Vec::new();
+        for idx in 0..self.payload.SignedInfo.Reference.arrayLen {
+            references.push(SignatureReference::decode(
+                self.payload.SignedInfo.Reference.array[idx as usize],
+            ));
+        }
+        references
+    }
+
+    pub fn get_signature(&self) -> &[u8] {
+        array_to_bytes(
+            &self.payload.SignatureValue.CONTENT.bytes,
+            self.payload.SignatureValue.CONTENT.bytesLen,
+        )
+    }
+
+    pub fn decode(payload: cglue::iso2_SignatureType) -> Self {
+        Self { payload }
+    }
+
+    pub fn encode(&self) -> cglue::iso2_SignatureType {
+        self.payload
+    }
+}
+
+impl ExiFields for SignatureReference {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .option("URI", self.get_uri())
+            .option("Transform", self.get_transform())
+            .result("DigestMethod", self.get_digest_method())
+            .field("DigestValue", ExiHexa(self.get_digest()));
+    }
+}
+
+impl ExiFields for SignatureType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .result("CanonicalizationMethod", self.get_canonicalization())
+            .result("SignatureMethod", self.get_signature_method())
+            .field("Reference", ExiList(self.get_references()))
+            .field("SignatureValue", ExiHexa(self.get_signature()));
+    }
+}
+crate::exi_format!(SignatureReference, SignatureType);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct AlgorithmXsd {
+        Algorithm: String,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct TransformsXsd {
+        Transform: AlgorithmXsd,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ReferenceXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        URI: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Transforms: Option<TransformsXsd>,
+        DigestMethod: AlgorithmXsd,
+        DigestValue: ExiBytes,
+    }
+
+    impl ReferenceXsd {
+        fn from_exi(exi: &SignatureReference) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id().map(|value| value.to_string()),
+                URI: exi.get_uri().map(|value| value.to_string()),
+                Transforms: exi.get_transform().map(|value| TransformsXsd {
+                    Transform: AlgorithmXsd {
+                        Algorithm: value.to_string(),
+                    },
+                }),
+                DigestMethod: AlgorithmXsd {
+                    Algorithm: exi.get_digest_method()?.to_string(),
+                },
+                DigestValue: ExiBytes::from(exi.get_digest()),
+            })
+        }
+
+        fn into_exi(self) -> Result<SignatureReference, AfbError> {
+            let mut exi = SignatureReference::new(
+                self.URI.as_deref().unwrap_or(""),
+                &self.DigestMethod.Algorithm,
+                self.DigestValue.as_slice(),
+            )?;
+            if self.URI.is_none() {
+                exi.payload.set_URI_isUsed(0);
+            }
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            if let Some(transforms) = &self.Transforms {
+                exi.set_transform(&transforms.Transform.Algorithm)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SignatureReference, ReferenceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SignedInfoXsd {
+        CanonicalizationMethod: AlgorithmXsd,
+        SignatureMethod: AlgorithmXsd,
+        Reference: Vec<SignatureReference>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SignatureValueXsd {
+        CONTENT: ExiBytes,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SignatureXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        SignedInfo: SignedInfoXsd,
+        SignatureValue: SignatureValueXsd,
+    }
+
+    impl SignatureXsd {
+        fn from_exi(exi: &SignatureType) -> Result<Self, AfbError> {
+            Ok(Self {
+                Id: exi.get_id().map(|value| value.to_string()),
+                SignedInfo: SignedInfoXsd {
+                    CanonicalizationMethod: AlgorithmXsd {
+                        Algorithm: exi.get_canonicalization()?.to_string(),
+                    },
+                    SignatureMethod: AlgorithmXsd {
+                        Algorithm: exi.get_signature_method()?.to_string(),
+                    },
+                    Reference: exi.get_references(),
+                },
+                SignatureValue: SignatureValueXsd {
+                    CONTENT: ExiBytes::from(exi.get_signature()),
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<SignatureType, AfbError> {
+            let mut exi = SignatureType::new(
+                &self.SignedInfo.CanonicalizationMethod.Algorithm,
+                &self.SignedInfo.SignatureMethod.Algorithm,
+                self.SignatureValue.CONTENT.as_slice(),
+            )?;
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            for reference in &self.SignedInfo.Reference {
+                exi.add_reference(reference)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SignatureType, SignatureXsd);
+}
@@ -205,8 +205,9 @@ impl MeteringReceiptRequest {
         if status != 0 {
             return afb_error!(
                 "iso2-metering-receipe-sign",
-                "fail to check signature error:{}",
-                PkiErrorStatus::from_u32(status).to_label()
+                "fail to check signature error:{:?}({})",
+                PkiErrorStatus::try_from(status).ok(),
+                status
             );
         }
         Ok(())
@@ -225,7 +226,7 @@ impl MeteringReceiptResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -285,3 +286,168 @@ impl MeteringReceiptResponse {
         body
     }
 }
+
+impl ExiFields for MeterInfo {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("MeterID", self.get_id())
+            .option("MeterReading", self.get_reading())
+            .option("SigMeterReading", self.get_sig().map(ExiHexa))
+            .option("MeterStatus", self.get_status())
+            .option("TMeter", self.get_tmeter());
+    }
+}
+
+impl ExiFields for MeteringReceiptRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .field("SessionID", ExiHexa(self.get_session_id()))
+            .option("SAScheduleTupleID", self.get_tuple_id())
+            .field("MeterInfo", self.get_info());
+    }
+}
+
+impl ExiFields for MeteringReceiptResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .option("AC_EVSEStatus", self.get_ac_evse_status())
+            .option("DC_EVSEStatus", self.get_dc_evse_status())
+            .option("EVSEStatus", self.get_evse_status());
+    }
+}
+crate::exi_format!(MeterInfo, MeteringReceiptRequest, MeteringReceiptResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct MeterInfoXsd {
+        MeterID: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MeterReading: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SigMeterReading: Option<ExiBytes>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MeterStatus: Option<i16>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TMeter: Option<i64>,
+    }
+
+    impl MeterInfoXsd {
+        fn from_exi(exi: &MeterInfo) -> Result<Self, AfbError> {
+            Ok(Self {
+                MeterID: exi.get_id()?.to_string(),
+                MeterReading: exi.get_reading(),
+                SigMeterReading: exi.get_sig().map(ExiBytes::from),
+                MeterStatus: exi.get_status(),
+                TMeter: exi.get_tmeter(),
+            })
+        }
+
+        fn into_exi(self) -> Result<MeterInfo, AfbError> {
+            let mut exi = MeterInfo::new(&self.MeterID)?;
+            if let Some(value) = self.MeterReading {
+                exi.set_reading(value);
+            }
+            if let Some(value) = &self.SigMeterReading {
+                exi.payload.SigMeterReading.bytesLen = bytes_to_array(
+                    value.as_slice(),
+                    &mut exi.payload.SigMeterReading.bytes,
+                    cglue::iso2_sigMeterReadingType_BYTES_SIZE,
+                )?;
+                exi.payload.set_SigMeterReading_isUsed(1);
+            }
+            if let Some(value) = self.MeterStatus {
+                exi.set_status(value);
+            }
+            if let Some(value) = self.TMeter {
+                exi.set_tmeter(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(MeterInfo, MeterInfoXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct MeteringReceiptReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        SessionID: ExiBytes,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SAScheduleTupleID: Option<u8>,
+        MeterInfo: MeterInfo,
+    }
+
+    impl MeteringReceiptReqXsd {
+        fn from_exi(exi: &MeteringReceiptRequest) -> Result<Self, AfbError> {
+            let id = if exi.payload.Id_isUsed() == 0 {
+                None
+            } else {
+                Some(
+                    array_to_str(&exi.payload.Id.characters, exi.payload.Id.charactersLen)?
+                        .to_string(),
+                )
+            };
+            Ok(Self {
+                Id: id,
+                SessionID: ExiBytes::from(exi.get_session_id()),
+                SAScheduleTupleID: exi.get_tuple_id(),
+                MeterInfo: exi.get_info(),
+            })
+        }
+
+        fn into_exi(self) -> Result<MeteringReceiptRequest, AfbError> {
+            let mut exi = MeteringReceiptRequest::new(self.SessionID.as_slice(), &self.MeterInfo)?;
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+            }
+            if let Some(value) = self.SAScheduleTupleID {
+                exi.set_tupple_id(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(MeteringReceiptRequest, MeteringReceiptReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct MeteringReceiptResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVSEStatus: Option<AcEvseStatusType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVSEStatus: Option<DcEvseStatusType>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEStatus: Option<EvseStatusType>,
+    }
+
+    impl MeteringReceiptResXsd {
+        fn from_exi(exi: &MeteringReceiptResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                AC_EVSEStatus: exi.get_ac_evse_status(),
+                DC_EVSEStatus: exi.get_dc_evse_status(),
+                EVSEStatus: exi.get_evse_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<MeteringReceiptResponse, AfbError> {
+            let mut exi = MeteringReceiptResponse::new(self.ResponseCode);
+            if let Some(value) = &self.AC_EVSEStatus {
+                exi.set_ac_evse_status(value);
+            }
+            if let Some(value) = &self.DC_EVSEStatus {
+                exi.set_dc_evse_status(value);
+            }
+            if let Some(value) = &self.EVSEStatus {
+                exi.set_evse_status(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(MeteringReceiptResponse, MeteringReceiptResXsd);
+}
@@ -31,19 +31,19 @@ impl DcEvChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::iso2_DC_EVChargeParameterType>() };
 
-        if max_current.get_unit() != PhysicalUnit::Ampere {
+        if max_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "dc-ev-charge-param",
                 "max_current expect: PhysicalUnit::Ampere get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
 
-        if max_voltage.get_unit() != PhysicalUnit::Volt {
+        if max_voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "dc-ev-charge-param",
                 "max_voltage expect: PhysicalUnit::Volt get:{}",
-                max_voltage.get_unit()
+                max_voltage.get_unit()?
             );
         }
 
@@ -67,11 +67,11 @@ impl DcEvChargeParam {
 
     #[track_caller]
     pub fn set_max_power(&mut self, power_limit: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if power_limit.get_unit() != PhysicalUnit::Watt {
+        if power_limit.get_unit()? != PhysicalUnit::Watt {
             return afb_error!(
                 "dc-ev-charge-param",
                 "max_power expect: PhysicalUnit::Watt get:{}",
-                power_limit.get_unit()
+                power_limit.get_unit()?
             );
         }
         self.payload.EVMaximumPowerLimit = power_limit.encode();
@@ -92,11 +92,11 @@ impl DcEvChargeParam {
         &mut self,
         power_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if power_limit.get_unit() != PhysicalUnit::Wh {
+        if power_limit.get_unit()? != PhysicalUnit::Wh {
             return afb_error!(
                 "dc-ev-charge-param",
                 "energy_capacity expect: PhysicalUnit::Wh get:{}",
-                power_limit.get_unit()
+                power_limit.get_unit()?
             );
         }
         self.payload.EVEnergyCapacity = power_limit.encode();
@@ -116,11 +116,11 @@ impl DcEvChargeParam {
         &mut self,
         power_limit: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if power_limit.get_unit() != PhysicalUnit::Wh {
+        if power_limit.get_unit()? != PhysicalUnit::Wh {
             return afb_error!(
                 "dc-ev-charge-param",
                 "power_limit expect: PhysicalUnit::Wh get:{}",
-                power_limit.get_unit()
+                power_limit.get_unit()?
             );
         }
         self.payload.EVEnergyRequest = power_limit.encode();
@@ -201,27 +201,27 @@ impl AcEvChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::iso2_AC_EVChargeParameterType>() };
 
-        if max_current.get_unit() != PhysicalUnit::Ampere {
+        if max_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "ac-ev-charge-param",
                 "max_current expect: PhysicalUnit::Ampere get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
 
-        if min_current.get_unit() != PhysicalUnit::Ampere {
+        if min_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "ac-ev-charge-param",
                 "min_current expect: PhysicalUnit::Ampere get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
 
-        if max_voltage.get_unit() != PhysicalUnit::Volt {
+        if max_voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "ac-ev-charge-param",
                 "max_voltage expect: PhysicalUnit::Volt get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
 
@@ -326,7 +326,7 @@ impl ParamDiscoveryRequest {
         Self { payload }
     }
 
-    pub fn get_transfert_energy_mode(&self) -> EngyTransfertMode {
+    pub fn get_transfert_energy_mode(&self) -> Result<EngyTransfertMode, AfbError> {
         EngyTransfertMode::from_u32(self.payload.RequestedEnergyTransferMode)
     }
 
@@ -358,7 +358,7 @@ impl ParamDiscoveryRequest {
             );
         }
 
-        match EngyTransfertMode::from_u32(self.payload.RequestedEnergyTransferMode) {
+        match EngyTransfertMode::from_u32(self.payload.RequestedEnergyTransferMode)? {
             EngyTransfertMode::AcSinglePhase => {}
             EngyTransfertMode::AcThreePhase => {}
             _ => {
@@ -394,7 +394,7 @@ impl ParamDiscoveryRequest {
                 "fail set_dc_charge_param because ac already set"
             );
         }
-        match EngyTransfertMode::from_u32(self.payload.RequestedEnergyTransferMode) {
+        match EngyTransfertMode::from_u32(self.payload.RequestedEnergyTransferMode)? {
             EngyTransfertMode::DcBasic => {}
             EngyTransfertMode::DcExtended => {}
             EngyTransfertMode::DcCombo => {}
@@ -704,7 +704,7 @@ impl CostType {
         Self { payload }
     }
 
-    pub fn get_kind(&self) -> CostKind {
+    pub fn get_kind(&self) -> Result<CostKind, AfbError> {
         CostKind::from_u32(self.payload.costKind)
     }
 
@@ -914,19 +914,19 @@ impl AcEvseChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::iso2_AC_EVSEChargeParameterType>() };
 
-        if max_current.get_unit() != PhysicalUnit::Ampere {
+        if max_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "ac-evse-charge-param",
                 "max_current expect: ampere get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
 
-        if nominate_voltage.get_unit() != PhysicalUnit::Volt {
+        if nominate_voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "ac-evse-charge-param",
                 "nominate_voltage expect: volt get:{}",
-                nominate_voltage.get_unit()
+                nominate_voltage.get_unit()?
             );
         }
 
@@ -975,46 +975,46 @@ impl DcEvseChargeParam {
     ) -> Result<Self, AfbError> {
         let mut payload = unsafe { mem::zeroed::<cglue::iso2_DC_EVSEChargeParameterType>() };
 
-        if max_voltage.get_unit() != PhysicalUnit::Volt {
+        if max_voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "dc-charge-param",
                 "max_voltage expect: PhysicalUnit::Volt get:{}",
-                max_voltage.get_unit()
+                max_voltage.get_unit()?
             );
         }
-        if min_voltage.get_unit() != PhysicalUnit::Volt {
+        if min_voltage.get_unit()? != PhysicalUnit::Volt {
             return afb_error!(
                 "dc-charge-param",
                 "min_voltage expect: PhysicalUnit::Volt get:{}",
-                min_voltage.get_unit()
+                min_voltage.get_unit()?
             );
         }
-        if max_current.get_unit() != PhysicalUnit::Ampere {
+        if max_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "pre-charge-req",
                 "max_current expect: PhysicalUnit::Ampere get:{}",
-                max_current.get_unit()
+                max_current.get_unit()?
             );
         }
-        if min_current.get_unit() != PhysicalUnit::Ampere {
+        if min_current.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "pre-charge-req",
                 "min_current expect: PhysicalUnit::Ampere get:{}",
-                min_current.get_unit()
+                min_current.get_unit()?
             );
         }
-        if max_power.get_unit() != PhysicalUnit::Watt {
+        if max_power.get_unit()? != PhysicalUnit::Watt {
             return afb_error!(
                 "pre-charge-req",
                 "max_power expect: PhysicalUnit::Watt get:{}",
-                max_power.get_unit()
+                max_power.get_unit()?
             );
         }
-        if current_ripple.get_unit() != PhysicalUnit::Ampere {
+        if current_ripple.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "pre-charge-req",
                 "current_ripple expect: PhysicalUnit::Ampere get:{}",
-                current_ripple.get_unit()
+                current_ripple.get_unit()?
             );
         }
 
@@ -1056,11 +1056,11 @@ impl DcEvseChargeParam {
         &mut self,
         tolerance: &PhysicalValue,
     ) -> Result<&mut Self, AfbError> {
-        if tolerance.get_unit() != PhysicalUnit::Ampere {
+        if tolerance.get_unit()? != PhysicalUnit::Ampere {
             return afb_error!(
                 "dc-ev-charge-param",
                 "regul_tolerance expect: PhysicalUnit::Ampere get:{}",
-                tolerance.get_unit()
+                tolerance.get_unit()?
             );
         }
         self.payload.EVSECurrentRegulationTolerance = tolerance.encode();
@@ -1079,11 +1079,11 @@ impl DcEvseChargeParam {
     }
 
     pub fn set_energy_to_deliver(&mut self, energy: &PhysicalValue) -> Result<&mut Self, AfbError> {
-        if energy.get_unit() != PhysicalUnit::Wh {
+        if energy.get_unit()? != PhysicalUnit::Wh {
             return afb_error!(
                 "dc-ev-charge-param",
                 "energy_to_deliver expect: PhysicalUnit::Wh get:{}",
-                energy.get_unit()
+                energy.get_unit()?
             );
         }
         self.payload.EVSEEnergyToBeDelivered = energy.encode();
@@ -1125,11 +1125,11 @@ impl ParamDiscoveryResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
-    pub fn get_processing(&self) -> EvseProcessing {
+    pub fn get_processing(&self) -> Result<EvseProcessing, AfbError> {
         EvseProcessing::from_u32(self.payload.EVSEProcessing)
     }
 
@@ -1232,3 +1232,788 @@ impl ParamDiscoveryResponse {
         body
     }
 }
+
+impl ExiFields for DcEvChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DepartureTime", self.get_departure_time())
+            .field("DC_EVStatus", self.get_status())
+            .field("EVMaximumCurrentLimit", self.get_max_current())
+            .option("EVMaximumPowerLimit", self.get_max_power())
+            .field("EVMaximumVoltageLimit", self.get_max_voltage())
+            .option("EVEnergyCapacity", self.get_energy_capacity())
+            .option("EVEnergyRequest", self.get_energy_request())
+            .option("FullSOC", self.get_full_soc())
+            .option("BulkSOC", self.get_bulk_soc());
+    }
+}
+
+impl ExiFields for AcEvChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DepartureTime", self.get_departure_time())
+            .field("EAmount", self.get_ea_mount())
+            .field("EVMaxVoltage", self.get_max_voltage())
+            .field("EVMaxCurrent", self.get_max_current())
+            .field("EVMinCurrent", self.get_min_current());
+    }
+}
+
+impl ExiFields for EvChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("DepartureTime", self.get_departure_time())
+            .field("AC_EVChargeParameter", self.get_ac_param())
+            .field("DC_EVChargeParameter", self.get_dc_param());
+    }
+}
+
+impl ExiFields for ParamDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("MaxEntriesSAScheduleTuple", self.get_max_schedule_tuple())
+            .result(
+                "RequestedEnergyTransferMode",
+                self.get_transfert_energy_mode(),
+            )
+            .option("EVChargeParameter", self.get_ev_charge_param())
+            .option("AC_EVChargeParameter", self.get_ac_charge_param())
+            .option("DC_EVChargeParameter", self.get_dc_charge_param());
+    }
+}
+
+impl ExiFields for SalesTariff {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("Id", self.get_id())
+            .field("SalesTariffID", self.get_tariff_id())
+            .option("SalesTariffDescription", self.get_description())
+            .option("NumEPriceLevels", self.get_tariff_level())
+            .field("SalesTariffEntry", ExiList(self.get_entries()));
+    }
+}
+
+impl ExiFields for RelativeTimeInterval {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("start", self.get_start())
+            .option("duration", self.get_duration());
+    }
+}
+
+// abstract IntervalType has no content
+impl ExiFields for TimeInterval {
+    fn exi_fields(&self, _fields: &mut ExiFormatter) {}
+}
+
+impl ExiFields for PMaxScheduleEntry {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.option("RelativeTimeInterval", self.get_relative_time_interval());
+        if self.payload.TimeInterval_isUsed() != 0 {
+            fields.field(
+                "TimeInterval",
+                TimeInterval::decode(self.payload.TimeInterval),
+            );
+        }
+        fields.field("PMax", self.get_pmax());
+    }
+}
+
+impl ExiFields for CostType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("costKind", self.get_kind())
+            .field("amount", self.get_amount())
+            .option("amountMultiplier", self.get_multiplier());
+    }
+}
+
+impl ExiFields for ConsumptionCost {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("startValue", PhysicalValue::decode(self.payload.startValue))
+            .field("Cost", ExiList(self.get_costs()));
+    }
+}
+
+impl ExiFields for SaleTariffEntry {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        let consumption = &self.payload.ConsumptionCost;
+        let costs = (0..consumption.arrayLen as usize)
+            .map(|idx| ConsumptionCost::decode(consumption.array[idx]))
+            .collect();
+        fields
+            .option("RelativeTimeInterval", self.get_relative_time())
+            .option("TimeInterval", self.get_time())
+            .option("EPriceLevel", self.get_price_level())
+            .field("ConsumptionCost", ExiList(costs));
+    }
+}
+
+impl ExiFields for SasScheduleTuple {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("SAScheduleTupleID", self.get_description())
+            .field("PMaxSchedule", ExiList(self.get_pmaxs()))
+            .option("SalesTariff", self.get_tariff());
+    }
+}
+
+impl ExiFields for AcEvseChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("AC_EVSEStatus", self.get_status())
+            .field("EVSENominalVoltage", self.get_nominate_voltage())
+            .field("EVSEMaxCurrent", self.get_max_current());
+    }
+}
+
+impl ExiFields for DcEvseChargeParam {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("DC_EVSEStatus", self.get_status())
+            .field("EVSEMaximumCurrentLimit", self.get_max_current())
+            .field("EVSEMaximumPowerLimit", self.get_max_power())
+            .field("EVSEMaximumVoltageLimit", self.get_max_voltage())
+            .field("EVSEMinimumCurrentLimit", self.get_min_current())
+            .field("EVSEMinimumVoltageLimit", self.get_min_voltage())
+            .option("EVSECurrentRegulationTolerance", self.get_regul_tolerance())
+            .field("EVSEPeakCurrentRipple", self.get_peak_current_ripple())
+            .option("EVSEEnergyToBeDelivered", self.get_energy_to_deliver());
+    }
+}
+
+impl ExiFields for ParamDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEProcessing", self.get_processing())
+            .option("SASchedules", self.get_schedules());
+        if self.payload.SAScheduleList_isUsed() != 0 {
+            fields.field("SAScheduleList", ExiList(self.get_schedule_tuples()));
+        }
+        fields
+            .option("EVSEChargeParameter", self.get_evse_charge_param())
+            .option("AC_EVSEChargeParameter", self.get_evse_ac_charge_param())
+            .option("DC_EVSEChargeParameter", self.get_evse_dc_charge_param());
+    }
+}
+crate::exi_format!(
+    DcEvChargeParam,
+    AcEvChargeParam,
+    EvChargeParam,
+    ParamDiscoveryRequest,
+    SalesTariff,
+    RelativeTimeInterval,
+    TimeInterval,
+    PMaxScheduleEntry,
+    CostType,
+    ConsumptionCost,
+    SaleTariffEntry,
+    SasScheduleTuple,
+    AcEvseChargeParam,
+    DcEvseChargeParam,
+    ParamDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    // abstract/empty XSD elements (IntervalType, SASchedulesType, ...) only carry their presence
+    #[derive(Serialize, Deserialize)]
+    struct AbstractXsd {}
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvChargeParamXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        DC_EVStatus: DcEvStatusType,
+        EVMaximumCurrentLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVMaximumPowerLimit: Option<PhysicalValue>,
+        EVMaximumVoltageLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVEnergyCapacity: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVEnergyRequest: Option<PhysicalValue>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        FullSOC: Option<i8>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        BulkSOC: Option<i8>,
+    }
+
+    impl DcEvChargeParamXsd {
+        fn from_exi(exi: &DcEvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                DC_EVStatus: exi.get_status(),
+                EVMaximumCurrentLimit: exi.get_max_current(),
+                EVMaximumPowerLimit: exi.get_max_power(),
+                EVMaximumVoltageLimit: exi.get_max_voltage(),
+                EVEnergyCapacity: exi.get_energy_capacity(),
+                EVEnergyRequest: exi.get_energy_request(),
+                FullSOC: exi.get_full_soc(),
+                BulkSOC: exi.get_bulk_soc(),
+            })
+        }
+
+        // fill payload directly, decoded messages are not always compliant with setter checks
+        fn into_exi(self) -> Result<DcEvChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_DC_EVChargeParameterType>() };
+            payload.DC_EVStatus = self.DC_EVStatus.encode();
+            payload.EVMaximumCurrentLimit = self.EVMaximumCurrentLimit.encode();
+            payload.EVMaximumVoltageLimit = self.EVMaximumVoltageLimit.encode();
+            if let Some(value) = &self.EVMaximumPowerLimit {
+                payload.EVMaximumPowerLimit = value.encode();
+                payload.set_EVMaximumPowerLimit_isUsed(1);
+            }
+            if let Some(value) = &self.EVEnergyCapacity {
+                payload.EVEnergyCapacity = value.encode();
+                payload.set_EVEnergyCapacity_isUsed(1);
+            }
+            if let Some(value) = &self.EVEnergyRequest {
+                payload.EVEnergyRequest = value.encode();
+                payload.set_EVEnergyRequest_isUsed(1);
+            }
+
+            let mut exi = DcEvChargeParam::decode(payload);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            if let Some(value) = self.FullSOC {
+                exi.set_full_soc(value);
+            }
+            if let Some(value) = self.BulkSOC {
+                exi.set_bulk_soc(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvChargeParam, DcEvChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvChargeParamXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        EAmount: PhysicalValue,
+        EVMaxVoltage: PhysicalValue,
+        EVMaxCurrent: PhysicalValue,
+        EVMinCurrent: PhysicalValue,
+    }
+
+    impl AcEvChargeParamXsd {
+        fn from_exi(exi: &AcEvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                EAmount: exi.get_ea_mount(),
+                EVMaxVoltage: exi.get_max_voltage(),
+                EVMaxCurrent: exi.get_max_current(),
+                EVMinCurrent: exi.get_min_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_AC_EVChargeParameterType>() };
+            payload.EAmount = self.EAmount.encode();
+            payload.EVMaxVoltage = self.EVMaxVoltage.encode();
+            payload.EVMaxCurrent = self.EVMaxCurrent.encode();
+            payload.EVMinCurrent = self.EVMinCurrent.encode();
+
+            let mut exi = AcEvChargeParam::decode(payload);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(AcEvChargeParam, AcEvChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct EvChargeParamXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DepartureTime: Option<u32>,
+        AC_EVChargeParameter: AcEvChargeParam,
+        DC_EVChargeParameter: DcEvChargeParam,
+    }
+
+    impl EvChargeParamXsd {
+        fn from_exi(exi: &EvChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DepartureTime: exi.get_departure_time(),
+                AC_EVChargeParameter: exi.get_ac_param(),
+                DC_EVChargeParameter: exi.get_dc_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<EvChargeParam, AfbError> {
+            let mut exi =
+                EvChargeParam::new(&self.AC_EVChargeParameter, &self.DC_EVChargeParameter);
+            if let Some(value) = self.DepartureTime {
+                exi.set_departure_time(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(EvChargeParam, EvChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargeParameterDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        MaxEntriesSAScheduleTuple: Option<u16>,
+        RequestedEnergyTransferMode: EngyTransfertMode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVChargeParameter: Option<EvChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVChargeParameter: Option<AcEvChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVChargeParameter: Option<DcEvChargeParam>,
+    }
+
+    impl ChargeParameterDiscoveryReqXsd {
+        fn from_exi(exi: &ParamDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                MaxEntriesSAScheduleTuple: exi.get_max_schedule_tuple(),
+                RequestedEnergyTransferMode: exi.get_transfert_energy_mode()?,
+                EVChargeParameter: exi.get_ev_charge_param(),
+                AC_EVChargeParameter: exi.get_ac_charge_param(),
+                DC_EVChargeParameter: exi.get_dc_charge_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamDiscoveryRequest, AfbError> {
+            let mut exi = ParamDiscoveryRequest::new(self.RequestedEnergyTransferMode);
+            if let Some(value) = self.MaxEntriesSAScheduleTuple {
+                exi.set_max_schedule_tuple(value);
+            }
+            if let Some(value) = &self.EVChargeParameter {
+                exi.payload.EVChargeParameter = value.encode();
+                exi.payload.set_EVChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.AC_EVChargeParameter {
+                exi.payload.AC_EVChargeParameter = value.encode();
+                exi.payload.set_AC_EVChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.DC_EVChargeParameter {
+                exi.payload.DC_EVChargeParameter = value.encode();
+                exi.payload.set_DC_EVChargeParameter_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ParamDiscoveryRequest, ChargeParameterDiscoveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct RelativeTimeIntervalXsd {
+        start: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration: Option<u32>,
+    }
+
+    impl RelativeTimeIntervalXsd {
+        fn from_exi(exi: &RelativeTimeInterval) -> Result<Self, AfbError> {
+            Ok(Self {
+                start: exi.get_start(),
+                duration: exi.get_duration(),
+            })
+        }
+
+        fn into_exi(self) -> Result<RelativeTimeInterval, AfbError> {
+            let mut exi = RelativeTimeInterval::new(self.start);
+            if let Some(value) = self.duration {
+                exi.set_duration(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(RelativeTimeInterval, RelativeTimeIntervalXsd);
+
+    impl AbstractXsd {
+        fn from_exi(_exi: &TimeInterval) -> Result<Self, AfbError> {
+            Ok(Self {})
+        }
+
+        fn into_exi(self) -> Result<TimeInterval, AfbError> {
+            Ok(TimeInterval::new(0))
+        }
+    }
+    crate::exi_serde_mirror!(TimeInterval, AbstractXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PMaxScheduleEntryXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RelativeTimeInterval: Option<RelativeTimeInterval>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TimeInterval: Option<TimeInterval>,
+        PMax: PhysicalValue,
+    }
+
+    impl PMaxScheduleEntryXsd {
+        fn from_exi(exi: &PMaxScheduleEntry) -> Result<Self, AfbError> {
+            let time_interval = if exi.payload.TimeInterval_isUsed() == 0 {
+                None
+            } else {
+                Some(TimeInterval::decode(exi.payload.TimeInterval))
+            };
+            Ok(Self {
+                RelativeTimeInterval: exi.get_relative_time_interval(),
+                TimeInterval: time_interval,
+                PMax: exi.get_pmax(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PMaxScheduleEntry, AfbError> {
+            let mut exi = PMaxScheduleEntry::new(&self.PMax);
+            if let Some(value) = &self.RelativeTimeInterval {
+                exi.set_relative_time_interval(value);
+            }
+            if let Some(value) = &self.TimeInterval {
+                exi.set_time_interval(value.get_unused());
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PMaxScheduleEntry, PMaxScheduleEntryXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct CostXsd {
+        costKind: CostKind,
+        amount: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        amountMultiplier: Option<i8>,
+    }
+
+    impl CostXsd {
+        fn from_exi(exi: &CostType) -> Result<Self, AfbError> {
+            Ok(Self {
+                costKind: exi.get_kind()?,
+                amount: exi.get_amount(),
+                amountMultiplier: exi.get_multiplier(),
+            })
+        }
+
+        fn into_exi(self) -> Result<CostType, AfbError> {
+            let mut exi = CostType::new(self.costKind, self.amount, 0);
+            if let Some(value) = self.amountMultiplier {
+                exi.payload.amountMultiplier = value;
+                exi.payload.set_amountMultiplier_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(CostType, CostXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ConsumptionCostXsd {
+        startValue: PhysicalValue,
+        Cost: Vec<CostType>,
+    }
+
+    impl ConsumptionCostXsd {
+        fn from_exi(exi: &ConsumptionCost) -> Result<Self, AfbError> {
+            Ok(Self {
+                startValue: PhysicalValue::decode(exi.payload.startValue),
+                Cost: exi.get_costs(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ConsumptionCost, AfbError> {
+            let mut exi = ConsumptionCost::new(self.startValue);
+            for cost in self.Cost {
+                exi.add_cost(cost)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ConsumptionCost, ConsumptionCostXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SalesTariffEntryXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        RelativeTimeInterval: Option<RelativeTimeInterval>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        TimeInterval: Option<TimeInterval>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EPriceLevel: Option<u8>,
+        ConsumptionCost: Vec<ConsumptionCost>,
+    }
+
+    impl SalesTariffEntryXsd {
+        fn from_exi(exi: &SaleTariffEntry) -> Result<Self, AfbError> {
+            let mut costs = Vec::new();
+            for idx in 0..exi.payload.ConsumptionCost.arrayLen as usize {
+                costs.push(ConsumptionCost::decode(
+                    exi.payload.ConsumptionCost.array[idx],
+                ));
+            }
+            Ok(Self {
+                RelativeTimeInterval: exi.get_relative_time(),
+                TimeInterval: exi.get_time(),
+                EPriceLevel: exi.get_price_level(),
+                ConsumptionCost: costs,
+            })
+        }
+
+        fn into_exi(self) -> Result<SaleTariffEntry, AfbError> {
+            let mut exi = SaleTariffEntry::new();
+            if let Some(value) = &self.RelativeTimeInterval {
+                exi.set_relative_time(value);
+            }
+            if let Some(value) = &self.TimeInterval {
+                exi.set_time(value);
+            }
+            if let Some(value) = self.EPriceLevel {
+                exi.set_price_level(value);
+            }
+            for cost in self.ConsumptionCost {
+                exi.add_comsumption_cost(cost)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SaleTariffEntry, SalesTariffEntryXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SalesTariffXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        Id: Option<String>,
+        SalesTariffID: u8,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SalesTariffDescription: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        NumEPriceLevels: Option<u8>,
+        SalesTariffEntry: Vec<SaleTariffEntry>,
+    }
+
+    impl SalesTariffXsd {
+        fn from_exi(exi: &SalesTariff) -> Result<Self, AfbError> {
+            let payload = &exi.payload;
+            let id = if payload.Id_isUsed() == 0 {
+                None
+            } else {
+                Some(array_to_str(&payload.Id.characters, payload.Id.charactersLen)?.to_string())
+            };
+            let description = if payload.SalesTariffDescription_isUsed() == 0 {
+                None
+            } else {
+                Some(
+                    array_to_str(
+                        &payload.SalesTariffDescription.characters,
+                        payload.SalesTariffDescription.charactersLen,
+                    )?
+                    .to_string(),
+                )
+            };
+            Ok(Self {
+                Id: id,
+                SalesTariffID: exi.get_tariff_id(),
+                SalesTariffDescription: description,
+                NumEPriceLevels: exi.get_tariff_level(),
+                SalesTariffEntry: exi.get_entries(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SalesTariff, AfbError> {
+            let mut exi = SalesTariff::new(self.SalesTariffID);
+            if let Some(value) = &self.Id {
+                exi.set_id(value)?;
+                exi.payload.set_Id_isUsed(1);
+            }
+            if let Some(value) = &self.SalesTariffDescription {
+                exi.set_description(value)?;
+                exi.payload.set_SalesTariffDescription_isUsed(1);
+            }
+            if let Some(value) = self.NumEPriceLevels {
+                exi.set_tariff_level(value);
+            }
+            for entry in &self.SalesTariffEntry {
+                exi.add_entry(entry)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SalesTariff, SalesTariffXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PMaxScheduleXsd {
+        PMaxScheduleEntry: Vec<PMaxScheduleEntry>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SAScheduleTupleXsd {
+        SAScheduleTupleID: u8,
+        PMaxSchedule: PMaxScheduleXsd,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SalesTariff: Option<SalesTariff>,
+    }
+
+    impl SAScheduleTupleXsd {
+        fn from_exi(exi: &SasScheduleTuple) -> Result<Self, AfbError> {
+            Ok(Self {
+                SAScheduleTupleID: exi.get_description(),
+                PMaxSchedule: PMaxScheduleXsd {
+                    PMaxScheduleEntry: exi.get_pmaxs(),
+                },
+                SalesTariff: exi.get_tariff(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SasScheduleTuple, AfbError> {
+            let mut exi = SasScheduleTuple::new(self.SAScheduleTupleID);
+            for pmax in &self.PMaxSchedule.PMaxScheduleEntry {
+                exi.add_pmax(pmax)?;
+            }
+            if let Some(value) = &self.SalesTariff {
+                exi.set_tariff(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SasScheduleTuple, SAScheduleTupleXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvseChargeParamXsd {
+        AC_EVSEStatus: AcEvseStatusType,
+        EVSENominalVoltage: PhysicalValue,
+        EVSEMaxCurrent: PhysicalValue,
+    }
+
+    impl AcEvseChargeParamXsd {
+        fn from_exi(exi: &AcEvseChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                AC_EVSEStatus: exi.get_status(),
+                EVSENominalVoltage: exi.get_nominate_voltage(),
+                EVSEMaxCurrent: exi.get_max_current(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvseChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_AC_EVSEChargeParameterType>() };
+            payload.AC_EVSEStatus = self.AC_EVSEStatus.encode();
+            payload.EVSENominalVoltage = self.EVSENominalVoltage.encode();
+            payload.EVSEMaxCurrent = self.EVSEMaxCurrent.encode();
+            Ok(AcEvseChargeParam::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(AcEvseChargeParam, AcEvseChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvseChargeParamXsd {
+        DC_EVSEStatus: DcEvseStatusType,
+        EVSEMaximumCurrentLimit: PhysicalValue,
+        EVSEMaximumPowerLimit: PhysicalValue,
+        EVSEMaximumVoltageLimit: PhysicalValue,
+        EVSEMinimumCurrentLimit: PhysicalValue,
+        EVSEMinimumVoltageLimit: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSECurrentRegulationTolerance: Option<PhysicalValue>,
+        EVSEPeakCurrentRipple: PhysicalValue,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEEnergyToBeDelivered: Option<PhysicalValue>,
+    }
+
+    impl DcEvseChargeParamXsd {
+        fn from_exi(exi: &DcEvseChargeParam) -> Result<Self, AfbError> {
+            Ok(Self {
+                DC_EVSEStatus: exi.get_status(),
+                EVSEMaximumCurrentLimit: exi.get_max_current(),
+                EVSEMaximumPowerLimit: exi.get_max_power(),
+                EVSEMaximumVoltageLimit: exi.get_max_voltage(),
+                EVSEMinimumCurrentLimit: exi.get_min_current(),
+                EVSEMinimumVoltageLimit: exi.get_min_voltage(),
+                EVSECurrentRegulationTolerance: exi.get_regul_tolerance(),
+                EVSEPeakCurrentRipple: exi.get_peak_current_ripple(),
+                EVSEEnergyToBeDelivered: exi.get_energy_to_deliver(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvseChargeParam, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_DC_EVSEChargeParameterType>() };
+            payload.DC_EVSEStatus = self.DC_EVSEStatus.encode();
+            payload.EVSEMaximumCurrentLimit = self.EVSEMaximumCurrentLimit.encode();
+            payload.EVSEMaximumPowerLimit = self.EVSEMaximumPowerLimit.encode();
+            payload.EVSEMaximumVoltageLimit = self.EVSEMaximumVoltageLimit.encode();
+            payload.EVSEMinimumCurrentLimit = self.EVSEMinimumCurrentLimit.encode();
+            payload.EVSEMinimumVoltageLimit = self.EVSEMinimumVoltageLimit.encode();
+            payload.EVSEPeakCurrentRipple = self.EVSEPeakCurrentRipple.encode();
+            if let Some(value) = &self.EVSECurrentRegulationTolerance {
+                payload.EVSECurrentRegulationTolerance = value.encode();
+                payload.set_EVSECurrentRegulationTolerance_isUsed(1);
+            }
+            if let Some(value) = &self.EVSEEnergyToBeDelivered {
+                payload.EVSEEnergyToBeDelivered = value.encode();
+                payload.set_EVSEEnergyToBeDelivered_isUsed(1);
+            }
+            Ok(DcEvseChargeParam::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(DcEvseChargeParam, DcEvseChargeParamXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SAScheduleListXsd {
+        SAScheduleTuple: Vec<SasScheduleTuple>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargeParameterDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        EVSEProcessing: EvseProcessing,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SASchedules: Option<AbstractXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SAScheduleList: Option<SAScheduleListXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEChargeParameter: Option<AbstractXsd>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        AC_EVSEChargeParameter: Option<AcEvseChargeParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        DC_EVSEChargeParameter: Option<DcEvseChargeParam>,
+    }
+
+    impl ChargeParameterDiscoveryResXsd {
+        fn from_exi(exi: &ParamDiscoveryResponse) -> Result<Self, AfbError> {
+            let schedules = if exi.payload.SAScheduleList_isUsed() == 0 {
+                None
+            } else {
+                Some(SAScheduleListXsd {
+                    SAScheduleTuple: exi.get_schedule_tuples(),
+                })
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEProcessing: exi.get_processing()?,
+                SASchedules: exi.get_schedules().map(|_| AbstractXsd {}),
+                SAScheduleList: schedules,
+                EVSEChargeParameter: exi.get_evse_charge_param().map(|_| AbstractXsd {}),
+                AC_EVSEChargeParameter: exi.get_evse_ac_charge_param(),
+                DC_EVSEChargeParameter: exi.get_evse_dc_charge_param(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ParamDiscoveryResponse, AfbError> {
+            let mut exi = ParamDiscoveryResponse::new(self.ResponseCode, self.EVSEProcessing);
+            if self.SASchedules.is_some() {
+                exi.payload.set_SASchedules_isUsed(1);
+            }
+            if let Some(schedules) = &self.SAScheduleList {
+                for tuple in &schedules.SAScheduleTuple {
+                    exi.add_schedule_tuple(tuple)?;
+                }
+                exi.payload.set_SAScheduleList_isUsed(1);
+            }
+            if self.EVSEChargeParameter.is_some() {
+                exi.payload.set_EVSEChargeParameter_isUsed(1);
+            }
+            if let Some(value) = &self.AC_EVSEChargeParameter {
+                exi.set_evse_ac_charge_param(value);
+            }
+            if let Some(value) = &self.DC_EVSEChargeParameter {
+                exi.set_evse_dc_charge_param(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ParamDiscoveryResponse, ChargeParameterDiscoveryResXsd);
+}
@@ -88,7 +88,7 @@ impl PaymentDetailsResponse {
         Ok(Self { payload })
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -124,3 +124,73 @@ impl PaymentDetailsResponse {
     }
 
 }
+
+impl ExiFields for PaymentDetailsRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("eMAID", self.get_emaid())
+            .field("ContractSignatureCertChain", self.get_contract_chain());
+    }
+}
+
+impl ExiFields for PaymentDetailsResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("GenChallenge", ExiHexa(self.get_challenge()))
+            .field("EVSETimeStamp", self.get_time_stamp());
+    }
+}
+crate::exi_format!(PaymentDetailsRequest, PaymentDetailsResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentDetailsReqXsd {
+        eMAID: String,
+        ContractSignatureCertChain: CertificateChainType,
+    }
+
+    impl PaymentDetailsReqXsd {
+        fn from_exi(exi: &PaymentDetailsRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                eMAID: exi.get_emaid()?.to_string(),
+                ContractSignatureCertChain: exi.get_contract_chain(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentDetailsRequest, AfbError> {
+            PaymentDetailsRequest::new(&self.eMAID, &self.ContractSignatureCertChain)
+        }
+    }
+    crate::exi_serde_mirror!(PaymentDetailsRequest, PaymentDetailsReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentDetailsResXsd {
+        ResponseCode: ResponseCode,
+        GenChallenge: ExiBytes,
+        EVSETimeStamp: i64,
+    }
+
+    impl PaymentDetailsResXsd {
+        fn from_exi(exi: &PaymentDetailsResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                GenChallenge: ExiBytes::from(exi.get_challenge()),
+                EVSETimeStamp: exi.get_time_stamp(),
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentDetailsResponse, AfbError> {
+            let mut exi =
+                PaymentDetailsResponse::new(self.ResponseCode, self.GenChallenge.as_slice())?;
+            exi.set_timestamp(self.EVSETimeStamp);
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PaymentDetailsResponse, PaymentDetailsResXsd);
+}
@@ -68,7 +68,7 @@ impl PaymentSelectionRequest {
         Self { payload }
     }
 
-    pub fn get_option(&self) -> PaymentOption {
+    pub fn get_option(&self) -> Result<PaymentOption, AfbError> {
         PaymentOption::from_u32(self.payload.SelectedPaymentOption)
     }
 
@@ -119,7 +119,7 @@ impl PaymentSelectionResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -137,3 +137,111 @@ impl PaymentSelectionResponse {
         body
     }
 }
+
+impl ExiFields for SelectedService {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceID", self.get_service_id())
+            .option("ParameterSetID", self.get_param_id());
+    }
+}
+
+impl ExiFields for PaymentSelectionRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("SelectedPaymentOption", self.get_option())
+            .field("SelectedServiceList", ExiList(self.get_services()));
+    }
+}
+
+impl ExiFields for PaymentSelectionResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ResponseCode", self.get_rcode());
+    }
+}
+crate::exi_format!(
+    SelectedService,
+    PaymentSelectionRequest,
+    PaymentSelectionResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SelectedServiceXsd {
+        ServiceID: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ParameterSetID: Option<i16>,
+    }
+
+    impl SelectedServiceXsd {
+        fn from_exi(exi: &SelectedService) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_service_id(),
+                ParameterSetID: exi.get_param_id(),
+            })
+        }
+
+        fn into_exi(self) -> Result<SelectedService, AfbError> {
+            let mut exi = SelectedService::new(self.ServiceID);
+            if let Some(value) = self.ParameterSetID {
+                exi.set_param_id(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SelectedService, SelectedServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SelectedServiceListXsd {
+        SelectedService: Vec<SelectedService>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentServiceSelectionReqXsd {
+        SelectedPaymentOption: PaymentOption,
+        SelectedServiceList: SelectedServiceListXsd,
+    }
+
+    impl PaymentServiceSelectionReqXsd {
+        fn from_exi(exi: &PaymentSelectionRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                SelectedPaymentOption: exi.get_option()?,
+                SelectedServiceList: SelectedServiceListXsd {
+                    SelectedService: exi.get_services(),
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentSelectionRequest, AfbError> {
+            let mut exi = PaymentSelectionRequest::new(self.SelectedPaymentOption);
+            for service in &self.SelectedServiceList.SelectedService {
+                exi.add_service(service)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(PaymentSelectionRequest, PaymentServiceSelectionReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentServiceSelectionResXsd {
+        ResponseCode: ResponseCode,
+    }
+
+    impl PaymentServiceSelectionResXsd {
+        fn from_exi(exi: &PaymentSelectionResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<PaymentSelectionResponse, AfbError> {
+            Ok(PaymentSelectionResponse::new(self.ResponseCode))
+        }
+    }
+    crate::exi_serde_mirror!(PaymentSelectionResponse, PaymentServiceSelectionResXsd);
+}
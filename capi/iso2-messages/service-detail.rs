@@ -65,7 +65,7 @@ impl ServiceDetailResponse {
         self.payload.ServiceID
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -106,3 +106,88 @@ impl ServiceDetailResponse {
         body
     }
 }
+
+impl ExiFields for ServiceDetailRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("ServiceID", self.get_id());
+    }
+}
+
+impl ExiFields for ServiceDetailResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .field("ServiceID", self.get_id());
+        if self.payload.ServiceParameterList_isUsed() != 0 {
+            fields.field("ServiceParameterList", ExiList(self.get_psets()));
+        }
+    }
+}
+crate::exi_format!(ServiceDetailRequest, ServiceDetailResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDetailReqXsd {
+        ServiceID: u16,
+    }
+
+    impl ServiceDetailReqXsd {
+        fn from_exi(exi: &ServiceDetailRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_id(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDetailRequest, AfbError> {
+            Ok(ServiceDetailRequest::new(self.ServiceID))
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDetailRequest, ServiceDetailReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceParameterListXsd {
+        ParameterSet: Vec<ParamSet>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDetailResXsd {
+        ResponseCode: ResponseCode,
+        ServiceID: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceParameterList: Option<ServiceParameterListXsd>,
+    }
+
+    impl ServiceDetailResXsd {
+        fn from_exi(exi: &ServiceDetailResponse) -> Result<Self, AfbError> {
+            let psets = if exi.payload.ServiceParameterList_isUsed() == 0 {
+                None
+            } else {
+                Some(ServiceParameterListXsd {
+                    ParameterSet: exi.get_psets(),
+                })
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                ServiceID: exi.get_id(),
+                ServiceParameterList: psets,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDetailResponse, AfbError> {
+            let mut exi = ServiceDetailResponse::new(self.ServiceID, self.ResponseCode);
+            if let Some(psets) = &self.ServiceParameterList {
+                for pset in &psets.ParameterSet {
+                    exi.add_pset(pset)?;
+                }
+                exi.payload.set_ServiceParameterList_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDetailResponse, ServiceDetailResXsd);
+}
@@ -40,7 +40,7 @@ impl ServiceOther {
         self.payload.ServiceID
     }
 
-    pub fn get_category(&self) -> ServiceCategory {
+    pub fn get_category(&self) -> Result<ServiceCategory, AfbError> {
         ServiceCategory::from_u32(self.payload.ServiceCategory)
     }
 
@@ -241,13 +241,13 @@ impl ServiceDiscoveryRequest {
         self
     }
 
-    pub fn get_category(&self) -> Option<ServiceCategory> {
+    pub fn get_category(&self) -> Result<Option<ServiceCategory>, AfbError> {
         let category = if self.payload.ServiceCategory_isUsed() == 0 {
             None
         } else {
-            Some(ServiceCategory::from_u32(self.payload.ServiceCategory))
+            Some(ServiceCategory::from_u32(self.payload.ServiceCategory)?)
         };
-        category
+        Ok(category)
     }
 
     pub fn decode(payload: cglue::iso2_ServiceDiscoveryReqType) -> Self {
@@ -276,7 +276,7 @@ impl ServiceDiscoveryResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -309,7 +309,7 @@ impl ServiceDiscoveryResponse {
             .EnergyTransferMode;
 
         for idx in 0..transfer_mode.arrayLen {
-            let transfer = EngyTransfertMode::from_u32(transfer_mode.array[idx as usize]);
+            let transfer = EngyTransfertMode::from_u32(transfer_mode.array[idx as usize])?;
             response.push(transfer.clone())
         }
         Ok(response)
@@ -339,15 +339,15 @@ impl ServiceDiscoveryResponse {
         Ok(self)
     }
 
-    pub fn get_payments(&self) -> Vec<PaymentOption> {
+    pub fn get_payments(&self) -> Result<Vec<PaymentOption>, AfbError> {
         let mut payments = Vec::new();
         for idx in 0..self.payload.PaymentOptionList.PaymentOption.arrayLen {
             let payment = PaymentOption::from_u32(
                 self.payload.PaymentOptionList.PaymentOption.array[idx as usize],
-            );
+            )?;
             payments.push(payment);
         }
-        payments
+        Ok(payments)
     }
 
     pub fn add_service(&mut self, service: &ServiceOther) -> Result<&mut Self, AfbError> {
@@ -390,3 +390,257 @@ impl ServiceDiscoveryResponse {
         body
     }
 }
+
+impl ExiFields for ServiceOther {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("ServiceID", self.get_id())
+            .option("ServiceName", self.get_name())
+            .result("ServiceCategory", self.get_category())
+            .option("ServiceScope", self.get_scope())
+            .field("FreeService", self.get_isfree());
+    }
+}
+
+impl ExiFields for ServiceCharging {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        let transfer_mode = &self.payload.SupportedEnergyTransferMode.EnergyTransferMode;
+        let transfers: Result<Vec<EngyTransfertMode>, AfbError> = (0..transfer_mode.arrayLen)
+            .map(|idx| EngyTransfertMode::from_u32(transfer_mode.array[idx as usize]))
+            .collect();
+        fields
+            .field("ServiceID", self.get_id())
+            .option("ServiceName", self.get_name())
+            .result(
+                "ServiceCategory",
+                ServiceCategory::from_u32(self.payload.ServiceCategory),
+            )
+            .option("ServiceScope", self.get_scope())
+            .field("FreeService", self.get_isfree())
+            .result("SupportedEnergyTransferMode", transfers.map(ExiList));
+    }
+}
+
+impl ExiFields for ServiceDiscoveryRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .option("ServiceScope", self.get_scope())
+            .result_option("ServiceCategory", self.get_category());
+    }
+}
+
+impl ExiFields for ServiceDiscoveryResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("PaymentOptionList", self.get_payments().map(ExiList))
+            .field(
+                "ChargeService",
+                ServiceCharging::decode(self.payload.ChargeService),
+            );
+        if self.payload.ServiceList_isUsed() != 0 {
+            fields.result("ServiceList", self.get_services().map(ExiList));
+        }
+    }
+}
+crate::exi_format!(
+    ServiceOther,
+    ServiceCharging,
+    ServiceDiscoveryRequest,
+    ServiceDiscoveryResponse
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceXsd {
+        ServiceID: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceName: Option<String>,
+        ServiceCategory: ServiceCategory,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceScope: Option<String>,
+        FreeService: bool,
+    }
+
+    impl ServiceXsd {
+        fn from_exi(exi: &ServiceOther) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceID: exi.get_id(),
+                ServiceName: exi.get_name().map(|value| value.to_string()),
+                ServiceCategory: exi.get_category()?,
+                ServiceScope: exi.get_scope().map(|value| value.to_string()),
+                FreeService: exi.get_isfree(),
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceOther, AfbError> {
+            let mut exi = ServiceOther::new(self.ServiceID, self.ServiceCategory, self.FreeService);
+            if let Some(value) = &self.ServiceName {
+                exi.set_name(value)?;
+            }
+            if let Some(value) = &self.ServiceScope {
+                exi.set_scope(value)?;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceOther, ServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SupportedEnergyTransferModeXsd {
+        EnergyTransferMode: Vec<EngyTransfertMode>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ChargeServiceXsd {
+        ServiceID: u16,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceName: Option<String>,
+        ServiceCategory: ServiceCategory,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceScope: Option<String>,
+        FreeService: bool,
+        SupportedEnergyTransferMode: SupportedEnergyTransferModeXsd,
+    }
+
+    impl ChargeServiceXsd {
+        fn from_exi(exi: &ServiceCharging) -> Result<Self, AfbError> {
+            let transfer_mode = &exi.payload.SupportedEnergyTransferMode.EnergyTransferMode;
+            let mut transfers = Vec::new();
+            for idx in 0..transfer_mode.arrayLen {
+                transfers.push(EngyTransfertMode::from_u32(
+                    transfer_mode.array[idx as usize],
+                )?);
+            }
+            Ok(Self {
+                ServiceID: exi.get_id(),
+                ServiceName: exi.get_name().map(|value| value.to_string()),
+                ServiceCategory: ServiceCategory::from_u32(exi.payload.ServiceCategory)?,
+                ServiceScope: exi.get_scope().map(|value| value.to_string()),
+                FreeService: exi.get_isfree(),
+                SupportedEnergyTransferMode: SupportedEnergyTransferModeXsd {
+                    EnergyTransferMode: transfers,
+                },
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceCharging, AfbError> {
+            let mut exi = ServiceCharging::new(self.ServiceID, self.FreeService);
+            exi.payload.ServiceCategory = self.ServiceCategory as u32;
+            if let Some(value) = &self.ServiceName {
+                exi.set_name(value)?;
+            }
+            if let Some(value) = &self.ServiceScope {
+                exi.set_scope(value)?;
+            }
+            let transfer_mode = &mut exi.payload.SupportedEnergyTransferMode.EnergyTransferMode;
+            for transfer in &self.SupportedEnergyTransferMode.EnergyTransferMode {
+                let idx = transfer_mode.arrayLen;
+                if idx == cglue::iso2_EnergyTransferModeType_6_ARRAY_SIZE as u16 {
+                    return afb_error!(
+                        "iso2-charge-service",
+                        "fail to add energy transfert mode (array full)"
+                    );
+                }
+                transfer_mode.array[idx as usize] = *transfer as u32;
+                transfer_mode.arrayLen = idx + 1;
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceCharging, ChargeServiceXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryReqXsd {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceScope: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceCategory: Option<ServiceCategory>,
+    }
+
+    impl ServiceDiscoveryReqXsd {
+        fn from_exi(exi: &ServiceDiscoveryRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ServiceScope: exi.get_scope().map(|value| value.to_string()),
+                ServiceCategory: exi.get_category()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryRequest, AfbError> {
+            let mut exi = ServiceDiscoveryRequest::new();
+            if let Some(value) = &self.ServiceScope {
+                exi.payload.ServiceScope.charactersLen = str_to_array(
+                    value,
+                    &mut exi.payload.ServiceScope.characters,
+                    cglue::iso2_ServiceScope_CHARACTER_SIZE,
+                )?;
+                exi.payload.set_ServiceScope_isUsed(1);
+            }
+            if let Some(value) = self.ServiceCategory {
+                exi.set_category(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryRequest, ServiceDiscoveryReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct PaymentOptionListXsd {
+        PaymentOption: Vec<PaymentOption>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceListXsd {
+        Service: Vec<ServiceOther>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ServiceDiscoveryResXsd {
+        ResponseCode: ResponseCode,
+        PaymentOptionList: PaymentOptionListXsd,
+        ChargeService: ServiceCharging,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ServiceList: Option<ServiceListXsd>,
+    }
+
+    impl ServiceDiscoveryResXsd {
+        fn from_exi(exi: &ServiceDiscoveryResponse) -> Result<Self, AfbError> {
+            let services = if exi.payload.ServiceList_isUsed() == 0 {
+                None
+            } else {
+                Some(ServiceListXsd {
+                    Service: exi.get_services()?,
+                })
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                PaymentOptionList: PaymentOptionListXsd {
+                    PaymentOption: exi.get_payments()?,
+                },
+                ChargeService: ServiceCharging::decode(exi.payload.ChargeService),
+                ServiceList: services,
+            })
+        }
+
+        fn into_exi(self) -> Result<ServiceDiscoveryResponse, AfbError> {
+            let mut exi = ServiceDiscoveryResponse::new(self.ResponseCode);
+            for payment in &self.PaymentOptionList.PaymentOption {
+                exi.add_payment(*payment)?;
+            }
+            exi.payload.ChargeService = self.ChargeService.encode();
+            if let Some(services) = &self.ServiceList {
+                for service in &services.Service {
+                    exi.add_service(service)?;
+                }
+                exi.payload.set_ServiceList_isUsed(1);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(ServiceDiscoveryResponse, ServiceDiscoveryResXsd);
+}
@@ -107,7 +107,7 @@ impl SessionSetupResponse {
         )
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -136,3 +136,91 @@ impl SessionSetupResponse {
     }
 
 }
+
+impl ExiFields for SessionSetupRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.field("EVCCID", ExiHexa(self.get_id()));
+    }
+}
+
+impl ExiFields for SessionSetupResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .result("ResponseCode", self.get_rcode())
+            .result("EVSEID", self.get_id());
+        if self.payload.EVSETimeStamp_isUsed() != 0 {
+            fields.field("EVSETimeStamp", self.get_time_stamp());
+        }
+    }
+}
+crate::exi_format!(SessionSetupRequest, SessionSetupResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupReqXsd {
+        EVCCID: ExiBytes,
+    }
+
+    impl SessionSetupReqXsd {
+        fn from_exi(exi: &SessionSetupRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVCCID: ExiBytes::from(exi.get_id()),
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionSetupRequest, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_SessionSetupReqType>() };
+            payload.EVCCID.bytesLen = bytes_to_array(
+                self.EVCCID.as_slice(),
+                &mut payload.EVCCID.bytes,
+                cglue::iso2_evccIDType_BYTES_SIZE,
+            )?;
+            Ok(SessionSetupRequest::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupRequest, SessionSetupReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionSetupResXsd {
+        ResponseCode: ResponseCode,
+        EVSEID: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSETimeStamp: Option<i64>,
+    }
+
+    impl SessionSetupResXsd {
+        fn from_exi(exi: &SessionSetupResponse) -> Result<Self, AfbError> {
+            let timestamp = if exi.payload.EVSETimeStamp_isUsed() == 0 {
+                None
+            } else {
+                Some(exi.payload.EVSETimeStamp)
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                EVSEID: exi.get_id()?.to_string(),
+                EVSETimeStamp: timestamp,
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionSetupResponse, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::iso2_SessionSetupResType>() };
+            payload.ResponseCode = self.ResponseCode as u32;
+            payload.EVSEID.charactersLen = str_to_array(
+                &self.EVSEID,
+                &mut payload.EVSEID.characters,
+                cglue::iso2_EVSEID_CHARACTER_SIZE,
+            )?;
+            if let Some(timestamp) = self.EVSETimeStamp {
+                payload.EVSETimeStamp = timestamp;
+                payload.set_EVSETimeStamp_isUsed(1);
+            }
+            Ok(SessionSetupResponse::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(SessionSetupResponse, SessionSetupResXsd);
+}
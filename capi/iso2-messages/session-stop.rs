@@ -30,7 +30,7 @@ impl SessionStopRequest {
         SessionStopRequest { payload }
     }
 
-    pub fn get_action (&self) -> ChargingSessionType {
+    pub fn get_action(&self) -> Result<ChargingSessionType, AfbError> {
         ChargingSessionType::from_u32(self.payload.ChargingSession)
     }
 
@@ -61,7 +61,7 @@ impl SessionStopResponse {
         Self { payload }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
@@ -80,3 +80,59 @@ impl SessionStopResponse {
     }
 
 }
+
+impl ExiFields for SessionStopRequest {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ChargingSession", self.get_action());
+    }
+}
+
+impl ExiFields for SessionStopResponse {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields.result("ResponseCode", self.get_rcode());
+    }
+}
+crate::exi_format!(SessionStopRequest, SessionStopResponse);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopReqXsd {
+        ChargingSession: ChargingSessionType,
+    }
+
+    impl SessionStopReqXsd {
+        fn from_exi(exi: &SessionStopRequest) -> Result<Self, AfbError> {
+            Ok(Self {
+                ChargingSession: exi.get_action()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionStopRequest, AfbError> {
+            Ok(SessionStopRequest::new(self.ChargingSession))
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopRequest, SessionStopReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SessionStopResXsd {
+        ResponseCode: ResponseCode,
+    }
+
+    impl SessionStopResXsd {
+        fn from_exi(exi: &SessionStopResponse) -> Result<Self, AfbError> {
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<SessionStopResponse, AfbError> {
+            Ok(SessionStopResponse::new(self.ResponseCode))
+        }
+    }
+    crate::exi_serde_mirror!(SessionStopResponse, SessionStopResXsd);
+}
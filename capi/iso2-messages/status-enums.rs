@@ -18,11 +18,11 @@
 use std::convert::AsRef;
 use std::mem;
 use std::str::FromStr;
-use strum_macros::{AsRefStr, Display, EnumString};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
 
 use super::*;
 
-#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 #[allow(dead_code)]
@@ -65,9 +65,12 @@ pub enum MessageTagId {
     Unsupported,
 }
 
+crate::exi_enum_try_from!(MessageTagId, u32);
+
 impl MessageTagId {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
 
     #[track_caller]
@@ -114,7 +117,7 @@ impl MessageTagId {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum CostKind {
@@ -122,9 +125,18 @@ pub enum CostKind {
     RenewGenPercent = cglue::iso2_costKindType_iso2_costKindType_RenewableGenerationPercentage,
     CarbonEmission = cglue::iso2_costKindType_iso2_costKindType_CarbonDioxideEmission,
 }
+crate::exi_enum_try_from!(CostKind, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(CostKind, {
+    PricePercent => "relativePricePercentage",
+    RenewGenPercent => "RenewableGenerationPercentage",
+    CarbonEmission => "CarbonDioxideEmission",
+});
+
 impl CostKind {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
 
     #[track_caller]
@@ -147,7 +159,7 @@ impl CostKind {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 #[allow(dead_code)]
@@ -194,9 +206,46 @@ pub enum ResponseCode {
         cglue::iso2_responseCodeType_iso2_responseCodeType_FAILED_CertificateRevoked,
 }
 
+crate::exi_enum_try_from!(ResponseCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ResponseCode, {
+    Ok => "OK",
+    Failed => "FAILED",
+    NewSession => "OK_NewSessionEstablished",
+    OldSessionJoin => "OK_OldSessionJoined",
+    CertificateExpiresSoon => "OK_CertificateExpiresSoon",
+    SequenceError => "FAILED_SequenceError",
+    ServiceIDInvalid => "FAILED_ServiceIDInvalid",
+    UnknownSession => "FAILED_UnknownSession",
+    ServiceSelectionInvalid => "FAILED_ServiceSelectionInvalid",
+    PaymentSelectionInvalid => "FAILED_PaymentSelectionInvalid",
+    CertificateExpired => "FAILED_CertificateExpired",
+    SignatureError => "FAILED_SignatureError",
+    NoCertificateAvailable => "FAILED_NoCertificateAvailable",
+    CertChainError => "FAILED_CertChainError",
+    ChallengeInvalid => "FAILED_ChallengeInvalid",
+    ContractCanceled => "FAILED_ContractCanceled",
+    WrongChargeParameter => "FAILED_WrongChargeParameter",
+    PowerDeliveryNotApplied => "FAILED_PowerDeliveryNotApplied",
+    TariffSelectionInvalid => "FAILED_TariffSelectionInvalid",
+    ChargingProfileInvalid => "FAILED_ChargingProfileInvalid",
+    MeteringSignatureNotValid => "FAILED_MeteringSignatureNotValid",
+    NoChargeServiceSelected => "FAILED_NoChargeServiceSelected",
+    WrongEnergyTransferMode => "FAILED_WrongEnergyTransferMode",
+    ContactorError => "FAILED_ContactorError",
+    CertificateNotAllowedAtThisEVSE => "FAILED_CertificateNotAllowedAtThisEVSE",
+    CertificateRevoked => "FAILED_CertificateRevoked",
+});
+
 impl ResponseCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
+    }
+
+    // any FAILED_xxx response code terminates the communication session
+    pub fn is_failed(&self) -> bool {
+        *self as u32 >= ResponseCode::Failed as u32
     }
 
     #[track_caller]
@@ -214,7 +263,7 @@ impl ResponseCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum ServiceCategory {
@@ -224,9 +273,19 @@ pub enum ServiceCategory {
     Other = cglue::iso2_serviceCategoryType_iso2_serviceCategoryType_OtherCustom,
 }
 
+crate::exi_enum_try_from!(ServiceCategory, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ServiceCategory, {
+    EvCharger => "EVCharging",
+    Internet => "Internet",
+    Certificate => "ContractCertificate",
+    Other => "OtherCustom",
+});
+
 impl ServiceCategory {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -243,7 +302,7 @@ impl ServiceCategory {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -252,9 +311,17 @@ pub enum PaymentOption {
     External = cglue::iso2_paymentOptionType_iso2_paymentOptionType_ExternalPayment,
 }
 
+crate::exi_enum_try_from!(PaymentOption, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(PaymentOption, {
+    Contract => "Contract",
+    External => "ExternalPayment",
+});
+
 impl PaymentOption {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -271,7 +338,7 @@ impl PaymentOption {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -280,9 +347,17 @@ pub enum ChargingSessionType {
     Pause = cglue::iso2_chargingSessionType_iso2_chargingSessionType_Pause,
 }
 
+crate::exi_enum_try_from!(ChargingSessionType, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ChargingSessionType, {
+    Terminate => "Terminate",
+    Pause => "Pause",
+});
+
 impl ChargingSessionType {
-    pub fn from_u32(value: u32) -> Self {
-        unsafe { std::mem::transmute(value) }
+    #[track_caller]
+    pub fn from_u32(value: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(value)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -300,7 +375,7 @@ impl ChargingSessionType {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum ChargeProgress {
@@ -308,9 +383,18 @@ pub enum ChargeProgress {
     Stop = cglue::iso2_chargeProgressType_iso2_chargeProgressType_Stop,
     Renegotiate = cglue::iso2_chargeProgressType_iso2_chargeProgressType_Renegotiate,
 }
+crate::exi_enum_try_from!(ChargeProgress, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ChargeProgress, {
+    Start => "Start",
+    Stop => "Stop",
+    Renegotiate => "Renegotiate",
+});
+
 impl ChargeProgress {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -328,7 +412,7 @@ impl ChargeProgress {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum EvseProcessing {
@@ -336,9 +420,18 @@ pub enum EvseProcessing {
     Ongoing = cglue::iso2_EVSEProcessingType_iso2_EVSEProcessingType_Ongoing,
     CustomerInteraction = cglue::iso2_EVSEProcessingType_iso2_EVSEProcessingType_Ongoing_WaitingForCustomerInteraction,
 }
+crate::exi_enum_try_from!(EvseProcessing, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseProcessing, {
+    Finished => "Finished",
+    Ongoing => "Ongoing",
+    CustomerInteraction => "Ongoing_WaitingForCustomerInteraction",
+});
+
 impl EvseProcessing {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -356,7 +449,7 @@ impl EvseProcessing {
 }
 
 #[allow(dead_code)]
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum DcEvErrorCode {
@@ -379,9 +472,27 @@ pub enum DcEvErrorCode {
         cglue::iso2_DC_EVErrorCodeType_iso2_DC_EVErrorCodeType_FAILED_ChargingSystemIncompatibility,
     FailCodeNoData = cglue::iso2_DC_EVErrorCodeType_iso2_DC_EVErrorCodeType_NoData,
 }
+crate::exi_enum_try_from!(DcEvErrorCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(DcEvErrorCode, {
+    NoError => "NO_ERROR",
+    FailRessTempInhibit => "FAILED_RESSTemperatureInhibit",
+    FailEvShiftPos => "FAILED_EVShiftPosition",
+    FailChargeConnectLock => "FAILED_ChargerConnectorLockFault",
+    FailEvresFault => "FAILED_EVRESSMalfunction",
+    FailCurrentDifferential => "FAILED_ChargingCurrentdifferential",
+    FailVoltOutOfRange => "FAILED_ChargingVoltageOutOfRange",
+    FailReserveA => "Reserved_A",
+    FailReserveB => "Reserved_B",
+    FailReserveC => "Reserved_C",
+    FailIncompatible => "FAILED_ChargingSystemIncompatibility",
+    FailCodeNoData => "NoData",
+});
+
 impl DcEvErrorCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -398,7 +509,7 @@ impl DcEvErrorCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -412,9 +523,21 @@ pub enum EngyTransfertMode {
     DcCombo = cglue::iso2_EnergyTransferModeType_iso2_EnergyTransferModeType_DC_combo_core,
     DcUnique = cglue::iso2_EnergyTransferModeType_iso2_EnergyTransferModeType_DC_unique,
 }
+crate::exi_enum_try_from!(EngyTransfertMode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EngyTransfertMode, {
+    AcSinglePhase => "AC_single_phase_core",
+    AcThreePhase => "AC_three_phase_core",
+    DcBasic => "DC_core",
+    DcExtended => "DC_extended",
+    DcCombo => "DC_combo_core",
+    DcUnique => "DC_unique",
+});
+
 impl EngyTransfertMode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -435,7 +558,7 @@ impl EngyTransfertMode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -445,9 +568,18 @@ pub enum EvseNotification {
     StopCharging = cglue::iso2_EVSENotificationType_iso2_EVSENotificationType_StopCharging,
     ReNegotiation = cglue::iso2_EVSENotificationType_iso2_EVSENotificationType_ReNegotiation,
 }
+crate::exi_enum_try_from!(EvseNotification, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(EvseNotification, {
+    None => "None",
+    StopCharging => "StopCharging",
+    ReNegotiation => "ReNegotiation",
+});
+
 impl EvseNotification {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -464,7 +596,7 @@ impl EvseNotification {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -475,9 +607,20 @@ pub enum IsolationStatus {
     Fault = cglue::iso2_isolationLevelType_iso2_isolationLevelType_Fault,
     NoImd = cglue::iso2_isolationLevelType_iso2_isolationLevelType_No_IMD,
 }
+crate::exi_enum_try_from!(IsolationStatus, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(IsolationStatus, {
+    Invalid => "Invalid",
+    Valid => "Valid",
+    Warning => "Warning",
+    Fault => "Fault",
+    NoImd => "No_IMD",
+});
+
 impl IsolationStatus {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -494,7 +637,7 @@ impl IsolationStatus {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -516,9 +659,27 @@ pub enum DcEvseErrorCode {
     ReserveC = cglue::iso2_DC_EVSEStatusCodeType_iso2_DC_EVSEStatusCodeType_Reserved_C,
 }
 
+crate::exi_enum_try_from!(DcEvseErrorCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(DcEvseErrorCode, {
+    NotReady => "EVSE_NotReady",
+    Ready => "EVSE_Ready",
+    Shutdown => "EVSE_Shutdown",
+    UtilInteruptEvt => "EVSE_UtilityInterruptEvent",
+    MonitoringActive => "EVSE_IsolationMonitoringActive",
+    EmergencyShutdown => "EVSE_EmergencyShutdown",
+    EvseMalfunction => "EVSE_Malfunction",
+    Reserve8 => "Reserved_8",
+    Reserve9 => "Reserved_9",
+    ReserveA => "Reserved_A",
+    ReserveB => "Reserved_B",
+    ReserveC => "Reserved_C",
+});
+
 impl DcEvseErrorCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -539,7 +700,7 @@ impl DcEvseErrorCode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[allow(dead_code)]
 #[repr(u32)]
@@ -552,9 +713,22 @@ pub enum PhysicalUnit {
     Watt = cglue::iso2_unitSymbolType_iso2_unitSymbolType_W,
     Wh = cglue::iso2_unitSymbolType_iso2_unitSymbolType_Wh,
 }
+crate::exi_enum_try_from!(PhysicalUnit, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(PhysicalUnit, {
+    Hour => "h",
+    Minute => "m",
+    Second => "s",
+    Ampere => "A",
+    Volt => "V",
+    Watt => "W",
+    Wh => "Wh",
+});
+
 impl PhysicalUnit {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -569,6 +743,19 @@ impl PhysicalUnit {
     pub fn to_label(&self) -> &str {
         self.as_ref()
     }
+
+    /// SI symbol used by PhysicalValue Display, ex: "400 V"
+    pub fn to_symbol(&self) -> &'static str {
+        match self {
+            PhysicalUnit::Hour => "h",
+            PhysicalUnit::Minute => "min",
+            PhysicalUnit::Second => "s",
+            PhysicalUnit::Ampere => "A",
+            PhysicalUnit::Volt => "V",
+            PhysicalUnit::Watt => "W",
+            PhysicalUnit::Wh => "Wh",
+        }
+    }
 }
 
 pub struct DcEvseStatusType {
@@ -584,11 +771,11 @@ impl DcEvseStatusType {
         Self { payload }
     }
 
-    pub fn get_error(&self) -> DcEvseErrorCode {
+    pub fn get_error(&self) -> Result<DcEvseErrorCode, AfbError> {
         DcEvseErrorCode::from_u32(self.payload.EVSEStatusCode)
     }
 
-    pub fn get_notification(&self) -> EvseNotification {
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
         EvseNotification::from_u32(self.payload.EVSENotification)
     }
 
@@ -602,11 +789,11 @@ impl DcEvseStatusType {
         self
     }
 
-    pub fn get_isolation_status(&self) -> Option<IsolationStatus> {
+    pub fn get_isolation_status(&self) -> Result<Option<IsolationStatus>, AfbError> {
         if self.payload.EVSEIsolationStatus_isUsed() == 0 {
-            None
+            Ok(None)
         } else {
-            Some(IsolationStatus::from_u32(self.payload.EVSEIsolationStatus))
+            Ok(Some(IsolationStatus::from_u32(self.payload.EVSEIsolationStatus)?))
         }
     }
 
@@ -653,7 +840,7 @@ impl DcEvStatusType {
         }
     }
 
-    pub fn get_error(&self) -> DcEvErrorCode {
+    pub fn get_error(&self) -> Result<DcEvErrorCode, AfbError> {
         DcEvErrorCode::from_u32(self.payload.EVErrorCode)
     }
 
@@ -679,7 +866,7 @@ impl AcEvseStatusType {
         Self { payload }
     }
 
-    pub fn get_notification(&self) -> EvseNotification {
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
         EvseNotification::from_u32(self.payload.EVSENotification)
     }
 
@@ -725,7 +912,7 @@ impl EvseStatusType {
         Self { payload }
     }
 
-    pub fn get_notification(&self) -> EvseNotification {
+    pub fn get_notification(&self) -> Result<EvseNotification, AfbError> {
         EvseNotification::from_u32(self.payload.EVSENotification)
     }
 
@@ -750,3 +937,168 @@ impl EvseStatusType {
         self.payload
     }
 }
+
+impl ExiFields for DcEvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification())
+            .result_option("EVSEIsolationStatus", self.get_isolation_status())
+            .result("DC_EVSEStatusCode", self.get_error());
+    }
+}
+
+impl ExiFields for DcEvStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("EVReady", self.get_ready())
+            .result("EVErrorCode", self.get_error())
+            .field("EVRESSSOC", self.get_evresssoc());
+    }
+}
+
+impl ExiFields for AcEvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification())
+            .field("RCD", self.get_rcd());
+    }
+}
+
+impl ExiFields for EvseStatusType {
+    fn exi_fields(&self, fields: &mut ExiFormatter) {
+        fields
+            .field("NotificationMaxDelay", self.get_delay())
+            .result("EVSENotification", self.get_notification())
+            .field("AC_EVSEStatus", self.get_ac_status())
+            .field("DC_EVSEStatus", self.get_dc_status());
+    }
+}
+crate::exi_format!(
+    DcEvseStatusType,
+    DcEvStatusType,
+    AcEvseStatusType,
+    EvseStatusType
+);
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvseStatusXsd {
+        NotificationMaxDelay: u16,
+        EVSENotification: EvseNotification,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        EVSEIsolationStatus: Option<IsolationStatus>,
+        DC_EVSEStatusCode: DcEvseErrorCode,
+    }
+
+    impl DcEvseStatusXsd {
+        fn from_exi(exi: &DcEvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+                EVSEIsolationStatus: exi.get_isolation_status()?,
+                DC_EVSEStatusCode: exi.get_error()?,
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvseStatusType, AfbError> {
+            let mut exi = DcEvseStatusType::new(
+                self.DC_EVSEStatusCode,
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+            );
+            if let Some(value) = self.EVSEIsolationStatus {
+                exi.set_isolation_status(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(DcEvseStatusType, DcEvseStatusXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct DcEvStatusXsd {
+        EVReady: bool,
+        EVErrorCode: DcEvErrorCode,
+        EVRESSSOC: i8,
+    }
+
+    impl DcEvStatusXsd {
+        fn from_exi(exi: &DcEvStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                EVReady: exi.get_ready(),
+                EVErrorCode: exi.get_error()?,
+                EVRESSSOC: exi.get_evresssoc(),
+            })
+        }
+
+        fn into_exi(self) -> Result<DcEvStatusType, AfbError> {
+            Ok(DcEvStatusType::new(
+                self.EVReady,
+                self.EVErrorCode,
+                self.EVRESSSOC,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(DcEvStatusType, DcEvStatusXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct AcEvseStatusXsd {
+        NotificationMaxDelay: u16,
+        EVSENotification: EvseNotification,
+        RCD: bool,
+    }
+
+    impl AcEvseStatusXsd {
+        fn from_exi(exi: &AcEvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+                RCD: exi.get_rcd(),
+            })
+        }
+
+        fn into_exi(self) -> Result<AcEvseStatusType, AfbError> {
+            Ok(AcEvseStatusType::new(
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+                self.RCD,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(AcEvseStatusType, AcEvseStatusXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct EvseStatusXsd {
+        NotificationMaxDelay: u16,
+        EVSENotification: EvseNotification,
+        AC_EVSEStatus: AcEvseStatusType,
+        DC_EVSEStatus: DcEvseStatusType,
+    }
+
+    impl EvseStatusXsd {
+        fn from_exi(exi: &EvseStatusType) -> Result<Self, AfbError> {
+            Ok(Self {
+                NotificationMaxDelay: exi.get_delay(),
+                EVSENotification: exi.get_notification()?,
+                AC_EVSEStatus: exi.get_ac_status(),
+                DC_EVSEStatus: exi.get_dc_status(),
+            })
+        }
+
+        fn into_exi(self) -> Result<EvseStatusType, AfbError> {
+            Ok(EvseStatusType::new(
+                self.EVSENotification,
+                self.NotificationMaxDelay,
+                &self.AC_EVSEStatus,
+                &self.DC_EVSEStatus,
+            ))
+        }
+    }
+    crate::exi_serde_mirror!(EvseStatusType, EvseStatusXsd);
+}
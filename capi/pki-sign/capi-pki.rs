@@ -24,7 +24,7 @@ use core::mem;
  *
  */
 use ::std::os::raw;
-use afbv4::utilv4::*;
+use crate::afb::*;
 use cglue::gnutls_privkey_init;
 use std::ffi::CStr;
 use std::ffi::CString;
@@ -36,7 +36,7 @@ pub mod cglue {
     #![allow(non_upper_case_globals)]
     #![allow(non_camel_case_types)]
     #![allow(non_snake_case)]
-    include!("_pki-capi.rs");
+    include!(concat!(env!("OUT_DIR"), "/_pki-capi.rs"));
 }
 
 // fn load_buffer(filename: &str) -> Result<Vec<u8>, AfbError> {
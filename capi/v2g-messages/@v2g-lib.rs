@@ -23,6 +23,9 @@ mod status_enums;
 #[path = "proto-apphand.rs"]
 mod app_protocol;
 
+#[path = "app-negotiator.rs"]
+mod app_negotiator;
+
 #[path = "sdp-discovery.rs"]
 mod sdp;
 
@@ -33,11 +36,12 @@ pub(self) mod cglue {
         #![allow(non_snake_case)]
         // force reuse of C bitstream from exi-encoder
         use crate::prelude::exi_bitstream_t;
-        include!("_v2g-capi.rs");
+        include!(concat!(env!("OUT_DIR"), "/_v2g-capi.rs"));
 }
 
 pub mod v2g {
     pub use super::status_enums::*;
     pub use super::app_protocol::*;
+    pub use super::app_negotiator::*;
     pub use super::sdp::*;
 }
@@ -0,0 +1,198 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §8.3.3 [V2G2-172] to [V2G2-180] (supportedAppProtocol handshake)
+ */
+
+use super::app_protocol::*;
+use super::status_enums::*;
+
+/// Why an EV proposal was or was not retained by the negotiator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProtocolVerdict {
+    Selected,
+    Unsupported,
+    Disabled,
+    MajorMismatch,
+    LowerPriority,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProtocolCandidate {
+    pub name: String,
+    pub major: u32,
+    pub minor: u32,
+    pub schema_id: u8,
+    pub priority: u8,
+    pub verdict: ProtocolVerdict,
+}
+
+#[derive(Clone, Copy)]
+struct ProtocolPreference {
+    conf: SupportedAppProtocolConf,
+    enabled: bool,
+}
+
+/// EVSE side protocol configuration, list order defines EVSE preference
+pub struct AppProtocolNegotiator {
+    protocols: Vec<ProtocolPreference>,
+}
+
+impl AppProtocolNegotiator {
+    pub fn new(protocols: &[&SupportedAppProtocolConf]) -> Self {
+        let protocols = protocols
+            .iter()
+            .map(|conf| ProtocolPreference {
+                conf: **conf,
+                enabled: true,
+            })
+            .collect();
+        Self { protocols }
+    }
+
+    pub fn add_protocol(&mut self, protocol: &SupportedAppProtocolConf) -> &mut Self {
+        self.protocols.push(ProtocolPreference {
+            conf: *protocol,
+            enabled: true,
+        });
+        self
+    }
+
+    pub fn set_enable(&mut self, tag_id: ProtocolTagId, enabled: bool) -> &mut Self {
+        for protocol in self.protocols.iter_mut() {
+            if protocol.conf.tag_id == tag_id {
+                protocol.enabled = enabled;
+            }
+        }
+        self
+    }
+
+    pub fn get_protocols(&self) -> Vec<(SupportedAppProtocolConf, bool)> {
+        self.protocols
+            .iter()
+            .map(|protocol| (protocol.conf, protocol.enabled))
+            .collect()
+    }
+
+    /// Select the EV highest priority protocol also supported by the EVSE.
+    /// EVSE preference only breaks ties between proposals with the same EV priority.
+    pub fn negotiate(&self, request: &SupportedAppProtocolReq) -> AppProtocolNegotiation {
+        let mut candidates = Vec::new();
+        let mut selected: Option<(usize, usize)> = None;
+
+        for proposal in request.get_protocols() {
+            let name = proposal.get_name().unwrap_or("").to_string();
+            let mut verdict = ProtocolVerdict::Unsupported;
+            let mut preference = None;
+
+            for (idx, protocol) in self.protocols.iter().enumerate() {
+                if protocol.conf.name != name {
+                    continue;
+                }
+                if !protocol.enabled {
+                    verdict = ProtocolVerdict::Disabled;
+                } else if protocol.conf.major != proposal.get_major() {
+                    verdict = ProtocolVerdict::MajorMismatch;
+                } else {
+                    verdict = ProtocolVerdict::LowerPriority;
+                    preference = Some(idx);
+                    break;
+                }
+            }
+
+            // proposals are sorted by EV priority, lower value wins
+            if let Some(idx) = preference {
+                let better = match selected {
+                    None => true,
+                    Some((current, pref)) => {
+                        proposal.get_priority() < candidates[current].priority
+                            || (proposal.get_priority() == candidates[current].priority
+                                && idx < pref)
+                    }
+                };
+                if better {
+                    selected = Some((candidates.len(), idx));
+                }
+            }
+
+            candidates.push(ProtocolCandidate {
+                name,
+                major: proposal.get_major(),
+                minor: proposal.get_minor(),
+                schema_id: proposal.get_schema(),
+                priority: proposal.get_priority(),
+                verdict,
+            });
+        }
+
+        let selected = match selected {
+            Some((candidate, idx)) => {
+                candidates[candidate].verdict = ProtocolVerdict::Selected;
+                Some((candidate, self.protocols[idx].conf))
+            }
+            None => None,
+        };
+
+        AppProtocolNegotiation {
+            candidates,
+            selected,
+        }
+    }
+}
+
+pub struct AppProtocolNegotiation {
+    candidates: Vec<ProtocolCandidate>,
+    selected: Option<(usize, SupportedAppProtocolConf)>,
+}
+
+impl AppProtocolNegotiation {
+    pub fn get_selected(&self) -> Option<&SupportedAppProtocolConf> {
+        self.selected.as_ref().map(|(_, conf)| conf)
+    }
+
+    pub fn get_candidates(&self) -> &[ProtocolCandidate] {
+        &self.candidates
+    }
+
+    pub fn get_rcode(&self) -> ResponseCode {
+        match &self.selected {
+            None => ResponseCode::Failed,
+            Some((idx, conf)) => {
+                if self.candidates[*idx].minor == conf.minor {
+                    ResponseCode::Success
+                } else {
+                    ResponseCode::SuccessWithMinorDeviation
+                }
+            }
+        }
+    }
+
+    // response uses the schema id provided by the EV for the selected protocol
+    pub fn get_schema(&self) -> Option<u8> {
+        self.selected
+            .as_ref()
+            .map(|(idx, _)| self.candidates[*idx].schema_id)
+    }
+
+    pub fn get_response(&self) -> SupportedAppProtocolRes {
+        let mut response = SupportedAppProtocolRes::new(self.get_rcode(), 0);
+        if let Some(schema_id) = self.get_schema() {
+            response.set_schema(schema_id);
+        }
+        response
+    }
+}
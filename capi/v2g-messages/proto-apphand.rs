@@ -40,7 +40,8 @@ pub fn v2gtp_header_check(type_id: PayloadMsgId, buffer: Pin<&[u8]>) -> Result<u
     Ok(payload_size + cglue::V2GTP_HEADER_LENGTH)
 }
 
-pub fn v2gtp_get_payload_id(buffer: Pin<&[u8]>) -> PayloadMsgId {
+#[track_caller]
+pub fn v2gtp_get_payload_id(buffer: Pin<&[u8]>) -> Result<PayloadMsgId, AfbError> {
     let payload_id = unsafe { cglue::V2GTP20_GetPayloadId(buffer.as_ptr()) };
     PayloadMsgId::from_u16(payload_id)
 }
@@ -83,11 +84,20 @@ pub struct AppHandAppProtocolType {
     payload: cglue::appHand_AppProtocolType,
 }
 
+// appHand schema priority range, 1 is the highest priority
+pub const APP_PROTOCOL_PRIORITY_MAX: u8 = 20;
+
 impl AppHandAppProtocolType {
-    fn new(
-        protocol: &SupportedAppProtocolConf,
-        priority: u8,
-    ) -> Result<Self, AfbError> {
+    #[track_caller]
+    pub fn new(protocol: &SupportedAppProtocolConf, priority: u8) -> Result<Self, AfbError> {
+        if priority < 1 || priority > APP_PROTOCOL_PRIORITY_MAX {
+            return afb_error!(
+                "app-protocol-type",
+                "invalid priority:{} (1..{})",
+                priority,
+                APP_PROTOCOL_PRIORITY_MAX
+            );
+        }
         let mut payload = unsafe { mem::zeroed::<cglue::appHand_AppProtocolType>() };
         payload.ProtocolNamespace.charactersLen = str_to_array(
             protocol.name,
@@ -117,6 +127,12 @@ impl AppHandAppProtocolType {
         self.payload.VersionNumberMinor
     }
 
+    // default schema id is the protocol tag id
+    pub fn set_schema(&mut self, schema_id: u8) -> &mut Self {
+        self.payload.SchemaID = schema_id;
+        self
+    }
+
     pub fn get_schema(&self) -> u8 {
         self.payload.SchemaID
     }
@@ -175,17 +191,70 @@ impl SupportedAppProtocolReq {
         body
     }
 
+    // request without protocol, entries are added with push_protocol
+    pub fn empty() -> Self {
+        let payload = unsafe { mem::zeroed::<cglue::appHand_supportedAppProtocolReq>() };
+        Self { payload }
+    }
+
+    // every entry gets priority 1, kept for requests matching legacy EV traces
+    #[deprecated(note = "entries share priority 1, use add_protocol_priority")]
+    #[track_caller]
     pub fn add_protocol(
         &mut self,
         protocol: &SupportedAppProtocolConf,
     ) -> Result<&mut Self, AfbError> {
         let idx = self.payload.AppProtocol.arrayLen;
+        if idx == cglue::appHand_AppProtocolType_5_ARRAY_SIZE as u16 {
+            return afb_error!("app-protocol-req", "fail to add protocol (array full)");
+        }
         self.payload.AppProtocol.array[idx as usize] =
             AppHandAppProtocolType::new(protocol, 1)?.encode();
-        self.payload.AppProtocol.arrayLen += 1;
+        self.payload.AppProtocol.arrayLen = idx + 1;
+        Ok(self)
+    }
+
+    #[track_caller]
+    pub fn add_protocol_priority(
+        &mut self,
+        protocol: &SupportedAppProtocolConf,
+        priority: u8,
+    ) -> Result<&mut Self, AfbError> {
+        self.push_protocol(&AppHandAppProtocolType::new(protocol, priority)?)
+    }
+
+    /// Add an entry with explicit priority and schema id, both should be unique within the request
+    #[track_caller]
+    pub fn push_protocol(
+        &mut self,
+        protocol: &AppHandAppProtocolType,
+    ) -> Result<&mut Self, AfbError> {
+        let idx = self.payload.AppProtocol.arrayLen;
+        if idx == cglue::appHand_AppProtocolType_5_ARRAY_SIZE as u16 {
+            return afb_error!("app-protocol-req", "fail to push protocol (array full)");
+        }
+        for entry in &self.payload.AppProtocol.array[0..idx as usize] {
+            if entry.Priority == protocol.get_priority() {
+                return afb_error!(
+                    "app-protocol-req",
+                    "priority:{} already used",
+                    protocol.get_priority()
+                );
+            }
+            if entry.SchemaID == protocol.get_schema() {
+                return afb_error!(
+                    "app-protocol-req",
+                    "schema_id:{} already used",
+                    protocol.get_schema()
+                );
+            }
+        }
+        self.payload.AppProtocol.array[idx as usize] = protocol.encode();
+        self.payload.AppProtocol.arrayLen = idx + 1;
         Ok(self)
     }
 
+    /// Return EV proposals in priority order, highest priority (lowest value) first
     pub fn get_protocols(&self) -> Vec<AppHandAppProtocolType> {
         let mut response = Vec::new();
         for idx in 0..self.payload.AppProtocol.arrayLen {
@@ -249,10 +318,17 @@ impl SupportedAppProtocolRes {
         }
     }
 
-    pub fn get_rcode(&self) -> ResponseCode {
+    pub fn get_rcode(&self) -> Result<ResponseCode, AfbError> {
         ResponseCode::from_u32(self.payload.ResponseCode)
     }
 
+    // schema 0 is a valid id (iso-20), new() omits it while set_schema always emits it
+    pub fn set_schema(&mut self, schema_id: u8) -> &mut Self {
+        self.payload.SchemaID = schema_id;
+        self.payload.set_SchemaID_isUsed(1);
+        self
+    }
+
     pub fn get_schema(&self) -> u8 {
         if self.payload.SchemaID_isUsed() != 0 {
             self.payload.SchemaID
@@ -279,18 +355,21 @@ impl SupportedAppProtocolRes {
 
 impl fmt::Debug for SupportedAppProtocolRes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "rcode:{} schema:{}",
-            self.get_rcode().to_label(),
-            self.get_schema()
-        )?;
+        let rcode = match self.get_rcode() {
+            Ok(rcode) => rcode.to_label().to_string(),
+            Err(_) => format!("invalid({})", self.payload.ResponseCode),
+        };
+        write!(f, "rcode:{} schema:{}", rcode, self.get_schema())?;
         Ok(())
     }
 }
 
+// serde uses XSD document element names as variant tags
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V2gMsgBody {
+    #[cfg_attr(feature = "serde", serde(rename = "supportedAppProtocolReq"))]
     Request(SupportedAppProtocolReq),
+    #[cfg_attr(feature = "serde", serde(rename = "supportedAppProtocolRes"))]
     Response(SupportedAppProtocolRes),
 }
 
@@ -298,17 +377,26 @@ pub enum V2gMsgBody {
 pub struct SupportedAppProtocolExi {}
 impl SupportedAppProtocolExi {
     #[track_caller]
-    pub fn decode_from_stream(locked: &RawStream) -> Result<V2gMsgBody, AfbError> {
+    pub fn decode_from_stream(locked: &mut RawStream) -> Result<V2gMsgBody, AfbError> {
+        Ok(Self::try_decode_from_stream(locked)?)
+    }
+
+    /// same as decode_from_stream with typed error (exi code & stream position)
+    pub fn try_decode_from_stream(locked: &mut RawStream) -> Result<V2gMsgBody, Iso15118Error> {
         let body = unsafe {
             let mut exi_raw = mem::MaybeUninit::<cglue::appHand_exiDocument>::uninit();
-            let status = cglue::decode_appHand_exiDocument(locked.stream, exi_raw.as_mut_ptr());
-            let exi_raw = exi_raw.assume_init();
+            let status =
+                cglue::decode_appHand_exiDocument(locked.as_mut_ptr(), exi_raw.as_mut_ptr());
             if status != 0 {
-                return afb_error!(
-                    "v2g-exi-decode",
-                    "fail to decode v2g (AppProtocolExi) from stream"
-                );
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::AppHand,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
             }
+            let exi_raw = exi_raw.assume_init();
             if exi_raw.supportedAppProtocolReq_isUsed() != 0 {
                 V2gMsgBody::Request(SupportedAppProtocolReq::decode(
                     exi_raw.__bindgen_anon_1.supportedAppProtocolReq,
@@ -318,7 +406,14 @@ impl SupportedAppProtocolExi {
                     exi_raw.__bindgen_anon_1.supportedAppProtocolRes,
                 ))
             } else {
-                return afb_error!("v2g-exi-decode", "hoops nether request or response");
+                // nether request or response
+                return Err(Iso15118Error::exi(
+                    ExiProtocol::AppHand,
+                    ExiOperation::Decode,
+                    status,
+                    locked,
+                    None,
+                ));
             }
         };
         Ok(body)
@@ -329,29 +424,38 @@ impl SupportedAppProtocolExi {
         locked: &mut RawStream,
         v2g_body: &V2gAppHandDoc,
     ) -> Result<(), AfbError> {
+        Ok(Self::try_encode_to_stream(locked, v2g_body)?)
+    }
+
+    /// same as encode_to_stream with typed error (exi code, stream position & message tag)
+    pub fn try_encode_to_stream(
+        locked: &mut RawStream,
+        v2g_body: &V2gAppHandDoc,
+    ) -> Result<(), Iso15118Error> {
         locked.reset(); // cleanup stream before encoding
 
         // reserve space for v2g header
-        match unsafe { locked.stream.as_mut() } {
-            Some(data) => {
-                data.byte_pos = cglue::SDP_V2G_HEADER_LEN as usize;
-            }
-            None => {
-                return afb_error!(
-                    "v2g-encode_stream",
-                    "fail to get locked.stream (invalid stream)"
-                )
-            }
-        }
+        locked.set_window(EXI_MAX_DOCUMENT_SIZE, cglue::SDP_V2G_HEADER_LEN as usize);
 
         let status = unsafe {
             cglue::encode_appHand_exiDocument(
-                locked.stream,
+                locked.as_mut_ptr(),
                 v2g_body as *const _ as *mut V2gAppHandDoc,
             )
         };
         if status < 0 {
-            return afb_error!("v2g-encode_stream", "fail to encode V2gAppHandDoc to exi");
+            let tag = if v2g_body.supportedAppProtocolReq_isUsed() != 0 {
+                "supported_app_protocol_req"
+            } else {
+                "supported_app_protocol_res"
+            };
+            return Err(Iso15118Error::exi(
+                ExiProtocol::AppHand,
+                ExiOperation::Encode,
+                status,
+                locked,
+                Some(tag),
+            ));
         }
 
         // retrieve document encoded size from stream
@@ -368,3 +472,115 @@ impl SupportedAppProtocolExi {
         Ok(())
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_xsd {
+    #![allow(non_snake_case)]
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct AppProtocolXsd {
+        ProtocolNamespace: String,
+        VersionNumberMajor: u32,
+        VersionNumberMinor: u32,
+        SchemaID: u8,
+        Priority: u8,
+    }
+
+    impl AppProtocolXsd {
+        fn from_exi(exi: &AppHandAppProtocolType) -> Result<Self, AfbError> {
+            Ok(Self {
+                ProtocolNamespace: exi.get_name()?.to_string(),
+                VersionNumberMajor: exi.get_major(),
+                VersionNumberMinor: exi.get_minor(),
+                SchemaID: exi.get_schema(),
+                Priority: exi.get_priority(),
+            })
+        }
+
+        // fill payload directly, new() only accepts statically configured protocols
+        fn into_exi(self) -> Result<AppHandAppProtocolType, AfbError> {
+            let mut payload = unsafe { mem::zeroed::<cglue::appHand_AppProtocolType>() };
+            payload.ProtocolNamespace.charactersLen = str_to_array(
+                &self.ProtocolNamespace,
+                &mut payload.ProtocolNamespace.characters,
+                cglue::appHand_ProtocolNamespace_CHARACTER_SIZE,
+            )?;
+            payload.VersionNumberMajor = self.VersionNumberMajor;
+            payload.VersionNumberMinor = self.VersionNumberMinor;
+            payload.SchemaID = self.SchemaID;
+            payload.Priority = self.Priority;
+            Ok(AppHandAppProtocolType::decode(payload))
+        }
+    }
+    crate::exi_serde_mirror!(AppHandAppProtocolType, AppProtocolXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SupportedAppProtocolReqXsd {
+        AppProtocol: Vec<AppHandAppProtocolType>,
+    }
+
+    impl SupportedAppProtocolReqXsd {
+        // keep received order, get_protocols() sorts entries by priority
+        fn from_exi(exi: &SupportedAppProtocolReq) -> Result<Self, AfbError> {
+            let mut protocols = Vec::new();
+            for idx in 0..exi.payload.AppProtocol.arrayLen as usize {
+                protocols.push(AppHandAppProtocolType::decode(
+                    exi.payload.AppProtocol.array[idx],
+                ));
+            }
+            Ok(Self {
+                AppProtocol: protocols,
+            })
+        }
+
+        fn into_exi(self) -> Result<SupportedAppProtocolReq, AfbError> {
+            if self.AppProtocol.len() > cglue::appHand_AppProtocolType_5_ARRAY_SIZE as usize {
+                return afb_error!(
+                    "app-protocol-req",
+                    "too many protocols:{} (max:{})",
+                    self.AppProtocol.len(),
+                    cglue::appHand_AppProtocolType_5_ARRAY_SIZE
+                );
+            }
+            let mut exi = SupportedAppProtocolReq::empty();
+            for (idx, protocol) in self.AppProtocol.iter().enumerate() {
+                exi.payload.AppProtocol.array[idx] = protocol.encode();
+            }
+            exi.payload.AppProtocol.arrayLen = self.AppProtocol.len() as u16;
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SupportedAppProtocolReq, SupportedAppProtocolReqXsd);
+
+    #[derive(Serialize, Deserialize)]
+    struct SupportedAppProtocolResXsd {
+        ResponseCode: ResponseCode,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        SchemaID: Option<u8>,
+    }
+
+    impl SupportedAppProtocolResXsd {
+        fn from_exi(exi: &SupportedAppProtocolRes) -> Result<Self, AfbError> {
+            let schema_id = if exi.payload.SchemaID_isUsed() == 0 {
+                None
+            } else {
+                Some(exi.payload.SchemaID)
+            };
+            Ok(Self {
+                ResponseCode: exi.get_rcode()?,
+                SchemaID: schema_id,
+            })
+        }
+
+        fn into_exi(self) -> Result<SupportedAppProtocolRes, AfbError> {
+            let mut exi = SupportedAppProtocolRes::new(self.ResponseCode, 0);
+            if let Some(value) = self.SchemaID {
+                exi.set_schema(value);
+            }
+            Ok(exi)
+        }
+    }
+    crate::exi_serde_mirror!(SupportedAppProtocolRes, SupportedAppProtocolResXsd);
+}
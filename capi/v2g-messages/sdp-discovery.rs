@@ -18,23 +18,26 @@
 
 use super::*;
 use std::mem;
+use strum_macros::EnumIter;
 
 pub const SDP_V2G_HEADER_LEN: usize = cglue::SDP_V2G_HEADER_LEN as usize;
 pub const V2GTP20_SAP_PAYLOAD_ID: u16 = cglue::V2GTP20_SAP_PAYLOAD_ID as u16;
 
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug, EnumIter)]
 #[repr(u8)]
 pub enum SdpSecurityModel {
     TLS = cglue::SDP_V2G_SECURITY_TLS,
     NONE = cglue::SDP_V2G_SECURITY_NONE,
 }
+crate::exi_enum_try_from!(SdpSecurityModel, u8);
 
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug, EnumIter)]
 #[repr(u8)]
 pub enum SdpTransportProtocol {
     TCP = cglue::SDP_V2G_TRANSPORT_TCP,
     UDP = cglue::SDP_V2G_TRANSPORT_UDP,
 }
+crate::exi_enum_try_from!(SdpTransportProtocol, u8);
 
 pub enum SdpMsgType {
     Request,
@@ -136,14 +139,14 @@ impl SdpRequest {
         Ok(self)
     }
 
-    pub fn get_transport(&self) -> SdpTransportProtocol {
-        let transport = unsafe { mem::transmute(self.payload.transport) };
-        transport
+    #[track_caller]
+    pub fn get_transport(&self) -> Result<SdpTransportProtocol, AfbError> {
+        Ok(SdpTransportProtocol::try_from(self.payload.transport)?)
     }
 
-    pub fn get_security(&self) -> SdpSecurityModel {
-        let transport = unsafe { mem::transmute(self.payload.security) };
-        transport
+    #[track_caller]
+    pub fn get_security(&self) -> Result<SdpSecurityModel, AfbError> {
+        Ok(SdpSecurityModel::try_from(self.payload.security)?)
     }
 }
 
@@ -244,14 +247,14 @@ impl SdpResponse {
         Ok(self)
     }
 
-    pub fn get_transport(&self) -> SdpTransportProtocol {
-        let transport = unsafe { mem::transmute(self.payload.transport) };
-        transport
+    #[track_caller]
+    pub fn get_transport(&self) -> Result<SdpTransportProtocol, AfbError> {
+        Ok(SdpTransportProtocol::try_from(self.payload.transport)?)
     }
 
-    pub fn get_security(&self) -> SdpSecurityModel {
-        let transport = unsafe { mem::transmute(self.payload.security) };
-        transport
+    #[track_caller]
+    pub fn get_security(&self) -> Result<SdpSecurityModel, AfbError> {
+        Ok(SdpSecurityModel::try_from(self.payload.security)?)
     }
 
     pub fn get_port(&self) -> u16 {
@@ -18,10 +18,9 @@
 use super::*;
 use std::convert::AsRef;
 use std::str::FromStr;
-use strum_macros::{Display, EnumString, AsRefStr};
-use std::mem;
+use strum_macros::{Display, EnumString, AsRefStr, EnumIter};
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, EnumIter)]
 #[allow(non_camel_case_types)]
 #[repr(u16)]
 pub enum PayloadMsgId {
@@ -42,12 +41,15 @@ pub enum PayloadMsgId {
     SDP_RESPONSE_WIRELESS = cglue::V2GTP20_SDP_RESPONSE_WIRELESS_PAYLOAD_ID as u16,
 }
 
+crate::exi_enum_try_from!(PayloadMsgId, u16);
+
 impl PayloadMsgId {
-    pub fn from_u16(code: u16) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u16(code: u16) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
 }
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr, Debug)]
+#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr, Debug, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u8)]
 pub enum ProtocolTagId {
@@ -61,9 +63,12 @@ const PROTO_DIN:&str = "urn:din:70121:2012:MsgDef";
 const PROTO_ISO2:&str =  "urn:iso:15118:2:2013:MsgDef";
 const PROTO_ISO20:&str = "urn:iso:15118:20:2018:MsgDef";
 
+crate::exi_enum_try_from!(ProtocolTagId, u8);
+
 impl ProtocolTagId {
-    pub fn from_u8(code: u8) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u8(code: u8) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
 
     #[track_caller]
@@ -90,21 +95,27 @@ impl ProtocolTagId {
         self.as_ref()
     }
 
-    pub fn to_jsonc(&self) -> Result<JsoncObj, AfbError> {
+    #[track_caller]
+    pub fn to_urn(&self) -> Result<&'static str, AfbError> {
         let proto= match self {
           ProtocolTagId::Din => PROTO_DIN,
           ProtocolTagId::Iso2 => PROTO_ISO2,
           ProtocolTagId::Iso20 => PROTO_ISO20,
-          _ => return afb_error!("to_jsonc", "invalid protocol")
+          _ => return afb_error!("to_urn", "invalid protocol")
         };
+        Ok(proto)
+    }
+
+    #[cfg(feature = "afbv4")]
+    pub fn to_jsonc(&self) -> Result<JsoncObj, AfbError> {
         let jsonc = JsoncObj::new();
         jsonc.add("id", self.to_label())?;
-        jsonc.add("urn", proto)?;
+        jsonc.add("urn", self.to_urn()?)?;
         Ok(jsonc)
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 pub enum ResponseCode {
@@ -113,9 +124,18 @@ pub enum ResponseCode {
     Failed = cglue::appHand_responseCodeType_appHand_responseCodeType_Failed_NoNegotiation,
 }
 
+crate::exi_enum_try_from!(ResponseCode, u32);
+#[cfg(feature = "serde")]
+crate::exi_enum_serde!(ResponseCode, {
+    Success => "OK_SuccessfulNegotiation",
+    SuccessWithMinorDeviation => "OK_SuccessfulNegotiationWithMinorDeviation",
+    Failed => "Failed_NoNegotiation",
+});
+
 impl ResponseCode {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
     #[track_caller]
     pub fn from_label(json: &str) -> Result<Self, AfbError> {
@@ -192,7 +212,7 @@ pub const V2G_PROTOCOLS_SUPPORTED_LIST: [&SupportedAppProtocolConf; 3] = [
     },
 ];
 
-#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr)]
+#[derive(Clone, Copy, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 #[repr(u32)]
 #[allow(dead_code)]
@@ -202,9 +222,12 @@ pub enum MessageTagId {
     Unsupported,
 }
 
+crate::exi_enum_try_from!(MessageTagId, u32);
+
 impl MessageTagId {
-    pub fn from_u32(code: u32) -> Self {
-        unsafe { mem::transmute(code) }
+    #[track_caller]
+    pub fn from_u32(code: u32) -> Result<Self, AfbError> {
+        Ok(Self::try_from(code)?)
     }
 
     #[track_caller]
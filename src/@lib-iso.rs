@@ -24,13 +24,24 @@
     html_favicon_url = "https://iot.bzh/images/defaults/favicon.ico"
 )]
 
-#[cfg(not(feature = "afbv4"))]
-#[cfg(not(feature = "afbmock"))]
+#[cfg(feature = "afbv4")]
 extern crate afbv4;
 
-#[cfg(feature = "afbmock")]
-#[path = "../afb-mock/error-log.rs"]
-mod afbv4;
+// redpesk binder integration, AfbError/logs are provided by afb-librust
+#[cfg(feature = "afbv4")]
+mod afb {
+    pub use afbv4::prelude::*;
+    pub type ExiError = AfbError;
+}
+
+// standalone build, native error and 'log' crate based logging
+#[cfg(not(feature = "afbv4"))]
+#[path = "afb-native.rs"]
+mod afb;
+
+#[cfg(not(feature = "afbv4"))]
+#[doc(hidden)]
+pub use log as __log;
 
 #[path = "../capi/@capi-lib.rs"]
 mod capi;
@@ -41,13 +52,76 @@ mod stream;
 #[path = "pki-sign.rs"]
 mod pki_sign;
 
-// Include either afbV4 or mock for log/error handling
+#[path = "controller.rs"]
+mod controller;
+
+#[path = "evcc-controller.rs"]
+mod evcc_controller;
+
+#[path = "din-controller.rs"]
+mod din_controller;
+
+#[path = "charging-session.rs"]
+mod charging_session;
+
+#[path = "v2gtp-framing.rs"]
+mod framing;
+
+#[path = "exi-codec.rs"]
+mod exi_codec;
+
+#[path = "v2g-document.rs"]
+mod v2g_document;
+
+#[path = "v2g-recorder.rs"]
+mod v2g_recorder;
+
+#[path = "v2g-replay.rs"]
+mod v2g_replay;
+
+#[cfg(feature = "xml")]
+#[path = "v2g-xml.rs"]
+mod v2g_xml;
+
+#[cfg(feature = "pcap")]
+#[path = "pcap-capture.rs"]
+mod pcap_capture;
+
+#[cfg(feature = "pcap")]
+#[path = "pcap-tls.rs"]
+mod pcap_tls;
+
+#[cfg(feature = "pcap")]
+#[path = "pcap-analyzer.rs"]
+mod pcap_analyzer;
+
+#[cfg(feature = "sdp-socket")]
+#[path = "sdp-socket.rs"]
+mod sdp_socket;
+
+// Include either afbV4 or native log/error handling
 pub mod prelude {
     pub use crate::capi::prelude::*;
     pub use crate::stream::*;
     pub use crate::pki_sign::*;
-    #[cfg(feature = "afbmock")]
-    pub use crate::afbv4::*;
-    #[cfg(not(feature = "afbmock"))]
-    pub use afbv4::prelude::*;
+    pub use crate::controller::*;
+    pub use crate::evcc_controller::*;
+    pub use crate::din_controller::*;
+    pub use crate::charging_session::*;
+    pub use crate::framing::*;
+    pub use crate::exi_codec::*;
+    pub use crate::v2g_document::*;
+    pub use crate::v2g_recorder::*;
+    pub use crate::v2g_replay::*;
+    #[cfg(feature = "xml")]
+    pub use crate::v2g_xml::*;
+    #[cfg(feature = "pcap")]
+    pub use crate::pcap_capture::*;
+    #[cfg(feature = "pcap")]
+    pub use crate::pcap_tls::*;
+    #[cfg(feature = "pcap")]
+    pub use crate::pcap_analyzer::*;
+    #[cfg(feature = "sdp-socket")]
+    pub use crate::sdp_socket::*;
+    pub use crate::afb::*;
 }
\ No newline at end of file
@@ -15,13 +15,14 @@
  * limitations under the License.
  *
  * Reference: https://github.com/redpesk-common/afb-librust
- * Object: mock log/error APIs
- *
+ * Object: native error/log used when crate is built without "afbv4" feature.
+ *  - errors keep afb-librust AfbError API (uid, info, caller location)
+ *  - afb_log_msg! is routed to the 'log' crate facade
  */
 use std::fmt;
 use std::panic::Location;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DbgInfo {
     pub name: &'static str,
     pub file: &'static str,
@@ -29,34 +30,52 @@ pub struct DbgInfo {
     pub column: u32,
 }
 
-pub struct AfbError {
+/// Native crate error, AfbError is kept as an alias for source compatibility with afbv4
+#[derive(Clone)]
+pub struct ExiError {
     uid: String,
     info: String,
     dbg_info: DbgInfo,
 }
 
-impl AfbError {
+pub type AfbError = ExiError;
+
+impl ExiError {
     #[track_caller]
-    pub fn new<T>(uid: &str, msg: T) -> AfbError
+    pub fn new<T>(uid: &str, msg: T) -> ExiError
     where
-        AfbError: MakeError<T>,
+        ExiError: MakeError<T>,
     {
         Self::make(uid, msg, Location::caller())
     }
+
+    pub fn get_uid(&self) -> &str {
+        &self.uid
+    }
+
+    pub fn get_info(&self) -> &str {
+        &self.info
+    }
+
+    pub fn get_dbg(&self) -> &DbgInfo {
+        &self.dbg_info
+    }
 }
 
-impl fmt::Display for AfbError {
+impl fmt::Display for ExiError {
     fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
         write!(format, "{}:{}\n{:?}", self.uid, self.info, self.dbg_info)
     }
 }
 
-impl fmt::Debug for AfbError {
+impl fmt::Debug for ExiError {
     fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
         write!(format, "{}:{}\n{:?}", self.uid, self.info, self.dbg_info)
     }
 }
 
+impl std::error::Error for ExiError {}
+
 #[derive(Debug)]
 pub enum AfbLogLevel {
     Error,
@@ -84,27 +103,18 @@ macro_rules! func_name {
 }
 
 pub trait MakeError<T> {
-    fn make(uid: &str, msg: T, location: &'static Location<'static>) -> AfbError;
+    fn make(uid: &str, msg: T, location: &'static Location<'static>) -> ExiError;
 }
 
-impl MakeError<&str> for AfbError {
-    fn make(uid: &str, msg: &str, caller: &'static Location<'static>) -> AfbError {
-        AfbError {
-            uid: uid.to_string(),
-            info: msg.to_string(),
-            dbg_info: DbgInfo {
-                name: func_name!(),
-                file: caller.file(),
-                line: caller.line(),
-                column: caller.column(),
-            },
-        }
+impl MakeError<&str> for ExiError {
+    fn make(uid: &str, msg: &str, caller: &'static Location<'static>) -> ExiError {
+        Self::make(uid, msg.to_string(), caller)
     }
 }
 
-impl MakeError<String> for AfbError {
-    fn make(uid: &str, msg: String, caller: &'static Location<'static>) -> AfbError {
-        AfbError {
+impl MakeError<String> for ExiError {
+    fn make(uid: &str, msg: String, caller: &'static Location<'static>) -> ExiError {
+        ExiError {
             uid: uid.to_string(),
             info: msg,
             dbg_info: DbgInfo {
@@ -132,29 +142,42 @@ macro_rules! afb_error {
  }
 }
 
+// map afb syslog levels onto log crate levels
+pub use crate::afb_log_level;
+#[doc(hidden)]
+#[macro_export]
+macro_rules! afb_log_level {
+    (Emergency) => {
+        $crate::__log::Level::Error
+    };
+    (Critical) => {
+        $crate::__log::Level::Error
+    };
+    (Error) => {
+        $crate::__log::Level::Error
+    };
+    (Warning) => {
+        $crate::__log::Level::Warn
+    };
+    (Notice) => {
+        $crate::__log::Level::Info
+    };
+    (Info) => {
+        $crate::__log::Level::Info
+    };
+    (Debug) => {
+        $crate::__log::Level::Debug
+    };
+}
+
+// handle is only meaningful for afb binder, it is ignored by native log
 pub use crate::afb_log_msg;
 #[macro_export]
 macro_rules! afb_log_msg {
  ( $level:tt, $handle:expr,$format:expr, $( $args:expr ),*) => {
-    let name= func_name!();
-    let file= file!();
-    let line= line!();
-    let column= column!();
-    let message= format! ($format, $($args),*);
-    println! ("{:?}:{} {}\n {}:{}:{}", AfbLogLevel::$level, name, message, file,line,column)
+    $crate::__log::log!($crate::afb_log_level!($level), $format, $($args),*)
  };
  ( $level:tt, $handle:expr,$format:expr) => {
-    let name= func_name!();
-    let file= file!();
-    let line= line!();
-    let column= column!();
-    println! ("{:?}:{} {}\n {}:{}:{}", AfbLogLevel::$level, name, $format, file,line,column)
-    }
+    $crate::__log::log!($crate::afb_log_level!($level), "{}", $format)
+ }
 }
-
-// ignore any AfbDataConverter! call
-pub use crate::AfbDataConverter;
-#[macro_export]
-macro_rules! AfbDataConverter{
-    ($handle:expr,$format:expr) => {}
-}
\ No newline at end of file
@@ -0,0 +1,1312 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: protocol neutral DC charging session model for DIN, ISO-2 and ISO-20
+ *  - EV requests (limits, targets, status, SoC, complete flags) are normalized with from_xxx()
+ *  - EVSE responses are written once and encoded for each protocol with to_xxx()
+ *  - ISO-20 carries no EV/EVSE DC status, EV is ready and EVSE status only holds notifications
+ *  - ChargingSession dispatches DC charging messages to a backend, others stay in controllers
+ *  - AC charging is out of scope, AC ParamDiscovery requests are left to controllers
+ *
+ * Reference:
+ *   DIN 70121:2014 §9.4 DC charging messages
+ *   ISO 15118-2:2014 §8.4.5 DC specific messages
+ *   ISO 15118-20:2022 §8.3.4.6 DC specific messages
+ */
+
+use crate::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingResponseCode {
+    Ok,
+    Failed,
+}
+
+impl ChargingResponseCode {
+    pub fn to_din(self) -> din_exi::ResponseCode {
+        match self {
+            ChargingResponseCode::Ok => din_exi::ResponseCode::Ok,
+            ChargingResponseCode::Failed => din_exi::ResponseCode::Failed,
+        }
+    }
+
+    pub fn to_iso2(self) -> iso2_exi::ResponseCode {
+        match self {
+            ChargingResponseCode::Ok => iso2_exi::ResponseCode::Ok,
+            ChargingResponseCode::Failed => iso2_exi::ResponseCode::Failed,
+        }
+    }
+
+    pub fn to_iso20(self) -> iso20_exi::ResponseCode {
+        match self {
+            ChargingResponseCode::Ok => iso20_exi::ResponseCode::Ok,
+            ChargingResponseCode::Failed => iso20_exi::ResponseCode::Failed,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingProcessing {
+    Finished,
+    Ongoing,
+}
+
+impl ChargingProcessing {
+    pub fn to_din(self) -> din_exi::EvseProcessing {
+        match self {
+            ChargingProcessing::Finished => din_exi::EvseProcessing::Finished,
+            ChargingProcessing::Ongoing => din_exi::EvseProcessing::Ongoing,
+        }
+    }
+
+    pub fn to_iso2(self) -> iso2_exi::EvseProcessing {
+        match self {
+            ChargingProcessing::Finished => iso2_exi::EvseProcessing::Finished,
+            ChargingProcessing::Ongoing => iso2_exi::EvseProcessing::Ongoing,
+        }
+    }
+
+    pub fn to_iso20(self) -> iso20_exi::EvseProcessing {
+        match self {
+            ChargingProcessing::Finished => iso20_exi::EvseProcessing::Finished,
+            ChargingProcessing::Ongoing => iso20_exi::EvseProcessing::Ongoing,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingProgress {
+    Start,
+    Stop,
+    Standby,
+    Renegotiate,
+}
+
+/// ISO-20 EV chooses between targets sent by the EV (scheduled) or by the EVSE (dynamic),
+/// with or without bidirectional power transfer. DIN and ISO-2 are always scheduled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingControlMode {
+    Scheduled,
+    Dynamic,
+    BptScheduled,
+    BptDynamic,
+}
+
+impl ChargingControlMode {
+    pub fn is_bpt(self) -> bool {
+        matches!(
+            self,
+            ChargingControlMode::BptScheduled | ChargingControlMode::BptDynamic
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingEvError {
+    NoError,
+    RessTemperatureInhibit,
+    ShiftPosition,
+    ConnectorLock,
+    RessFault,
+    CurrentDifferential,
+    VoltageOutOfRange,
+    Incompatible,
+    Unknown,
+}
+
+impl ChargingEvError {
+    pub fn from_din(code: din_exi::DcEvErrorCode) -> Self {
+        match code {
+            din_exi::DcEvErrorCode::NoError => ChargingEvError::NoError,
+            din_exi::DcEvErrorCode::FailRessTempInhibit => ChargingEvError::RessTemperatureInhibit,
+            din_exi::DcEvErrorCode::FailEvShiftPos => ChargingEvError::ShiftPosition,
+            din_exi::DcEvErrorCode::FailChargeConnectLock => ChargingEvError::ConnectorLock,
+            din_exi::DcEvErrorCode::FailEvresFault => ChargingEvError::RessFault,
+            din_exi::DcEvErrorCode::FailCurrentDifferential => ChargingEvError::CurrentDifferential,
+            din_exi::DcEvErrorCode::FailVoltOutOfRange => ChargingEvError::VoltageOutOfRange,
+            din_exi::DcEvErrorCode::FailIncompatible => ChargingEvError::Incompatible,
+            _ => ChargingEvError::Unknown,
+        }
+    }
+
+    pub fn from_iso2(code: iso2_exi::DcEvErrorCode) -> Self {
+        match code {
+            iso2_exi::DcEvErrorCode::NoError => ChargingEvError::NoError,
+            iso2_exi::DcEvErrorCode::FailRessTempInhibit => ChargingEvError::RessTemperatureInhibit,
+            iso2_exi::DcEvErrorCode::FailEvShiftPos => ChargingEvError::ShiftPosition,
+            iso2_exi::DcEvErrorCode::FailChargeConnectLock => ChargingEvError::ConnectorLock,
+            iso2_exi::DcEvErrorCode::FailEvresFault => ChargingEvError::RessFault,
+            iso2_exi::DcEvErrorCode::FailCurrentDifferential => {
+                ChargingEvError::CurrentDifferential
+            }
+            iso2_exi::DcEvErrorCode::FailVoltOutOfRange => ChargingEvError::VoltageOutOfRange,
+            iso2_exi::DcEvErrorCode::FailIncompatible => ChargingEvError::Incompatible,
+            _ => ChargingEvError::Unknown,
+        }
+    }
+}
+
+// out of range SoC (ex: 127 for unknown) is handled as not provided
+fn soc_from_wire(percent: Option<i8>) -> Option<Soc> {
+    percent.and_then(|percent| Soc::new(percent).ok())
+}
+
+// DIN/ISO-2/ISO-20 departure time is u32 seconds
+#[track_caller]
+fn duration_opt(secs: Option<u32>) -> Result<Option<Duration>, AfbError> {
+    match secs {
+        Some(secs) => Ok(Some(Duration::from_secs(secs)?)),
+        None => Ok(None),
+    }
+}
+
+#[track_caller]
+fn required_limit<Q: ExiQuantity>(limit: Option<Q>, label: &str) -> Result<Q, AfbError> {
+    match limit {
+        Some(value) => Ok(value),
+        None => afb_error!("charging-evse-limit", "missing {} limit", label),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvStatus {
+    pub ready: bool,
+    pub error: ChargingEvError,
+    pub soc: Option<Soc>,
+}
+
+impl ChargingEvStatus {
+    /// ISO-20 DC messages have no EV status, an EV still talking is ready
+    pub fn ready() -> Self {
+        Self {
+            ready: true,
+            error: ChargingEvError::NoError,
+            soc: None,
+        }
+    }
+
+    #[track_caller]
+    pub fn from_din(status: &din_exi::DcEvStatusType) -> Result<Self, AfbError> {
+        Ok(Self {
+            ready: status.get_ready(),
+            error: ChargingEvError::from_din(status.get_error()?),
+            soc: soc_from_wire(Some(status.get_evress_soc())),
+        })
+    }
+
+    #[track_caller]
+    pub fn from_iso2(status: &iso2_exi::DcEvStatusType) -> Result<Self, AfbError> {
+        Ok(Self {
+            ready: status.get_ready(),
+            error: ChargingEvError::from_iso2(status.get_error()?),
+            soc: soc_from_wire(Some(status.get_evresssoc())),
+        })
+    }
+}
+
+/// EV or EVSE limits, each protocol/message only fills what it transports
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChargingLimits {
+    pub max_voltage: Option<Voltage>,
+    pub min_voltage: Option<Voltage>,
+    pub max_current: Option<Current>,
+    pub min_current: Option<Current>,
+    pub max_power: Option<Power>,
+    pub min_power: Option<Power>,
+    /// ISO-20 BPT only, discharge current/power are positive values
+    pub max_discharge_current: Option<Current>,
+    pub min_discharge_current: Option<Current>,
+    pub max_discharge_power: Option<Power>,
+    pub min_discharge_power: Option<Power>,
+}
+
+// ISO-20 DC and BPT energy transfer modes share their charge getters
+macro_rules! iso20_charge_limits {
+    ($transfer:expr) => {
+        ChargingLimits {
+            max_voltage: Some($transfer.get_max_voltage_quantity()?),
+            min_voltage: Some($transfer.get_min_voltage_quantity()?),
+            max_current: Some($transfer.get_max_charge_current_quantity()?),
+            min_current: Some($transfer.get_min_charge_current_quantity()?),
+            max_power: Some($transfer.get_max_charge_power_quantity()?),
+            min_power: Some($transfer.get_min_charge_power_quantity()?),
+            ..Default::default()
+        }
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingParamDiscovery {
+    pub status: ChargingEvStatus,
+    pub limits: ChargingLimits,
+    pub energy_capacity: Option<Energy>,
+    pub energy_request: Option<Energy>,
+    pub departure_time: Option<Duration>,
+    pub target_soc: Option<Soc>,
+    pub bulk_soc: Option<Soc>,
+    /// ISO-20 BPT energy transfer, EVSE should answer with its discharge limits
+    pub bidirectional: bool,
+}
+
+impl ChargingParamDiscovery {
+    #[track_caller]
+    pub fn from_din(request: &din_exi::ParamDiscoveryRequest) -> Result<Self, AfbError> {
+        let param = match request.get_dc_charge_param() {
+            Some(value) => value,
+            None => return afb_error!("charging-param-discovery", "din request without DC param"),
+        };
+        Ok(Self {
+            status: ChargingEvStatus::from_din(&param.get_status())?,
+            limits: ChargingLimits {
+                max_voltage: Some(param.get_max_voltage_quantity()?),
+                max_current: Some(param.get_max_current_quantity()?),
+                max_power: param.get_max_power_quantity()?,
+                ..Default::default()
+            },
+            energy_capacity: param.get_energy_capacity_quantity()?,
+            energy_request: param.get_energy_request_quantity()?,
+            departure_time: None,
+            target_soc: soc_from_wire(param.get_full_soc()),
+            bulk_soc: soc_from_wire(param.get_bulk_soc()),
+            bidirectional: false,
+        })
+    }
+
+    /// DC only, AC or generic EV parameters are rejected
+    #[track_caller]
+    pub fn from_iso2(request: &iso2_exi::ParamDiscoveryRequest) -> Result<Self, AfbError> {
+        let param = match request.get_dc_charge_param() {
+            Some(value) => value,
+            None => return afb_error!("charging-param-discovery", "iso2 request without DC param"),
+        };
+        Ok(Self {
+            status: ChargingEvStatus::from_iso2(&param.get_status())?,
+            limits: ChargingLimits {
+                max_voltage: Some(param.get_max_voltage_quantity()?),
+                max_current: Some(param.get_max_current_quantity()?),
+                max_power: param.get_max_power_quantity()?,
+                ..Default::default()
+            },
+            energy_capacity: param.get_energy_capacity_quantity()?,
+            energy_request: param.get_energy_request_quantity()?,
+            departure_time: duration_opt(param.get_departure_time())?,
+            target_soc: soc_from_wire(param.get_full_soc()),
+            bulk_soc: soc_from_wire(param.get_bulk_soc()),
+            bidirectional: false,
+        })
+    }
+
+    #[track_caller]
+    pub fn from_iso20(
+        request: &iso20_dc_exi::DcChargeParamDiscoveryRequest,
+    ) -> Result<Self, AfbError> {
+        let mut discovery = Self {
+            status: ChargingEvStatus::ready(),
+            limits: ChargingLimits::default(),
+            energy_capacity: None,
+            energy_request: None,
+            departure_time: None,
+            target_soc: None,
+            bulk_soc: None,
+            bidirectional: false,
+        };
+        if let Some(transfer) = request.get_energy_transfer() {
+            discovery.limits = iso20_charge_limits!(transfer);
+            discovery.target_soc = soc_from_wire(transfer.get_target_soc());
+        } else if let Some(transfer) = request.get_bpt_energy_transfer() {
+            let limits = &mut discovery.limits;
+            *limits = iso20_charge_limits!(transfer);
+            limits.max_discharge_current = Some(transfer.get_max_discharge_current_quantity()?);
+            limits.min_discharge_current = Some(transfer.get_min_discharge_current_quantity()?);
+            limits.max_discharge_power = Some(transfer.get_max_discharge_power_quantity()?);
+            limits.min_discharge_power = Some(transfer.get_min_discharge_power_quantity()?);
+            discovery.target_soc = soc_from_wire(transfer.get_target_soc());
+            discovery.bidirectional = true;
+        } else {
+            return afb_error!(
+                "charging-param-discovery",
+                "iso20 request without energy transfer"
+            );
+        }
+        Ok(discovery)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingPreCharge {
+    pub status: ChargingEvStatus,
+    pub target_voltage: Voltage,
+    pub target_current: Option<Current>,
+    pub present_voltage: Option<Voltage>,
+}
+
+impl ChargingPreCharge {
+    #[track_caller]
+    pub fn from_din(request: &din_exi::PreChargeRequest) -> Result<Self, AfbError> {
+        Ok(Self {
+            status: ChargingEvStatus::from_din(&request.get_status())?,
+            target_voltage: request.get_target_voltage_quantity()?,
+            target_current: Some(request.get_target_current_quantity()?),
+            present_voltage: None,
+        })
+    }
+
+    #[track_caller]
+    pub fn from_iso2(request: &iso2_exi::PreChargeRequest) -> Result<Self, AfbError> {
+        Ok(Self {
+            status: ChargingEvStatus::from_iso2(&request.get_status())?,
+            target_voltage: request.get_target_voltage_quantity()?,
+            target_current: Some(request.get_target_current_quantity()?),
+            present_voltage: None,
+        })
+    }
+
+    #[track_caller]
+    pub fn from_iso20(request: &iso20_dc_exi::DcPreChargeRequest) -> Result<Self, AfbError> {
+        Ok(Self {
+            status: ChargingEvStatus::ready(),
+            target_voltage: request.get_target_voltage_quantity()?,
+            target_current: None,
+            present_voltage: Some(request.get_present_voltage_quantity()?),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingPowerDelivery {
+    pub status: Option<ChargingEvStatus>,
+    pub progress: ChargingProgress,
+    pub charging_complete: bool,
+    pub bulk_complete: Option<bool>,
+}
+
+impl ChargingPowerDelivery {
+    #[track_caller]
+    pub fn from_din(request: &din_exi::PowerDeliveryRequest) -> Result<Self, AfbError> {
+        // DIN has no renegotiation, ready means start
+        let progress = if request.get_ready() {
+            ChargingProgress::Start
+        } else {
+            ChargingProgress::Stop
+        };
+        let mut delivery = Self {
+            status: None,
+            progress,
+            charging_complete: false,
+            bulk_complete: None,
+        };
+        if let Some(param) = request.get_dc_delivery_params() {
+            delivery.status = Some(ChargingEvStatus::from_din(&param.get_status())?);
+            delivery.charging_complete = param.get_charge_complete();
+            delivery.bulk_complete = param.get_bulk_complete();
+        }
+        Ok(delivery)
+    }
+
+    #[track_caller]
+    pub fn from_iso2(request: &iso2_exi::PowerDeliveryRequest) -> Result<Self, AfbError> {
+        let progress = match request.get_progress()? {
+            iso2_exi::ChargeProgress::Start => ChargingProgress::Start,
+            iso2_exi::ChargeProgress::Stop => ChargingProgress::Stop,
+            iso2_exi::ChargeProgress::Renegotiate => ChargingProgress::Renegotiate,
+        };
+        let mut delivery = Self {
+            status: None,
+            progress,
+            charging_complete: false,
+            bulk_complete: None,
+        };
+        if let Some(param) = request.get_dc_delivery_params() {
+            delivery.status = Some(ChargingEvStatus::from_iso2(&param.get_status())?);
+            delivery.charging_complete = param.get_charge_complete();
+            delivery.bulk_complete = param.get_bulk_complete();
+        }
+        Ok(delivery)
+    }
+
+    // ISO-20 has no complete flag, a stop request ends the charge
+    #[track_caller]
+    pub fn from_iso20(request: &iso20_exi::PowerDeliveryRequest) -> Result<Self, AfbError> {
+        let progress = match request.get_progress()? {
+            iso20_exi::ChargeProgress::Start => ChargingProgress::Start,
+            iso20_exi::ChargeProgress::Stop => ChargingProgress::Stop,
+            iso20_exi::ChargeProgress::Standby => ChargingProgress::Standby,
+            iso20_exi::ChargeProgress::ScheduleRenegotiation => ChargingProgress::Renegotiate,
+        };
+        Ok(Self {
+            status: None,
+            progress,
+            charging_complete: progress == ChargingProgress::Stop,
+            bulk_complete: None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingCurrentDemand {
+    pub status: ChargingEvStatus,
+    pub control_mode: ChargingControlMode,
+    pub target_voltage: Option<Voltage>,
+    pub target_current: Option<Current>,
+    pub present_voltage: Option<Voltage>,
+    pub limits: ChargingLimits,
+    pub energy_request: Option<Energy>,
+    pub departure_time: Option<Duration>,
+    pub time_to_full: Option<Duration>,
+    pub time_to_bulk: Option<Duration>,
+    pub charging_complete: bool,
+    pub bulk_complete: Option<bool>,
+}
+
+// ISO-20 scheduled and BPT scheduled modes: EV targets with optional limits
+macro_rules! iso20_scheduled_demand {
+    ($demand:ident, $mode:expr) => {
+        $demand.target_voltage = Some($mode.get_target_voltage_quantity()?);
+        $demand.target_current = Some($mode.get_target_current_quantity()?);
+        $demand.energy_request = $mode.get_target_energy_quantity()?;
+        $demand.limits = ChargingLimits {
+            max_voltage: $mode.get_max_voltage_quantity()?,
+            min_voltage: $mode.get_min_voltage_quantity()?,
+            max_current: $mode.get_max_charge_current_quantity()?,
+            min_current: None,
+            max_power: $mode.get_max_charge_power_quantity()?,
+            min_power: $mode.get_min_charge_power_quantity()?,
+            ..Default::default()
+        };
+    };
+}
+
+// ISO-20 dynamic and BPT dynamic modes: EVSE picks targets within EV limits
+macro_rules! iso20_dynamic_demand {
+    ($demand:ident, $mode:expr) => {
+        $demand.energy_request = Some($mode.get_target_energy_quantity()?);
+        $demand.departure_time = duration_opt($mode.get_departure_time())?;
+        $demand.limits = ChargingLimits {
+            max_voltage: Some($mode.get_max_voltage_quantity()?),
+            min_voltage: Some($mode.get_min_voltage_quantity()?),
+            max_current: Some($mode.get_max_charge_current_quantity()?),
+            min_current: None,
+            max_power: Some($mode.get_max_charge_power_quantity()?),
+            min_power: Some($mode.get_min_charge_power_quantity()?),
+            ..Default::default()
+        };
+    };
+}
+
+impl ChargingCurrentDemand {
+    #[track_caller]
+    pub fn from_din(request: &din_exi::CurrentDemandRequest) -> Result<Self, AfbError> {
+        Ok(Self {
+            status: ChargingEvStatus::from_din(&request.get_status())?,
+            control_mode: ChargingControlMode::Scheduled,
+            target_voltage: Some(request.get_voltage_target_quantity()?),
+            target_current: Some(request.get_current_target_quantity()?),
+            present_voltage: None,
+            limits: ChargingLimits {
+                max_voltage: request.get_voltage_limit_quantity()?,
+                max_current: request.get_current_limit_quantity()?,
+                max_power: request.get_power_limit_quantity()?,
+                ..Default::default()
+            },
+            energy_request: None,
+            departure_time: None,
+            time_to_full: request.get_time_to_full_sock_quantity()?,
+            time_to_bulk: request.get_time_to_bulk_sock_quantity()?,
+            charging_complete: request.get_charging_complete(),
+            bulk_complete: request.get_bulk_complete(),
+        })
+    }
+
+    #[track_caller]
+    pub fn from_iso2(request: &iso2_exi::CurrentDemandRequest) -> Result<Self, AfbError> {
+        Ok(Self {
+            status: ChargingEvStatus::from_iso2(&request.get_status())?,
+            control_mode: ChargingControlMode::Scheduled,
+            target_voltage: Some(request.get_voltage_target_quantity()?),
+            target_current: Some(request.get_current_target_quantity()?),
+            present_voltage: None,
+            limits: ChargingLimits {
+                max_voltage: request.get_voltage_limit_quantity()?,
+                max_current: request.get_current_limit_quantity()?,
+                max_power: request.get_power_limit_quantity()?,
+                ..Default::default()
+            },
+            energy_request: None,
+            departure_time: None,
+            time_to_full: request.get_time_to_full_sock_quantity()?,
+            time_to_bulk: request.get_time_to_bulk_sock_quantity()?,
+            charging_complete: request.get_charging_complete(),
+            bulk_complete: request.get_bulk_complete(),
+        })
+    }
+
+    // ISO-20 has no complete flag, the EV ends the charge loop with a PowerDelivery stop
+    #[track_caller]
+    pub fn from_iso20(request: &iso20_dc_exi::DcChargeLoopRequest) -> Result<Self, AfbError> {
+        let mut demand = Self {
+            status: ChargingEvStatus::ready(),
+            control_mode: ChargingControlMode::Scheduled,
+            target_voltage: None,
+            target_current: None,
+            present_voltage: Some(request.get_present_voltage_quantity()?),
+            limits: ChargingLimits::default(),
+            energy_request: None,
+            departure_time: None,
+            time_to_full: None,
+            time_to_bulk: None,
+            charging_complete: false,
+            bulk_complete: None,
+        };
+        match request.get_control_mode()? {
+            iso20_dc_exi::DcClReqControlMode::Scheduled(mode) => {
+                iso20_scheduled_demand!(demand, mode);
+            }
+            iso20_dc_exi::DcClReqControlMode::BptScheduled(mode) => {
+                iso20_scheduled_demand!(demand, mode);
+                demand.control_mode = ChargingControlMode::BptScheduled;
+                demand.limits.max_discharge_current = mode.get_max_discharge_current_quantity()?;
+                demand.limits.max_discharge_power = mode.get_max_discharge_power_quantity()?;
+                demand.limits.min_discharge_power = mode.get_min_discharge_power_quantity()?;
+            }
+            iso20_dc_exi::DcClReqControlMode::Dynamic(mode) => {
+                iso20_dynamic_demand!(demand, mode);
+                demand.control_mode = ChargingControlMode::Dynamic;
+            }
+            iso20_dc_exi::DcClReqControlMode::BptDynamic(mode) => {
+                iso20_dynamic_demand!(demand, mode);
+                demand.control_mode = ChargingControlMode::BptDynamic;
+                demand.limits.max_discharge_current =
+                    Some(mode.get_max_discharge_current_quantity()?);
+                demand.limits.max_discharge_power = Some(mode.get_max_discharge_power_quantity()?);
+                demand.limits.min_discharge_power = Some(mode.get_min_discharge_power_quantity()?);
+            }
+        }
+        Ok(demand)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingEvseState {
+    NotReady,
+    Ready,
+    Shutdown,
+    UtilityInterrupt,
+    IsolationMonitoring,
+    EmergencyShutdown,
+    Malfunction,
+}
+
+impl ChargingEvseState {
+    pub fn to_din(self) -> din_exi::DcEvseErrorCode {
+        match self {
+            ChargingEvseState::NotReady => din_exi::DcEvseErrorCode::NotReady,
+            ChargingEvseState::Ready => din_exi::DcEvseErrorCode::Ready,
+            ChargingEvseState::Shutdown => din_exi::DcEvseErrorCode::Shutdown,
+            ChargingEvseState::UtilityInterrupt => din_exi::DcEvseErrorCode::UtilInteruptEvt,
+            ChargingEvseState::IsolationMonitoring => din_exi::DcEvseErrorCode::MonitoringActive,
+            ChargingEvseState::EmergencyShutdown => din_exi::DcEvseErrorCode::EmergencyShutdown,
+            ChargingEvseState::Malfunction => din_exi::DcEvseErrorCode::EvseMalfunction,
+        }
+    }
+
+    pub fn to_iso2(self) -> iso2_exi::DcEvseErrorCode {
+        match self {
+            ChargingEvseState::NotReady => iso2_exi::DcEvseErrorCode::NotReady,
+            ChargingEvseState::Ready => iso2_exi::DcEvseErrorCode::Ready,
+            ChargingEvseState::Shutdown => iso2_exi::DcEvseErrorCode::Shutdown,
+            ChargingEvseState::UtilityInterrupt => iso2_exi::DcEvseErrorCode::UtilInteruptEvt,
+            ChargingEvseState::IsolationMonitoring => iso2_exi::DcEvseErrorCode::MonitoringActive,
+            ChargingEvseState::EmergencyShutdown => iso2_exi::DcEvseErrorCode::EmergencyShutdown,
+            ChargingEvseState::Malfunction => iso2_exi::DcEvseErrorCode::EvseMalfunction,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingEvseNotification {
+    None,
+    StopCharging,
+    Renegotiation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargingIsolation {
+    Invalid,
+    Valid,
+    Warning,
+    Fault,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvseStatus {
+    pub state: ChargingEvseState,
+    pub notification: ChargingEvseNotification,
+    /// seconds before the EV should react to notification
+    pub delay: u16,
+    pub isolation: Option<ChargingIsolation>,
+}
+
+impl ChargingEvseStatus {
+    pub fn new(state: ChargingEvseState) -> Self {
+        Self {
+            state,
+            notification: ChargingEvseNotification::None,
+            delay: 0,
+            isolation: None,
+        }
+    }
+
+    pub fn to_din(&self) -> din_exi::DcEvseStatusType {
+        let notification = match self.notification {
+            ChargingEvseNotification::None => din_exi::EvseNotification::None,
+            ChargingEvseNotification::StopCharging => din_exi::EvseNotification::StopCharging,
+            ChargingEvseNotification::Renegotiation => din_exi::EvseNotification::ReNegotiation,
+        };
+        let mut status =
+            din_exi::DcEvseStatusType::new(self.state.to_din(), notification, self.delay as u32);
+        if let Some(isolation) = self.isolation {
+            status.set_isolation_status(match isolation {
+                ChargingIsolation::Invalid => din_exi::IsolationStatus::Invalid,
+                ChargingIsolation::Valid => din_exi::IsolationStatus::Valid,
+                ChargingIsolation::Warning => din_exi::IsolationStatus::Warning,
+                ChargingIsolation::Fault => din_exi::IsolationStatus::Fault,
+            });
+        }
+        status
+    }
+
+    pub fn to_iso2(&self) -> iso2_exi::DcEvseStatusType {
+        let notification = match self.notification {
+            ChargingEvseNotification::None => iso2_exi::EvseNotification::None,
+            ChargingEvseNotification::StopCharging => iso2_exi::EvseNotification::StopCharging,
+            ChargingEvseNotification::Renegotiation => iso2_exi::EvseNotification::ReNegotiation,
+        };
+        let mut status =
+            iso2_exi::DcEvseStatusType::new(self.state.to_iso2(), notification, self.delay);
+        if let Some(isolation) = self.isolation {
+            status.set_isolation_status(match isolation {
+                ChargingIsolation::Invalid => iso2_exi::IsolationStatus::Invalid,
+                ChargingIsolation::Valid => iso2_exi::IsolationStatus::Valid,
+                ChargingIsolation::Warning => iso2_exi::IsolationStatus::Warning,
+                ChargingIsolation::Fault => iso2_exi::IsolationStatus::Fault,
+            });
+        }
+        status
+    }
+
+    /// ISO-20 status is optional and only carries notifications
+    pub fn to_iso20(&self) -> Option<iso20_exi::EvseStatusType> {
+        let notification = match self.notification {
+            ChargingEvseNotification::None => return None,
+            ChargingEvseNotification::StopCharging => iso20_exi::EvseNotification::Terminate,
+            ChargingEvseNotification::Renegotiation => {
+                iso20_exi::EvseNotification::ScheduleRenegotiation
+            }
+        };
+        Some(iso20_exi::EvseStatusType::new(notification, self.delay))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvseParam {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+    pub processing: ChargingProcessing,
+    /// max voltage/current are mandatory, ISO-2 and ISO-20 also require max power,
+    /// ISO-20 BPT max discharge current/power
+    pub limits: ChargingLimits,
+    pub current_ripple: Current,
+    pub energy_to_deliver: Option<Energy>,
+}
+
+impl ChargingEvseParam {
+    #[track_caller]
+    pub fn to_din(&self) -> Result<din_exi::ParamDiscoveryResponse, AfbError> {
+        let limits = &self.limits;
+        let mut param = din_exi::DcEvseChargeParam::from_quantities(
+            &self.status.to_din(),
+            required_limit(limits.max_voltage, "max_voltage")?,
+            limits.min_voltage.unwrap_or_default(),
+            required_limit(limits.max_current, "max_current")?,
+            limits.min_current.unwrap_or_default(),
+            self.current_ripple,
+        )?;
+        if let Some(power) = limits.max_power {
+            param.set_max_power_quantity(power)?;
+        }
+        if let Some(energy) = self.energy_to_deliver {
+            param.set_energy_to_deliver_quantity(energy)?;
+        }
+        let mut response = din_exi::ParamDiscoveryResponse::new(self.rcode.to_din());
+        response
+            .set_processing(self.processing.to_din())
+            .set_evse_dc_charge_param(&param);
+        Ok(response)
+    }
+
+    #[track_caller]
+    pub fn to_iso2(&self) -> Result<iso2_exi::ParamDiscoveryResponse, AfbError> {
+        let limits = &self.limits;
+        let mut param = iso2_exi::DcEvseChargeParam::from_quantities(
+            &self.status.to_iso2(),
+            required_limit(limits.max_voltage, "max_voltage")?,
+            limits.min_voltage.unwrap_or_default(),
+            required_limit(limits.max_current, "max_current")?,
+            limits.min_current.unwrap_or_default(),
+            required_limit(limits.max_power, "max_power")?,
+            self.current_ripple,
+        )?;
+        if let Some(energy) = self.energy_to_deliver {
+            param.set_energy_to_deliver_quantity(energy)?;
+        }
+        let mut response =
+            iso2_exi::ParamDiscoveryResponse::new(self.rcode.to_iso2(), self.processing.to_iso2());
+        response.set_evse_dc_charge_param(&param);
+        Ok(response)
+    }
+
+    /// ISO-20 BPT request expects a BPT energy transfer response
+    #[track_caller]
+    pub fn to_iso20(
+        &self,
+        bidirectional: bool,
+    ) -> Result<iso20_dc_exi::DcChargeParamDiscoveryResponse, AfbError> {
+        let limits = &self.limits;
+        if !bidirectional {
+            let transfer = iso20_dc_exi::DcCpdResEnergyTransfer::from_quantities(
+                required_limit(limits.max_power, "max_power")?,
+                limits.min_power.unwrap_or_default(),
+                required_limit(limits.max_current, "max_current")?,
+                limits.min_current.unwrap_or_default(),
+                required_limit(limits.max_voltage, "max_voltage")?,
+                limits.min_voltage.unwrap_or_default(),
+            );
+            return Ok(iso20_dc_exi::DcChargeParamDiscoveryResponse::new(
+                self.rcode.to_iso20(),
+                &transfer,
+            ));
+        }
+        let mut transfer = iso20_dc_exi::BptDcCpdResEnergyTransfer::from_quantities(
+            required_limit(limits.max_power, "max_power")?,
+            limits.min_power.unwrap_or_default(),
+            required_limit(limits.max_current, "max_current")?,
+            limits.min_current.unwrap_or_default(),
+            required_limit(limits.max_voltage, "max_voltage")?,
+            limits.min_voltage.unwrap_or_default(),
+        );
+        transfer
+            .set_max_discharge_power_quantity(required_limit(
+                limits.max_discharge_power,
+                "max_discharge_power",
+            )?)
+            .set_min_discharge_power_quantity(limits.min_discharge_power.unwrap_or_default())
+            .set_max_discharge_current_quantity(required_limit(
+                limits.max_discharge_current,
+                "max_discharge_current",
+            )?)
+            .set_min_discharge_current_quantity(limits.min_discharge_current.unwrap_or_default());
+        Ok(iso20_dc_exi::DcChargeParamDiscoveryResponse::new_bpt(
+            self.rcode.to_iso20(),
+            &transfer,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvseCableCheck {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+    pub processing: ChargingProcessing,
+}
+
+impl ChargingEvseCableCheck {
+    pub fn to_din(&self) -> din_exi::CableCheckResponse {
+        din_exi::CableCheckResponse::new(
+            self.rcode.to_din(),
+            &self.status.to_din(),
+            self.processing.to_din(),
+        )
+    }
+
+    pub fn to_iso2(&self) -> iso2_exi::CableCheckResponse {
+        iso2_exi::CableCheckResponse::new(
+            self.rcode.to_iso2(),
+            &self.status.to_iso2(),
+            self.processing.to_iso2(),
+        )
+    }
+
+    pub fn to_iso20(&self) -> iso20_dc_exi::DcCableCheckResponse {
+        iso20_dc_exi::DcCableCheckResponse::new(self.rcode.to_iso20(), self.processing.to_iso20())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvsePreCharge {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+    pub present_voltage: Voltage,
+}
+
+impl ChargingEvsePreCharge {
+    #[track_caller]
+    pub fn to_din(&self) -> Result<din_exi::PreChargeResponse, AfbError> {
+        din_exi::PreChargeResponse::from_quantities(
+            self.rcode.to_din(),
+            &self.status.to_din(),
+            self.present_voltage,
+        )
+    }
+
+    #[track_caller]
+    pub fn to_iso2(&self) -> Result<iso2_exi::PreChargeResponse, AfbError> {
+        iso2_exi::PreChargeResponse::from_quantities(
+            self.rcode.to_iso2(),
+            &self.status.to_iso2(),
+            self.present_voltage,
+        )
+    }
+
+    pub fn to_iso20(&self) -> iso20_dc_exi::DcPreChargeResponse {
+        iso20_dc_exi::DcPreChargeResponse::from_quantities(
+            self.rcode.to_iso20(),
+            self.present_voltage,
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvsePowerDelivery {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+}
+
+impl ChargingEvsePowerDelivery {
+    #[track_caller]
+    pub fn to_din(&self) -> Result<din_exi::PowerDeliveryResponse, AfbError> {
+        let mut response = din_exi::PowerDeliveryResponse::new(self.rcode.to_din());
+        response.set_dc_evse_status(&self.status.to_din())?;
+        Ok(response)
+    }
+
+    #[track_caller]
+    pub fn to_iso2(&self) -> Result<iso2_exi::PowerDeliveryResponse, AfbError> {
+        let mut response = iso2_exi::PowerDeliveryResponse::new(self.rcode.to_iso2());
+        response.set_dc_evse_status(&self.status.to_iso2())?;
+        Ok(response)
+    }
+
+    pub fn to_iso20(&self) -> iso20_exi::PowerDeliveryResponse {
+        let mut response = iso20_exi::PowerDeliveryResponse::new(self.rcode.to_iso20());
+        if let Some(status) = self.status.to_iso20() {
+            response.set_evse_status(&status);
+        }
+        response
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvseCurrentDemand {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+    pub present_voltage: Voltage,
+    pub present_current: Current,
+    /// ISO-20 dynamic mode requires max voltage/current/power, BPT dynamic also max discharge
+    /// current/power, others protocols are optional
+    pub limits: ChargingLimits,
+    pub voltage_limit_achieved: bool,
+    pub current_limit_achieved: bool,
+    pub power_limit_achieved: bool,
+}
+
+impl ChargingEvseCurrentDemand {
+    #[track_caller]
+    pub fn to_din(&self) -> Result<din_exi::CurrentDemandResponse, AfbError> {
+        let mut response = din_exi::CurrentDemandResponse::from_quantities(
+            self.rcode.to_din(),
+            &self.status.to_din(),
+            self.present_voltage,
+            self.present_current,
+            self.voltage_limit_achieved,
+            self.current_limit_achieved,
+            self.power_limit_achieved,
+        )?;
+        if let Some(voltage) = self.limits.max_voltage {
+            response.set_voltage_limit_quantity(voltage)?;
+        }
+        if let Some(current) = self.limits.max_current {
+            response.set_current_limit_quantity(current)?;
+        }
+        if let Some(power) = self.limits.max_power {
+            response.set_power_limit_quantity(power)?;
+        }
+        Ok(response)
+    }
+
+    /// ISO-2 also echoes EVSE id and the schedule tuple selected by PowerDelivery
+    #[track_caller]
+    pub fn to_iso2(
+        &self,
+        evse_id: &str,
+        schedule_id: u8,
+    ) -> Result<iso2_exi::CurrentDemandResponse, AfbError> {
+        let mut response = iso2_exi::CurrentDemandResponse::from_quantities(
+            self.rcode.to_iso2(),
+            evse_id,
+            &self.status.to_iso2(),
+            self.present_current,
+            self.current_limit_achieved,
+            self.present_voltage,
+            self.voltage_limit_achieved,
+            self.power_limit_achieved,
+            schedule_id,
+        )?;
+        if let Some(voltage) = self.limits.max_voltage {
+            response.set_voltage_limit_quantity(voltage)?;
+        }
+        if let Some(current) = self.limits.max_current {
+            response.set_current_limit_quantity(current)?;
+        }
+        if let Some(power) = self.limits.max_power {
+            response.set_power_limit_quantity(power)?;
+        }
+        Ok(response)
+    }
+
+    /// ISO-20 response control mode should match the one of the EV request
+    #[track_caller]
+    pub fn to_iso20(
+        &self,
+        mode: ChargingControlMode,
+    ) -> Result<iso20_dc_exi::DcChargeLoopResponse, AfbError> {
+        let limits = &self.limits;
+        let control = match mode {
+            ChargingControlMode::Scheduled => {
+                let mut scheduled = iso20_dc_exi::ScheduledDcClResMode::new();
+                if let Some(power) = limits.max_power {
+                    scheduled.set_max_charge_power_quantity(power);
+                }
+                if let Some(power) = limits.min_power {
+                    scheduled.set_min_charge_power_quantity(power);
+                }
+                if let Some(current) = limits.max_current {
+                    scheduled.set_max_charge_current_quantity(current);
+                }
+                if let Some(voltage) = limits.max_voltage {
+                    scheduled.set_max_voltage_quantity(voltage);
+                }
+                iso20_dc_exi::DcClResControlMode::Scheduled(scheduled)
+            }
+            ChargingControlMode::Dynamic => iso20_dc_exi::DcClResControlMode::Dynamic(
+                iso20_dc_exi::DynamicDcClResMode::from_quantities(
+                    required_limit(limits.max_power, "max_power")?,
+                    limits.min_power.unwrap_or_default(),
+                    required_limit(limits.max_current, "max_current")?,
+                    required_limit(limits.max_voltage, "max_voltage")?,
+                ),
+            ),
+            ChargingControlMode::BptScheduled => {
+                let mut scheduled = iso20_dc_exi::BptScheduledDcClResMode::new();
+                if let Some(power) = limits.max_power {
+                    scheduled.set_max_charge_power_quantity(power);
+                }
+                if let Some(power) = limits.min_power {
+                    scheduled.set_min_charge_power_quantity(power);
+                }
+                if let Some(current) = limits.max_current {
+                    scheduled.set_max_charge_current_quantity(current);
+                }
+                if let Some(voltage) = limits.max_voltage {
+                    scheduled.set_max_voltage_quantity(voltage);
+                }
+                if let Some(voltage) = limits.min_voltage {
+                    scheduled.set_min_voltage_quantity(voltage);
+                }
+                if let Some(power) = limits.max_discharge_power {
+                    scheduled.set_max_discharge_power_quantity(power);
+                }
+                if let Some(power) = limits.min_discharge_power {
+                    scheduled.set_min_discharge_power_quantity(power);
+                }
+                if let Some(current) = limits.max_discharge_current {
+                    scheduled.set_max_discharge_current_quantity(current);
+                }
+                iso20_dc_exi::DcClResControlMode::BptScheduled(scheduled)
+            }
+            ChargingControlMode::BptDynamic => {
+                let mut dynamic = iso20_dc_exi::BptDynamicDcClResMode::from_quantities(
+                    required_limit(limits.max_power, "max_power")?,
+                    limits.min_power.unwrap_or_default(),
+                    required_limit(limits.max_current, "max_current")?,
+                    required_limit(limits.max_voltage, "max_voltage")?,
+                );
+                dynamic
+                    .set_max_discharge_power_quantity(required_limit(
+                        limits.max_discharge_power,
+                        "max_discharge_power",
+                    )?)
+                    .set_min_discharge_power_quantity(
+                        limits.min_discharge_power.unwrap_or_default(),
+                    )
+                    .set_max_discharge_current_quantity(required_limit(
+                        limits.max_discharge_current,
+                        "max_discharge_current",
+                    )?)
+                    .set_min_voltage_quantity(limits.min_voltage.unwrap_or_default());
+                iso20_dc_exi::DcClResControlMode::BptDynamic(dynamic)
+            }
+        };
+        let mut response = iso20_dc_exi::DcChargeLoopResponse::from_quantities(
+            self.rcode.to_iso20(),
+            self.present_current,
+            self.present_voltage,
+            &control,
+        );
+        response.set_limits_achieved(
+            self.power_limit_achieved,
+            self.current_limit_achieved,
+            self.voltage_limit_achieved,
+        );
+        if let Some(status) = self.status.to_iso20() {
+            response.set_evse_status(&status);
+        }
+        Ok(response)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChargingEvseWeldingDetection {
+    pub rcode: ChargingResponseCode,
+    pub status: ChargingEvseStatus,
+    pub present_voltage: Voltage,
+}
+
+impl ChargingEvseWeldingDetection {
+    #[track_caller]
+    pub fn to_din(&self) -> Result<din_exi::WeldingDetectionResponse, AfbError> {
+        din_exi::WeldingDetectionResponse::from_quantities(
+            self.rcode.to_din(),
+            &self.status.to_din(),
+            self.present_voltage,
+        )
+    }
+
+    #[track_caller]
+    pub fn to_iso2(&self) -> Result<iso2_exi::WeldingDetectionResponse, AfbError> {
+        iso2_exi::WeldingDetectionResponse::from_quantities(
+            self.rcode.to_iso2(),
+            &self.status.to_iso2(),
+            self.present_voltage,
+        )
+    }
+
+    pub fn to_iso20(&self) -> iso20_dc_exi::DcWeldingDetectionResponse {
+        iso20_dc_exi::DcWeldingDetectionResponse::from_quantities(
+            self.rcode.to_iso20(),
+            self.present_voltage,
+        )
+    }
+}
+
+/// Charger DC logic written once for every negotiated protocol. Requests are normalized
+/// before reaching the backend, responses are encoded for the session protocol.
+pub trait ChargingBackend: Send + Sync {
+    fn param_discovery(
+        &self,
+        request: &ChargingParamDiscovery,
+    ) -> Result<ChargingEvseParam, AfbError>;
+
+    fn cable_check(&self, status: &ChargingEvStatus) -> Result<ChargingEvseCableCheck, AfbError>;
+
+    fn pre_charge(&self, request: &ChargingPreCharge) -> Result<ChargingEvsePreCharge, AfbError>;
+
+    fn power_delivery(
+        &self,
+        request: &ChargingPowerDelivery,
+    ) -> Result<ChargingEvsePowerDelivery, AfbError>;
+
+    fn current_demand(
+        &self,
+        request: &ChargingCurrentDemand,
+    ) -> Result<ChargingEvseCurrentDemand, AfbError>;
+
+    fn welding_detection(
+        &self,
+        status: &ChargingEvStatus,
+    ) -> Result<ChargingEvseWeldingDetection, AfbError>;
+}
+
+/// Serves DC charging messages from a ChargingBackend whatever the negotiated protocol.
+/// Session keeps the per protocol context the backend should not care about (ISO-2 schedule
+/// tuple, ISO-20 control mode). Non DC charging messages (including AC ParamDiscovery)
+/// return None and stay with controllers.
+pub struct ChargingSession {
+    backend: Box<dyn ChargingBackend>,
+    evse_id: String,
+    schedule_id: u8,
+    control_mode: ChargingControlMode,
+}
+
+impl ChargingSession {
+    pub fn new(evse_id: &str, backend: Box<dyn ChargingBackend>) -> Self {
+        Self {
+            backend,
+            evse_id: evse_id.to_string(),
+            schedule_id: 1,
+            control_mode: ChargingControlMode::Scheduled,
+        }
+    }
+
+    pub fn get_backend(&self) -> &dyn ChargingBackend {
+        self.backend.as_ref()
+    }
+
+    #[track_caller]
+    pub fn din_request(
+        &mut self,
+        body: &din_exi::MessageBody,
+    ) -> Result<Option<din_exi::DinBodyType>, AfbError> {
+        let backend = self.backend.as_ref();
+        let response = match body {
+            din_exi::MessageBody::ParamDiscoveryReq(request)
+                if request.get_dc_charge_param().is_some() =>
+            {
+                let request = ChargingParamDiscovery::from_din(request)?;
+                backend.param_discovery(&request)?.to_din()?.encode()
+            }
+            din_exi::MessageBody::CableCheckReq(request) => {
+                let status = ChargingEvStatus::from_din(&request.get_status())?;
+                backend.cable_check(&status)?.to_din().encode()
+            }
+            din_exi::MessageBody::PreChargeReq(request) => {
+                let request = ChargingPreCharge::from_din(request)?;
+                backend.pre_charge(&request)?.to_din()?.encode()
+            }
+            din_exi::MessageBody::PowerDeliveryReq(request) => {
+                let request = ChargingPowerDelivery::from_din(request)?;
+                backend.power_delivery(&request)?.to_din()?.encode()
+            }
+            din_exi::MessageBody::CurrentDemandReq(request) => {
+                let request = ChargingCurrentDemand::from_din(request)?;
+                backend.current_demand(&request)?.to_din()?.encode()
+            }
+            din_exi::MessageBody::WeldingDetectionReq(request) => {
+                let status = ChargingEvStatus::from_din(&request.get_status())?;
+                backend.welding_detection(&status)?.to_din()?.encode()
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(response))
+    }
+
+    #[track_caller]
+    pub fn iso2_request(
+        &mut self,
+        body: &iso2_exi::MessageBody,
+    ) -> Result<Option<iso2_exi::Iso2BodyType>, AfbError> {
+        let backend = self.backend.as_ref();
+        let response = match body {
+            // AC or generic EV parameters stay with controllers
+            iso2_exi::MessageBody::ParamDiscoveryReq(request)
+                if request.get_dc_charge_param().is_some() =>
+            {
+                let request = ChargingParamDiscovery::from_iso2(request)?;
+                backend.param_discovery(&request)?.to_iso2()?.encode()
+            }
+            iso2_exi::MessageBody::CableCheckReq(request) => {
+                let status = ChargingEvStatus::from_iso2(&request.get_status())?;
+                backend.cable_check(&status)?.to_iso2().encode()
+            }
+            iso2_exi::MessageBody::PreChargeReq(request) => {
+                let request = ChargingPreCharge::from_iso2(request)?;
+                backend.pre_charge(&request)?.to_iso2()?.encode()
+            }
+            iso2_exi::MessageBody::PowerDeliveryReq(request) => {
+                self.schedule_id = request.get_schedule_id();
+                let request = ChargingPowerDelivery::from_iso2(request)?;
+                backend.power_delivery(&request)?.to_iso2()?.encode()
+            }
+            iso2_exi::MessageBody::CurrentDemandReq(request) => {
+                let request = ChargingCurrentDemand::from_iso2(request)?;
+                backend
+                    .current_demand(&request)?
+                    .to_iso2(&self.evse_id, self.schedule_id)?
+                    .encode()
+            }
+            iso2_exi::MessageBody::WeldingDetectionReq(request) => {
+                let status = ChargingEvStatus::from_iso2(&request.get_status())?;
+                backend.welding_detection(&status)?.to_iso2()?.encode()
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(response))
+    }
+
+    /// ISO-20 common namespace only holds PowerDelivery for charging
+    #[track_caller]
+    pub fn iso20_request(
+        &mut self,
+        body: &iso20_exi::MessageBody,
+    ) -> Result<Option<iso20_exi::Iso20BodyType>, AfbError> {
+        match body {
+            iso20_exi::MessageBody::PowerDeliveryReq(request) => {
+                let request = ChargingPowerDelivery::from_iso20(request)?;
+                let response = self.backend.power_delivery(&request)?.to_iso20();
+                Ok(Some(response.encode()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    #[track_caller]
+    pub fn iso20_dc_request(
+        &mut self,
+        body: &iso20_dc_exi::MessageBody,
+    ) -> Result<Option<iso20_dc_exi::Iso20DcBodyType>, AfbError> {
+        let backend = self.backend.as_ref();
+        let response = match body {
+            iso20_dc_exi::MessageBody::DcChargeParamDiscoveryReq(request) => {
+                let request = ChargingParamDiscovery::from_iso20(request)?;
+                backend
+                    .param_discovery(&request)?
+                    .to_iso20(request.bidirectional)?
+                    .encode()
+            }
+            iso20_dc_exi::MessageBody::DcCableCheckReq(_) => backend
+                .cable_check(&ChargingEvStatus::ready())?
+                .to_iso20()
+                .encode(),
+            iso20_dc_exi::MessageBody::DcPreChargeReq(request) => {
+                let request = ChargingPreCharge::from_iso20(request)?;
+                backend.pre_charge(&request)?.to_iso20().encode()
+            }
+            iso20_dc_exi::MessageBody::DcChargeLoopReq(request) => {
+                let request = ChargingCurrentDemand::from_iso20(request)?;
+                self.control_mode = request.control_mode;
+                backend
+                    .current_demand(&request)?
+                    .to_iso20(self.control_mode)?
+                    .encode()
+            }
+            iso20_dc_exi::MessageBody::DcWeldingDetectionReq(_) => backend
+                .welding_detection(&ChargingEvStatus::ready())?
+                .to_iso20()
+                .encode(),
+            _ => return Ok(None),
+        };
+        Ok(Some(response))
+    }
+}
@@ -14,176 +14,674 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  *
+ * Reference:
+ *   ISO 15118-2:2014 §8.8.2 (V2G communication states) & Table 105/106 (message sequencing)
  */
 
+use crate::prelude::iso2_exi::*;
 use crate::prelude::*;
-use std::sync::{Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use self::v2g::SupportedAppProtocolExi;
+// SECC only speaks iso15118-2, other protocols are rejected at SupportedAppProtocol time
+pub const ISO2_PROTOCOL_CONF: v2g::SupportedAppProtocolConf = v2g::SupportedAppProtocolConf {
+    tag_id: v2g::ProtocolTagId::Iso2,
+    name: "urn:iso:15118:2:2013:MsgDef",
+    major: 2,
+    minor: 0,
+};
 
-pub struct ControlerConfig {}
+/// SECC communication state, each state restricts which EV request is acceptable.
+/// When the controller returns to Idle the TCP connection should be closed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeccState {
+    Idle,
+    SessionSetup,
+    ServiceDiscovery,
+    ServiceDetail,
+    PaymentSelected,
+    PaymentDetails,
+    Authorization,
+    ChargeParam,
+    CableCheck,
+    PreCharge,
+    PowerDelivery,
+    AcCharging,
+    DcCharging,
+    Stopping,
+}
+
+impl SeccState {
+    // SessionStop is refused while energy flows, EV should first send PowerDelivery(stop)
+    pub fn allowed(&self) -> &'static [MessageTagId] {
+        use MessageTagId::*;
+        match self {
+            SeccState::Idle => &[],
+            SeccState::SessionSetup => &[SessionSetupReq],
+            SeccState::ServiceDiscovery => &[ServiceDiscoveryReq],
+            SeccState::ServiceDetail => &[ServiceDetailReq, PaymentSelectionReq, SessionStopReq],
+            SeccState::PaymentSelected => &[
+                PaymentDetailsReq,
+                CertificateInstallReq,
+                CertificateUpdateReq,
+                AuthorizationReq,
+                SessionStopReq,
+            ],
+            SeccState::PaymentDetails => &[PaymentDetailsReq, SessionStopReq],
+            SeccState::Authorization => &[AuthorizationReq, SessionStopReq],
+            SeccState::ChargeParam => &[ParamDiscoveryReq, SessionStopReq],
+            SeccState::CableCheck => &[CableCheckReq, SessionStopReq],
+            SeccState::PreCharge => &[PreChargeReq, PowerDeliveryReq, SessionStopReq],
+            SeccState::PowerDelivery => &[PowerDeliveryReq, SessionStopReq],
+            SeccState::AcCharging => &[ChargingStatusReq, MeteringReceiptReq, PowerDeliveryReq],
+            SeccState::DcCharging => &[CurrentDemandReq, MeteringReceiptReq, PowerDeliveryReq],
+            SeccState::Stopping => &[WeldingDetectionReq, SessionStopReq],
+        }
+    }
+
+    pub fn accept(&self, tagid: MessageTagId) -> bool {
+        self.allowed().contains(&tagid)
+    }
+}
+
+/// Charger logic plugged into the SECC controller. The controller handles session id,
+/// message sequencing and encoding; the handler only builds the responses.
+pub trait Iso2ChargerHandler: Send + Sync {
+    fn get_evse_id(&self) -> &str;
+
+    fn service_discovery(
+        &self,
+        request: &ServiceDiscoveryRequest,
+    ) -> Result<ServiceDiscoveryResponse, AfbError>;
+
+    fn service_detail(
+        &self,
+        request: &ServiceDetailRequest,
+    ) -> Result<ServiceDetailResponse, AfbError>;
+
+    fn payment_selection(
+        &self,
+        request: &PaymentSelectionRequest,
+    ) -> Result<PaymentSelectionResponse, AfbError>;
+
+    fn authorization(
+        &self,
+        request: &AuthorizationRequest,
+    ) -> Result<AuthorizationResponse, AfbError>;
+
+    fn param_discovery(
+        &self,
+        request: &ParamDiscoveryRequest,
+    ) -> Result<ParamDiscoveryResponse, AfbError>;
+
+    fn power_delivery(
+        &self,
+        request: &PowerDeliveryRequest,
+    ) -> Result<PowerDeliveryResponse, AfbError>;
+
+    fn cable_check(&self, request: &CableCheckRequest) -> Result<CableCheckResponse, AfbError>;
+
+    fn pre_charge(&self, request: &PreChargeRequest) -> Result<PreChargeResponse, AfbError>;
+
+    fn current_demand(
+        &self,
+        request: &CurrentDemandRequest,
+    ) -> Result<CurrentDemandResponse, AfbError>;
+
+    fn charging_status(
+        &self,
+        request: &ChargingStatusRequest,
+    ) -> Result<ChargingStatusResponse, AfbError>;
+
+    fn welding_detection(
+        &self,
+        request: &WeldingDetectionRequest,
+    ) -> Result<WeldingDetectionResponse, AfbError>;
+
+    // default session setup only reflects controller session status
+    fn session_setup(
+        &self,
+        _request: &SessionSetupRequest,
+        rcode: ResponseCode,
+    ) -> Result<SessionSetupResponse, AfbError> {
+        SessionSetupResponse::new(self.get_evse_id(), rcode)
+    }
+
+    fn session_stop(&self, _request: &SessionStopRequest) -> Result<SessionStopResponse, AfbError> {
+        Ok(SessionStopResponse::new(ResponseCode::Ok))
+    }
+
+    // following messages are only used by plug&charge, default is to refuse them
+    fn payment_details(
+        &self,
+        _request: &PaymentDetailsRequest,
+    ) -> Result<PaymentDetailsResponse, AfbError> {
+        PaymentDetailsResponse::new(ResponseCode::Failed, &[])
+    }
+
+    fn certificate_install(
+        &self,
+        _request: &CertificateInstallRequest,
+    ) -> Result<CertificateInstallResponse, AfbError> {
+        let chain = CertificateChainType::new(&[])?;
+        Ok(CertificateInstallResponse::new(
+            ResponseCode::Failed,
+            &chain,
+            &chain,
+            &PrivateKeyType::new("", &[])?,
+            &DhPublicKeyType::new("", &[])?,
+            &EmaidType::new("", "")?,
+        ))
+    }
+
+    fn certificate_update(
+        &self,
+        _request: &CertificateUpdateRequest,
+    ) -> Result<CertificateUpdateResponse, AfbError> {
+        let chain = CertificateChainType::new(&[])?;
+        Ok(CertificateUpdateResponse::new(
+            ResponseCode::Failed,
+            &chain,
+            &chain,
+            &PrivateKeyType::new("", &[])?,
+            &DhPublicKeyType::new("", &[])?,
+            &EmaidType::new("", "")?,
+        ))
+    }
+
+    fn metering_receipt(
+        &self,
+        _request: &MeteringReceiptRequest,
+    ) -> Result<MeteringReceiptResponse, AfbError> {
+        Ok(MeteringReceiptResponse::new(ResponseCode::Ok))
+    }
+}
 
-pub struct ControlerState {
-    pub status: u32,
-    pub protocol: v2g::V2gProtocolTagId,
-    session_id: iso2::SessionId,
-    evccid: iso2::SessionSetupRequest,
+pub struct ControllerState {
+    pub state: SeccState,
+    pub protocol: v2g::ProtocolTagId,
+    pub session_id: Vec<u8>,
+    pub evccid: Vec<u8>,
+    pub dc_mode: bool,
 }
 
 pub struct IsoController {
-    pub config: ControlerConfig,
-    pub data_set: Mutex<ControlerState>,
+    handler: Box<dyn Iso2ChargerHandler>,
+    data_set: Mutex<ControllerState>,
+    recorder: Option<Arc<dyn V2gRecordHook>>,
+}
+
+// no random source within the crate, time based id is unique enough for a charger
+pub(crate) fn new_session_id() -> Vec<u8> {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(time) => time.as_nanos() as u64,
+        Err(_) => 1,
+    };
+    nanos.to_be_bytes().to_vec()
+}
+
+// answer EV SupportedAppProtocolReq in place, returns the selected protocol if any
+pub(crate) fn app_protocol_handshake(
+    lock: &mut MutexGuard<RawStream>,
+    supported: &[&v2g::SupportedAppProtocolConf],
+) -> Result<Option<v2g::ProtocolTagId>, AfbError> {
+    let request = match v2g::SupportedAppProtocolExi::decode_from_stream(lock)? {
+        v2g::V2gMsgBody::Request(value) => value,
+        v2g::V2gMsgBody::Response(_) => {
+            return afb_error!(
+                "secc-controller-protocol",
+                "expect 'SupportedAppProtocolReq' as initial request"
+            )
+        }
+    };
+
+    let negotiation = v2g::AppProtocolNegotiator::new(supported).negotiate(&request);
+    for candidate in negotiation.get_candidates() {
+        afb_log_msg!(
+            Debug,
+            None,
+            "SupportedAppProtocol:{} priority:{} verdict:{:?}",
+            candidate.name,
+            candidate.priority,
+            candidate.verdict
+        );
+    }
+    let response = negotiation.get_response();
+    let selected = negotiation
+        .get_selected()
+        .map(|protocol| protocol.get_schema());
+    v2g::SupportedAppProtocolExi::encode_to_stream(lock, &response.encode())?;
+    Ok(selected)
+}
+
+// build a minimal response matching the request when the controller refuses it
+fn failed_body(
+    tagid: MessageTagId,
+    rcode: ResponseCode,
+    evse_id: &str,
+    dc_mode: bool,
+) -> Result<Iso2BodyType, AfbError> {
+    let voltage = PhysicalValue::new(0, 0, PhysicalUnit::Volt);
+    let current = PhysicalValue::new(0, 0, PhysicalUnit::Ampere);
+    let ac_status = AcEvseStatusType::new(EvseNotification::None, 0, false);
+    let dc_status = DcEvseStatusType::new(DcEvseErrorCode::NotReady, EvseNotification::None, 0);
+
+    let body = match tagid {
+        MessageTagId::SessionSetupReq => SessionSetupResponse::new(evse_id, rcode)?.encode(),
+        MessageTagId::ServiceDiscoveryReq => ServiceDiscoveryResponse::new(rcode).encode(),
+        MessageTagId::ServiceDetailReq => ServiceDetailResponse::new(0, rcode).encode(),
+        MessageTagId::PaymentSelectionReq => PaymentSelectionResponse::new(rcode).encode(),
+        MessageTagId::PaymentDetailsReq => PaymentDetailsResponse::new(rcode, &[])?.encode(),
+        MessageTagId::AuthorizationReq => {
+            AuthorizationResponse::new(rcode, EvseProcessing::Finished).encode()
+        }
+        MessageTagId::CertificateInstallReq | MessageTagId::CertificateUpdateReq => {
+            let chain = CertificateChainType::new(&[])?;
+            let private_key = PrivateKeyType::new("", &[])?;
+            let public_key = DhPublicKeyType::new("", &[])?;
+            let emaid = EmaidType::new("", "")?;
+            if tagid == MessageTagId::CertificateInstallReq {
+                CertificateInstallResponse::new(
+                    rcode,
+                    &chain,
+                    &chain,
+                    &private_key,
+                    &public_key,
+                    &emaid,
+                )
+                .encode()
+            } else {
+                CertificateUpdateResponse::new(
+                    rcode,
+                    &chain,
+                    &chain,
+                    &private_key,
+                    &public_key,
+                    &emaid,
+                )
+                .encode()
+            }
+        }
+        MessageTagId::ParamDiscoveryReq => {
+            let mut response = ParamDiscoveryResponse::new(rcode, EvseProcessing::Finished);
+            if dc_mode {
+                let param = DcEvseChargeParam::new(
+                    &dc_status,
+                    &voltage,
+                    &voltage,
+                    &current,
+                    &current,
+                    &PhysicalValue::new(0, 0, PhysicalUnit::Watt),
+                    &current,
+                )?;
+                response.set_evse_dc_charge_param(&param);
+            } else {
+                let param = AcEvseChargeParam::new(&ac_status, &voltage, &current)?;
+                response.set_evse_ac_charge_param(&param);
+            }
+            response.encode()
+        }
+        MessageTagId::PowerDeliveryReq => {
+            let mut response = PowerDeliveryResponse::new(rcode);
+            if dc_mode {
+                response.set_dc_evse_status(&dc_status)?;
+            } else {
+                response.set_ac_evse_status(&ac_status)?;
+            }
+            response.encode()
+        }
+        MessageTagId::MeteringReceiptReq => {
+            let mut response = MeteringReceiptResponse::new(rcode);
+            if dc_mode {
+                response.set_dc_evse_status(&dc_status);
+            } else {
+                response.set_ac_evse_status(&ac_status);
+            }
+            response.encode()
+        }
+        MessageTagId::CableCheckReq => {
+            CableCheckResponse::new(rcode, &dc_status, EvseProcessing::Finished).encode()
+        }
+        MessageTagId::PreChargeReq => PreChargeResponse::new(rcode, &dc_status, &voltage)?.encode(),
+        MessageTagId::CurrentDemandReq => CurrentDemandResponse::new(
+            rcode, evse_id, &dc_status, &current, false, &voltage, false, false, 0,
+        )?
+        .encode(),
+        MessageTagId::ChargingStatusReq => {
+            ChargingStatusResponse::new(rcode, evse_id, 0, &ac_status)?.encode()
+        }
+        MessageTagId::WeldingDetectionReq => {
+            WeldingDetectionResponse::new(rcode, &dc_status, &voltage)?.encode()
+        }
+        MessageTagId::SessionStopReq => SessionStopResponse::new(rcode).encode(),
+        others => {
+            return afb_error!(
+                "iso2-controller-failed",
+                "cannot respond to non request message:{}",
+                others.to_label()
+            )
+        }
+    };
+    Ok(body)
 }
 
 impl IsoController {
-    pub fn new() -> Result<Self, AfbError> {
-        let state = Mutex::new(ControlerState {
-            status: 0,
-            protocol: v2g::V2gProtocolTagId::Unknown,
-            session_id: iso2::SessionId::null(),
-            evccid: iso2::SessionSetupRequest::empty(),
+    pub fn new(handler: Box<dyn Iso2ChargerHandler>) -> Self {
+        let state = Mutex::new(ControllerState {
+            state: SeccState::Idle,
+            protocol: v2g::ProtocolTagId::Unknown,
+            session_id: Vec::new(),
+            evccid: Vec::new(),
+            dc_mode: false,
         });
-        let controler = IsoController {
+        IsoController {
+            handler,
             data_set: state,
-            config: ControlerConfig {},
-        };
-        Ok(controler)
+            recorder: None,
+        }
     }
 
-    #[track_caller]
-    pub fn lock_handle(&self) -> Result<MutexGuard<'_, ControlerState>, AfbError> {
-        let guard = self.data_set.lock().unwrap();
-        Ok(guard)
+    /// Every EV request and SECC response frame is passed to the hook
+    pub fn set_recorder(&mut self, recorder: Arc<dyn V2gRecordHook>) -> &mut Self {
+        self.recorder = Some(recorder);
+        self
     }
 
     #[track_caller]
-    pub fn get_protocol(&self) -> Result<v2g::V2gProtocolTagId, AfbError> {
-        let data_set = self.lock_handle()?;
-        Ok(data_set.protocol.clone())
+    pub fn lock_handle(&self) -> Result<MutexGuard<'_, ControllerState>, AfbError> {
+        match self.data_set.lock() {
+            Ok(guard) => Ok(guard),
+            Err(_) => afb_error!("iso2-controller-lock", "fail to lock controller state"),
+        }
     }
 
-    #[track_caller]
-    pub fn set_protocol(&self, protocol: v2g::V2gProtocolTagId) -> Result<(), AfbError> {
+    pub fn get_state(&self) -> Result<SeccState, AfbError> {
+        Ok(self.lock_handle()?.state)
+    }
+
+    pub fn get_protocol(&self) -> Result<v2g::ProtocolTagId, AfbError> {
+        Ok(self.lock_handle()?.protocol)
+    }
+
+    pub fn get_session_id(&self) -> Result<Vec<u8>, AfbError> {
+        Ok(self.lock_handle()?.session_id.clone())
+    }
+
+    // TCP connection closed by EV or SECC, keep session id to allow a paused session to resume
+    pub fn reset(&self) -> Result<(), AfbError> {
         let mut data_set = self.lock_handle()?;
-        data_set.protocol = protocol;
+        data_set.state = SeccState::Idle;
+        data_set.protocol = v2g::ProtocolTagId::Unknown;
         Ok(())
     }
 
+    /// Decode the EV message from the locked stream and encode the response in place.
+    /// Returns the new controller state, Idle means the connection should be closed.
     pub fn iso_decode_payload(
         &self,
-        stream: &ExiStream,
         lock: &mut MutexGuard<RawStream>,
-    ) -> Result<(), AfbError> {
-        match self.get_protocol()? {
-            v2g::V2gProtocolTagId::Unknown => {
-                // initial message should be v2g::AppHandSupportedAppProtocolReq
-                let v2g_msg = SupportedAppProtocolExi::decode_from_stream(lock)?;
-                let app_protocol_req= match v2g_msg {
-                    v2g::V2gMsgBody::Response(_) =>  return afb_error!(
-                        "iso2-controller-protocol",
-                        "expect 'AppHandSupportedAppProtocolReq' as initial request"
-                    ),
-                    v2g::V2gMsgBody::Request(value) => value
-                };
+    ) -> Result<SeccState, AfbError> {
+        let mut data_set = self.lock_handle()?;
+        if let Some(recorder) = &self.recorder {
+            recorder.record(V2gRecordDirection::EvToEvse, lock.get_buffer());
+        }
+        let state = match data_set.protocol {
+            v2g::ProtocolTagId::Unknown => self.app_protocol(&mut data_set, lock)?,
+            v2g::ProtocolTagId::Iso2 => self.iso2_message(&mut data_set, lock)?,
+            _ => return afb_error!("iso2-controller-payload", "unsupported exi document type"),
+        };
+        if let Some(recorder) = &self.recorder {
+            recorder.record(V2gRecordDirection::EvseToEv, lock.get_buffer());
+        }
+        Ok(state)
+    }
+
+    // initial message should be SupportedAppProtocolReq
+    fn app_protocol(
+        &self,
+        data_set: &mut ControllerState,
+        lock: &mut MutexGuard<RawStream>,
+    ) -> Result<SeccState, AfbError> {
+        match app_protocol_handshake(lock, &[&ISO2_PROTOCOL_CONF])? {
+            Some(protocol) => {
+                data_set.protocol = protocol;
+                data_set.state = SeccState::SessionSetup;
+            }
+            None => data_set.state = SeccState::Idle,
+        }
+        Ok(data_set.state)
+    }
 
-                // compare AppHandSupportedAppProtocolReq with evse supported protocols
-                let (rcode, schema) = app_protocol_req.match_protocol(&V2G_PROTOCOLS_SUPPORTED_LIST)?;
-                let v2g_response= v2g::SupportedAppProtocolRes::new(rcode, schema).encode();
-                SupportedAppProtocolExi::encode_to_stream(lock, &v2g_response)?;
-            }
-            v2g::V2gProtocolTagId::Iso2 => {
-                use iso2::*;
-                let message = Iso2Payload::decode_from_stream(lock)?;
-                let mut data_set = self.lock_handle()?;
-                match message.get_payload() {
-                    MessageBody::SessionSetupReq(request) => {
-                        data_set.evccid = request.clone();
-
-                        afb_log_msg!(
-                            Debug,
-                            None,
-                            "SessionSetupReq evccid:[{}]",
-                            dump_buffer(request.get_id())
-                        );
-
-                        // check if we are facing a new session
-                        let session_id = message.get_session();
-                        let status = if !data_set.session_id.equal(session_id) {
-                            data_set.session_id = session_id.clone();
-                            ResponseCode::NewSession
-                        } else {
-                            ResponseCode::Ok
-                        };
-
-                        // Fulup TBD this should comme from config
-                        let evse_id = "tux-evse-001";
-                        let body = SessionSetupResponse::new(evse_id, status)?.encode();
-
-                        Iso2MessageExi::encode_to_stream(lock, &body, &data_set.session_id)?;
-                    } //end SessionSetupReq
-
-                    MessageBody::ServiceDiscoveryReq(request) => {
-                        let scope = match request.get_scope() {
-                            Some(value) => value.to_string(),
-                            None => "no-scope-defined".to_string(),
-                        };
-
-                        afb_log_msg!(Debug, None, "DiscoverySvcReq optional scope:[{}]", scope);
-                        let charging= ServiceCharging::new("Tux-Evse", "IoT-bzh", false);
-                        let service = ServiceOther::new(56, "LTE", "Network", ServiceCategory::Internet, true);
-                        let transfer= EngyTransfertMode::AcSinglePhase;
-
-                        let body = ServiceDiscoveryResponse::new(ResponseCode::Ok)
-                            .add_payment(PaymentOption::Contract)?
-                            .set_charging(&charging)?
-                            .add_service(&service)?
-                            .add_transfer(transfer)?
-                            .encode();
-
-                        Iso2MessageExi::encode_to_stream(lock, &body, &data_set.session_id)?;
-                    } // end DiscoverySvcReq
-
-                    MessageBody::ServiceDetailReq(request) => {
-                        afb_log_msg!(
-                            Debug,
-                            None,
-                            "ServiceDetailReq service_id:{}",
-                            request.get_id()
-                        );
-
-                        let mut param_set_1 = ParamSet::new(1);
-                        param_set_1.add_param("prm_1", ParamValue::Int16(123))?;
-                        param_set_1
-                            .add_param("prm_2", ParamValue::Text("snoopy".to_string()))?;
-                        param_set_1.add_param(
-                            "prm_3",
-                            ParamValue::PhyValue(PhysicalValue::new(
-                                240,
-                                1,
-                                PhysicalUnit::Volt,
-                            )),
-                        )?;
-
-                        let body =
-                            ServiceDetailResponse::new(request.get_id(), ResponseCode::Ok)
-                                .add_pset(&param_set_1)?
-                                .encode();
-                        Iso2MessageExi::encode_to_stream(lock, &body, &data_set.session_id)?;
-                    } // end ServiceDetailReq
-
-                    _ => {
-                        return afb_error!(
-                            "mgr_handle-exi",
-                            "unsupported iso2 message:[{}]",
-                            stream.dump_buffer(lock, ExiDump::Everything)?
-                        )
-                    }
+    fn iso2_message(
+        &self,
+        data_set: &mut ControllerState,
+        lock: &mut MutexGuard<RawStream>,
+    ) -> Result<SeccState, AfbError> {
+        let message = ExiMessageDoc::decode_from_stream(lock)?;
+        let header = message.get_header();
+        let body = message.get_body()?;
+        let tagid = body.get_tagid();
+        let evse_id = self.handler.get_evse_id();
+        afb_log_msg!(Debug, None, "iso2-controller: received {}", body);
+
+        if !data_set.state.accept(tagid) {
+            afb_log_msg!(
+                Notice,
+                None,
+                "iso2-controller: unexpected {} in state {:?}",
+                tagid.to_label(),
+                data_set.state
+            );
+            let body = failed_body(
+                tagid,
+                ResponseCode::SequenceError,
+                evse_id,
+                data_set.dc_mode,
+            )?;
+            return self.send_response(data_set, lock, body, SeccState::Idle);
+        }
+
+        // except for session setup, EV should reuse SECC provided session id
+        if tagid != MessageTagId::SessionSetupReq && header.get_session_id() != data_set.session_id
+        {
+            let body = failed_body(
+                tagid,
+                ResponseCode::UnknownSession,
+                evse_id,
+                data_set.dc_mode,
+            )?;
+            return self.send_response(data_set, lock, body, SeccState::Idle);
+        }
+
+        let state = data_set.state;
+        let (body, rcode, next) = match body {
+            MessageBody::SessionSetupReq(request) => {
+                let session_id = header.get_session_id();
+                let rcode = if !data_set.session_id.is_empty()
+                    && session_id == data_set.session_id
+                    && request.get_id() == data_set.evccid
+                {
+                    ResponseCode::OldSessionJoin
+                } else {
+                    data_set.session_id = new_session_id();
+                    ResponseCode::NewSession
+                };
+                data_set.evccid = request.get_id().to_vec();
+                afb_log_msg!(
+                    Debug,
+                    None,
+                    "SessionSetupReq evccid:[{}]",
+                    dump_buffer(request.get_id())
+                );
+                let response = self.handler.session_setup(&request, rcode)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::ServiceDiscovery,
+                )
+            }
+            MessageBody::ServiceDiscoveryReq(request) => {
+                let response = self.handler.service_discovery(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::ServiceDetail,
+                )
+            }
+            MessageBody::ServiceDetailReq(request) => {
+                let response = self.handler.service_detail(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::ServiceDetail,
+                )
+            }
+            MessageBody::PaymentSelectionReq(request) => {
+                let response = self.handler.payment_selection(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::PaymentSelected,
+                )
+            }
+            MessageBody::CertificateInstallReq(request) => {
+                let response = self.handler.certificate_install(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::PaymentDetails,
+                )
+            }
+            MessageBody::CertificateUpdateReq(request) => {
+                let response = self.handler.certificate_update(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::PaymentDetails,
+                )
+            }
+            MessageBody::PaymentDetailsReq(request) => {
+                let response = self.handler.payment_details(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::Authorization,
+                )
+            }
+            MessageBody::AuthorizationReq(request) => {
+                let response = self.handler.authorization(&request)?;
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => SeccState::ChargeParam,
+                    _ => SeccState::Authorization,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::ParamDiscoveryReq(request) => {
+                data_set.dc_mode = !matches!(
+                    request.get_transfert_energy_mode()?,
+                    EngyTransfertMode::AcSinglePhase | EngyTransfertMode::AcThreePhase
+                );
+                let response = self.handler.param_discovery(&request)?;
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished if data_set.dc_mode => SeccState::CableCheck,
+                    EvseProcessing::Finished => SeccState::PowerDelivery,
+                    _ => SeccState::ChargeParam,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::CableCheckReq(request) => {
+                let response = self.handler.cable_check(&request)?;
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => SeccState::PreCharge,
+                    _ => SeccState::CableCheck,
                 };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::PreChargeReq(request) => {
+                let response = self.handler.pre_charge(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::PreCharge,
+                )
             }
+            MessageBody::PowerDeliveryReq(request) => {
+                let response = self.handler.power_delivery(&request)?;
+                let next = match request.get_progress()? {
+                    ChargeProgress::Start if data_set.dc_mode => SeccState::DcCharging,
+                    ChargeProgress::Start => SeccState::AcCharging,
+                    ChargeProgress::Renegotiate => SeccState::ChargeParam,
+                    ChargeProgress::Stop => SeccState::Stopping,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::CurrentDemandReq(request) => {
+                let response = self.handler.current_demand(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::DcCharging,
+                )
+            }
+            MessageBody::ChargingStatusReq(request) => {
+                let response = self.handler.charging_status(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::AcCharging,
+                )
+            }
+            MessageBody::MeteringReceiptReq(request) => {
+                let response = self.handler.metering_receipt(&request)?;
+                (response.encode(), response.get_rcode()?, state)
+            }
+            MessageBody::WeldingDetectionReq(request) => {
+                let response = self.handler.welding_detection(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    SeccState::Stopping,
+                )
+            }
+            MessageBody::SessionStopReq(request) => {
+                let response = self.handler.session_stop(&request)?;
+                // only a paused session may later be joined again
+                if let ChargingSessionType::Terminate = request.get_action()? {
+                    data_set.session_id.clear();
+                    data_set.evccid.clear();
+                }
+                (response.encode(), response.get_rcode()?, SeccState::Idle)
+            }
+            _ => {
+                return afb_error!(
+                    "iso2-controller-message",
+                    "unsupported iso2 message:{}",
+                    tagid.to_label()
+                )
+            }
+        };
+
+        let next = if rcode.is_failed() {
+            SeccState::Idle
+        } else {
+            next
+        };
+        self.send_response(data_set, lock, body, next)
+    }
 
-            // unexpected request coming from EV
-            _ => return afb_error!("controller-handle-exi", "unsupported exi document type"),
+    fn send_response(
+        &self,
+        data_set: &mut ControllerState,
+        lock: &mut MutexGuard<RawStream>,
+        body: Iso2BodyType,
+        next: SeccState,
+    ) -> Result<SeccState, AfbError> {
+        let header = ExiMessageHeader::new(&data_set.session_id)?;
+        ExiMessageDoc::new(&header, &body).encode_to_stream(lock)?;
+        data_set.state = next;
+        if next == SeccState::Idle {
+            data_set.protocol = v2g::ProtocolTagId::Unknown;
         }
-        Ok(())
+        Ok(next)
     }
 }
@@ -0,0 +1,469 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   DIN SPEC 70121:2014 §9.1 (message sequencing) & §9.4 (EIM only, DC charging)
+ */
+
+use crate::controller::{app_protocol_handshake, new_session_id};
+use crate::prelude::din_exi::*;
+use crate::prelude::*;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub const DIN_PROTOCOL_CONF: v2g::SupportedAppProtocolConf = v2g::SupportedAppProtocolConf {
+    tag_id: v2g::ProtocolTagId::Din,
+    name: "urn:din:70121:2012:MsgDef",
+    major: 2,
+    minor: 0,
+};
+
+/// DIN SECC communication state. DIN only supports external identification (EIM)
+/// and DC charging, there is no ServiceDetail, PaymentDetails or AC loop.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DinState {
+    Idle,
+    SessionSetup,
+    ServiceDiscovery,
+    PaymentSelection,
+    ContractAuthentication,
+    ChargeParam,
+    CableCheck,
+    PreCharge,
+    CurrentDemand,
+    WeldingDetection,
+}
+
+impl DinState {
+    pub fn allowed(&self) -> &'static [MessageTagId] {
+        use MessageTagId::*;
+        match self {
+            DinState::Idle => &[],
+            DinState::SessionSetup => &[SessionSetupReq],
+            DinState::ServiceDiscovery => &[ServiceDiscoveryReq],
+            DinState::PaymentSelection => &[PaymentSelectionReq],
+            DinState::ContractAuthentication => &[ContractAuthenticationReq],
+            DinState::ChargeParam => &[ParamDiscoveryReq],
+            DinState::CableCheck => &[CableCheckReq],
+            DinState::PreCharge => &[PreChargeReq, PowerDeliveryReq],
+            DinState::CurrentDemand => &[CurrentDemandReq, PowerDeliveryReq],
+            DinState::WeldingDetection => &[WeldingDetectionReq, SessionStopReq],
+        }
+    }
+
+    pub fn accept(&self, tagid: MessageTagId) -> bool {
+        self.allowed().contains(&tagid)
+    }
+}
+
+/// Charger logic plugged into the DIN SECC controller, same contract as Iso2ChargerHandler.
+pub trait DinChargerHandler: Send + Sync {
+    fn get_evse_id(&self) -> &[u8];
+
+    fn service_discovery(
+        &self,
+        request: &ServiceDiscoveryRequest,
+    ) -> Result<ServiceDiscoveryResponse, AfbError>;
+
+    fn contract_authentication(
+        &self,
+        request: &ContractAuthenticationRequest,
+    ) -> Result<ContractAuthenticationResponse, AfbError>;
+
+    fn param_discovery(
+        &self,
+        request: &ParamDiscoveryRequest,
+    ) -> Result<ParamDiscoveryResponse, AfbError>;
+
+    fn cable_check(&self, request: &CableCheckRequest) -> Result<CableCheckResponse, AfbError>;
+
+    fn pre_charge(&self, request: &PreChargeRequest) -> Result<PreChargeResponse, AfbError>;
+
+    fn power_delivery(
+        &self,
+        request: &PowerDeliveryRequest,
+    ) -> Result<PowerDeliveryResponse, AfbError>;
+
+    fn current_demand(
+        &self,
+        request: &CurrentDemandRequest,
+    ) -> Result<CurrentDemandResponse, AfbError>;
+
+    fn welding_detection(
+        &self,
+        request: &WeldingDetectionRequest,
+    ) -> Result<WeldingDetectionResponse, AfbError>;
+
+    fn session_setup(
+        &self,
+        _request: &SessionSetupRequest,
+        rcode: ResponseCode,
+    ) -> Result<SessionSetupResponse, AfbError> {
+        SessionSetupResponse::new(self.get_evse_id(), rcode)
+    }
+
+    // controller already refused contract payment, only service list remains to check
+    fn payment_selection(
+        &self,
+        _request: &PaymentSelectionRequest,
+    ) -> Result<PaymentSelectionResponse, AfbError> {
+        Ok(PaymentSelectionResponse::new(ResponseCode::Ok))
+    }
+
+    fn session_stop(&self, _request: &SessionStopRequest) -> Result<SessionStopResponse, AfbError> {
+        Ok(SessionStopResponse::new(ResponseCode::Ok))
+    }
+}
+
+pub struct DinControllerState {
+    pub state: DinState,
+    pub protocol: v2g::ProtocolTagId,
+    pub session_id: Vec<u8>,
+    pub evccid: Vec<u8>,
+}
+
+pub struct DinController {
+    handler: Box<dyn DinChargerHandler>,
+    data_set: Mutex<DinControllerState>,
+    recorder: Option<Arc<dyn V2gRecordHook>>,
+}
+
+// build a minimal response matching the request when the controller refuses it
+fn failed_body(
+    tagid: MessageTagId,
+    rcode: ResponseCode,
+    evse_id: &[u8],
+) -> Result<DinBodyType, AfbError> {
+    let voltage = PhysicalValue::new(0, 0, PhysicalUnit::Volt);
+    let current = PhysicalValue::new(0, 0, PhysicalUnit::Ampere);
+    let ac_status = AcEvseStatusType::new(EvseNotification::None, 0, false);
+    let dc_status = DcEvseStatusType::new(DcEvseErrorCode::NotReady, EvseNotification::None, 0);
+
+    let body = match tagid {
+        MessageTagId::SessionSetupReq => SessionSetupResponse::new(evse_id, rcode)?.encode(),
+        MessageTagId::ServiceDiscoveryReq => {
+            let tag = ServiceTag::new(0, ServiceCategory::EvCharger);
+            let charging = ServiceCharging::new(&tag, EvRequestTransfertMode::DcExtended, false);
+            ServiceDiscoveryResponse::new(rcode, &charging).encode()
+        }
+        MessageTagId::ServiceDetailReq => ServiceDetailResponse::new(0, rcode).encode(),
+        MessageTagId::PaymentSelectionReq => PaymentSelectionResponse::new(rcode).encode(),
+        MessageTagId::PaymentDetailsReq => PaymentDetailsResponse::new(rcode, "")?.encode(),
+        MessageTagId::ContractAuthenticationReq => {
+            ContractAuthenticationResponse::new(rcode, EvseProcessing::Finished).encode()
+        }
+        MessageTagId::CertificateInstallReq => {
+            let chain = CertificateChainType::new(&[])?;
+            CertificateInstallResponse::new(rcode, "", "", &chain, &[], &[])?.encode()
+        }
+        MessageTagId::CertificateUpdateReq => {
+            let chain = CertificateChainType::new(&[])?;
+            CertificateUpdateResponse::new(rcode, "", "", &chain, &[], &[], 0)?.encode()
+        }
+        MessageTagId::ParamDiscoveryReq => {
+            let param = DcEvseChargeParam::new(
+                &dc_status, &voltage, &voltage, &current, &current, &current,
+            )?;
+            ParamDiscoveryResponse::new(rcode)
+                .set_evse_dc_charge_param(&param)
+                .encode()
+        }
+        MessageTagId::CableCheckReq => {
+            CableCheckResponse::new(rcode, &dc_status, EvseProcessing::Finished).encode()
+        }
+        MessageTagId::PreChargeReq => PreChargeResponse::new(rcode, &dc_status, &voltage)?.encode(),
+        MessageTagId::PowerDeliveryReq => PowerDeliveryResponse::new(rcode)
+            .set_dc_evse_status(&dc_status)?
+            .encode(),
+        MessageTagId::CurrentDemandReq => {
+            CurrentDemandResponse::new(rcode, &dc_status, &voltage, &current, false, false, false)?
+                .encode()
+        }
+        MessageTagId::ChargingStatusReq => {
+            ChargingStatusResponse::new(rcode, evse_id, 0, false, &ac_status)?.encode()
+        }
+        MessageTagId::MeteringReceiptReq => {
+            MeteringReceiptResponse::new(rcode, &ac_status).encode()
+        }
+        MessageTagId::WeldingDetectionReq => {
+            WeldingDetectionResponse::new(rcode, &dc_status, &voltage)?.encode()
+        }
+        MessageTagId::SessionStopReq => SessionStopResponse::new(rcode).encode(),
+        others => {
+            return afb_error!(
+                "din-controller-failed",
+                "cannot respond to non request message:{}",
+                others.to_label()
+            )
+        }
+    };
+    Ok(body)
+}
+
+impl DinController {
+    pub fn new(handler: Box<dyn DinChargerHandler>) -> Self {
+        let state = Mutex::new(DinControllerState {
+            state: DinState::Idle,
+            protocol: v2g::ProtocolTagId::Unknown,
+            session_id: Vec::new(),
+            evccid: Vec::new(),
+        });
+        DinController {
+            handler,
+            data_set: state,
+            recorder: None,
+        }
+    }
+
+    /// Every EV request and SECC response frame is passed to the hook
+    pub fn set_recorder(&mut self, recorder: Arc<dyn V2gRecordHook>) -> &mut Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    #[track_caller]
+    pub fn lock_handle(&self) -> Result<MutexGuard<'_, DinControllerState>, AfbError> {
+        match self.data_set.lock() {
+            Ok(guard) => Ok(guard),
+            Err(_) => afb_error!("din-controller-lock", "fail to lock controller state"),
+        }
+    }
+
+    pub fn get_state(&self) -> Result<DinState, AfbError> {
+        Ok(self.lock_handle()?.state)
+    }
+
+    pub fn get_protocol(&self) -> Result<v2g::ProtocolTagId, AfbError> {
+        Ok(self.lock_handle()?.protocol)
+    }
+
+    pub fn get_session_id(&self) -> Result<Vec<u8>, AfbError> {
+        Ok(self.lock_handle()?.session_id.clone())
+    }
+
+    pub fn reset(&self) -> Result<(), AfbError> {
+        let mut data_set = self.lock_handle()?;
+        data_set.state = DinState::Idle;
+        data_set.protocol = v2g::ProtocolTagId::Unknown;
+        Ok(())
+    }
+
+    /// Decode the EV message from the locked stream and encode the response in place.
+    /// Returns the new controller state, Idle means the connection should be closed.
+    pub fn din_decode_payload(
+        &self,
+        lock: &mut MutexGuard<RawStream>,
+    ) -> Result<DinState, AfbError> {
+        let mut data_set = self.lock_handle()?;
+        if let Some(recorder) = &self.recorder {
+            recorder.record(V2gRecordDirection::EvToEvse, lock.get_buffer());
+        }
+        let state = match data_set.protocol {
+            v2g::ProtocolTagId::Unknown => {
+                match app_protocol_handshake(lock, &[&DIN_PROTOCOL_CONF])? {
+                    Some(protocol) => {
+                        data_set.protocol = protocol;
+                        data_set.state = DinState::SessionSetup;
+                    }
+                    None => data_set.state = DinState::Idle,
+                }
+                data_set.state
+            }
+            v2g::ProtocolTagId::Din => self.din_message(&mut data_set, lock)?,
+            _ => return afb_error!("din-controller-payload", "unsupported exi document type"),
+        };
+        if let Some(recorder) = &self.recorder {
+            recorder.record(V2gRecordDirection::EvseToEv, lock.get_buffer());
+        }
+        Ok(state)
+    }
+
+    fn din_message(
+        &self,
+        data_set: &mut DinControllerState,
+        lock: &mut MutexGuard<RawStream>,
+    ) -> Result<DinState, AfbError> {
+        let message = ExiMessageDoc::decode_from_stream(lock)?;
+        let header = message.get_header();
+        let body = message.get_body()?;
+        let tagid = body.get_tagid();
+        let evse_id = self.handler.get_evse_id();
+        afb_log_msg!(Debug, None, "din-controller: received {}", body);
+
+        if !data_set.state.accept(tagid) {
+            afb_log_msg!(
+                Notice,
+                None,
+                "din-controller: unexpected {} in state {:?}",
+                tagid.to_label(),
+                data_set.state
+            );
+            let body = failed_body(tagid, ResponseCode::SequenceError, evse_id)?;
+            return self.send_response(data_set, lock, body, DinState::Idle);
+        }
+
+        if tagid != MessageTagId::SessionSetupReq && header.get_session_id() != data_set.session_id
+        {
+            let body = failed_body(tagid, ResponseCode::UnknownSession, evse_id)?;
+            return self.send_response(data_set, lock, body, DinState::Idle);
+        }
+
+        let (body, rcode, next) = match body {
+            MessageBody::SessionSetupReq(request) => {
+                let session_id = header.get_session_id();
+                let rcode = if !data_set.session_id.is_empty()
+                    && session_id == data_set.session_id
+                    && request.get_id() == data_set.evccid
+                {
+                    ResponseCode::OldSessionJoin
+                } else {
+                    data_set.session_id = new_session_id();
+                    ResponseCode::NewSession
+                };
+                data_set.evccid = request.get_id().to_vec();
+                let response = self.handler.session_setup(&request, rcode)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::ServiceDiscovery,
+                )
+            }
+            MessageBody::ServiceDiscoveryReq(request) => {
+                let response = self.handler.service_discovery(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::PaymentSelection,
+                )
+            }
+            MessageBody::PaymentSelectionReq(request) => {
+                // DIN only defines external identification means
+                let response = match request.get_option()? {
+                    PaymentOption::External => self.handler.payment_selection(&request)?,
+                    PaymentOption::Contract => {
+                        PaymentSelectionResponse::new(ResponseCode::PaymentSelectionInvalid)
+                    }
+                };
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::ContractAuthentication,
+                )
+            }
+            MessageBody::ContractAuthenticationReq(request) => {
+                let response = self.handler.contract_authentication(&request)?;
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => DinState::ChargeParam,
+                    EvseProcessing::Ongoing => DinState::ContractAuthentication,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::ParamDiscoveryReq(request) => {
+                let response = match request.get_transfert_energy_mode()? {
+                    EvRequestTransfertMode::AcSinglePhase
+                    | EvRequestTransfertMode::AcThreePhase => {
+                        let body =
+                            failed_body(tagid, ResponseCode::WrongEnergyTransferType, evse_id)?;
+                        return self.send_response(data_set, lock, body, DinState::Idle);
+                    }
+                    _ => self.handler.param_discovery(&request)?,
+                };
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => DinState::CableCheck,
+                    EvseProcessing::Ongoing => DinState::ChargeParam,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::CableCheckReq(request) => {
+                let response = self.handler.cable_check(&request)?;
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => DinState::PreCharge,
+                    EvseProcessing::Ongoing => DinState::CableCheck,
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::PreChargeReq(request) => {
+                let response = self.handler.pre_charge(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::PreCharge,
+                )
+            }
+            MessageBody::PowerDeliveryReq(request) => {
+                let response = self.handler.power_delivery(&request)?;
+                let next = if request.get_ready() {
+                    DinState::CurrentDemand
+                } else {
+                    DinState::WeldingDetection
+                };
+                (response.encode(), response.get_rcode()?, next)
+            }
+            MessageBody::CurrentDemandReq(request) => {
+                let response = self.handler.current_demand(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::CurrentDemand,
+                )
+            }
+            MessageBody::WeldingDetectionReq(request) => {
+                let response = self.handler.welding_detection(&request)?;
+                (
+                    response.encode(),
+                    response.get_rcode()?,
+                    DinState::WeldingDetection,
+                )
+            }
+            MessageBody::SessionStopReq(request) => {
+                let response = self.handler.session_stop(&request)?;
+                // DIN has no pause, a stopped session cannot be joined again
+                data_set.session_id.clear();
+                data_set.evccid.clear();
+                (response.encode(), response.get_rcode()?, DinState::Idle)
+            }
+            _ => {
+                return afb_error!(
+                    "din-controller-message",
+                    "unsupported din message:{}",
+                    tagid.to_label()
+                )
+            }
+        };
+
+        let next = if rcode.is_failed() {
+            DinState::Idle
+        } else {
+            next
+        };
+        self.send_response(data_set, lock, body, next)
+    }
+
+    fn send_response(
+        &self,
+        data_set: &mut DinControllerState,
+        lock: &mut MutexGuard<RawStream>,
+        body: DinBodyType,
+        next: DinState,
+    ) -> Result<DinState, AfbError> {
+        let header = ExiMessageHeader::new(&data_set.session_id)?;
+        ExiMessageDoc::new(&header, &body).encode_to_stream(lock)?;
+        data_set.state = next;
+        if next == DinState::Idle {
+            data_set.protocol = v2g::ProtocolTagId::Unknown;
+        }
+        Ok(next)
+    }
+}
@@ -0,0 +1,456 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §8.8.2 (V2G communication states) EVCC side
+ */
+
+use crate::prelude::iso2_exi::*;
+use crate::prelude::*;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// EVCC session state, each state names the next request to send to the SECC.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EvccState {
+    Sdp,
+    AppProtocol,
+    SessionSetup,
+    ServiceDiscovery,
+    PaymentSelection,
+    Authorization,
+    ChargeParam,
+    CableCheck,
+    PreCharge,
+    PowerDeliveryStart,
+    Charging,
+    PowerDeliveryStop,
+    WeldingDetection,
+    SessionStop,
+    Done,
+    /// session failed (ex: SECC changed session id), restart from SDP
+    Idle,
+}
+
+/// EV decisions plugged into the EVCC driver. DC callbacks only need to be
+/// implemented when the vehicle requests a DC energy transfer mode.
+pub trait Iso2VehicleHandler: Send + Sync {
+    fn get_evcc_id(&self) -> &[u8];
+
+    fn get_energy_mode(&self) -> EngyTransfertMode;
+
+    fn is_charging_complete(&self) -> bool;
+
+    fn get_payment(&self) -> PaymentOption {
+        PaymentOption::External
+    }
+
+    fn get_ac_charge_param(&self) -> Result<AcEvChargeParam, AfbError> {
+        afb_error!("evcc-ac-param", "vehicle does not support ac charging")
+    }
+
+    fn get_dc_charge_param(&self) -> Result<DcEvChargeParam, AfbError> {
+        afb_error!("evcc-dc-param", "vehicle does not support dc charging")
+    }
+
+    fn get_dc_status(&self) -> DcEvStatusType {
+        DcEvStatusType::new(true, DcEvErrorCode::NoError, 0)
+    }
+
+    // return (target_voltage, target_current) for pre-charge and current demand
+    fn get_dc_target(&self) -> Result<(PhysicalValue, PhysicalValue), AfbError> {
+        afb_error!("evcc-dc-target", "vehicle does not support dc charging")
+    }
+
+    // iso15118-2 [V2G2-847] EV waits until EVSE voltage is within 20V of its target
+    fn is_precharge_done(&self, evse_voltage: &PhysicalValue) -> bool {
+        let (target, _) = match self.get_dc_target() {
+            Ok(value) => value,
+            Err(_) => return false,
+        };
+        match (
+            target.to_quantity::<Voltage>(),
+            evse_voltage.to_quantity::<Voltage>(),
+        ) {
+            (Ok(target), Ok(voltage)) => (target.to_f64() - voltage.to_f64()).abs() < 20.0,
+            _ => false,
+        }
+    }
+
+    // called with every SECC response matching the expected sequence
+    fn on_response(&self, _body: &MessageBody) -> Result<(), AfbError> {
+        Ok(())
+    }
+}
+
+pub struct EvccSession {
+    pub state: EvccState,
+    pub session_id: Vec<u8>,
+    pub service_id: u16,
+    pub schedule_id: u8,
+    pub dc_mode: bool,
+}
+
+pub struct EvccController {
+    handler: Box<dyn Iso2VehicleHandler>,
+    data_set: Mutex<EvccSession>,
+    recorder: Option<Arc<dyn V2gRecordHook>>,
+}
+
+// expected SECC response for each EVCC request
+fn expected_response(state: EvccState, dc_mode: bool) -> Result<MessageTagId, AfbError> {
+    let tagid = match state {
+        EvccState::SessionSetup => MessageTagId::SessionSetupRes,
+        EvccState::ServiceDiscovery => MessageTagId::ServiceDiscoveryRes,
+        EvccState::PaymentSelection => MessageTagId::PaymentSelectionRes,
+        EvccState::Authorization => MessageTagId::AuthorizationRes,
+        EvccState::ChargeParam => MessageTagId::ParamDiscoveryRes,
+        EvccState::CableCheck => MessageTagId::CableCheckRes,
+        EvccState::PreCharge => MessageTagId::PreChargeRes,
+        EvccState::PowerDeliveryStart | EvccState::PowerDeliveryStop => {
+            MessageTagId::PowerDeliveryRes
+        }
+        EvccState::Charging if dc_mode => MessageTagId::CurrentDemandRes,
+        EvccState::Charging => MessageTagId::ChargingStatusRes,
+        EvccState::WeldingDetection => MessageTagId::WeldingDetectionRes,
+        EvccState::SessionStop => MessageTagId::SessionStopRes,
+        _ => {
+            return afb_error!(
+                "evcc-expected-response",
+                "no iso2 response expected in state:{:?}",
+                state
+            )
+        }
+    };
+    Ok(tagid)
+}
+
+impl EvccController {
+    pub fn new(handler: Box<dyn Iso2VehicleHandler>) -> Self {
+        let dc_mode = !matches!(
+            handler.get_energy_mode(),
+            EngyTransfertMode::AcSinglePhase | EngyTransfertMode::AcThreePhase
+        );
+        let state = Mutex::new(EvccSession {
+            state: EvccState::Sdp,
+            session_id: vec![0; 8],
+            service_id: 1,
+            schedule_id: 1,
+            dc_mode,
+        });
+        EvccController {
+            handler,
+            data_set: state,
+            recorder: None,
+        }
+    }
+
+    /// Every EV request and SECC response frame is passed to the hook
+    pub fn set_recorder(&mut self, recorder: Arc<dyn V2gRecordHook>) -> &mut Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    #[track_caller]
+    pub fn lock_handle(&self) -> Result<MutexGuard<'_, EvccSession>, AfbError> {
+        match self.data_set.lock() {
+            Ok(guard) => Ok(guard),
+            Err(_) => afb_error!("evcc-controller-lock", "fail to lock evcc state"),
+        }
+    }
+
+    pub fn get_state(&self) -> Result<EvccState, AfbError> {
+        Ok(self.lock_handle()?.state)
+    }
+
+    pub fn get_session_id(&self) -> Result<Vec<u8>, AfbError> {
+        Ok(self.lock_handle()?.session_id.clone())
+    }
+
+    fn record(&self, direction: V2gRecordDirection, lock: &MutexGuard<RawStream>) {
+        if let Some(recorder) = &self.recorder {
+            recorder.record(direction, lock.get_buffer());
+        }
+    }
+
+    /// SECC discovery request to broadcast on UDP before opening the TCP session
+    pub fn sdp_request(&self) -> Result<v2g::SdpRequestBuffer, AfbError> {
+        let data_set = self.lock_handle()?;
+        if data_set.state != EvccState::Sdp && data_set.state != EvccState::Idle {
+            return afb_error!("evcc-sdp-request", "unexpected state:{:?}", data_set.state);
+        }
+        v2g::SdpRequest::new(v2g::SdpTransportProtocol::TCP, v2g::SdpSecurityModel::NONE).encode()
+    }
+
+    /// Check SECC discovery response, caller should then connect to returned address/port
+    pub fn sdp_response(
+        &self,
+        buffer: &v2g::SdpResponseBuffer,
+    ) -> Result<v2g::SdpResponse, AfbError> {
+        let mut data_set = self.lock_handle()?;
+        let response = v2g::SdpResponse::decode(buffer)?;
+        response.check_header()?;
+        if response.get_transport()? != v2g::SdpTransportProtocol::TCP {
+            return afb_error!("evcc-sdp-response", "SECC does not offer TCP transport");
+        }
+        data_set.state = EvccState::AppProtocol;
+        Ok(response)
+    }
+
+    /// Encode the request matching current state into the locked stream.
+    pub fn encode_request(&self, lock: &mut MutexGuard<RawStream>) -> Result<EvccState, AfbError> {
+        let data_set = self.lock_handle()?;
+        let body = match data_set.state {
+            EvccState::AppProtocol => {
+                let request = v2g::SupportedAppProtocolReq::new(&ISO2_PROTOCOL_CONF)?;
+                v2g::SupportedAppProtocolExi::encode_to_stream(lock, &request.encode())?;
+                self.record(V2gRecordDirection::EvToEvse, lock);
+                return Ok(data_set.state);
+            }
+            EvccState::SessionSetup => {
+                SessionSetupRequest::new(self.handler.get_evcc_id())?.encode()
+            }
+            EvccState::ServiceDiscovery => ServiceDiscoveryRequest::new().encode(),
+            EvccState::PaymentSelection => PaymentSelectionRequest::new(self.handler.get_payment())
+                .add_service(&SelectedService::new(data_set.service_id))?
+                .encode(),
+            EvccState::Authorization => AuthorizationRequest::new().encode(),
+            EvccState::ChargeParam => {
+                let mut request = ParamDiscoveryRequest::new(self.handler.get_energy_mode());
+                if data_set.dc_mode {
+                    request.set_dc_charge_param(&self.handler.get_dc_charge_param()?)?;
+                } else {
+                    request.set_ac_charge_param(&self.handler.get_ac_charge_param()?)?;
+                }
+                request.encode()
+            }
+            EvccState::CableCheck => CableCheckRequest::new(&self.handler.get_dc_status()).encode(),
+            EvccState::PreCharge => {
+                let (voltage, current) = self.handler.get_dc_target()?;
+                PreChargeRequest::new(&self.handler.get_dc_status(), &voltage, &current)?.encode()
+            }
+            EvccState::PowerDeliveryStart | EvccState::PowerDeliveryStop => {
+                let (progress, complete) = if data_set.state == EvccState::PowerDeliveryStart {
+                    (ChargeProgress::Start, false)
+                } else {
+                    (ChargeProgress::Stop, true)
+                };
+                let mut request = PowerDeliveryRequest::new(progress, data_set.schedule_id);
+                if data_set.dc_mode {
+                    let params =
+                        DcEvPowerDeliveryParam::new(&self.handler.get_dc_status(), complete);
+                    request.set_dc_delivery_params(&params)?;
+                }
+                request.encode()
+            }
+            EvccState::Charging if data_set.dc_mode => {
+                let (voltage, current) = self.handler.get_dc_target()?;
+                CurrentDemandRequest::new(
+                    &self.handler.get_dc_status(),
+                    &current,
+                    &voltage,
+                    self.handler.is_charging_complete(),
+                )
+                .encode()
+            }
+            EvccState::Charging => ChargingStatusRequest::new().encode(),
+            EvccState::WeldingDetection => {
+                WeldingDetectionRequest::new(&self.handler.get_dc_status()).encode()
+            }
+            EvccState::SessionStop => {
+                SessionStopRequest::new(ChargingSessionType::Terminate).encode()
+            }
+            EvccState::Sdp | EvccState::Done | EvccState::Idle => {
+                return afb_error!(
+                    "evcc-encode-request",
+                    "no iso2 request to send in state:{:?}",
+                    data_set.state
+                )
+            }
+        };
+
+        let header = ExiMessageHeader::new(&data_set.session_id)?;
+        ExiMessageDoc::new(&header, &body).encode_to_stream(lock)?;
+        self.record(V2gRecordDirection::EvToEvse, lock);
+        Ok(data_set.state)
+    }
+
+    /// Decode and validate SECC response, then move to next state.
+    pub fn decode_response(&self, lock: &mut MutexGuard<RawStream>) -> Result<EvccState, AfbError> {
+        let mut data_set = self.lock_handle()?;
+        self.record(V2gRecordDirection::EvseToEv, lock);
+
+        if data_set.state == EvccState::AppProtocol {
+            let response = match v2g::SupportedAppProtocolExi::decode_from_stream(lock)? {
+                v2g::V2gMsgBody::Response(value) => value,
+                v2g::V2gMsgBody::Request(_) => {
+                    return afb_error!(
+                        "evcc-app-protocol",
+                        "expect 'SupportedAppProtocolRes' from SECC"
+                    )
+                }
+            };
+            if response.get_rcode()? == v2g::ResponseCode::Failed
+                || response.get_schema() != ISO2_PROTOCOL_CONF.tag_id as u8
+            {
+                return afb_error!(
+                    "evcc-app-protocol",
+                    "SECC refused iso15118-2 rcode:{} schema:{}",
+                    response.get_rcode()?.to_label(),
+                    response.get_schema()
+                );
+            }
+            data_set.state = EvccState::SessionSetup;
+            return Ok(data_set.state);
+        }
+
+        let message = ExiMessageDoc::decode_from_stream(lock)?;
+        let body = message.get_body()?;
+        let tagid = body.get_tagid();
+        let expected = expected_response(data_set.state, data_set.dc_mode)?;
+        if tagid != expected {
+            return afb_error!(
+                "evcc-decode-response",
+                "unexpected response:{} in state:{:?}",
+                tagid.to_label(),
+                data_set.state
+            );
+        }
+
+        // except for session setup, SECC should keep the session id it provided
+        if tagid != MessageTagId::SessionSetupRes
+            && message.get_header().get_session_id() != data_set.session_id
+        {
+            data_set.state = EvccState::Idle;
+            data_set.session_id = vec![0; 8];
+            return afb_error!(
+                "evcc-decode-response",
+                "unknown session id in response:{}",
+                tagid.to_label()
+            );
+        }
+
+        // let EV record SECC values before it gets asked for its next decision
+        self.handler.on_response(&body)?;
+
+        let (rcode, next) = match &body {
+            MessageBody::SessionSetupRes(response) => {
+                data_set.session_id = message.get_header().get_session_id().to_vec();
+                (response.get_rcode()?, EvccState::ServiceDiscovery)
+            }
+            MessageBody::ServiceDiscoveryRes(response) => {
+                let mode = self.handler.get_energy_mode();
+                if !response.get_transfers()?.contains(&mode) {
+                    return afb_error!(
+                        "evcc-service-discovery",
+                        "SECC does not offer energy mode:{}",
+                        mode.to_label()
+                    );
+                }
+                if let Some(charging) = response.get_charging() {
+                    data_set.service_id = charging.get_id();
+                }
+                (response.get_rcode()?, EvccState::PaymentSelection)
+            }
+            MessageBody::PaymentSelectionRes(response) => {
+                (response.get_rcode()?, EvccState::Authorization)
+            }
+            MessageBody::AuthorizationRes(response) => {
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => EvccState::ChargeParam,
+                    _ => EvccState::Authorization,
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::ParamDiscoveryRes(response) => {
+                if let Some(tuple) = response.get_schedule_tuples().first() {
+                    data_set.schedule_id = tuple.get_description();
+                }
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished if data_set.dc_mode => EvccState::CableCheck,
+                    EvseProcessing::Finished => EvccState::PowerDeliveryStart,
+                    _ => EvccState::ChargeParam,
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::CableCheckRes(response) => {
+                let next = match response.get_processing()? {
+                    EvseProcessing::Finished => EvccState::PreCharge,
+                    _ => EvccState::CableCheck,
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::PreChargeRes(response) => {
+                let next = if self.handler.is_precharge_done(&response.get_voltage()) {
+                    EvccState::PowerDeliveryStart
+                } else {
+                    EvccState::PreCharge
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::PowerDeliveryRes(response) => {
+                let next = match data_set.state {
+                    EvccState::PowerDeliveryStart => EvccState::Charging,
+                    _ if data_set.dc_mode => EvccState::WeldingDetection,
+                    _ => EvccState::SessionStop,
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::CurrentDemandRes(response) => {
+                let stop =
+                    response.get_status().get_notification()? == EvseNotification::StopCharging;
+                let next = if stop || self.handler.is_charging_complete() {
+                    EvccState::PowerDeliveryStop
+                } else {
+                    EvccState::Charging
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::ChargingStatusRes(response) => {
+                let stop = response.get_ac_evse_status().get_notification()?
+                    == EvseNotification::StopCharging;
+                let next = if stop || self.handler.is_charging_complete() {
+                    EvccState::PowerDeliveryStop
+                } else {
+                    EvccState::Charging
+                };
+                (response.get_rcode()?, next)
+            }
+            MessageBody::WeldingDetectionRes(response) => {
+                (response.get_rcode()?, EvccState::SessionStop)
+            }
+            MessageBody::SessionStopRes(response) => (response.get_rcode()?, EvccState::Done),
+            _ => {
+                return afb_error!(
+                    "evcc-decode-response",
+                    "unsupported response:{}",
+                    tagid.to_label()
+                )
+            }
+        };
+
+        if rcode.is_failed() {
+            return afb_error!(
+                "evcc-decode-response",
+                "SECC {} failed rcode:{}",
+                tagid.to_label(),
+                rcode.to_label()
+            );
+        }
+
+        data_set.state = next;
+        Ok(next)
+    }
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::prelude::*;
+
+/// Owned encode/decode on byte slices, V2GTP header included. Each call uses its own
+/// private stream, no ExiStream/Mutex is needed and calls may run in parallel.
+pub trait ExiCodec: Sized {
+    /// V2GTP payload type written by encode and expected by decode
+    fn get_payload_id() -> v2g::PayloadMsgId;
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError>;
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError>;
+
+    /// Encode message into a new V2GTP frame
+    #[track_caller]
+    fn encode(&self) -> Result<Vec<u8>, AfbError> {
+        let mut stream = RawStream::new();
+        self.to_raw_stream(&mut stream)?;
+        Ok(stream.get_buffer().to_vec())
+    }
+
+    /// Encode message into caller buffer and return the V2GTP frame size
+    #[track_caller]
+    fn encode_into(&self, buffer: &mut [u8]) -> Result<usize, AfbError> {
+        let mut stream = RawStream::new();
+        self.to_raw_stream(&mut stream)?;
+        let frame = stream.get_buffer();
+        if frame.len() > buffer.len() {
+            return afb_error!(
+                "exi-codec-encode",
+                "buffer too small len:{} frame:{}",
+                buffer.len(),
+                frame.len()
+            );
+        }
+        buffer[0..frame.len()].copy_from_slice(frame);
+        Ok(frame.len())
+    }
+
+    /// Decode one complete V2GTP frame, partial or trailing bytes are rejected
+    #[track_caller]
+    fn decode(data: &[u8]) -> Result<Self, AfbError> {
+        let mut stream = RawStream::new();
+        match stream.push_chunk(data)? {
+            (count, V2gtpChunk::Complete(payload_id)) => {
+                if count != data.len() {
+                    afb_error!(
+                        "exi-codec-decode",
+                        "trailing bytes:{} after v2gtp frame",
+                        data.len() - count
+                    )
+                } else if payload_id != Self::get_payload_id() {
+                    afb_error!(
+                        "exi-codec-decode",
+                        "unexpected payload type:{:?} expected:{:?}",
+                        payload_id,
+                        Self::get_payload_id()
+                    )
+                } else {
+                    Self::from_raw_stream(&mut stream)
+                }
+            }
+            (_, V2gtpChunk::Partial) => afb_error!(
+                "exi-codec-decode",
+                "incomplete v2gtp frame len:{}",
+                data.len()
+            ),
+        }
+    }
+}
+
+impl ExiCodec for v2g::V2gMsgBody {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::SAP
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        let document = match self {
+            v2g::V2gMsgBody::Request(request) => request.encode(),
+            v2g::V2gMsgBody::Response(response) => response.encode(),
+        };
+        v2g::SupportedAppProtocolExi::encode_to_stream(stream, &document)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        v2g::SupportedAppProtocolExi::decode_from_stream(stream)
+    }
+}
+
+// iso-2 & din reuse SAP payload type (0x8001 EXI encoded V2G message)
+impl ExiCodec for iso2_exi::ExiMessageDoc {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::SAP
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        self.encode_to_stream(stream)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        Self::decode_from_stream(stream)
+    }
+}
+
+impl ExiCodec for din_exi::ExiMessageDoc {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::SAP
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        self.encode_to_stream(stream)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        Self::decode_from_stream(stream)
+    }
+}
+
+impl ExiCodec for iso20_exi::ExiMessageDoc {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::MAIN
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        self.encode_to_stream(stream)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        Self::decode_from_stream(stream)
+    }
+}
+
+impl ExiCodec for iso20_dc_exi::ExiMessageDoc {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::DC_MAIN
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        self.encode_to_stream(stream)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        Self::decode_from_stream(stream)
+    }
+}
+
+impl ExiCodec for iso20_ac_exi::ExiMessageDoc {
+    fn get_payload_id() -> v2g::PayloadMsgId {
+        v2g::PayloadMsgId::AC_MAIN
+    }
+
+    fn to_raw_stream(&self, stream: &mut RawStream) -> Result<(), AfbError> {
+        self.encode_to_stream(stream)
+    }
+
+    fn from_raw_stream(stream: &mut RawStream) -> Result<Self, AfbError> {
+        Self::decode_from_stream(stream)
+    }
+}
@@ -37,6 +37,96 @@ pub fn dump_hexa(buffer: &[u8]) -> String {
     dump
 }
 
+// reverse of dump_buffer/dump_hexa, accepts "0x1,0xfe,..." as well as "01fe..." (blanks & ':' ignored)
+#[track_caller]
+pub fn parse_dump(text: &str) -> Result<Vec<u8>, AfbError> {
+    let mut buffer = Vec::new();
+    if text.contains("0x") {
+        for token in text.split(|c: char| c == ',' || c.is_whitespace()) {
+            if token.is_empty() {
+                continue;
+            }
+            let digits = token.trim_start_matches("0x");
+            match u8::from_str_radix(digits, 16) {
+                Ok(byte) => buffer.push(byte),
+                Err(_) => return afb_error!("parse-dump", "invalid byte:'{}'", token),
+            }
+        }
+    } else {
+        let digits: Vec<char> = text
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != ':')
+            .collect();
+        if digits.len() % 2 != 0 {
+            return afb_error!("parse-dump", "odd hexadecimal length:{}", digits.len());
+        }
+        for pair in digits.chunks(2) {
+            let token: String = pair.iter().collect();
+            match u8::from_str_radix(&token, 16) {
+                Ok(byte) => buffer.push(byte),
+                Err(_) => return afb_error!("parse-dump", "invalid byte:'{}'", token),
+            }
+        }
+    }
+    Ok(buffer)
+}
+
+const BASE64_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// xsd:base64Binary content (certificates, signatures, ...)
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut text = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let block = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let value = ((block[0] as u32) << 16) | ((block[1] as u32) << 8) | block[2] as u32;
+        for idx in 0..4 {
+            if idx <= chunk.len() {
+                text.push(BASE64_TABLE[((value >> (18 - 6 * idx)) & 0x3f) as usize] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    text
+}
+
+// RFC 4648 padded base64, whitespace is ignored, padding and unused bits are checked
+#[track_caller]
+pub fn base64_decode(text: &str) -> Result<Vec<u8>, AfbError> {
+    let letters: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if letters.len() % 4 != 0 {
+        return afb_error!("base64-decode", "invalid base64 length:{}", letters.len());
+    }
+    let padding = letters.iter().rev().take_while(|c| **c == '=').count();
+    if padding > 2 || letters[..letters.len() - padding].contains(&'=') {
+        return afb_error!("base64-decode", "invalid base64 padding");
+    }
+
+    let mut data = Vec::with_capacity(letters.len() / 4 * 3);
+    let mut value: u32 = 0;
+    let mut bits = 0;
+    for letter in &letters[..letters.len() - padding] {
+        let digit = match BASE64_TABLE.iter().position(|c| *c as char == *letter) {
+            Some(digit) => digit as u32,
+            None => return afb_error!("base64-decode", "invalid base64 character:'{}'", letter),
+        };
+        value = (value << 6) | digit;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            data.push(((value >> bits) & 0xff) as u8);
+        }
+    }
+    if value & ((1 << bits) - 1) != 0 {
+        return afb_error!("base64-decode", "invalid base64 trailing bits");
+    }
+    Ok(data)
+}
+
 #[track_caller]
 pub fn dump_string(buffer: &[raw::c_char]) -> String {
     unsafe { std::str::from_utf8_unchecked(std::mem::transmute(buffer)) }.to_string()
@@ -113,25 +203,29 @@ impl ExiStream {
         lock.get_index()
     }
 
-    pub fn drop(&self) {
-        let lock = self.lock_stream();
-        (*lock).drop()
-    }
+    #[deprecated(note = "stream memory is released when ExiStream is dropped")]
+    pub fn drop(&self) {}
 
     pub fn reset(&self, lock: &mut MutexGuard<RawStream>) {
         lock.reset()
     }
 
     // (decode only) remove header from data buffer stream to match exi decoder
-    pub fn finalize(&self, lock: &MutexGuard<RawStream>, doc_size: u32) -> Result<(), AfbError> {
-        match unsafe { lock.stream.as_mut() } {
-            Some(data) => {
-                data.data_size = v2g::SDP_V2G_HEADER_LEN+doc_size as usize;
-                data.byte_pos = v2g::SDP_V2G_HEADER_LEN as usize; // SDP_V2G_HEADER_LEN as usize
-                data.bit_count = 0;
-            }
-            None => return afb_error!("exi-stream-shift", "fail to shift header (invalid stream)"),
-        };
+    pub fn finalize(
+        &self,
+        lock: &mut MutexGuard<RawStream>,
+        doc_size: u32,
+    ) -> Result<(), AfbError> {
+        let data_size = v2g::SDP_V2G_HEADER_LEN + doc_size as usize;
+        if data_size > EXI_MAX_DOCUMENT_SIZE {
+            return afb_error!(
+                "exi-stream-shift",
+                "doc size:{} too big max:{}",
+                data_size,
+                EXI_MAX_DOCUMENT_SIZE
+            );
+        }
+        lock.set_window(data_size, v2g::SDP_V2G_HEADER_LEN);
         Ok(())
     }
 
@@ -153,7 +247,11 @@ impl ExiStream {
         Ok(count)
     }
 
-    pub fn get_payload_id(&self, lock: &MutexGuard<RawStream>) -> v2g::PayloadMsgId {
+    #[track_caller]
+    pub fn get_payload_id(
+        &self,
+        lock: &MutexGuard<RawStream>,
+    ) -> Result<v2g::PayloadMsgId, AfbError> {
         v2g::v2gtp_get_payload_id(lock.buffer.as_ref())
     }
 
@@ -0,0 +1,641 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: V2G session analyzer for pcap/pcapng captures
+ *  - UDP SDP request/response on port 15118
+ *  - TCP reassembly (retransmission & out of order), V2GTP framing, optional TLS decryption
+ *  - SAP frames are appHand until SupportedAppProtocolRes, then DIN or ISO-2 from negotiated schema ID
+ *  - ISO-20 frames are selected from their V2GTP payload type
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §7.10.1 (SDP) §8.2 (SupportedAppProtocol)
+ */
+
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::net::{Ipv6Addr, SocketAddrV6};
+
+const SDP_UDP_PORT: u16 = 15118;
+
+// out of order segments kept per direction before declaring a lost segment
+const TCP_MAX_PENDING: usize = 256;
+
+// ISO-20 appHand namespaces: urn:iso:std:iso:15118:-20:DC, ...:AC, ...
+const PROTO_ISO20_PREFIX: &str = "urn:iso:std:iso:15118:-20";
+
+pub enum V2gTraceContent {
+    SdpRequest {
+        security: v2g::SdpSecurityModel,
+        transport: v2g::SdpTransportProtocol,
+    },
+    SdpResponse {
+        addr: Ipv6Addr,
+        port: u16,
+        security: v2g::SdpSecurityModel,
+        transport: v2g::SdpTransportProtocol,
+    },
+    Message(V2gDocument),
+    Info(String),
+    Error(String),
+}
+
+/// One timeline entry, timestamp in microseconds since epoch
+pub struct V2gTraceEvent {
+    pub timestamp: u64,
+    pub source: SocketAddrV6,
+    pub destination: SocketAddrV6,
+    pub tls: bool,
+    pub content: V2gTraceContent,
+}
+
+#[derive(Default)]
+struct TcpDirection {
+    next_seq: Option<u32>,
+    pending: BTreeMap<u32, Vec<u8>>,
+    v2gtp: Vec<u8>,
+}
+
+impl TcpDirection {
+    // returns in order bytes and lost flag, retransmitted bytes are dropped
+    fn push_segment(&mut self, seq: u32, syn: bool, payload: &[u8]) -> (Vec<u8>, bool) {
+        let seq = if syn { seq.wrapping_add(1) } else { seq };
+        let mut next = *self.next_seq.get_or_insert(seq);
+        let mut data = Vec::new();
+        if payload.is_empty() {
+            return (data, false);
+        }
+        self.pending.insert(seq, payload.to_vec());
+
+        let mut lost = false;
+        loop {
+            let ready = self
+                .pending
+                .keys()
+                .copied()
+                .find(|key| next.wrapping_sub(*key) as i32 >= 0);
+            let key = match ready {
+                Some(key) => key,
+                None if self.pending.len() > TCP_MAX_PENDING => {
+                    // resync on the closest segment, current frame is lost
+                    lost = true;
+                    next = self
+                        .pending
+                        .keys()
+                        .copied()
+                        .min_by_key(|key| key.wrapping_sub(next))
+                        .unwrap_or(next);
+                    continue;
+                }
+                None => break,
+            };
+            let segment = self.pending.remove(&key).unwrap_or_default();
+            let overlap = next.wrapping_sub(key) as usize;
+            if overlap < segment.len() {
+                data.extend_from_slice(&segment[overlap..]);
+                next = next.wrapping_add((segment.len() - overlap) as u32);
+            }
+        }
+        self.next_seq = Some(next);
+        (data, lost)
+    }
+}
+
+enum TcpPayload {
+    Unknown,
+    Clear,
+    Tls(Box<TlsSession>),
+}
+
+struct TcpConnection {
+    client: SocketAddrV6,
+    to_server: TcpDirection,
+    to_client: TcpDirection,
+    payload: TcpPayload,
+    protocol: Option<V2gProtocol>,
+    // EV proposals (schema id, namespace) from SupportedAppProtocolReq
+    proposals: Vec<(u8, String)>,
+}
+
+fn protocol_from_urn(name: &str) -> Option<v2g::ProtocolTagId> {
+    for conf in v2g::V2G_PROTOCOLS_SUPPORTED_LIST {
+        if conf.name == name {
+            return Some(conf.tag_id);
+        }
+    }
+    if name.starts_with(PROTO_ISO20_PREFIX) {
+        return Some(v2g::ProtocolTagId::Iso20);
+    }
+    v2g::ProtocolTagId::from_urn(name).ok()
+}
+
+impl TcpConnection {
+    fn new(client: SocketAddrV6) -> Self {
+        TcpConnection {
+            client,
+            to_server: TcpDirection::default(),
+            to_client: TcpDirection::default(),
+            payload: TcpPayload::Unknown,
+            protocol: None,
+            proposals: Vec::new(),
+        }
+    }
+
+    fn get_direction(&mut self, from_client: bool) -> &mut TcpDirection {
+        if from_client {
+            &mut self.to_server
+        } else {
+            &mut self.to_client
+        }
+    }
+
+    fn push(
+        &mut self,
+        from_client: bool,
+        seq: u32,
+        syn: bool,
+        payload: &[u8],
+        keylog: &TlsKeyLog,
+    ) -> Vec<V2gTraceContent> {
+        let mut contents = Vec::new();
+        let (data, lost) = self
+            .get_direction(from_client)
+            .push_segment(seq, syn, payload);
+        if lost {
+            let direction = self.get_direction(from_client);
+            contents.push(V2gTraceContent::Error(format!(
+                "tcp segment lost, {} pending bytes dropped",
+                direction.v2gtp.len()
+            )));
+            direction.v2gtp.clear();
+        }
+        if data.is_empty() {
+            return contents;
+        }
+
+        if let TcpPayload::Unknown = self.payload {
+            self.payload = if TlsSession::is_tls_record(&data) {
+                TcpPayload::Tls(Box::new(TlsSession::new()))
+            } else {
+                TcpPayload::Clear
+            };
+        }
+
+        let plaintext = match &mut self.payload {
+            TcpPayload::Tls(session) => {
+                let mut plaintext = Vec::new();
+                for event in session.push(from_client, &data, keylog) {
+                    match event {
+                        TlsEvent::ApplicationData(value) => plaintext.extend_from_slice(&value),
+                        TlsEvent::Info(info) => contents.push(V2gTraceContent::Info(info)),
+                        TlsEvent::Error(error) => contents.push(V2gTraceContent::Error(error)),
+                    }
+                }
+                plaintext
+            }
+            _ => data,
+        };
+        if !plaintext.is_empty() {
+            self.push_v2gtp(from_client, &plaintext, &mut contents);
+        }
+        contents
+    }
+
+    fn push_v2gtp(&mut self, from_client: bool, data: &[u8], contents: &mut Vec<V2gTraceContent>) {
+        let direction = self.get_direction(from_client);
+        direction.v2gtp.extend_from_slice(data);
+
+        let mut frames = Vec::new();
+        loop {
+            match V2gtpFrame::decode(&mut direction.v2gtp, EXI_MAX_DOCUMENT_SIZE) {
+                Ok(Some(frame)) => frames.push(frame),
+                Ok(None) => break,
+                Err(error) => {
+                    contents.push(V2gTraceContent::Error(format!(
+                        "{}, {} bytes dropped",
+                        error,
+                        direction.v2gtp.len()
+                    )));
+                    direction.v2gtp.clear();
+                    break;
+                }
+            }
+        }
+
+        for frame in frames {
+            let data = frame.encode();
+            let document = match (frame.payload_id, self.protocol) {
+                (v2g::PayloadMsgId::SAP, Some(V2gProtocol::Din)) => {
+                    V2gDocument::decode_as(V2gProtocol::Din, &data)
+                }
+                (v2g::PayloadMsgId::SAP, Some(V2gProtocol::Iso2)) => {
+                    V2gDocument::decode_as(V2gProtocol::Iso2, &data)
+                }
+                (v2g::PayloadMsgId::SAP, _) => V2gDocument::decode_as(V2gProtocol::AppHand, &data),
+                _ => V2gDocument::decode(&data),
+            };
+
+            // negotiated codec failure falls back on payload type detection
+            match document.or_else(|_| V2gDocument::decode(&data)) {
+                Ok(document) => {
+                    let negotiation = self.check_negotiation(&document);
+                    contents.push(V2gTraceContent::Message(document));
+                    if let Some(content) = negotiation {
+                        contents.push(content);
+                    }
+                }
+                Err(error) => contents.push(V2gTraceContent::Error(format!(
+                    "fail to decode {:?} frame len:{} {}",
+                    frame.payload_id,
+                    data.len(),
+                    error
+                ))),
+            }
+        }
+    }
+
+    fn check_negotiation(&mut self, document: &V2gDocument) -> Option<V2gTraceContent> {
+        let response = match document {
+            V2gDocument::AppHand(v2g::V2gMsgBody::Request(request)) => {
+                self.proposals = request
+                    .get_protocols()
+                    .iter()
+                    .map(|proposal| {
+                        let name = proposal.get_name().unwrap_or_default();
+                        (proposal.get_schema(), name.to_string())
+                    })
+                    .collect();
+                return None;
+            }
+            V2gDocument::AppHand(v2g::V2gMsgBody::Response(response)) => response,
+            _ => return None,
+        };
+
+        match response.get_rcode() {
+            Ok(v2g::ResponseCode::Success) | Ok(v2g::ResponseCode::SuccessWithMinorDeviation) => {}
+            _ => return Some(V2gTraceContent::Info("no protocol negotiated".to_string())),
+        }
+
+        // without request, schema id is expected to be the protocol tag id (crate default)
+        let schema = response.get_schema();
+        let tag_id = match self.proposals.iter().find(|(id, _)| *id == schema) {
+            Some((_, name)) => protocol_from_urn(name),
+            None => v2g::ProtocolTagId::try_from(schema).ok(),
+        };
+        let protocol = match tag_id {
+            Some(v2g::ProtocolTagId::Din) => V2gProtocol::Din,
+            Some(v2g::ProtocolTagId::Iso2) => V2gProtocol::Iso2,
+            Some(v2g::ProtocolTagId::Iso20) => V2gProtocol::Iso20,
+            _ => {
+                return Some(V2gTraceContent::Error(format!(
+                    "unknown negotiated schema:{}",
+                    schema
+                )))
+            }
+        };
+        self.protocol = Some(protocol);
+        Some(V2gTraceContent::Info(format!(
+            "negotiated protocol:{} schema:{}",
+            protocol, schema
+        )))
+    }
+}
+
+fn decode_sdp(request: bool, payload: &[u8]) -> Result<V2gTraceContent, AfbError> {
+    if request {
+        let buffer = match <v2g::SdpRequestBuffer>::try_from(payload) {
+            Ok(value) => value,
+            Err(_) => {
+                return afb_error!("pcap-sdp-decode", "invalid request len:{}", payload.len())
+            }
+        };
+        let request = v2g::SdpRequest::decode(&buffer)?;
+        request.check_header()?;
+        Ok(V2gTraceContent::SdpRequest {
+            security: request.get_security()?,
+            transport: request.get_transport()?,
+        })
+    } else {
+        let buffer = match <v2g::SdpResponseBuffer>::try_from(payload) {
+            Ok(value) => value,
+            Err(_) => {
+                return afb_error!("pcap-sdp-decode", "invalid response len:{}", payload.len())
+            }
+        };
+        let response = v2g::SdpResponse::decode(&buffer)?;
+        response.check_header()?;
+        Ok(V2gTraceContent::SdpResponse {
+            addr: Ipv6Addr::from(response.get_addr6()),
+            port: response.get_port(),
+            security: response.get_security()?,
+            transport: response.get_transport()?,
+        })
+    }
+}
+
+/// Rebuild V2G sessions timeline from captured packets
+pub struct V2gPcapAnalyzer {
+    keylog: TlsKeyLog,
+    connections: HashMap<(SocketAddrV6, SocketAddrV6), TcpConnection>,
+    events: Vec<V2gTraceEvent>,
+}
+
+impl V2gPcapAnalyzer {
+    pub fn new() -> Self {
+        V2gPcapAnalyzer {
+            keylog: TlsKeyLog::new(),
+            connections: HashMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// TLS secrets used to decrypt TLS sessions, without key log only handshakes are reported
+    pub fn set_keylog(&mut self, keylog: TlsKeyLog) -> &mut Self {
+        self.keylog = keylog;
+        self
+    }
+
+    /// Decode a complete pcap/pcapng capture and return its timeline
+    #[track_caller]
+    pub fn analyze(&mut self, capture: &[u8]) -> Result<&[V2gTraceEvent], AfbError> {
+        let mut reader = PcapReader::new(capture)?;
+        while let Some(packet) = reader.next_packet()? {
+            self.push_packet(&packet);
+        }
+        Ok(&self.events)
+    }
+
+    pub fn push_packet(&mut self, packet: &PcapPacket) {
+        let segment = match packet.get_segment() {
+            Ok(Some(value)) => value,
+            Ok(None) => return,
+            Err(error) => {
+                let unspecified = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0);
+                self.events.push(V2gTraceEvent {
+                    timestamp: packet.timestamp,
+                    source: unspecified,
+                    destination: unspecified,
+                    tls: false,
+                    content: V2gTraceContent::Error(error.to_string()),
+                });
+                return;
+            }
+        };
+
+        match segment.transport {
+            PcapTransport::Udp => self.push_udp(packet.timestamp, &segment),
+            PcapTransport::Tcp { seq, flags, .. } => {
+                self.push_tcp(packet.timestamp, &segment, seq, flags)
+            }
+        }
+    }
+
+    fn push_udp(&mut self, timestamp: u64, segment: &PcapSegment) {
+        let request = if segment.destination.port() == SDP_UDP_PORT {
+            true
+        } else if segment.source.port() == SDP_UDP_PORT {
+            false
+        } else {
+            return; // not V2G traffic
+        };
+
+        let content = match decode_sdp(request, segment.payload) {
+            Ok(value) => value,
+            Err(error) => V2gTraceContent::Error(error.to_string()),
+        };
+        self.events.push(V2gTraceEvent {
+            timestamp,
+            source: segment.source,
+            destination: segment.destination,
+            tls: false,
+            content,
+        });
+    }
+
+    fn push_tcp(&mut self, timestamp: u64, segment: &PcapSegment, seq: u32, flags: u8) {
+        let syn = flags & TCP_FLAG_SYN != 0;
+        let forward = (segment.source, segment.destination);
+        let backward = (segment.destination, segment.source);
+
+        // SYN starts a new connection (port reuse), otherwise first data sender is the EV
+        let key = if syn && flags & TCP_FLAG_ACK == 0 {
+            self.connections
+                .insert(forward, TcpConnection::new(segment.source));
+            forward
+        } else if self.connections.contains_key(&forward) {
+            forward
+        } else if self.connections.contains_key(&backward) {
+            backward
+        } else if syn {
+            self.connections
+                .insert(backward, TcpConnection::new(segment.destination));
+            backward
+        } else if segment.payload.is_empty() {
+            return;
+        } else {
+            self.connections
+                .insert(forward, TcpConnection::new(segment.source));
+            forward
+        };
+
+        let connection = match self.connections.get_mut(&key) {
+            Some(value) => value,
+            None => return,
+        };
+        let from_client = segment.source == connection.client;
+        let contents = connection.push(from_client, seq, syn, segment.payload, &self.keylog);
+        let tls = matches!(connection.payload, TcpPayload::Tls(_));
+        for content in contents {
+            self.events.push(V2gTraceEvent {
+                timestamp,
+                source: segment.source,
+                destination: segment.destination,
+                tls,
+                content,
+            });
+        }
+    }
+
+    pub fn get_events(&self) -> &[V2gTraceEvent] {
+        &self.events
+    }
+
+    /// One line per event, "seconds.micros source -> destination [tls] content"
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for event in &self.events {
+            text.push_str(&event.to_string());
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl Default for V2gPcapAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for V2gTraceContent {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            V2gTraceContent::SdpRequest {
+                security,
+                transport,
+            } => write!(
+                format,
+                "sdp request security:{:?} transport:{:?}",
+                security, transport
+            ),
+            V2gTraceContent::SdpResponse {
+                addr,
+                port,
+                security,
+                transport,
+            } => write!(
+                format,
+                "sdp response secc:[{}]:{} security:{:?} transport:{:?}",
+                addr, port, security, transport
+            ),
+            V2gTraceContent::Message(document) => {
+                write!(format, "{} ", document.get_protocol())?;
+                match document {
+                    V2gDocument::AppHand(v2g::V2gMsgBody::Request(request)) => {
+                        write!(format, "supportedAppProtocolReq [")?;
+                        for (idx, protocol) in request.get_protocols().iter().enumerate() {
+                            let separator = if idx == 0 { "" } else { ", " };
+                            write!(format, "{}{:?}", separator, protocol)?;
+                        }
+                        write!(format, "]")
+                    }
+                    V2gDocument::AppHand(v2g::V2gMsgBody::Response(response)) => {
+                        write!(format, "supportedAppProtocolRes {:?}", response)
+                    }
+                    V2gDocument::Din(document) => write!(format, "{}", document),
+                    V2gDocument::Iso2(document) => write!(format, "{}", document),
+                    V2gDocument::Iso20(document) => match document.get_body() {
+                        Ok(body) => write!(format, "{}", body.get_tagid().to_label()),
+                        Err(error) => write!(format, "invalid({})", error),
+                    },
+                    V2gDocument::Iso20Dc(document) => match document.get_body() {
+                        Ok(body) => write!(format, "{}", body.get_tagid().to_label()),
+                        Err(error) => write!(format, "invalid({})", error),
+                    },
+                    V2gDocument::Iso20Ac(document) => match document.get_body() {
+                        Ok(body) => write!(format, "{}", body.get_tagid().to_label()),
+                        Err(error) => write!(format, "invalid({})", error),
+                    },
+                }
+            }
+            V2gTraceContent::Info(info) => write!(format, "info: {}", info),
+            V2gTraceContent::Error(error) => write!(format, "error: {}", error),
+        }
+    }
+}
+
+impl fmt::Display for V2gTraceEvent {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            format,
+            "{}.{:06} {} -> {}{} {}",
+            self.timestamp / 1_000_000,
+            self.timestamp % 1_000_000,
+            self.source,
+            self.destination,
+            if self.tls { " tls" } else { "" },
+            self.content
+        )
+    }
+}
+
+// JSON timeline: {"timestamp":..,"source":..,"destination":..,"tls":..,"event":"message","protocol":..,"message":..}
+#[cfg(feature = "serde")]
+mod serde_trace {
+    use super::*;
+    use serde::{Serialize, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(tag = "event", rename_all = "kebab-case")]
+    enum ContentJson<'a> {
+        SdpRequest {
+            security: String,
+            transport: String,
+        },
+        SdpResponse {
+            addr: String,
+            port: u16,
+            security: String,
+            transport: String,
+        },
+        Message(&'a V2gDocument),
+        Info {
+            info: &'a str,
+        },
+        Error {
+            error: &'a str,
+        },
+    }
+
+    #[derive(Serialize)]
+    struct EventJson<'a> {
+        timestamp: u64,
+        source: String,
+        destination: String,
+        tls: bool,
+        #[serde(flatten)]
+        content: ContentJson<'a>,
+    }
+
+    impl Serialize for V2gTraceEvent {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let content = match &self.content {
+                V2gTraceContent::SdpRequest {
+                    security,
+                    transport,
+                } => ContentJson::SdpRequest {
+                    security: format!("{:?}", security),
+                    transport: format!("{:?}", transport),
+                },
+                V2gTraceContent::SdpResponse {
+                    addr,
+                    port,
+                    security,
+                    transport,
+                } => ContentJson::SdpResponse {
+                    addr: addr.to_string(),
+                    port: *port,
+                    security: format!("{:?}", security),
+                    transport: format!("{:?}", transport),
+                },
+                V2gTraceContent::Message(document) => ContentJson::Message(document),
+                V2gTraceContent::Info(info) => ContentJson::Info { info },
+                V2gTraceContent::Error(error) => ContentJson::Error { error },
+            };
+            EventJson {
+                timestamp: self.timestamp,
+                source: self.source.to_string(),
+                destination: self.destination.to_string(),
+                tls: self.tls,
+                content,
+            }
+            .serialize(serializer)
+        }
+    }
+}
@@ -0,0 +1,728 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: pcap/pcapng capture reader & writer
+ *  - classic pcap (micro/nano second, both byte orders) and pcapng (EPB/SPB/OPB blocks)
+ *  - Ethernet (802.1Q), Linux cooked (SLL/SLL2), raw IP & BSD loopback link types
+ *  - only IPv6 UDP/TCP segments are extracted, V2G never uses IPv4 [V2G2-001]
+ *
+ * Reference:
+ *   https://www.tcpdump.org/manpages/pcap-savefile.5.txt
+ *   https://www.ietf.org/archive/id/draft-ietf-opsawg-pcapng-02.html
+ */
+
+use crate::prelude::*;
+use std::net::{Ipv6Addr, SocketAddrV6};
+
+const PCAP_MAGIC_USEC: u32 = 0xa1b2c3d4;
+const PCAP_MAGIC_NSEC: u32 = 0xa1b23c4d;
+const PCAP_HEADER_LEN: usize = 24;
+const PCAP_RECORD_LEN: usize = 16;
+
+const PCAPNG_SECTION_BLOCK: u32 = 0x0a0d0d0a;
+const PCAPNG_BYTE_ORDER: u32 = 0x1a2b3c4d;
+const PCAPNG_INTERFACE_BLOCK: u32 = 0x00000001;
+const PCAPNG_OBSOLETE_PACKET_BLOCK: u32 = 0x00000002;
+const PCAPNG_SIMPLE_PACKET_BLOCK: u32 = 0x00000003;
+const PCAPNG_ENHANCED_PACKET_BLOCK: u32 = 0x00000006;
+const PCAPNG_OPTION_TSRESOL: u16 = 9;
+
+pub const PCAP_LINKTYPE_NULL: u32 = 0;
+pub const PCAP_LINKTYPE_ETHERNET: u32 = 1;
+pub const PCAP_LINKTYPE_RAW: u32 = 101;
+pub const PCAP_LINKTYPE_LINUX_SLL: u32 = 113;
+pub const PCAP_LINKTYPE_IPV6: u32 = 229;
+pub const PCAP_LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88a8;
+
+const IPV6_HEADER_LEN: usize = 40;
+const IP_PROTO_HOPOPTS: u8 = 0;
+const IP_PROTO_TCP: u8 = 6;
+const IP_PROTO_UDP: u8 = 17;
+const IP_PROTO_ROUTING: u8 = 43;
+const IP_PROTO_FRAGMENT: u8 = 44;
+const IP_PROTO_AUTH: u8 = 51;
+const IP_PROTO_DSTOPTS: u8 = 60;
+
+pub const TCP_FLAG_FIN: u8 = 0x01;
+pub const TCP_FLAG_SYN: u8 = 0x02;
+pub const TCP_FLAG_RST: u8 = 0x04;
+pub const TCP_FLAG_PSH: u8 = 0x08;
+pub const TCP_FLAG_ACK: u8 = 0x10;
+
+#[derive(Clone, Copy, PartialEq)]
+enum PcapFormat {
+    Pcap { swapped: bool, nano: bool },
+    PcapNg { swapped: bool },
+}
+
+// pcapng interface description, timestamps are converted to microseconds
+#[derive(Clone, Copy)]
+struct PcapInterface {
+    linktype: u32,
+    ticks_per_second: u64,
+}
+
+/// One captured link layer frame, timestamp in microseconds since epoch
+pub struct PcapPacket<'a> {
+    pub timestamp: u64,
+    pub linktype: u32,
+    pub data: &'a [u8],
+}
+
+pub enum PcapTransport {
+    Udp,
+    Tcp { seq: u32, ack: u32, flags: u8 },
+}
+
+/// IPv6 UDP datagram or TCP segment extracted from a captured packet
+pub struct PcapSegment<'a> {
+    pub source: SocketAddrV6,
+    pub destination: SocketAddrV6,
+    pub transport: PcapTransport,
+    pub payload: &'a [u8],
+}
+
+fn read_u16(data: &[u8], offset: usize, swapped: bool) -> u16 {
+    let value = u16::from_le_bytes([data[offset], data[offset + 1]]);
+    if swapped {
+        value.swap_bytes()
+    } else {
+        value
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize, swapped: bool) -> u32 {
+    let value = u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ]);
+    if swapped {
+        value.swap_bytes()
+    } else {
+        value
+    }
+}
+
+fn read_be16(data: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_be32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+fn ticks_to_usec(ticks: u64, ticks_per_second: u64) -> u64 {
+    if ticks_per_second == 1_000_000 {
+        ticks
+    } else {
+        ((ticks as u128 * 1_000_000) / ticks_per_second as u128) as u64
+    }
+}
+
+/// Sequential packet reader over a complete pcap or pcapng capture
+pub struct PcapReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    format: PcapFormat,
+    interfaces: Vec<PcapInterface>,
+}
+
+impl<'a> PcapReader<'a> {
+    #[track_caller]
+    pub fn new(data: &'a [u8]) -> Result<Self, AfbError> {
+        if data.len() < 12 {
+            return afb_error!("pcap-reader-new", "capture too short len:{}", data.len());
+        }
+
+        let magic = read_u32(data, 0, false);
+        let (format, offset) = if magic == PCAPNG_SECTION_BLOCK {
+            let swapped = match read_u32(data, 8, false) {
+                PCAPNG_BYTE_ORDER => false,
+                value if value.swap_bytes() == PCAPNG_BYTE_ORDER => true,
+                value => {
+                    return afb_error!("pcap-reader-new", "invalid pcapng byte order:{:#x}", value)
+                }
+            };
+            (PcapFormat::PcapNg { swapped }, 0)
+        } else {
+            let (swapped, nano) = match magic {
+                PCAP_MAGIC_USEC => (false, false),
+                PCAP_MAGIC_NSEC => (false, true),
+                value if value.swap_bytes() == PCAP_MAGIC_USEC => (true, false),
+                value if value.swap_bytes() == PCAP_MAGIC_NSEC => (true, true),
+                value => {
+                    return afb_error!("pcap-reader-new", "unknown capture magic:{:#x}", value)
+                }
+            };
+            if data.len() < PCAP_HEADER_LEN {
+                return afb_error!("pcap-reader-new", "truncated pcap header");
+            }
+            (PcapFormat::Pcap { swapped, nano }, PCAP_HEADER_LEN)
+        };
+
+        let mut reader = PcapReader {
+            data,
+            offset,
+            format,
+            interfaces: Vec::new(),
+        };
+
+        // classic pcap has a single implicit interface
+        if let PcapFormat::Pcap { swapped, nano } = format {
+            reader.interfaces.push(PcapInterface {
+                linktype: read_u32(data, 20, swapped) & 0x0fff_ffff,
+                ticks_per_second: if nano { 1_000_000_000 } else { 1_000_000 },
+            });
+        }
+        Ok(reader)
+    }
+
+    /// Next packet or None at end of capture, truncated trailing records are ignored
+    #[track_caller]
+    pub fn next_packet(&mut self) -> Result<Option<PcapPacket<'a>>, AfbError> {
+        match self.format {
+            PcapFormat::Pcap { swapped, .. } => self.next_pcap(swapped),
+            PcapFormat::PcapNg { .. } => self.next_pcapng(),
+        }
+    }
+
+    fn next_pcap(&mut self, swapped: bool) -> Result<Option<PcapPacket<'a>>, AfbError> {
+        let data = self.data;
+        if self.offset + PCAP_RECORD_LEN > data.len() {
+            return Ok(None);
+        }
+        let seconds = read_u32(data, self.offset, swapped) as u64;
+        let fraction = read_u32(data, self.offset + 4, swapped) as u64;
+        let caplen = read_u32(data, self.offset + 8, swapped) as usize;
+        let start = self.offset + PCAP_RECORD_LEN;
+        if start + caplen > data.len() {
+            return Ok(None);
+        }
+        self.offset = start + caplen;
+
+        let interface = self.interfaces[0];
+        Ok(Some(PcapPacket {
+            timestamp: seconds * 1_000_000 + ticks_to_usec(fraction, interface.ticks_per_second),
+            linktype: interface.linktype,
+            data: &data[start..start + caplen],
+        }))
+    }
+
+    fn next_pcapng(&mut self) -> Result<Option<PcapPacket<'a>>, AfbError> {
+        let data = self.data;
+        loop {
+            if self.offset + 12 > data.len() {
+                return Ok(None);
+            }
+
+            // section header block may switch byte order, block type is a palindrome
+            let block_type = read_u32(data, self.offset, false);
+            if block_type == PCAPNG_SECTION_BLOCK {
+                let swapped = read_u32(data, self.offset + 8, false) != PCAPNG_BYTE_ORDER;
+                self.format = PcapFormat::PcapNg { swapped };
+                self.interfaces.clear();
+            }
+            let swapped = match self.format {
+                PcapFormat::PcapNg { swapped } => swapped,
+                PcapFormat::Pcap { swapped, .. } => swapped,
+            };
+
+            let block_len = read_u32(data, self.offset + 4, swapped) as usize;
+            if block_len < 12 || !block_len.is_multiple_of(4) {
+                return afb_error!(
+                    "pcap-reader-block",
+                    "invalid pcapng block len:{} offset:{}",
+                    block_len,
+                    self.offset
+                );
+            }
+            if self.offset + block_len > data.len() {
+                return Ok(None);
+            }
+            let body = &data[self.offset + 8..self.offset + block_len - 4];
+            self.offset += block_len;
+
+            match block_type {
+                PCAPNG_INTERFACE_BLOCK if body.len() >= 8 => {
+                    self.interfaces.push(PcapInterface {
+                        linktype: read_u16(body, 0, swapped) as u32,
+                        ticks_per_second: Self::get_tsresol(&body[8..], swapped),
+                    });
+                }
+                PCAPNG_ENHANCED_PACKET_BLOCK if body.len() >= 20 => {
+                    let interface = self.get_interface(read_u32(body, 0, swapped) as usize)?;
+                    let ticks = ((read_u32(body, 4, swapped) as u64) << 32)
+                        | read_u32(body, 8, swapped) as u64;
+                    let caplen = read_u32(body, 12, swapped) as usize;
+                    if 20 + caplen > body.len() {
+                        return afb_error!("pcap-reader-block", "truncated enhanced packet");
+                    }
+                    return Ok(Some(PcapPacket {
+                        timestamp: ticks_to_usec(ticks, interface.ticks_per_second),
+                        linktype: interface.linktype,
+                        data: &body[20..20 + caplen],
+                    }));
+                }
+                PCAPNG_OBSOLETE_PACKET_BLOCK if body.len() >= 20 => {
+                    let interface = self.get_interface(read_u16(body, 0, swapped) as usize)?;
+                    let ticks = ((read_u32(body, 4, swapped) as u64) << 32)
+                        | read_u32(body, 8, swapped) as u64;
+                    let caplen = read_u32(body, 12, swapped) as usize;
+                    if 20 + caplen > body.len() {
+                        return afb_error!("pcap-reader-block", "truncated packet block");
+                    }
+                    return Ok(Some(PcapPacket {
+                        timestamp: ticks_to_usec(ticks, interface.ticks_per_second),
+                        linktype: interface.linktype,
+                        data: &body[20..20 + caplen],
+                    }));
+                }
+                // simple packet block has no timestamp and always uses the first interface
+                PCAPNG_SIMPLE_PACKET_BLOCK if body.len() >= 4 => {
+                    let interface = self.get_interface(0)?;
+                    let caplen = (read_u32(body, 0, swapped) as usize).min(body.len() - 4);
+                    return Ok(Some(PcapPacket {
+                        timestamp: 0,
+                        linktype: interface.linktype,
+                        data: &body[4..4 + caplen],
+                    }));
+                }
+                _ => {} // section header, statistics, name resolution, ...
+            }
+        }
+    }
+
+    fn get_interface(&self, index: usize) -> Result<PcapInterface, AfbError> {
+        match self.interfaces.get(index) {
+            Some(interface) => Ok(*interface),
+            None => afb_error!("pcap-reader-block", "undefined pcapng interface:{}", index),
+        }
+    }
+
+    // if_tsresol: MSB clear => 10^-value, MSB set => 2^-value, default microsecond
+    fn get_tsresol(options: &[u8], swapped: bool) -> u64 {
+        let mut offset = 0;
+        while offset + 4 <= options.len() {
+            let code = read_u16(options, offset, swapped);
+            let len = read_u16(options, offset + 2, swapped) as usize;
+            if code == 0 || offset + 4 + len > options.len() {
+                break;
+            }
+            if code == PCAPNG_OPTION_TSRESOL && len >= 1 {
+                let value = options[offset + 4];
+                let exponent = (value & 0x7f) as u32;
+                return if value & 0x80 == 0 {
+                    10u64.checked_pow(exponent).unwrap_or(1_000_000)
+                } else {
+                    2u64.checked_pow(exponent).unwrap_or(1_000_000)
+                };
+            }
+            offset += 4 + ((len + 3) & !3);
+        }
+        1_000_000
+    }
+}
+
+impl<'a> PcapPacket<'a> {
+    /// Extract IPv6 UDP/TCP segment, Ok(None) for any other traffic (IPv4, HomePlug, ...)
+    #[track_caller]
+    pub fn get_segment(&self) -> Result<Option<PcapSegment<'a>>, AfbError> {
+        let data = self.data;
+        let ipv6 = match self.linktype {
+            PCAP_LINKTYPE_ETHERNET => {
+                if data.len() < 14 {
+                    return Ok(None);
+                }
+                let mut offset = 12;
+                let mut ethertype = read_be16(data, offset);
+                while (ethertype == ETHERTYPE_VLAN || ethertype == ETHERTYPE_QINQ)
+                    && data.len() >= offset + 6
+                {
+                    offset += 4;
+                    ethertype = read_be16(data, offset);
+                }
+                if ethertype != ETHERTYPE_IPV6 {
+                    return Ok(None);
+                }
+                &data[offset + 2..]
+            }
+            PCAP_LINKTYPE_LINUX_SLL => {
+                if data.len() < 16 || read_be16(data, 14) != ETHERTYPE_IPV6 {
+                    return Ok(None);
+                }
+                &data[16..]
+            }
+            PCAP_LINKTYPE_LINUX_SLL2 => {
+                if data.len() < 20 || read_be16(data, 0) != ETHERTYPE_IPV6 {
+                    return Ok(None);
+                }
+                &data[20..]
+            }
+            PCAP_LINKTYPE_RAW | PCAP_LINKTYPE_IPV6 => data,
+            // BSD loopback, address family in host byte order (AF_INET6 is 10, 24, 28 or 30)
+            PCAP_LINKTYPE_NULL => {
+                if data.len() < 4 {
+                    return Ok(None);
+                }
+                &data[4..]
+            }
+            linktype => {
+                return afb_error!("pcap-packet-segment", "unsupported link type:{}", linktype)
+            }
+        };
+        parse_ipv6(ipv6)
+    }
+}
+
+fn parse_ipv6(data: &[u8]) -> Result<Option<PcapSegment<'_>>, AfbError> {
+    if data.len() < IPV6_HEADER_LEN || data[0] >> 4 != 6 {
+        return Ok(None);
+    }
+    let payload_len = read_be16(data, 4) as usize;
+    let mut next_header = data[6];
+    let mut source = [0u8; 16];
+    let mut destination = [0u8; 16];
+    source.copy_from_slice(&data[8..24]);
+    destination.copy_from_slice(&data[24..40]);
+
+    // ignore ethernet padding, keep truncated (snaplen) payload as is
+    let end = (IPV6_HEADER_LEN + payload_len).min(data.len());
+    let mut offset = IPV6_HEADER_LEN;
+    loop {
+        match next_header {
+            IP_PROTO_HOPOPTS | IP_PROTO_ROUTING | IP_PROTO_DSTOPTS => {
+                if offset + 8 > end {
+                    return Ok(None);
+                }
+                next_header = data[offset];
+                offset += (data[offset + 1] as usize + 1) * 8;
+            }
+            IP_PROTO_AUTH => {
+                if offset + 8 > end {
+                    return Ok(None);
+                }
+                next_header = data[offset];
+                offset += (data[offset + 1] as usize + 2) * 4;
+            }
+            IP_PROTO_FRAGMENT => {
+                if offset + 8 > end {
+                    return Ok(None);
+                }
+                let fragment = read_be16(data, offset + 2);
+                if fragment & 0xfff9 != 0 {
+                    return afb_error!("pcap-packet-ipv6", "fragmented ipv6 packets not supported");
+                }
+                next_header = data[offset];
+                offset += 8;
+            }
+            _ => break,
+        }
+    }
+    if offset > end {
+        return Ok(None);
+    }
+
+    let segment = &data[offset..end];
+    let (source_port, destination_port, transport, payload) = match next_header {
+        IP_PROTO_UDP => {
+            if segment.len() < 8 {
+                return Ok(None);
+            }
+            let len = (read_be16(segment, 4) as usize).clamp(8, segment.len());
+            (
+                read_be16(segment, 0),
+                read_be16(segment, 2),
+                PcapTransport::Udp,
+                &segment[8..len],
+            )
+        }
+        IP_PROTO_TCP => {
+            if segment.len() < 20 {
+                return Ok(None);
+            }
+            let header_len = ((segment[12] >> 4) as usize * 4).clamp(20, segment.len());
+            (
+                read_be16(segment, 0),
+                read_be16(segment, 2),
+                PcapTransport::Tcp {
+                    seq: read_be32(segment, 4),
+                    ack: read_be32(segment, 8),
+                    flags: segment[13],
+                },
+                &segment[header_len..],
+            )
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(PcapSegment {
+        source: SocketAddrV6::new(Ipv6Addr::from(source), source_port, 0, 0),
+        destination: SocketAddrV6::new(Ipv6Addr::from(destination), destination_port, 0, 0),
+        transport,
+        payload,
+    }))
+}
+
+// internet checksum including IPv6 pseudo header
+fn ipv6_checksum(source: &Ipv6Addr, destination: &Ipv6Addr, proto: u8, segment: &[u8]) -> u16 {
+    let mut sum: u32 = 0;
+    let mut add = |data: &[u8]| {
+        for pair in data.chunks(2) {
+            let word = if pair.len() == 2 {
+                u16::from_be_bytes([pair[0], pair[1]])
+            } else {
+                u16::from_be_bytes([pair[0], 0])
+            };
+            sum += word as u32;
+        }
+    };
+    add(&source.octets());
+    add(&destination.octets());
+    add(&(segment.len() as u32).to_be_bytes());
+    add(&[0, 0, 0, proto]);
+    add(segment);
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    match !(sum as u16) {
+        0 => 0xffff,
+        value => value,
+    }
+}
+
+// link-local addresses map to an ethernet MAC through their modified EUI-64 identifier
+fn ipv6_to_mac(addr: &Ipv6Addr) -> [u8; 6] {
+    let octets = addr.octets();
+    [
+        octets[8] ^ 0x02,
+        octets[9],
+        octets[10],
+        octets[13],
+        octets[14],
+        octets[15],
+    ]
+}
+
+/// Classic pcap (Ethernet, microsecond) writer, used to build sample captures from encoded messages
+pub struct PcapWriter {
+    buffer: Vec<u8>,
+}
+
+impl PcapWriter {
+    pub fn new() -> Self {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&PCAP_MAGIC_USEC.to_le_bytes());
+        buffer.extend_from_slice(&2u16.to_le_bytes());
+        buffer.extend_from_slice(&4u16.to_le_bytes());
+        buffer.extend_from_slice(&0i32.to_le_bytes());
+        buffer.extend_from_slice(&0u32.to_le_bytes());
+        buffer.extend_from_slice(&65535u32.to_le_bytes());
+        buffer.extend_from_slice(&PCAP_LINKTYPE_ETHERNET.to_le_bytes());
+        PcapWriter { buffer }
+    }
+
+    pub fn add_packet(&mut self, timestamp: u64, frame: &[u8]) -> &mut Self {
+        let seconds = (timestamp / 1_000_000) as u32;
+        let micros = (timestamp % 1_000_000) as u32;
+        self.buffer.extend_from_slice(&seconds.to_le_bytes());
+        self.buffer.extend_from_slice(&micros.to_le_bytes());
+        self.buffer
+            .extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        self.buffer
+            .extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        self.buffer.extend_from_slice(frame);
+        self
+    }
+
+    fn add_ipv6(
+        &mut self,
+        timestamp: u64,
+        source: &SocketAddrV6,
+        destination: &SocketAddrV6,
+        proto: u8,
+        segment: &[u8],
+    ) -> &mut Self {
+        let mut frame = Vec::with_capacity(14 + IPV6_HEADER_LEN + segment.len());
+        let destination_mac = if destination.ip().segments()[0] == 0xff02 {
+            [0x33, 0x33, 0, 0, 0, 1]
+        } else {
+            ipv6_to_mac(destination.ip())
+        };
+        frame.extend_from_slice(&destination_mac);
+        frame.extend_from_slice(&ipv6_to_mac(source.ip()));
+        frame.extend_from_slice(&ETHERTYPE_IPV6.to_be_bytes());
+
+        frame.extend_from_slice(&[0x60, 0, 0, 0]);
+        frame.extend_from_slice(&(segment.len() as u16).to_be_bytes());
+        frame.push(proto);
+        frame.push(64);
+        frame.extend_from_slice(&source.ip().octets());
+        frame.extend_from_slice(&destination.ip().octets());
+        frame.extend_from_slice(segment);
+        self.add_packet(timestamp, &frame)
+    }
+
+    pub fn add_udp(
+        &mut self,
+        timestamp: u64,
+        source: &SocketAddrV6,
+        destination: &SocketAddrV6,
+        payload: &[u8],
+    ) -> &mut Self {
+        let mut segment = Vec::with_capacity(8 + payload.len());
+        segment.extend_from_slice(&source.port().to_be_bytes());
+        segment.extend_from_slice(&destination.port().to_be_bytes());
+        segment.extend_from_slice(&((8 + payload.len()) as u16).to_be_bytes());
+        segment.extend_from_slice(&[0, 0]);
+        segment.extend_from_slice(payload);
+        let checksum = ipv6_checksum(source.ip(), destination.ip(), IP_PROTO_UDP, &segment);
+        segment[6..8].copy_from_slice(&checksum.to_be_bytes());
+        self.add_ipv6(timestamp, source, destination, IP_PROTO_UDP, &segment)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_tcp(
+        &mut self,
+        timestamp: u64,
+        source: &SocketAddrV6,
+        destination: &SocketAddrV6,
+        seq: u32,
+        ack: u32,
+        flags: u8,
+        payload: &[u8],
+    ) -> &mut Self {
+        let mut segment = Vec::with_capacity(20 + payload.len());
+        segment.extend_from_slice(&source.port().to_be_bytes());
+        segment.extend_from_slice(&destination.port().to_be_bytes());
+        segment.extend_from_slice(&seq.to_be_bytes());
+        segment.extend_from_slice(&ack.to_be_bytes());
+        segment.extend_from_slice(&[0x50, flags]);
+        segment.extend_from_slice(&65535u16.to_be_bytes());
+        segment.extend_from_slice(&[0, 0, 0, 0]);
+        segment.extend_from_slice(payload);
+        let checksum = ipv6_checksum(source.ip(), destination.ip(), IP_PROTO_TCP, &segment);
+        segment[16..18].copy_from_slice(&checksum.to_be_bytes());
+        self.add_ipv6(timestamp, source, destination, IP_PROTO_TCP, &segment)
+    }
+
+    pub fn get_buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+impl Default for PcapWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// TCP connection helper for PcapWriter, keeps track of both sides sequence numbers
+pub struct PcapTcpFlow {
+    client: SocketAddrV6,
+    server: SocketAddrV6,
+    client_seq: u32,
+    server_seq: u32,
+}
+
+impl PcapTcpFlow {
+    pub fn new(client: SocketAddrV6, server: SocketAddrV6) -> Self {
+        PcapTcpFlow {
+            client,
+            server,
+            client_seq: 0x1000_0000,
+            server_seq: 0x2000_0000,
+        }
+    }
+
+    /// SYN, SYN/ACK, ACK three way handshake
+    pub fn connect(&mut self, writer: &mut PcapWriter, timestamp: u64) -> &mut Self {
+        writer.add_tcp(
+            timestamp,
+            &self.client,
+            &self.server,
+            self.client_seq,
+            0,
+            TCP_FLAG_SYN,
+            &[],
+        );
+        self.client_seq = self.client_seq.wrapping_add(1);
+        writer.add_tcp(
+            timestamp + 100,
+            &self.server,
+            &self.client,
+            self.server_seq,
+            self.client_seq,
+            TCP_FLAG_SYN | TCP_FLAG_ACK,
+            &[],
+        );
+        self.server_seq = self.server_seq.wrapping_add(1);
+        writer.add_tcp(
+            timestamp + 200,
+            &self.client,
+            &self.server,
+            self.client_seq,
+            self.server_seq,
+            TCP_FLAG_ACK,
+            &[],
+        );
+        self
+    }
+
+    pub fn send(
+        &mut self,
+        writer: &mut PcapWriter,
+        timestamp: u64,
+        from_client: bool,
+        payload: &[u8],
+    ) -> &mut Self {
+        let flags = TCP_FLAG_PSH | TCP_FLAG_ACK;
+        if from_client {
+            writer.add_tcp(
+                timestamp,
+                &self.client,
+                &self.server,
+                self.client_seq,
+                self.server_seq,
+                flags,
+                payload,
+            );
+            self.client_seq = self.client_seq.wrapping_add(payload.len() as u32);
+        } else {
+            writer.add_tcp(
+                timestamp,
+                &self.server,
+                &self.client,
+                self.server_seq,
+                self.client_seq,
+                flags,
+                payload,
+            );
+            self.server_seq = self.server_seq.wrapping_add(payload.len() as u32);
+        }
+        self
+    }
+}
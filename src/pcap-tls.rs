@@ -0,0 +1,715 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: passive TLS record decryption from a NSS key log (SSLKEYLOGFILE)
+ *  - TLS-1.2 CLIENT_RANDOM master secret, AES-CBC-SHA256 (ISO-2 mandatory suites), AES-GCM & ChaCha20
+ *  - TLS-1.3 handshake/traffic secrets, AES-GCM & ChaCha20 (ISO-20)
+ *  - only key material is used, certificates & signatures are never checked
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §7.7.3.8 [V2G2-068] (TLS cipher suites)
+ *   RFC 5246 (TLS-1.2) RFC 5288 (AES-GCM) RFC 7905 (ChaCha20) RFC 8446 (TLS-1.3)
+ *   https://firefox-source-docs.mozilla.org/security/nss/legacy/key_log_format/index.html
+ */
+
+use crate::prelude::*;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use cbc::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
+use chacha20poly1305::ChaCha20Poly1305;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use sha2::{Sha256, Sha384};
+use std::collections::HashMap;
+use std::fs;
+
+const TLS_CHANGE_CIPHER_SPEC: u8 = 20;
+const TLS_ALERT: u8 = 21;
+const TLS_HANDSHAKE: u8 = 22;
+const TLS_APPLICATION_DATA: u8 = 23;
+
+const TLS_RECORD_HEADER_LEN: usize = 5;
+const TLS_RECORD_MAX_LEN: usize = 16384 + 2048;
+const TLS_CLIENT_HELLO: u8 = 1;
+const TLS_SERVER_HELLO: u8 = 2;
+const TLS_EXTENSION_SUPPORTED_VERSIONS: u16 = 0x002b;
+
+const TLS_VERSION_12: u16 = 0x0303;
+const TLS_VERSION_13: u16 = 0x0304;
+const TLS_AEAD_TAG_LEN: usize = 16;
+const TLS_SHA256_LEN: usize = 32;
+
+/// Secrets from a NSS key log file, indexed by label and client random
+#[derive(Default)]
+pub struct TlsKeyLog {
+    secrets: HashMap<(String, Vec<u8>), Vec<u8>>,
+}
+
+impl TlsKeyLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse key log text, one "LABEL <client_random> <secret>" per line, '#' for comments
+    #[track_caller]
+    pub fn parse(text: &str) -> Result<Self, AfbError> {
+        let mut keylog = TlsKeyLog::new();
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() != 3 {
+                return afb_error!(
+                    "tls-keylog-parse",
+                    "line:{} expect 'LABEL <client_random> <secret>'",
+                    idx + 1
+                );
+            }
+            let client_random = parse_dump(tokens[1])?;
+            let secret = parse_dump(tokens[2])?;
+            keylog.add_secret(tokens[0], &client_random, &secret);
+        }
+        Ok(keylog)
+    }
+
+    #[track_caller]
+    pub fn from_file(path: &str) -> Result<Self, AfbError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(error) => afb_error!("tls-keylog-file", "fail to read {}:{}", path, error),
+        }
+    }
+
+    pub fn add_secret(&mut self, label: &str, client_random: &[u8], secret: &[u8]) -> &mut Self {
+        self.secrets.insert(
+            (label.to_uppercase(), client_random.to_vec()),
+            secret.to_vec(),
+        );
+        self
+    }
+
+    pub fn get_secret(&self, label: &str, client_random: &[u8]) -> Option<&[u8]> {
+        self.secrets
+            .get(&(label.to_string(), client_random.to_vec()))
+            .map(|value| value.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.secrets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TlsHash {
+    Sha256,
+    Sha384,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TlsBulk {
+    Aes128Gcm,
+    Aes256Gcm,
+    ChaCha20,
+    Aes128Cbc,
+    Aes256Cbc,
+}
+
+#[derive(Clone, Copy)]
+struct TlsSuite {
+    bulk: TlsBulk,
+    hash: TlsHash,
+}
+
+impl TlsSuite {
+    fn from_id(id: u16) -> Option<Self> {
+        let (bulk, hash) = match id {
+            // TLS-1.3
+            0x1301 => (TlsBulk::Aes128Gcm, TlsHash::Sha256),
+            0x1302 => (TlsBulk::Aes256Gcm, TlsHash::Sha384),
+            0x1303 => (TlsBulk::ChaCha20, TlsHash::Sha256),
+            // TLS-1.2 AES-GCM
+            0x009c | 0xc02b | 0xc02f => (TlsBulk::Aes128Gcm, TlsHash::Sha256),
+            0x009d | 0xc02c | 0xc030 => (TlsBulk::Aes256Gcm, TlsHash::Sha384),
+            // TLS-1.2 ChaCha20-Poly1305
+            0xcca8 | 0xcca9 => (TlsBulk::ChaCha20, TlsHash::Sha256),
+            // TLS-1.2 CBC HMAC-SHA256, ISO-2 uses ECDH(E)_ECDSA_WITH_AES_128_CBC_SHA256
+            0x003c | 0xc023 | 0xc025 | 0xc027 | 0xc029 => (TlsBulk::Aes128Cbc, TlsHash::Sha256),
+            0x003d => (TlsBulk::Aes256Cbc, TlsHash::Sha256),
+            _ => return None,
+        };
+        Some(TlsSuite { bulk, hash })
+    }
+
+    fn get_key_len(&self) -> usize {
+        match self.bulk {
+            TlsBulk::Aes128Gcm | TlsBulk::Aes128Cbc => 16,
+            TlsBulk::Aes256Gcm | TlsBulk::ChaCha20 | TlsBulk::Aes256Cbc => 32,
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], chunks: &[&[u8]]) -> Vec<u8> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("hmac accepts any key size");
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn hmac_sha384(key: &[u8], chunks: &[&[u8]]) -> Vec<u8> {
+    let mut mac = <Hmac<Sha384> as Mac>::new_from_slice(key).expect("hmac accepts any key size");
+    for chunk in chunks {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+// TLS-1.2 PRF, P_hash(secret, label + seed) RFC 5246 §5
+fn tls12_prf(hash: TlsHash, secret: &[u8], label: &str, seed: &[u8], len: usize) -> Vec<u8> {
+    let hmac = |chunks: &[&[u8]]| match hash {
+        TlsHash::Sha256 => hmac_sha256(secret, chunks),
+        TlsHash::Sha384 => hmac_sha384(secret, chunks),
+    };
+    let mut label_seed = label.as_bytes().to_vec();
+    label_seed.extend_from_slice(seed);
+
+    let mut output = Vec::with_capacity(len);
+    let mut block = hmac(&[&label_seed]);
+    while output.len() < len {
+        output.extend_from_slice(&hmac(&[&block, &label_seed]));
+        block = hmac(&[&block]);
+    }
+    output.truncate(len);
+    output
+}
+
+// TLS-1.3 HKDF-Expand-Label(secret, label, "", len) RFC 8446 §7.1
+fn tls13_expand_label(hash: TlsHash, secret: &[u8], label: &str, len: usize) -> Option<Vec<u8>> {
+    let label = format!("tls13 {}", label);
+    let mut info = Vec::with_capacity(4 + label.len());
+    info.extend_from_slice(&(len as u16).to_be_bytes());
+    info.push(label.len() as u8);
+    info.extend_from_slice(label.as_bytes());
+    info.push(0);
+
+    let mut output = vec![0u8; len];
+    let status = match hash {
+        TlsHash::Sha256 => Hkdf::<Sha256>::from_prk(secret)
+            .ok()?
+            .expand(&info, &mut output),
+        TlsHash::Sha384 => Hkdf::<Sha384>::from_prk(secret)
+            .ok()?
+            .expand(&info, &mut output),
+    };
+    status.ok()?;
+    Some(output)
+}
+
+enum TlsAead {
+    Aes128Gcm(Box<Aes128Gcm>),
+    Aes256Gcm(Box<Aes256Gcm>),
+    ChaCha20(Box<ChaCha20Poly1305>),
+}
+
+impl TlsAead {
+    fn new(bulk: TlsBulk, key: &[u8]) -> Option<Self> {
+        let aead = match bulk {
+            TlsBulk::Aes128Gcm => {
+                TlsAead::Aes128Gcm(Box::new(<Aes128Gcm as KeyInit>::new_from_slice(key).ok()?))
+            }
+            TlsBulk::Aes256Gcm => {
+                TlsAead::Aes256Gcm(Box::new(<Aes256Gcm as KeyInit>::new_from_slice(key).ok()?))
+            }
+            TlsBulk::ChaCha20 => TlsAead::ChaCha20(Box::new(
+                <ChaCha20Poly1305 as KeyInit>::new_from_slice(key).ok()?,
+            )),
+            TlsBulk::Aes128Cbc | TlsBulk::Aes256Cbc => return None,
+        };
+        Some(aead)
+    }
+
+    fn open(&self, nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> Option<Vec<u8>> {
+        let payload = Payload {
+            msg: ciphertext,
+            aad,
+        };
+        let plaintext = match self {
+            TlsAead::Aes128Gcm(cipher) => cipher.decrypt(nonce.into(), payload),
+            TlsAead::Aes256Gcm(cipher) => cipher.decrypt(nonce.into(), payload),
+            TlsAead::ChaCha20(cipher) => cipher.decrypt(nonce.into(), payload),
+        };
+        plaintext.ok()
+    }
+}
+
+enum TlsCipherMode {
+    // TLS-1.2 AES-GCM: 4 bytes implicit + 8 bytes explicit nonce
+    Tls12Gcm { aead: TlsAead, salt: Vec<u8> },
+    // TLS-1.2 ChaCha20 & TLS-1.3: 12 bytes iv xor sequence number
+    Tls12Nonce { aead: TlsAead, iv: Vec<u8> },
+    Tls13 { aead: TlsAead, iv: Vec<u8> },
+    // TLS-1.2 MAC then encrypt, explicit iv per record
+    Tls12Cbc { key: Vec<u8>, mac_key: Vec<u8> },
+}
+
+/// Record decryption state for one direction and epoch
+struct TlsCipher {
+    mode: TlsCipherMode,
+    seq: u64,
+}
+
+fn xor_nonce(iv: &[u8], seq: u64) -> Vec<u8> {
+    let mut nonce = iv.to_vec();
+    let offset = nonce.len() - 8;
+    for (idx, byte) in seq.to_be_bytes().iter().enumerate() {
+        nonce[offset + idx] ^= byte;
+    }
+    nonce
+}
+
+impl TlsCipher {
+    fn new(mode: TlsCipherMode) -> Self {
+        TlsCipher { mode, seq: 0 }
+    }
+
+    fn tls13(suite: TlsSuite, secret: &[u8]) -> Option<Self> {
+        let key = tls13_expand_label(suite.hash, secret, "key", suite.get_key_len())?;
+        let iv = tls13_expand_label(suite.hash, secret, "iv", 12)?;
+        let aead = TlsAead::new(suite.bulk, &key)?;
+        Some(Self::new(TlsCipherMode::Tls13 { aead, iv }))
+    }
+
+    // returns (content type, plaintext), None when record does not authenticate
+    fn decrypt(&self, header: &[u8], fragment: &[u8]) -> Option<(u8, Vec<u8>)> {
+        let content_type = header[0];
+        let aad12 = |len: usize| {
+            let mut aad = self.seq.to_be_bytes().to_vec();
+            aad.extend_from_slice(&header[0..3]);
+            aad.extend_from_slice(&(len as u16).to_be_bytes());
+            aad
+        };
+
+        match &self.mode {
+            TlsCipherMode::Tls12Gcm { aead, salt } => {
+                if fragment.len() < 8 + TLS_AEAD_TAG_LEN {
+                    return None;
+                }
+                let mut nonce = salt.clone();
+                nonce.extend_from_slice(&fragment[0..8]);
+                let ciphertext = &fragment[8..];
+                let aad = aad12(ciphertext.len() - TLS_AEAD_TAG_LEN);
+                Some((content_type, aead.open(&nonce, &aad, ciphertext)?))
+            }
+            TlsCipherMode::Tls12Nonce { aead, iv } => {
+                if fragment.len() < TLS_AEAD_TAG_LEN {
+                    return None;
+                }
+                let aad = aad12(fragment.len() - TLS_AEAD_TAG_LEN);
+                let plaintext = aead.open(&xor_nonce(iv, self.seq), &aad, fragment)?;
+                Some((content_type, plaintext))
+            }
+            TlsCipherMode::Tls13 { aead, iv } => {
+                let mut plaintext = aead.open(&xor_nonce(iv, self.seq), header, fragment)?;
+                // inner plaintext: content, real content type, zero padding
+                while let Some(0) = plaintext.last() {
+                    plaintext.pop();
+                }
+                let inner_type = plaintext.pop()?;
+                Some((inner_type, plaintext))
+            }
+            TlsCipherMode::Tls12Cbc { key, mac_key } => {
+                if fragment.len() < 32 || !fragment.len().is_multiple_of(16) {
+                    return None;
+                }
+                let (iv, ciphertext) = fragment.split_at(16);
+                let mut buffer = ciphertext.to_vec();
+                let status = match key.len() {
+                    16 => cbc::Decryptor::<aes::Aes128>::new_from_slices(key, iv)
+                        .ok()?
+                        .decrypt_padded_mut::<NoPadding>(&mut buffer)
+                        .map(|_| ()),
+                    _ => cbc::Decryptor::<aes::Aes256>::new_from_slices(key, iv)
+                        .ok()?
+                        .decrypt_padded_mut::<NoPadding>(&mut buffer)
+                        .map(|_| ()),
+                };
+                status.ok()?;
+
+                let padding = *buffer.last()? as usize + 1;
+                if padding + TLS_SHA256_LEN > buffer.len() {
+                    return None;
+                }
+                let content_len = buffer.len() - padding - TLS_SHA256_LEN;
+                let (content, mac) = buffer[..buffer.len() - padding].split_at(content_len);
+                let expected = hmac_sha256(mac_key, &[&aad12(content_len), content]);
+                if expected != mac {
+                    return None;
+                }
+                Some((content_type, content.to_vec()))
+            }
+        }
+    }
+}
+
+/// Decrypted/parsed TLS content for one direction
+pub enum TlsEvent {
+    ApplicationData(Vec<u8>),
+    Info(String),
+    Error(String),
+}
+
+#[derive(Default)]
+struct TlsDirection {
+    buffer: Vec<u8>,
+    handshake: Vec<u8>,
+    // TLS-1.2: single epoch after ChangeCipherSpec, TLS-1.3: handshake then application epoch
+    epochs: Vec<TlsCipher>,
+    epoch: usize,
+    encrypted: bool,
+    failed: bool,
+}
+
+/// Passive TLS session, client is the EV (TLS client) and server the EVSE
+#[derive(Default)]
+pub struct TlsSession {
+    client_random: Option<Vec<u8>>,
+    server_random: Option<Vec<u8>>,
+    suite_id: u16,
+    version: u16,
+    client: TlsDirection,
+    server: TlsDirection,
+    keys_ready: bool,
+}
+
+impl TlsSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record layer check used to detect TLS on a TCP connection (handshake, TLS-1.x)
+    pub fn is_tls_record(data: &[u8]) -> bool {
+        data.len() >= 3 && data[0] == TLS_HANDSHAKE && data[1] == 0x03 && data[2] <= 0x04
+    }
+
+    pub fn get_version(&self) -> u16 {
+        self.version
+    }
+
+    pub fn get_suite(&self) -> u16 {
+        self.suite_id
+    }
+
+    /// Push reassembled TCP bytes for one direction, complete records are decrypted
+    pub fn push(&mut self, from_client: bool, data: &[u8], keylog: &TlsKeyLog) -> Vec<TlsEvent> {
+        let mut events = Vec::new();
+        self.get_direction(from_client)
+            .buffer
+            .extend_from_slice(data);
+
+        loop {
+            let direction = self.get_direction(from_client);
+            if direction.buffer.len() < TLS_RECORD_HEADER_LEN {
+                break;
+            }
+            let len = u16::from_be_bytes([direction.buffer[3], direction.buffer[4]]) as usize;
+            if len > TLS_RECORD_MAX_LEN || direction.buffer[1] != 0x03 {
+                events.push(TlsEvent::Error(format!(
+                    "invalid tls record header, {} bytes dropped",
+                    direction.buffer.len()
+                )));
+                direction.buffer.clear();
+                break;
+            }
+            if direction.buffer.len() < TLS_RECORD_HEADER_LEN + len {
+                break;
+            }
+            let record: Vec<u8> = direction
+                .buffer
+                .drain(0..TLS_RECORD_HEADER_LEN + len)
+                .collect();
+            self.process_record(from_client, &record, keylog, &mut events);
+        }
+        events
+    }
+
+    fn get_direction(&mut self, from_client: bool) -> &mut TlsDirection {
+        if from_client {
+            &mut self.client
+        } else {
+            &mut self.server
+        }
+    }
+
+    fn process_record(
+        &mut self,
+        from_client: bool,
+        record: &[u8],
+        keylog: &TlsKeyLog,
+        events: &mut Vec<TlsEvent>,
+    ) {
+        let (header, fragment) = record.split_at(TLS_RECORD_HEADER_LEN);
+        let content_type = header[0];
+
+        // TLS-1.3 keeps sending a dummy ChangeCipherSpec for middlebox compatibility
+        if content_type == TLS_CHANGE_CIPHER_SPEC {
+            if self.version != TLS_VERSION_13 {
+                self.get_direction(from_client).encrypted = true;
+            }
+            return;
+        }
+
+        let encrypted = match self.version {
+            TLS_VERSION_13 => content_type == TLS_APPLICATION_DATA,
+            _ => self.get_direction(from_client).encrypted,
+        };
+        if !encrypted {
+            match content_type {
+                TLS_HANDSHAKE => self.process_handshake(from_client, fragment, keylog, events),
+                TLS_ALERT => events.push(Self::alert_event(fragment)),
+                _ => events.push(TlsEvent::Error(format!(
+                    "unexpected clear text tls record type:{}",
+                    content_type
+                ))),
+            }
+            return;
+        }
+
+        let (content_type, plaintext) = match self.decrypt(from_client, header, fragment) {
+            Some(value) => value,
+            None => {
+                let direction = self.get_direction(from_client);
+                if !direction.failed {
+                    direction.failed = true;
+                    let reason = if self.keys_ready {
+                        "record does not authenticate (key log mismatch or lost segment)"
+                    } else {
+                        "no key log entry for this session"
+                    };
+                    events.push(TlsEvent::Error(format!("tls decrypt: {}", reason)));
+                }
+                return;
+            }
+        };
+
+        match content_type {
+            TLS_APPLICATION_DATA => events.push(TlsEvent::ApplicationData(plaintext)),
+            TLS_ALERT => events.push(Self::alert_event(&plaintext)),
+            _ => {} // encrypted handshake (Finished, EncryptedExtensions, tickets, ...)
+        }
+    }
+
+    fn decrypt(
+        &mut self,
+        from_client: bool,
+        header: &[u8],
+        fragment: &[u8],
+    ) -> Option<(u8, Vec<u8>)> {
+        let direction = self.get_direction(from_client);
+        // TLS-1.3 switches from handshake to application secrets without any clear text marker
+        for epoch in direction.epoch..direction.epochs.len() {
+            let cipher = &mut direction.epochs[epoch];
+            if let Some(value) = cipher.decrypt(header, fragment) {
+                cipher.seq += 1;
+                direction.epoch = epoch;
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn alert_event(fragment: &[u8]) -> TlsEvent {
+        if fragment.len() < 2 {
+            return TlsEvent::Error("truncated tls alert".to_string());
+        }
+        let level = if fragment[0] == 2 { "fatal" } else { "warning" };
+        TlsEvent::Info(format!("tls alert {} description:{}", level, fragment[1]))
+    }
+
+    fn process_handshake(
+        &mut self,
+        from_client: bool,
+        fragment: &[u8],
+        keylog: &TlsKeyLog,
+        events: &mut Vec<TlsEvent>,
+    ) {
+        self.get_direction(from_client)
+            .handshake
+            .extend_from_slice(fragment);
+
+        loop {
+            let direction = self.get_direction(from_client);
+            if direction.handshake.len() < 4 {
+                break;
+            }
+            let len = u32::from_be_bytes([
+                0,
+                direction.handshake[1],
+                direction.handshake[2],
+                direction.handshake[3],
+            ]) as usize;
+            if direction.handshake.len() < 4 + len {
+                break;
+            }
+            let message: Vec<u8> = direction.handshake.drain(0..4 + len).collect();
+            match message[0] {
+                TLS_CLIENT_HELLO if from_client && message.len() >= 38 => {
+                    self.client_random = Some(message[6..38].to_vec());
+                    events.push(TlsEvent::Info("tls client hello".to_string()));
+                }
+                TLS_SERVER_HELLO if !from_client => {
+                    match self.parse_server_hello(&message[4..]) {
+                        Some(()) => events.push(TlsEvent::Info(format!(
+                            "tls server hello version:{} cipher:{:#06x}",
+                            if self.version == TLS_VERSION_13 {
+                                "1.3"
+                            } else {
+                                "1.2"
+                            },
+                            self.suite_id
+                        ))),
+                        None => {
+                            events.push(TlsEvent::Error("invalid tls server hello".to_string()))
+                        }
+                    }
+                    if let Err(error) = self.setup_keys(keylog) {
+                        events.push(TlsEvent::Error(error));
+                    }
+                }
+                _ => {} // certificates, key exchange, ...
+            }
+        }
+    }
+
+    fn parse_server_hello(&mut self, body: &[u8]) -> Option<()> {
+        // version(2) random(32) session_id(1+n) cipher(2) compression(1) [extensions]
+        let random = body.get(2..34)?;
+        let sid_len = *body.get(34)? as usize;
+        let mut offset = 35 + sid_len;
+        let suite = body.get(offset..offset + 2)?;
+        self.server_random = Some(random.to_vec());
+        self.suite_id = u16::from_be_bytes([suite[0], suite[1]]);
+        self.version = TLS_VERSION_12;
+        offset += 3;
+
+        if let Some(extensions) = body.get(offset + 2..) {
+            let mut offset = 0;
+            while offset + 4 <= extensions.len() {
+                let ext_type = u16::from_be_bytes([extensions[offset], extensions[offset + 1]]);
+                let ext_len =
+                    u16::from_be_bytes([extensions[offset + 2], extensions[offset + 3]]) as usize;
+                if ext_type == TLS_EXTENSION_SUPPORTED_VERSIONS && ext_len == 2 {
+                    let value = extensions.get(offset + 4..offset + 6)?;
+                    self.version = u16::from_be_bytes([value[0], value[1]]);
+                }
+                offset += 4 + ext_len;
+            }
+        }
+        Some(())
+    }
+
+    fn setup_keys(&mut self, keylog: &TlsKeyLog) -> Result<(), String> {
+        let client_random = match &self.client_random {
+            Some(value) => value.clone(),
+            None => return Err("tls server hello without client hello".to_string()),
+        };
+        let suite = match TlsSuite::from_id(self.suite_id) {
+            Some(value) => value,
+            None => {
+                return Err(format!(
+                    "tls cipher suite:{:#06x} not supported",
+                    self.suite_id
+                ))
+            }
+        };
+
+        if self.version == TLS_VERSION_13 {
+            let secrets = [
+                ("CLIENT_HANDSHAKE_TRAFFIC_SECRET", true),
+                ("CLIENT_TRAFFIC_SECRET_0", true),
+                ("SERVER_HANDSHAKE_TRAFFIC_SECRET", false),
+                ("SERVER_TRAFFIC_SECRET_0", false),
+            ];
+            for (label, from_client) in secrets {
+                let secret = match keylog.get_secret(label, &client_random) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                match TlsCipher::tls13(suite, secret) {
+                    Some(cipher) => self.get_direction(from_client).epochs.push(cipher),
+                    None => return Err(format!("tls invalid {} length", label)),
+                }
+                self.keys_ready = true;
+            }
+            return Ok(());
+        }
+
+        let master = match keylog.get_secret("CLIENT_RANDOM", &client_random) {
+            Some(value) => value.to_vec(),
+            None => return Ok(()),
+        };
+        let server_random = self.server_random.clone().unwrap_or_default();
+        let key_len = suite.get_key_len();
+        let (mac_len, iv_len) = match suite.bulk {
+            TlsBulk::Aes128Cbc | TlsBulk::Aes256Cbc => (TLS_SHA256_LEN, 16),
+            TlsBulk::Aes128Gcm | TlsBulk::Aes256Gcm => (0, 4),
+            TlsBulk::ChaCha20 => (0, 12),
+        };
+
+        // key_block: client/server mac keys, client/server keys, client/server ivs
+        let mut seed = server_random;
+        seed.extend_from_slice(&client_random);
+        let block = tls12_prf(
+            suite.hash,
+            &master,
+            "key expansion",
+            &seed,
+            2 * (mac_len + key_len + iv_len),
+        );
+        let (macs, rest) = block.split_at(2 * mac_len);
+        let (keys, ivs) = rest.split_at(2 * key_len);
+
+        for (idx, from_client) in [(0, true), (1, false)] {
+            let mac_key = &macs[idx * mac_len..(idx + 1) * mac_len];
+            let key = &keys[idx * key_len..(idx + 1) * key_len];
+            let iv = &ivs[idx * iv_len..(idx + 1) * iv_len];
+            let mode = match suite.bulk {
+                TlsBulk::Aes128Cbc | TlsBulk::Aes256Cbc => TlsCipherMode::Tls12Cbc {
+                    key: key.to_vec(),
+                    mac_key: mac_key.to_vec(),
+                },
+                TlsBulk::Aes128Gcm | TlsBulk::Aes256Gcm => TlsCipherMode::Tls12Gcm {
+                    aead: TlsAead::new(suite.bulk, key).ok_or("tls invalid aead key")?,
+                    salt: iv.to_vec(),
+                },
+                TlsBulk::ChaCha20 => TlsCipherMode::Tls12Nonce {
+                    aead: TlsAead::new(suite.bulk, key).ok_or("tls invalid aead key")?,
+                    iv: iv.to_vec(),
+                },
+            };
+            self.get_direction(from_client)
+                .epochs
+                .push(TlsCipher::new(mode));
+        }
+        self.keys_ready = true;
+        Ok(())
+    }
+}
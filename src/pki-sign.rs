@@ -32,6 +32,13 @@ pub trait PkiSignature {
     ) -> Result<(), AfbError>;
 }
 
+/// Contract certificate chain, certificates are base64 encoded DER
+pub struct PkiContractChain {
+    pub emaid: String,
+    pub cert: String,
+    pub sub_certs: Vec<String>,
+}
+
 pub struct PkiConfig {
     pki: GnuPkiConfig,
 }
@@ -70,23 +77,13 @@ impl PkiConfig {
     }
 
     ///
-    /// Get the contract chain as JSON from:
+    /// Get the contract chain from:
     /// - the contract cert (the has to be added before through e.g. set_cert_key)
     /// - MO Sub CA1 and MO Sub CA 2 certificates
     ///
     /// Certificates are looked for in the trust list (ca_trust argument in ::new())
-    ///
-    /// The returned JSON follows this structure:
-    /// {
-    ///   "emaid": "${emaid}",
-    ///   "chain": {
-    ///     "cert": "${contract}",
-    ///     "sub_certs": [
-    ///       "${mo_sub1}",
-    ///       "${mo_sub2}"
-    ///   ]
-    /// }
-    pub fn get_contract_chain_as_json(&self) -> Result<JsoncObj, AfbError> {
+    /// and returned as base64 DER strings.
+    pub fn get_contract_chain(&self) -> Result<PkiContractChain, AfbError> {
         // look for the contract certificate in the trust list
         let contract_cert: GnuPkiCerts = self.pki.get_cert(0)?;
         let contract_emaid = contract_cert.get_cn();
@@ -101,7 +98,7 @@ impl PkiConfig {
         }
         if sub_ca1_cert.is_none() {
             return afb_error!(
-                "get-contract-chain-as-json",
+                "get-contract-chain",
                 "Cannot find a certificate issued by {} in the trust store",
                 contract_issuer
             );
@@ -119,7 +116,7 @@ impl PkiConfig {
         }
         if sub_ca2_cert.is_none() {
             return afb_error!(
-                "get-contract-chain-as-json",
+                "get-contract-chain",
                 "Cannot find a certificate issued by {} in the trust store",
                 sub_ca1_issuer
             );
@@ -133,15 +130,38 @@ impl PkiConfig {
             .export(GnuPkiCertFormat::DER)?
             .b64encode()?;
 
+        Ok(PkiContractChain {
+            emaid: contract_emaid,
+            cert: contract_cert.to_string()?,
+            sub_certs: vec![sub1.to_string()?, sub2.to_string()?],
+        })
+    }
+
+    ///
+    /// Same as get_contract_chain, returned JSON follows this structure:
+    /// {
+    ///   "emaid": "${emaid}",
+    ///   "chain": {
+    ///     "cert": "${contract}",
+    ///     "sub_certs": [
+    ///       "${mo_sub1}",
+    ///       "${mo_sub2}"
+    ///   ]
+    /// }
+    #[cfg(feature = "afbv4")]
+    pub fn get_contract_chain_as_json(&self) -> Result<JsoncObj, AfbError> {
+        let chain = self.get_contract_chain()?;
+
         let sub_certs = JsoncObj::array();
-        sub_certs.append(&sub1.to_string()?)?;
-        sub_certs.append(&sub2.to_string()?)?;
+        for sub_cert in &chain.sub_certs {
+            sub_certs.append(sub_cert)?;
+        }
         let chain_json = JsoncObj::new();
-        chain_json.add("cert", &contract_cert.to_string()?)?;
+        chain_json.add("cert", &chain.cert)?;
         chain_json.add("sub_certs", sub_certs)?;
 
         let json = JsoncObj::new();
-        json.add("emaid", &contract_emaid)?;
+        json.add("emaid", &chain.emaid)?;
         json.add("chain", chain_json)?;
 
         Ok(json)
@@ -152,6 +172,7 @@ impl PkiConfig {
         self.pki.check_cert(cert_list, GnuPkiVerifFlag::DEFAULT)
     }
 
+    #[cfg(feature = "afbv4")]
     #[track_caller]
     pub fn from_jsonc(jtls: JsoncObj) -> Result<&'static Self, AfbError> {
         let cert_format = jtls.default("format", "pem")?; // iso15118-2 x.509v3 DER format
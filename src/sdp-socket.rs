@@ -0,0 +1,284 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §7.10.1 (SDP) [V2G2-139] to [V2G2-161] & Table 15/16 (SDP timing)
+ */
+
+use crate::prelude::v2g::*;
+use crate::prelude::*;
+use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6, UdpSocket};
+use std::time::{Duration, Instant};
+
+// V2G_UDP_SDP_SERVER port & link-local all-nodes multicast group
+pub const SDP_SERVER_PORT: u16 = 15118;
+pub const SDP_MULTICAST_ADDR: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+
+// EVCC retries SDP_RETRY_CYCLES times, waiting SDP_RESPONSE_TIMEOUT for each response
+pub const SDP_RETRY_CYCLES: u32 = 50;
+pub const SDP_RESPONSE_TIMEOUT: Duration = Duration::from_millis(250);
+
+pub struct SdpServerConfig {
+    pub bind: SocketAddrV6,
+    pub multicast_iface: Option<u32>,
+    pub addr6: Ipv6Addr,
+    pub tcp_port: Option<u16>,
+    pub tls_port: Option<u16>,
+}
+
+impl SdpServerConfig {
+    /// Default server listening on every interface, caller should at least set addr6
+    /// (SECC link-local address) and one of tcp_port/tls_port.
+    pub fn new(addr6: Ipv6Addr, iface: u32) -> Self {
+        SdpServerConfig {
+            bind: SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, SDP_SERVER_PORT, 0, 0),
+            multicast_iface: Some(iface),
+            addr6,
+            tcp_port: None,
+            tls_port: None,
+        }
+    }
+}
+
+/// SECC side SDP responder
+pub struct SdpServer {
+    socket: UdpSocket,
+    config: SdpServerConfig,
+}
+
+impl SdpServer {
+    #[track_caller]
+    pub fn new(config: SdpServerConfig) -> Result<Self, AfbError> {
+        if config.tcp_port.is_none() && config.tls_port.is_none() {
+            return afb_error!("sdp-server-config", "should provide tcp_port or tls_port");
+        }
+
+        let socket = match UdpSocket::bind(config.bind) {
+            Ok(value) => value,
+            Err(error) => {
+                return afb_error!(
+                    "sdp-server-bind",
+                    "fail to bind:{} error:{}",
+                    config.bind,
+                    error
+                )
+            }
+        };
+
+        if let Some(iface) = config.multicast_iface {
+            if let Err(error) = socket.join_multicast_v6(&SDP_MULTICAST_ADDR, iface) {
+                return afb_error!(
+                    "sdp-server-multicast",
+                    "fail to join:{} iface:{} error:{}",
+                    SDP_MULTICAST_ADDR,
+                    iface,
+                    error
+                );
+            }
+        }
+        Ok(SdpServer { socket, config })
+    }
+
+    #[track_caller]
+    pub fn get_local_addr(&self) -> Result<SocketAddr, AfbError> {
+        match self.socket.local_addr() {
+            Ok(value) => Ok(value),
+            Err(error) => afb_error!("sdp-server-addr", "fail to get local addr:{}", error),
+        }
+    }
+
+    #[track_caller]
+    pub fn set_timeout(&self, timeout: Option<Duration>) -> Result<&Self, AfbError> {
+        if let Err(error) = self.socket.set_read_timeout(timeout) {
+            return afb_error!("sdp-server-timeout", "fail to set timeout:{}", error);
+        }
+        Ok(self)
+    }
+
+    // TLS is only selected when the EV asks for it and the SECC provides it
+    #[track_caller]
+    pub fn get_response(&self, request: &SdpRequest) -> Result<SdpResponse, AfbError> {
+        let (port, security) = match (request.get_security()?, self.config.tls_port) {
+            (SdpSecurityModel::TLS, Some(port)) => (port, SdpSecurityModel::TLS),
+            (_, _) => match self.config.tcp_port {
+                Some(port) => (port, SdpSecurityModel::NONE),
+                None => (self.config.tls_port.unwrap_or(0), SdpSecurityModel::TLS),
+            },
+        };
+        Ok(SdpResponse::new(
+            self.config.addr6.octets(),
+            port,
+            SdpTransportProtocol::TCP,
+            security,
+        ))
+    }
+
+    /// Wait for one SDP request and answer it, returns the EV address.
+    /// Invalid datagrams are dropped and reported as an error.
+    #[track_caller]
+    pub fn process_request(&self) -> Result<SocketAddr, AfbError> {
+        let mut buffer = [0u8; 64];
+        let (count, remote) = match self.socket.recv_from(&mut buffer) {
+            Ok(value) => value,
+            Err(error) => return afb_error!("sdp-server-recv", "fail to receive:{}", error),
+        };
+
+        let mut data: SdpRequestBuffer = Default::default();
+        if count != data.len() {
+            return afb_error!(
+                "sdp-server-recv",
+                "invalid sdp request len:{} from:{}",
+                count,
+                remote
+            );
+        }
+        data.copy_from_slice(&buffer[0..count]);
+        let request = SdpRequest::decode(&data)?;
+        request.check_header()?;
+
+        // EV only support TCP, UDP transport is reserved
+        if request.get_transport()? != SdpTransportProtocol::TCP {
+            return afb_error!(
+                "sdp-server-request",
+                "unsupported transport:{:?} from:{}",
+                request.get_transport()?,
+                remote
+            );
+        }
+
+        let response = self.get_response(&request)?.encode()?;
+        if let Err(error) = self.socket.send_to(&response, remote) {
+            return afb_error!(
+                "sdp-server-send",
+                "fail to respond:{} error:{}",
+                remote,
+                error
+            );
+        }
+        Ok(remote)
+    }
+}
+
+/// EVCC side SDP discovery
+pub struct SdpClient {
+    socket: UdpSocket,
+    target: SocketAddrV6,
+    retry: u32,
+    timeout: Duration,
+}
+
+impl SdpClient {
+    /// Client sending to SECC multicast group on given interface
+    #[track_caller]
+    pub fn new(iface: u32) -> Result<Self, AfbError> {
+        Self::with_target(SocketAddrV6::new(
+            SDP_MULTICAST_ADDR,
+            SDP_SERVER_PORT,
+            0,
+            iface,
+        ))
+    }
+
+    #[track_caller]
+    pub fn with_target(target: SocketAddrV6) -> Result<Self, AfbError> {
+        let bind = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0);
+        let socket = match UdpSocket::bind(bind) {
+            Ok(value) => value,
+            Err(error) => {
+                return afb_error!("sdp-client-bind", "fail to bind:{} error:{}", bind, error)
+            }
+        };
+        Ok(SdpClient {
+            socket,
+            target,
+            retry: SDP_RETRY_CYCLES,
+            timeout: SDP_RESPONSE_TIMEOUT,
+        })
+    }
+
+    pub fn set_retry(&mut self, retry: u32) -> &mut Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send SDP request until a valid TCP response is received or retry cycles expire
+    #[track_caller]
+    pub fn discover(&self, security: SdpSecurityModel) -> Result<SdpResponse, AfbError> {
+        let request = SdpRequest::new(SdpTransportProtocol::TCP, security).encode()?;
+        for _ in 0..self.retry {
+            if let Err(error) = self.socket.send_to(&request, self.target) {
+                return afb_error!(
+                    "sdp-client-send",
+                    "fail to send:{} error:{}",
+                    self.target,
+                    error
+                );
+            }
+            if let Some(response) = self.wait_response()? {
+                return Ok(response);
+            }
+        }
+
+        afb_error!(
+            "sdp-client-discover",
+            "no SDP response from:{} after {} cycles",
+            self.target,
+            self.retry
+        )
+    }
+
+    // invalid or foreign datagrams are dropped without consuming the retry cycle,
+    // request is only sent again when the cycle timeout expires
+    fn wait_response(&self) -> Result<Option<SdpResponse>, AfbError> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            if let Err(error) = self.socket.set_read_timeout(Some(remaining)) {
+                return afb_error!("sdp-client-timeout", "fail to set timeout:{}", error);
+            }
+
+            let mut buffer = [0u8; 64];
+            let count = match self.socket.recv_from(&mut buffer) {
+                Ok((count, _remote)) => count,
+                Err(_) => return Ok(None), // timeout
+            };
+
+            let mut data: SdpResponseBuffer = Default::default();
+            if count != data.len() {
+                continue;
+            }
+            data.copy_from_slice(&buffer[0..count]);
+            let response = match SdpResponse::decode(&data) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            if response.check_header().is_err()
+                || !matches!(response.get_transport(), Ok(SdpTransportProtocol::TCP))
+            {
+                continue;
+            }
+            return Ok(Some(response));
+        }
+    }
+}
@@ -0,0 +1,211 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: protocol agnostic V2GTP frame decoding
+ *  - codec is selected from V2GTP payload type
+ *  - SAP frames are shared by appHand, DIN & ISO-2, the first codec re-encoding identical bytes wins
+ *  - with "serde" feature documents are tagged with their protocol and built back from JSON
+ */
+
+use crate::prelude::*;
+use std::convert::AsRef;
+use std::str::FromStr;
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString};
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumString, AsRefStr, EnumIter)]
+pub enum V2gProtocol {
+    #[strum(serialize = "apphand")]
+    AppHand,
+    #[strum(serialize = "din")]
+    Din,
+    #[strum(serialize = "iso2")]
+    Iso2,
+    #[strum(serialize = "iso20")]
+    Iso20,
+    #[strum(serialize = "iso20-dc")]
+    Iso20Dc,
+    #[strum(serialize = "iso20-ac")]
+    Iso20Ac,
+}
+
+impl V2gProtocol {
+    #[track_caller]
+    pub fn from_label(label: &str) -> Result<Self, AfbError> {
+        match Self::from_str(label) {
+            Ok(value) => Ok(value),
+            Err(_) => afb_error!(
+                "v2g-protocol-from-label",
+                "unknown protocol:{} (apphand|din|iso2|iso20|iso20-dc|iso20-ac)",
+                label
+            ),
+        }
+    }
+
+    pub fn to_label(&self) -> &str {
+        self.as_ref()
+    }
+
+    pub fn get_payload_id(&self) -> v2g::PayloadMsgId {
+        match self {
+            V2gProtocol::AppHand | V2gProtocol::Din | V2gProtocol::Iso2 => v2g::PayloadMsgId::SAP,
+            V2gProtocol::Iso20 => v2g::PayloadMsgId::MAIN,
+            V2gProtocol::Iso20Dc => v2g::PayloadMsgId::DC_MAIN,
+            V2gProtocol::Iso20Ac => v2g::PayloadMsgId::AC_MAIN,
+        }
+    }
+}
+
+// serde uses protocol label as tag and XSD V2G_Message/appHand document as content
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "protocol", content = "message")
+)]
+pub enum V2gDocument {
+    #[cfg_attr(feature = "serde", serde(rename = "apphand"))]
+    AppHand(v2g::V2gMsgBody),
+    #[cfg_attr(feature = "serde", serde(rename = "din"))]
+    Din(din_exi::ExiMessageDoc),
+    #[cfg_attr(feature = "serde", serde(rename = "iso2"))]
+    Iso2(iso2_exi::ExiMessageDoc),
+    #[cfg_attr(feature = "serde", serde(rename = "iso20"))]
+    Iso20(iso20_exi::ExiMessageDoc),
+    #[cfg_attr(feature = "serde", serde(rename = "iso20-dc"))]
+    Iso20Dc(iso20_dc_exi::ExiMessageDoc),
+    #[cfg_attr(feature = "serde", serde(rename = "iso20-ac"))]
+    Iso20Ac(iso20_ac_exi::ExiMessageDoc),
+}
+
+impl V2gDocument {
+    /// Decode a complete V2GTP frame with given protocol codec
+    #[track_caller]
+    pub fn decode_as(protocol: V2gProtocol, frame: &[u8]) -> Result<Self, AfbError> {
+        let document = match protocol {
+            V2gProtocol::AppHand => V2gDocument::AppHand(v2g::V2gMsgBody::decode(frame)?),
+            V2gProtocol::Din => V2gDocument::Din(din_exi::ExiMessageDoc::decode(frame)?),
+            V2gProtocol::Iso2 => V2gDocument::Iso2(iso2_exi::ExiMessageDoc::decode(frame)?),
+            V2gProtocol::Iso20 => V2gDocument::Iso20(iso20_exi::ExiMessageDoc::decode(frame)?),
+            V2gProtocol::Iso20Dc => {
+                V2gDocument::Iso20Dc(iso20_dc_exi::ExiMessageDoc::decode(frame)?)
+            }
+            V2gProtocol::Iso20Ac => {
+                V2gDocument::Iso20Ac(iso20_ac_exi::ExiMessageDoc::decode(frame)?)
+            }
+        };
+        Ok(document)
+    }
+
+    /// Decode a complete V2GTP frame, codec is detected from payload type. When several
+    /// codecs accept the frame, the one re-encoding identical bytes is preferred.
+    #[track_caller]
+    pub fn decode(frame: &[u8]) -> Result<Self, AfbError> {
+        let payload_id = match v2gtp_parse_header(frame, EXI_MAX_DOCUMENT_SIZE)? {
+            Some((payload_id, _)) => payload_id,
+            None => {
+                return afb_error!(
+                    "v2g-document-decode",
+                    "incomplete v2gtp header len:{}",
+                    frame.len()
+                )
+            }
+        };
+
+        let candidates: &[V2gProtocol] = match payload_id {
+            v2g::PayloadMsgId::SAP => &[V2gProtocol::AppHand, V2gProtocol::Din, V2gProtocol::Iso2],
+            v2g::PayloadMsgId::MAIN => &[V2gProtocol::Iso20],
+            v2g::PayloadMsgId::DC_MAIN => &[V2gProtocol::Iso20Dc],
+            v2g::PayloadMsgId::AC_MAIN => &[V2gProtocol::Iso20Ac],
+            _ => {
+                return afb_error!(
+                    "v2g-document-decode",
+                    "unsupported payload type:{:?}",
+                    payload_id
+                )
+            }
+        };
+
+        let mut fallback = None;
+        for protocol in candidates {
+            let document = match Self::decode_as(*protocol, frame) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            match document.encode() {
+                Ok(data) if data == frame => return Ok(document),
+                _ => {
+                    if fallback.is_none() {
+                        fallback = Some(document)
+                    }
+                }
+            }
+        }
+
+        match fallback {
+            Some(document) => Ok(document),
+            None => afb_error!(
+                "v2g-document-decode",
+                "no codec accepts frame payload type:{:?}",
+                payload_id
+            ),
+        }
+    }
+
+    /// Same as decode for a raw EXI document (without V2GTP header)
+    #[track_caller]
+    pub fn decode_exi(data: &[u8]) -> Result<Self, AfbError> {
+        let payload_ids = [
+            v2g::PayloadMsgId::SAP,
+            v2g::PayloadMsgId::MAIN,
+            v2g::PayloadMsgId::DC_MAIN,
+            v2g::PayloadMsgId::AC_MAIN,
+        ];
+        for payload_id in payload_ids {
+            let frame = V2gtpFrame::new(payload_id, data).encode();
+            if let Ok(document) = Self::decode(&frame) {
+                return Ok(document);
+            }
+        }
+        afb_error!(
+            "v2g-document-decode",
+            "no codec accepts raw exi len:{}",
+            data.len()
+        )
+    }
+
+    /// Encode document into a V2GTP frame, header included
+    #[track_caller]
+    pub fn encode(&self) -> Result<Vec<u8>, AfbError> {
+        match self {
+            V2gDocument::AppHand(document) => document.encode(),
+            V2gDocument::Din(document) => document.encode(),
+            V2gDocument::Iso2(document) => document.encode(),
+            V2gDocument::Iso20(document) => document.encode(),
+            V2gDocument::Iso20Dc(document) => document.encode(),
+            V2gDocument::Iso20Ac(document) => document.encode(),
+        }
+    }
+
+    pub fn get_protocol(&self) -> V2gProtocol {
+        match self {
+            V2gDocument::AppHand(_) => V2gProtocol::AppHand,
+            V2gDocument::Din(_) => V2gProtocol::Din,
+            V2gDocument::Iso2(_) => V2gProtocol::Iso2,
+            V2gDocument::Iso20(_) => V2gProtocol::Iso20,
+            V2gDocument::Iso20Dc(_) => V2gProtocol::Iso20Dc,
+            V2gDocument::Iso20Ac(_) => V2gProtocol::Iso20Ac,
+        }
+    }
+}
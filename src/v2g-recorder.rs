@@ -0,0 +1,325 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: V2GTP session recorder
+ *  - controllers push every complete inbound/outbound V2GTP frame to an optional hook
+ *  - file format: "V2GREC" magic, version, reserved byte, then one record per frame
+ *  - record: timestamp µs since epoch (u64 BE), direction (u8), frame len (u32 BE), V2GTP frame
+ */
+
+use crate::prelude::*;
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const V2G_RECORD_MAGIC: &[u8; 6] = b"V2GREC";
+pub const V2G_RECORD_VERSION: u8 = 1;
+const V2G_RECORD_HEADER_LEN: usize = 8;
+const V2G_RECORD_FRAME_HEADER_LEN: usize = 13;
+
+/// Frame direction on the wire, independent of which side did the recording
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum V2gRecordDirection {
+    EvToEvse = 1,
+    EvseToEv = 2,
+}
+
+impl V2gRecordDirection {
+    #[track_caller]
+    pub fn from_u8(code: u8) -> Result<Self, AfbError> {
+        match code {
+            1 => Ok(V2gRecordDirection::EvToEvse),
+            2 => Ok(V2gRecordDirection::EvseToEv),
+            _ => afb_error!("v2g-record-direction", "unknown direction:{}", code),
+        }
+    }
+
+    pub fn to_label(&self) -> &'static str {
+        match self {
+            V2gRecordDirection::EvToEvse => "ev->evse",
+            V2gRecordDirection::EvseToEv => "evse->ev",
+        }
+    }
+}
+
+/// Hook called by controllers with every complete V2GTP frame (header included).
+/// Recording should never break a charging session, errors are only logged.
+pub trait V2gRecordHook: Send + Sync {
+    fn record(&self, direction: V2gRecordDirection, frame: &[u8]);
+}
+
+#[derive(Clone)]
+pub struct V2gRecord {
+    pub timestamp: u64,
+    pub direction: V2gRecordDirection,
+    pub frame: Vec<u8>,
+}
+
+impl V2gRecord {
+    pub fn new(timestamp: u64, direction: V2gRecordDirection, frame: &[u8]) -> Self {
+        V2gRecord {
+            timestamp,
+            direction,
+            frame: frame.to_vec(),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(V2G_RECORD_FRAME_HEADER_LEN + self.frame.len());
+        data.extend_from_slice(&self.timestamp.to_be_bytes());
+        data.push(self.direction as u8);
+        data.extend_from_slice(&(self.frame.len() as u32).to_be_bytes());
+        data.extend_from_slice(&self.frame);
+        data
+    }
+}
+
+impl fmt::Display for V2gRecord {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            format,
+            "{}.{:06} {} ",
+            self.timestamp / 1_000_000,
+            self.timestamp % 1_000_000,
+            self.direction.to_label()
+        )?;
+        match V2gDocument::decode(&self.frame) {
+            Ok(V2gDocument::AppHand(v2g::V2gMsgBody::Request(request))) => {
+                write!(format, "apphand supportedAppProtocolReq {:?}", request)
+            }
+            Ok(V2gDocument::AppHand(v2g::V2gMsgBody::Response(response))) => {
+                write!(format, "apphand supportedAppProtocolRes {:?}", response)
+            }
+            Ok(V2gDocument::Din(document)) => write!(format, "din {}", document),
+            Ok(V2gDocument::Iso2(document)) => write!(format, "iso2 {}", document),
+            Ok(V2gDocument::Iso20(document)) => write!(format, "iso20 {}", document),
+            Ok(V2gDocument::Iso20Dc(document)) => write!(format, "iso20-dc {}", document),
+            Ok(V2gDocument::Iso20Ac(document)) => write!(format, "iso20-ac {}", document),
+            Err(_) => write!(format, "[{}]", dump_hexa(&self.frame)),
+        }
+    }
+}
+
+/// In memory list of recorded frames, also used to load/save record files
+#[derive(Clone, Default)]
+pub struct V2gRecording {
+    records: Vec<V2gRecord>,
+}
+
+impl V2gRecording {
+    pub fn new() -> Self {
+        V2gRecording {
+            records: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, record: V2gRecord) -> &mut Self {
+        self.records.push(record);
+        self
+    }
+
+    pub fn get_records(&self) -> &[V2gRecord] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn encode_header() -> [u8; V2G_RECORD_HEADER_LEN] {
+        let mut header = [0; V2G_RECORD_HEADER_LEN];
+        header[0..V2G_RECORD_MAGIC.len()].copy_from_slice(V2G_RECORD_MAGIC);
+        header[V2G_RECORD_MAGIC.len()] = V2G_RECORD_VERSION;
+        header
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Self::encode_header().to_vec();
+        for record in &self.records {
+            data.extend_from_slice(&record.encode());
+        }
+        data
+    }
+
+    /// Parse a record file, a truncated last record (recorder killed while writing) is dropped
+    #[track_caller]
+    pub fn decode(data: &[u8]) -> Result<Self, AfbError> {
+        if data.len() < V2G_RECORD_HEADER_LEN
+            || data[0..V2G_RECORD_MAGIC.len()] != V2G_RECORD_MAGIC[..]
+        {
+            return afb_error!("v2g-recording-decode", "not a V2G record file");
+        }
+        let version = data[V2G_RECORD_MAGIC.len()];
+        if version != V2G_RECORD_VERSION {
+            return afb_error!(
+                "v2g-recording-decode",
+                "unsupported record version:{} expect:{}",
+                version,
+                V2G_RECORD_VERSION
+            );
+        }
+
+        let mut recording = V2gRecording::new();
+        let mut index = V2G_RECORD_HEADER_LEN;
+        while data.len() - index >= V2G_RECORD_FRAME_HEADER_LEN {
+            let mut timestamp = [0; 8];
+            timestamp.copy_from_slice(&data[index..index + 8]);
+            let direction = V2gRecordDirection::from_u8(data[index + 8])?;
+            let mut length = [0; 4];
+            length.copy_from_slice(&data[index + 9..index + 13]);
+            let length = u32::from_be_bytes(length) as usize;
+            if length > EXI_MAX_DOCUMENT_SIZE {
+                return afb_error!(
+                    "v2g-recording-decode",
+                    "record:{} frame size:{} too big max:{}",
+                    recording.len(),
+                    length,
+                    EXI_MAX_DOCUMENT_SIZE
+                );
+            }
+
+            let start = index + V2G_RECORD_FRAME_HEADER_LEN;
+            if data.len() - start < length {
+                afb_log_msg!(
+                    Notice,
+                    None,
+                    "v2g-recording: drop truncated record:{}",
+                    recording.len()
+                );
+                break;
+            }
+            recording.push(V2gRecord::new(
+                u64::from_be_bytes(timestamp),
+                direction,
+                &data[start..start + length],
+            ));
+            index = start + length;
+        }
+        Ok(recording)
+    }
+
+    #[track_caller]
+    pub fn from_file(path: &str) -> Result<Self, AfbError> {
+        match std::fs::read(path) {
+            Ok(data) => Self::decode(&data),
+            Err(error) => afb_error!("v2g-recording-file", "fail to read {}:{}", path, error),
+        }
+    }
+
+    /// One text line per frame, DIN/ISO-2/ISO-20 documents use their compact Display
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for record in &self.records {
+            text.push_str(&format!("{}\n", record));
+        }
+        text
+    }
+}
+
+enum RecorderOutput {
+    Memory(V2gRecording),
+    Writer(Box<dyn Write + Send>),
+}
+
+/// Record hook writing frames to a file/writer, or keeping them in memory.
+/// Each frame is written with a single write, a killed process leaves a readable file.
+pub struct V2gRecorder {
+    output: Mutex<RecorderOutput>,
+}
+
+impl V2gRecorder {
+    pub fn new() -> Self {
+        V2gRecorder {
+            output: Mutex::new(RecorderOutput::Memory(V2gRecording::new())),
+        }
+    }
+
+    #[track_caller]
+    pub fn from_writer(mut writer: Box<dyn Write + Send>) -> Result<Self, AfbError> {
+        if let Err(error) = writer.write_all(&V2gRecording::encode_header()) {
+            return afb_error!("v2g-recorder-create", "fail to write header:{}", error);
+        }
+        Ok(V2gRecorder {
+            output: Mutex::new(RecorderOutput::Writer(writer)),
+        })
+    }
+
+    #[track_caller]
+    pub fn create(path: &str) -> Result<Self, AfbError> {
+        match File::create(path) {
+            Ok(file) => Self::from_writer(Box::new(file)),
+            Err(error) => afb_error!("v2g-recorder-create", "fail to create {}:{}", path, error),
+        }
+    }
+
+    /// Frames recorded so far, only available for in memory recorders
+    #[track_caller]
+    pub fn get_recording(&self) -> Result<V2gRecording, AfbError> {
+        let output = match self.output.lock() {
+            Ok(guard) => guard,
+            Err(_) => return afb_error!("v2g-recorder-lock", "fail to lock recorder"),
+        };
+        match &*output {
+            RecorderOutput::Memory(recording) => Ok(recording.clone()),
+            RecorderOutput::Writer(_) => {
+                afb_error!("v2g-recorder-recording", "recorder writes to file, reload it")
+            }
+        }
+    }
+}
+
+impl Default for V2gRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl V2gRecordHook for V2gRecorder {
+    fn record(&self, direction: V2gRecordDirection, frame: &[u8]) {
+        let timestamp = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(time) => time.as_micros() as u64,
+            Err(_) => 0,
+        };
+        let record = V2gRecord::new(timestamp, direction, frame);
+
+        let mut output = match self.output.lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                afb_log_msg!(Warning, None, "v2g-recorder: fail to lock recorder");
+                return;
+            }
+        };
+        match &mut *output {
+            RecorderOutput::Memory(recording) => {
+                recording.push(record);
+            }
+            RecorderOutput::Writer(writer) => {
+                let status = writer
+                    .write_all(&record.encode())
+                    .and_then(|_| writer.flush());
+                if let Err(error) = status {
+                    afb_log_msg!(Warning, None, "v2g-recorder: fail to write frame:{}", error);
+                }
+            }
+        }
+    }
+}
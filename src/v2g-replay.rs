@@ -0,0 +1,433 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: deterministic replay of recorded V2GTP sessions
+ *  - SECC replay: recorded EV requests are fed to an ISO-2/DIN controller, responses are checked
+ *  - EVCC replay: recorded EVSE responses are fed to the EVCC driver, requests are checked
+ *  - SECC session id is time based, recorded id is replaced by the live one in EV requests
+ *  - SessionSetupRes EVSETimeStamp is ignored, other fields are compared with compact Display
+ */
+
+use crate::prelude::*;
+use std::fmt;
+use std::net::Ipv6Addr;
+use std::sync::MutexGuard;
+
+// decoded frame reduced to what the replay compares
+struct ReplayView {
+    session_id: Vec<u8>,
+    timestamp: Option<i64>,
+    text: String,
+}
+
+// timestamp: when set, SessionSetupRes EVSETimeStamp is forced to it before rendering
+fn replay_view(
+    protocol: V2gProtocol,
+    frame: &[u8],
+    timestamp: Option<i64>,
+) -> Result<ReplayView, AfbError> {
+    let view = match protocol {
+        V2gProtocol::AppHand => {
+            let text = match v2g::V2gMsgBody::decode(frame)? {
+                v2g::V2gMsgBody::Request(request) => {
+                    format!("supportedAppProtocolReq {:?}", request)
+                }
+                v2g::V2gMsgBody::Response(response) => {
+                    format!("supportedAppProtocolRes {:?}", response)
+                }
+            };
+            ReplayView {
+                session_id: Vec::new(),
+                timestamp: None,
+                text,
+            }
+        }
+        V2gProtocol::Iso2 => {
+            use iso2_exi::*;
+            let document = ExiMessageDoc::decode(frame)?;
+            let (text, own) = match document.get_body()? {
+                MessageBody::SessionSetupRes(mut response) => {
+                    let own = response.get_time_stamp();
+                    if let Some(value) = timestamp {
+                        response.set_timestamp(value);
+                    }
+                    (format!("SessionSetupRes {}", response), Some(own))
+                }
+                body => (format!("{}", body), None),
+            };
+            ReplayView {
+                session_id: document.get_header().get_session_id().to_vec(),
+                timestamp: own,
+                text,
+            }
+        }
+        V2gProtocol::Din => {
+            use din_exi::*;
+            let document = ExiMessageDoc::decode(frame)?;
+            let (text, own) = match document.get_body()? {
+                MessageBody::SessionSetupRes(mut response) => {
+                    let own = response.get_time_stamp();
+                    if let Some(value) = timestamp {
+                        response.set_timestamp(value);
+                    }
+                    (format!("SessionSetupRes {}", response), Some(own))
+                }
+                body => (format!("{}", body), None),
+            };
+            ReplayView {
+                session_id: document.get_header().get_session_id().to_vec(),
+                timestamp: own,
+                text,
+            }
+        }
+        _ => {
+            return afb_error!(
+                "v2g-replay-protocol",
+                "replay does not support protocol:{}",
+                protocol
+            )
+        }
+    };
+    Ok(view)
+}
+
+fn replay_text(protocol: V2gProtocol, frame: &[u8]) -> String {
+    match replay_view(protocol, frame, None) {
+        Ok(view) => view.text,
+        Err(error) => format!("invalid({}) [{}]", error, dump_hexa(frame)),
+    }
+}
+
+// EV request carrying the recorded session id is re-encoded with the live SECC one
+fn replace_session_id(
+    protocol: V2gProtocol,
+    frame: &[u8],
+    recorded: &[u8],
+    live: &[u8],
+) -> Result<Vec<u8>, AfbError> {
+    match protocol {
+        V2gProtocol::Iso2 => {
+            use iso2_exi::*;
+            let document = ExiMessageDoc::decode(frame)?;
+            let mut header = document.get_header();
+            if header.get_session_id() != recorded {
+                return Ok(frame.to_vec());
+            }
+            header.set_session_id(live)?;
+            ExiMessageDoc::new(&header, &document.get_payload().V2G_Message.Body).encode()
+        }
+        V2gProtocol::Din => {
+            use din_exi::*;
+            let document = ExiMessageDoc::decode(frame)?;
+            let mut header = document.get_header();
+            if header.get_session_id() != recorded {
+                return Ok(frame.to_vec());
+            }
+            header.set_session_id(live)?;
+            ExiMessageDoc::new(&header, &document.get_payload().V2G_Message.Body).encode()
+        }
+        _ => Ok(frame.to_vec()),
+    }
+}
+
+#[track_caller]
+fn load_frame(
+    lock: &mut MutexGuard<RawStream>,
+    index: usize,
+    frame: &[u8],
+) -> Result<(), AfbError> {
+    lock.reset();
+    match lock.push_chunk(frame)? {
+        (count, V2gtpChunk::Complete(_)) if count == frame.len() => Ok(()),
+        _ => afb_error!(
+            "v2g-replay-frame",
+            "record:{} is not a single complete v2gtp frame",
+            index
+        ),
+    }
+}
+
+/// Recorded and replayed frame differ, index is the recorded frame position
+pub struct V2gReplayDivergence {
+    pub index: usize,
+    pub expected: String,
+    pub received: String,
+}
+
+impl fmt::Display for V2gReplayDivergence {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            format,
+            "record:{} expected:{} received:{}",
+            self.index, self.expected, self.received
+        )
+    }
+}
+
+pub struct V2gReplayReport {
+    frames: usize,
+    divergences: Vec<V2gReplayDivergence>,
+}
+
+impl V2gReplayReport {
+    fn new() -> Self {
+        V2gReplayReport {
+            frames: 0,
+            divergences: Vec::new(),
+        }
+    }
+
+    fn diverge(&mut self, index: usize, expected: String, received: String) {
+        self.divergences.push(V2gReplayDivergence {
+            index,
+            expected,
+            received,
+        });
+    }
+
+    /// number of recorded frames fed to the controller
+    pub fn get_frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn get_divergences(&self) -> &[V2gReplayDivergence] {
+        &self.divergences
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    /// Regression test helper, fails with the first divergence
+    #[track_caller]
+    pub fn check(&self) -> Result<(), AfbError> {
+        match self.divergences.first() {
+            None => Ok(()),
+            Some(divergence) => afb_error!(
+                "v2g-replay-check",
+                "{} divergence(s), first {}",
+                self.divergences.len(),
+                divergence
+            ),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "replayed frames:{} divergences:{}\n",
+            self.frames,
+            self.divergences.len()
+        );
+        for divergence in &self.divergences {
+            text.push_str(&format!("{}\n", divergence));
+        }
+        text
+    }
+}
+
+/// Replay one side of a recording against a live controller
+pub struct V2gReplayer {
+    recording: V2gRecording,
+}
+
+impl V2gReplayer {
+    pub fn new(recording: V2gRecording) -> Self {
+        V2gReplayer { recording }
+    }
+
+    /// Feed recorded EV requests to an ISO-2 SECC controller and check its responses
+    pub fn replay_iso2(&self, controller: &IsoController) -> Result<V2gReplayReport, AfbError> {
+        self.replay_charger(V2gProtocol::Iso2, |lock| {
+            Ok(controller.iso_decode_payload(lock)? == SeccState::Idle)
+        })
+    }
+
+    /// Feed recorded EV requests to a DIN SECC controller and check its responses
+    pub fn replay_din(&self, controller: &DinController) -> Result<V2gReplayReport, AfbError> {
+        self.replay_charger(V2gProtocol::Din, |lock| {
+            Ok(controller.din_decode_payload(lock)? == DinState::Idle)
+        })
+    }
+
+    // process returns true when the controller closed the connection (back to appHand)
+    fn replay_charger<F>(
+        &self,
+        protocol: V2gProtocol,
+        mut process: F,
+    ) -> Result<V2gReplayReport, AfbError>
+    where
+        F: FnMut(&mut MutexGuard<RawStream>) -> Result<bool, AfbError>,
+    {
+        let stream = ExiStream::new();
+        let records = self.recording.get_records();
+        let mut report = V2gReplayReport::new();
+        let mut current = V2gProtocol::AppHand;
+        let mut sessions: Option<(Vec<u8>, Vec<u8>)> = None;
+
+        let mut index = 0;
+        while index < records.len() {
+            let request_idx = index;
+            let request = &records[index];
+            index += 1;
+            if request.direction != V2gRecordDirection::EvToEvse {
+                report.diverge(
+                    request_idx,
+                    "no response without request".to_string(),
+                    replay_text(current, &request.frame),
+                );
+                continue;
+            }
+
+            let frame = match &sessions {
+                Some((recorded, live)) => {
+                    replace_session_id(current, &request.frame, recorded, live)?
+                }
+                None => request.frame.clone(),
+            };
+            let expected = match records.get(index) {
+                Some(record) if record.direction == V2gRecordDirection::EvseToEv => {
+                    index += 1;
+                    Some(record)
+                }
+                _ => None,
+            };
+
+            let mut lock = stream.lock_stream();
+            load_frame(&mut lock, request_idx, &frame)?;
+            report.frames += 1;
+            let closed = match process(&mut lock) {
+                Ok(value) => value,
+                Err(error) => {
+                    let expected = match expected {
+                        Some(record) => replay_text(current, &record.frame),
+                        None => "no response".to_string(),
+                    };
+                    report.diverge(request_idx, expected, format!("error({})", error));
+                    break;
+                }
+            };
+            let received = lock.get_buffer().to_vec();
+            drop(lock);
+
+            match expected {
+                None => report.diverge(
+                    request_idx,
+                    "no response".to_string(),
+                    replay_text(current, &received),
+                ),
+                Some(record) => {
+                    let expected = replay_view(current, &record.frame, None)?;
+                    match replay_view(current, &received, expected.timestamp) {
+                        Ok(view) => {
+                            if view.text != expected.text {
+                                report.diverge(request_idx + 1, expected.text, view.text);
+                            }
+                            if !expected.session_id.is_empty() && !view.session_id.is_empty() {
+                                sessions = Some((expected.session_id, view.session_id));
+                            }
+                        }
+                        Err(error) => report.diverge(
+                            request_idx + 1,
+                            expected.text,
+                            format!("invalid({}) [{}]", error, dump_hexa(&received)),
+                        ),
+                    }
+                }
+            }
+
+            current = if closed {
+                V2gProtocol::AppHand
+            } else {
+                protocol
+            };
+        }
+        Ok(report)
+    }
+
+    /// Feed recorded EVSE responses to the EVCC driver and check its requests.
+    /// When the driver still waits for SDP, a TCP/no-TLS SDP response is provided.
+    pub fn replay_evcc(&self, controller: &EvccController) -> Result<V2gReplayReport, AfbError> {
+        if controller.get_state()? == EvccState::Sdp {
+            let response = v2g::SdpResponse::new(
+                Ipv6Addr::LOCALHOST.octets(),
+                0,
+                v2g::SdpTransportProtocol::TCP,
+                v2g::SdpSecurityModel::NONE,
+            )
+            .encode()?;
+            controller.sdp_response(&response)?;
+        }
+
+        let stream = ExiStream::new();
+        let mut report = V2gReplayReport::new();
+        let mut current = V2gProtocol::AppHand;
+
+        for (index, record) in self.recording.get_records().iter().enumerate() {
+            let mut lock = stream.lock_stream();
+            match record.direction {
+                V2gRecordDirection::EvToEvse => {
+                    lock.reset();
+                    if let Err(error) = controller.encode_request(&mut lock) {
+                        report.diverge(
+                            index,
+                            replay_text(current, &record.frame),
+                            format!("error({})", error),
+                        );
+                        break;
+                    }
+                    let expected = replay_view(current, &record.frame, None)?;
+                    let received = lock.get_buffer();
+                    match replay_view(current, received, None) {
+                        Ok(view) => {
+                            if view.session_id != expected.session_id {
+                                report.diverge(
+                                    index,
+                                    format!("SessionID:{}", dump_hexa(&expected.session_id)),
+                                    format!("SessionID:{}", dump_hexa(&view.session_id)),
+                                );
+                            }
+                            if view.text != expected.text {
+                                report.diverge(index, expected.text, view.text);
+                            }
+                        }
+                        Err(error) => report.diverge(
+                            index,
+                            expected.text,
+                            format!("invalid({}) [{}]", error, dump_hexa(received)),
+                        ),
+                    }
+                }
+                V2gRecordDirection::EvseToEv => {
+                    load_frame(&mut lock, index, &record.frame)?;
+                    report.frames += 1;
+                    let state = controller.get_state()?;
+                    if let Err(error) = controller.decode_response(&mut lock) {
+                        report.diverge(
+                            index,
+                            "accepted response".to_string(),
+                            format!("error({})", error),
+                        );
+                        break;
+                    }
+                    if state == EvccState::AppProtocol {
+                        current = V2gProtocol::Iso2;
+                    }
+                }
+            }
+        }
+        Ok(report)
+    }
+}
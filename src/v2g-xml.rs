@@ -0,0 +1,1151 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: XSD conformant XML import/export for DIN, ISO-2 & appHand documents ("xml" feature)
+ *  - documents go through the same serde mirrors as JSON (XSD element names & enumeration values)
+ *  - element namespaces follow V2G_CI_MsgDef/MsgHeader/MsgBody/MsgDataTypes & xmldsig schemas
+ *  - Id/Algorithm/URI fields are written as attributes, CONTENT as element simple content
+ *  - xsd:base64Binary elements are base64 encoded, xsd:hexBinary stays hexadecimal
+ *  - import resolves namespace prefixes, only root element namespace is checked
+ */
+
+use crate::prelude::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt;
+
+const XMLDSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+// V2G_Message schema namespace index
+const NS_MSG_DEF: usize = 0;
+const NS_HEADER: usize = 1;
+const NS_BODY: usize = 2;
+const NS_TYPES: usize = 3;
+const NS_XMLDSIG: usize = 4;
+
+// fields written as XML attributes
+const XML_ATTRIBUTES: [&str; 3] = ["Id", "Algorithm", "URI"];
+
+struct XmlSchema {
+    label: &'static str,
+    // V2G_Message: MsgDef, MsgHeader, MsgBody, MsgDataTypes, xmldsig. appHand: AppProtocol only
+    namespaces: &'static [(&'static str, &'static str)],
+    root: Option<&'static str>,
+    // MsgDataTypes global elements referenced from body messages (substitution groups)
+    data_types: &'static [&'static str],
+    // messages referencing the EVSEStatus substitution group
+    status_refs: &'static [&'static str],
+    // xsd:base64Binary elements & simple content types
+    base64: &'static [&'static str],
+    base64_content: &'static [&'static str],
+}
+
+const ISO2_SCHEMA: XmlSchema = XmlSchema {
+    label: "iso2",
+    namespaces: &[
+        ("v2gci_d", "urn:iso:15118:2:2013:MsgDef"),
+        ("v2gci_h", "urn:iso:15118:2:2013:MsgHeader"),
+        ("v2gci_b", "urn:iso:15118:2:2013:MsgBody"),
+        ("v2gci_t", "urn:iso:15118:2:2013:MsgDataTypes"),
+        ("xmlsig", XMLDSIG_NAMESPACE),
+    ],
+    root: Some("V2G_Message"),
+    data_types: &[
+        "EVChargeParameter",
+        "AC_EVChargeParameter",
+        "DC_EVChargeParameter",
+        "EVSEChargeParameter",
+        "AC_EVSEChargeParameter",
+        "DC_EVSEChargeParameter",
+        "SASchedules",
+        "SAScheduleList",
+        "EVPowerDeliveryParameter",
+        "DC_EVPowerDeliveryParameter",
+        "EVSEStatus",
+    ],
+    status_refs: &["PowerDeliveryRes", "MeteringReceiptRes"],
+    base64: &[
+        "Certificate",
+        "OEMProvisioningCert",
+        "GenChallenge",
+        "SigMeterReading",
+        "DigestValue",
+    ],
+    base64_content: &[
+        "ContractSignatureEncryptedPrivateKey",
+        "DHpublickey",
+        "SignatureValue",
+    ],
+};
+
+const DIN_SCHEMA: XmlSchema = XmlSchema {
+    label: "din",
+    namespaces: &[
+        ("v2gci_d", "urn:din:70121:2012:MsgDef"),
+        ("v2gci_h", "urn:din:70121:2012:MsgHeader"),
+        ("v2gci_b", "urn:din:70121:2012:MsgBody"),
+        ("v2gci_t", "urn:din:70121:2012:MsgDataTypes"),
+        ("xmlsig", XMLDSIG_NAMESPACE),
+    ],
+    root: Some("V2G_Message"),
+    data_types: &[
+        "EVChargeParameter",
+        "AC_EVChargeParameter",
+        "DC_EVChargeParameter",
+        "EVSEChargeParameter",
+        "AC_EVSEChargeParameter",
+        "DC_EVSEChargeParameter",
+        "SASchedules",
+        "SAScheduleList",
+        "EVPowerDeliveryParameter",
+        "DC_EVPowerDeliveryParameter",
+        "EVSEStatus",
+    ],
+    status_refs: &["PowerDeliveryRes"],
+    base64: &[
+        "Certificate",
+        "OEMProvisioningCert",
+        "ContractSignatureEncryptedPrivateKey",
+        "DHParams",
+        "SigMeterReading",
+        "DigestValue",
+    ],
+    base64_content: &["SignatureValue"],
+};
+
+// appHand schema is unqualified, only root element is in AppProtocol namespace
+const APPHAND_SCHEMA: XmlSchema = XmlSchema {
+    label: "apphand",
+    namespaces: &[("v2gci_ah", "urn:iso:15118:2:2010:AppProtocol")],
+    root: None,
+    data_types: &[],
+    status_refs: &[],
+    base64: &[],
+    base64_content: &[],
+};
+
+impl XmlSchema {
+    // namespace index of 'name' element, path holds its ancestors from root element
+    fn element_ns(&self, path: &[String], name: &str) -> Option<usize> {
+        if self.root.is_none() {
+            return if path.is_empty() { Some(0) } else { None };
+        }
+
+        // xmldsig types (Signature, X509IssuerSerialType) children are in xmldsig namespace
+        if path
+            .iter()
+            .any(|ancestor| ancestor == "Signature" || ancestor == "RootCertificateID")
+        {
+            return Some(NS_XMLDSIG);
+        }
+
+        let ns = match path.len() {
+            0 | 1 => NS_MSG_DEF,
+            2 if path[1] == "Header" => {
+                if name == "Signature" {
+                    NS_XMLDSIG
+                } else {
+                    NS_HEADER
+                }
+            }
+            2 => NS_BODY,
+            3 if path[1] == "Body" => {
+                let status_ref = self.status_refs.contains(&path[2].as_str())
+                    && (name == "AC_EVSEStatus" || name == "DC_EVSEStatus");
+                if status_ref || self.data_types.contains(&name) {
+                    NS_TYPES
+                } else {
+                    NS_BODY
+                }
+            }
+            _ => NS_TYPES,
+        };
+        Some(ns)
+    }
+
+    fn is_base64(&self, parent: Option<&String>, name: &str) -> bool {
+        match name {
+            "CONTENT" => match parent {
+                Some(parent) => self.base64_content.contains(&parent.as_str()),
+                None => false,
+            },
+            _ => self.base64.contains(&name),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct XmlError(String);
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(format, "{}", self.0)
+    }
+}
+
+impl std::error::Error for XmlError {}
+
+impl ser::Error for XmlError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        XmlError(msg.to_string())
+    }
+}
+
+impl de::Error for XmlError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        XmlError(msg.to_string())
+    }
+}
+
+// serde data model reduced to what XML can express
+enum XmlValue {
+    Empty,
+    Text(String),
+    Node(Vec<(String, XmlValue)>),
+    List(Vec<XmlValue>),
+}
+
+struct XmlSerializer;
+
+struct XmlSeq {
+    items: Vec<XmlValue>,
+}
+
+struct XmlNode {
+    variant: Option<&'static str>,
+    fields: Vec<(String, XmlValue)>,
+    key: Option<String>,
+}
+
+impl XmlNode {
+    fn new(variant: Option<&'static str>) -> Self {
+        XmlNode {
+            variant,
+            fields: Vec::new(),
+            key: None,
+        }
+    }
+
+    fn done(self) -> XmlValue {
+        let node = XmlValue::Node(self.fields);
+        match self.variant {
+            Some(variant) => XmlValue::Node(vec![(variant.to_string(), node)]),
+            None => node,
+        }
+    }
+}
+
+macro_rules! serialize_text {
+    ($($method:ident: $type:ty),+) => {
+        $(fn $method(self, value: $type) -> Result<XmlValue, XmlError> {
+            Ok(XmlValue::Text(value.to_string()))
+        })+
+    };
+}
+
+impl ser::Serializer for XmlSerializer {
+    type Ok = XmlValue;
+    type Error = XmlError;
+    type SerializeSeq = XmlSeq;
+    type SerializeTuple = XmlSeq;
+    type SerializeTupleStruct = XmlSeq;
+    type SerializeTupleVariant = Impossible<XmlValue, XmlError>;
+    type SerializeMap = XmlNode;
+    type SerializeStruct = XmlNode;
+    type SerializeStructVariant = XmlNode;
+
+    serialize_text!(
+        serialize_bool: bool,
+        serialize_i8: i8,
+        serialize_i16: i16,
+        serialize_i32: i32,
+        serialize_i64: i64,
+        serialize_u8: u8,
+        serialize_u16: u16,
+        serialize_u32: u32,
+        serialize_u64: u64,
+        serialize_f32: f32,
+        serialize_f64: f64,
+        serialize_char: char,
+        serialize_str: &str
+    );
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::Text(dump_hexa(value)))
+    }
+
+    fn serialize_none(self) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::Empty)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<XmlValue, XmlError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::Empty)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::Node(Vec::new()))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::Text(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<XmlValue, XmlError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<XmlValue, XmlError> {
+        let value = value.serialize(XmlSerializer)?;
+        Ok(XmlValue::Node(vec![(variant.to_string(), value)]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<XmlSeq, XmlError> {
+        Ok(XmlSeq {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<XmlSeq, XmlError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<XmlSeq, XmlError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, XmlError> {
+        Err(XmlError(format!(
+            "tuple variant {}::{} has no xml mapping",
+            name, variant
+        )))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<XmlNode, XmlError> {
+        Ok(XmlNode::new(None))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<XmlNode, XmlError> {
+        Ok(XmlNode::new(None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<XmlNode, XmlError> {
+        Ok(XmlNode::new(Some(variant)))
+    }
+}
+
+impl ser::SerializeSeq for XmlSeq {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), XmlError> {
+        self.items.push(value.serialize(XmlSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        Ok(XmlValue::List(self.items))
+    }
+}
+
+impl ser::SerializeTuple for XmlSeq {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), XmlError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for XmlSeq {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), XmlError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeMap for XmlNode {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), XmlError> {
+        match key.serialize(XmlSerializer)? {
+            XmlValue::Text(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(XmlError("xml element name should be a string".to_string())),
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), XmlError> {
+        match self.key.take() {
+            Some(key) => {
+                self.fields.push((key, value.serialize(XmlSerializer)?));
+                Ok(())
+            }
+            None => Err(XmlError("xml element value without name".to_string())),
+        }
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        Ok(self.done())
+    }
+}
+
+impl ser::SerializeStruct for XmlNode {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), XmlError> {
+        self.fields
+            .push((key.to_string(), value.serialize(XmlSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        Ok(self.done())
+    }
+}
+
+impl ser::SerializeStructVariant for XmlNode {
+    type Ok = XmlValue;
+    type Error = XmlError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), XmlError> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<XmlValue, XmlError> {
+        Ok(self.done())
+    }
+}
+
+fn xml_escape(text: &str, output: &mut String) {
+    for letter in text.chars() {
+        match letter {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            _ => output.push(letter),
+        }
+    }
+}
+
+struct XmlWriter<'a> {
+    schema: &'a XmlSchema,
+    path: Vec<String>,
+    output: String,
+}
+
+impl XmlWriter<'_> {
+    fn qname(&self, name: &str) -> String {
+        match self.schema.element_ns(&self.path, name) {
+            Some(idx) => format!("{}:{}", self.schema.namespaces[idx].0, name),
+            None => name.to_string(),
+        }
+    }
+
+    // hexadecimal serde text to base64 when XSD type is base64Binary
+    fn text_content(&self, name: &str, text: &str) -> Result<String, XmlError> {
+        if self.schema.is_base64(self.path.last(), name) {
+            let data = parse_dump(text).map_err(|error| XmlError(error.to_string()))?;
+            Ok(base64_encode(&data))
+        } else {
+            Ok(text.to_string())
+        }
+    }
+
+    fn write_element(&mut self, name: &str, value: &XmlValue) -> Result<(), XmlError> {
+        let qname = self.qname(name);
+        let indent = "  ".repeat(self.path.len());
+        match value {
+            XmlValue::Empty => {}
+            XmlValue::List(items) => {
+                for item in items {
+                    self.write_element(name, item)?;
+                }
+            }
+            XmlValue::Text(text) => {
+                let text = self.text_content(name, text)?;
+                self.output.push_str(&format!("{}<{}>", indent, qname));
+                xml_escape(&text, &mut self.output);
+                self.output.push_str(&format!("</{}>\n", qname));
+            }
+            XmlValue::Node(fields) => {
+                self.output.push_str(&format!("{}<{}", indent, qname));
+                if self.path.is_empty() {
+                    for (prefix, uri) in self.schema.namespaces {
+                        self.output
+                            .push_str(&format!(" xmlns:{}=\"{}\"", prefix, uri));
+                    }
+                }
+
+                let mut content = None;
+                let mut children = Vec::new();
+                for (field, value) in fields {
+                    match value {
+                        XmlValue::Text(text) if XML_ATTRIBUTES.contains(&field.as_str()) => {
+                            self.output.push_str(&format!(" {}=\"", field));
+                            xml_escape(text, &mut self.output);
+                            self.output.push('"');
+                        }
+                        XmlValue::Text(text) if field == "CONTENT" => content = Some(text),
+                        XmlValue::Empty => {}
+                        _ => children.push((field, value)),
+                    }
+                }
+
+                self.path.push(name.to_string());
+                if let Some(text) = content {
+                    let text = self.text_content("CONTENT", text)?;
+                    self.output.push('>');
+                    xml_escape(&text, &mut self.output);
+                    self.output.push_str(&format!("</{}>\n", qname));
+                } else if children.is_empty() {
+                    self.output.push_str("/>\n");
+                } else {
+                    self.output.push_str(">\n");
+                    for (field, value) in children {
+                        self.write_element(field, value)?;
+                    }
+                    self.output.push_str(&format!("{}</{}>\n", indent, qname));
+                }
+                self.path.pop();
+            }
+        }
+        Ok(())
+    }
+}
+
+fn xml_export<T: Serialize>(value: &T, schema: &XmlSchema) -> Result<String, XmlError> {
+    let value = value.serialize(XmlSerializer)?;
+    let mut writer = XmlWriter {
+        schema,
+        path: Vec::new(),
+        output: "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n".to_string(),
+    };
+    match (schema.root, &value) {
+        (Some(root), _) => writer.write_element(root, &value)?,
+        // appHand enum variant is the root element
+        (None, XmlValue::Node(fields)) if fields.len() == 1 => {
+            writer.write_element(&fields[0].0, &fields[0].1)?
+        }
+        _ => return Err(XmlError("document has no root element".to_string())),
+    }
+    Ok(writer.output)
+}
+
+// parsed XML element, names are local (prefix removed)
+struct XmlElement {
+    name: String,
+    namespace: Option<String>,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    fn from_text(name: String, text: String) -> Self {
+        XmlElement {
+            name,
+            namespace: None,
+            attributes: Vec::new(),
+            text,
+            children: Vec::new(),
+        }
+    }
+
+    fn open(
+        start: &BytesStart,
+        scopes: &mut Vec<Vec<(String, String)>>,
+    ) -> Result<XmlElement, XmlError> {
+        let mut scope = Vec::new();
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute.map_err(|error| XmlError(error.to_string()))?;
+            let key = String::from_utf8_lossy(attribute.key.as_ref()).to_string();
+            let value = attribute
+                .unescape_value()
+                .map_err(|error| XmlError(error.to_string()))?
+                .to_string();
+            if key == "xmlns" {
+                scope.push((String::new(), value));
+            } else if let Some(prefix) = key.strip_prefix("xmlns:") {
+                scope.push((prefix.to_string(), value));
+            } else if !key.contains(':') {
+                attributes.push((key, value));
+            }
+        }
+        scopes.push(scope);
+
+        let qname = String::from_utf8_lossy(start.name().as_ref()).to_string();
+        let (prefix, name) = match qname.split_once(':') {
+            Some((prefix, name)) => (prefix.to_string(), name.to_string()),
+            None => (String::new(), qname.clone()),
+        };
+        let namespace = scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter())
+            .find(|(key, _)| *key == prefix)
+            .map(|(_, uri)| uri.clone());
+        if namespace.is_none() && !prefix.is_empty() {
+            return Err(XmlError(format!("undeclared namespace prefix:{}", qname)));
+        }
+
+        Ok(XmlElement {
+            name,
+            namespace,
+            attributes,
+            text: String::new(),
+            children: Vec::new(),
+        })
+    }
+
+    fn parse(xml: &str) -> Result<XmlElement, XmlError> {
+        let mut reader = Reader::from_str(xml);
+        let mut scopes = Vec::new();
+        let mut stack: Vec<XmlElement> = Vec::new();
+        let mut root = None;
+
+        loop {
+            let event = reader.read_event().map_err(|error| {
+                XmlError(format!("position:{} {}", reader.buffer_position(), error))
+            })?;
+            let closed = match event {
+                Event::Start(start) => {
+                    stack.push(XmlElement::open(&start, &mut scopes)?);
+                    None
+                }
+                Event::Empty(start) => Some(XmlElement::open(&start, &mut scopes)?),
+                Event::End(_) => stack.pop(),
+                Event::Text(text) => {
+                    let text = text
+                        .unescape()
+                        .map_err(|error| XmlError(error.to_string()))?;
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(text.trim());
+                    }
+                    None
+                }
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element
+                            .text
+                            .push_str(&String::from_utf8_lossy(&data.into_inner()));
+                    }
+                    None
+                }
+                Event::Eof => break,
+                _ => None,
+            };
+
+            if let Some(element) = closed {
+                scopes.pop();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None if root.is_none() => root = Some(element),
+                    None => return Err(XmlError("multiple root elements".to_string())),
+                }
+            }
+        }
+
+        match root {
+            Some(root) if stack.is_empty() => Ok(root),
+            _ => Err(XmlError("incomplete xml document".to_string())),
+        }
+    }
+
+    // base64Binary content back to serde hexadecimal text
+    fn base64_to_hexa(
+        &mut self,
+        schema: &XmlSchema,
+        parent: Option<&String>,
+    ) -> Result<(), XmlError> {
+        if !self.text.is_empty() && schema.is_base64(parent, &self.name) {
+            let data = base64_decode(&self.text).map_err(|error| XmlError(error.to_string()))?;
+            self.text = dump_hexa(&data);
+        }
+        let name = self.name.clone();
+        for child in &mut self.children {
+            child.base64_to_hexa(schema, Some(&name))?;
+        }
+        // simple content (CONTENT field) is element own text
+        if schema.is_base64(Some(&name), "CONTENT") && !self.text.is_empty() {
+            let data = base64_decode(&self.text).map_err(|error| XmlError(error.to_string()))?;
+            self.text = dump_hexa(&data);
+        }
+        Ok(())
+    }
+
+    fn parse_text<T: std::str::FromStr>(&self) -> Result<T, XmlError> {
+        match self.text.parse::<T>() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(XmlError(format!(
+                "element {} invalid value:'{}'",
+                self.name, self.text
+            ))),
+        }
+    }
+}
+
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident),+) => {
+        $(fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+            visitor.$visit(self.parse_text()?)
+        })+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for XmlElement {
+    type Error = XmlError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        if self.children.is_empty() && self.attributes.is_empty() {
+            visitor.visit_string(self.text)
+        } else {
+            self.deserialize_map(visitor)
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        match self.text.as_str() {
+            "true" | "1" => visitor.visit_bool(true),
+            "false" | "0" => visitor.visit_bool(false),
+            _ => Err(XmlError(format!(
+                "element {} invalid boolean:'{}'",
+                self.name, self.text
+            ))),
+        }
+    }
+
+    deserialize_number!(
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64
+    );
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_string(self.text)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_string(self.text)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_seq(XmlSeqAccess(vec![self].into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_map(XmlMapAccess::new(self, false))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        let content = fields.contains(&"CONTENT");
+        visitor.visit_map(XmlMapAccess::new(self, content))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        char bytes byte_buf identifier
+    }
+}
+
+// repeated elements sharing the same name (xsd maxOccurs > 1)
+struct XmlGroup(Vec<XmlElement>);
+
+impl<'de> de::Deserializer<'de> for XmlGroup {
+    type Error = XmlError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_seq(XmlSeqAccess(self.0.into_iter()))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, XmlError> {
+        visitor.visit_some(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct XmlSeqAccess(std::vec::IntoIter<XmlElement>);
+
+impl<'de> SeqAccess<'de> for XmlSeqAccess {
+    type Error = XmlError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, XmlError> {
+        match self.0.next() {
+            Some(element) => seed.deserialize(element).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+struct XmlMapAccess {
+    entries: std::vec::IntoIter<(String, Vec<XmlElement>)>,
+    value: Option<Vec<XmlElement>>,
+}
+
+impl XmlMapAccess {
+    // attributes, simple content & children grouped by element name
+    fn new(element: XmlElement, content: bool) -> Self {
+        let mut entries: Vec<(String, Vec<XmlElement>)> = Vec::new();
+        for (name, value) in element.attributes {
+            entries.push((name.clone(), vec![XmlElement::from_text(name, value)]));
+        }
+        if content {
+            let name = "CONTENT".to_string();
+            entries.push((
+                name.clone(),
+                vec![XmlElement::from_text(name, element.text)],
+            ));
+        }
+        for child in element.children {
+            match entries.iter_mut().find(|(name, _)| *name == child.name) {
+                Some((_, group)) => group.push(child),
+                None => entries.push((child.name.clone(), vec![child])),
+            }
+        }
+        XmlMapAccess {
+            entries: entries.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for XmlMapAccess {
+    type Error = XmlError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, XmlError> {
+        match self.entries.next() {
+            Some((name, group)) => {
+                self.value = Some(group);
+                let key: de::value::StringDeserializer<XmlError> = name.into_deserializer();
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, XmlError> {
+        let mut group = match self.value.take() {
+            Some(group) => group,
+            None => return Err(XmlError("xml value without element".to_string())),
+        };
+        if group.len() == 1 {
+            match group.pop() {
+                Some(element) => seed.deserialize(element),
+                None => Err(XmlError("xml value without element".to_string())),
+            }
+        } else {
+            seed.deserialize(XmlGroup(group))
+        }
+    }
+}
+
+// enum variant is the element single child (Body content, appHand root)
+impl<'de> EnumAccess<'de> for XmlElement {
+    type Error = XmlError;
+    type Variant = XmlElement;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        mut self,
+        seed: V,
+    ) -> Result<(V::Value, XmlElement), XmlError> {
+        if self.children.len() != 1 {
+            return Err(XmlError(format!(
+                "element {} should have exactly one child",
+                self.name
+            )));
+        }
+        match self.children.pop() {
+            Some(child) => {
+                let key: de::value::StringDeserializer<XmlError> =
+                    child.name.clone().into_deserializer();
+                Ok((seed.deserialize(key)?, child))
+            }
+            None => Err(XmlError(format!("element {} is empty", self.name))),
+        }
+    }
+}
+
+impl<'de> VariantAccess<'de> for XmlElement {
+    type Error = XmlError;
+
+    fn unit_variant(self) -> Result<(), XmlError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, XmlError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, XmlError> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, XmlError> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+fn xml_root(xml: &str, schema: &XmlSchema) -> Result<XmlElement, XmlError> {
+    let mut root = XmlElement::parse(xml)?;
+    let expected = schema
+        .element_ns(&[], &root.name)
+        .map(|idx| schema.namespaces[idx].1);
+    if root.namespace.as_deref() != expected {
+        return Err(XmlError(format!(
+            "root element {} namespace:{} is not {}",
+            root.name,
+            root.namespace.as_deref().unwrap_or("none"),
+            schema.label
+        )));
+    }
+    if let Some(name) = schema.root {
+        if root.name != name {
+            return Err(XmlError(format!(
+                "root element {} is not {}",
+                root.name, name
+            )));
+        }
+    }
+    root.base64_to_hexa(schema, None)?;
+    Ok(root)
+}
+
+fn xml_import<T: DeserializeOwned>(xml: &str, schema: &XmlSchema) -> Result<T, XmlError> {
+    let root = xml_root(xml, schema)?;
+    match schema.root {
+        Some(_) => T::deserialize(root),
+        // appHand enum variant is the root element, wrap it into an anonymous parent
+        None => {
+            let mut parent = XmlElement::from_text(String::new(), String::new());
+            parent.children.push(root);
+            T::deserialize(parent)
+        }
+    }
+}
+
+/// XSD conformant XML export/import, complement to ExiCodec for conformance fixtures
+pub trait ExiXml: Sized {
+    /// V2G_Message (or appHand) document with XSD namespaces
+    fn to_xml(&self) -> Result<String, AfbError>;
+
+    /// Namespace prefixes are free, root element namespace should match the protocol
+    fn from_xml(xml: &str) -> Result<Self, AfbError>;
+}
+
+macro_rules! impl_exi_xml {
+    ($type:ty, $schema:expr) => {
+        impl ExiXml for $type {
+            #[track_caller]
+            fn to_xml(&self) -> Result<String, AfbError> {
+                match xml_export(self, &$schema) {
+                    Ok(xml) => Ok(xml),
+                    Err(error) => afb_error!("exi-xml-export", "{} {}", $schema.label, error),
+                }
+            }
+
+            #[track_caller]
+            fn from_xml(xml: &str) -> Result<Self, AfbError> {
+                match xml_import(xml, &$schema) {
+                    Ok(value) => Ok(value),
+                    Err(error) => afb_error!("exi-xml-import", "{} {}", $schema.label, error),
+                }
+            }
+        }
+    };
+}
+
+impl_exi_xml!(iso2_exi::ExiMessageDoc, ISO2_SCHEMA);
+impl_exi_xml!(din_exi::ExiMessageDoc, DIN_SCHEMA);
+impl_exi_xml!(v2g::V2gMsgBody, APPHAND_SCHEMA);
+
+impl V2gDocument {
+    /// XML export, ISO-20 documents have no XML mapping
+    #[track_caller]
+    pub fn to_xml(&self) -> Result<String, AfbError> {
+        match self {
+            V2gDocument::AppHand(document) => document.to_xml(),
+            V2gDocument::Din(document) => document.to_xml(),
+            V2gDocument::Iso2(document) => document.to_xml(),
+            _ => afb_error!(
+                "v2g-document-xml",
+                "protocol:{} has no xml mapping",
+                self.get_protocol().to_label()
+            ),
+        }
+    }
+
+    /// XML import, protocol is selected from root element namespace
+    #[track_caller]
+    pub fn from_xml(xml: &str) -> Result<Self, AfbError> {
+        let namespace = match XmlElement::parse(xml) {
+            Ok(root) => root.namespace.unwrap_or_default(),
+            Err(error) => return afb_error!("v2g-document-xml", "{}", error),
+        };
+        let document = if namespace == APPHAND_SCHEMA.namespaces[0].1 {
+            V2gDocument::AppHand(v2g::V2gMsgBody::from_xml(xml)?)
+        } else if namespace == DIN_SCHEMA.namespaces[NS_MSG_DEF].1 {
+            V2gDocument::Din(din_exi::ExiMessageDoc::from_xml(xml)?)
+        } else if namespace == ISO2_SCHEMA.namespaces[NS_MSG_DEF].1 {
+            V2gDocument::Iso2(iso2_exi::ExiMessageDoc::from_xml(xml)?)
+        } else {
+            return afb_error!(
+                "v2g-document-xml",
+                "unsupported root namespace:'{}'",
+                namespace
+            );
+        };
+        Ok(document)
+    }
+}
@@ -0,0 +1,276 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Reference:
+ *   ISO 15118-2:2014 §7.8.3 (V2GTP header) [V2G2-061] to [V2G2-067]
+ */
+
+use crate::prelude::*;
+use std::sync::MutexGuard;
+
+// V2GTP header: version, inverse version, payload type (u16 BE), payload length (u32 BE)
+pub const V2GTP_HEADER_LEN: usize = v2g::SDP_V2G_HEADER_LEN;
+pub const V2GTP_VERSION: u8 = 0x01;
+pub const V2GTP_VERSION_INV: u8 = 0xFE;
+
+// only accept known ids, INVALID (0) is never a valid payload type on the wire
+fn v2gtp_payload_id(code: u16) -> Option<v2g::PayloadMsgId> {
+    match v2g::PayloadMsgId::try_from(code) {
+        Ok(v2g::PayloadMsgId::INVALID) | Err(_) => None,
+        Ok(value) => Some(value),
+    }
+}
+
+/// Parse V2GTP header, returns None until the 8 header bytes are available.
+/// Frames larger than max_len (header included) are rejected before any buffering.
+#[track_caller]
+pub fn v2gtp_parse_header(
+    data: &[u8],
+    max_len: usize,
+) -> Result<Option<(v2g::PayloadMsgId, usize)>, AfbError> {
+    if data.len() < V2GTP_HEADER_LEN {
+        return Ok(None);
+    }
+    if data[0] != V2GTP_VERSION || data[1] != V2GTP_VERSION_INV {
+        return afb_error!(
+            "v2gtp-header-parse",
+            "invalid version:[{:#02x},{:#02x}]",
+            data[0],
+            data[1]
+        );
+    }
+
+    let code = u16::from_be_bytes([data[2], data[3]]);
+    let payload_id = match v2gtp_payload_id(code) {
+        Some(value) => value,
+        None => return afb_error!("v2gtp-header-parse", "unknown payload type:{:#04x}", code),
+    };
+
+    let payload_len = u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+    if payload_len > max_len.saturating_sub(V2GTP_HEADER_LEN) {
+        return afb_error!(
+            "v2gtp-header-parse",
+            "frame size:{} too big max:{}",
+            payload_len + V2GTP_HEADER_LEN,
+            max_len
+        );
+    }
+    Ok(Some((payload_id, V2GTP_HEADER_LEN + payload_len)))
+}
+
+pub fn v2gtp_make_header(
+    payload_id: v2g::PayloadMsgId,
+    payload_len: u32,
+) -> [u8; V2GTP_HEADER_LEN] {
+    let code = (payload_id as u16).to_be_bytes();
+    let len = payload_len.to_be_bytes();
+    [
+        V2GTP_VERSION,
+        V2GTP_VERSION_INV,
+        code[0],
+        code[1],
+        len[0],
+        len[1],
+        len[2],
+        len[3],
+    ]
+}
+
+pub enum V2gtpChunk {
+    Partial,
+    Complete(v2g::PayloadMsgId),
+}
+
+impl RawStream {
+    /// Accumulate a TCP chunk into stream buffer and return the number of bytes consumed.
+    /// When the frame is complete the stream is ready for decode_from_stream, remaining
+    /// chunk bytes belong to the next frame and should be pushed after decoding.
+    #[track_caller]
+    pub fn push_chunk(&mut self, chunk: &[u8]) -> Result<(usize, V2gtpChunk), AfbError> {
+        // previous frame was completed, start a new one
+        if self.expected_len != 0 && self.current_len == self.expected_len {
+            self.reset();
+        }
+
+        let mut consumed = 0;
+        let mut current = self.current_len as usize;
+
+        // complete header first, then we know the frame size
+        if self.expected_len == 0 {
+            let count = usize::min(V2GTP_HEADER_LEN - current, chunk.len());
+            self.buffer[current..current + count].copy_from_slice(&chunk[0..count]);
+            current += count;
+            consumed += count;
+            self.current_len = current as u32;
+
+            match v2gtp_parse_header(&self.buffer[0..current], EXI_MAX_DOCUMENT_SIZE) {
+                Ok(Some((_, frame_len))) => self.expected_len = frame_len as u32,
+                Ok(None) => return Ok((consumed, V2gtpChunk::Partial)),
+                Err(error) => {
+                    self.reset();
+                    return Err(error);
+                }
+            }
+        }
+
+        let expected = self.expected_len as usize;
+        let count = usize::min(expected - current, chunk.len() - consumed);
+        self.buffer[current..current + count].copy_from_slice(&chunk[consumed..consumed + count]);
+        current += count;
+        consumed += count;
+        self.current_len = current as u32;
+
+        if current < expected {
+            return Ok((consumed, V2gtpChunk::Partial));
+        }
+
+        let payload_id = match v2gtp_parse_header(&self.buffer[0..current], expected)? {
+            Some((payload_id, _)) => payload_id,
+            None => return afb_error!("v2gtp-push-chunk", "hoops lost frame header"),
+        };
+
+        // same as ExiStream::finalize, skip header for exi decoder
+        self.set_window(expected, V2GTP_HEADER_LEN);
+        Ok((consumed, V2gtpChunk::Complete(payload_id)))
+    }
+}
+
+impl ExiStream {
+    pub fn push_chunk(
+        &self,
+        lock: &mut MutexGuard<RawStream>,
+        chunk: &[u8],
+    ) -> Result<(usize, V2gtpChunk), AfbError> {
+        lock.push_chunk(chunk)
+    }
+}
+
+/// Owned V2GTP frame, payload excludes the header
+pub struct V2gtpFrame {
+    pub payload_id: v2g::PayloadMsgId,
+    pub payload: Vec<u8>,
+}
+
+impl V2gtpFrame {
+    pub fn new(payload_id: v2g::PayloadMsgId, payload: &[u8]) -> Self {
+        V2gtpFrame {
+            payload_id,
+            payload: payload.to_vec(),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(V2GTP_HEADER_LEN + self.payload.len());
+        data.extend_from_slice(&v2gtp_make_header(
+            self.payload_id,
+            self.payload.len() as u32,
+        ));
+        data.extend_from_slice(&self.payload);
+        data
+    }
+
+    /// Extract first complete frame from buffer, consumed bytes are drained
+    #[track_caller]
+    pub fn decode(buffer: &mut Vec<u8>, max_len: usize) -> Result<Option<Self>, AfbError> {
+        let (payload_id, frame_len) = match v2gtp_parse_header(buffer, max_len)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+        if buffer.len() < frame_len {
+            return Ok(None);
+        }
+        let payload = buffer[V2GTP_HEADER_LEN..frame_len].to_vec();
+        buffer.drain(0..frame_len);
+        Ok(Some(V2gtpFrame {
+            payload_id,
+            payload,
+        }))
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+mod codec {
+    use super::*;
+    use bytes::{Buf, BytesMut};
+    use std::io;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    fn io_error(error: AfbError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+    }
+
+    pub struct V2gtpCodec {
+        max_len: usize,
+    }
+
+    impl V2gtpCodec {
+        pub fn new() -> Self {
+            V2gtpCodec {
+                max_len: EXI_MAX_DOCUMENT_SIZE,
+            }
+        }
+
+        pub fn set_max_len(&mut self, max_len: usize) -> &mut Self {
+            self.max_len = max_len;
+            self
+        }
+    }
+
+    impl Decoder for V2gtpCodec {
+        type Item = V2gtpFrame;
+        type Error = io::Error;
+
+        fn decode(&mut self, src: &mut BytesMut) -> Result<Option<V2gtpFrame>, io::Error> {
+            let (payload_id, frame_len) = match v2gtp_parse_header(&src[..], self.max_len) {
+                Ok(Some(value)) => value,
+                Ok(None) => return Ok(None),
+                Err(error) => return Err(io_error(error)),
+            };
+            if src.len() < frame_len {
+                src.reserve(frame_len - src.len());
+                return Ok(None);
+            }
+            src.advance(V2GTP_HEADER_LEN);
+            let payload = src.split_to(frame_len - V2GTP_HEADER_LEN).to_vec();
+            Ok(Some(V2gtpFrame {
+                payload_id,
+                payload,
+            }))
+        }
+    }
+
+    impl Encoder<V2gtpFrame> for V2gtpCodec {
+        type Error = io::Error;
+
+        fn encode(&mut self, frame: V2gtpFrame, dst: &mut BytesMut) -> Result<(), io::Error> {
+            if V2GTP_HEADER_LEN + frame.payload.len() > self.max_len {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "v2gtp frame too big",
+                ));
+            }
+            dst.extend_from_slice(&v2gtp_make_header(
+                frame.payload_id,
+                frame.payload.len() as u32,
+            ));
+            dst.extend_from_slice(&frame.payload);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "tokio-codec")]
+pub use codec::*;
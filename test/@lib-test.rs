@@ -25,9 +25,6 @@
 )]
 
 // cargo test --package iso15118 --test test-v2g
-#[cfg(not(afbv4))]
-#[cfg(not(feature = "afbmock"))]
-extern crate afbv4;
 
 #[cfg(test)]
 #[path = "sdp-test.rs"]
@@ -44,3 +41,84 @@ mod test_v2g;
 #[cfg(test)]
 #[path = "iso2-test.rs"]
 mod test_iso2;
+
+#[cfg(test)]
+#[path = "iso20-test.rs"]
+mod test_iso20;
+
+#[cfg(test)]
+#[path = "iso20-dc-test.rs"]
+mod test_iso20_dc;
+
+#[cfg(test)]
+#[path = "iso20-ac-test.rs"]
+mod test_iso20_ac;
+
+#[cfg(test)]
+#[path = "controller-test.rs"]
+mod test_controller;
+
+#[cfg(test)]
+#[path = "evcc-test.rs"]
+mod test_evcc;
+
+#[cfg(test)]
+#[path = "din-controller-test.rs"]
+mod test_din_controller;
+
+#[cfg(test)]
+#[cfg(feature = "sdp-socket")]
+#[path = "sdp-socket-test.rs"]
+mod test_sdp_socket;
+
+#[cfg(test)]
+#[path = "replay-test.rs"]
+mod test_replay;
+
+#[cfg(test)]
+#[path = "framing-test.rs"]
+mod test_framing;
+
+#[cfg(test)]
+#[path = "exi-codec-test.rs"]
+mod test_exi_codec;
+
+#[cfg(test)]
+#[path = "stream-miri-test.rs"]
+mod test_stream_miri;
+
+#[cfg(test)]
+#[path = "exi-error-test.rs"]
+mod test_exi_error;
+
+#[cfg(all(test, feature = "serde"))]
+#[path = "serde-test.rs"]
+mod test_serde;
+
+#[cfg(test)]
+#[path = "v2g-document-test.rs"]
+mod test_v2g_document;
+
+#[cfg(all(test, feature = "xml"))]
+#[path = "v2g-xml-test.rs"]
+mod test_v2g_xml;
+
+#[cfg(test)]
+#[path = "exi-format-test.rs"]
+mod test_exi_format;
+
+#[cfg(test)]
+#[path = "physical-value-test.rs"]
+mod test_physical_value;
+
+#[cfg(test)]
+#[path = "quantity-test.rs"]
+mod test_quantity;
+
+#[cfg(test)]
+#[path = "charging-session-test.rs"]
+mod test_charging_session;
+
+#[cfg(all(test, feature = "pcap"))]
+#[path = "pcap-test.rs"]
+mod test_pcap;
@@ -0,0 +1,443 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --package iso15118 --test test-v2g test_charging_session
+use iso15118::prelude::*;
+
+const EVSE_ID: &str = "tux-evse-001";
+
+// one backend for DIN, ISO-2 and ISO-20
+struct MockBackend {}
+
+impl MockBackend {
+    fn limits() -> ChargingLimits {
+        ChargingLimits {
+            max_voltage: Some(Voltage::new(500, 0)),
+            max_current: Some(Current::new(200, 0)),
+            max_power: Some(Power::new(100, 3)),
+            ..Default::default()
+        }
+    }
+}
+
+impl ChargingBackend for MockBackend {
+    fn param_discovery(
+        &self,
+        _request: &ChargingParamDiscovery,
+    ) -> Result<ChargingEvseParam, AfbError> {
+        Ok(ChargingEvseParam {
+            rcode: ChargingResponseCode::Ok,
+            status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+            processing: ChargingProcessing::Finished,
+            limits: MockBackend::limits(),
+            current_ripple: Current::new(1, 0),
+            energy_to_deliver: None,
+        })
+    }
+
+    fn cable_check(&self, _status: &ChargingEvStatus) -> Result<ChargingEvseCableCheck, AfbError> {
+        let mut status = ChargingEvseStatus::new(ChargingEvseState::Ready);
+        status.isolation = Some(ChargingIsolation::Valid);
+        Ok(ChargingEvseCableCheck {
+            rcode: ChargingResponseCode::Ok,
+            status,
+            processing: ChargingProcessing::Finished,
+        })
+    }
+
+    fn pre_charge(&self, request: &ChargingPreCharge) -> Result<ChargingEvsePreCharge, AfbError> {
+        Ok(ChargingEvsePreCharge {
+            rcode: ChargingResponseCode::Ok,
+            status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+            present_voltage: request.target_voltage,
+        })
+    }
+
+    fn power_delivery(
+        &self,
+        _request: &ChargingPowerDelivery,
+    ) -> Result<ChargingEvsePowerDelivery, AfbError> {
+        Ok(ChargingEvsePowerDelivery {
+            rcode: ChargingResponseCode::Ok,
+            status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+        })
+    }
+
+    fn current_demand(
+        &self,
+        request: &ChargingCurrentDemand,
+    ) -> Result<ChargingEvseCurrentDemand, AfbError> {
+        let limits = MockBackend::limits();
+        let mut status = ChargingEvseStatus::new(ChargingEvseState::Ready);
+        if request.status.soc >= Some(Soc::new(90)?) {
+            status.notification = ChargingEvseNotification::StopCharging;
+        }
+        Ok(ChargingEvseCurrentDemand {
+            rcode: ChargingResponseCode::Ok,
+            status,
+            present_voltage: request.target_voltage.unwrap_or(Voltage::new(400, 0)),
+            present_current: request.target_current.unwrap_or(Current::new(50, 0)),
+            limits,
+            voltage_limit_achieved: false,
+            current_limit_achieved: false,
+            power_limit_achieved: false,
+        })
+    }
+
+    fn welding_detection(
+        &self,
+        _status: &ChargingEvStatus,
+    ) -> Result<ChargingEvseWeldingDetection, AfbError> {
+        Ok(ChargingEvseWeldingDetection {
+            rcode: ChargingResponseCode::Ok,
+            status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+            present_voltage: Voltage::new(0, 0),
+        })
+    }
+}
+
+#[test]
+fn charging_requests() -> Result<(), AfbError> {
+    let voltage = Voltage::new(4005, -1);
+    let current = Current::new(80, 0);
+
+    // same current demand from every protocol
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 50);
+    let mut request =
+        din_exi::CurrentDemandRequest::new(&status, &current.into(), &voltage.into(), false);
+    request.set_power_limit(&Power::new(80, 3).into())?;
+    let din = ChargingCurrentDemand::from_din(&request)?;
+    assert!(din.status.soc == Some(Soc::new(50)?));
+    assert!(din.limits.max_power == Some(Power::from_decimal("80000")?));
+
+    let status =
+        iso2_exi::DcEvStatusType::new(true, iso2_exi::DcEvErrorCode::FailVoltOutOfRange, 127);
+    let request =
+        iso2_exi::CurrentDemandRequest::new(&status, &current.into(), &voltage.into(), true);
+    let iso2 = ChargingCurrentDemand::from_iso2(&request)?;
+    assert!(iso2.status.error == ChargingEvError::VoltageOutOfRange);
+    assert!(iso2.status.soc.is_none());
+    assert!(iso2.charging_complete);
+
+    let mode = iso20_dc_exi::ScheduledDcClReqMode::new(&current.into(), &voltage.into());
+    let request = iso20_dc_exi::DcChargeLoopRequest::new(
+        &Voltage::new(398, 0).into(),
+        &iso20_dc_exi::DcClReqControlMode::Scheduled(mode),
+    );
+    let iso20 = ChargingCurrentDemand::from_iso20(&request)?;
+    assert!(iso20.control_mode == ChargingControlMode::Scheduled);
+    assert!(iso20.present_voltage == Some(Voltage::new(398, 0)));
+    assert!(iso20.status == ChargingEvStatus::ready());
+
+    for demand in [&din, &iso2, &iso20] {
+        assert!(demand.target_voltage == Some(voltage));
+        assert!(demand.target_current == Some(current));
+    }
+
+    // ISO-20 dynamic mode leaves targets to the EVSE
+    let mut mode = iso20_dc_exi::DynamicDcClReqMode::new(
+        &Energy::new(40, 3).into(),
+        &Energy::new(60, 3).into(),
+        &Energy::new(10, 3).into(),
+    );
+    mode.set_max_charge_power(&Power::new(150, 3).into())
+        .set_min_charge_power(&Power::new(0, 0).into())
+        .set_max_charge_current(&Current::new(300, 0).into())
+        .set_max_voltage(&Voltage::new(800, 0).into())
+        .set_min_voltage(&Voltage::new(200, 0).into())
+        .set_departure_time(3600);
+    let request = iso20_dc_exi::DcChargeLoopRequest::new(
+        &Voltage::new(400, 0).into(),
+        &iso20_dc_exi::DcClReqControlMode::Dynamic(mode),
+    );
+    let dynamic = ChargingCurrentDemand::from_iso20(&request)?;
+    assert!(dynamic.control_mode == ChargingControlMode::Dynamic);
+    assert!(dynamic.target_current.is_none());
+    assert!(dynamic.limits.max_current == Some(Current::new(300, 0)));
+    assert!(dynamic.energy_request == Some(Energy::new(40, 3)));
+    assert!(dynamic.departure_time == Some(Duration::from_secs(3600)?));
+
+    // complete flags: ISO-2 DC param, ISO-20 stop progress
+    let status = iso2_exi::DcEvStatusType::new(true, iso2_exi::DcEvErrorCode::NoError, 100);
+    let mut param = iso2_exi::DcEvPowerDeliveryParam::new(&status, true);
+    param.set_bulk_complete(true);
+    let mut request = iso2_exi::PowerDeliveryRequest::new(iso2_exi::ChargeProgress::Stop, 1);
+    request.set_dc_delivery_params(&param)?;
+    let delivery = ChargingPowerDelivery::from_iso2(&request)?;
+    assert!(delivery.progress == ChargingProgress::Stop);
+    assert!(delivery.charging_complete && delivery.bulk_complete == Some(true));
+
+    let request = iso20_exi::PowerDeliveryRequest::new(
+        iso20_exi::EvseProcessing::Finished,
+        iso20_exi::ChargeProgress::Stop,
+    );
+    let delivery = ChargingPowerDelivery::from_iso20(&request)?;
+    assert!(delivery.status.is_none() && delivery.charging_complete);
+
+    // ISO-20 param discovery
+    let mut transfer = iso20_dc_exi::DcCpdReqEnergyTransfer::new(
+        &Power::new(150, 3).into(),
+        &Power::new(0, 0).into(),
+        &Current::new(300, 0).into(),
+        &Current::new(0, 0).into(),
+        &Voltage::new(800, 0).into(),
+        &Voltage::new(200, 0).into(),
+    );
+    transfer.set_target_soc(80);
+    let request = iso20_dc_exi::DcChargeParamDiscoveryRequest::new(&transfer);
+    let discovery = ChargingParamDiscovery::from_iso20(&request)?;
+    assert!(discovery.limits.max_voltage == Some(Voltage::new(800, 0)));
+    assert!(discovery.limits.min_voltage == Some(Voltage::new(200, 0)));
+    assert!(discovery.target_soc == Some(Soc::new(80)?));
+    assert!(!discovery.bidirectional && discovery.limits.max_discharge_power.is_none());
+    Ok(())
+}
+
+#[test]
+fn charging_responses() -> Result<(), AfbError> {
+    let mut status = ChargingEvseStatus::new(ChargingEvseState::Ready);
+    status.notification = ChargingEvseNotification::StopCharging;
+    let response = ChargingEvseCurrentDemand {
+        rcode: ChargingResponseCode::Ok,
+        status,
+        present_voltage: Voltage::new(4005, -1),
+        present_current: Current::new(80, 0),
+        limits: ChargingLimits::default(),
+        voltage_limit_achieved: false,
+        current_limit_achieved: true,
+        power_limit_achieved: false,
+    };
+
+    let din = response.to_din()?;
+    assert!(din.get_status().get_notification()? == din_exi::EvseNotification::StopCharging);
+    assert!(din.get_voltage_present().to_quantity::<Voltage>()? == response.present_voltage);
+    assert!(din.get_current_limit_reach());
+
+    let iso2 = response.to_iso2(EVSE_ID, 3)?;
+    assert!(iso2.get_evse_id()? == EVSE_ID && iso2.get_tuple_id() == 3);
+    assert!(iso2.get_current_present().to_quantity::<Current>()? == response.present_current);
+
+    let iso20 = response.to_iso20(ChargingControlMode::Scheduled)?;
+    assert!(iso20.get_current_limit_achieved());
+    match iso20.get_evse_status() {
+        Some(status) => {
+            assert!(status.get_notification()? == iso20_exi::EvseNotification::Terminate)
+        }
+        None => panic!("stop charging should set ISO-20 EVSE status"),
+    }
+
+    // dynamic mode requires EVSE limits
+    if response.to_iso20(ChargingControlMode::Dynamic).is_ok() {
+        panic!("dynamic response without limits should fail");
+    }
+    let response = ChargingEvseCurrentDemand {
+        limits: MockBackend::limits(),
+        ..response
+    };
+    response.to_iso20(ChargingControlMode::Dynamic)?;
+
+    // DIN max power is optional, ISO-2 one is mandatory
+    let mut param = ChargingEvseParam {
+        rcode: ChargingResponseCode::Ok,
+        status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+        processing: ChargingProcessing::Ongoing,
+        limits: MockBackend::limits(),
+        current_ripple: Current::new(1, 0),
+        energy_to_deliver: None,
+    };
+    param.limits.max_power = None;
+    param.to_din()?;
+    assert!(param.to_iso2().is_err());
+    assert!(param.to_iso20(false).is_err());
+    Ok(())
+}
+
+#[test]
+fn charging_bpt() -> Result<(), AfbError> {
+    // BPT param discovery keeps EV discharge limits and expects a BPT response
+    let mut transfer = iso20_dc_exi::BptDcCpdReqEnergyTransfer::new(
+        &Power::new(150, 3).into(),
+        &Power::new(0, 0).into(),
+        &Current::new(300, 0).into(),
+        &Current::new(0, 0).into(),
+        &Voltage::new(800, 0).into(),
+        &Voltage::new(200, 0).into(),
+    );
+    transfer
+        .set_max_discharge_power(&Power::new(11, 3).into())
+        .set_min_discharge_power(&Power::new(0, 0).into())
+        .set_max_discharge_current(&Current::new(30, 0).into())
+        .set_min_discharge_current(&Current::new(0, 0).into());
+    let bpt_request = iso20_dc_exi::DcChargeParamDiscoveryRequest::new_bpt(&transfer);
+    let discovery = ChargingParamDiscovery::from_iso20(&bpt_request)?;
+    assert!(discovery.bidirectional);
+    assert!(discovery.limits.max_discharge_power == Some(Power::new(11, 3)));
+    assert!(discovery.limits.max_discharge_current == Some(Current::new(30, 0)));
+
+    let mut param = ChargingEvseParam {
+        rcode: ChargingResponseCode::Ok,
+        status: ChargingEvseStatus::new(ChargingEvseState::Ready),
+        processing: ChargingProcessing::Finished,
+        limits: MockBackend::limits(),
+        current_ripple: Current::new(1, 0),
+        energy_to_deliver: None,
+    };
+    assert!(param.to_iso20(true).is_err());
+    param.limits.max_discharge_power = Some(Power::new(10, 3));
+    param.limits.max_discharge_current = Some(Current::new(25, 0));
+    let response = param.to_iso20(true)?;
+    assert!(response.get_energy_transfer().is_none());
+    match response.get_bpt_energy_transfer() {
+        Some(transfer) => {
+            assert!(transfer.get_max_discharge_power().to_quantity::<Power>()? == Power::new(10, 3))
+        }
+        None => panic!("BPT request should get a BPT energy transfer"),
+    }
+
+    // BPT scheduled charge loop, negative target current discharges the EV
+    let mut mode = iso20_dc_exi::BptScheduledDcClReqMode::new(
+        &Current::new(-20, 0).into(),
+        &Voltage::new(400, 0).into(),
+    );
+    mode.set_max_discharge_power(&Power::new(8, 3).into())
+        .set_max_discharge_current(&Current::new(20, 0).into());
+    let request = iso20_dc_exi::DcChargeLoopRequest::new(
+        &Voltage::new(398, 0).into(),
+        &iso20_dc_exi::DcClReqControlMode::BptScheduled(mode),
+    );
+    let demand = ChargingCurrentDemand::from_iso20(&request)?;
+    assert!(demand.control_mode == ChargingControlMode::BptScheduled);
+    assert!(demand.control_mode.is_bpt());
+    assert!(demand.target_current == Some(Current::new(-20, 0)));
+    assert!(demand.limits.max_discharge_power == Some(Power::new(8, 3)));
+    assert!(demand.limits.max_discharge_current == Some(Current::new(20, 0)));
+    assert!(demand.limits.min_discharge_power.is_none());
+
+    let mut response = MockBackend {}.current_demand(&demand)?;
+    response.limits.max_discharge_power = Some(Power::new(10, 3));
+    response.limits.max_discharge_current = Some(Current::new(25, 0));
+    match response.to_iso20(demand.control_mode)?.get_control_mode()? {
+        iso20_dc_exi::DcClResControlMode::BptScheduled(mode) => {
+            assert!(mode.get_max_discharge_current().is_some())
+        }
+        _ => panic!("BPT scheduled request should get a BPT scheduled response"),
+    }
+
+    // BPT dynamic charge loop requires EVSE discharge limits
+    let mut mode = iso20_dc_exi::BptDynamicDcClReqMode::new(
+        &Energy::new(40, 3).into(),
+        &Energy::new(60, 3).into(),
+        &Energy::new(-10, 3).into(),
+    );
+    mode.set_max_charge_power(&Power::new(150, 3).into())
+        .set_min_charge_power(&Power::new(0, 0).into())
+        .set_max_charge_current(&Current::new(300, 0).into())
+        .set_max_voltage(&Voltage::new(800, 0).into())
+        .set_min_voltage(&Voltage::new(200, 0).into())
+        .set_max_discharge_power(&Power::new(11, 3).into())
+        .set_min_discharge_power(&Power::new(0, 0).into())
+        .set_max_discharge_current(&Current::new(30, 0).into());
+    let request = iso20_dc_exi::DcChargeLoopRequest::new(
+        &Voltage::new(400, 0).into(),
+        &iso20_dc_exi::DcClReqControlMode::BptDynamic(mode),
+    );
+    let demand = ChargingCurrentDemand::from_iso20(&request)?;
+    assert!(demand.control_mode == ChargingControlMode::BptDynamic);
+    assert!(demand.limits.max_discharge_current == Some(Current::new(30, 0)));
+    assert!(demand.limits.min_discharge_power == Some(Power::new(0, 0)));
+
+    let response = MockBackend {}.current_demand(&demand)?;
+    assert!(response.to_iso20(ChargingControlMode::BptDynamic).is_err());
+    let response = ChargingEvseCurrentDemand {
+        limits: ChargingLimits {
+            max_discharge_power: Some(Power::new(10, 3)),
+            max_discharge_current: Some(Current::new(25, 0)),
+            ..response.limits
+        },
+        ..response
+    };
+    match response.to_iso20(demand.control_mode)?.get_control_mode()? {
+        iso20_dc_exi::DcClResControlMode::BptDynamic(mode) => {
+            assert!(
+                mode.get_max_discharge_current().to_quantity::<Current>()? == Current::new(25, 0)
+            )
+        }
+        _ => panic!("BPT dynamic request should get a BPT dynamic response"),
+    }
+
+    // mock backend has no discharge limits, session cannot answer a BPT EV
+    let mut session = ChargingSession::new(EVSE_ID, Box::new(MockBackend {}));
+    let body = iso20_dc_exi::MessageBody::DcChargeParamDiscoveryReq(bpt_request);
+    assert!(session.iso20_dc_request(&body).is_err());
+    Ok(())
+}
+
+#[test]
+fn charging_session() -> Result<(), AfbError> {
+    let mut session = ChargingSession::new(EVSE_ID, Box::new(MockBackend {}));
+    let target = Voltage::new(400, 0);
+    let current = Current::new(2, 0);
+
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 50);
+    let request = din_exi::PreChargeRequest::new(&status, &target.into(), &current.into())?;
+    let body = din_exi::MessageBody::PreChargeReq(request);
+    assert!(session.din_request(&body)?.is_some());
+    let body = din_exi::MessageBody::SessionStopReq(din_exi::SessionStopRequest::new(0));
+    assert!(session.din_request(&body)?.is_none());
+
+    let status = iso2_exi::DcEvStatusType::new(true, iso2_exi::DcEvErrorCode::NoError, 95);
+    let request =
+        iso2_exi::CurrentDemandRequest::new(&status, &current.into(), &target.into(), false);
+    let body = iso2_exi::MessageBody::CurrentDemandReq(request);
+    assert!(session.iso2_request(&body)?.is_some());
+    let body = iso2_exi::MessageBody::SessionStopReq(iso2_exi::SessionStopRequest::new(
+        iso2_exi::ChargingSessionType::Terminate,
+    ));
+    assert!(session.iso2_request(&body)?.is_none());
+
+    // AC sessions stay with controllers
+    let ac_param = iso2_exi::AcEvChargeParam::new(
+        &Energy::new(20, 3).into(),
+        &Voltage::new(400, 0).into(),
+        &Current::new(32, 0).into(),
+        &Current::new(6, 0).into(),
+    )?;
+    let mut request =
+        iso2_exi::ParamDiscoveryRequest::new(iso2_exi::EngyTransfertMode::AcThreePhase);
+    request.set_ac_charge_param(&ac_param)?;
+    let body = iso2_exi::MessageBody::ParamDiscoveryReq(request);
+    assert!(session.iso2_request(&body)?.is_none());
+
+    let request = iso20_exi::PowerDeliveryRequest::new(
+        iso20_exi::EvseProcessing::Finished,
+        iso20_exi::ChargeProgress::Start,
+    );
+    let body = iso20_exi::MessageBody::PowerDeliveryReq(request);
+    assert!(session.iso20_request(&body)?.is_some());
+
+    let request = iso20_dc_exi::DcPreChargeRequest::new(
+        iso20_dc_exi::EvseProcessing::Ongoing,
+        &Voltage::new(390, 0).into(),
+        &target.into(),
+    );
+    let body = iso20_dc_exi::MessageBody::DcPreChargeReq(request);
+    assert!(session.iso20_dc_request(&body)?.is_some());
+    let body = iso20_dc_exi::MessageBody::DcCableCheckReq(iso20_dc_exi::DcCableCheckRequest::new());
+    assert!(session.iso20_dc_request(&body)?.is_some());
+    Ok(())
+}
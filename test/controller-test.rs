@@ -0,0 +1,340 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::mock_exi::*;
+use iso15118::prelude::iso2_exi::*;
+use iso15118::prelude::*;
+
+pub const EVSE_ID: &str = "tux-evse-001";
+
+// minimal charger answering OK to every request (also used by evcc tests)
+pub struct MockCharger {}
+
+impl MockCharger {
+    fn ac_status() -> AcEvseStatusType {
+        AcEvseStatusType::new(EvseNotification::None, 0, false)
+    }
+
+    fn dc_status() -> DcEvseStatusType {
+        DcEvseStatusType::new(DcEvseErrorCode::Ready, EvseNotification::None, 0)
+    }
+}
+
+impl Iso2ChargerHandler for MockCharger {
+    fn get_evse_id(&self) -> &str {
+        EVSE_ID
+    }
+
+    fn service_discovery(
+        &self,
+        _request: &ServiceDiscoveryRequest,
+    ) -> Result<ServiceDiscoveryResponse, AfbError> {
+        let mut response = ServiceDiscoveryResponse::new(ResponseCode::Ok);
+        response
+            .set_charging(&ServiceCharging::new(1, false))
+            .add_transfer(EngyTransfertMode::AcSinglePhase)?
+            .add_transfer(EngyTransfertMode::DcExtended)?
+            .add_payment(PaymentOption::External)?;
+        Ok(response)
+    }
+
+    fn service_detail(
+        &self,
+        request: &ServiceDetailRequest,
+    ) -> Result<ServiceDetailResponse, AfbError> {
+        Ok(ServiceDetailResponse::new(
+            request.get_id(),
+            ResponseCode::Ok,
+        ))
+    }
+
+    fn payment_selection(
+        &self,
+        _request: &PaymentSelectionRequest,
+    ) -> Result<PaymentSelectionResponse, AfbError> {
+        Ok(PaymentSelectionResponse::new(ResponseCode::Ok))
+    }
+
+    fn authorization(
+        &self,
+        _request: &AuthorizationRequest,
+    ) -> Result<AuthorizationResponse, AfbError> {
+        Ok(AuthorizationResponse::new(
+            ResponseCode::Ok,
+            EvseProcessing::Finished,
+        ))
+    }
+
+    fn param_discovery(
+        &self,
+        request: &ParamDiscoveryRequest,
+    ) -> Result<ParamDiscoveryResponse, AfbError> {
+        let mut response = ParamDiscoveryResponse::new(ResponseCode::Ok, EvseProcessing::Finished);
+        match request.get_transfert_energy_mode()? {
+            EngyTransfertMode::AcSinglePhase | EngyTransfertMode::AcThreePhase => {
+                let params = AcEvseChargeParam::new(
+                    &MockCharger::ac_status(),
+                    &PhysicalValue::new(240, 0, PhysicalUnit::Volt),
+                    &PhysicalValue::new(32, 0, PhysicalUnit::Ampere),
+                )?;
+                response.set_evse_ac_charge_param(&params);
+            }
+            _ => {
+                let params = DcEvseChargeParam::new(
+                    &MockCharger::dc_status(),
+                    &PhysicalValue::new(500, 0, PhysicalUnit::Volt),
+                    &PhysicalValue::new(200, 0, PhysicalUnit::Volt),
+                    &PhysicalValue::new(100, 0, PhysicalUnit::Ampere),
+                    &PhysicalValue::new(0, 0, PhysicalUnit::Ampere),
+                    &PhysicalValue::new(50, 3, PhysicalUnit::Watt),
+                    &PhysicalValue::new(1, 0, PhysicalUnit::Ampere),
+                )?;
+                response.set_evse_dc_charge_param(&params);
+            }
+        }
+        Ok(response)
+    }
+
+    fn power_delivery(
+        &self,
+        request: &PowerDeliveryRequest,
+    ) -> Result<PowerDeliveryResponse, AfbError> {
+        let mut response = PowerDeliveryResponse::new(ResponseCode::Ok);
+        match request.get_dc_delivery_params() {
+            Some(_) => response.set_dc_evse_status(&MockCharger::dc_status())?,
+            None => response.set_ac_evse_status(&MockCharger::ac_status())?,
+        };
+        Ok(response)
+    }
+
+    fn cable_check(&self, _request: &CableCheckRequest) -> Result<CableCheckResponse, AfbError> {
+        Ok(CableCheckResponse::new(
+            ResponseCode::Ok,
+            &MockCharger::dc_status(),
+            EvseProcessing::Finished,
+        ))
+    }
+
+    fn pre_charge(&self, request: &PreChargeRequest) -> Result<PreChargeResponse, AfbError> {
+        PreChargeResponse::new(
+            ResponseCode::Ok,
+            &MockCharger::dc_status(),
+            &request.get_target_voltage(),
+        )
+    }
+
+    fn current_demand(
+        &self,
+        _request: &CurrentDemandRequest,
+    ) -> Result<CurrentDemandResponse, AfbError> {
+        CurrentDemandResponse::new(
+            ResponseCode::Ok,
+            EVSE_ID,
+            &MockCharger::dc_status(),
+            &PhysicalValue::new(20, 0, PhysicalUnit::Ampere),
+            false,
+            &PhysicalValue::new(400, 0, PhysicalUnit::Volt),
+            false,
+            false,
+            1,
+        )
+    }
+
+    fn charging_status(
+        &self,
+        _request: &ChargingStatusRequest,
+    ) -> Result<ChargingStatusResponse, AfbError> {
+        ChargingStatusResponse::new(ResponseCode::Ok, EVSE_ID, 1, &MockCharger::ac_status())
+    }
+
+    fn welding_detection(
+        &self,
+        _request: &WeldingDetectionRequest,
+    ) -> Result<WeldingDetectionResponse, AfbError> {
+        WeldingDetectionResponse::new(
+            ResponseCode::Ok,
+            &MockCharger::dc_status(),
+            &PhysicalValue::new(0, 0, PhysicalUnit::Volt),
+        )
+    }
+}
+
+// simulate EV SupportedAppProtocolReq and return controller response
+fn send_app_protocol(
+    controller: &IsoController,
+) -> Result<(SeccState, v2g::SupportedAppProtocolRes), AfbError> {
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let request = v2g::SupportedAppProtocolReq::new(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[1])?;
+        v2g::SupportedAppProtocolExi::encode_to_stream(&mut lock, &request.encode())?;
+    }
+
+    let input = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = input.lock_stream();
+    let state = controller.iso_decode_payload(&mut lock)?;
+
+    let output = mock_network_input(lock.get_buffer());
+    let mut lock = output.lock_stream();
+    match v2g::SupportedAppProtocolExi::decode_from_stream(&mut lock)? {
+        v2g::V2gMsgBody::Response(response) => Ok((state, response)),
+        _ => panic!("Unexpected message type"),
+    }
+}
+
+// simulate EV iso2 request and return controller response
+fn send_message(
+    controller: &IsoController,
+    session_id: &[u8],
+    body: Iso2BodyType,
+) -> Result<(SeccState, ExiMessageDoc), AfbError> {
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let header = ExiMessageHeader::new(session_id)?;
+        ExiMessageDoc::new(&header, &body).encode_to_stream(&mut lock)?;
+    }
+
+    let input = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = input.lock_stream();
+    let state = controller.iso_decode_payload(&mut lock)?;
+
+    let output = mock_network_input(lock.get_buffer());
+    let mut lock = output.lock_stream();
+    let message = ExiMessageDoc::decode_from_stream(&mut lock)?;
+    Ok((state, message))
+}
+
+// run SupportedAppProtocol + SessionSetup and return SECC allocated session id
+fn open_session(controller: &IsoController) -> Result<Vec<u8>, AfbError> {
+    let (state, response) = send_app_protocol(controller)?;
+    assert!(state == SeccState::SessionSetup);
+    assert!(response.get_rcode()? == v2g::ResponseCode::Success);
+    assert!(controller.get_protocol()? == v2g::ProtocolTagId::Iso2);
+
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    let (state, message) = send_message(controller, &[0; 8], body)?;
+    assert!(state == SeccState::ServiceDiscovery);
+    let response = match message.get_body()? {
+        MessageBody::SessionSetupRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+    assert!(response.get_rcode()? == ResponseCode::NewSession);
+    assert!(response.get_id()? == EVSE_ID);
+
+    let session_id = message.get_header().get_session_id().to_vec();
+    assert!(session_id == controller.get_session_id()?);
+    Ok(session_id)
+}
+
+#[test]
+fn secc_ac_session() -> Result<(), AfbError> {
+    let controller = IsoController::new(Box::new(MockCharger {}));
+    let session_id = open_session(&controller)?;
+
+    let body = ServiceDiscoveryRequest::new().encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::ServiceDetail);
+    match message.get_body()? {
+        MessageBody::ServiceDiscoveryRes(msg) => assert!(msg.get_rcode()? == ResponseCode::Ok),
+        _ => panic!("Unexpected message type"),
+    };
+
+    let body = PaymentSelectionRequest::new(PaymentOption::External)
+        .add_service(&SelectedService::new(1))?
+        .encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::PaymentSelected);
+
+    let body = AuthorizationRequest::new().encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::ChargeParam);
+
+    let ac_params = AcEvChargeParam::new(
+        &PhysicalValue::new(20, 3, PhysicalUnit::Wh),
+        &PhysicalValue::new(240, 0, PhysicalUnit::Volt),
+        &PhysicalValue::new(32, 0, PhysicalUnit::Ampere),
+        &PhysicalValue::new(6, 0, PhysicalUnit::Ampere),
+    )?;
+    let body = ParamDiscoveryRequest::new(EngyTransfertMode::AcSinglePhase)
+        .set_ac_charge_param(&ac_params)?
+        .encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::PowerDelivery);
+
+    let body = PowerDeliveryRequest::new(ChargeProgress::Start, 1).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::AcCharging);
+
+    let body = ChargingStatusRequest::new().encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::AcCharging);
+    match message.get_body()? {
+        MessageBody::ChargingStatusRes(msg) => assert!(msg.get_evse_id()? == EVSE_ID),
+        _ => panic!("Unexpected message type"),
+    };
+
+    let body = PowerDeliveryRequest::new(ChargeProgress::Stop, 1).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::Stopping);
+
+    let body = SessionStopRequest::new(ChargingSessionType::Terminate).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::Idle);
+    match message.get_body()? {
+        MessageBody::SessionStopRes(msg) => assert!(msg.get_rcode()? == ResponseCode::Ok),
+        _ => panic!("Unexpected message type"),
+    };
+    assert!(controller.get_protocol()? == v2g::ProtocolTagId::Unknown);
+    Ok(())
+}
+
+#[test]
+fn secc_sequence_error() -> Result<(), AfbError> {
+    let controller = IsoController::new(Box::new(MockCharger {}));
+    let session_id = open_session(&controller)?;
+
+    // PowerDelivery before ServiceDiscovery is out of sequence
+    let body = PowerDeliveryRequest::new(ChargeProgress::Start, 1).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == SeccState::Idle);
+    match message.get_body()? {
+        MessageBody::PowerDeliveryRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::SequenceError)
+        }
+        _ => panic!("Unexpected message type"),
+    };
+    Ok(())
+}
+
+#[test]
+fn secc_unknown_session() -> Result<(), AfbError> {
+    let controller = IsoController::new(Box::new(MockCharger {}));
+    open_session(&controller)?;
+
+    let body = ServiceDiscoveryRequest::new().encode();
+    let (state, message) = send_message(&controller, &[0xA, 0xB, 0xC, 0xD], body)?;
+    assert!(state == SeccState::Idle);
+    match message.get_body()? {
+        MessageBody::ServiceDiscoveryRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::UnknownSession)
+        }
+        _ => panic!("Unexpected message type"),
+    };
+    Ok(())
+}
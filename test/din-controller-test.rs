@@ -0,0 +1,316 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::mock_exi::*;
+use iso15118::prelude::din_exi::*;
+use iso15118::prelude::*;
+
+const EVSE_ID: &[u8] = &[0x49, 0xA8, 0x9A, 0x63, 0x60];
+
+// minimal DC charger answering OK to every request
+struct MockDinCharger {}
+
+impl MockDinCharger {
+    fn dc_status() -> DcEvseStatusType {
+        DcEvseStatusType::new(DcEvseErrorCode::Ready, EvseNotification::None, 0)
+    }
+}
+
+impl DinChargerHandler for MockDinCharger {
+    fn get_evse_id(&self) -> &[u8] {
+        EVSE_ID
+    }
+
+    fn service_discovery(
+        &self,
+        _request: &ServiceDiscoveryRequest,
+    ) -> Result<ServiceDiscoveryResponse, AfbError> {
+        let tag = ServiceTag::new(1, ServiceCategory::EvCharger);
+        let charging = ServiceCharging::new(&tag, EvRequestTransfertMode::DcExtended, false);
+        let mut response = ServiceDiscoveryResponse::new(ResponseCode::Ok, &charging);
+        response.add_payment(PaymentOption::External)?;
+        Ok(response)
+    }
+
+    fn contract_authentication(
+        &self,
+        _request: &ContractAuthenticationRequest,
+    ) -> Result<ContractAuthenticationResponse, AfbError> {
+        Ok(ContractAuthenticationResponse::new(
+            ResponseCode::Ok,
+            EvseProcessing::Finished,
+        ))
+    }
+
+    fn param_discovery(
+        &self,
+        _request: &ParamDiscoveryRequest,
+    ) -> Result<ParamDiscoveryResponse, AfbError> {
+        let param = DcEvseChargeParam::new(
+            &Self::dc_status(),
+            &PhysicalValue::new(500, 0, PhysicalUnit::Volt),
+            &PhysicalValue::new(50, 0, PhysicalUnit::Volt),
+            &PhysicalValue::new(125, 0, PhysicalUnit::Ampere),
+            &PhysicalValue::new(0, 0, PhysicalUnit::Ampere),
+            &PhysicalValue::new(1, 0, PhysicalUnit::Ampere),
+        )?;
+        let mut response = ParamDiscoveryResponse::new(ResponseCode::Ok);
+        response
+            .set_processing(EvseProcessing::Finished)
+            .set_evse_dc_charge_param(&param);
+        Ok(response)
+    }
+
+    fn cable_check(&self, _request: &CableCheckRequest) -> Result<CableCheckResponse, AfbError> {
+        Ok(CableCheckResponse::new(
+            ResponseCode::Ok,
+            &Self::dc_status(),
+            EvseProcessing::Finished,
+        ))
+    }
+
+    fn pre_charge(&self, request: &PreChargeRequest) -> Result<PreChargeResponse, AfbError> {
+        PreChargeResponse::new(
+            ResponseCode::Ok,
+            &Self::dc_status(),
+            &request.get_target_voltage(),
+        )
+    }
+
+    fn power_delivery(
+        &self,
+        _request: &PowerDeliveryRequest,
+    ) -> Result<PowerDeliveryResponse, AfbError> {
+        let mut response = PowerDeliveryResponse::new(ResponseCode::Ok);
+        response.set_dc_evse_status(&Self::dc_status())?;
+        Ok(response)
+    }
+
+    fn current_demand(
+        &self,
+        request: &CurrentDemandRequest,
+    ) -> Result<CurrentDemandResponse, AfbError> {
+        CurrentDemandResponse::new(
+            ResponseCode::Ok,
+            &Self::dc_status(),
+            &request.get_voltage_target(),
+            &request.get_current_target(),
+            false,
+            false,
+            false,
+        )
+    }
+
+    fn welding_detection(
+        &self,
+        _request: &WeldingDetectionRequest,
+    ) -> Result<WeldingDetectionResponse, AfbError> {
+        WeldingDetectionResponse::new(
+            ResponseCode::Ok,
+            &Self::dc_status(),
+            &PhysicalValue::new(0, 0, PhysicalUnit::Volt),
+        )
+    }
+}
+
+fn ev_status() -> DcEvStatusType {
+    DcEvStatusType::new(true, DcEvErrorCode::NoError, 50)
+}
+
+fn send_message(
+    controller: &DinController,
+    session_id: &[u8],
+    body: DinBodyType,
+) -> Result<(DinState, MessageBody), AfbError> {
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let header = ExiMessageHeader::new(session_id)?;
+        ExiMessageDoc::new(&header, &body).encode_to_stream(&mut lock)?;
+    }
+
+    let input = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = input.lock_stream();
+    let state = controller.din_decode_payload(&mut lock)?;
+
+    let output = mock_network_input(lock.get_buffer());
+    let message = ExiMessageDoc::decode_from_stream(&mut output.lock_stream())?;
+    Ok((state, message.get_body()?))
+}
+
+fn open_session(controller: &DinController) -> Result<Vec<u8>, AfbError> {
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let request = v2g::SupportedAppProtocolReq::new(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[2])?;
+        v2g::SupportedAppProtocolExi::encode_to_stream(&mut lock, &request.encode())?;
+    }
+    let input = mock_network_input(stream.lock_stream().get_buffer());
+    let state = controller.din_decode_payload(&mut input.lock_stream())?;
+    assert!(state == DinState::SessionSetup);
+    assert!(controller.get_protocol()? == v2g::ProtocolTagId::Din);
+
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    let (state, message) = send_message(controller, &[0; 8], body)?;
+    assert!(state == DinState::ServiceDiscovery);
+    match message {
+        MessageBody::SessionSetupRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::NewSession);
+            assert!(msg.get_id() == EVSE_ID);
+        }
+        _ => panic!("Unexpected message type"),
+    };
+    controller.get_session_id()
+}
+
+#[test]
+fn din_dc_session() -> Result<(), AfbError> {
+    let controller = DinController::new(Box::new(MockDinCharger {}));
+    let session_id = open_session(&controller)?;
+
+    let body = ServiceDiscoveryRequest::new().encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::PaymentSelection);
+
+    let mut request = PaymentSelectionRequest::new(PaymentOption::External);
+    request.add_service(&SelectedService::new(1))?;
+    let (state, _) = send_message(&controller, &session_id, request.encode())?;
+    assert!(state == DinState::ContractAuthentication);
+
+    let body = ContractAuthenticationRequest::new().encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::ChargeParam);
+
+    let mut request = ParamDiscoveryRequest::new(EvRequestTransfertMode::DcExtended);
+    request.set_dc_charge_param(&DcEvChargeParam::new(
+        &ev_status(),
+        &PhysicalValue::new(450, 0, PhysicalUnit::Volt),
+        &PhysicalValue::new(100, 0, PhysicalUnit::Ampere),
+    )?)?;
+    let (state, _) = send_message(&controller, &session_id, request.encode())?;
+    assert!(state == DinState::CableCheck);
+
+    let body = CableCheckRequest::new(&ev_status()).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::PreCharge);
+
+    let body = PreChargeRequest::new(
+        &ev_status(),
+        &PhysicalValue::new(400, 0, PhysicalUnit::Volt),
+        &PhysicalValue::new(2, 0, PhysicalUnit::Ampere),
+    )?
+    .encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::PreCharge);
+    match message {
+        MessageBody::PreChargeRes(msg) => assert!(msg.get_voltage().get_value() == 400),
+        _ => panic!("Unexpected message type"),
+    };
+
+    let body = PowerDeliveryRequest::new(true).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::CurrentDemand);
+
+    let body = CurrentDemandRequest::new(
+        &ev_status(),
+        &PhysicalValue::new(50, 0, PhysicalUnit::Ampere),
+        &PhysicalValue::new(400, 0, PhysicalUnit::Volt),
+        false,
+    )
+    .encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::CurrentDemand);
+
+    let body = PowerDeliveryRequest::new(false).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::WeldingDetection);
+
+    let body = WeldingDetectionRequest::new(&ev_status()).encode();
+    let (state, _) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::WeldingDetection);
+
+    let body = SessionStopRequest::new(0).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::Idle);
+    match message {
+        MessageBody::SessionStopRes(msg) => assert!(msg.get_rcode()? == ResponseCode::Ok),
+        _ => panic!("Unexpected message type"),
+    };
+    assert!(controller.get_session_id()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn din_sequence_error() -> Result<(), AfbError> {
+    let controller = DinController::new(Box::new(MockDinCharger {}));
+    let session_id = open_session(&controller)?;
+
+    // CableCheck before ServiceDiscovery is out of sequence
+    let body = CableCheckRequest::new(&ev_status()).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::Idle);
+    match message {
+        MessageBody::CableCheckRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::SequenceError)
+        }
+        _ => panic!("Unexpected message type"),
+    };
+    Ok(())
+}
+
+#[test]
+fn din_refuse_contract_and_ac() -> Result<(), AfbError> {
+    let controller = DinController::new(Box::new(MockDinCharger {}));
+    let session_id = open_session(&controller)?;
+    send_message(
+        &controller,
+        &session_id,
+        ServiceDiscoveryRequest::new().encode(),
+    )?;
+
+    // DIN is EIM only
+    let body = PaymentSelectionRequest::new(PaymentOption::Contract).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::Idle);
+    match message {
+        MessageBody::PaymentSelectionRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::PaymentSelectionInvalid)
+        }
+        _ => panic!("Unexpected message type"),
+    };
+
+    // DIN is DC only
+    let session_id = open_session(&controller)?;
+    for body in [
+        ServiceDiscoveryRequest::new().encode(),
+        PaymentSelectionRequest::new(PaymentOption::External).encode(),
+        ContractAuthenticationRequest::new().encode(),
+    ] {
+        send_message(&controller, &session_id, body)?;
+    }
+    let body = ParamDiscoveryRequest::new(EvRequestTransfertMode::AcThreePhase).encode();
+    let (state, message) = send_message(&controller, &session_id, body)?;
+    assert!(state == DinState::Idle);
+    match message {
+        MessageBody::ParamDiscoveryRes(msg) => {
+            assert!(msg.get_rcode()? == ResponseCode::WrongEnergyTransferType)
+        }
+        _ => panic!("Unexpected message type"),
+    };
+    Ok(())
+}
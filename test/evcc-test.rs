@@ -0,0 +1,247 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::mock_exi::*;
+use crate::test_controller::MockCharger;
+use iso15118::prelude::iso2_exi::*;
+use iso15118::prelude::*;
+use std::net;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// simulated vehicle stopping after a fixed number of charging loops (also used by replay tests)
+pub struct MockVehicle {
+    mode: EngyTransfertMode,
+    loops: AtomicU32,
+}
+
+impl MockVehicle {
+    pub fn new(mode: EngyTransfertMode) -> Self {
+        MockVehicle {
+            mode,
+            loops: AtomicU32::new(0),
+        }
+    }
+}
+
+impl Iso2VehicleHandler for MockVehicle {
+    fn get_evcc_id(&self) -> &[u8] {
+        &[0x1, 0x2, 0x3, 0x4, 0x5, 0x6]
+    }
+
+    fn get_energy_mode(&self) -> EngyTransfertMode {
+        self.mode
+    }
+
+    fn is_charging_complete(&self) -> bool {
+        self.loops.load(Ordering::Relaxed) >= 3
+    }
+
+    fn get_ac_charge_param(&self) -> Result<AcEvChargeParam, AfbError> {
+        AcEvChargeParam::new(
+            &PhysicalValue::new(20, 3, PhysicalUnit::Wh),
+            &PhysicalValue::new(240, 0, PhysicalUnit::Volt),
+            &PhysicalValue::new(32, 0, PhysicalUnit::Ampere),
+            &PhysicalValue::new(6, 0, PhysicalUnit::Ampere),
+        )
+    }
+
+    fn get_dc_charge_param(&self) -> Result<DcEvChargeParam, AfbError> {
+        DcEvChargeParam::new(
+            &self.get_dc_status(),
+            &PhysicalValue::new(450, 0, PhysicalUnit::Volt),
+            &PhysicalValue::new(100, 0, PhysicalUnit::Ampere),
+        )
+    }
+
+    fn get_dc_target(&self) -> Result<(PhysicalValue, PhysicalValue), AfbError> {
+        Ok((
+            PhysicalValue::new(400, 0, PhysicalUnit::Volt),
+            PhysicalValue::new(50, 0, PhysicalUnit::Ampere),
+        ))
+    }
+
+    fn on_response(&self, body: &MessageBody) -> Result<(), AfbError> {
+        match body {
+            MessageBody::CurrentDemandRes(_) | MessageBody::ChargingStatusRes(_) => {
+                self.loops.fetch_add(1, Ordering::Relaxed);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// pump EVCC requests through SECC controller until session is done
+pub fn run_session(
+    evcc: &EvccController,
+    secc: &IsoController,
+) -> Result<Vec<EvccState>, AfbError> {
+    let mut states = Vec::new();
+    for _ in 0..64 {
+        let stream = ExiStream::new();
+        states.push(evcc.encode_request(&mut stream.lock_stream())?);
+
+        let input = mock_network_input(stream.lock_stream().get_buffer());
+        let mut lock = input.lock_stream();
+        secc.iso_decode_payload(&mut lock)?;
+
+        let output = mock_network_input(lock.get_buffer());
+        if evcc.decode_response(&mut output.lock_stream())? == EvccState::Done {
+            return Ok(states);
+        }
+    }
+    panic!("evcc session did not complete");
+}
+
+#[test]
+fn evcc_sdp_discovery() -> Result<(), AfbError> {
+    let evcc = EvccController::new(Box::new(MockVehicle::new(EngyTransfertMode::AcSinglePhase)));
+    let request = v2g::SdpRequest::decode(&evcc.sdp_request()?)?;
+    request.check_header()?;
+
+    let addr6 = net::Ipv6Addr::new(0xfe80, 1, 2, 3, 4, 5, 6, 7).octets();
+    let response = v2g::SdpResponse::new(
+        addr6,
+        15118,
+        request.get_transport()?,
+        request.get_security()?,
+    )
+    .encode()?;
+    let response = evcc.sdp_response(&response)?;
+    assert!(response.get_port() == 15118);
+    assert!(evcc.get_state()? == EvccState::AppProtocol);
+    Ok(())
+}
+
+#[test]
+fn evcc_ac_session() -> Result<(), AfbError> {
+    let vehicle = MockVehicle::new(EngyTransfertMode::AcSinglePhase);
+    let evcc = EvccController::new(Box::new(vehicle));
+    let secc = IsoController::new(Box::new(MockCharger {}));
+
+    let addr6 = net::Ipv6Addr::LOCALHOST.octets();
+    let sdp = v2g::SdpResponse::new(
+        addr6,
+        15118,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    );
+    evcc.sdp_response(&sdp.encode()?)?;
+
+    let states = run_session(&evcc, &secc)?;
+    assert!(
+        states
+            == [
+                EvccState::AppProtocol,
+                EvccState::SessionSetup,
+                EvccState::ServiceDiscovery,
+                EvccState::PaymentSelection,
+                EvccState::Authorization,
+                EvccState::ChargeParam,
+                EvccState::PowerDeliveryStart,
+                EvccState::Charging,
+                EvccState::Charging,
+                EvccState::Charging,
+                EvccState::PowerDeliveryStop,
+                EvccState::SessionStop,
+            ]
+    );
+    assert!(evcc.get_session_id()? == secc.get_session_id()?);
+    assert!(secc.get_state()? == SeccState::Idle);
+    Ok(())
+}
+
+#[test]
+fn evcc_dc_session() -> Result<(), AfbError> {
+    let vehicle = MockVehicle::new(EngyTransfertMode::DcExtended);
+    let evcc = EvccController::new(Box::new(vehicle));
+    let secc = IsoController::new(Box::new(MockCharger {}));
+
+    let sdp = v2g::SdpResponse::new(
+        net::Ipv6Addr::LOCALHOST.octets(),
+        15118,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    );
+    evcc.sdp_response(&sdp.encode()?)?;
+
+    let states = run_session(&evcc, &secc)?;
+    assert!(
+        states
+            == [
+                EvccState::AppProtocol,
+                EvccState::SessionSetup,
+                EvccState::ServiceDiscovery,
+                EvccState::PaymentSelection,
+                EvccState::Authorization,
+                EvccState::ChargeParam,
+                EvccState::CableCheck,
+                EvccState::PreCharge,
+                EvccState::PowerDeliveryStart,
+                EvccState::Charging,
+                EvccState::Charging,
+                EvccState::Charging,
+                EvccState::PowerDeliveryStop,
+                EvccState::WeldingDetection,
+                EvccState::SessionStop,
+            ]
+    );
+    Ok(())
+}
+
+// single EVCC request/SECC response round trip
+fn exchange(evcc: &EvccController, secc: &IsoController) -> Result<EvccState, AfbError> {
+    let stream = ExiStream::new();
+    evcc.encode_request(&mut stream.lock_stream())?;
+
+    let input = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = input.lock_stream();
+    secc.iso_decode_payload(&mut lock)?;
+
+    let output = mock_network_input(lock.get_buffer());
+    evcc.decode_response(&mut output.lock_stream())
+}
+
+#[test]
+fn evcc_session_id_mismatch() -> Result<(), AfbError> {
+    let vehicle = MockVehicle::new(EngyTransfertMode::AcSinglePhase);
+    let evcc = EvccController::new(Box::new(vehicle));
+    let secc = IsoController::new(Box::new(MockCharger {}));
+
+    let sdp = v2g::SdpResponse::new(
+        net::Ipv6Addr::LOCALHOST.octets(),
+        15118,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    );
+    evcc.sdp_response(&sdp.encode()?)?;
+
+    // run appHand and SessionSetup, then let SECC switch to another session id
+    exchange(&evcc, &secc)?;
+    assert!(exchange(&evcc, &secc)? == EvccState::ServiceDiscovery);
+    secc.lock_handle()?.session_id = vec![0xAA; 8];
+
+    // SECC response carries its new session id, EVCC drops the session
+    assert!(exchange(&evcc, &secc).is_err());
+    assert!(evcc.get_state()? == EvccState::Idle);
+    assert!(evcc
+        .encode_request(&mut ExiStream::new().lock_stream())
+        .is_err());
+    evcc.sdp_request()?;
+    Ok(())
+}
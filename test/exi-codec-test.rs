@@ -0,0 +1,152 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::*;
+use std::thread;
+
+const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+fn iso2_session_setup(evcc_id: &[u8]) -> Result<iso2_exi::ExiMessageDoc, AfbError> {
+    use iso2_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(evcc_id)?.encode();
+    Ok(ExiMessageDoc::new(&header, &body))
+}
+
+#[test]
+fn exi_codec_iso2() -> Result<(), AfbError> {
+    use iso2_exi::*;
+
+    // same frame as test_iso2::session_setup_request
+    let expected = [
+        0x1, 0xfe, 0x80, 0x1, 0x0, 0x0, 0x0, 0x15, 0x80, 0x98, 0x2, 0x0, 0x40, 0x80, 0xc1, 0x1,
+        0x41, 0x81, 0xc2, 0x11, 0xd0, 0x18, 0x4, 0x8, 0xc, 0x10, 0x14, 0x18, 0x0,
+    ];
+    let setup_in = [0x1, 0x2, 0x3, 0x4, 0x5, 0x6];
+    let frame = iso2_session_setup(&setup_in)?.encode()?;
+    assert!(frame == expected);
+
+    let mut buffer = [0u8; 64];
+    let count = iso2_session_setup(&setup_in)?.encode_into(&mut buffer)?;
+    assert!(buffer[0..count] == expected);
+    assert!(iso2_session_setup(&setup_in)?
+        .encode_into(&mut buffer[0..8])
+        .is_err());
+
+    let message = ExiMessageDoc::decode(&frame)?;
+    assert!(message.get_header().get_session_id() == SESSION_ID);
+    match message.get_body()? {
+        MessageBody::SessionSetupReq(msg) => assert!(msg.get_id() == setup_in),
+        _ => panic!("Unexpected message type"),
+    };
+    Ok(())
+}
+
+#[test]
+fn exi_codec_app_protocol() -> Result<(), AfbError> {
+    use v2g::*;
+    let request = SupportedAppProtocolReq::new(V2G_PROTOCOLS_SUPPORTED_LIST[2])?;
+    let frame = V2gMsgBody::Request(request).encode()?;
+
+    match V2gMsgBody::decode(&frame)? {
+        V2gMsgBody::Request(msg) => {
+            assert!(msg.get_protocols()[0].get_name()? == V2G_PROTOCOLS_SUPPORTED_LIST[2].name)
+        }
+        _ => panic!("Unexpected message type"),
+    }
+
+    let response = SupportedAppProtocolRes::new(ResponseCode::Success, 1);
+    let frame = V2gMsgBody::Response(response).encode()?;
+    match V2gMsgBody::decode(&frame)? {
+        V2gMsgBody::Response(msg) => assert!(msg.get_rcode()? == ResponseCode::Success),
+        _ => panic!("Unexpected message type"),
+    }
+    Ok(())
+}
+
+#[test]
+fn exi_codec_din_iso20() -> Result<(), AfbError> {
+    {
+        use din_exi::*;
+        let header = ExiMessageHeader::new(&SESSION_ID)?;
+        let body = SessionStopRequest::new(0).encode();
+        let frame = ExiMessageDoc::new(&header, &body).encode()?;
+        match ExiMessageDoc::decode(&frame)?.get_body()? {
+            MessageBody::SessionStopReq(_) => {}
+            _ => panic!("Unexpected message type"),
+        };
+    }
+
+    use iso20_exi::*;
+    let evcc_id = "WMIV1234567890ABCDEX";
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(evcc_id)?.encode();
+    let frame = ExiMessageDoc::new(&header, &body)?.encode()?;
+    let message = ExiMessageDoc::decode(&frame)?;
+    assert!(message.get_header()?.get_session_id() == SESSION_ID);
+    match message.get_body()? {
+        MessageBody::SessionSetupReq(msg) => assert!(msg.get_id()? == evcc_id),
+        _ => panic!("Unexpected message type"),
+    };
+
+    // iso-20 frame uses MAIN payload type and cannot be decoded as iso-2
+    assert!(iso2_exi::ExiMessageDoc::decode(&frame).is_err());
+    Ok(())
+}
+
+#[test]
+fn exi_codec_reject_frames() -> Result<(), AfbError> {
+    let frame = iso2_session_setup(&[0x1, 0x2])?.encode()?;
+
+    // partial, trailing & corrupted frames
+    assert!(iso2_exi::ExiMessageDoc::decode(&frame[0..frame.len() - 1]).is_err());
+    assert!(iso2_exi::ExiMessageDoc::decode(&frame[0..4]).is_err());
+    let mut data = frame.clone();
+    data.push(0);
+    assert!(iso2_exi::ExiMessageDoc::decode(&data).is_err());
+    let mut data = frame.clone();
+    data[1] = 0;
+    assert!(iso2_exi::ExiMessageDoc::decode(&data).is_err());
+    Ok(())
+}
+
+#[test]
+fn exi_codec_parallel() {
+    // no shared stream, every thread encode/decode independently
+    let workers: Vec<_> = (0..8u8)
+        .map(|idx| {
+            thread::spawn(move || -> Result<(), AfbError> {
+                for count in 0..50u8 {
+                    let evcc_id = [idx, count, 0x3, 0x4, 0x5, 0x6];
+                    let frame = iso2_session_setup(&evcc_id)?.encode()?;
+                    match iso2_exi::ExiMessageDoc::decode(&frame)?.get_body()? {
+                        iso2_exi::MessageBody::SessionSetupReq(msg) => {
+                            assert!(msg.get_id() == evcc_id)
+                        }
+                        _ => panic!("Unexpected message type"),
+                    }
+                }
+                Ok(())
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap().unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::*;
+
+// valid V2GTP header with a corrupted EXI payload
+fn corrupted_stream(payload_id: v2g::PayloadMsgId) -> Result<RawStream, AfbError> {
+    let payload = [0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    let mut frame = v2gtp_make_header(payload_id, payload.len() as u32).to_vec();
+    frame.extend_from_slice(&payload);
+
+    let mut stream = RawStream::new();
+    match stream.push_chunk(&frame)? {
+        (_, V2gtpChunk::Complete(_)) => Ok(stream),
+        _ => panic!("expect complete frame"),
+    }
+}
+
+#[test]
+fn exi_error_iso2_decode() -> Result<(), AfbError> {
+    let mut stream = corrupted_stream(v2g::PayloadMsgId::SAP)?;
+    let error = match iso2_exi::ExiMessageDoc::try_decode_from_stream(&mut stream) {
+        Ok(_) => panic!("corrupted payload should not decode"),
+        Err(error) => error,
+    };
+
+    match &error {
+        Iso15118Error::Exi {
+            protocol,
+            operation,
+            status,
+            code,
+            byte_pos,
+            ..
+        } => {
+            assert!(*protocol == ExiProtocol::Iso2);
+            assert!(*operation == ExiOperation::Decode);
+            assert!(*status < 0);
+            assert!(*code == ExiErrorCode::from_status(*status));
+            assert!(*byte_pos >= V2GTP_HEADER_LEN);
+        }
+        _ => panic!("expect exi error"),
+    }
+    assert!(error.get_pki_status().is_none());
+
+    // legacy API still returns AfbError
+    let mut stream = corrupted_stream(v2g::PayloadMsgId::SAP)?;
+    assert!(iso2_exi::ExiMessageDoc::decode_from_stream(&mut stream).is_err());
+    let afb_error: AfbError = error.into();
+    assert!(afb_error.to_string().contains("Iso2"));
+    Ok(())
+}
+
+#[test]
+fn exi_error_app_protocol_decode() -> Result<(), AfbError> {
+    let mut stream = corrupted_stream(v2g::PayloadMsgId::SAP)?;
+    match v2g::SupportedAppProtocolExi::try_decode_from_stream(&mut stream) {
+        Err(error) => assert!(error.get_protocol() == ExiProtocol::AppHand),
+        Ok(_) => panic!("corrupted payload should not decode"),
+    }
+    Ok(())
+}
+
+#[test]
+fn exi_error_status_lookup() {
+    assert!(ExiErrorCode::from_status(0) == Some(ExiErrorCode::NO_ERROR));
+    assert!(ExiErrorCode::from_status(i32::MIN).is_none());
+
+    let error = Iso15118Error::pki(
+        ExiProtocol::Iso2,
+        PkiErrorStatus::BAD_SIGNATURE as u32,
+        "authorization_req",
+    );
+    assert!(error.get_pki_status() == Some(PkiErrorStatus::BAD_SIGNATURE));
+    assert!(error.get_tag() == Some("authorization_req"));
+    assert!(error.get_exi_code().is_none());
+}
+
+#[test]
+#[cfg(not(feature = "afbv4"))]
+fn exi_error_native() {
+    // standalone build, AfbError is the crate native ExiError
+    let error: Result<(), AfbError> = afb_error!("exi-native", "invalid len:{}", 12);
+    let error: ExiError = error.unwrap_err();
+    assert!(error.get_uid() == "exi-native");
+    assert!(error.get_info() == "invalid len:12");
+    assert!(error.get_dbg().file.ends_with("exi-error-test.rs"));
+
+    assert!(v2g::ProtocolTagId::Iso2.to_urn().unwrap() == "urn:iso:15118:2:2013:MsgDef");
+    assert!(v2g::ProtocolTagId::Unknown.to_urn().is_err());
+}
+
+#[test]
+fn exi_error_enum_try_from() {
+    // known values round trip through their C representation
+    let rcode = iso2_exi::ResponseCode::try_from(iso2_exi::ResponseCode::Failed as u32);
+    assert!(rcode == Ok(iso2_exi::ResponseCode::Failed));
+    let payload_id = v2g::PayloadMsgId::try_from(v2g::PayloadMsgId::DC_MAIN as u16);
+    assert!(payload_id == Ok(v2g::PayloadMsgId::DC_MAIN));
+
+    // unknown values are rejected instead of being transmuted
+    match din_exi::ResponseCode::try_from(0xFFFFu32) {
+        Err(error) => assert!(error == ExiEnumError::new("ResponseCode", 0xFFFF)),
+        Ok(_) => panic!("unknown response code should fail"),
+    }
+    assert!(v2g::PayloadMsgId::try_from(0x1234u16).is_err());
+    assert!(PkiErrorStatus::try_from(u32::MAX).is_err());
+    assert!(iso2_exi::PhysicalUnit::from_u32(0xFF).is_err());
+    assert!(iso20_exi::ResponseCode::from_u32(0xFF).is_err());
+    assert!(iso20_exi::ServiceId::from_u16(0).is_err());
+    assert!(iso20_dc_exi::MessageTagId::try_from(0xFFu32).is_err());
+    assert!(iso20_exi::EvseNotification::from_u32(0xFF).is_err());
+
+    // unknown SDP security byte, either rejected by C decoder or by getter
+    let data_in: v2g::SdpRequestBuffer =
+        [0x01, 0xfe, 0x90, 0x00, 0x00, 0x00, 0x00, 0x02, 0x55, 0x00];
+    if let Ok(request) = v2g::SdpRequest::decode(&data_in) {
+        assert!(request.get_security().is_err());
+    }
+}
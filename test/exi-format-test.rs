@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --package iso15118 --test test-v2g test_exi_format
+use iso15118::prelude::*;
+
+#[test]
+fn format_scaled_value() {
+    assert!(scaled_to_string(4000, -1) == "400");
+    assert!(scaled_to_string(4005, -1) == "400.5");
+    assert!(scaled_to_string(-25, -2) == "-0.25");
+    assert!(scaled_to_string(4, 3) == "4000");
+    assert!(scaled_to_string(0, -3) == "0");
+
+    let voltage = iso2_exi::PhysicalValue::new(4000, -1, iso2_exi::PhysicalUnit::Volt);
+    assert!(voltage.to_string() == "400 V");
+    let energy = din_exi::PhysicalValue::new(125, 2, din_exi::PhysicalUnit::Wh);
+    assert!(energy.to_string() == "12500 Wh");
+}
+
+#[test]
+fn format_iso2_message() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 42);
+    let voltage = PhysicalValue::new(4005, -1, PhysicalUnit::Volt);
+    let current = PhysicalValue::new(10, 0, PhysicalUnit::Ampere);
+    let request = PreChargeRequest::new(&status, &voltage, &current)?;
+
+    let display = request.to_string();
+    println!("{}", display);
+    assert!(!display.contains('\n'));
+    assert!(display.contains("EVTargetVoltage:400.5 V"));
+    assert!(display.contains("EVTargetCurrent:10 A"));
+    assert!(display.contains("EVRESSSOC:42"));
+
+    let debug = format!("{:?}", request);
+    assert!(debug.starts_with("PreChargeRequest {"));
+    assert!(debug.contains("DC_EVStatus: DcEvStatusType {"));
+
+    let body = MessageBody::decode(&request.encode())?;
+    assert!(body.to_string().starts_with("PreChargeReq {"));
+    Ok(())
+}
+
+#[test]
+fn format_din_message() -> Result<(), AfbError> {
+    use din_exi::*;
+    let request = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?;
+    assert!(request.to_string() == "{EVCCID:010203040506}");
+    let body = MessageBody::decode(&request.encode())?;
+    assert!(body.to_string() == "SessionSetupReq {EVCCID:010203040506}");
+    Ok(())
+}
+
+#[test]
+fn format_iso20_message() -> Result<(), AfbError> {
+    use iso20_dc_exi::*;
+    let voltage = RationalNumber::new(4005, -1);
+    assert!(voltage.to_string() == "400.5");
+
+    let mode = ScheduledDcClReqMode::new(&RationalNumber::new(10, 0), &voltage);
+    let request = DcChargeLoopRequest::new(&voltage, &DcClReqControlMode::Scheduled(mode));
+    let display = request.to_string();
+    println!("{}", display);
+    assert!(!display.contains('\n'));
+    assert!(display.contains("EVPresentVoltage:400.5"));
+    assert!(display.contains("Scheduled_DC_CLReqControlMode:{EVTargetCurrent:10"));
+    assert!(!display.contains("EVMaximumChargePower"));
+
+    let debug = format!("{:?}", request);
+    assert!(debug.starts_with("DcChargeLoopRequest {"));
+    assert!(debug.contains("Scheduled_DC_CLReqControlMode: ScheduledDcClReqMode {"));
+
+    let header = ExiMessageHeader::new(&[0x1, 0x2, 0x3, 0x4])?;
+    let document = ExiMessageDoc::new(&header, &request.encode())?;
+    let display = document.to_string();
+    assert!(display.starts_with("{Header:{SessionID:01020304 TimeStamp:"));
+    assert!(display.contains("Body:DC_ChargeLoopReq {EVPresentVoltage:400.5"));
+    Ok(())
+}
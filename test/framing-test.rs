@@ -0,0 +1,97 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::v2g::*;
+use iso15118::prelude::*;
+
+// encode a SupportedAppProtocolReq as a full V2GTP frame
+fn app_protocol_frame() -> Result<Vec<u8>, AfbError> {
+    let stream = ExiStream::new();
+    let mut lock = stream.lock_stream();
+    let request = SupportedAppProtocolReq::new(V2G_PROTOCOLS_SUPPORTED_LIST[1])?;
+    SupportedAppProtocolExi::encode_to_stream(&mut lock, &request.encode())?;
+    Ok(lock.get_buffer().to_vec())
+}
+
+#[test]
+fn v2gtp_push_chunks() -> Result<(), AfbError> {
+    let frame = app_protocol_frame()?;
+
+    // two frames split in small TCP chunks
+    let mut data = frame.clone();
+    data.extend_from_slice(&frame);
+
+    let stream = ExiStream::new();
+    let mut lock = stream.lock_stream();
+    let mut frames = 0;
+    for chunk in data.chunks(3) {
+        let mut offset = 0;
+        while offset < chunk.len() {
+            let (count, status) = stream.push_chunk(&mut lock, &chunk[offset..])?;
+            offset += count;
+            if let V2gtpChunk::Complete(payload_id) = status {
+                assert!(payload_id == PayloadMsgId::SAP);
+                assert!(lock.get_buffer() == frame.as_slice());
+                match SupportedAppProtocolExi::decode_from_stream(&mut lock)? {
+                    V2gMsgBody::Request(msg) => {
+                        assert!(
+                            msg.get_protocols()[0].get_name()?
+                                == V2G_PROTOCOLS_SUPPORTED_LIST[1].name
+                        )
+                    }
+                    _ => panic!("Unexpected message type"),
+                }
+                frames += 1;
+            }
+        }
+    }
+    assert!(frames == 2);
+    Ok(())
+}
+
+#[test]
+fn v2gtp_reject_oversize() -> Result<(), AfbError> {
+    let header = v2gtp_make_header(PayloadMsgId::MAIN, EXI_MAX_DOCUMENT_SIZE as u32);
+    let stream = ExiStream::new();
+    let mut lock = stream.lock_stream();
+    assert!(stream.push_chunk(&mut lock, &header).is_err());
+
+    // invalid version
+    assert!(v2gtp_parse_header(&[0x02, 0xFD, 0x80, 0x01, 0, 0, 0, 1], 64).is_err());
+    // stream is usable after an error
+    let frame = app_protocol_frame()?;
+    match stream.push_chunk(&mut lock, &frame)? {
+        (count, V2gtpChunk::Complete(_)) => assert!(count == frame.len()),
+        _ => panic!("expect complete frame"),
+    }
+    Ok(())
+}
+
+#[test]
+fn v2gtp_owned_frame() -> Result<(), AfbError> {
+    let frame = V2gtpFrame::new(PayloadMsgId::MAIN, &[0x80, 0x98, 0x02]);
+    let mut buffer = frame.encode();
+    buffer.extend_from_slice(&frame.encode()[0..4]);
+
+    let decoded = V2gtpFrame::decode(&mut buffer, 64)?.expect("complete frame");
+    assert!(decoded.payload_id == PayloadMsgId::MAIN);
+    assert!(decoded.payload == [0x80, 0x98, 0x02]);
+    assert!(buffer.len() == 4);
+    assert!(V2gtpFrame::decode(&mut buffer, 64)?.is_none());
+    Ok(())
+}
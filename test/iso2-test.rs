@@ -109,7 +109,7 @@ fn session_setup_response() -> Result<(), AfbError> {
 
     // Decoding API
     let evse_out = payload.get_id()?;
-    let code_out = payload.get_rcode();
+    let code_out = payload.get_rcode()?;
     let _time_stamp = payload.get_time_stamp();
 
     assert!(evse_id == evse_out);
@@ -149,7 +149,7 @@ fn service_discovery_request() -> Result<(), AfbError> {
 
     // Decoding API
     let scope_out = payload.get_scope().unwrap();
-    let category_out = payload.get_category().unwrap();
+    let category_out = payload.get_category()?.unwrap();
 
     // assert input==output
     assert!(scope_in == scope_out);
@@ -207,10 +207,10 @@ fn service_discovery_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    let rcode_out = payload.get_rcode();
+    let rcode_out = payload.get_rcode()?;
     let charging_out = payload.get_charging().unwrap();
     let transfers_out = payload.get_transfers()?;
-    let payments_out = payload.get_payments();
+    let payments_out = payload.get_payments()?;
     let services_out = payload.get_services()?;
 
     // assert input==output
@@ -226,12 +226,12 @@ fn service_discovery_response() -> Result<(), AfbError> {
     assert!(services_out[0].get_name() == service_in0.get_name());
     assert!(services_out[0].get_scope() == service_in0.get_scope());
     assert!(services_out[0].get_isfree() == service_in0.get_isfree());
-    assert!(services_out[0].get_category() == service_in0.get_category());
+    assert!(services_out[0].get_category()? == service_in0.get_category()?);
     assert!(services_out[1].get_id() == service_in1.get_id());
     assert!(services_out[1].get_name() == service_in1.get_name());
     assert!(services_out[1].get_scope() == service_in1.get_scope());
     assert!(services_out[1].get_isfree() == service_in1.get_isfree());
-    assert!(services_out[1].get_category() == service_in1.get_category());
+    assert!(services_out[1].get_category()? == service_in1.get_category()?);
 
     Ok(())
 }
@@ -330,7 +330,7 @@ fn service_detail_response() -> Result<(), AfbError> {
 
     // Decoding API
     let id_out = payload.get_id();
-    let rcode_out = payload.get_rcode();
+    let rcode_out = payload.get_rcode()?;
     let psets_out = payload.get_psets();
 
     // assert input == output
@@ -362,7 +362,7 @@ fn service_detail_response() -> Result<(), AfbError> {
                 },
                 ParamValue::PhyValue(rec) => match value_in {
                     ParamValue::PhyValue(tst) => {
-                        assert!(rec.get_unit() == tst.get_unit());
+                        assert!(rec.get_unit()? == tst.get_unit()?);
                         assert!(rec.get_multiplier() == tst.get_multiplier());
                         assert!(rec.get_value() == tst.get_value());
                     }
@@ -444,8 +444,8 @@ fn authorization_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    let rcode_out = payload.get_rcode();
-    let processing_out = payload.get_processing();
+    let rcode_out = payload.get_rcode()?;
+    let processing_out = payload.get_processing()?;
 
     // assert input == output
     assert!(rcode_out == rcode);
@@ -485,7 +485,7 @@ fn cable_check_request() -> Result<(), AfbError> {
 
     // assert input == output
     assert!(status_out.get_ready() == ready_in);
-    assert!(status_out.get_error() == dc_rcode);
+    assert!(status_out.get_error()? == dc_rcode);
     assert!(status_out.get_evresssoc() == evresssoc_in);
 
     Ok(())
@@ -522,16 +522,16 @@ fn cable_check_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    let code_out = payload.get_rcode();
+    let code_out = payload.get_rcode()?;
     let status_out = payload.get_status();
-    let processing_out = payload.get_processing();
+    let processing_out = payload.get_processing()?;
 
     // assert input == output
     assert!(code_out == rcode);
     assert!(processing_out == processing_in);
-    assert!(status_out.get_notification() == notification_in);
+    assert!(status_out.get_notification()? == notification_in);
     assert!(status_out.get_delay() == delay_in);
-    assert!(status_out.get_error() == dc_rcode);
+    assert!(status_out.get_error()? == dc_rcode);
 
     Ok(())
 }
@@ -673,7 +673,7 @@ fn certificate_install_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    let rcode_out = payload.get_rcode();
+    let rcode_out = payload.get_rcode()?;
     let cert_chain_out = payload.get_provisioning_chain();
     let contract_chain_out = payload.get_contract_chain();
     let private_key_out = payload.get_private_key();
@@ -844,7 +844,7 @@ fn certificate_update_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    let rcode_out = payload.get_rcode();
+    let rcode_out = payload.get_rcode()?;
     let cert_chain_out = payload.get_provisioning_chain();
     let contract_chain_out = payload.get_contract_chain();
     let private_key_out = payload.get_private_key();
@@ -910,7 +910,7 @@ fn current_demand_request() -> Result<(), AfbError> {
     // Decoding API
     assert!(dc_complete == payload.get_charging_complete());
     let current = payload.get_current_target();
-    assert!(current.get_unit() == PhysicalUnit::Ampere);
+    assert!(current.get_unit()? == PhysicalUnit::Ampere);
     assert!(current.get_value() == 80);
     assert!(current.get_multiplier() == 1);
     let voltage = payload.get_voltage_target();
@@ -972,16 +972,16 @@ fn current_demand_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
     assert!(payload.get_evse_id().unwrap() == evse_id);
     assert!(payload.get_current_limit_reach() == current_limit);
     assert!(payload.get_voltage_limit_reach() == voltage_limit);
     assert!(payload.get_power_limit_reach() == power_limit);
     assert!(payload.get_tuple_id() == schd_tuple_id);
     let status = payload.get_status();
-    assert!(status.get_isolation_status().unwrap() == isolation);
-    assert!(status.get_error() == dc_rcode);
-    assert!(status.get_notification() == notif);
+    assert!(status.get_isolation_status()?.unwrap() == isolation);
+    assert!(status.get_error()? == dc_rcode);
+    assert!(status.get_notification()? == notif);
     assert!(status.get_delay() == delay);
 
     Ok(())
@@ -1047,11 +1047,11 @@ fn charging_status_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
     assert!(payload.get_evse_id().unwrap() == evse_id);
     assert!(payload.get_tuple_id() == tuple_id);
     let status = payload.get_ac_evse_status();
-    assert!(status.get_notification() == notif);
+    assert!(status.get_notification()? == notif);
     assert!(status.get_delay() == delay);
     assert!(status.get_rcd() == rcd);
 
@@ -1136,7 +1136,7 @@ fn metering_outeipt_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
 
     Ok(())
 }
@@ -1175,7 +1175,7 @@ fn ac_param_discovery_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_transfert_energy_mode() == EngyTransfertMode::AcSinglePhase);
+    assert!(payload.get_transfert_energy_mode()? == EngyTransfertMode::AcSinglePhase);
     assert!(payload.get_max_schedule_tuple().unwrap() == 16);
     let ac_value = payload.get_ac_charge_param().unwrap();
     assert!(ac_value.get_departure_time().unwrap() == 1234);
@@ -1220,7 +1220,7 @@ fn ac_param_discovery_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_transfert_energy_mode() == EngyTransfertMode::AcSinglePhase);
+    assert!(payload.get_transfert_energy_mode()? == EngyTransfertMode::AcSinglePhase);
     assert!(payload.get_max_schedule_tuple().unwrap() == 16);
     let ac_value = payload.get_ac_charge_param().unwrap();
     assert!(ac_value.get_departure_time().unwrap() == 1234);
@@ -1265,12 +1265,12 @@ fn dc_param_discovery_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_transfert_energy_mode() == EngyTransfertMode::DcExtended);
+    assert!(payload.get_transfert_energy_mode()? == EngyTransfertMode::DcExtended);
     assert!(payload.get_max_schedule_tuple().unwrap() == max_tuple);
     let dc_value = payload.get_dc_charge_param().unwrap();
     assert!(dc_value.get_max_current().get_value() == 100);
     assert!(dc_value.get_max_voltage().get_value() == 800);
-    assert!(dc_value.get_status().get_error() == DcEvErrorCode::NoError);
+    assert!(dc_value.get_status().get_error()? == DcEvErrorCode::NoError);
     assert!(dc_value.get_status().get_ready() == false);
     Ok(())
 }
@@ -1334,8 +1334,8 @@ fn dc_param_discovery_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
-    assert!(payload.get_processing() == processing);
+    assert!(payload.get_rcode()? == rcode);
+    assert!(payload.get_processing()? == processing);
 
     let dc_params = payload.get_evse_dc_charge_param().unwrap();
     assert!(dc_params.get_max_voltage().get_value() == 5200);
@@ -1343,8 +1343,8 @@ fn dc_param_discovery_response() -> Result<(), AfbError> {
     assert!(dc_params.get_max_current().get_value() == 3000);
     assert!(dc_params.get_min_current().get_value() == 0);
     assert!(dc_params.get_max_power().get_value() == 11000);
-    assert!(dc_params.get_status().get_error() == DcEvseErrorCode::Ready);
-    assert!(dc_params.get_status().get_notification() == EvseNotification::None);
+    assert!(dc_params.get_status().get_error()? == DcEvseErrorCode::Ready);
+    assert!(dc_params.get_status().get_notification()? == EvseNotification::None);
 
     let dc_sched = payload.get_schedule_tuples();
     let dc_pmax = dc_sched[0].get_pmaxs();
@@ -1403,7 +1403,7 @@ fn ev_param_discovery_request() -> Result<(), AfbError> {
     let dc_value = params.get_dc_param();
     assert!(dc_value.get_max_current().get_value() == 100);
     assert!(dc_value.get_max_voltage().get_value() == 800);
-    assert!(dc_value.get_status().get_error() == DcEvErrorCode::NoError);
+    assert!(dc_value.get_status().get_error()? == DcEvErrorCode::NoError);
     assert!(dc_value.get_status().get_ready() == true);
     // check ac params
     let ac_value = params.get_ac_param();
@@ -1480,11 +1480,11 @@ fn ev_param_discovery_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
-    assert!(payload.get_processing() == processing);
+    assert!(payload.get_rcode()? == rcode);
+    assert!(payload.get_processing()? == processing);
     let charge_prm = payload.get_evse_dc_charge_param().unwrap();
-    assert!(charge_prm.get_status().get_error() == dc_rcode);
-    assert!(charge_prm.get_status().get_notification() == dc_notification);
+    assert!(charge_prm.get_status().get_error()? == dc_rcode);
+    assert!(charge_prm.get_status().get_notification()? == dc_notification);
     assert!(charge_prm.get_status().get_delay() == dc_delay);
     assert!(charge_param.get_max_voltage().get_value() == 250);
     assert!(charge_param.get_min_voltage().get_value() == 200);
@@ -1576,7 +1576,7 @@ fn payment_detail_response() -> Result<(), AfbError> {
 
     // Decoding API
     assert!(payload.get_challenge() == challenge);
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
 
     Ok(())
 }
@@ -1614,7 +1614,7 @@ fn payment_selection_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_option() == service_contract);
+    assert!(payload.get_option()? == service_contract);
     let services = payload.get_services();
     assert!(services[0].get_service_id() == service_option_0.get_service_id());
     assert!(services[0].get_param_id() == service_option_0.get_param_id());
@@ -1648,7 +1648,7 @@ fn payment_selection_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
 
     Ok(())
 }
@@ -1691,11 +1691,13 @@ fn power_delivery_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_progress() == charge_progress);
+    assert!(payload.get_progress()? == charge_progress);
     assert!(payload.get_schedule_id() == schedule_id);
     let profiles = payload.get_charging_profiles();
     assert!(profiles[0].get_start() == charge_profile_0.get_start());
-    assert!(profiles[0].get_power_max().get_unit() == charge_profile_0.get_power_max().get_unit());
+    assert!(
+        profiles[0].get_power_max().get_unit()? == charge_profile_0.get_power_max().get_unit()?
+    );
     assert!(
         profiles[0].get_power_max().get_value() == charge_profile_0.get_power_max().get_value()
     );
@@ -1704,7 +1706,7 @@ fn power_delivery_request() -> Result<(), AfbError> {
             == charge_profile_0.get_power_max().get_multiplier()
     );
     let delivery_prm = payload.get_dc_delivery_params().unwrap();
-    assert!(delivery_prm.get_status().get_error() == DcEvErrorCode::FailVoltOutOfRange);
+    assert!(delivery_prm.get_status().get_error()? == DcEvErrorCode::FailVoltOutOfRange);
     assert!(delivery_prm.get_status().get_ready() == dc_status.get_ready());
     assert!(delivery_prm.get_charge_complete() == true);
 
@@ -1743,9 +1745,9 @@ fn power_ac_delivery_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
     let status = payload.get_ac_evse_status().unwrap();
-    assert!(status.get_notification() == ac_status.get_notification());
+    assert!(status.get_notification()? == ac_status.get_notification()?);
     assert!(status.get_delay() == ac_status.get_delay());
     assert!(status.get_rcd() == ac_status.get_rcd());
 
@@ -1779,13 +1781,13 @@ fn pre_charge_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_status().get_error() == DcEvErrorCode::NoError);
+    assert!(payload.get_status().get_error()? == DcEvErrorCode::NoError);
     assert!(payload.get_status().get_ready() == true);
     assert!(payload.get_status().get_evresssoc() == 1);
-    assert!(payload.get_target_voltage().get_unit() == target_voltage.get_unit());
+    assert!(payload.get_target_voltage().get_unit()? == target_voltage.get_unit()?);
     assert!(payload.get_target_voltage().get_value() == target_voltage.get_value());
     assert!(payload.get_target_voltage().get_multiplier() == target_voltage.get_multiplier());
-    assert!(payload.get_target_current().get_unit() == target_current.get_unit());
+    assert!(payload.get_target_current().get_unit()? == target_current.get_unit()?);
     assert!(payload.get_target_current().get_value() == target_current.get_value());
     assert!(payload.get_target_current().get_multiplier() == target_current.get_multiplier());
 
@@ -1823,10 +1825,10 @@ fn pre_charge_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
-    assert!(payload.get_status().get_error() == dc_status);
-    assert!(payload.get_status().get_isolation_status().unwrap() == IsolationStatus::Warning);
-    assert!(payload.get_voltage().get_unit() == evse_voltage.get_unit());
+    assert!(payload.get_rcode()? == rcode);
+    assert!(payload.get_status().get_error()? == dc_status);
+    assert!(payload.get_status().get_isolation_status()?.unwrap() == IsolationStatus::Warning);
+    assert!(payload.get_voltage().get_unit()? == evse_voltage.get_unit()?);
     assert!(payload.get_voltage().get_value() == evse_voltage.get_value());
     assert!(payload.get_voltage().get_multiplier() == evse_voltage.get_multiplier());
 
@@ -1857,7 +1859,7 @@ fn session_stop_request() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_action() == action);
+    assert!(payload.get_action()? == action);
 
     Ok(())
 }
@@ -1886,7 +1888,7 @@ fn session_stop_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
 
     Ok(())
 }
@@ -1921,7 +1923,7 @@ fn welding_detection_request() -> Result<(), AfbError> {
     // Decoding API
     let status_out = payload.get_status();
     assert!(status_out.get_ready() == ready_in);
-    assert!(status_out.get_error() == dc_rcode);
+    assert!(status_out.get_error()? == dc_rcode);
     assert!(status_out.get_evresssoc() == evresssoc_in);
 
     Ok(())
@@ -1958,13 +1960,13 @@ fn welding_detection_response() -> Result<(), AfbError> {
     };
 
     // Decoding API
-    assert!(payload.get_rcode() == rcode);
+    assert!(payload.get_rcode()? == rcode);
     let voltage = payload.get_voltage();
     assert!(voltage.get_value() == 400);
-    assert!(voltage.get_unit() == PhysicalUnit::Volt);
+    assert!(voltage.get_unit()? == PhysicalUnit::Volt);
     let status = payload.get_status();
-    assert!(status.get_error() == dc_rcode);
+    assert!(status.get_error()? == dc_rcode);
     assert!(status.get_delay() == dc_delay);
-    assert!(status.get_notification() == dc_notification);
+    assert!(status.get_notification()? == dc_notification);
     Ok(())
 }
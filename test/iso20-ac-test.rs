@@ -0,0 +1,230 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::mock_exi::*;
+use iso15118::prelude::iso20_ac_exi::*;
+use iso15118::prelude::*;
+
+// iso-20 has no reference binary yet, tests only check encode/decode round trip
+pub fn encode_to_stream(funcname: &str, body: Iso20AcBodyType) -> Result<ExiStream, AfbError> {
+    const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+    // mock network stream and encode message
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let mut header = ExiMessageHeader::new(&SESSION_ID)?;
+        header.set_timestamp(0);
+        ExiMessageDoc::new(&header, &body)?.encode_to_stream(&mut lock)?;
+        let doc_size = stream
+            .header_check(&lock, v2g::PayloadMsgId::AC_MAIN)
+            .expect("expect valid V2G header");
+        println!(
+            "{}-> ({}) [{}]",
+            funcname,
+            doc_size,
+            dump_buffer(lock.get_buffer())
+        );
+    }
+
+    Ok(stream)
+}
+
+pub fn decode_from_stream(_funcname: &str, stream: ExiStream) -> Result<ExiMessageDoc, AfbError> {
+    let stream_decode = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = stream_decode.lock_stream();
+    let message = ExiMessageDoc::decode_from_stream(&mut lock)?;
+    Ok(message)
+}
+
+#[test]
+fn ac_param_discovery_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut transfer = AcCpdResEnergyTransfer::new(
+        &RationalNumber::new(22, 3),
+        &RationalNumber::new(1, 3),
+        &RationalNumber::new(50, 0),
+    );
+    transfer
+        .set_max_charge_power_l2(&RationalNumber::new(7, 3))
+        .set_max_charge_power_l3(&RationalNumber::new(7, 3));
+    let payload = AcChargeParamDiscoveryResponse::new(ResponseCode::Ok, &transfer).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AcChargeParamDiscoveryRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let transfer = payload
+        .get_energy_transfer()
+        .expect("expect ac transfer mode");
+    assert!(payload.get_rcode()? == ResponseCode::Ok);
+    assert!(transfer.get_max_charge_power().get_value() == 22);
+    assert!(transfer.get_nominal_frequency().get_value() == 50);
+    assert!(
+        transfer
+            .get_max_charge_power_l2()
+            .expect("expect L2")
+            .get_value()
+            == 7
+    );
+    assert!(transfer.get_min_charge_power_l2().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn ac_charge_loop_scheduled_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = ScheduledAcClReqMode::new(&RationalNumber::new(11, 3));
+    mode.set_max_charge_power(&RationalNumber::new(22, 3));
+    let payload = AcChargeLoopRequest::new(&AcClReqControlMode::Scheduled(mode)).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AcChargeLoopReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(!payload.get_meter_info_requested());
+    match payload.get_control_mode()? {
+        AcClReqControlMode::Scheduled(mode) => {
+            assert!(mode.get_present_active_power().get_value() == 11);
+            assert!(
+                mode.get_max_charge_power()
+                    .expect("expect max power")
+                    .get_value()
+                    == 22
+            );
+            assert!(mode.get_target_energy().is_none());
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn ac_charge_loop_dynamic_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = DynamicAcClResMode::new(&RationalNumber::new(11, 3));
+    mode.set_target_soc(90).set_ack_max_delay(30);
+    let payload = AcChargeLoopResponse::new(ResponseCode::Ok, &AcClResControlMode::Dynamic(mode))
+        .set_target_frequency(&RationalNumber::new(50, 0))
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AcChargeLoopRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(
+        payload
+            .get_target_frequency()
+            .expect("expect frequency")
+            .get_value()
+            == 50
+    );
+    assert!(payload.get_evse_status().is_none());
+    match payload.get_control_mode()? {
+        AcClResControlMode::Dynamic(mode) => {
+            assert!(mode.get_target_active_power().get_value() == 11);
+            assert!(mode.get_target_soc() == Some(90));
+            assert!(mode.get_ack_max_delay() == Some(30));
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn ac_bpt_param_discovery_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut transfer =
+        BptAcCpdReqEnergyTransfer::new(&RationalNumber::new(11, 3), &RationalNumber::new(1, 3));
+    transfer
+        .set_max_discharge_power(&RationalNumber::new(7, 3))
+        .set_min_discharge_power(&RationalNumber::new(1, 3))
+        .set_max_discharge_power_l2(&RationalNumber::new(3, 3));
+    let payload = AcChargeParamDiscoveryRequest::new_bpt(&transfer).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AcChargeParamDiscoveryReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_energy_transfer().is_none());
+    let transfer = payload
+        .get_bpt_energy_transfer()
+        .expect("expect bpt transfer mode");
+    assert!(transfer.get_max_charge_power().get_value() == 11);
+    assert!(transfer.get_max_discharge_power().get_value() == 7);
+    assert!(
+        transfer
+            .get_max_discharge_power_l2()
+            .expect("expect L2")
+            .get_value()
+            == 3
+    );
+    assert!(transfer.get_max_discharge_power_l3().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn ac_bpt_charge_loop_dynamic_response() -> Result<(), AfbError> {
+    // Encoding API (negative target power requests discharge)
+    let mut mode = BptDynamicAcClResMode::new(&RationalNumber::new(-7, 3));
+    mode.set_departure_time(3600);
+    let payload =
+        AcChargeLoopResponse::new(ResponseCode::Ok, &AcClResControlMode::BptDynamic(mode)).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AcChargeLoopRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    match payload.get_control_mode()? {
+        AcClResControlMode::BptDynamic(mode) => {
+            assert!(mode.get_target_active_power().get_value() == -7);
+            assert!(mode.get_departure_time() == Some(3600));
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
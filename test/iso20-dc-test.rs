@@ -0,0 +1,362 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::mock_exi::*;
+use iso15118::prelude::iso20_dc_exi::*;
+use iso15118::prelude::*;
+
+// iso-20 has no reference binary yet, tests only check encode/decode round trip
+pub fn encode_to_stream(funcname: &str, body: Iso20DcBodyType) -> Result<ExiStream, AfbError> {
+    const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+    // mock network stream and encode message
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let mut header = ExiMessageHeader::new(&SESSION_ID)?;
+        header.set_timestamp(0);
+        ExiMessageDoc::new(&header, &body)?.encode_to_stream(&mut lock)?;
+        let doc_size = stream
+            .header_check(&lock, v2g::PayloadMsgId::DC_MAIN)
+            .expect("expect valid V2G header");
+        println!(
+            "{}-> ({}) [{}]",
+            funcname,
+            doc_size,
+            dump_buffer(lock.get_buffer())
+        );
+    }
+
+    Ok(stream)
+}
+
+pub fn decode_from_stream(_funcname: &str, stream: ExiStream) -> Result<ExiMessageDoc, AfbError> {
+    let stream_decode = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = stream_decode.lock_stream();
+    let message = ExiMessageDoc::decode_from_stream(&mut lock)?;
+    Ok(message)
+}
+
+#[test]
+fn dc_param_discovery_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut transfer = DcCpdReqEnergyTransfer::new(
+        &RationalNumber::new(150, 3),
+        &RationalNumber::new(10, 3),
+        &RationalNumber::new(300, 0),
+        &RationalNumber::new(1, 0),
+        &RationalNumber::new(900, 0),
+        &RationalNumber::new(150, 0),
+    );
+    transfer.set_target_soc(80);
+    let payload = DcChargeParamDiscoveryRequest::new(&transfer).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let header = message.get_header()?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeParamDiscoveryReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(header.get_session_id() == [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+    let transfer = payload
+        .get_energy_transfer()
+        .expect("expect dc transfer mode");
+    assert!(transfer.get_max_charge_power().get_value() == 150);
+    assert!(transfer.get_max_charge_power().get_exponent() == 3);
+    assert!(transfer.get_max_voltage().get_value() == 900);
+    assert!(transfer.get_target_soc() == Some(80));
+
+    Ok(())
+}
+
+#[test]
+fn dc_cable_check_response() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = DcCableCheckResponse::new(ResponseCode::Ok, EvseProcessing::Ongoing).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcCableCheckRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_rcode()? == ResponseCode::Ok);
+    assert!(payload.get_processing()? == EvseProcessing::Ongoing);
+
+    Ok(())
+}
+
+#[test]
+fn dc_pre_charge_request() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = DcPreChargeRequest::new(
+        EvseProcessing::Ongoing,
+        &RationalNumber::new(380, 0),
+        &RationalNumber::new(400, 0),
+    )
+    .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcPreChargeReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_processing()? == EvseProcessing::Ongoing);
+    assert!(payload.get_present_voltage().get_value() == 380);
+    assert!(payload.get_target_voltage().get_value() == 400);
+
+    Ok(())
+}
+
+#[test]
+fn dc_charge_loop_dynamic_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = DynamicDcClReqMode::new(
+        &RationalNumber::new(40, 3),
+        &RationalNumber::new(60, 3),
+        &RationalNumber::new(5, 3),
+    );
+    mode.set_max_charge_power(&RationalNumber::new(150, 3))
+        .set_min_charge_power(&RationalNumber::new(1, 3))
+        .set_max_charge_current(&RationalNumber::new(300, 0))
+        .set_max_voltage(&RationalNumber::new(900, 0))
+        .set_min_voltage(&RationalNumber::new(150, 0))
+        .set_departure_time(7200);
+    let payload = DcChargeLoopRequest::new(
+        &RationalNumber::new(402, 0),
+        &DcClReqControlMode::Dynamic(mode),
+    )
+    .set_meter_info_requested(true)
+    .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeLoopReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_present_voltage().get_value() == 402);
+    assert!(payload.get_meter_info_requested());
+    match payload.get_control_mode()? {
+        DcClReqControlMode::Dynamic(mode) => {
+            assert!(mode.get_target_energy().get_value() == 40);
+            assert!(mode.get_max_charge_current().get_value() == 300);
+            assert!(mode.get_departure_time() == Some(7200));
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn dc_charge_loop_scheduled_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = ScheduledDcClResMode::new();
+    mode.set_max_charge_power(&RationalNumber::new(50, 3));
+    let payload = DcChargeLoopResponse::new(
+        ResponseCode::Ok,
+        &RationalNumber::new(125, 0),
+        &RationalNumber::new(400, 0),
+        &DcClResControlMode::Scheduled(mode),
+    )
+    .set_limits_achieved(false, true, false)
+    .set_evse_status(&EvseStatusType::new(EvseNotification::Pause, 10))
+    .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeLoopRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_rcode()? == ResponseCode::Ok);
+    assert!(payload.get_present_current().get_value() == 125);
+    assert!(!payload.get_power_limit_achieved());
+    assert!(payload.get_current_limit_achieved());
+    let status = payload.get_evse_status().expect("expect evse status");
+    assert!(status.get_notification()? == EvseNotification::Pause);
+    match payload.get_control_mode()? {
+        DcClResControlMode::Scheduled(mode) => {
+            assert!(
+                mode.get_max_charge_power()
+                    .expect("expect max power")
+                    .get_value()
+                    == 50
+            );
+            assert!(mode.get_max_voltage().is_none());
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn dc_welding_detection_response() -> Result<(), AfbError> {
+    // Encoding API
+    let payload =
+        DcWeldingDetectionResponse::new(ResponseCode::Ok, &RationalNumber::new(12, 0)).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcWeldingDetectionRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_present_voltage().get_value() == 12);
+
+    Ok(())
+}
+
+#[test]
+fn dc_bpt_param_discovery_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut transfer = BptDcCpdResEnergyTransfer::new(
+        &RationalNumber::new(150, 3),
+        &RationalNumber::new(1, 3),
+        &RationalNumber::new(300, 0),
+        &RationalNumber::new(1, 0),
+        &RationalNumber::new(920, 0),
+        &RationalNumber::new(150, 0),
+    );
+    transfer
+        .set_max_discharge_power(&RationalNumber::new(11, 3))
+        .set_min_discharge_power(&RationalNumber::new(1, 3))
+        .set_max_discharge_current(&RationalNumber::new(30, 0))
+        .set_min_discharge_current(&RationalNumber::new(1, 0));
+    let payload = DcChargeParamDiscoveryResponse::new_bpt(ResponseCode::Ok, &transfer).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeParamDiscoveryRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_energy_transfer().is_none());
+    let transfer = payload
+        .get_bpt_energy_transfer()
+        .expect("expect bpt transfer mode");
+    assert!(transfer.get_max_voltage().get_value() == 920);
+    assert!(transfer.get_max_discharge_power().get_value() == 11);
+    assert!(transfer.get_max_discharge_current().get_value() == 30);
+
+    Ok(())
+}
+
+#[test]
+fn dc_bpt_charge_loop_dynamic_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = BptDynamicDcClReqMode::new(
+        &RationalNumber::new(40, 3),
+        &RationalNumber::new(60, 3),
+        &RationalNumber::new(5, 3),
+    );
+    mode.set_max_charge_power(&RationalNumber::new(150, 3))
+        .set_max_discharge_power(&RationalNumber::new(11, 3))
+        .set_max_discharge_current(&RationalNumber::new(30, 0))
+        .set_min_v2x_energy(&RationalNumber::new(-10, 3));
+    let payload = DcChargeLoopRequest::new(
+        &RationalNumber::new(398, 0),
+        &DcClReqControlMode::BptDynamic(mode),
+    )
+    .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeLoopReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    match payload.get_control_mode()? {
+        DcClReqControlMode::BptDynamic(mode) => {
+            assert!(mode.get_max_discharge_power().get_value() == 11);
+            assert!(mode.get_max_discharge_current().get_value() == 30);
+            let v2x = mode.get_min_v2x_energy().expect("expect min v2x energy");
+            assert!(v2x.get_value() == -10);
+            assert!(mode.get_max_v2x_energy().is_none());
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn dc_bpt_charge_loop_scheduled_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = BptScheduledDcClResMode::new();
+    mode.set_max_discharge_power(&RationalNumber::new(11, 3))
+        .set_min_voltage(&RationalNumber::new(200, 0));
+    let payload = DcChargeLoopResponse::new(
+        ResponseCode::Ok,
+        &RationalNumber::new(-25, 0),
+        &RationalNumber::new(398, 0),
+        &DcClResControlMode::BptScheduled(mode),
+    )
+    .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::DcChargeLoopRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API (discharging current is negative)
+    assert!(payload.get_present_current().get_value() == -25);
+    match payload.get_control_mode()? {
+        DcClResControlMode::BptScheduled(mode) => {
+            let power = mode
+                .get_max_discharge_power()
+                .expect("expect discharge power");
+            assert!(power.get_value() == 11);
+            assert!(power.get_exponent() == 3);
+            assert!(mode.get_max_charge_power().is_none());
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
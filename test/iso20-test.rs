@@ -0,0 +1,350 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::mock_exi::*;
+use iso15118::prelude::iso20_exi::*;
+use iso15118::prelude::*;
+
+// iso-20 has no reference binary yet, tests only check encode/decode round trip
+pub fn encode_to_stream(funcname: &str, body: Iso20BodyType) -> Result<ExiStream, AfbError> {
+    const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+    // mock network stream and encode message
+    let stream = ExiStream::new();
+    {
+        let mut lock = stream.lock_stream();
+        let mut header = ExiMessageHeader::new(&SESSION_ID)?;
+        header.set_timestamp(0);
+        ExiMessageDoc::new(&header, &body)?.encode_to_stream(&mut lock)?;
+        let doc_size = stream
+            .header_check(&lock, v2g::PayloadMsgId::MAIN)
+            .expect("expect valid V2G header");
+        println!(
+            "{}-> ({}) [{}]",
+            funcname,
+            doc_size,
+            dump_buffer(lock.get_buffer())
+        );
+    }
+
+    Ok(stream)
+}
+
+pub fn decode_from_stream(_funcname: &str, stream: ExiStream) -> Result<ExiMessageDoc, AfbError> {
+    let stream_decode = mock_network_input(stream.lock_stream().get_buffer());
+    let mut lock = stream_decode.lock_stream();
+    let message = ExiMessageDoc::decode_from_stream(&mut lock)?;
+    Ok(message)
+}
+
+#[test]
+fn session_setup_request() -> Result<(), AfbError> {
+    // Encoding API
+    let evcc_id = "WMIV1234567890ABCDEX";
+    let payload = SessionSetupRequest::new(evcc_id)?.encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let header = message.get_header()?;
+    let payload = match message.get_body()? {
+        MessageBody::SessionSetupReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(header.get_session_id() == [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+    assert!(header.get_timestamp() == 0);
+    assert!(payload.get_id()? == evcc_id);
+
+    Ok(())
+}
+
+#[test]
+fn session_setup_response() -> Result<(), AfbError> {
+    // Encoding API
+    let evse_id = "tux-evse-001";
+    let rcode = ResponseCode::NewSession;
+    let payload = SessionSetupResponse::new(evse_id, rcode)?.encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::SessionSetupRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_id()? == evse_id);
+    assert!(payload.get_rcode()? == rcode);
+
+    Ok(())
+}
+
+#[test]
+fn authorization_setup_response() -> Result<(), AfbError> {
+    // Encoding API
+    let rcode = ResponseCode::Ok;
+    let challenge = [
+        0x1, 0x2, 0x3, 0x4, 0x5, 0x6, 0x7, 0x8, 0x9, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf, 0x10,
+    ];
+    let payload = AuthorizationSetupResponse::new(rcode, false)
+        .add_authorization(AuthorizationType::Eim)?
+        .add_authorization(AuthorizationType::Pnc)?
+        .set_pnc_challenge(&challenge)?
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AuthorizationSetupRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let auths = payload.get_authorizations()?;
+    assert!(payload.get_rcode()? == rcode);
+    assert!(payload.get_cert_install_service() == false);
+    assert!(auths.len() == 2);
+    assert!(auths[0] == AuthorizationType::Eim);
+    assert!(auths[1] == AuthorizationType::Pnc);
+    assert!(payload.get_pnc_challenge() == Some(&challenge[..]));
+
+    Ok(())
+}
+
+#[test]
+fn authorization_request() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = AuthorizationRequest::new(AuthorizationType::Eim).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::AuthorizationReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_authorization()? == AuthorizationType::Eim);
+    assert!(payload.get_pnc_id().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn service_discovery_response() -> Result<(), AfbError> {
+    // Encoding API
+    let rcode = ResponseCode::Ok;
+    let payload = ServiceDiscoveryResponse::new(rcode, true)
+        .add_transfer_service(&ServiceOffer::new(ServiceId::Dc, true))?
+        .add_transfer_service(&ServiceOffer::new(ServiceId::Ac, false))?
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::ServiceDiscoveryRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let services = payload.get_transfer_services();
+    assert!(payload.get_rcode()? == rcode);
+    assert!(payload.get_renegotiation());
+    assert!(services.len() == 2);
+    assert!(services[0].get_id()? == ServiceId::Dc);
+    assert!(services[0].get_isfree());
+    assert!(services[1].get_id()? == ServiceId::Ac);
+    assert!(payload.get_vas_services().len() == 0);
+
+    Ok(())
+}
+
+#[test]
+fn service_detail_response() -> Result<(), AfbError> {
+    // Encoding API
+    let mut pset = ParamSet::new(1);
+    pset.add_param(&ParamTuple::new("Connector", &ParamValue::Int32(2))?)?
+        .add_param(&ParamTuple::new("ControlMode", &ParamValue::Int32(1))?)?
+        .add_param(&ParamTuple::new(
+            "MaximumVoltage",
+            &ParamValue::Rational(RationalNumber::new(400, 0)),
+        )?)?;
+    let payload = ServiceDetailResponse::new(ServiceId::Dc as u16, ResponseCode::Ok)
+        .add_pset(&pset)?
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::ServiceDetailRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let psets = payload.get_psets();
+    assert!(payload.get_id() == ServiceId::Dc as u16);
+    assert!(psets.len() == 1);
+    let params = psets[0].get_params();
+    assert!(params.len() == 3);
+    assert!(params[0].get_name()? == "Connector");
+    match params[2].get_value()? {
+        ParamValue::Rational(value) => {
+            assert!(value.get_value() == 400);
+            assert!(value.get_exponent() == 0);
+        }
+        _ => panic!("Unexpected param type"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn schedule_exchange_request() -> Result<(), AfbError> {
+    // Encoding API
+    let mut mode = DynamicReqMode::new(
+        3600,
+        &RationalNumber::new(40, 3),
+        &RationalNumber::new(60, 3),
+        &RationalNumber::new(10, 3),
+    );
+    mode.set_target_soc(80);
+    let payload = ScheduleExchangeRequest::new(12, &ScheduleReqMode::Dynamic(mode)).encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::ScheduleExchangeReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_max_points() == 12);
+    match payload.get_mode()? {
+        ScheduleReqMode::Dynamic(mode) => {
+            assert!(mode.get_departure_time() == 3600);
+            assert!(mode.get_target_energy().get_value() == 40);
+            assert!(mode.get_target_soc() == Some(80));
+            assert!(mode.get_min_soc().is_none());
+        }
+        _ => panic!("Unexpected control mode"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn power_delivery_response() -> Result<(), AfbError> {
+    // Encoding API
+    let status = EvseStatusType::new(EvseNotification::Terminate, 30);
+    let payload = PowerDeliveryResponse::new(ResponseCode::Ok)
+        .set_evse_status(&status)
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::PowerDeliveryRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let status = payload.get_evse_status().expect("expect evse status");
+    assert!(status.get_notification()? == EvseNotification::Terminate);
+    assert!(status.get_delay() == 30);
+
+    Ok(())
+}
+
+#[test]
+fn bpt_service_discovery_response() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = ServiceDiscoveryResponse::new(ResponseCode::Ok, false)
+        .add_transfer_service(&ServiceOffer::new(ServiceId::DcBpt, false))?
+        .add_transfer_service(&ServiceOffer::new(ServiceId::AcBpt, false))?
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::ServiceDiscoveryRes(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    let services = payload.get_transfer_services();
+    assert!(services[0].get_id()? == ServiceId::DcBpt);
+    assert!(services[0].get_id()?.is_bpt());
+    assert!(services[1].get_id()? == ServiceId::AcBpt);
+    assert!(!ServiceId::Dc.is_bpt());
+
+    Ok(())
+}
+
+#[test]
+fn bpt_power_delivery_request() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = PowerDeliveryRequest::new(EvseProcessing::Finished, ChargeProgress::Start)
+        .set_channel_selection(ChannelSelection::Discharge)
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::PowerDeliveryReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_progress()? == ChargeProgress::Start);
+    assert!(payload.get_channel_selection()? == Some(ChannelSelection::Discharge));
+
+    Ok(())
+}
+
+#[test]
+fn session_stop_request() -> Result<(), AfbError> {
+    // Encoding API
+    let payload = SessionStopRequest::new(ChargingSessionType::Terminate)
+        .set_termination_code("user-abort")?
+        .encode();
+
+    // encode message to stream_exi and decode it back
+    let stream = encode_to_stream(func_name!(), payload)?;
+    let message = decode_from_stream(func_name!(), stream)?;
+    let payload = match message.get_body()? {
+        MessageBody::SessionStopReq(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    // Decoding API
+    assert!(payload.get_action()? == ChargingSessionType::Terminate);
+    assert!(payload.get_termination_code() == Some("user-abort"));
+    assert!(payload.get_termination_explanation().is_none());
+
+    Ok(())
+}
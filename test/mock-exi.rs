@@ -18,7 +18,7 @@ pub fn mock_network_input(exi_data: &[u8]) -> ExiStream {
 
         // validate buffer stream (should not be locked)
         stream
-            .finalize(&lock, doc_size)
+            .finalize(&mut lock, doc_size)
             .expect("expect valid stream handle");
     }
     stream
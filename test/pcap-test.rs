@@ -0,0 +1,422 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::*;
+use std::net::{Ipv6Addr, SocketAddrV6};
+
+const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+const START: u64 = 1_700_000_000_000_000;
+
+fn ev_addr(port: u16) -> SocketAddrV6 {
+    SocketAddrV6::new(
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0x201, 0x2ff, 0xfe03, 0x405),
+        port,
+        0,
+        0,
+    )
+}
+
+fn evse_addr(port: u16) -> SocketAddrV6 {
+    SocketAddrV6::new(
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0x211, 0x22ff, 0xfe33, 0x4455),
+        port,
+        0,
+        0,
+    )
+}
+
+// EV/EVSE exchange: appHand negotiation to ISO-2 (schema 1) then SessionSetup
+fn session_frames() -> Result<Vec<(bool, Vec<u8>)>, AfbError> {
+    let mut request = v2g::SupportedAppProtocolReq::empty();
+    request.add_protocol_priority(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[2], 1)?;
+    request.add_protocol_priority(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[1], 2)?;
+    let response = v2g::SupportedAppProtocolRes::new(
+        v2g::ResponseCode::Success,
+        v2g::ProtocolTagId::Iso2 as u8,
+    );
+
+    use iso2_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let setup_req = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    let setup_res = SessionSetupResponse::new("FR*IOT*E12345", ResponseCode::NewSession)?.encode();
+    Ok(vec![
+        (true, v2g::V2gMsgBody::Request(request).encode()?),
+        (false, v2g::V2gMsgBody::Response(response).encode()?),
+        (true, ExiMessageDoc::new(&header, &setup_req).encode()?),
+        (false, ExiMessageDoc::new(&header, &setup_res).encode()?),
+    ])
+}
+
+fn session_capture() -> Result<Vec<u8>, AfbError> {
+    let mut writer = PcapWriter::new();
+    let request = v2g::SdpRequest::new(v2g::SdpTransportProtocol::TCP, v2g::SdpSecurityModel::NONE)
+        .encode()?;
+    let multicast = SocketAddrV6::new(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1), 15118, 0, 0);
+    writer.add_udp(START, &ev_addr(50000), &multicast, &request);
+    let response = v2g::SdpResponse::new(
+        evse_addr(0).ip().octets(),
+        61341,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    )
+    .encode()?;
+    writer.add_udp(START + 1000, &evse_addr(15118), &ev_addr(50000), &response);
+
+    let mut flow = PcapTcpFlow::new(ev_addr(50001), evse_addr(61341));
+    flow.connect(&mut writer, START + 2000);
+    let mut timestamp = START + 10_000;
+    for (from_client, frame) in session_frames()? {
+        flow.send(&mut writer, timestamp, from_client, &frame);
+        timestamp += 10_000;
+    }
+    Ok(writer.get_buffer().to_vec())
+}
+
+fn check_session(events: &[V2gTraceEvent]) {
+    let mut messages = Vec::new();
+    for event in events {
+        match &event.content {
+            V2gTraceContent::Message(document) => messages.push(document.get_protocol()),
+            V2gTraceContent::Error(error) => panic!("unexpected error:{}", error),
+            _ => {}
+        }
+    }
+    assert!(
+        messages
+            == [
+                V2gProtocol::AppHand,
+                V2gProtocol::AppHand,
+                V2gProtocol::Iso2,
+                V2gProtocol::Iso2
+            ]
+    );
+}
+
+#[test]
+fn pcap_session_timeline() -> Result<(), AfbError> {
+    let mut analyzer = V2gPcapAnalyzer::new();
+    let events = analyzer.analyze(&session_capture()?)?;
+
+    match &events[0].content {
+        V2gTraceContent::SdpRequest { transport, .. } => {
+            assert!(*transport == v2g::SdpTransportProtocol::TCP)
+        }
+        _ => panic!("expect sdp request"),
+    }
+    match &events[1].content {
+        V2gTraceContent::SdpResponse { port, addr, .. } => {
+            assert!(*port == 61341);
+            assert!(addr == evse_addr(0).ip());
+        }
+        _ => panic!("expect sdp response"),
+    }
+    match &events[4].content {
+        V2gTraceContent::Info(info) => assert!(info == "negotiated protocol:iso2 schema:1"),
+        _ => panic!("expect negotiation info"),
+    }
+    match &events[5].content {
+        V2gTraceContent::Message(V2gDocument::Iso2(document)) => match document.get_body()? {
+            iso2_exi::MessageBody::SessionSetupReq(_) => {}
+            _ => panic!("unexpected iso2 message"),
+        },
+        _ => panic!("expect iso2 message"),
+    }
+    assert!(events[5].source == ev_addr(50001));
+    assert!(events[5].timestamp == START + 30_000);
+    assert!(!events[5].tls);
+    check_session(events);
+
+    let text = analyzer.to_text();
+    assert!(text.lines().count() == 7);
+    assert!(text.contains("iso2 {Header:{SessionID:0102030405060708} Body:SessionSetupReq"));
+    Ok(())
+}
+
+#[test]
+fn pcap_tcp_reassembly() -> Result<(), AfbError> {
+    let client = ev_addr(50001);
+    let server = evse_addr(61341);
+    let mut writer = PcapWriter::new();
+    let flags = TCP_FLAG_PSH | TCP_FLAG_ACK;
+    let (mut client_seq, mut server_seq) = (1000u32, 5000u32);
+    writer.add_tcp(
+        START,
+        &client,
+        &server,
+        client_seq - 1,
+        0,
+        TCP_FLAG_SYN,
+        &[],
+    );
+    let syn_ack = TCP_FLAG_SYN | TCP_FLAG_ACK;
+    writer.add_tcp(
+        START,
+        &server,
+        &client,
+        server_seq - 1,
+        client_seq,
+        syn_ack,
+        &[],
+    );
+
+    // client frames are split in two segments, second half arrives first then is retransmitted
+    for (idx, (from_client, frame)) in session_frames()?.into_iter().enumerate() {
+        let timestamp = START + (idx as u64 + 1) * 1000;
+        if from_client {
+            let (head, tail) = frame.split_at(5);
+            let tail_seq = client_seq.wrapping_add(head.len() as u32);
+            writer.add_tcp(
+                timestamp, &client, &server, tail_seq, server_seq, flags, tail,
+            );
+            writer.add_tcp(
+                timestamp + 1,
+                &client,
+                &server,
+                client_seq,
+                server_seq,
+                flags,
+                head,
+            );
+            writer.add_tcp(
+                timestamp + 2,
+                &client,
+                &server,
+                tail_seq,
+                server_seq,
+                flags,
+                tail,
+            );
+            client_seq = client_seq.wrapping_add(frame.len() as u32);
+        } else {
+            writer.add_tcp(
+                timestamp, &server, &client, server_seq, client_seq, flags, &frame,
+            );
+            server_seq = server_seq.wrapping_add(frame.len() as u32);
+        }
+    }
+
+    let mut analyzer = V2gPcapAnalyzer::new();
+    check_session(analyzer.analyze(writer.get_buffer())?);
+    Ok(())
+}
+
+// rewrite classic pcap records as pcapng blocks (nanosecond resolution)
+fn pcap_to_pcapng(capture: &[u8]) -> Result<Vec<u8>, AfbError> {
+    let mut data = Vec::new();
+    let mut block = |block_type: u32, body: &[u8]| {
+        let len = (12 + body.len() + 3) & !3;
+        data.extend_from_slice(&block_type.to_le_bytes());
+        data.extend_from_slice(&(len as u32).to_le_bytes());
+        data.extend_from_slice(body);
+        data.resize(data.len() + len - 12 - body.len(), 0);
+        data.extend_from_slice(&(len as u32).to_le_bytes());
+    };
+
+    let mut section = 0x1a2b3c4du32.to_le_bytes().to_vec();
+    section.extend_from_slice(&[1, 0, 0, 0]);
+    section.extend_from_slice(&(-1i64).to_le_bytes());
+    block(0x0a0d0d0a, &section);
+
+    let mut interface = (PCAP_LINKTYPE_ETHERNET as u16).to_le_bytes().to_vec();
+    interface.extend_from_slice(&[0, 0]);
+    interface.extend_from_slice(&65535u32.to_le_bytes());
+    interface.extend_from_slice(&[9, 0, 1, 0, 9, 0, 0, 0, 0, 0, 0, 0]);
+    block(0x00000001, &interface);
+
+    let mut reader = PcapReader::new(capture)?;
+    while let Some(packet) = reader.next_packet()? {
+        let ticks = packet.timestamp * 1000;
+        let mut packet_block = 0u32.to_le_bytes().to_vec();
+        packet_block.extend_from_slice(&((ticks >> 32) as u32).to_le_bytes());
+        packet_block.extend_from_slice(&(ticks as u32).to_le_bytes());
+        packet_block.extend_from_slice(&(packet.data.len() as u32).to_le_bytes());
+        packet_block.extend_from_slice(&(packet.data.len() as u32).to_le_bytes());
+        packet_block.extend_from_slice(packet.data);
+        block(0x00000006, &packet_block);
+    }
+    Ok(data)
+}
+
+#[test]
+fn pcap_pcapng_reader() -> Result<(), AfbError> {
+    let capture = pcap_to_pcapng(&session_capture()?)?;
+    let mut analyzer = V2gPcapAnalyzer::new();
+    let events = analyzer.analyze(&capture)?;
+    assert!(events[0].timestamp == START);
+    check_session(events);
+
+    assert!(PcapReader::new(&[0u8; 24]).is_err());
+    Ok(())
+}
+
+#[test]
+fn pcap_tls_keylog() -> Result<(), AfbError> {
+    let keylog = TlsKeyLog::parse(
+        "# SSLKEYLOGFILE\n\
+         CLIENT_RANDOM 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 00112233\n",
+    )?;
+    assert!(keylog.len() == 1);
+    let random: Vec<u8> = (0..32).collect();
+    assert!(keylog.get_secret("CLIENT_RANDOM", &random) == Some(&[0x00, 0x11, 0x22, 0x33][..]));
+    assert!(TlsKeyLog::parse("CLIENT_RANDOM 0001").is_err());
+
+    // TLS stream without key log only reports handshake and a decrypt error
+    let mut hello = vec![22, 0x03, 0x01, 0x00, 0x2a, 1, 0x00, 0x00, 0x26, 0x03, 0x03];
+    hello.extend_from_slice(&random);
+    hello.extend_from_slice(&[0, 0, 0, 0]);
+    assert!(TlsSession::is_tls_record(&hello));
+    let mut session = TlsSession::new();
+    let events = session.push(true, &hello, &TlsKeyLog::new());
+    match &events[..] {
+        [TlsEvent::Info(info)] => assert!(info == "tls client hello"),
+        _ => panic!("expect client hello"),
+    }
+    Ok(())
+}
+
+// clear text hellos, client random 00..1f, server random 20..3f
+fn tls_hello(
+    session: &mut TlsSession,
+    suite: u16,
+    tls13: bool,
+    keylog: &TlsKeyLog,
+) -> Vec<TlsEvent> {
+    let mut client = vec![22, 0x03, 0x01, 0x00, 0x2a, 1, 0x00, 0x00, 0x26, 0x03, 0x03];
+    client.extend((0..32).collect::<Vec<u8>>());
+    client.extend_from_slice(&[0, 0, 0, 0]);
+    session.push(true, &client, keylog);
+
+    // session id, cipher suite, compression, supported_versions extension for TLS-1.3
+    let mut body = vec![0x03, 0x03];
+    body.extend((32..64).collect::<Vec<u8>>());
+    body.extend_from_slice(&[0, (suite >> 8) as u8, suite as u8, 0]);
+    if tls13 {
+        body.extend_from_slice(&[0x00, 0x06, 0x00, 0x2b, 0x00, 0x02, 0x03, 0x04]);
+    } else {
+        body.extend_from_slice(&[0x00, 0x00]);
+    }
+    let mut server = vec![22, 0x03, 0x03, 0x00, body.len() as u8 + 4];
+    server.extend_from_slice(&[2, 0x00, 0x00, body.len() as u8]);
+    server.extend_from_slice(&body);
+    session.push(false, &server, keylog)
+}
+
+#[test]
+fn pcap_tls13_rfc8448() -> Result<(), AfbError> {
+    // RFC 8448 §3 simple 1-RTT handshake, server handshake & application traffic secrets
+    let client_random: Vec<u8> = (0..32).collect();
+    let mut keylog = TlsKeyLog::new();
+    keylog.add_secret(
+        "SERVER_HANDSHAKE_TRAFFIC_SECRET",
+        &client_random,
+        &parse_dump("b67b7d690cc16c4e75e54213cb2d37b4e9c912bcded9105d42befd59d391ad38")?,
+    );
+    keylog.add_secret(
+        "SERVER_TRAFFIC_SECRET_0",
+        &client_random,
+        &parse_dump("a11af9f05531f856ad47116b45a950328204b4f44bfb6b3a4b4f1f3fcb631643")?,
+    );
+    let mut session = TlsSession::new();
+    match &tls_hello(&mut session, 0x1301, true, &keylog)[..] {
+        [TlsEvent::Info(info)] => assert!(info == "tls server hello version:1.3 cipher:0x1301"),
+        _ => panic!("expect server hello"),
+    }
+    assert!(session.get_version() == 0x0304);
+
+    // application key seq:0 stands for the RFC NewSessionTicket, inner type is handshake
+    let ticket = parse_dump(
+        "17 03 03 00 15 3a 6b 8f 59 57 28 bc 76 3c d5 a3 5c 64 22 78 64 dd ae b6 e9 8a",
+    )?;
+    assert!(session.push(false, &ticket, &keylog).is_empty());
+
+    // RFC server application data seq:1, nonce = iv xor seq, aad = record header
+    let record = parse_dump(
+        "17 03 03 00 43 2e 93 7e 11 ef 4a c7 40 e5 38 ad 36 00 5f c4 a4 69 32 fc 32 25
+         d0 5f 82 aa 1b 36 e3 0e fa f9 7d 90 e6 df fc 60 2d cb 50 1a 59 a8 fc c4 9c 4b
+         f2 e5 f0 a2 1c 00 47 c2 ab f3 32 54 0d d0 32 e1 67 c2 95 5d",
+    )?;
+    match &session.push(false, &record, &keylog)[..] {
+        [TlsEvent::ApplicationData(data)] => assert!(*data == (0..50).collect::<Vec<u8>>()),
+        _ => panic!("expect rfc8448 application data"),
+    }
+    Ok(())
+}
+
+#[test]
+fn pcap_tls12_cbc_sha256() -> Result<(), AfbError> {
+    // ECDHE_ECDSA_WITH_AES_128_CBC_SHA256 (ISO-2), master secret 40..6f, records built
+    // with an independent RFC 5246 implementation (PRF key expansion, MAC then encrypt)
+    let client_random: Vec<u8> = (0..32).collect();
+    let master: Vec<u8> = (0x40..0x70).collect();
+    let mut keylog = TlsKeyLog::new();
+    keylog.add_secret("CLIENT_RANDOM", &client_random, &master);
+    let mut session = TlsSession::new();
+    match &tls_hello(&mut session, 0xc023, false, &keylog)[..] {
+        [TlsEvent::Info(info)] => assert!(info == "tls server hello version:1.2 cipher:0xc023"),
+        _ => panic!("expect server hello"),
+    }
+    assert!(session
+        .push(true, &[20, 0x03, 0x03, 0x00, 0x01, 0x01], &keylog)
+        .is_empty());
+
+    // client Finished seq:0, explicit iv 80..8f
+    let finished = parse_dump(
+        "16 03 03 00 50 80 81 82 83 84 85 86 87 88 89 8a 8b 8c 8d 8e 8f f3 2a e7 33 19
+         64 96 53 81 e3 0c ea 16 ee 1a 2d dc dc 30 cf ad c5 c9 ee f8 09 b4 46 a8 4d 4f
+         ac 58 56 d1 c7 6b f9 6e 36 f1 a9 b6 1f 5d 89 af bc 0a 1f 77 64 19 b2 b8 97 76
+         4a b9 a5 bc e3 c4 dc",
+    )?;
+    assert!(session.push(true, &finished, &keylog).is_empty());
+
+    // V2GTP frame seq:1, explicit iv 90..9f
+    let record = parse_dump(
+        "17 03 03 00 40 90 91 92 93 94 95 96 97 98 99 9a 9b 9c 9d 9e 9f 60 e8 2d ef c2
+         7e 60 39 9d b9 d8 f7 43 29 22 78 95 1c b3 f5 a2 9a 1e 1e 90 54 9b 5b 63 31 82
+         ee fb 29 af 72 bd 19 b5 01 a3 fb b2 d3 fd ec a8 7f",
+    )?;
+    match &session.push(true, &record, &keylog)[..] {
+        [TlsEvent::ApplicationData(data)] => {
+            assert!(
+                *data == [0x01, 0xfe, 0x80, 0x01, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef]
+            )
+        }
+        _ => panic!("expect cbc application data"),
+    }
+
+    // MAC covers the sequence number, a replayed record does not authenticate
+    match &session.push(true, &finished, &keylog)[..] {
+        [TlsEvent::Error(error)] => assert!(error.starts_with("tls decrypt: record does not")),
+        _ => panic!("expect replay error"),
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn pcap_timeline_json() -> Result<(), AfbError> {
+    let mut analyzer = V2gPcapAnalyzer::new();
+    let events = analyzer.analyze(&session_capture()?)?;
+    let json = serde_json::to_string(events).expect("serialize");
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    assert!(value[0]["event"] == "sdp-request");
+    assert!(value[1]["port"] == 61341);
+    assert!(value[5]["event"] == "message");
+    assert!(value[5]["protocol"] == "iso2");
+    assert!(value[5]["message"]["Body"]["SessionSetupReq"]["EVCCID"] == "010203040506");
+    Ok(())
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --package iso15118 --test test-v2g test_physical_value
+use iso15118::prelude::*;
+use std::cmp::Ordering;
+
+#[test]
+fn scaled_value_conversion() -> Result<(), AfbError> {
+    assert!(scaled_from_f64(400.5)? == (4005, -1));
+    assert!(scaled_from_f64(0.001)? == (1, -3));
+    assert!(scaled_from_f64(-0.25)? == (-250, -3));
+    assert!(scaled_from_f64(0.0)? == (0, 0));
+    assert!(scaled_from_f64(32767000.0)? == (32767, 3));
+    assert!(scaled_from_f64(40_000_000.0).is_err());
+    assert!(scaled_from_f64(f64::NAN).is_err());
+
+    assert!(scaled_from_decimal("400.5")? == (4005, -1));
+    assert!(scaled_from_decimal("-0.25")? == (-250, -3));
+    assert!(scaled_from_decimal("+12.3456")? == (12346, -3));
+    assert!(scaled_from_decimal("007")? == (7000, -3));
+    assert!(scaled_from_decimal("1.2.3").is_err());
+    assert!(scaled_from_decimal("12V").is_err());
+    assert!(scaled_from_decimal("-").is_err());
+
+    assert!(scaled_cmp((4000, -1), (400, 0)) == Ordering::Equal);
+    assert!(scaled_cmp((-1, 3), (32767, -3)) == Ordering::Less);
+    Ok(())
+}
+
+#[test]
+fn iso2_physical_value() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let voltage = PhysicalValue::from_f64(400.0, PhysicalUnit::Volt)?;
+    assert!(voltage.get_value() == 4000 && voltage.get_multiplier() == -1);
+    assert!(voltage.to_f64() == 400.0);
+    assert!(voltage.to_decimal() == "400");
+
+    let delta = PhysicalValue::from_decimal("0.5", PhysicalUnit::Volt)?;
+    let sum = voltage.checked_add(&delta)?;
+    assert!(sum.get_value() == 4005 && sum.get_multiplier() == -1);
+    assert!(sum.get_unit()? == PhysicalUnit::Volt);
+
+    let target = PhysicalValue::new(450, 0, PhysicalUnit::Volt);
+    let diff = voltage.checked_sub(&target)?;
+    assert!(diff.to_f64() == -50.0);
+
+    // i16 overflow selects a bigger multiplier instead of wrapping
+    let max = PhysicalValue::new(i16::MAX, 0, PhysicalUnit::Watt);
+    let sum = max.checked_add(&PhysicalValue::new(1, 0, PhysicalUnit::Watt))?;
+    assert!(sum.get_multiplier() == 1 && sum.to_f64() == 32770.0);
+    let max = PhysicalValue::new(i16::MAX, 3, PhysicalUnit::Watt);
+    assert!(max.checked_add(&max).is_err());
+
+    assert!(voltage == PhysicalValue::new(400, 0, PhysicalUnit::Volt));
+    assert!(voltage < target);
+    assert!(voltage.checked_cmp(&target)? == Ordering::Less);
+
+    // volts and amps do not mix
+    let current = PhysicalValue::new(400, 0, PhysicalUnit::Ampere);
+    assert!(voltage.checked_add(&current).is_err());
+    assert!(voltage.checked_cmp(&current).is_err());
+    assert!(voltage.partial_cmp(&current).is_none());
+    assert!(voltage != current);
+    Ok(())
+}
+
+#[test]
+fn din_physical_value() -> Result<(), AfbError> {
+    use din_exi::*;
+    let soc = PhysicalValue::from_f64(42.0, PhysicalUnit::Unset)?;
+    assert!(soc.get_unit()?.is_none());
+    let sum = soc.checked_add(&PhysicalValue::new(8, 0, PhysicalUnit::Unset))?;
+    assert!(sum.to_f64() == 50.0);
+
+    let energy = PhysicalValue::from_decimal("12.5", PhysicalUnit::Wh)?;
+    assert!(energy.to_decimal() == "12.5");
+    assert!(energy.checked_add(&soc).is_err());
+    assert!(energy > PhysicalValue::new(12, 0, PhysicalUnit::Wh));
+    Ok(())
+}
@@ -0,0 +1,215 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --package iso15118 --test test-v2g test_quantity
+use iso15118::prelude::*;
+
+#[test]
+fn quantity_conversion() -> Result<(), AfbError> {
+    let voltage = Voltage::from_f64(400.5)?;
+    assert!(voltage.get_value() == 4005 && voltage.get_multiplier() == -1);
+    assert!(voltage == Voltage::from_decimal("400.5")?);
+    assert!(voltage.to_string() == "400.5 V");
+    assert!(Voltage::new(4000, -1) == Voltage::new(400, 0));
+    assert!(Voltage::new(4000, -1) < voltage);
+
+    let energy = Energy::new(30, 3).checked_add(&Energy::from_decimal("500")?)?;
+    assert!(energy == Energy::new(3050, 1));
+    assert!(energy.to_string() == "30500 Wh");
+    assert!(Current::from_f64(40_000_000.0).is_err());
+
+    let duration = Duration::from_secs(3600)?;
+    assert!(duration.to_secs() == 3600);
+    assert!(duration.to_string() == "3600 s");
+    assert!(Duration::from_secs(100_000)?.to_secs() == 100_000);
+
+    let soc = Soc::new(80)?;
+    assert!(i8::from(soc) == 80);
+    assert!(soc.to_string() == "80 %");
+    assert!(Soc::try_from(101i8).is_err());
+    assert!(Soc::new(-1).is_err());
+    Ok(())
+}
+
+#[test]
+fn quantity_wire_value() -> Result<(), AfbError> {
+    let power = Power::new(150, 3);
+
+    // ISO-2 unit is mandatory and checked
+    let iso2: iso2_exi::PhysicalValue = power.into();
+    assert!(iso2.get_unit()? == iso2_exi::PhysicalUnit::Watt);
+    assert!(Power::try_from(iso2)? == power);
+    if Voltage::try_from(iso2).is_ok() {
+        panic!("watt should not convert to volt");
+    }
+
+    // DIN unit is optional, unset takes the element unit
+    let din: din_exi::PhysicalValue = power.into();
+    assert!(din.get_unit()? == Some(din_exi::PhysicalUnit::Watt));
+    let unset = din_exi::PhysicalValue::new(4000, -1, din_exi::PhysicalUnit::Unset);
+    assert!(unset.to_quantity::<Voltage>()? == Voltage::new(400, 0));
+    let ampere = din_exi::PhysicalValue::new(10, 0, din_exi::PhysicalUnit::Ampere);
+    assert!(ampere.to_quantity::<Power>().is_err());
+
+    // ISO-20 has no unit, large exponents are fitted back in -3..3
+    let iso20: iso20_exi::RationalNumber = power.into();
+    assert!(iso20.get_value() == 150 && iso20.get_exponent() == 3);
+    let rational = iso20_exi::RationalNumber::new(5, 4);
+    assert!(rational.to_quantity::<Energy>()? == Energy::from_decimal("50000")?);
+    assert!(iso20_exi::RationalNumber::new(i16::MAX, 10)
+        .to_quantity::<Energy>()
+        .is_err());
+
+    // durations accept hours and minutes
+    let hours = iso2_exi::PhysicalValue::new(2, 0, iso2_exi::PhysicalUnit::Hour);
+    assert!(hours.to_quantity::<Duration>()?.to_secs() == 7200);
+    let minutes = din_exi::PhysicalValue::new(15, 0, din_exi::PhysicalUnit::Minute);
+    assert!(minutes.to_quantity::<Duration>()?.to_secs() == 900);
+    assert!(hours.to_quantity::<Energy>().is_err());
+    Ok(())
+}
+
+// charger logic written once, whatever the negotiated protocol
+fn precharge_gap<T: ExiQuantityValue>(target: &T, present: &T) -> Result<Voltage, AfbError> {
+    let target: Voltage = target.to_quantity()?;
+    let present: Voltage = present.to_quantity()?;
+    target.checked_sub(&present)
+}
+
+#[test]
+fn quantity_messages() -> Result<(), AfbError> {
+    let target = Voltage::from_decimal("400")?;
+    let present = Voltage::from_decimal("390.5")?;
+    let current = Current::from_decimal("2")?;
+    let gap = Voltage::from_decimal("9.5")?;
+
+    let status = iso2_exi::DcEvStatusType::new(true, iso2_exi::DcEvErrorCode::NoError, 50);
+    let request = iso2_exi::PreChargeRequest::new(&status, &target.into(), &current.into())?;
+    assert!(Current::try_from(request.get_target_current())? == current);
+    let response = iso2_exi::PreChargeResponse::new(
+        iso2_exi::ResponseCode::Ok,
+        &iso2_exi::DcEvseStatusType::new(
+            iso2_exi::DcEvseErrorCode::Ready,
+            iso2_exi::EvseNotification::None,
+            0,
+        ),
+        &present.into(),
+    )?;
+    assert!(precharge_gap(&request.get_target_voltage(), &response.get_voltage())? == gap);
+
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 50);
+    let request = din_exi::PreChargeRequest::new(&status, &target.into(), &current.into())?;
+    let response = din_exi::PreChargeResponse::new(
+        din_exi::ResponseCode::Ok,
+        &din_exi::DcEvseStatusType::new(
+            din_exi::DcEvseErrorCode::Ready,
+            din_exi::EvseNotification::None,
+            0,
+        ),
+        &present.into(),
+    )?;
+    assert!(precharge_gap(&request.get_target_voltage(), &response.get_voltage())? == gap);
+
+    let request = iso20_dc_exi::DcPreChargeRequest::new(
+        iso20_dc_exi::EvseProcessing::Ongoing,
+        &present.into(),
+        &target.into(),
+    );
+    let present = request.get_present_voltage();
+    assert!(precharge_gap(&request.get_target_voltage(), &present)? == gap);
+    Ok(())
+}
+
+#[test]
+fn quantity_typed_messages() -> Result<(), AfbError> {
+    let target = Voltage::from_decimal("400")?;
+    let current = Current::from_decimal("125.5")?;
+    let power = Power::new(50, 3);
+
+    // DIN/ISO-2 builders take typed quantities, getters return them back
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 50);
+    let mut request =
+        din_exi::CurrentDemandRequest::from_quantities(&status, current, target, false);
+    request
+        .set_power_limit_quantity(power)?
+        .set_time_to_full_sock_quantity(Duration::from_secs(1800)?)?;
+    assert!(request.get_voltage_target_quantity()? == target);
+    assert!(request.get_current_target_quantity()? == current);
+    assert!(request.get_power_limit_quantity()? == Some(power));
+    assert!(request.get_voltage_limit_quantity()?.is_none());
+    let full = request.get_time_to_full_sock_quantity()?;
+    assert!(full == Some(Duration::from_secs(1800)?));
+
+    let soc = Soc::new(50)?;
+    let status =
+        iso2_exi::DcEvStatusType::from_quantities(true, iso2_exi::DcEvErrorCode::NoError, soc);
+    let request = iso2_exi::PreChargeRequest::from_quantities(&status, target, current)?;
+    assert!(request.get_target_voltage_quantity()? == target);
+    assert!(request.get_target_current_quantity()? == current);
+    assert!(request.get_status().get_evresssoc_quantity()? == soc);
+
+    // DIN/ISO-2 SoC is a raw i8, typed getters reject out of range values
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 127);
+    let mut param = din_exi::DcEvChargeParam::from_quantities(&status, target, current)?;
+    param.set_full_soc_quantity(Soc::new(90)?);
+    assert!(param.get_full_soc_quantity()? == Some(Soc::new(90)?));
+    assert!(param.get_bulk_soc_quantity()?.is_none());
+    assert!(param.get_status().get_evress_soc_quantity().is_err());
+
+    // ISO-2 AC ParamDiscovery elements
+    let energy = Energy::new(20, 3);
+    let param = iso2_exi::AcEvChargeParam::from_quantities(
+        energy,
+        target,
+        Current::new(32, 0),
+        Current::new(6, 0),
+    )?;
+    assert!(param.get_ea_mount_quantity()? == energy);
+    assert!(param.get_max_current_quantity()? == Current::new(32, 0));
+    let status = iso2_exi::AcEvseStatusType::new(iso2_exi::EvseNotification::None, 0, false);
+    let param =
+        iso2_exi::AcEvseChargeParam::from_quantities(&status, Voltage::new(230, 0), current)?;
+    assert!(param.get_nominate_voltage_quantity()? == Voltage::new(230, 0));
+    assert!(param.get_max_current_quantity()? == current);
+
+    // ISO-20 RationalNumber carries no unit, the element defines it
+    let mut mode = iso20_dc_exi::ScheduledDcClReqMode::from_quantities(current, target);
+    mode.set_max_charge_power_quantity(power);
+    assert!(mode.get_target_voltage_quantity()? == target);
+    assert!(mode.get_max_charge_power_quantity()? == Some(power));
+    assert!(mode.get_min_charge_power_quantity()?.is_none());
+
+    // ISO-20 AC power & energy elements, reactive power stays raw
+    let energy = Energy::new(30, 3);
+    let mut mode = iso20_ac_exi::ScheduledAcClReqMode::from_quantities(power);
+    mode.set_max_energy_quantity(energy)
+        .set_present_active_power_l2_quantity(power);
+    assert!(mode.get_present_active_power_quantity()? == power);
+    assert!(mode.get_max_energy_quantity()? == Some(energy));
+    assert!(mode.get_present_active_power_l2_quantity()? == Some(power));
+    assert!(mode.get_target_energy_quantity()?.is_none());
+    let transfer = iso20_ac_exi::BptAcCpdReqEnergyTransfer::from_quantities(power, power);
+    assert!(transfer.get_max_charge_power_quantity()? == power);
+
+    // raw builders stay available, typed getters still check the wire unit
+    let status = din_exi::DcEvStatusType::new(true, din_exi::DcEvErrorCode::NoError, 50);
+    let request =
+        din_exi::CurrentDemandRequest::new(&status, &target.into(), &target.into(), false);
+    assert!(request.get_current_target_quantity().is_err());
+    Ok(())
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::test_controller::MockCharger;
+use crate::test_evcc::{run_session, MockVehicle};
+use iso15118::prelude::iso2_exi::*;
+use iso15118::prelude::*;
+use std::net;
+use std::sync::Arc;
+
+fn new_evcc(mode: EngyTransfertMode) -> Result<EvccController, AfbError> {
+    let evcc = EvccController::new(Box::new(MockVehicle::new(mode)));
+    let sdp = v2g::SdpResponse::new(
+        net::Ipv6Addr::LOCALHOST.octets(),
+        15118,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    );
+    evcc.sdp_response(&sdp.encode()?)?;
+    Ok(evcc)
+}
+
+// record a complete AC session on the SECC side
+fn record_secc_session() -> Result<V2gRecording, AfbError> {
+    let recorder = Arc::new(V2gRecorder::new());
+    let mut secc = IsoController::new(Box::new(MockCharger {}));
+    secc.set_recorder(recorder.clone());
+
+    let evcc = new_evcc(EngyTransfertMode::AcSinglePhase)?;
+    run_session(&evcc, &secc)?;
+    recorder.get_recording()
+}
+
+#[test]
+fn replay_record_format() -> Result<(), AfbError> {
+    let recording = record_secc_session()?;
+    // 12 requests & 12 responses, requests first
+    assert!(recording.len() == 24);
+    let records = recording.get_records();
+    assert!(records[0].direction == V2gRecordDirection::EvToEvse);
+    assert!(records[1].direction == V2gRecordDirection::EvseToEv);
+    assert!(records[0].timestamp <= records[23].timestamp);
+
+    let data = recording.encode();
+    assert!(&data[0..6] == V2G_RECORD_MAGIC);
+    let decoded = V2gRecording::decode(&data)?;
+    assert!(decoded.len() == recording.len());
+    for (record, copy) in recording.get_records().iter().zip(decoded.get_records()) {
+        assert!(record.timestamp == copy.timestamp);
+        assert!(record.direction == copy.direction);
+        assert!(record.frame == copy.frame);
+    }
+
+    // truncated last record is dropped, not an error
+    let decoded = V2gRecording::decode(&data[0..data.len() - 3])?;
+    assert!(decoded.len() == recording.len() - 1);
+
+    if V2gRecording::decode(&data[8..]).is_ok() {
+        panic!("record file without magic should fail");
+    }
+
+    let text = recording.to_text();
+    assert!(text.lines().count() == 24);
+    assert!(text.contains("ev->evse iso2 {Header:"));
+    Ok(())
+}
+
+#[test]
+fn replay_record_file() -> Result<(), AfbError> {
+    let path = std::env::temp_dir().join(format!("v2g-replay-{}.rec", std::process::id()));
+    let path = path.to_string_lossy().to_string();
+
+    let recorder = V2gRecorder::create(&path)?;
+    let frame = v2g::V2gMsgBody::Request(v2g::SupportedAppProtocolReq::new(
+        &ISO2_PROTOCOL_CONF,
+    )?)
+    .encode()?;
+    recorder.record(V2gRecordDirection::EvToEvse, &frame);
+    if recorder.get_recording().is_ok() {
+        panic!("file recorder should not keep frames in memory");
+    }
+    drop(recorder);
+
+    let recording = V2gRecording::from_file(&path)?;
+    let _ = std::fs::remove_file(&path);
+    assert!(recording.len() == 1);
+    assert!(recording.get_records()[0].frame == frame);
+    Ok(())
+}
+
+#[test]
+fn replay_secc_session() -> Result<(), AfbError> {
+    let recording = record_secc_session()?;
+
+    // live controller generates its own session id, replay should not diverge
+    let secc = IsoController::new(Box::new(MockCharger {}));
+    let report = V2gReplayer::new(recording).replay_iso2(&secc)?;
+    report.check()?;
+    assert!(report.get_frames() == 12);
+    assert!(secc.get_state()? == SeccState::Idle);
+    Ok(())
+}
+
+#[test]
+fn replay_secc_divergence() -> Result<(), AfbError> {
+    let recording = record_secc_session()?;
+
+    // pretend field charger refused SessionStop
+    let mut tampered = V2gRecording::new();
+    for record in recording.get_records() {
+        let mut record = record.clone();
+        if record.direction == V2gRecordDirection::EvseToEv {
+            if let Ok(document) = ExiMessageDoc::decode(&record.frame) {
+                if let MessageBody::SessionStopRes(_) = document.get_body()? {
+                    let body = SessionStopResponse::new(ResponseCode::Failed).encode();
+                    record.frame = ExiMessageDoc::new(&document.get_header(), &body).encode()?;
+                }
+            }
+        }
+        tampered.push(record);
+    }
+
+    let secc = IsoController::new(Box::new(MockCharger {}));
+    let report = V2gReplayer::new(tampered).replay_iso2(&secc)?;
+    assert!(report.get_frames() == 12);
+    let divergences = report.get_divergences();
+    assert!(divergences.len() == 1);
+    assert!(divergences[0].index == 23);
+    assert!(divergences[0].expected.starts_with("SessionStopRes"));
+    assert!(divergences[0].received.starts_with("SessionStopRes"));
+    assert!(report.check().is_err());
+    Ok(())
+}
+
+#[test]
+fn replay_evcc_session() -> Result<(), AfbError> {
+    let recorder = Arc::new(V2gRecorder::new());
+    let mut evcc = new_evcc(EngyTransfertMode::AcSinglePhase)?;
+    evcc.set_recorder(recorder.clone());
+    let secc = IsoController::new(Box::new(MockCharger {}));
+    run_session(&evcc, &secc)?;
+    let recording = recorder.get_recording()?;
+    assert!(recording.len() == 24);
+
+    // replay SECC responses, EVCC starts from SDP
+    let evcc = EvccController::new(Box::new(MockVehicle::new(
+        EngyTransfertMode::AcSinglePhase,
+    )));
+    let report = V2gReplayer::new(recording.clone()).replay_evcc(&evcc)?;
+    report.check()?;
+    assert!(report.get_frames() == 12);
+    assert!(evcc.get_state()? == EvccState::Done);
+
+    // a DC vehicle does not send the recorded AC requests
+    let evcc = new_evcc(EngyTransfertMode::DcExtended)?;
+    let report = V2gReplayer::new(recording).replay_evcc(&evcc)?;
+    assert!(!report.is_ok());
+    Ok(())
+}
@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::v2g::*;
+use iso15118::prelude::*;
+use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6, UdpSocket};
+use std::thread;
+use std::time::Duration;
+
+// loopback server without multicast, bound on an ephemeral port
+fn loopback_server() -> Result<(SdpServer, SocketAddrV6), AfbError> {
+    let mut config = SdpServerConfig::new(Ipv6Addr::LOCALHOST, 0);
+    config.bind = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0);
+    config.multicast_iface = None;
+    config.tcp_port = Some(15118);
+    config.tls_port = Some(15119);
+
+    let server = SdpServer::new(config)?;
+    let addr = match server.get_local_addr()? {
+        SocketAddr::V6(addr) => addr,
+        SocketAddr::V4(_) => panic!("expect ipv6 socket"),
+    };
+    Ok((server, addr))
+}
+
+#[test]
+fn sdp_socket_discovery() -> Result<(), AfbError> {
+    let (server, addr) = loopback_server()?;
+    server.set_timeout(Some(Duration::from_secs(5)))?;
+    let thread = thread::spawn(move || -> Result<(), AfbError> {
+        server.process_request()?;
+        server.process_request()?;
+        Ok(())
+    });
+
+    let mut client = SdpClient::with_target(addr)?;
+    client.set_retry(4).set_timeout(Duration::from_millis(500));
+
+    let response = client.discover(SdpSecurityModel::NONE)?;
+    assert!(response.get_port() == 15118);
+    assert!(response.get_security()? == SdpSecurityModel::NONE);
+    assert!(response.get_addr6() == Ipv6Addr::LOCALHOST.octets());
+
+    let response = client.discover(SdpSecurityModel::TLS)?;
+    assert!(response.get_port() == 15119);
+    assert!(response.get_security()? == SdpSecurityModel::TLS);
+
+    thread.join().expect("sdp server thread")?;
+    Ok(())
+}
+
+#[test]
+fn sdp_socket_timeout() -> Result<(), AfbError> {
+    // server exists but never answers
+    let (_server, addr) = loopback_server()?;
+    let mut client = SdpClient::with_target(addr)?;
+    client.set_retry(2).set_timeout(Duration::from_millis(20));
+    assert!(client.discover(SdpSecurityModel::NONE).is_err());
+    Ok(())
+}
+
+#[test]
+fn sdp_socket_invalid_transport() -> Result<(), AfbError> {
+    // fake server answers a single request with a foreign datagram, an unknown
+    // transport byte and finally a valid response, all within one retry cycle
+    let socket = UdpSocket::bind(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0, 0, 0)).unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let addr = match socket.local_addr().unwrap() {
+        SocketAddr::V6(addr) => addr,
+        SocketAddr::V4(_) => panic!("expect ipv6 socket"),
+    };
+    let thread = thread::spawn(move || -> Result<(), AfbError> {
+        let valid = SdpResponse::new(
+            Ipv6Addr::LOCALHOST.octets(),
+            15118,
+            SdpTransportProtocol::TCP,
+            SdpSecurityModel::NONE,
+        )
+        .encode()?;
+        let mut invalid = valid;
+        let last = invalid.len() - 1;
+        invalid[last] = 0x55;
+
+        let mut buffer = [0u8; 64];
+        let (_count, remote) = socket.recv_from(&mut buffer).unwrap();
+        for answer in [&[0x01, 0xfe, 0x90][..], &invalid[..], &valid[..]] {
+            socket.send_to(answer, remote).unwrap();
+        }
+        Ok(())
+    });
+
+    let mut client = SdpClient::with_target(addr)?;
+    client.set_retry(1).set_timeout(Duration::from_millis(500));
+    let response = client.discover(SdpSecurityModel::NONE)?;
+    assert!(response.get_port() == 15118);
+    assert!(response.get_transport()? == SdpTransportProtocol::TCP);
+
+    thread.join().expect("sdp fake server thread")?;
+    Ok(())
+}
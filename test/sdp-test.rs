@@ -12,12 +12,12 @@ fn sdp_request() -> Result<(), AfbError> {
         Err(error) => panic!("{}", error),
     }
 
-    match request.get_transport() {
+    match request.get_transport()? {
         SdpTransportProtocol::TCP => {}
         _ => panic!("fail to decode transport"),
     }
 
-    match request.get_security() {
+    match request.get_security()? {
         SdpSecurityModel::NONE => {}
         _ => panic!("fail to decode transport"),
     }
@@ -44,12 +44,12 @@ fn sdp_response() -> Result<(), AfbError> {
         Err(error) => panic!("{}", error),
     }
 
-    match response.get_transport() {
+    match response.get_transport()? {
         SdpTransportProtocol::TCP => {}
         _ => panic!("fail to decode transport"),
     }
 
-    match response.get_security() {
+    match response.get_security()? {
         SdpSecurityModel::NONE => {}
         _ => panic!("fail to decode transport"),
     }
@@ -67,8 +67,8 @@ fn sdp_decode() {
 
     println!(
         "sdp request transport:{:?} security:{:?}",
-        &request.get_transport(),
-        &request.get_security()
+        &request.get_transport().expect("valid transport"),
+        &request.get_security().expect("valid security")
     )
 }
 
@@ -97,8 +97,8 @@ fn sdp_encode() {
     // simulate network input and decode received message
     let response= SdpResponse::decode(&buffer).unwrap();
     assert!(response.get_addr6() == fake_ipv6.octets() );
-    assert!(response.get_transport() == SdpTransportProtocol::TCP);
-    assert!(response.get_security() == SdpSecurityModel::TLS);
+    assert!(response.get_transport().unwrap() == SdpTransportProtocol::TCP);
+    assert!(response.get_security().unwrap() == SdpSecurityModel::TLS);
     assert!(response.get_port() == port);
 }
 
@@ -0,0 +1,380 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --features=serde --package iso15118 --test test-v2g test_serde
+use iso15118::prelude::*;
+
+const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+// decode frame, serialize to json, deserialize back and check EXI re-encoding is identical
+fn json_round_trip<T>(frame: &[u8]) -> Result<String, AfbError>
+where
+    T: ExiCodec + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let message = T::decode(frame)?;
+    let json = serde_json::to_string_pretty(&message).expect("serialize message");
+
+    let message: T = serde_json::from_str(&json).expect("deserialize message");
+    assert!(message.encode()? == frame);
+    Ok(json)
+}
+
+#[test]
+fn serde_iso2_session_setup() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    let frame = ExiMessageDoc::new(&header, &body).encode()?;
+
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    assert!(value["Header"]["SessionID"] == "0102030405060708");
+    assert!(value["Body"]["SessionSetupReq"]["EVCCID"] == "010203040506");
+    Ok(())
+}
+
+#[test]
+fn serde_iso2_param_discovery() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let status = DcEvseStatusType::new(DcEvseErrorCode::Ready, EvseNotification::None, 0);
+    let mut params = DcEvseChargeParam::new(
+        &status,
+        &PhysicalValue::new(5200, -1, PhysicalUnit::Volt),
+        &PhysicalValue::new(500, -1, PhysicalUnit::Volt),
+        &PhysicalValue::new(3000, -2, PhysicalUnit::Ampere),
+        &PhysicalValue::new(0, -1, PhysicalUnit::Ampere),
+        &PhysicalValue::new(11000, 0, PhysicalUnit::Watt),
+        &PhysicalValue::new(10, -1, PhysicalUnit::Ampere),
+    )?;
+    params.set_regul_tolerance(&PhysicalValue::new(5, -1, PhysicalUnit::Ampere))?;
+
+    let mut pmax = PMaxScheduleEntry::new(&PhysicalValue::new(11000, 0, PhysicalUnit::Watt));
+    pmax.set_relative_time_interval(RelativeTimeInterval::new(0).set_duration(86400));
+    let mut sched = SasScheduleTuple::new(1);
+    sched.add_pmax(&pmax)?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = ParamDiscoveryResponse::new(ResponseCode::Ok, EvseProcessing::Finished)
+        .add_schedule_tuple(&sched)?
+        .set_evse_dc_charge_param(&params)
+        .encode();
+    let frame = ExiMessageDoc::new(&header, &body).encode()?;
+
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let response = &value["Body"]["ChargeParameterDiscoveryRes"];
+    assert!(response["ResponseCode"] == "OK");
+    assert!(response["EVSEProcessing"] == "Finished");
+    assert!(response["DC_EVSEChargeParameter"]["EVSEMaximumVoltageLimit"]["Unit"] == "V");
+    assert!(response["DC_EVSEChargeParameter"]
+        .get("EVSEEnergyToBeDelivered")
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_iso2_current_demand() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let ev_status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 64);
+    let mut request = CurrentDemandRequest::new(
+        &ev_status,
+        &PhysicalValue::new(80, 1, PhysicalUnit::Ampere),
+        &PhysicalValue::new(400, 1, PhysicalUnit::Volt),
+        false,
+    );
+    request
+        .set_voltage_limit(&PhysicalValue::new(800, 1, PhysicalUnit::Volt))?
+        .set_power_limit(&PhysicalValue::new(50, 3, PhysicalUnit::Watt))?
+        .set_time_to_full_sock(&PhysicalValue::new(30, 0, PhysicalUnit::Minute))?
+        .set_time_to_bulk_sock(&PhysicalValue::new(900, 0, PhysicalUnit::Second))?
+        .set_bulk_complete(false);
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let request = &value["Body"]["CurrentDemandReq"];
+    assert!(request["RemainingTimeToFullSoC"]["Unit"] == "m");
+    assert!(request["BulkChargingComplete"] == false);
+    assert!(request.get("EVMaximumCurrentLimit").is_none());
+
+    // response carries a signed MeterInfo
+    let mut meter = MeterInfo::new("tux-meter")?;
+    meter
+        .set_reading(123456)
+        .set_sig(&[0xa, 0xb, 0xc, 0xd])?
+        .set_status(1)
+        .set_tmeter(1700000000);
+    let evse_status = DcEvseStatusType::new(DcEvseErrorCode::Ready, EvseNotification::None, 0);
+    let mut response = CurrentDemandResponse::new(
+        ResponseCode::Ok,
+        "tux-evse-001",
+        &evse_status,
+        &PhysicalValue::new(64, 1, PhysicalUnit::Ampere),
+        false,
+        &PhysicalValue::new(400, 1, PhysicalUnit::Volt),
+        false,
+        true,
+        1,
+    )?;
+    response
+        .set_power_limit(&PhysicalValue::new(50, 3, PhysicalUnit::Watt))?
+        .set_meter_info(&meter)
+        .set_receipt_require(true);
+
+    let frame = ExiMessageDoc::new(&header, &response.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let response = &value["Body"]["CurrentDemandRes"];
+    assert!(response["MeterInfo"]["MeterID"] == "tux-meter");
+    assert!(response["MeterInfo"]["SigMeterReading"] == "0a0b0c0d");
+    assert!(response["ReceiptRequired"] == true);
+    Ok(())
+}
+
+#[test]
+fn serde_iso2_power_delivery() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let mut entry = ChargingProfileEntry::new(0, &PhysicalValue::new(11, 3, PhysicalUnit::Watt))?;
+    entry.set_phases_used(3);
+    let ev_status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 64);
+    let mut request = PowerDeliveryRequest::new(ChargeProgress::Start, 12);
+    request
+        .add_charging_profile(&entry)?
+        .add_charging_profile(&ChargingProfileEntry::new(
+            3600,
+            &PhysicalValue::new(7, 3, PhysicalUnit::Watt),
+        )?)?
+        .set_dc_delivery_params(&DcEvPowerDeliveryParam::new(&ev_status, false))?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let request = &value["Body"]["PowerDeliveryReq"];
+    assert!(request["SAScheduleTupleID"] == 12);
+    let entries = &request["ChargingProfile"]["ProfileEntry"];
+    assert!(entries[0]["ChargingProfileEntryMaxNumberOfPhasesInUse"] == 3);
+    assert!(entries[1]["ChargingProfileEntryStart"] == 3600);
+    assert!(request.get("EVPowerDeliveryParameter").is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_iso2_metering_receipt() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let mut meter = MeterInfo::new("tux-meter")?;
+    meter.set_reading(64).set_sig(&[0x1, 0x2, 0x3])?;
+    let mut request = MeteringReceiptRequest::new(&SESSION_ID, &meter)?;
+    request.set_id("receipt-001")?.set_tupple_id(1);
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let request = &value["Body"]["MeteringReceiptReq"];
+    assert!(request["Id"] == "receipt-001");
+    assert!(request["MeterInfo"]["MeterReading"] == 64);
+    assert!(request["MeterInfo"].get("TMeter").is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_iso2_certificate_chain() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let mut provisioning = CertificateChainType::new(&[0x01, 0x02, 0x03, 0x04])?;
+    provisioning
+        .set_id("Cert-TuxEvSE")?
+        .add_subcert(&[0x11, 0x12, 0x13])?
+        .add_subcert(&[0x21, 0x22, 0x23])?;
+    let mut contract = CertificateChainType::new(&[0xa1, 0xa2, 0xa3, 0xa4])?;
+    contract.add_subcert(&[0xb1, 0xb2])?;
+
+    let response = CertificateInstallResponse::new(
+        ResponseCode::Ok,
+        &contract,
+        &provisioning,
+        &PrivateKeyType::new("Private_TuxEvSe", &[0xd1, 0xd2, 0xd3])?,
+        &DhPublicKeyType::new("Public_TuxEvSe", &[0xe1, 0xe2, 0xe3])?,
+        &EmaidType::new("Emaid_TuxEvSe", "FRTUXC123456789")?,
+    );
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &response.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let response = &value["Body"]["CertificateInstallationRes"];
+    let chain = &response["SAProvisioningCertificateChain"];
+    assert!(chain["Id"] == "Cert-TuxEvSE");
+    assert!(chain["SubCertificates"]["Certificate"][1] == "212223");
+    assert!(response["ContractSignatureCertChain"].get("Id").is_none());
+
+    // contract chain without sub certificates
+    let contract = CertificateChainType::new(&[0xa1, 0xa2, 0xa3, 0xa4])?;
+    let request = PaymentDetailsRequest::new("FRTUXC123456789", &contract)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let chain = &value["Body"]["PaymentDetailsReq"]["ContractSignatureCertChain"];
+    assert!(chain["Certificate"] == "a1a2a3a4");
+    assert!(chain.get("SubCertificates").is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_din_param_discovery() -> Result<(), AfbError> {
+    use din_exi::*;
+    let ev_status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 50);
+    let mut params = DcEvChargeParam::new(
+        &ev_status,
+        &PhysicalValue::new(400, 0, PhysicalUnit::Volt),
+        &PhysicalValue::new(100, 0, PhysicalUnit::Ampere),
+    )?;
+    params.set_full_soc(90);
+
+    let mut request = ParamDiscoveryRequest::new(EvRequestTransfertMode::DcExtended);
+    request.set_dc_charge_param(&params)?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let request = &value["Body"]["ChargeParameterDiscoveryReq"];
+    assert!(request["DC_EVChargeParameter"]["FullSOC"] == 90);
+    assert!(request["DC_EVChargeParameter"].get("BulkSOC").is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_din_current_demand() -> Result<(), AfbError> {
+    use din_exi::*;
+    let ev_status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 64);
+    let mut request = CurrentDemandRequest::new(
+        &ev_status,
+        &PhysicalValue::new(80, 1, PhysicalUnit::Ampere),
+        &PhysicalValue::new(400, 1, PhysicalUnit::Volt),
+        false,
+    );
+    request
+        .set_current_limit(&PhysicalValue::new(125, 0, PhysicalUnit::Ampere))?
+        .set_time_to_full_sock(&PhysicalValue::new(1800, 0, PhysicalUnit::Second))?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let request = &value["Body"]["CurrentDemandReq"];
+    assert!(request["EVMaximumCurrentLimit"]["Value"] == 125);
+    assert!(request["RemainingTimeToFullSoC"]["Unit"] == "s");
+
+    let mut evse_status = DcEvseStatusType::new(DcEvseErrorCode::Ready, EvseNotification::None, 0);
+    evse_status.set_isolation_status(IsolationStatus::Valid);
+    let mut response = CurrentDemandResponse::new(
+        ResponseCode::Ok,
+        &evse_status,
+        &PhysicalValue::new(400, 1, PhysicalUnit::Volt),
+        &PhysicalValue::new(64, 1, PhysicalUnit::Ampere),
+        false,
+        true,
+        false,
+    )?;
+    response.set_voltage_limit(&PhysicalValue::new(500, 0, PhysicalUnit::Volt))?;
+    let frame = ExiMessageDoc::new(&header, &response.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let response = &value["Body"]["CurrentDemandRes"];
+    assert!(response["EVSECurrentLimitAchieved"] == true);
+    assert!(response["EVSEMaximumVoltageLimit"]["Value"] == 500);
+    assert!(response.get("EVSEMaximumPowerLimit").is_none());
+    Ok(())
+}
+
+#[test]
+fn serde_din_power_delivery() -> Result<(), AfbError> {
+    use din_exi::*;
+    let ev_status = DcEvStatusType::new(true, DcEvErrorCode::NoError, 64);
+    let mut request = PowerDeliveryRequest::new(true);
+    request
+        .set_schedule_id(1)
+        .add_charging_profile(&ChargingProfileEntry::new(0, 110))?
+        .add_charging_profile(&ChargingProfileEntry::new(3600, 70))?
+        .set_dc_delivery_params(&DcEvPowerDeliveryParam::new(&ev_status, false))?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let profile = &value["Body"]["PowerDeliveryReq"]["ChargingProfile"];
+    assert!(profile["SAScheduleTupleID"] == 1);
+    assert!(profile["ProfileEntry"][1]["ChargingProfileEntryMaxPower"] == 70);
+    Ok(())
+}
+
+#[test]
+fn serde_din_certificate_chain() -> Result<(), AfbError> {
+    use din_exi::*;
+    let mut contract = CertificateChainType::new(&[0xa1, 0xa2, 0xa3, 0xa4])?;
+    contract.set_subcert(&[0xb1, 0xb2, 0xb3])?;
+    let request = PaymentDetailsRequest::new("TUX-CONTRACT-001", &contract)?;
+
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+    let json = json_round_trip::<ExiMessageDoc>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let chain = &value["Body"]["PaymentDetailsReq"]["ContractSignatureCertChain"];
+    assert!(chain["Certificate"] == "a1a2a3a4");
+    assert!(chain["SubCertificates"]["Certificate"] == "b1b2b3");
+    Ok(())
+}
+
+#[test]
+fn serde_app_protocol() -> Result<(), AfbError> {
+    use v2g::*;
+
+    // entries are kept in received order, not sorted by priority
+    let mut request = SupportedAppProtocolReq::empty();
+    request
+        .add_protocol_priority(V2G_PROTOCOLS_SUPPORTED_LIST[1], 2)?
+        .add_protocol_priority(V2G_PROTOCOLS_SUPPORTED_LIST[2], 1)?;
+    let frame = V2gMsgBody::Request(request).encode()?;
+    let json = json_round_trip::<V2gMsgBody>(&frame)?;
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let protocols = &value["supportedAppProtocolReq"]["AppProtocol"];
+    assert!(protocols[0]["ProtocolNamespace"] == V2G_PROTOCOLS_SUPPORTED_LIST[1].name);
+    assert!(protocols[1]["Priority"] == 1);
+
+    let response = SupportedAppProtocolRes::new(ResponseCode::Success, 1);
+    let frame = V2gMsgBody::Response(response).encode()?;
+    json_round_trip::<V2gMsgBody>(&frame)?;
+    Ok(())
+}
+
+#[test]
+fn serde_reject_json() {
+    // unknown enum value
+    let json = r#"{"supportedAppProtocolRes":{"ResponseCode":"OK"}}"#;
+    assert!(serde_json::from_str::<v2g::V2gMsgBody>(json).is_err());
+
+    // invalid hexBinary content
+    let json = r#"{"Header":{"SessionID":"0102zz"},"Body":{"SessionStopReq":{}}}"#;
+    assert!(serde_json::from_str::<din_exi::ExiMessageDoc>(json).is_err());
+
+    // missing mandatory element
+    let json = r#"{"Header":{"SessionID":"01"},"Body":{"SessionSetupRes":{"ResponseCode":"OK"}}}"#;
+    assert!(serde_json::from_str::<iso2_exi::ExiMessageDoc>(json).is_err());
+}
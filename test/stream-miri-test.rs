@@ -0,0 +1,213 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// pure Rust tests, no libiso15118 call (RawStream::new skips C init under Miri)
+// cargo +nightly miri test --test test-v2g test_stream_miri
+use iso15118::prelude::v2g::*;
+use iso15118::prelude::*;
+use std::mem;
+use std::thread;
+
+fn assert_send<T: Send>() {}
+fn assert_sync<T: Sync>() {}
+
+#[test]
+fn stream_send_sync() {
+    // RawStream may move to a worker thread, ExiStream may also be shared
+    assert_send::<RawStream>();
+    assert_send::<ExiStream>();
+    assert_sync::<ExiStream>();
+}
+
+#[test]
+fn stream_header_roundtrip() -> Result<(), AfbError> {
+    let header = v2gtp_make_header(PayloadMsgId::DC_MAIN, 0x0102);
+    assert!(header == [0x01, 0xFE, 0x80, 0x04, 0x00, 0x00, 0x01, 0x02]);
+
+    match v2gtp_parse_header(&header, EXI_MAX_DOCUMENT_SIZE)? {
+        Some((payload_id, frame_len)) => {
+            assert!(payload_id == PayloadMsgId::DC_MAIN);
+            assert!(frame_len == V2GTP_HEADER_LEN + 0x0102);
+        }
+        None => panic!("expect complete header"),
+    }
+    assert!(v2gtp_parse_header(&header[0..7], EXI_MAX_DOCUMENT_SIZE)?.is_none());
+    assert!(v2gtp_parse_header(&header, 64).is_err());
+    Ok(())
+}
+
+#[test]
+fn stream_frame_threads() {
+    // frames are owned, encode/decode on independent threads
+    let workers: Vec<_> = (0..4u8)
+        .map(|idx| {
+            thread::spawn(move || {
+                let frame = V2gtpFrame::new(PayloadMsgId::MAIN, &[idx; 16]);
+                let mut buffer = frame.encode();
+                let decoded = V2gtpFrame::decode(&mut buffer, 64)
+                    .expect("valid frame")
+                    .expect("complete frame");
+                assert!(decoded.payload == [idx; 16]);
+                assert!(buffer.is_empty());
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
+
+// complete V2GTP frame with a recognizable payload
+fn raw_frame(fill: u8, len: usize) -> Vec<u8> {
+    let mut frame = v2gtp_make_header(PayloadMsgId::SAP, len as u32).to_vec();
+    frame.extend(std::iter::repeat(fill).take(len));
+    frame
+}
+
+// push a full frame and check the decoder window skips the V2GTP header
+fn raw_push(stream: &mut RawStream, frame: &[u8]) -> Result<(), AfbError> {
+    match stream.push_chunk(frame)? {
+        (count, V2gtpChunk::Complete(payload_id)) => {
+            assert!(count == frame.len());
+            assert!(payload_id == PayloadMsgId::SAP);
+        }
+        _ => panic!("expect complete frame"),
+    }
+    assert!(stream.get_buffer() == frame);
+    assert!(stream.get_position() == (V2GTP_HEADER_LEN, 0));
+    Ok(())
+}
+
+#[test]
+fn stream_raw_move_threads() -> Result<(), AfbError> {
+    let mut stream = RawStream::new();
+    raw_push(&mut stream, &raw_frame(0x11, 8))?;
+
+    // stream heap buffer and bitstream move with the owner, back and forth
+    let worker = thread::spawn(move || -> Result<RawStream, AfbError> {
+        assert!(stream.get_buffer() == raw_frame(0x11, 8));
+        raw_push(&mut stream, &raw_frame(0x22, 16))?;
+        Ok(stream)
+    });
+    let mut stream = worker.join().unwrap()?;
+    assert!(stream.get_buffer() == raw_frame(0x22, 16));
+    raw_push(&mut stream, &raw_frame(0x33, 4))?;
+
+    // same through the ExiStream mutex shared by reference
+    let shared = ExiStream::new();
+    thread::scope(|scope| {
+        for idx in 0..2u8 {
+            let shared = &shared;
+            scope.spawn(move || {
+                let mut lock = shared.lock_stream();
+                raw_push(&mut lock, &raw_frame(idx, 8)).unwrap();
+            });
+        }
+    });
+    assert!(shared.get_size(&shared.lock_stream()) == V2GTP_HEADER_LEN + 8);
+    Ok(())
+}
+
+#[test]
+fn stream_raw_drop_borrowed() -> Result<(), AfbError> {
+    let frame = raw_frame(0x44, 12);
+    let stream = ExiStream::new();
+    {
+        // decode borrows the buffer through the lock, both end before the drop
+        let mut lock = stream.lock_stream();
+        raw_push(&mut lock, &frame)?;
+        let buffer = stream.get_buffer(&lock);
+        match v2gtp_parse_header(buffer, EXI_MAX_DOCUMENT_SIZE)? {
+            Some((payload_id, len)) => {
+                assert!(payload_id == PayloadMsgId::SAP);
+                assert!(len == frame.len());
+            }
+            None => panic!("expect complete header"),
+        }
+        stream.finalize(&mut lock, 12)?;
+        assert!(lock.get_position() == (V2GTP_HEADER_LEN, 0));
+    }
+    drop(stream);
+
+    // partial frame copied out of the buffer, the view outlives the stream
+    let mut raw = RawStream::new();
+    let (count, chunk) = raw.push_chunk(&frame[0..5])?;
+    assert!(count == 5 && matches!(chunk, V2gtpChunk::Partial));
+    let view = raw.get_buffer()[0..5].to_vec();
+    drop(raw);
+    assert!(view == frame[0..5]);
+    Ok(())
+}
+
+#[test]
+fn stream_raw_reset_reuse() -> Result<(), AfbError> {
+    let mut stream = RawStream::new();
+    raw_push(&mut stream, &raw_frame(0x55, 32))?;
+
+    // explicit reset drops the previous window, buffer is reused in place
+    stream.reset();
+    assert!(stream.current_len == 0 && stream.expected_len == 0);
+    assert!(stream.get_position() == (0, 0));
+    assert!(stream.get_size() == EXI_MAX_DOCUMENT_SIZE);
+
+    // next frame arrives in chunks, header split in two
+    let frame = raw_frame(0x66, 10);
+    let (count, chunk) = stream.push_chunk(&frame[0..3])?;
+    assert!(count == 3 && matches!(chunk, V2gtpChunk::Partial));
+    let (count, chunk) = stream.push_chunk(&frame[3..])?;
+    assert!(count == frame.len() - 3 && matches!(chunk, V2gtpChunk::Complete(_)));
+    assert!(stream.get_buffer() == frame);
+
+    // a completed frame is implicitly reset by the next chunk
+    raw_push(&mut stream, &raw_frame(0x77, 2))?;
+
+    // invalid header resets the stream, it stays usable
+    assert!(stream.push_chunk(&[0x02, 0x00, 0, 0, 0, 0, 0, 0]).is_err());
+    assert!(stream.current_len == 0);
+    raw_push(&mut stream, &raw_frame(0x88, 6))?;
+    Ok(())
+}
+
+#[test]
+fn stream_raw_move_drop_bitstream() -> Result<(), AfbError> {
+    // boxed bitstream keeps pointing to the pinned buffer heap, whatever the owner moves
+    let mut first = RawStream::new();
+    let mut second = RawStream::new();
+    raw_push(&mut first, &raw_frame(0x01, 8))?;
+    raw_push(&mut second, &raw_frame(0x02, 24))?;
+
+    mem::swap(&mut first, &mut second);
+    assert!(first.get_buffer() == raw_frame(0x02, 24));
+    assert!(second.get_buffer() == raw_frame(0x01, 8));
+
+    let mut streams = vec![first, second, RawStream::new()];
+    let mut moved = Box::new(streams.remove(0));
+    raw_push(&mut moved, &raw_frame(0x03, 4))?;
+    for stream in streams.iter_mut() {
+        stream.reset();
+        raw_push(stream, &raw_frame(0x04, 4))?;
+    }
+
+    // drop a stream in the middle of a frame, then all the others
+    let (_, chunk) = moved.push_chunk(&raw_frame(0x05, 16)[0..10])?;
+    assert!(matches!(chunk, V2gtpChunk::Partial));
+    drop(moved);
+    drop(streams);
+    Ok(())
+}
@@ -0,0 +1,176 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use iso15118::prelude::*;
+
+const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+fn app_protocol_frame() -> Result<Vec<u8>, AfbError> {
+    let request = v2g::SupportedAppProtocolReq::new(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[2])?;
+    v2g::V2gMsgBody::Request(request).encode()
+}
+
+fn din_frame() -> Result<Vec<u8>, AfbError> {
+    use din_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    ExiMessageDoc::new(&header, &body).encode()
+}
+
+fn iso2_frame() -> Result<Vec<u8>, AfbError> {
+    use iso2_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    ExiMessageDoc::new(&header, &body).encode()
+}
+
+fn iso20_frame() -> Result<Vec<u8>, AfbError> {
+    use iso20_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new("WMIV1234567890ABCDEX")?.encode();
+    ExiMessageDoc::new(&header, &body)?.encode()
+}
+
+fn iso20_dc_frame() -> Result<Vec<u8>, AfbError> {
+    use iso20_dc_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = DcCableCheckResponse::new(ResponseCode::Ok, EvseProcessing::Ongoing).encode();
+    ExiMessageDoc::new(&header, &body)?.encode()
+}
+
+#[cfg(feature = "serde")]
+fn iso20_dc_loop_frame() -> Result<Vec<u8>, AfbError> {
+    use iso20_dc_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let mut mode = DynamicDcClReqMode::new(
+        &RationalNumber::new(40, 3),
+        &RationalNumber::new(60, 3),
+        &RationalNumber::new(10, 3),
+    );
+    mode.set_departure_time(3600)
+        .set_max_charge_power(&RationalNumber::new(50, 3))
+        .set_max_charge_current(&RationalNumber::new(125, 0))
+        .set_max_voltage(&RationalNumber::new(450, 0));
+    let body = DcChargeLoopRequest::new(
+        &RationalNumber::new(380, 0),
+        &DcClReqControlMode::Dynamic(mode),
+    )
+    .encode();
+    ExiMessageDoc::new(&header, &body)?.encode()
+}
+
+#[test]
+fn v2g_document_detect() -> Result<(), AfbError> {
+    let frames = [
+        (app_protocol_frame()?, V2gProtocol::AppHand),
+        (din_frame()?, V2gProtocol::Din),
+        (iso2_frame()?, V2gProtocol::Iso2),
+        (iso20_frame()?, V2gProtocol::Iso20),
+        (iso20_dc_frame()?, V2gProtocol::Iso20Dc),
+    ];
+    for (frame, protocol) in frames {
+        let document = V2gDocument::decode(&frame)?;
+        assert!(document.get_protocol() == protocol);
+        assert!(document.encode()? == frame);
+
+        // raw exi without V2GTP header
+        let document = V2gDocument::decode_exi(&frame[V2GTP_HEADER_LEN..])?;
+        assert!(document.get_protocol() == protocol);
+    }
+
+    match V2gDocument::decode_as(V2gProtocol::Din, &din_frame()?)? {
+        V2gDocument::Din(document) => match document.get_body()? {
+            din_exi::MessageBody::SessionSetupReq(_) => {}
+            _ => panic!("Unexpected message type"),
+        },
+        _ => panic!("Unexpected protocol"),
+    }
+
+    // iso-20 codec does not accept SAP payload type
+    assert!(V2gDocument::decode_as(V2gProtocol::Iso20, &iso2_frame()?).is_err());
+    assert!(V2gDocument::decode(&iso2_frame()?[0..4]).is_err());
+    assert!(V2gProtocol::from_label("iso20-dc")? == V2gProtocol::Iso20Dc);
+    assert!(V2gProtocol::from_label("iso-15118").is_err());
+    Ok(())
+}
+
+#[test]
+fn v2g_document_parse_dump() -> Result<(), AfbError> {
+    let frame = iso2_frame()?;
+    assert!(parse_dump(&dump_buffer(&frame))? == frame);
+    assert!(parse_dump(&dump_hexa(&frame))? == frame);
+    assert!(parse_dump("01 fe:80 01")? == [0x01, 0xfe, 0x80, 0x01]);
+    assert!(parse_dump("01f").is_err());
+    assert!(parse_dump("0x1,0xzz,").is_err());
+
+    // base64 padding, length and unused bits are checked
+    assert!(base64_decode(&base64_encode(&frame))? == frame);
+    assert!(base64_decode("AQID\nAQ==")? == [1, 2, 3, 1]);
+    assert!(base64_decode("AQ=").is_err());
+    assert!(base64_decode("AR==").is_err());
+    assert!(base64_decode("A=Q=").is_err());
+    assert!(base64_decode("AQ==AQID").is_err());
+    assert!(base64_decode("not base64").is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn v2g_document_json() -> Result<(), AfbError> {
+    let frame = din_frame()?;
+    let json = serde_json::to_string(&V2gDocument::decode(&frame)?).expect("serialize");
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    assert!(value["protocol"] == "din");
+    assert!(value["message"]["Body"]["SessionSetupReq"]["EVCCID"] == "010203040506");
+
+    let document: V2gDocument = serde_json::from_str(&json).expect("deserialize");
+    assert!(document.encode()? == frame);
+
+    // iso-20 header is moved next to the body
+    let frame = iso20_frame()?;
+    let json = serde_json::to_string(&V2gDocument::decode(&frame)?).expect("serialize");
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    assert!(value["protocol"] == "iso20");
+    assert!(value["message"]["Header"]["SessionID"] == "0102030405060708");
+    assert!(value["message"]["Body"]["SessionSetupReq"]["EVCCID"] == "WMIV1234567890ABCDEX");
+    let document: V2gDocument = serde_json::from_str(&json).expect("deserialize");
+    assert!(document.encode()? == frame);
+
+    let frame = iso20_dc_frame()?;
+    let json = serde_json::to_string(&V2gDocument::decode(&frame)?).expect("serialize");
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    assert!(value["protocol"] == "iso20-dc");
+    let body = &value["message"]["Body"]["DC_CableCheckRes"];
+    assert!(body["ResponseCode"] == "OK");
+    assert!(body["EVSEProcessing"] == "Ongoing");
+    let document: V2gDocument = serde_json::from_str(&json).expect("deserialize");
+    assert!(document.encode()? == frame);
+
+    // control mode choice is rebuilt from the XSD element name
+    let frame = iso20_dc_loop_frame()?;
+    let json = serde_json::to_string(&V2gDocument::decode(&frame)?).expect("serialize");
+    let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+    let body = &value["message"]["Body"]["DC_ChargeLoopReq"];
+    assert!(body["Dynamic_DC_CLReqControlMode"]["DepartureTime"] == 3600);
+    let document: V2gDocument = serde_json::from_str(&json).expect("deserialize");
+    assert!(document.encode()? == frame);
+
+    let json = json.replace("Dynamic_DC_CLReqControlMode", "Unknown_DC_CLReqControlMode");
+    assert!(serde_json::from_str::<V2gDocument>(&json).is_err());
+    Ok(())
+}
@@ -21,12 +21,13 @@ pub fn encode_to_stream<'a>(
 
 pub fn decode_from_stream(stream: &MutexGuard<RawStream>) -> Result<V2gMsgBody, AfbError> {
     let stream_decode = mock_network_input(stream.get_buffer());
-    let stream_lock = stream_decode.lock_stream();
-    let message = SupportedAppProtocolExi::decode_from_stream(&stream_lock)?;
+    let mut stream_lock = stream_decode.lock_stream();
+    let message = SupportedAppProtocolExi::decode_from_stream(&mut stream_lock)?;
     Ok(message)
 }
 
 #[test]
+#[allow(deprecated)]
 fn supported_app_protocol_req() -> Result<(), AfbError> {
     // extract from Everest test suite
     let expected_response = [
@@ -90,7 +91,7 @@ fn supported_app_protocol_res() -> Result<(), AfbError> {
     let payload = response.encode();
 
     assert!(response.get_schema() == schema_id);
-    assert!(response.get_rcode() == rcode);
+    assert!(response.get_rcode()? == rcode);
 
     // encode message to stream_exi an compare with expected binary result
     let exi = ExiStream::new();
@@ -105,6 +106,136 @@ fn supported_app_protocol_res() -> Result<(), AfbError> {
     };
 
     assert!(message.get_schema() == schema_id);
-    assert!(message.get_rcode() == rcode);
+    assert!(message.get_rcode()? == rcode);
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn app_protocol_negotiation() -> Result<(), AfbError> {
+    let mut request = SupportedAppProtocolReq::new(V2G_PROTOCOLS_SUPPORTED_LIST[0])?;
+    request.add_protocol(V2G_PROTOCOLS_SUPPORTED_LIST[1])?;
+    request.add_protocol(V2G_PROTOCOLS_SUPPORTED_LIST[2])?;
+
+    // EV gives the same priority to every entry, EVSE preference breaks the tie
+    let mut negotiator = AppProtocolNegotiator::new(&[
+        V2G_PROTOCOLS_SUPPORTED_LIST[0],
+        V2G_PROTOCOLS_SUPPORTED_LIST[2],
+        V2G_PROTOCOLS_SUPPORTED_LIST[1],
+    ]);
+    negotiator.set_enable(ProtocolTagId::Iso20, false);
+
+    let negotiation = negotiator.negotiate(&request);
+    let selected = negotiation.get_selected().expect("protocol selected");
+    assert!(selected.tag_id == ProtocolTagId::Din);
+    assert!(negotiation.get_rcode() == ResponseCode::Success);
+    assert!(negotiation.get_schema() == Some(ProtocolTagId::Din as u8));
+
+    let verdicts: Vec<ProtocolVerdict> = negotiation
+        .get_candidates()
+        .iter()
+        .map(|candidate| candidate.verdict)
+        .collect();
+    assert!(
+        verdicts
+            == [
+                ProtocolVerdict::Disabled,
+                ProtocolVerdict::LowerPriority,
+                ProtocolVerdict::Selected,
+            ]
+    );
+
+    let response = negotiation.get_response();
+    assert!(response.get_schema() == ProtocolTagId::Din as u8);
+
+    // iso-20 schema id is 0 and must still be sent back
+    let request = SupportedAppProtocolReq::new(V2G_PROTOCOLS_SUPPORTED_LIST[0])?;
+    let negotiation =
+        AppProtocolNegotiator::new(&[V2G_PROTOCOLS_SUPPORTED_LIST[0]]).negotiate(&request);
+    assert!(negotiation.get_schema() == Some(ProtocolTagId::Iso20 as u8));
+    assert!(negotiation.get_response().get_schema() == 0);
+    Ok(())
+}
+
+#[test]
+fn app_protocol_negotiation_failed() -> Result<(), AfbError> {
+    let request = SupportedAppProtocolReq::new(V2G_PROTOCOLS_SUPPORTED_LIST[1])?;
+
+    // same namespace but incompatible major version
+    let iso2_v3 = SupportedAppProtocolConf {
+        tag_id: ProtocolTagId::Iso2,
+        name: V2G_PROTOCOLS_SUPPORTED_LIST[1].name,
+        major: 3,
+        minor: 0,
+    };
+    let negotiation = AppProtocolNegotiator::new(&[&iso2_v3, V2G_PROTOCOLS_SUPPORTED_LIST[2]])
+        .negotiate(&request);
+    assert!(negotiation.get_selected().is_none());
+    assert!(negotiation.get_rcode() == ResponseCode::Failed);
+    assert!(negotiation.get_candidates()[0].verdict == ProtocolVerdict::MajorMismatch);
+
+    let negotiation =
+        AppProtocolNegotiator::new(&[V2G_PROTOCOLS_SUPPORTED_LIST[2]]).negotiate(&request);
+    assert!(negotiation.get_candidates()[0].verdict == ProtocolVerdict::Unsupported);
+    Ok(())
+}
+
+#[test]
+fn app_protocol_req_priorities() -> Result<(), AfbError> {
+    let iso20 = V2G_PROTOCOLS_SUPPORTED_LIST[0];
+    let iso2 = V2G_PROTOCOLS_SUPPORTED_LIST[1];
+    let din = V2G_PROTOCOLS_SUPPORTED_LIST[2];
+
+    // EV prefers iso2 then din then iso20, with its own schema ids
+    let mut request = SupportedAppProtocolReq::empty();
+    request
+        .push_protocol(AppHandAppProtocolType::new(iso20, 3)?.set_schema(10))?
+        .push_protocol(AppHandAppProtocolType::new(din, 2)?.set_schema(11))?
+        .push_protocol(AppHandAppProtocolType::new(iso2, 1)?.set_schema(12))?;
+
+    assert!(request.add_protocol_priority(din, 2).is_err());
+    assert!(AppHandAppProtocolType::new(din, 0).is_err());
+    assert!(AppHandAppProtocolType::new(din, APP_PROTOCOL_PRIORITY_MAX + 1).is_err());
+
+    let exi = ExiStream::new();
+    let stream = encode_to_stream(func_name!(), &exi, request.encode());
+    let message = match decode_from_stream(&stream)? {
+        V2gMsgBody::Request(msg) => msg,
+        _ => panic!("Unexpected message type"),
+    };
+
+    let protocols = message.get_protocols();
+    assert!(protocols.len() == 3);
+    assert!(protocols[0].get_name()? == iso2.name);
+    assert!(protocols[0].get_schema() == 12);
+    assert!(protocols[1].get_name()? == din.name);
+    assert!(protocols[2].get_name()? == iso20.name);
+
+    // EV priority wins over EVSE preference
+    let negotiation = AppProtocolNegotiator::new(&[din, iso2]).negotiate(&message);
+    assert!(
+        negotiation
+            .get_selected()
+            .expect("protocol selected")
+            .tag_id
+            == ProtocolTagId::Iso2
+    );
+    assert!(negotiation.get_schema() == Some(12));
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn app_protocol_req_full() -> Result<(), AfbError> {
+    let protocol = V2G_PROTOCOLS_SUPPORTED_LIST[1];
+    let mut request = SupportedAppProtocolReq::new(protocol)?;
+    let mut status = Ok(());
+    for _ in 0..32 {
+        if let Err(error) = request.add_protocol(protocol) {
+            status = Err(error);
+            break;
+        }
+    }
+    assert!(status.is_err());
     Ok(())
 }
@@ -0,0 +1,131 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+// cargo test --features=xml --package iso15118 --test test-v2g test_v2g_xml
+use iso15118::prelude::*;
+
+const SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+fn iso2_session_setup() -> Result<Vec<u8>, AfbError> {
+    use iso2_exi::*;
+    let header = ExiMessageHeader::new(&SESSION_ID)?;
+    let body = SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    ExiMessageDoc::new(&header, &body).encode()
+}
+
+#[test]
+fn xml_iso2_session_setup() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let frame = iso2_session_setup()?;
+    let xml = ExiMessageDoc::decode(&frame)?.to_xml()?;
+    println!("{}", xml);
+    assert!(xml.contains("<v2gci_d:V2G_Message xmlns:v2gci_d=\"urn:iso:15118:2:2013:MsgDef\""));
+    assert!(xml.contains("<v2gci_h:SessionID>0102030405060708</v2gci_h:SessionID>"));
+    assert!(xml.contains("<v2gci_b:EVCCID>010203040506</v2gci_b:EVCCID>"));
+
+    let document = ExiMessageDoc::from_xml(&xml)?;
+    assert!(document.encode()? == frame);
+    Ok(())
+}
+
+#[test]
+fn xml_reference_import() -> Result<(), AfbError> {
+    // prefixes differ from our export, default namespace is used for MsgDef
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <V2G_Message xmlns="urn:iso:15118:2:2013:MsgDef"
+                     xmlns:ns2="urn:iso:15118:2:2013:MsgHeader"
+                     xmlns:ns3="urn:iso:15118:2:2013:MsgBody">
+          <Header><ns2:SessionID>0102030405060708</ns2:SessionID></Header>
+          <Body>
+            <ns3:SessionSetupReq><ns3:EVCCID>010203040506</ns3:EVCCID></ns3:SessionSetupReq>
+          </Body>
+        </V2G_Message>"#;
+
+    let document = iso2_exi::ExiMessageDoc::from_xml(xml)?;
+    assert!(document.encode()? == iso2_session_setup()?);
+
+    // din & appHand codecs reject ISO-2 root namespace
+    assert!(din_exi::ExiMessageDoc::from_xml(xml).is_err());
+    assert!(v2g::V2gMsgBody::from_xml(xml).is_err());
+    assert!(iso2_exi::ExiMessageDoc::from_xml("<V2G_Message><Header>").is_err());
+    Ok(())
+}
+
+#[test]
+fn xml_iso2_signature() -> Result<(), AfbError> {
+    use iso2_exi::*;
+    let digest = [0x22; 32];
+    let mut reference =
+        SignatureReference::new("#id1", "http://www.w3.org/2001/04/xmlenc#sha256", &digest)?;
+    reference.set_transform("http://www.w3.org/TR/canonical-exi/")?;
+    let mut signature = SignatureType::new(
+        "http://www.w3.org/TR/canonical-exi/",
+        "http://www.w3.org/2001/04/xmldsig-more#ecdsa-sha256",
+        &[0x11; 64],
+    )?;
+    signature.add_reference(&reference)?;
+
+    let mut header = ExiMessageHeader::new(&SESSION_ID)?;
+    header.set_signature(&signature);
+    let mut request = AuthorizationRequest::new();
+    request.set_id("id1")?.set_challenge(&[0x33; 16])?;
+    let frame = ExiMessageDoc::new(&header, &request.encode()).encode()?;
+
+    let xml = ExiMessageDoc::decode(&frame)?.to_xml()?;
+    println!("{}", xml);
+    assert!(xml.contains("<xmlsig:Signature>"));
+    assert!(xml.contains("<xmlsig:Reference URI=\"#id1\">"));
+    assert!(xml.contains(&format!(
+        "<xmlsig:DigestValue>{}</xmlsig:DigestValue>",
+        base64_encode(&digest)
+    )));
+    assert!(xml.contains("<v2gci_b:AuthorizationReq Id=\"id1\">"));
+
+    let document = ExiMessageDoc::from_xml(&xml)?;
+    let signature = match document.get_header().get_signature() {
+        Some(value) => value,
+        None => panic!("signature missing"),
+    };
+    assert!(signature.get_references()[0].get_digest() == digest);
+    assert!(document.encode()? == frame);
+    Ok(())
+}
+
+#[test]
+fn xml_din_apphand_document() -> Result<(), AfbError> {
+    let header = din_exi::ExiMessageHeader::new(&SESSION_ID)?;
+    let body = din_exi::SessionSetupRequest::new(&[0x1, 0x2, 0x3, 0x4, 0x5, 0x6])?.encode();
+    let frame = din_exi::ExiMessageDoc::new(&header, &body).encode()?;
+    let xml = V2gDocument::decode(&frame)?.to_xml()?;
+    assert!(xml.contains("xmlns:v2gci_b=\"urn:din:70121:2012:MsgBody\""));
+    let document = V2gDocument::from_xml(&xml)?;
+    assert!(document.get_protocol() == V2gProtocol::Din);
+    assert!(document.encode()? == frame);
+
+    // appHand children are unqualified
+    let request = v2g::SupportedAppProtocolReq::new(v2g::V2G_PROTOCOLS_SUPPORTED_LIST[1])?;
+    let frame = v2g::V2gMsgBody::Request(request).encode()?;
+    let xml = V2gDocument::decode(&frame)?.to_xml()?;
+    println!("{}", xml);
+    assert!(xml.contains("<v2gci_ah:supportedAppProtocolReq"));
+    assert!(xml.contains("<AppProtocol>"));
+    let document = V2gDocument::from_xml(&xml)?;
+    assert!(document.get_protocol() == V2gProtocol::AppHand);
+    assert!(document.encode()? == frame);
+    Ok(())
+}
@@ -0,0 +1,223 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: decode V2GTP/EXI dumps into JSON/XML and encode JSON/XML back into EXI
+ *   cargo run --features=cli --bin v2g-codec -- decode 0x1,0xfe,0x80,0x1,...
+ *   cargo run --features=cli --bin v2g-codec -- encode message.json
+ */
+
+use iso15118::prelude::*;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::pin::Pin;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage:
+  v2g-codec decode [--protocol apphand|din|iso2|iso20|iso20-dc|iso20-ac] [--input hex|base64|dump] [--xml] [DATA|-]
+  v2g-codec encode [--raw] [--output hex|base64|dump] [FILE.json|FILE.xml|-]
+
+decode: DATA is a V2GTP frame or a raw EXI document as hexadecimal, base64 or
+        dump_buffer (0x1,0xfe,...) text, stdin when missing or '-'. The protocol
+        is detected from V2GTP payload type when not given. With --xml, the
+        document is printed as XSD conformant XML instead of JSON.
+encode: FILE is a JSON document as printed by decode ({\"protocol\":..,\"message\":..})
+        or a V2G_Message/appHand XML document, stdin when missing or '-'. Output is
+        a V2GTP frame (EXI only with --raw).
+        ISO-20 documents have no XML mapping, they are encoded from JSON only.";
+
+#[derive(Clone, Copy, PartialEq)]
+enum TextFormat {
+    Auto,
+    Hex,
+    Base64,
+    Dump,
+}
+
+impl TextFormat {
+    fn from_label(label: &str) -> Result<Self, AfbError> {
+        match label {
+            "hex" => Ok(TextFormat::Hex),
+            "base64" => Ok(TextFormat::Base64),
+            "dump" => Ok(TextFormat::Dump),
+            _ => afb_error!(
+                "v2g-codec-format",
+                "unknown format:{} (hex|base64|dump)",
+                label
+            ),
+        }
+    }
+}
+
+fn parse_input(text: &str, format: TextFormat) -> Result<Vec<u8>, AfbError> {
+    let text = text.trim();
+    match format {
+        TextFormat::Hex | TextFormat::Dump => parse_dump(text),
+        TextFormat::Base64 => base64_decode(text),
+        // dump & hexadecimal first, base64 only when text is not valid hexadecimal
+        TextFormat::Auto => match parse_dump(text) {
+            Ok(data) => Ok(data),
+            Err(_) => base64_decode(text),
+        },
+    }
+}
+
+fn format_output(data: &[u8], format: TextFormat) -> String {
+    match format {
+        TextFormat::Base64 => base64_encode(data),
+        TextFormat::Dump => dump_buffer(data),
+        TextFormat::Hex | TextFormat::Auto => dump_hexa(data),
+    }
+}
+
+fn read_input(arg: Option<&String>, is_file: bool) -> Result<String, AfbError> {
+    let mut text = String::new();
+    match arg.map(|value| value.as_str()) {
+        None | Some("-") => {
+            if let Err(error) = io::stdin().read_to_string(&mut text) {
+                return afb_error!("v2g-codec-input", "fail to read stdin:{}", error);
+            }
+        }
+        Some(path) if is_file => match fs::read_to_string(path) {
+            Ok(value) => text = value,
+            Err(error) => return afb_error!("v2g-codec-input", "fail to read {}:{}", path, error),
+        },
+        Some(value) => text = value.to_string(),
+    }
+    Ok(text)
+}
+
+// frame with a V2GTP header is checked against its declared size, otherwise data is raw exi
+fn decode_data(data: &[u8], protocol: Option<V2gProtocol>) -> Result<V2gDocument, AfbError> {
+    let has_header =
+        data.len() >= V2GTP_HEADER_LEN && data[0] == V2GTP_VERSION && data[1] == V2GTP_VERSION_INV;
+
+    if !has_header {
+        return match protocol {
+            Some(protocol) => {
+                let frame = V2gtpFrame::new(protocol.get_payload_id(), data).encode();
+                V2gDocument::decode_as(protocol, &frame)
+            }
+            None => V2gDocument::decode_exi(data),
+        };
+    }
+
+    let payload_id = v2g::v2gtp_get_payload_id(Pin::new(data))?;
+    let frame_len = v2g::v2gtp_header_check(payload_id, Pin::new(data))? as usize;
+    if frame_len != data.len() {
+        return afb_error!(
+            "v2g-codec-decode",
+            "v2gtp header size:{} does not match data size:{}",
+            frame_len,
+            data.len()
+        );
+    }
+
+    match protocol {
+        Some(protocol) => V2gDocument::decode_as(protocol, data),
+        None => V2gDocument::decode(data),
+    }
+}
+
+fn decode_cmd(args: &[String]) -> Result<(), AfbError> {
+    let mut protocol = None;
+    let mut format = TextFormat::Auto;
+    let mut xml = false;
+    let mut input = None;
+    let mut idx = 0;
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "--protocol" if idx + 1 < args.len() => {
+                idx += 1;
+                protocol = Some(V2gProtocol::from_label(&args[idx])?);
+            }
+            "--input" if idx + 1 < args.len() => {
+                idx += 1;
+                format = TextFormat::from_label(&args[idx])?;
+            }
+            "--xml" => xml = true,
+            _ => input = Some(&args[idx]),
+        }
+        idx += 1;
+    }
+
+    let data = parse_input(&read_input(input, false)?, format)?;
+    let document = decode_data(&data, protocol)?;
+    if xml {
+        print!("{}", document.to_xml()?);
+        return Ok(());
+    }
+    match serde_json::to_string_pretty(&document) {
+        Ok(json) => println!("{}", json),
+        Err(error) => return afb_error!("v2g-codec-decode", "json serialize:{}", error),
+    }
+    Ok(())
+}
+
+fn encode_cmd(args: &[String]) -> Result<(), AfbError> {
+    let mut raw = false;
+    let mut format = TextFormat::Hex;
+    let mut input = None;
+    let mut idx = 0;
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "--raw" => raw = true,
+            "--output" if idx + 1 < args.len() => {
+                idx += 1;
+                format = TextFormat::from_label(&args[idx])?;
+            }
+            _ => input = Some(&args[idx]),
+        }
+        idx += 1;
+    }
+
+    let text = read_input(input, true)?;
+    let document: V2gDocument = if text.trim_start().starts_with('<') {
+        V2gDocument::from_xml(&text)?
+    } else {
+        match serde_json::from_str(&text) {
+            Ok(value) => value,
+            Err(error) => return afb_error!("v2g-codec-encode", "json deserialize:{}", error),
+        }
+    };
+    let frame = document.encode()?;
+    let data = if raw {
+        &frame[V2GTP_HEADER_LEN..]
+    } else {
+        &frame[..]
+    };
+    println!("{}", format_output(data, format));
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let status = match args.first().map(|value| value.as_str()) {
+        Some("decode") => decode_cmd(&args[1..]),
+        Some("encode") => encode_cmd(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match status {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("v2g-codec: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * Object: print V2G session timeline from a pcap/pcapng capture of the PLC interface
+ *   cargo run --features=cli,pcap --bin v2g-pcap -- decode --keylog sslkey.log capture.pcapng
+ *   cargo run --features=cli,pcap --bin v2g-pcap -- sample session.pcap
+ */
+
+use iso15118::prelude::*;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::{Ipv6Addr, SocketAddrV6};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage:
+  v2g-pcap decode [--keylog FILE] [--json] [FILE.pcap|FILE.pcapng|-]
+  v2g-pcap sample [FILE.pcap|-]
+
+decode: FILE is a pcap or pcapng capture, stdin when missing or '-'. IPv6 UDP SDP
+        and TCP V2GTP streams are reassembled and every frame is decoded with the
+        codec negotiated by SupportedAppProtocolRes. TLS sessions are decrypted
+        when a NSS key log (SSLKEYLOGFILE format) is given. Output is one text
+        line per event, or a JSON array with --json.
+sample: write a clear text SDP, appHand & ISO-2 session capture built with the
+        crate encoders, stdout when missing or '-'.";
+
+const SAMPLE_SESSION_ID: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+const SAMPLE_EVCC_ID: [u8; 6] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+const SAMPLE_SECC_PORT: u16 = 61341;
+const SAMPLE_SDP_PORT: u16 = 15118;
+const SAMPLE_START: u64 = 1_700_000_000_000_000;
+
+fn read_capture(arg: Option<&String>) -> Result<Vec<u8>, AfbError> {
+    let mut data = Vec::new();
+    match arg.map(|value| value.as_str()) {
+        None | Some("-") => {
+            if let Err(error) = io::stdin().read_to_end(&mut data) {
+                return afb_error!("v2g-pcap-input", "fail to read stdin:{}", error);
+            }
+        }
+        Some(path) => match fs::read(path) {
+            Ok(value) => data = value,
+            Err(error) => return afb_error!("v2g-pcap-input", "fail to read {}:{}", path, error),
+        },
+    }
+    Ok(data)
+}
+
+fn decode_cmd(args: &[String]) -> Result<(), AfbError> {
+    let mut analyzer = V2gPcapAnalyzer::new();
+    let mut json = false;
+    let mut input = None;
+    let mut idx = 0;
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "--keylog" if idx + 1 < args.len() => {
+                idx += 1;
+                analyzer.set_keylog(TlsKeyLog::from_file(&args[idx])?);
+            }
+            "--json" => json = true,
+            _ => input = Some(&args[idx]),
+        }
+        idx += 1;
+    }
+
+    let capture = read_capture(input)?;
+    let events = analyzer.analyze(&capture)?;
+    if !json {
+        print!("{}", analyzer.to_text());
+        return Ok(());
+    }
+    match serde_json::to_string_pretty(events) {
+        Ok(json) => println!("{}", json),
+        Err(error) => return afb_error!("v2g-pcap-decode", "json serialize:{}", error),
+    }
+    Ok(())
+}
+
+fn sample_capture() -> Result<Vec<u8>, AfbError> {
+    let ev_addr = Ipv6Addr::new(0xfe80, 0, 0, 0, 0x0201, 0x02ff, 0xfe03, 0x0405);
+    let evse_addr = Ipv6Addr::new(0xfe80, 0, 0, 0, 0x0211, 0x22ff, 0xfe33, 0x4455);
+    let ev_sdp = SocketAddrV6::new(ev_addr, 50000, 0, 0);
+    let sdp_server = SocketAddrV6::new(
+        Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1),
+        SAMPLE_SDP_PORT,
+        0,
+        0,
+    );
+    let ev_tcp = SocketAddrV6::new(ev_addr, 50001, 0, 0);
+    let evse_tcp = SocketAddrV6::new(evse_addr, SAMPLE_SECC_PORT, 0, 0);
+
+    let mut writer = PcapWriter::new();
+    let mut timestamp = SAMPLE_START;
+
+    let request = v2g::SdpRequest::new(v2g::SdpTransportProtocol::TCP, v2g::SdpSecurityModel::NONE)
+        .encode()?;
+    writer.add_udp(timestamp, &ev_sdp, &sdp_server, &request);
+    let response = v2g::SdpResponse::new(
+        evse_addr.octets(),
+        SAMPLE_SECC_PORT,
+        v2g::SdpTransportProtocol::TCP,
+        v2g::SdpSecurityModel::NONE,
+    )
+    .encode()?;
+    timestamp += 5_000;
+    writer.add_udp(
+        timestamp,
+        &SocketAddrV6::new(evse_addr, SAMPLE_SDP_PORT, 0, 0),
+        &ev_sdp,
+        &response,
+    );
+
+    let mut flow = PcapTcpFlow::new(ev_tcp, evse_tcp);
+    timestamp += 20_000;
+    flow.connect(&mut writer, timestamp);
+
+    let mut frames = Vec::new();
+    let mut request = v2g::SupportedAppProtocolReq::empty();
+    for (idx, protocol) in v2g::V2G_PROTOCOLS_SUPPORTED_LIST.iter().enumerate() {
+        request.add_protocol_priority(protocol, idx as u8 + 1)?;
+    }
+    frames.push((true, v2g::V2gMsgBody::Request(request).encode()?));
+    let response = v2g::SupportedAppProtocolRes::new(
+        v2g::ResponseCode::Success,
+        v2g::ProtocolTagId::Iso2 as u8,
+    );
+    frames.push((false, v2g::V2gMsgBody::Response(response).encode()?));
+
+    {
+        use iso2_exi::*;
+        let header = ExiMessageHeader::new(&[0])?;
+        let body = SessionSetupRequest::new(&SAMPLE_EVCC_ID)?.encode();
+        frames.push((true, ExiMessageDoc::new(&header, &body).encode()?));
+
+        let header = ExiMessageHeader::new(&SAMPLE_SESSION_ID)?;
+        let body = SessionSetupResponse::new("FR*IOT*E12345", ResponseCode::NewSession)?.encode();
+        frames.push((false, ExiMessageDoc::new(&header, &body).encode()?));
+
+        let body = SessionStopRequest::new(ChargingSessionType::Terminate).encode();
+        frames.push((true, ExiMessageDoc::new(&header, &body).encode()?));
+        let body = SessionStopResponse::new(ResponseCode::Ok).encode();
+        frames.push((false, ExiMessageDoc::new(&header, &body).encode()?));
+    }
+
+    for (from_client, frame) in frames {
+        timestamp += 10_000;
+        flow.send(&mut writer, timestamp, from_client, &frame);
+    }
+    Ok(writer.get_buffer().to_vec())
+}
+
+fn sample_cmd(args: &[String]) -> Result<(), AfbError> {
+    let capture = sample_capture()?;
+    let status = match args.first().map(|value| value.as_str()) {
+        None | Some("-") => io::stdout().write_all(&capture),
+        Some(path) => fs::write(path, &capture),
+    };
+    if let Err(error) = status {
+        return afb_error!("v2g-pcap-sample", "fail to write capture:{}", error);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let status = match args.first().map(|value| value.as_str()) {
+        Some("decode") => decode_cmd(&args[1..]),
+        Some("sample") => sample_cmd(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match status {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("v2g-pcap: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}